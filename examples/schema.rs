@@ -3,9 +3,18 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
+use payable_asset_smart_contract::core::api::{get_execute_gas_hints, ApiDescriptorV1};
 use payable_asset_smart_contract::core::msg::{
-    ExecuteMsg, InitMsg, MigrateMsg, QueryMsg, QueryResponse,
+    AdminOverviewResponse, AllPayablesResponse, EffectiveFeesResponse, EscrowTotalsResponse,
+    ExecuteMsg, ExportPaymentsResponse, FeeHolidaysResponse, InitMsg, MetricsSnapshotResponse,
+    MigrateMsg, OverdueApprovalsResponse, PayableAuthorizedPayersResponse, PayableDiffResponse,
+    PayableInstallmentsResponse, PayableInterestResponse, PayableQueryResponse,
+    PayableTypeFrozenResponse, PaymentAllowancesResponse, PaymentHistoryResponse,
+    PaymentSimulationResponse, PaymentVelocityResponse, PaymentsResponse, QuarantineConfigResponse,
+    QuarantinedPaymentsResponse, QueryMsg, ReferrerRevenueResponse, RegistrationQuoteResponse,
+    RiskBandsResponse, ScopeAttributesResponse, StateQueryResponse,
 };
+use payable_asset_smart_contract::core::state::PayableTimelineV1;
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -13,9 +22,42 @@ fn main() {
     create_dir_all(&out_dir).unwrap();
     remove_schemas(&out_dir).unwrap();
 
-    export_schema(&schema_for!(ExecuteMsg), &out_dir);
+    // Client SDKs read the gas hints from the execute msg schema to set default gas limits
+    let mut execute_msg_schema = schema_for!(ExecuteMsg);
+    execute_msg_schema.schema.extensions.insert(
+        "gas_hints".to_string(),
+        serde_json::to_value(get_execute_gas_hints()).unwrap(),
+    );
+    export_schema(&execute_msg_schema, &out_dir);
     export_schema(&schema_for!(InitMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
-    export_schema(&schema_for!(QueryResponse), &out_dir);
+    export_schema(&schema_for!(StateQueryResponse), &out_dir);
+    export_schema(&schema_for!(PayableQueryResponse), &out_dir);
     export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(EffectiveFeesResponse), &out_dir);
+    export_schema(&schema_for!(PayableTimelineV1), &out_dir);
+    export_schema(&schema_for!(ApiDescriptorV1), &out_dir);
+    export_schema(&schema_for!(MetricsSnapshotResponse), &out_dir);
+    export_schema(&schema_for!(PaymentHistoryResponse), &out_dir);
+    export_schema(&schema_for!(FeeHolidaysResponse), &out_dir);
+    export_schema(&schema_for!(ExportPaymentsResponse), &out_dir);
+    export_schema(&schema_for!(PayableDiffResponse), &out_dir);
+    export_schema(&schema_for!(PayableInstallmentsResponse), &out_dir);
+    export_schema(&schema_for!(PayableAuthorizedPayersResponse), &out_dir);
+    export_schema(&schema_for!(QuarantineConfigResponse), &out_dir);
+    export_schema(&schema_for!(QuarantinedPaymentsResponse), &out_dir);
+    export_schema(&schema_for!(OverdueApprovalsResponse), &out_dir);
+    export_schema(&schema_for!(PayableInterestResponse), &out_dir);
+    export_schema(&schema_for!(RiskBandsResponse), &out_dir);
+    export_schema(&schema_for!(PayableTypeFrozenResponse), &out_dir);
+    export_schema(&schema_for!(AllPayablesResponse), &out_dir);
+    export_schema(&schema_for!(PaymentVelocityResponse), &out_dir);
+    export_schema(&schema_for!(ReferrerRevenueResponse), &out_dir);
+    export_schema(&schema_for!(AdminOverviewResponse), &out_dir);
+    export_schema(&schema_for!(RegistrationQuoteResponse), &out_dir);
+    export_schema(&schema_for!(PaymentAllowancesResponse), &out_dir);
+    export_schema(&schema_for!(PaymentSimulationResponse), &out_dir);
+    export_schema(&schema_for!(PaymentsResponse), &out_dir);
+    export_schema(&schema_for!(ScopeAttributesResponse), &out_dir);
+    export_schema(&schema_for!(EscrowTotalsResponse), &out_dir);
 }
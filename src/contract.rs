@@ -1,12 +1,96 @@
+#[cfg(not(feature = "allowance"))]
+use crate::core::api::ALLOWANCE_FEATURE;
+#[cfg(not(feature = "escrow"))]
+use crate::core::api::ESCROW_FEATURE;
 use crate::core::error::ContractError;
+use crate::core::money::Money;
 use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
+use crate::execute::cancel_payable::cancel_payable;
+use crate::execute::change_oracle::change_oracle;
+use crate::execute::cleanup_orphaned_attributes::cleanup_orphaned_attributes;
+use crate::execute::decline_payable::decline_payable;
+use crate::execute::denom_display_metadata::{
+    remove_denom_display_metadata, set_denom_display_metadata,
+};
+#[cfg(feature = "escrow")]
+use crate::execute::escrow_payment::{reclaim_escrow, release_payment, reverse_payment};
+use crate::execute::expire_payable::expire_payable;
+use crate::execute::fee_holiday::{cancel_fee_holiday, schedule_fee_holiday};
+use crate::execute::flag_erroneous_payment::flag_erroneous_payment;
+use crate::execute::make_batch_payment::make_batch_payment;
 use crate::execute::make_payment::make_payment;
-use crate::execute::oracle_approval::oracle_approval;
+use crate::execute::oracle_approval::{oracle_approval, renew_oracle_approval};
+use crate::execute::oracle_delegate::{add_delegate, remove_delegate};
+use crate::execute::patch_payable_meta::patch_payable_meta;
+use crate::execute::payable_dispute::{raise_dispute, resolve_dispute};
+use crate::execute::payable_freeze::set_payable_frozen;
+use crate::execute::payable_type_config::upsert_payable_type_config;
+use crate::execute::payable_type_freeze::set_payable_type_frozen;
+use crate::execute::payable_type_supply_check::set_payable_type_supply_check;
+#[cfg(feature = "allowance")]
+use crate::execute::payment_allowance::{
+    grant_payment_allowance, pull_allowance_payment, revoke_payment_allowance,
+};
+use crate::execute::payment_volume_cap::{reset_payment_volume, set_payment_volume_cap};
+#[cfg(feature = "escrow")]
+use crate::execute::pending_cancellation::{claim_cancellation_refund, finalize_cancellation};
+use crate::execute::propose_payment::{approve_payment, cancel_proposed_payment, propose_payment};
+use crate::execute::prune_payable::prune_payable;
+use crate::execute::quarantine::{
+    refund_quarantined_payment, release_quarantined_payment, set_quarantine_config,
+};
+use crate::execute::re_register_payable::re_register_payable;
+use crate::execute::reassign_oracle_bulk::reassign_oracle_bulk;
+use crate::execute::record_external_settlement::record_external_settlement;
+use crate::execute::referral::set_referral_config;
 use crate::execute::register_payable::register_payable;
+use crate::execute::register_payables::register_payables;
+use crate::execute::restructure_payable::restructure_payable;
+use crate::execute::revert_stale_approval::revert_stale_approval;
+use crate::execute::risk_bands::set_risk_bands;
+use crate::execute::set_paused::set_paused;
+#[cfg(feature = "escrow")]
+use crate::execute::settle_netting::settle_netting;
+use crate::execute::stage_migration_params::stage_migration_params;
+use crate::execute::subscribe::subscribe;
+use crate::execute::supported_payable_type::{add_payable_type, remove_payable_type};
+use crate::execute::transfer_payable::transfer_payable;
+use crate::execute::update_payable_total::update_payable_total;
+use crate::execute::write_off_payable::write_off_payable;
 use crate::instantiate::init_contract::init_contract;
 use crate::migrate::migrate_contract::migrate_contract;
+use crate::query::query_admin_overview::query_admin_overview;
+use crate::query::query_all_payables::query_all_payables;
+use crate::query::query_api_descriptor::query_api_descriptor;
+use crate::query::query_effective_fees::query_effective_fees;
+#[cfg(feature = "escrow")]
+use crate::query::query_escrow_totals::query_escrow_totals;
+use crate::query::query_export_payments::query_export_payments;
+use crate::query::query_fee_holidays::query_fee_holidays;
+use crate::query::query_instantiate_template::query_instantiate_template;
+use crate::query::query_metrics_snapshot::query_metrics_snapshot;
+use crate::query::query_overdue_approvals::query_overdue_approvals;
+use crate::query::query_payable::query_payable_binary;
+use crate::query::query_payable_authorized_payers::query_payable_authorized_payers;
 use crate::query::query_payable_by_scope_id::query_payable_binary_by_scope_id;
 use crate::query::query_payable_by_uuid::query_payable_binary_by_uuid;
+use crate::query::query_payable_diff::query_payable_diff;
+use crate::query::query_payable_installments::query_payable_installments;
+use crate::query::query_payable_interest::query_payable_interest;
+use crate::query::query_payable_timeline::query_payable_timeline;
+use crate::query::query_payable_type_frozen::query_payable_type_frozen;
+#[cfg(feature = "allowance")]
+use crate::query::query_payment_allowances::query_payment_allowances;
+use crate::query::query_payment_history::query_payment_history;
+use crate::query::query_payment_velocity::query_payment_velocity;
+use crate::query::query_payments::query_payments;
+use crate::query::query_quarantine_config::query_quarantine_config;
+use crate::query::query_quarantined_payments::query_quarantined_payments;
+use crate::query::query_referrer_revenue::query_referrer_revenue;
+use crate::query::query_registration_quote::query_registration_quote;
+use crate::query::query_risk_bands::query_risk_bands;
+use crate::query::query_scope_attributes::query_scope_attributes;
+use crate::query::query_simulate_payment::query_simulate_payment;
 use crate::query::query_state::query_state;
 use crate::util::traits::ValidatedMsg;
 use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
@@ -29,7 +113,7 @@ pub fn instantiate(
 #[entry_point]
 pub fn query(
     deps: Deps<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, ContractError> {
     // Ensure that the message is valid before processing the request
@@ -42,14 +126,112 @@ pub fn query(
         QueryMsg::QueryPayableByUuid { payable_uuid } => {
             query_payable_binary_by_uuid(&deps, payable_uuid)
         }
+        QueryMsg::QueryEffectiveFees { payable_type } => {
+            query_effective_fees(deps, env, payable_type)
+        }
+        QueryMsg::QueryPayableTimeline { payable_uuid } => {
+            query_payable_timeline(&deps, payable_uuid)
+        }
+        QueryMsg::QueryApiDescriptor {} => query_api_descriptor(deps),
+        QueryMsg::QueryMetricsSnapshot {} => query_metrics_snapshot(deps, env),
+        QueryMsg::QueryPaymentHistory { payable_uuid } => {
+            query_payment_history(&deps, payable_uuid)
+        }
+        QueryMsg::QueryFeeHolidays {} => query_fee_holidays(deps, env),
+        QueryMsg::ExportPayments { start_after, limit } => {
+            query_export_payments(&deps, start_after, limit)
+        }
+        QueryMsg::QueryPayableDiff {
+            payable_uuid,
+            from_seq,
+            to_seq,
+        } => query_payable_diff(&deps, payable_uuid, from_seq, to_seq),
+        QueryMsg::QueryPayableInstallments { payable_uuid } => {
+            query_payable_installments(&deps, env, payable_uuid)
+        }
+        QueryMsg::QueryPayableAuthorizedPayers { payable_uuid } => {
+            query_payable_authorized_payers(&deps, payable_uuid)
+        }
+        QueryMsg::QueryQuarantineConfig {} => query_quarantine_config(deps),
+        QueryMsg::QueryQuarantinedPayments { payable_uuid } => {
+            query_quarantined_payments(&deps, payable_uuid)
+        }
+        QueryMsg::QueryOverdueApprovals { limit } => query_overdue_approvals(deps, env, limit),
+        QueryMsg::QueryInstantiateTemplate {} => query_instantiate_template(deps),
+        QueryMsg::QueryPayableInterest { payable_uuid } => {
+            query_payable_interest(&deps, env, payable_uuid)
+        }
+        QueryMsg::QueryRiskBands {} => query_risk_bands(deps),
+        QueryMsg::QueryPayableTypeFrozen { payable_type } => {
+            query_payable_type_frozen(deps, payable_type)
+        }
+        QueryMsg::QueryAllPayables { start_after, limit } => {
+            query_all_payables(&deps, start_after, limit)
+        }
+        QueryMsg::QueryPaymentVelocity { denom } => query_payment_velocity(deps, env, denom),
+        QueryMsg::QueryReferrerRevenue { referrer } => query_referrer_revenue(deps, referrer),
+        QueryMsg::QueryAdminOverview {} => query_admin_overview(deps, env),
+        QueryMsg::QueryRegistrationQuote { payable_type } => {
+            query_registration_quote(deps, env, payable_type)
+        }
+        #[cfg(feature = "allowance")]
+        QueryMsg::QueryPaymentAllowances { payable_uuid } => {
+            query_payment_allowances(&deps, payable_uuid)
+        }
+        #[cfg(not(feature = "allowance"))]
+        QueryMsg::QueryPaymentAllowances { .. } => ContractError::FeatureNotCompiled {
+            feature: ALLOWANCE_FEATURE.to_string(),
+        }
+        .to_result(),
+        QueryMsg::SimulatePayment {
+            payable_uuid,
+            amount,
+            denom,
+            payer,
+            refund_overpayment,
+        } => query_simulate_payment(
+            deps,
+            env,
+            payable_uuid,
+            Money::new(amount, denom),
+            payer,
+            refund_overpayment.unwrap_or(false),
+        ),
+        QueryMsg::QueryPayments {
+            payable_uuid,
+            payer,
+            after_time,
+            start_after,
+            limit,
+        } => query_payments(&deps, payable_uuid, payer, after_time, start_after, limit),
+        QueryMsg::QueryPayable { identifier } => query_payable_binary(&deps, identifier),
+        QueryMsg::QueryScopeAttributes { scope_id } => query_scope_attributes(&deps, scope_id),
+        #[cfg(feature = "escrow")]
+        QueryMsg::QueryEscrowTotals { payable_type } => query_escrow_totals(deps, payable_type),
+        #[cfg(not(feature = "escrow"))]
+        QueryMsg::QueryEscrowTotals { .. } => ContractError::FeatureNotCompiled {
+            feature: ESCROW_FEATURE.to_string(),
+        }
+        .to_result(),
     }
 }
 
-/// Handle execution strategies - register payable, oracle approval, make payments
+/// Handle execution strategies - register payable, batch registration, payable total updates,
+/// oracle reassignment, payable restructuring, payable write offs, payable expiration, payable
+/// cancellation, payable transfers, oracle approval, oracle delegation, make payments, batch
+/// payments, denom display metadata management, migration staging, orphaned attribute cleanup,
+/// supply check configuration, payable subscriptions, fee holiday scheduling, contract pausing,
+/// payable meta patching, payable type configuration, supported payable type management, payable
+/// declines, payment volume caps, escrowed payment releases and reclaims, payable disputes, bulk
+/// oracle reassignment, settled payable pruning, oracle approval renewal, payment quarantine,
+/// oracle co-signed payments, external settlement recording, payable re-registration, escrowed
+/// payment reversal, risk band configuration, erroneous payment flagging, payable freezing,
+/// payable type freezing, stale approval reverts, payment allowance grants and pulls, escrowed
+/// payment netting, cancellation refund claims and cancellation finalization
 #[entry_point]
 pub fn execute(
     deps: DepsMut<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
@@ -57,10 +239,191 @@ pub fn execute(
     msg.validate()?;
     match msg {
         ExecuteMsg::RegisterPayable { .. } => {
-            register_payable(deps, info, msg.to_register_payable()?)
+            register_payable(deps, env, info, msg.to_register_payable()?)
+        }
+        ExecuteMsg::RegisterPayables { .. } => {
+            register_payables(deps, env, info, msg.to_register_payables()?)
+        }
+        ExecuteMsg::UpdatePayableTotal { .. } => {
+            update_payable_total(deps, env, info, msg.to_update_payable_total()?)
+        }
+        ExecuteMsg::ChangeOracle { .. } => change_oracle(deps, env, info, msg.to_change_oracle()?),
+        ExecuteMsg::RestructurePayable { .. } => {
+            restructure_payable(deps, env, info, msg.to_restructure_payable()?)
+        }
+        ExecuteMsg::WriteOffPayable { .. } => {
+            write_off_payable(deps, env, info, msg.to_write_off_payable()?)
+        }
+        ExecuteMsg::ExpirePayable { .. } => {
+            expire_payable(deps, env, info, msg.to_expire_payable()?)
+        }
+        ExecuteMsg::OracleApproval { .. } => {
+            oracle_approval(deps, env, info, msg.to_oracle_approval()?)
+        }
+        ExecuteMsg::AddOracleDelegate { .. } => {
+            add_delegate(deps, info, msg.to_add_oracle_delegate()?)
+        }
+        ExecuteMsg::RemoveOracleDelegate { .. } => {
+            remove_delegate(deps, info, msg.to_remove_oracle_delegate()?)
+        }
+        ExecuteMsg::MakePayment { .. } => make_payment(deps, env, info, msg.to_make_payment()?),
+        ExecuteMsg::MakeBatchPayment { .. } => {
+            make_batch_payment(deps, env, info, msg.to_make_batch_payment()?)
+        }
+        ExecuteMsg::SetDenomDisplayMetadata { .. } => {
+            set_denom_display_metadata(deps, info, msg.to_set_denom_display_metadata()?)
+        }
+        ExecuteMsg::RemoveDenomDisplayMetadata { .. } => {
+            remove_denom_display_metadata(deps, info, msg.to_remove_denom_display_metadata()?)
+        }
+        ExecuteMsg::StageMigrationParams { .. } => {
+            stage_migration_params(deps, env, info, msg.to_stage_migration_params()?)
+        }
+        ExecuteMsg::CleanupOrphanedAttributes { .. } => {
+            cleanup_orphaned_attributes(deps, info, msg.to_cleanup_orphaned_attributes()?)
+        }
+        ExecuteMsg::SetPayableTypeSupplyCheck { .. } => {
+            set_payable_type_supply_check(deps, info, msg.to_set_payable_type_supply_check()?)
+        }
+        ExecuteMsg::Subscribe { .. } => subscribe(deps, info, msg.to_subscribe()?),
+        ExecuteMsg::ScheduleFeeHoliday { .. } => {
+            schedule_fee_holiday(deps, env, info, msg.to_schedule_fee_holiday()?)
+        }
+        ExecuteMsg::CancelFeeHoliday { .. } => {
+            cancel_fee_holiday(deps, info, msg.to_cancel_fee_holiday()?)
+        }
+        ExecuteMsg::CancelPayable { .. } => {
+            cancel_payable(deps, env, info, msg.to_cancel_payable()?)
+        }
+        ExecuteMsg::TransferPayable { .. } => {
+            transfer_payable(deps, env, info, msg.to_transfer_payable()?)
         }
-        ExecuteMsg::OracleApproval { .. } => oracle_approval(deps, info, msg.to_oracle_approval()?),
-        ExecuteMsg::MakePayment { .. } => make_payment(deps, info, msg.to_make_payment()?),
+        ExecuteMsg::SetPaused { .. } => set_paused(deps, info, msg.to_set_paused()?),
+        ExecuteMsg::PatchPayableMeta { .. } => {
+            patch_payable_meta(deps, env, info, msg.to_patch_payable_meta()?)
+        }
+        ExecuteMsg::UpsertPayableTypeConfig { .. } => {
+            upsert_payable_type_config(deps, info, msg.to_upsert_payable_type_config()?)
+        }
+        ExecuteMsg::AddSupportedPayableType { .. } => {
+            add_payable_type(deps, info, msg.to_add_supported_payable_type()?)
+        }
+        ExecuteMsg::RemoveSupportedPayableType { .. } => {
+            remove_payable_type(deps, info, msg.to_remove_supported_payable_type()?)
+        }
+        ExecuteMsg::DeclinePayable { .. } => {
+            decline_payable(deps, env, info, msg.to_decline_payable()?)
+        }
+        ExecuteMsg::SetPaymentVolumeCap { .. } => {
+            set_payment_volume_cap(deps, info, msg.to_set_payment_volume_cap()?)
+        }
+        ExecuteMsg::ResetPaymentVolume { .. } => {
+            reset_payment_volume(deps, info, msg.to_reset_payment_volume()?)
+        }
+        #[cfg(feature = "escrow")]
+        ExecuteMsg::ReleasePayment { .. } => release_payment(deps, info, msg.to_release_payment()?),
+        #[cfg(feature = "escrow")]
+        ExecuteMsg::ReclaimEscrow { .. } => {
+            reclaim_escrow(deps, env, info, msg.to_reclaim_escrow()?)
+        }
+        ExecuteMsg::RaiseDispute { .. } => raise_dispute(deps, env, info, msg.to_raise_dispute()?),
+        ExecuteMsg::ResolveDispute { .. } => {
+            resolve_dispute(deps, env, info, msg.to_resolve_dispute()?)
+        }
+        ExecuteMsg::ReassignOracleBulk { .. } => {
+            reassign_oracle_bulk(deps, env, info, msg.to_reassign_oracle_bulk()?)
+        }
+        ExecuteMsg::PrunePayable { .. } => prune_payable(deps, env, info, msg.to_prune_payable()?),
+        ExecuteMsg::RenewOracleApproval { .. } => {
+            renew_oracle_approval(deps, env, info, msg.to_renew_oracle_approval()?)
+        }
+        ExecuteMsg::SetQuarantineConfig { .. } => {
+            set_quarantine_config(deps, info, msg.to_set_quarantine_config()?)
+        }
+        ExecuteMsg::ReleaseQuarantinedPayment { .. } => {
+            release_quarantined_payment(deps, env, info, msg.to_release_quarantined_payment()?)
+        }
+        ExecuteMsg::RefundQuarantinedPayment { .. } => {
+            refund_quarantined_payment(deps, env, info, msg.to_refund_quarantined_payment()?)
+        }
+        ExecuteMsg::ProposePayment { .. } => {
+            propose_payment(deps, env, info, msg.to_propose_payment()?)
+        }
+        ExecuteMsg::ApprovePayment { .. } => {
+            approve_payment(deps, env, info, msg.to_approve_payment()?)
+        }
+        ExecuteMsg::CancelProposedPayment { .. } => {
+            cancel_proposed_payment(deps, info, msg.to_cancel_proposed_payment()?)
+        }
+        ExecuteMsg::RecordExternalSettlement { .. } => {
+            record_external_settlement(deps, env, info, msg.to_record_external_settlement()?)
+        }
+        ExecuteMsg::ReRegisterPayable { .. } => {
+            re_register_payable(deps, env, info, msg.to_re_register_payable()?)
+        }
+        #[cfg(feature = "escrow")]
+        ExecuteMsg::ReversePayment { .. } => {
+            reverse_payment(deps, env, info, msg.to_reverse_payment()?)
+        }
+        // Leaner builds keep every message in the schema, but reject those of subsystems that they
+        // do not include
+        #[cfg(not(feature = "escrow"))]
+        ExecuteMsg::ReleasePayment { .. }
+        | ExecuteMsg::ReclaimEscrow { .. }
+        | ExecuteMsg::ReversePayment { .. }
+        | ExecuteMsg::SettleNetting { .. }
+        | ExecuteMsg::ClaimCancellationRefund { .. }
+        | ExecuteMsg::FinalizeCancellation { .. } => ContractError::FeatureNotCompiled {
+            feature: ESCROW_FEATURE.to_string(),
+        }
+        .to_result(),
+        ExecuteMsg::SetRiskBands { .. } => set_risk_bands(deps, info, msg.to_set_risk_bands()?),
+        ExecuteMsg::FlagErroneousPayment { .. } => {
+            flag_erroneous_payment(deps, env, info, msg.to_flag_erroneous_payment()?)
+        }
+        ExecuteMsg::SetPayableFrozen { .. } => {
+            set_payable_frozen(deps, env, info, msg.to_set_payable_frozen()?)
+        }
+        ExecuteMsg::SetPayableTypeFrozen { .. } => {
+            set_payable_type_frozen(deps, info, msg.to_set_payable_type_frozen()?)
+        }
+        ExecuteMsg::RevertStaleApproval { .. } => {
+            revert_stale_approval(deps, env, info, msg.to_revert_stale_approval()?)
+        }
+        ExecuteMsg::SetReferralConfig { .. } => {
+            set_referral_config(deps, info, msg.to_set_referral_config()?)
+        }
+        #[cfg(feature = "allowance")]
+        ExecuteMsg::GrantPaymentAllowance { .. } => {
+            grant_payment_allowance(deps, env, info, msg.to_grant_payment_allowance()?)
+        }
+        #[cfg(feature = "allowance")]
+        ExecuteMsg::RevokePaymentAllowance { .. } => {
+            revoke_payment_allowance(deps, info, msg.to_revoke_payment_allowance()?)
+        }
+        #[cfg(feature = "allowance")]
+        ExecuteMsg::PullAllowancePayment { .. } => {
+            pull_allowance_payment(deps, env, info, msg.to_pull_allowance_payment()?)
+        }
+        #[cfg(feature = "escrow")]
+        ExecuteMsg::SettleNetting { .. } => {
+            settle_netting(deps, env, info, msg.to_settle_netting()?)
+        }
+        #[cfg(feature = "escrow")]
+        ExecuteMsg::ClaimCancellationRefund { .. } => {
+            claim_cancellation_refund(deps, env, info, msg.to_claim_cancellation_refund()?)
+        }
+        #[cfg(feature = "escrow")]
+        ExecuteMsg::FinalizeCancellation { .. } => {
+            finalize_cancellation(deps, env, info, msg.to_finalize_cancellation()?)
+        }
+        #[cfg(not(feature = "allowance"))]
+        ExecuteMsg::GrantPaymentAllowance { .. }
+        | ExecuteMsg::RevokePaymentAllowance { .. }
+        | ExecuteMsg::PullAllowancePayment { .. } => ContractError::FeatureNotCompiled {
+            feature: ALLOWANCE_FEATURE.to_string(),
+        }
+        .to_result(),
     }
 }
 
@@ -68,11 +431,11 @@ pub fn execute(
 #[entry_point]
 pub fn migrate(
     deps: DepsMut<ProvenanceQuery>,
-    _env: Env,
+    env: Env,
     msg: MigrateMsg,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     // Ensure that the message is valid before processing the request
     msg.validate()?;
     let migrate_msg = msg.to_migrate_contract_v2(&deps.as_ref())?;
-    migrate_contract(deps, migrate_msg)
+    migrate_contract(deps, env, migrate_msg)
 }
@@ -0,0 +1,1206 @@
+use crate::core::state::StateV2;
+use crate::migrate::version_info::{CONTRACT_NAME, CONTRACT_VERSION};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The version of the descriptor format itself.  Increment this when the shape of the descriptor
+/// changes, not when messages are added or removed.
+pub const API_DESCRIPTOR_VERSION: u32 = 1;
+
+/// Every ExecuteMsg variant supported by this contract, in its serialized (snake_case) form.  This
+/// must be updated whenever a variant is added to or removed from ExecuteMsg.
+pub const SUPPORTED_EXECUTE_MSGS: &[&str] = &[
+    "register_payable",
+    "register_payables",
+    "update_payable_total",
+    "change_oracle",
+    "restructure_payable",
+    "write_off_payable",
+    "expire_payable",
+    "oracle_approval",
+    "add_oracle_delegate",
+    "remove_oracle_delegate",
+    "make_payment",
+    "make_batch_payment",
+    "set_denom_display_metadata",
+    "remove_denom_display_metadata",
+    "stage_migration_params",
+    "cleanup_orphaned_attributes",
+    "set_payable_type_supply_check",
+    "subscribe",
+    "schedule_fee_holiday",
+    "cancel_fee_holiday",
+    "cancel_payable",
+    "transfer_payable",
+    "set_paused",
+    "patch_payable_meta",
+    "upsert_payable_type_config",
+    "add_supported_payable_type",
+    "remove_supported_payable_type",
+    "decline_payable",
+    "set_payment_volume_cap",
+    "reset_payment_volume",
+    "release_payment",
+    "reclaim_escrow",
+    "raise_dispute",
+    "resolve_dispute",
+    "reassign_oracle_bulk",
+    "prune_payable",
+    "renew_oracle_approval",
+    "set_quarantine_config",
+    "release_quarantined_payment",
+    "refund_quarantined_payment",
+    "propose_payment",
+    "approve_payment",
+    "cancel_proposed_payment",
+    "record_external_settlement",
+    "re_register_payable",
+    "reverse_payment",
+    "set_risk_bands",
+    "flag_erroneous_payment",
+    "set_payable_frozen",
+    "set_payable_type_frozen",
+    "revert_stale_approval",
+    "set_referral_config",
+    "grant_payment_allowance",
+    "revoke_payment_allowance",
+    "pull_allowance_payment",
+    "settle_netting",
+    "claim_cancellation_refund",
+    "finalize_cancellation",
+];
+
+/// Every QueryMsg variant supported by this contract, in its serialized (snake_case) form.  This
+/// must be updated whenever a variant is added to or removed from QueryMsg.
+pub const SUPPORTED_QUERY_MSGS: &[&str] = &[
+    "query_state",
+    "query_payable_by_scope_id",
+    "query_payable_by_uuid",
+    "query_effective_fees",
+    "query_payable_timeline",
+    "query_api_descriptor",
+    "query_metrics_snapshot",
+    "query_payment_history",
+    "query_fee_holidays",
+    "export_payments",
+    "query_payable_diff",
+    "query_payable_installments",
+    "query_payable_authorized_payers",
+    "query_quarantine_config",
+    "query_quarantined_payments",
+    "query_overdue_approvals",
+    "query_instantiate_template",
+    "query_payable_interest",
+    "query_risk_bands",
+    "query_payable_type_frozen",
+    "query_all_payables",
+    "query_payment_velocity",
+    "query_referrer_revenue",
+    "query_admin_overview",
+    "query_registration_quote",
+    "query_payment_allowances",
+    "simulate_payment",
+    "query_payments",
+    "query_payable",
+    "query_scope_attributes",
+    "query_escrow_totals",
+];
+
+/// The cargo feature that compiles the escrow subsystem into the contract.
+pub const ESCROW_FEATURE: &str = "escrow";
+/// Every ExecuteMsg variant that is only handled when the escrow feature is compiled in.  Builds
+/// without the feature still accept these messages, but reject them with a FeatureNotCompiled
+/// error.
+pub const ESCROW_EXECUTE_MSGS: &[&str] = &[
+    "release_payment",
+    "reclaim_escrow",
+    "reverse_payment",
+    "settle_netting",
+    "claim_cancellation_refund",
+    "finalize_cancellation",
+];
+/// Every QueryMsg variant that is only handled when the escrow feature is compiled in.
+pub const ESCROW_QUERY_MSGS: &[&str] = &["query_escrow_totals"];
+/// The cargo feature that compiles scheduled payment allowance pulls into the contract.
+pub const ALLOWANCE_FEATURE: &str = "allowance";
+/// Every ExecuteMsg variant that is only handled when the allowance feature is compiled in.
+pub const ALLOWANCE_EXECUTE_MSGS: &[&str] = &[
+    "grant_payment_allowance",
+    "revoke_payment_allowance",
+    "pull_allowance_payment",
+];
+/// Every QueryMsg variant that is only handled when the allowance feature is compiled in.
+pub const ALLOWANCE_QUERY_MSGS: &[&str] = &["query_payment_allowances"];
+
+/// Emitted when payment attribute writes are batched within a configured window.
+pub const FEATURE_PAYMENT_BATCHING: &str = "payment_batching";
+/// Emitted when state-changing migrations must be staged before they can be applied.
+pub const FEATURE_MIGRATION_TIMELOCK: &str = "migration_timelock";
+/// Emitted when oracles may send a small amount of refundable funds with their approvals.
+pub const FEATURE_ORACLE_FUNDS_TOLERANCE: &str = "oracle_funds_tolerance";
+/// Emitted when payments below a contract-wide minimum amount are rejected.
+pub const FEATURE_MINIMUM_PAYMENT: &str = "minimum_payment";
+
+/// A machine-readable description of the messages that this contract version accepts, and the
+/// optional features that are currently enabled in its configuration.  Allows clients that support
+/// multiple contract versions to adapt at runtime.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApiDescriptorV1 {
+    pub descriptor_version: u32,
+    pub contract_name: String,
+    pub contract_version: String,
+    pub execute_msgs: Vec<String>,
+    pub query_msgs: Vec<String>,
+    pub enabled_features: Vec<String>,
+}
+
+/// Builds the API descriptor from the central message registry and the contract's current state.
+pub fn get_api_descriptor(state: &StateV2) -> ApiDescriptorV1 {
+    let mut enabled_features: Vec<String> = vec![];
+    if state.payment_batch_window.is_some() {
+        enabled_features.push(FEATURE_PAYMENT_BATCHING.to_string());
+    }
+    if state.migration_timelock_seconds.is_some() {
+        enabled_features.push(FEATURE_MIGRATION_TIMELOCK.to_string());
+    }
+    if state.oracle_funds_tolerance.is_some() {
+        enabled_features.push(FEATURE_ORACLE_FUNDS_TOLERANCE.to_string());
+    }
+    if state.minimum_payment_amount.is_some() {
+        enabled_features.push(FEATURE_MINIMUM_PAYMENT.to_string());
+    }
+    ApiDescriptorV1 {
+        descriptor_version: API_DESCRIPTOR_VERSION,
+        contract_name: CONTRACT_NAME.to_string(),
+        contract_version: CONTRACT_VERSION.to_string(),
+        execute_msgs: SUPPORTED_EXECUTE_MSGS
+            .iter()
+            .filter(|msg| is_execute_msg_compiled(msg))
+            .map(|msg| msg.to_string())
+            .collect(),
+        query_msgs: SUPPORTED_QUERY_MSGS
+            .iter()
+            .filter(|msg| is_query_msg_compiled(msg))
+            .map(|msg| msg.to_string())
+            .collect(),
+        enabled_features,
+    }
+}
+
+/// Determines whether the subsystem that handles the given execute msg is compiled into this build.
+pub fn is_execute_msg_compiled(msg: &str) -> bool {
+    (cfg!(feature = "escrow") || !ESCROW_EXECUTE_MSGS.contains(&msg))
+        && (cfg!(feature = "allowance") || !ALLOWANCE_EXECUTE_MSGS.contains(&msg))
+}
+
+/// Determines whether the subsystem that handles the given query msg is compiled into this build.
+pub fn is_query_msg_compiled(msg: &str) -> bool {
+    (cfg!(feature = "escrow") || !ESCROW_QUERY_MSGS.contains(&msg))
+        && (cfg!(feature = "allowance") || !ALLOWANCE_QUERY_MSGS.contains(&msg))
+}
+
+/// Gas charged for any execution before its storage operations and messages are counted.  Covers
+/// signature verification, message validation and wasm instantiation.
+pub const GAS_HINT_BASE: u64 = 100_000;
+/// Suggested gas per storage read.
+pub const GAS_HINT_PER_STORAGE_READ: u64 = 2_000;
+/// Suggested gas per storage write or removal.
+pub const GAS_HINT_PER_STORAGE_WRITE: u64 = 10_000;
+/// Suggested gas per message dispatched by the response.
+pub const GAS_HINT_PER_MESSAGE: u64 = 30_000;
+/// Suggested gas per scope attribute written, on top of its messages, as the serialized payable is
+/// stored on chain.
+pub const GAS_HINT_PER_ATTRIBUTE_WRITE: u64 = 40_000;
+
+/// A static description of the work that an ExecuteMsg variant performs in its common path.  The
+/// counts are upper bounds for a typical payable, not exact measurements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExecuteComplexity {
+    pub execute_msg: &'static str,
+    pub storage_reads: u32,
+    pub storage_writes: u32,
+    pub messages: u32,
+    pub attribute_writes: u32,
+    pub per_batch_entry: bool,
+}
+impl ExecuteComplexity {
+    /// A default gas limit for a single execution, or a single batch entry for batched messages.
+    pub fn suggested_gas_limit(&self) -> u64 {
+        GAS_HINT_BASE
+            + GAS_HINT_PER_STORAGE_READ * self.storage_reads as u64
+            + GAS_HINT_PER_STORAGE_WRITE * self.storage_writes as u64
+            + GAS_HINT_PER_MESSAGE * self.messages as u64
+            + GAS_HINT_PER_ATTRIBUTE_WRITE * self.attribute_writes as u64
+    }
+}
+
+/// The complexity of every ExecuteMsg variant, in the same order as SUPPORTED_EXECUTE_MSGS.  This
+/// must be updated whenever a variant is added, or when a variant's storage or message usage
+/// changes significantly.
+pub const EXECUTE_COMPLEXITY: &[ExecuteComplexity] = &[
+    ExecuteComplexity {
+        execute_msg: "register_payable",
+        storage_reads: 6,
+        storage_writes: 12,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "register_payables",
+        storage_reads: 6,
+        storage_writes: 12,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: true,
+    },
+    ExecuteComplexity {
+        execute_msg: "update_payable_total",
+        storage_reads: 4,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "change_oracle",
+        storage_reads: 4,
+        storage_writes: 2,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "restructure_payable",
+        storage_reads: 5,
+        storage_writes: 4,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "write_off_payable",
+        storage_reads: 4,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "expire_payable",
+        storage_reads: 5,
+        storage_writes: 15,
+        messages: 1,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "oracle_approval",
+        storage_reads: 6,
+        storage_writes: 5,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "add_oracle_delegate",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "remove_oracle_delegate",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "make_payment",
+        storage_reads: 9,
+        storage_writes: 10,
+        messages: 4,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "make_batch_payment",
+        storage_reads: 9,
+        storage_writes: 10,
+        messages: 4,
+        attribute_writes: 1,
+        per_batch_entry: true,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_denom_display_metadata",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "remove_denom_display_metadata",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "stage_migration_params",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "cleanup_orphaned_attributes",
+        storage_reads: 2,
+        storage_writes: 0,
+        messages: 1,
+        attribute_writes: 1,
+        per_batch_entry: true,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_payable_type_supply_check",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "subscribe",
+        storage_reads: 3,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "schedule_fee_holiday",
+        storage_reads: 2,
+        storage_writes: 2,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "cancel_fee_holiday",
+        storage_reads: 2,
+        storage_writes: 2,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "cancel_payable",
+        storage_reads: 6,
+        storage_writes: 6,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "transfer_payable",
+        storage_reads: 5,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_paused",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "patch_payable_meta",
+        storage_reads: 4,
+        storage_writes: 4,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "upsert_payable_type_config",
+        storage_reads: 2,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "add_supported_payable_type",
+        storage_reads: 2,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "remove_supported_payable_type",
+        storage_reads: 2,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "decline_payable",
+        storage_reads: 4,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_payment_volume_cap",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "reset_payment_volume",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "release_payment",
+        storage_reads: 4,
+        storage_writes: 1,
+        messages: 1,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "reclaim_escrow",
+        storage_reads: 5,
+        storage_writes: 4,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "raise_dispute",
+        storage_reads: 5,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "resolve_dispute",
+        storage_reads: 4,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "reassign_oracle_bulk",
+        storage_reads: 3,
+        storage_writes: 1,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: true,
+    },
+    ExecuteComplexity {
+        execute_msg: "prune_payable",
+        storage_reads: 4,
+        storage_writes: 15,
+        messages: 1,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "renew_oracle_approval",
+        storage_reads: 4,
+        storage_writes: 3,
+        messages: 1,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_quarantine_config",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "release_quarantined_payment",
+        storage_reads: 4,
+        storage_writes: 2,
+        messages: 1,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "refund_quarantined_payment",
+        storage_reads: 4,
+        storage_writes: 4,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "propose_payment",
+        storage_reads: 4,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "approve_payment",
+        storage_reads: 10,
+        storage_writes: 11,
+        messages: 4,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "cancel_proposed_payment",
+        storage_reads: 2,
+        storage_writes: 1,
+        messages: 1,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "record_external_settlement",
+        storage_reads: 5,
+        storage_writes: 5,
+        messages: 1,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "re_register_payable",
+        storage_reads: 14,
+        storage_writes: 26,
+        messages: 6,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "reverse_payment",
+        storage_reads: 7,
+        storage_writes: 5,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_risk_bands",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "flag_erroneous_payment",
+        storage_reads: 8,
+        storage_writes: 4,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_payable_frozen",
+        storage_reads: 5,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_payable_type_frozen",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "revert_stale_approval",
+        storage_reads: 5,
+        storage_writes: 3,
+        messages: 2,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "set_referral_config",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "grant_payment_allowance",
+        storage_reads: 5,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "revoke_payment_allowance",
+        storage_reads: 1,
+        storage_writes: 1,
+        messages: 0,
+        attribute_writes: 0,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "pull_allowance_payment",
+        storage_reads: 11,
+        storage_writes: 11,
+        messages: 5,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "settle_netting",
+        storage_reads: 6,
+        storage_writes: 4,
+        messages: 2,
+        attribute_writes: 0,
+        per_batch_entry: true,
+    },
+    ExecuteComplexity {
+        execute_msg: "claim_cancellation_refund",
+        storage_reads: 6,
+        storage_writes: 4,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+    ExecuteComplexity {
+        execute_msg: "finalize_cancellation",
+        storage_reads: 6,
+        storage_writes: 18,
+        messages: 3,
+        attribute_writes: 1,
+        per_batch_entry: false,
+    },
+];
+
+/// A gas estimation hint for an ExecuteMsg variant, published in the execute msg schema so that
+/// client SDKs can set sensible default gas limits per operation.  For batched messages, the counts
+/// and suggested gas limit apply to each entry in the batch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExecuteGasHintV1 {
+    pub execute_msg: String,
+    pub storage_reads: u32,
+    pub storage_writes: u32,
+    pub messages: u32,
+    pub attribute_writes: u32,
+    pub per_batch_entry: bool,
+    pub suggested_gas_limit: u64,
+}
+
+/// Builds the gas hints for every ExecuteMsg variant from the central complexity registry.
+pub fn get_execute_gas_hints() -> Vec<ExecuteGasHintV1> {
+    EXECUTE_COMPLEXITY
+        .iter()
+        .map(|complexity| ExecuteGasHintV1 {
+            execute_msg: complexity.execute_msg.to_string(),
+            storage_reads: complexity.storage_reads,
+            storage_writes: complexity.storage_writes,
+            messages: complexity.messages,
+            attribute_writes: complexity.attribute_writes,
+            per_batch_entry: complexity.per_batch_entry,
+            suggested_gas_limit: complexity.suggested_gas_limit(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::api::{
+        get_execute_gas_hints, EXECUTE_COMPLEXITY, GAS_HINT_BASE, SUPPORTED_EXECUTE_MSGS,
+        SUPPORTED_QUERY_MSGS,
+    };
+    use crate::core::msg::{ExecuteMsg, MigrateMsg, QueryMsg};
+    use crate::core::state::PayableMetaPatchV1;
+    use crate::execute::register_payable::RegisterPayableV2;
+    use cosmwasm_std::{Decimal, Timestamp, Uint128};
+    use serde::Serialize;
+
+    #[test]
+    fn test_execute_msg_registry_matches_variants() {
+        let variants = [
+            ExecuteMsg::RegisterPayable {
+                payable_type: String::new(),
+                payable_uuid: String::new(),
+                scope_id: String::new(),
+                oracle_address: String::new(),
+                payable_denom: String::new(),
+                payable_total: Uint128::zero(),
+                custom_fields: None,
+                priority_fee: None,
+                oracle_gas_rebate: None,
+                minimum_payment_amount: None,
+                expiration_time: None,
+                accepted_denoms: None,
+                payment_schedule: None,
+                late_fee: None,
+                escrow_timeout_seconds: None,
+                authorized_payers: None,
+                interest: None,
+                payee_splits: None,
+                registration_nonce: None,
+                referrer: None,
+            },
+            ExecuteMsg::RegisterPayables { payables: vec![] },
+            ExecuteMsg::UpdatePayableTotal {
+                payable_uuid: String::new(),
+                new_total: Uint128::zero(),
+            },
+            ExecuteMsg::ChangeOracle {
+                payable_uuid: String::new(),
+                new_oracle_address: String::new(),
+            },
+            ExecuteMsg::RestructurePayable {
+                payable_uuid: String::new(),
+                new_total: Uint128::zero(),
+            },
+            ExecuteMsg::WriteOffPayable {
+                payable_uuid: String::new(),
+                write_off_reason: String::new(),
+            },
+            ExecuteMsg::ExpirePayable {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::OracleApproval {
+                payable_uuid: String::new(),
+                external_reference_id: None,
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            ExecuteMsg::AddOracleDelegate {
+                delegate: String::new(),
+            },
+            ExecuteMsg::RemoveOracleDelegate {
+                delegate: String::new(),
+            },
+            ExecuteMsg::MakePayment {
+                payable_uuid: String::new(),
+                refund_overpayment: None,
+                memo: None,
+                on_behalf_of: None,
+            },
+            ExecuteMsg::MakeBatchPayment { payments: vec![] },
+            ExecuteMsg::SetDenomDisplayMetadata {
+                denom: String::new(),
+                symbol: String::new(),
+                exponent: 0,
+            },
+            ExecuteMsg::RemoveDenomDisplayMetadata {
+                denom: String::new(),
+            },
+            ExecuteMsg::StageMigrationParams {
+                params: empty_migrate_msg(),
+            },
+            ExecuteMsg::CleanupOrphanedAttributes { scope_ids: vec![] },
+            ExecuteMsg::SetPayableTypeSupplyCheck {
+                payable_type: String::new(),
+                enabled: false,
+            },
+            ExecuteMsg::Subscribe {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::ScheduleFeeHoliday {
+                holiday_id: String::new(),
+                start_time: Timestamp::default(),
+                end_time: Timestamp::default(),
+                payable_types: vec![],
+                onboarding_cost: Uint128::zero(),
+            },
+            ExecuteMsg::CancelFeeHoliday {
+                holiday_id: String::new(),
+            },
+            ExecuteMsg::CancelPayable {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::TransferPayable {
+                payable_uuid: String::new(),
+                new_owner: String::new(),
+            },
+            ExecuteMsg::SetPaused { paused: false },
+            ExecuteMsg::PatchPayableMeta {
+                payable_uuid: String::new(),
+                patch: PayableMetaPatchV1 { scope_id: None },
+                reason: String::new(),
+            },
+            ExecuteMsg::UpsertPayableTypeConfig {
+                payable_type: String::new(),
+                onboarding_cost: Uint128::zero(),
+                onboarding_denom: String::new(),
+                fee_percent: Decimal::zero(),
+                default_oracle: None,
+                registrant_requirement: None,
+            },
+            ExecuteMsg::AddSupportedPayableType {
+                payable_type: String::new(),
+            },
+            ExecuteMsg::RemoveSupportedPayableType {
+                payable_type: String::new(),
+            },
+            ExecuteMsg::DeclinePayable {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::SetPaymentVolumeCap {
+                denom: String::new(),
+                max_volume: Uint128::zero(),
+                window_seconds: 0,
+            },
+            ExecuteMsg::ResetPaymentVolume {
+                denom: String::new(),
+            },
+            ExecuteMsg::ReleasePayment {
+                payable_uuid: String::new(),
+                payment_id: 0,
+            },
+            ExecuteMsg::ReclaimEscrow {
+                payable_uuid: String::new(),
+                payment_id: 0,
+            },
+            ExecuteMsg::RaiseDispute {
+                payable_uuid: String::new(),
+                reason: String::new(),
+            },
+            ExecuteMsg::ResolveDispute {
+                payable_uuid: String::new(),
+                resolution: String::new(),
+                adjusted_total: None,
+            },
+            ExecuteMsg::ReassignOracleBulk {
+                old_oracle_address: String::new(),
+                new_oracle_address: String::new(),
+                start_after: None,
+                limit: None,
+            },
+            ExecuteMsg::PrunePayable {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::RenewOracleApproval {
+                payable_uuid: String::new(),
+                external_reference_id: None,
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            ExecuteMsg::SetQuarantineConfig {
+                flagged_payers: vec![],
+                large_payment_thresholds: vec![],
+            },
+            ExecuteMsg::ReleaseQuarantinedPayment {
+                payable_uuid: String::new(),
+                payment_id: 0,
+            },
+            ExecuteMsg::RefundQuarantinedPayment {
+                payable_uuid: String::new(),
+                payment_id: 0,
+            },
+            ExecuteMsg::ProposePayment {
+                payable_uuid: String::new(),
+                memo: None,
+                on_behalf_of: None,
+            },
+            ExecuteMsg::ApprovePayment {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::CancelProposedPayment {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::RecordExternalSettlement {
+                payable_uuid: String::new(),
+                amount: Uint128::zero(),
+                reference: String::new(),
+            },
+            ExecuteMsg::ReRegisterPayable {
+                payable: RegisterPayableV2 {
+                    payable_type: String::new(),
+                    payable_uuid: String::new(),
+                    scope_id: String::new(),
+                    oracle_address: String::new(),
+                    payable_denom: String::new(),
+                    payable_total: Uint128::zero(),
+                    custom_fields: None,
+                    priority_fee: None,
+                    oracle_gas_rebate: None,
+                    minimum_payment_amount: None,
+                    expiration_time: None,
+                    accepted_denoms: None,
+                    payment_schedule: None,
+                    late_fee: None,
+                    escrow_timeout_seconds: None,
+                    authorized_payers: None,
+                    interest: None,
+                    payee_splits: None,
+                    registration_nonce: None,
+                    referrer: None,
+                },
+            },
+            ExecuteMsg::ReversePayment {
+                payable_uuid: String::new(),
+                payment_id: 0,
+                reason: String::new(),
+            },
+            ExecuteMsg::SetRiskBands { risk_bands: vec![] },
+            ExecuteMsg::FlagErroneousPayment {
+                payable_uuid: String::new(),
+                payment_id: 0,
+            },
+            ExecuteMsg::SetPayableFrozen {
+                payable_uuid: String::new(),
+                frozen: false,
+            },
+            ExecuteMsg::SetPayableTypeFrozen {
+                payable_type: String::new(),
+                frozen: false,
+            },
+            ExecuteMsg::RevertStaleApproval {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::SetReferralConfig {
+                fee_share_bps: 0,
+                referrers: vec![],
+            },
+            ExecuteMsg::GrantPaymentAllowance {
+                payable_uuid: String::new(),
+                denom: String::new(),
+                max_total: Uint128::zero(),
+                pull_amount: Uint128::zero(),
+                pull_interval_seconds: 0,
+                first_pull_at: None,
+                expires_at: None,
+            },
+            ExecuteMsg::RevokePaymentAllowance {
+                payable_uuid: String::new(),
+            },
+            ExecuteMsg::PullAllowancePayment {
+                payable_uuid: String::new(),
+                payer: String::new(),
+            },
+            ExecuteMsg::SettleNetting {
+                payable_uuids: vec![],
+            },
+            ExecuteMsg::ClaimCancellationRefund {
+                payable_uuid: String::new(),
+                payment_id: 0,
+            },
+            ExecuteMsg::FinalizeCancellation {
+                payable_uuid: String::new(),
+            },
+        ];
+        assert_eq!(
+            SUPPORTED_EXECUTE_MSGS.to_vec(),
+            variants.iter().map(variant_name).collect::<Vec<String>>(),
+            "the execute msg registry should list every ExecuteMsg variant",
+        );
+    }
+
+    #[test]
+    fn test_query_msg_registry_matches_variants() {
+        let variants = [
+            QueryMsg::QueryState {},
+            QueryMsg::QueryPayableByScopeId {
+                scope_id: String::new(),
+            },
+            QueryMsg::QueryPayableByUuid {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryEffectiveFees {
+                payable_type: String::new(),
+            },
+            QueryMsg::QueryPayableTimeline {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryApiDescriptor {},
+            QueryMsg::QueryMetricsSnapshot {},
+            QueryMsg::QueryPaymentHistory {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryFeeHolidays {},
+            QueryMsg::ExportPayments {
+                start_after: None,
+                limit: None,
+            },
+            QueryMsg::QueryPayableDiff {
+                payable_uuid: String::new(),
+                from_seq: 0,
+                to_seq: 0,
+            },
+            QueryMsg::QueryPayableInstallments {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryPayableAuthorizedPayers {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryQuarantineConfig {},
+            QueryMsg::QueryQuarantinedPayments {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryOverdueApprovals { limit: None },
+            QueryMsg::QueryInstantiateTemplate {},
+            QueryMsg::QueryPayableInterest {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::QueryRiskBands {},
+            QueryMsg::QueryPayableTypeFrozen {
+                payable_type: String::new(),
+            },
+            QueryMsg::QueryAllPayables {
+                start_after: None,
+                limit: None,
+            },
+            QueryMsg::QueryPaymentVelocity {
+                denom: String::new(),
+            },
+            QueryMsg::QueryReferrerRevenue {
+                referrer: String::new(),
+            },
+            QueryMsg::QueryAdminOverview {},
+            QueryMsg::QueryRegistrationQuote {
+                payable_type: String::new(),
+            },
+            QueryMsg::QueryPaymentAllowances {
+                payable_uuid: String::new(),
+            },
+            QueryMsg::SimulatePayment {
+                payable_uuid: String::new(),
+                amount: Uint128::zero(),
+                denom: String::new(),
+                payer: String::new(),
+                refund_overpayment: None,
+            },
+            QueryMsg::QueryPayments {
+                payable_uuid: None,
+                payer: None,
+                after_time: None,
+                start_after: None,
+                limit: None,
+            },
+            QueryMsg::QueryPayable {
+                identifier: String::new(),
+            },
+            QueryMsg::QueryScopeAttributes {
+                scope_id: String::new(),
+            },
+            QueryMsg::QueryEscrowTotals { payable_type: None },
+        ];
+        assert_eq!(
+            SUPPORTED_QUERY_MSGS.to_vec(),
+            variants.iter().map(variant_name).collect::<Vec<String>>(),
+            "the query msg registry should list every QueryMsg variant",
+        );
+    }
+
+    #[test]
+    fn test_execute_gas_hints_cover_every_execute_msg() {
+        assert_eq!(
+            SUPPORTED_EXECUTE_MSGS.to_vec(),
+            EXECUTE_COMPLEXITY
+                .iter()
+                .map(|complexity| complexity.execute_msg)
+                .collect::<Vec<&str>>(),
+            "every ExecuteMsg variant should have a complexity descriptor, in registry order",
+        );
+        let hints = get_execute_gas_hints();
+        let register = hints
+            .iter()
+            .find(|hint| hint.execute_msg == "register_payable")
+            .unwrap();
+        let set_paused = hints
+            .iter()
+            .find(|hint| hint.execute_msg == "set_paused")
+            .unwrap();
+        assert!(
+            register.suggested_gas_limit > set_paused.suggested_gas_limit,
+            "writing a scope attribute should be suggested more gas than a config toggle",
+        );
+        assert!(
+            hints
+                .iter()
+                .all(|hint| hint.suggested_gas_limit > GAS_HINT_BASE),
+            "every execution touches storage, so its hint should exceed the base gas",
+        );
+        assert_eq!(
+            vec![
+                "register_payables",
+                "make_batch_payment",
+                "cleanup_orphaned_attributes",
+                "reassign_oracle_bulk",
+                "settle_netting",
+            ],
+            hints
+                .iter()
+                .filter(|hint| hint.per_batch_entry)
+                .map(|hint| hint.execute_msg.as_str())
+                .collect::<Vec<&str>>(),
+            "only batched messages should be marked as scaling per batch entry",
+        );
+    }
+
+    /// Externally-tagged enum variants serialize as {"variant_name":{...}}, so the variant name is
+    /// everything between the first pair of quotes.
+    fn variant_name<T: Serialize>(msg: &T) -> String {
+        serde_json_wasm::to_string(msg)
+            .unwrap()
+            .split('"')
+            .nth(1)
+            .unwrap()
+            .to_string()
+    }
+
+    fn empty_migrate_msg() -> MigrateMsg {
+        MigrateMsg {
+            onboarding_cost: None,
+            onboarding_denom: None,
+            fee_collection_address: None,
+            fee_percent: None,
+            is_local: None,
+            payment_batch_window: None,
+            disable_payment_batching: None,
+            admin_address: None,
+            migration_timelock_seconds: None,
+            disable_migration_timelock: None,
+            oracle_funds_tolerance: None,
+            disable_oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            disable_minimum_payment: None,
+            settled_retention_seconds: None,
+            disable_settled_pruning: None,
+            approval_valid_duration: None,
+            disable_approval_expiry: None,
+            oracle_approval_sla_seconds: None,
+            disable_oracle_approval_sla: None,
+            large_payment_threshold: None,
+            disable_large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            disable_payment_reversal: None,
+            provenance_msg_version: None,
+            authorized_registrars: None,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use std::num::ParseIntError;
 use thiserror::Error;
 
@@ -13,15 +13,43 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("The contract has already been initialized")]
+    AlreadyInitialized,
+
+    #[error("The contract is paused")]
+    ContractPaused,
+
+    #[error("Payment allowance of [{payer}] for payable [{payable_uuid}] cannot be pulled until {next_pull_at}")]
+    AllowancePullNotDue {
+        payable_uuid: String,
+        payer: String,
+        next_pull_at: Timestamp,
+    },
+
+    #[error("Address [{delegate}] is not a delegate of oracle [{oracle_address}]")]
+    DelegateNotFound {
+        oracle_address: String,
+        delegate: String,
+    },
+
     #[error("Payable with uuid {payable_uuid} has already been approved")]
     DuplicateApproval { payable_uuid: String },
 
     #[error("Scope with id {scope_id} has already been registered")]
     DuplicateRegistration { scope_id: String },
 
+    #[error("Fee holiday [{holiday_id}] has not been scheduled")]
+    FeeHolidayNotFound { holiday_id: String },
+
+    #[error("Feature [{feature}] is not compiled into this contract")]
+    FeatureNotCompiled { feature: String },
+
     #[error("Funds were provided for an operation that does not require them")]
     FundsPresent,
 
+    #[error("Funds provided [{provided}] do not match the expected funds [{expected}]")]
+    FundsMismatch { expected: String, provided: String },
+
     #[error("Insufficient funds provided. Required {amount_needed} but got {amount_provided}")]
     InsufficientFundsProvided {
         amount_needed: u128,
@@ -78,12 +106,81 @@ pub enum ContractError {
     #[error("Unable to locate target payable {payable_uuid}")]
     PayableNotFound { payable_uuid: String },
 
+    #[error("Payable with uuid [{payable_uuid}] expired at {expiration_time}")]
+    PayableExpired {
+        payable_uuid: String,
+        expiration_time: Timestamp,
+    },
+
+    #[error("Payable total [{payable_total}] exceeds the total supply [{total_supply}] of marker denom [{denom}]")]
+    PayableTotalExceedsSupply {
+        payable_total: u128,
+        total_supply: u128,
+        denom: String,
+    },
+
+    #[error(
+        "Address [{payer}] is not authorized to make payments against payable [{payable_uuid}]"
+    )]
+    PayerNotAuthorized { payable_uuid: String, payer: String },
+
+    #[error("Address [{referrer}] is not an allowlisted referrer")]
+    ReferrerNotAllowed { referrer: String },
+
+    #[error(
+        "Address [{sender}] failed the [{requirement}] registrant check for scope [{scope_id}]"
+    )]
+    RegistrantRequirementNotMet {
+        scope_id: String,
+        sender: String,
+        requirement: String,
+    },
+
+    #[error("Registration nonce [{nonce}] was already used for a different registration of payable [{payable_uuid}]")]
+    RegistrationNonceReused { payable_uuid: String, nonce: String },
+
     #[error("Payment too large. Total owed [{total_owed}], amount provided [{amount_provided}]")]
     PaymentTooLarge {
         total_owed: u128,
         amount_provided: u128,
     },
 
+    #[error("Refusing to overwrite attributes on scope [{scope_id}] for payable [{payable_uuid}]: {mismatch_reason}")]
+    ScopeAttributeMismatch {
+        scope_id: String,
+        payable_uuid: String,
+        mismatch_reason: String,
+    },
+
+    #[error(
+        "Payment volume cap for denom [{denom}] has been exceeded. Payments resume at {window_end}"
+    )]
+    PaymentVolumeCapExceeded {
+        denom: String,
+        window_end: Timestamp,
+    },
+
+    #[error("Payment too small. Minimum payment [{minimum_payment}], amount provided [{amount_provided}]")]
+    PaymentTooSmall {
+        minimum_payment: u128,
+        amount_provided: u128,
+    },
+
+    #[error("Payments over [{large_payment_threshold}] require oracle approval, amount provided [{amount_provided}]")]
+    PaymentRequiresCoSign {
+        large_payment_threshold: u128,
+        amount_provided: u128,
+    },
+
+    #[error("Denom mismatch. Expected [{expected_denom}], provided [{provided_denom}]")]
+    DenomMismatch {
+        expected_denom: String,
+        provided_denom: String,
+    },
+
+    #[error("Payable type [{payable_type}] is not supported")]
+    UnsupportedPayableType { payable_type: String },
+
     #[error("Semver parsing error: {0}")]
     SemVer(String),
 }
@@ -109,6 +206,14 @@ impl ContractError {
                 .collect(),
         }
     }
+    /// The name of the error's variant, ex: PaymentTooLarge, for clients that branch on the kind of
+    /// error rather than its message
+    pub fn kind(&self) -> String {
+        format!("{:?}", self)
+            .chars()
+            .take_while(|c| c.is_alphanumeric())
+            .collect()
+    }
 }
 impl From<semver::Error> for ContractError {
     /// Enables SemVer issues to cast convert implicitly to contract error
@@ -0,0 +1,293 @@
+//! Every storage namespace used by the contract, alongside helpers that produce the exact bytes
+//! that values are stored under.  Migrations that need to iterate or transform stored values should
+//! derive their keys here rather than re-deriving the encodings of the storage libraries.
+//!
+//! Two encodings are in use:
+//! - cosmwasm-storage singletons and buckets, used by the contract's oldest state, length-prefix
+//!   their namespace and append the bucket key verbatim.
+//! - cw-storage-plus Items are stored under their namespace verbatim.  Maps length-prefix their
+//!   namespace and every key segment but the last, which is appended verbatim.  Numeric segments
+//!   are encoded as big-endian bytes so that they iterate in order.
+
+///////////////////////////
+// cosmwasm-storage keys //
+///////////////////////////
+/// Layout: singleton_key(CONFIG_KEY_V2)
+pub static CONFIG_KEY_V2: &[u8] = b"config_v2";
+/// Layout: bucket_key(PAYABLE_META_V2_KEY, payable_uuid)
+pub static PAYABLE_META_V2_KEY: &[u8] = b"payable_meta_v2";
+
+///////////////////////////////////
+// Contract-wide Item namespaces //
+///////////////////////////////////
+// Layout: item_key(namespace)
+pub const QUARANTINE_CONFIG_NAMESPACE: &str = "quarantine_config_v1";
+pub const RISK_BANDS_NAMESPACE: &str = "risk_bands_v1";
+pub const REFERRAL_CONFIG_NAMESPACE: &str = "referral_config_v1";
+pub const STAGED_MIGRATION_NAMESPACE: &str = "staged_migration_v1";
+pub const VERSION_INFO_NAMESPACE: &str = "version_info";
+
+////////////////////////////
+// Payable Map namespaces //
+////////////////////////////
+// Layout: map_key(namespace, payable_uuid)
+pub const PAYMENT_BATCH_NAMESPACE: &str = "payment_batch_v1";
+pub const PAYABLE_CUSTOM_FIELDS_NAMESPACE: &str = "payable_custom_fields_v1";
+pub const PAYABLE_PRIORITY_FEE_NAMESPACE: &str = "payable_priority_fee_v1";
+pub const ORACLE_GAS_REBATE_NAMESPACE: &str = "oracle_gas_rebate_v1";
+pub const PAYABLE_MINIMUM_PAYMENT_NAMESPACE: &str = "payable_minimum_payment_v1";
+pub const RESTRUCTURE_PROPOSAL_NAMESPACE: &str = "restructure_proposal_v1";
+pub const ORACLE_APPROVAL_CONTEXT_NAMESPACE: &str = "oracle_approval_context_v1";
+pub const PAYABLE_TIMELINE_NAMESPACE: &str = "payable_timeline_v1";
+pub const PAYABLE_SUBSCRIBER_COUNTS_NAMESPACE: &str = "payable_subscriber_counts_v1";
+pub const HELD_ORACLE_FEE_NAMESPACE: &str = "held_oracle_fee_v1";
+pub const PAYABLE_META_PATCH_PROPOSAL_NAMESPACE: &str = "payable_meta_patch_proposal_v1";
+pub const PAYABLE_ESCROW_TIMEOUT_NAMESPACE: &str = "payable_escrow_timeout_v1";
+pub const PAYABLE_AUTHORIZED_PAYERS_NAMESPACE: &str = "payable_authorized_payers_v1";
+pub const PAYABLE_PAYEE_SPLITS_NAMESPACE: &str = "payable_payee_splits_v1";
+pub const REGISTRATION_NONCE_NAMESPACE: &str = "registration_nonce_v1";
+pub const PROPOSED_PAYMENT_NAMESPACE: &str = "proposed_payment_v1";
+pub const PENDING_CANCELLATION_NAMESPACE: &str = "pending_cancellation_v1";
+
+////////////////////////////////
+// Payable history namespaces //
+////////////////////////////////
+// Layout: sequence_key(namespace, payable_uuid, sequence)
+pub const PAYMENT_HISTORY_NAMESPACE: &str = "payment_history_v1";
+pub const PAYABLE_SNAPSHOTS_NAMESPACE: &str = "payable_snapshots_v1";
+pub const PAYABLE_META_PATCH_AUDIT_NAMESPACE: &str = "payable_meta_patch_audit_v1";
+pub const ESCROWED_PAYMENT_NAMESPACE: &str = "escrowed_payment_v1";
+pub const PAYMENT_REVERSAL_NAMESPACE: &str = "payment_reversal_v1";
+pub const QUARANTINED_PAYMENT_NAMESPACE: &str = "quarantined_payment_v1";
+
+//////////////////////////
+// Denom Map namespaces //
+//////////////////////////
+// Layout: map_key(namespace, denom)
+pub const DENOM_DISPLAY_METADATA_NAMESPACE: &str = "denom_display_metadata_v1";
+pub const PAYMENT_VOLUME_CAP_NAMESPACE: &str = "payment_volume_cap_v1";
+pub const PAYMENT_VOLUME_WINDOW_NAMESPACE: &str = "payment_volume_window_v1";
+
+/////////////////////////////////
+// Payable type Map namespaces //
+/////////////////////////////////
+// Layout: map_key(namespace, payable_type)
+pub const SUPPLY_CHECKED_PAYABLE_TYPES_NAMESPACE: &str = "supply_checked_payable_types_v1";
+pub const FROZEN_PAYABLE_TYPES_NAMESPACE: &str = "frozen_payable_types_v1";
+pub const PAYABLE_TYPE_CONFIG_NAMESPACE: &str = "payable_type_config_v1";
+pub const SUPPORTED_PAYABLE_TYPES_NAMESPACE: &str = "supported_payable_types_v1";
+
+//////////////////////////
+// Other Map namespaces //
+//////////////////////////
+/// Layout: map_key(FEE_HOLIDAYS_NAMESPACE, holiday_id)
+pub const FEE_HOLIDAYS_NAMESPACE: &str = "fee_holidays_v1";
+/// Layout: pair_key(ORACLE_DELEGATES_NAMESPACE, oracle, delegate)
+pub const ORACLE_DELEGATES_NAMESPACE: &str = "oracle_delegates_v1";
+/// Layout: pair_key(PAYABLE_SUBSCRIBERS_NAMESPACE, payable_uuid, subscriber)
+pub const PAYABLE_SUBSCRIBERS_NAMESPACE: &str = "payable_subscribers_v1";
+/// Layout: pair_key(PAYMENT_ALLOWANCE_NAMESPACE, payable_uuid, payer)
+pub const PAYMENT_ALLOWANCE_NAMESPACE: &str = "payment_allowance_v1";
+/// Layout: pair_key(REFERRER_REVENUE_NAMESPACE, referrer, denom)
+pub const REFERRER_REVENUE_NAMESPACE: &str = "referrer_revenue_v1";
+/// Layout: pair_key(ESCROW_TOTALS_NAMESPACE, payable_type, denom)
+pub const ESCROW_TOTALS_NAMESPACE: &str = "escrow_totals_v1";
+/// Layout: sequence_key(PAYMENT_VELOCITY_NAMESPACE, denom, bucket_start_seconds)
+pub const PAYMENT_VELOCITY_NAMESPACE: &str = "payment_velocity_v1";
+
+/// Prefixes a key segment with its length as two big-endian bytes.  Segments longer than u16::MAX
+/// bytes cannot be encoded by either storage library, and are never produced by the contract.
+pub fn length_prefixed(segment: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(segment.len() + 2);
+    key.extend_from_slice(&(segment.len() as u16).to_be_bytes());
+    key.extend_from_slice(segment);
+    key
+}
+
+/// The key of a cosmwasm-storage singleton, such as the contract config.
+pub fn singleton_key(namespace: &[u8]) -> Vec<u8> {
+    length_prefixed(namespace)
+}
+
+/// The key of a value in a cosmwasm-storage bucket, such as the meta of a payable.
+pub fn bucket_key(namespace: &[u8], key: &[u8]) -> Vec<u8> {
+    [length_prefixed(namespace), key.to_vec()].concat()
+}
+
+/// The key of a cw-storage-plus Item, which is its namespace verbatim.
+pub fn item_key(namespace: &str) -> Vec<u8> {
+    namespace.as_bytes().to_vec()
+}
+
+/// The key of a value in a cw-storage-plus Map keyed on a single string.
+pub fn map_key(namespace: &str, key: &str) -> Vec<u8> {
+    [
+        length_prefixed(namespace.as_bytes()),
+        key.as_bytes().to_vec(),
+    ]
+    .concat()
+}
+
+/// The prefix shared by every value in a cw-storage-plus Map with a composite key whose first
+/// segment is the given key, ex: every payment in the history of a single payable.
+pub fn map_prefix(namespace: &str, first: &str) -> Vec<u8> {
+    [
+        length_prefixed(namespace.as_bytes()),
+        length_prefixed(first.as_bytes()),
+    ]
+    .concat()
+}
+
+/// The key of a value in a cw-storage-plus Map keyed on a pair of strings.
+pub fn pair_key(namespace: &str, first: &str, second: &str) -> Vec<u8> {
+    [map_prefix(namespace, first), second.as_bytes().to_vec()].concat()
+}
+
+/// The key of a value in a cw-storage-plus Map keyed on a string and a sequence number, such as a
+/// payment in a payable's history.
+pub fn sequence_key(namespace: &str, first: &str, sequence: u64) -> Vec<u8> {
+    [
+        map_prefix(namespace, first),
+        sequence.to_be_bytes().to_vec(),
+    ]
+    .concat()
+}
+
+/// The key of the contract config.
+pub fn config_key() -> Vec<u8> {
+    singleton_key(CONFIG_KEY_V2)
+}
+
+/// The key of the meta that locates a payable's scope.
+pub fn payable_meta_key(payable_uuid: &str) -> Vec<u8> {
+    bucket_key(PAYABLE_META_V2_KEY, payable_uuid.as_bytes())
+}
+
+/// The key of a payment in a payable's history.
+pub fn payment_history_key(payable_uuid: &str, payment_id: u64) -> Vec<u8> {
+    sequence_key(PAYMENT_HISTORY_NAMESPACE, payable_uuid, payment_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::keys::{
+        config_key, item_key, length_prefixed, map_key, map_prefix, pair_key, payable_meta_key,
+        payment_history_key, sequence_key, FROZEN_PAYABLE_TYPES_NAMESPACE,
+        ORACLE_DELEGATES_NAMESPACE, PAYMENT_HISTORY_NAMESPACE, QUARANTINE_CONFIG_NAMESPACE,
+    };
+    use crate::core::state::{
+        add_oracle_delegate, append_payment_record, payable_meta_storage_v2,
+        save_payable_type_frozen, save_quarantine_config, PayableMetaV2, PaymentRecordV1,
+        QuarantineConfigV1,
+    };
+    use crate::testutil::test_utilities::{
+        test_instantiate, InstArgs, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Storage, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_key_byte_layouts() {
+        assert_eq!(b"\x00\x03abc".to_vec(), length_prefixed(b"abc"));
+        assert_eq!(b"\x00\x09config_v2".to_vec(), config_key());
+        assert_eq!(
+            b"\x00\x0fpayable_meta_v2uuid".to_vec(),
+            payable_meta_key("uuid"),
+        );
+        assert_eq!(b"risk_bands_v1".to_vec(), item_key("risk_bands_v1"));
+        assert_eq!(b"\x00\x02nsdenom".to_vec(), map_key("ns", "denom"));
+        assert_eq!(b"\x00\x02ns\x00\x04uuid".to_vec(), map_prefix("ns", "uuid"));
+        assert_eq!(
+            b"\x00\x02ns\x00\x06oraclealice".to_vec(),
+            pair_key("ns", "oracle", "alice"),
+        );
+        assert_eq!(
+            b"\x00\x02ns\x00\x04uuid\x00\x00\x00\x00\x00\x00\x01\x02".to_vec(),
+            sequence_key("ns", "uuid", 258),
+        );
+        assert_eq!(
+            sequence_key(PAYMENT_HISTORY_NAMESPACE, "uuid", 7),
+            payment_history_key("uuid", 7),
+        );
+    }
+
+    #[test]
+    fn test_keys_match_stored_values() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let storage = &mut deps.storage;
+        assert_stored(storage, config_key(), "config");
+        payable_meta_storage_v2(storage)
+            .save(
+                DEFAULT_PAYABLE_UUID.as_bytes(),
+                &PayableMetaV2 {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                    scope_id: DEFAULT_SCOPE_ID.to_string(),
+                },
+            )
+            .unwrap();
+        assert_stored(
+            storage,
+            payable_meta_key(DEFAULT_PAYABLE_UUID),
+            "payable meta",
+        );
+        save_quarantine_config(
+            storage,
+            &QuarantineConfigV1 {
+                flagged_payers: vec![],
+                large_payment_thresholds: vec![],
+            },
+        )
+        .unwrap();
+        assert_stored(
+            storage,
+            item_key(QUARANTINE_CONFIG_NAMESPACE),
+            "quarantine config",
+        );
+        save_payable_type_frozen(storage, DEFAULT_PAYABLE_TYPE, true).unwrap();
+        assert_stored(
+            storage,
+            map_key(FROZEN_PAYABLE_TYPES_NAMESPACE, DEFAULT_PAYABLE_TYPE),
+            "frozen payable type",
+        );
+        add_oracle_delegate(
+            storage,
+            &Addr::unchecked("oracle"),
+            &Addr::unchecked("alice"),
+        )
+        .unwrap();
+        assert_stored(
+            storage,
+            pair_key(ORACLE_DELEGATES_NAMESPACE, "oracle", "alice"),
+            "oracle delegate",
+        );
+        let record = PaymentRecordV1 {
+            payer: Addr::unchecked("payer"),
+            amount: Uint128::new(100),
+            denom: "nhash".to_string(),
+            paid_at: mock_env().block.time,
+            on_behalf_of: None,
+            external_reference: None,
+            payee_splits: None,
+            late_fees_paid: Uint128::zero(),
+            interest_paid: Uint128::zero(),
+            netting: None,
+        };
+        append_payment_record(storage, DEFAULT_PAYABLE_UUID, &record).unwrap();
+        let payment_id = append_payment_record(storage, DEFAULT_PAYABLE_UUID, &record).unwrap();
+        assert_stored(
+            storage,
+            payment_history_key(DEFAULT_PAYABLE_UUID, payment_id),
+            "payment history",
+        );
+    }
+
+    fn assert_stored(storage: &dyn Storage, key: Vec<u8>, description: &str) {
+        assert!(
+            storage.get(&key).is_some(),
+            "the {} should be stored under its derived key",
+            description,
+        );
+    }
+}
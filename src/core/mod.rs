@@ -1,3 +1,7 @@
+pub mod api;
 pub mod error;
+pub mod keys;
+pub mod money;
 pub mod msg;
+pub mod payee_resolver;
 pub mod state;
@@ -0,0 +1,247 @@
+use crate::core::error::ContractError;
+use cosmwasm_std::{BankMsg, Coin, CosmosMsg, StdError, Uint128};
+use provwasm_std::ProvenanceMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An amount of a single denom.  All arithmetic is checked and refuses to combine amounts of
+/// different denoms, so fee, payment, and refund math can never silently mix coin types.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Money {
+    pub amount: Uint128,
+    pub denom: String,
+}
+impl Money {
+    pub fn new(amount: impl Into<Uint128>, denom: impl Into<String>) -> Money {
+        Money {
+            amount: amount.into(),
+            denom: denom.into(),
+        }
+    }
+
+    pub fn zero(denom: impl Into<String>) -> Money {
+        Money::new(Uint128::zero(), denom)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, ContractError> {
+        self.check_same_denom(other)?;
+        Ok(Money::new(
+            self.amount
+                .checked_add(other.amount)
+                .map_err(StdError::from)?,
+            &self.denom,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, ContractError> {
+        self.check_same_denom(other)?;
+        Ok(Money::new(
+            self.amount
+                .checked_sub(other.amount)
+                .map_err(StdError::from)?,
+            &self.denom,
+        ))
+    }
+
+    /// Scales the amount by a whole number factor, such as the number of payables in a batch.
+    pub fn checked_mul(&self, factor: u128) -> Result<Money, ContractError> {
+        Ok(Money::new(
+            self.amount
+                .checked_mul(Uint128::new(factor))
+                .map_err(StdError::from)?,
+            &self.denom,
+        ))
+    }
+
+    /// Divides the amount into one portion per weight, each proportional to its weight's share of
+    /// the total weight and rounded down.  The indivisible remainder is added to the first portion,
+    /// so that the portions always sum to the full amount.
+    pub fn split_by_weights(&self, weights: &[u64]) -> Result<Vec<Money>, ContractError> {
+        let total_weight: u128 = weights.iter().map(|weight| *weight as u128).sum();
+        if total_weight == 0 {
+            return ContractError::std_err("cannot split an amount without any weight").to_result();
+        }
+        let mut portions = weights
+            .iter()
+            .map(|weight| {
+                Money::new(
+                    self.amount.multiply_ratio(*weight, total_weight),
+                    &self.denom,
+                )
+            })
+            .collect::<Vec<Money>>();
+        // Rounding down leaves less than one unit per portion undistributed
+        let distributed: Uint128 = portions.iter().map(|portion| portion.amount).sum();
+        portions[0].amount += self.amount - distributed;
+        Ok(portions)
+    }
+
+    /// Sums a set of coins into a single amount of this money's denom, failing if any coin is of
+    /// a different denom.
+    pub fn checked_add_coins(&self, coins: &[Coin]) -> Result<Money, ContractError> {
+        coins
+            .iter()
+            .try_fold(self.clone(), |total, coin| total.checked_add(&coin.into()))
+    }
+
+    pub fn to_coin(&self) -> Coin {
+        Coin::new(self.amount.u128(), &self.denom)
+    }
+
+    /// Creates a bank transfer of this amount to the given address.
+    pub fn send_to(&self, to_address: impl Into<String>) -> CosmosMsg<ProvenanceMsg> {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: to_address.into(),
+            amount: vec![self.to_coin()],
+        })
+    }
+
+    fn check_same_denom(&self, other: &Money) -> Result<(), ContractError> {
+        if self.denom != other.denom {
+            return ContractError::DenomMismatch {
+                expected_denom: self.denom.clone(),
+                provided_denom: other.denom.clone(),
+            }
+            .to_result();
+        }
+        Ok(())
+    }
+}
+impl From<&Coin> for Money {
+    fn from(coin: &Coin) -> Self {
+        Money::new(coin.amount, &coin.denom)
+    }
+}
+impl From<Coin> for Money {
+    fn from(coin: Coin) -> Self {
+        Money::new(coin.amount, coin.denom)
+    }
+}
+impl fmt::Display for Money {
+    /// Formats the money as "amount/denom", the format used by all denominated event attributes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.amount, self.denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::money::Money;
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, StdError};
+
+    #[test]
+    fn test_money_arithmetic() {
+        let money = Money::new(100u128, "nhash");
+        assert_eq!(
+            Money::new(150u128, "nhash"),
+            money.checked_add(&Money::new(50u128, "nhash")).unwrap(),
+            "amounts of the same denom should be added",
+        );
+        assert_eq!(
+            Money::new(25u128, "nhash"),
+            money.checked_sub(&Money::new(75u128, "nhash")).unwrap(),
+            "amounts of the same denom should be subtracted",
+        );
+        assert_eq!(
+            Money::new(300u128, "nhash"),
+            money.checked_mul(3).unwrap(),
+            "the amount should be scaled by the factor",
+        );
+        assert_eq!(
+            Money::new(106u128, "nhash"),
+            money
+                .checked_add_coins(&[coin(5, "nhash"), coin(1, "nhash")])
+                .unwrap(),
+            "coins of the same denom should be summed",
+        );
+        assert_eq!(
+            "100/nhash",
+            money.to_string(),
+            "money should display as amount/denom"
+        );
+    }
+
+    #[test]
+    fn test_money_split_by_weights() {
+        let amounts = |money: Money, weights: &[u64]| {
+            money
+                .split_by_weights(weights)
+                .unwrap()
+                .into_iter()
+                .map(|portion| portion.amount.u128())
+                .collect::<Vec<u128>>()
+        };
+        assert_eq!(
+            vec![50, 30, 20],
+            amounts(Money::new(100u128, "nhash"), &[5, 3, 2]),
+            "evenly divisible amounts should be split exactly by weight",
+        );
+        assert_eq!(
+            vec![34, 33, 33],
+            amounts(Money::new(100u128, "nhash"), &[1, 1, 1]),
+            "the remainder should be added to the first portion",
+        );
+        assert_eq!(
+            vec![1, 0],
+            amounts(Money::new(1u128, "nhash"), &[1, 1]),
+            "an indivisible amount should go to the first portion",
+        );
+        assert!(
+            Money::new(100u128, "nhash").split_by_weights(&[0]).is_err(),
+            "an amount cannot be split without any weight",
+        );
+    }
+
+    #[test]
+    fn test_money_rejects_mixed_denoms() {
+        let money = Money::new(100u128, "nhash");
+        for result in [
+            money.checked_add(&Money::new(1u128, "otherdenom")),
+            money.checked_sub(&Money::new(1u128, "otherdenom")),
+            money.checked_add_coins(&[coin(1, "nhash"), coin(1, "otherdenom")]),
+        ] {
+            let error = result.unwrap_err();
+            assert!(
+                matches!(error, ContractError::DenomMismatch { .. }),
+                "arithmetic across denoms should be rejected, but got: {:?}",
+                error,
+            );
+        }
+    }
+
+    #[test]
+    fn test_money_rejects_overflow_and_underflow() {
+        let error = Money::new(1u128, "nhash")
+            .checked_sub(&Money::new(2u128, "nhash"))
+            .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(StdError::Overflow { .. })),
+            "subtracting below zero should be rejected, but got: {:?}",
+            error,
+        );
+        let error = Money::new(u128::MAX, "nhash").checked_mul(2).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(StdError::Overflow { .. })),
+            "overflowing the amount should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_money_send_to() {
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "receiver".to_string(),
+                amount: vec![coin(10, "nhash")],
+            }),
+            Money::new(10u128, "nhash").send_to("receiver"),
+            "a transfer of the amount and denom should be created",
+        );
+    }
+}
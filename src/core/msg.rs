@@ -1,15 +1,104 @@
 use crate::core::error::ContractError;
-use cosmwasm_std::{CustomQuery, Decimal, Deps, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, CustomQuery, Decimal, Deps, StdResult, Timestamp, Uint128};
+use provwasm_std::{AttributeValueType, PartyType};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::core::state::StateV2;
+use crate::core::state::{
+    DenomDisplayMetadataV1, EscrowTotalV1, FeeHolidayV1, FundsToleranceV1, InterestAccrualV1,
+    InterestTermsV1, LateFeeAccrualV1, LateFeeV1, OracleApprovalContextV1, PayableDisputeV1,
+    PayableMetaPatchV1, PayableScopeAttribute, PayableTypeConfigV1, PayeeSplitV1,
+    PaymentAllowanceV1, PaymentBatchWindowV1, PaymentInstallmentV1, PaymentRecordV1,
+    PaymentVolumeCapV1, PaymentVolumeWindowV1, QuarantineConfigV1, QuarantinedPaymentV1,
+    ReferralConfigV1, ReferrerRevenueV1, RegistrantRequirementV1, RiskBandV1, StateV2,
+};
+use crate::execute::cancel_payable::CancelPayableV1;
+use crate::execute::change_oracle::ChangeOracleV1;
+use crate::execute::cleanup_orphaned_attributes::CleanupOrphanedAttributesV1;
+use crate::execute::decline_payable::DeclinePayableV1;
+use crate::execute::denom_display_metadata::{
+    RemoveDenomDisplayMetadataV1, SetDenomDisplayMetadataV1,
+};
+#[cfg(feature = "escrow")]
+use crate::execute::escrow_payment::{ReclaimEscrowV1, ReleasePaymentV1, ReversePaymentV1};
+use crate::execute::expire_payable::ExpirePayableV1;
+use crate::execute::fee_holiday::{CancelFeeHolidayV1, ScheduleFeeHolidayV1};
+use crate::execute::flag_erroneous_payment::FlagErroneousPaymentV1;
+use crate::execute::make_batch_payment::{BatchPaymentV1, MakeBatchPaymentV1};
 use crate::execute::make_payment::MakePaymentV1;
-use crate::execute::oracle_approval::OracleApprovalV1;
+use crate::execute::oracle_approval::{OracleApprovalV1, RenewOracleApprovalV1};
+use crate::execute::oracle_delegate::{AddOracleDelegateV1, RemoveOracleDelegateV1};
+use crate::execute::patch_payable_meta::PatchPayableMetaV1;
+use crate::execute::payable_dispute::{RaiseDisputeV1, ResolveDisputeV1};
+use crate::execute::payable_freeze::SetPayableFrozenV1;
+use crate::execute::payable_type_config::UpsertPayableTypeConfigV1;
+use crate::execute::payable_type_freeze::SetPayableTypeFrozenV1;
+use crate::execute::payable_type_supply_check::SetPayableTypeSupplyCheckV1;
+#[cfg(feature = "allowance")]
+use crate::execute::payment_allowance::{
+    GrantPaymentAllowanceV1, PullAllowancePaymentV1, RevokePaymentAllowanceV1,
+};
+use crate::execute::payment_volume_cap::{ResetPaymentVolumeV1, SetPaymentVolumeCapV1};
+#[cfg(feature = "escrow")]
+use crate::execute::pending_cancellation::{ClaimCancellationRefundV1, FinalizeCancellationV1};
+use crate::execute::propose_payment::{
+    ApprovePaymentV1, CancelProposedPaymentV1, ProposePaymentV1,
+};
+use crate::execute::prune_payable::PrunePayableV1;
+use crate::execute::quarantine::{
+    RefundQuarantinedPaymentV1, ReleaseQuarantinedPaymentV1, SetQuarantineConfigV1,
+};
+use crate::execute::re_register_payable::ReRegisterPayableV1;
+use crate::execute::reassign_oracle_bulk::ReassignOracleBulkV1;
+use crate::execute::record_external_settlement::RecordExternalSettlementV1;
+use crate::execute::referral::SetReferralConfigV1;
 use crate::execute::register_payable::RegisterPayableV2;
+use crate::execute::register_payables::RegisterPayablesV1;
+use crate::execute::restructure_payable::RestructurePayableV1;
+use crate::execute::revert_stale_approval::RevertStaleApprovalV1;
+use crate::execute::risk_bands::SetRiskBandsV1;
+use crate::execute::set_paused::SetPausedV1;
+#[cfg(feature = "escrow")]
+use crate::execute::settle_netting::SettleNettingV1;
+use crate::execute::stage_migration_params::StageMigrationParamsV1;
+use crate::execute::subscribe::SubscribeV1;
+use crate::execute::supported_payable_type::{
+    AddSupportedPayableTypeV1, RemoveSupportedPayableTypeV1,
+};
+use crate::execute::transfer_payable::TransferPayableV1;
+use crate::execute::update_payable_total::UpdatePayableTotalV1;
+use crate::execute::write_off_payable::WriteOffPayableV1;
 use crate::migrate::migrate_contract::MigrateContractV2;
+use crate::migrate::staged_migration::StagedMigrationV1;
 use crate::util::conversions::to_uint128;
-use crate::util::traits::ValidatedMsg;
+use crate::util::interest::InterestCompounding;
+use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+use crate::validation::execute::{
+    invalid_batch_payment_fields, invalid_cleanup_orphaned_attributes_fields,
+    invalid_denom_display_metadata_fields, invalid_external_settlement_fields,
+    invalid_fee_holiday_fields, invalid_oracle_approval_fields, invalid_patch_payable_meta_fields,
+    invalid_payable_text_fields, invalid_payable_total_fields, invalid_payable_type_config_fields,
+    invalid_payment_allowance_fields, invalid_payment_fields, invalid_payment_volume_cap_fields,
+    invalid_quarantine_config_fields, invalid_reassign_oracle_bulk_fields,
+    invalid_referral_config_fields, invalid_resolve_dispute_fields, invalid_risk_bands_fields,
+    invalid_settle_netting_fields, BatchPaymentFields, PayableTypeConfigFields,
+    PaymentAllowanceFields, RiskBandFields, ThresholdFields,
+};
+use crate::validation::init::{
+    invalid_init_fields, invalid_migrate_fields, InitFields, MigrateFields,
+};
+use crate::validation::query::{
+    invalid_limit_fields, invalid_page_fields, invalid_payable_diff_fields,
+    invalid_payable_identifier_fields, invalid_query_payments_fields, invalid_scope_id_fields,
+    invalid_simulate_payment_fields, MAX_ALL_PAYABLES_LIMIT, MAX_EXPORT_PAYMENTS_LIMIT,
+    MAX_OVERDUE_APPROVALS_LIMIT,
+};
+use crate::validation::register::{
+    invalid_register_payable_fields, invalid_register_payables_fields, RegisterPayableFields,
+};
+use crate::validation::{
+    invalid_denom_fields, invalid_empty_fields, invalid_payable_uuid_fields, FieldValidation,
+};
 
 /// A message sent to initialize the contract state.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -26,30 +115,53 @@ pub struct InitMsg {
     pub fee_percent: Decimal,
     // Whether or not this contract should have assistance for local environments
     pub is_local: Option<bool>,
+    // Defers scope attribute rewrites on payment until the window's thresholds are crossed
+    pub payment_batch_window: Option<PaymentBatchWindowV1>,
+    // Requires state-changing migrations to be staged for this many seconds before being applied
+    pub migration_timelock_seconds: Option<u64>,
+    // Allows oracle approvals to carry small amounts of a single denom, which are refunded
+    pub oracle_funds_tolerance: Option<FundsToleranceV1>,
+    // Rejects payments below this amount, unless they settle the payable's remaining balance
+    pub minimum_payment_amount: Option<Uint128>,
+    // Allows settled payables to be pruned once this many seconds have passed since they were paid
+    pub settled_retention_seconds: Option<u64>,
+    // Lapses oracle approvals this many seconds after they are made, until the oracle renews them
+    pub approval_valid_duration: Option<u64>,
+    // Reports payables awaiting oracle approval for longer than this many seconds as overdue
+    pub oracle_approval_sla_seconds: Option<u64>,
+    // Requires oracle co-signing for payments larger than this amount
+    pub large_payment_threshold: Option<Uint128>,
+    // Allows the admin to reverse escrowed payments for this many seconds after they are made
+    pub payment_reversal_window_seconds: Option<u64>,
+    // The Provenance message format supported by the target chain.  Defaults to v1 if omitted
+    pub provenance_msg_version: Option<ProvenanceMsgVersion>,
+    // Allows these addresses to register payables for scopes that they do not own
+    pub authorized_registrars: Option<Vec<String>>,
 }
-impl ValidatedMsg for InitMsg {
-    fn validate(&self) -> Result<(), ContractError> {
-        let mut invalid_fields: Vec<&str> = vec![];
-        if self.contract_name.is_empty() {
-            invalid_fields.push("contract_name");
-        }
-        if self.onboarding_cost.parse::<u128>().is_err() {
-            invalid_fields.push("onboarding_cost");
-        }
-        if self.onboarding_denom.is_empty() {
-            invalid_fields.push("onboarding_denom");
-        }
-        if self.fee_collection_address.is_empty() {
-            invalid_fields.push("fee_collection_address");
-        }
-        if self.fee_percent > Decimal::one() {
-            invalid_fields.push("fee_percent");
-        }
-        if !invalid_fields.is_empty() {
-            ContractError::invalid_fields(invalid_fields).to_result()
-        } else {
-            Ok(())
-        }
+impl FieldValidation for InitMsg {
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        invalid_init_fields(&InitFields {
+            contract_name: &self.contract_name,
+            onboarding_cost: &self.onboarding_cost,
+            onboarding_denom: &self.onboarding_denom,
+            fee_collection_address: &self.fee_collection_address,
+            fee_percent: self.fee_percent.atomics().u128(),
+            payment_batch_window: self
+                .payment_batch_window
+                .as_ref()
+                .map(|window| window.fields()),
+            migration_timelock_seconds: self.migration_timelock_seconds,
+            oracle_funds_tolerance: self
+                .oracle_funds_tolerance
+                .as_ref()
+                .map(|tolerance| tolerance.fields()),
+            minimum_payment_amount: self.minimum_payment_amount.map(|amount| amount.u128()),
+            approval_valid_duration: self.approval_valid_duration,
+            oracle_approval_sla_seconds: self.oracle_approval_sla_seconds,
+            large_payment_threshold: self.large_payment_threshold.map(|amount| amount.u128()),
+            payment_reversal_window_seconds: self.payment_reversal_window_seconds,
+            authorized_registrars: self.authorized_registrars.as_deref(),
+        })
     }
 }
 
@@ -64,12 +176,250 @@ pub enum ExecuteMsg {
         oracle_address: String,
         payable_denom: String,
         payable_total: Uint128,
+        custom_fields: Option<Binary>,
+        priority_fee: Option<Uint128>,
+        oracle_gas_rebate: Option<Uint128>,
+        minimum_payment_amount: Option<Uint128>,
+        expiration_time: Option<Timestamp>,
+        accepted_denoms: Option<Vec<String>>,
+        payment_schedule: Option<Vec<PaymentInstallmentV1>>,
+        late_fee: Option<LateFeeV1>,
+        escrow_timeout_seconds: Option<u64>,
+        authorized_payers: Option<Vec<String>>,
+        interest: Option<InterestTermsV1>,
+        payee_splits: Option<Vec<PayeeSplitV1>>,
+        registration_nonce: Option<String>,
+        referrer: Option<String>,
     },
     OracleApproval {
         payable_uuid: String,
+        external_reference_id: Option<String>,
+        risk_score: Option<u32>,
+        approval_valid_for: Option<u64>,
+    },
+    RegisterPayables {
+        payables: Vec<RegisterPayableV2>,
+    },
+    UpdatePayableTotal {
+        payable_uuid: String,
+        new_total: Uint128,
+    },
+    ChangeOracle {
+        payable_uuid: String,
+        new_oracle_address: String,
+    },
+    RestructurePayable {
+        payable_uuid: String,
+        new_total: Uint128,
+    },
+    WriteOffPayable {
+        payable_uuid: String,
+        write_off_reason: String,
+    },
+    ExpirePayable {
+        payable_uuid: String,
+    },
+    AddOracleDelegate {
+        delegate: String,
+    },
+    RemoveOracleDelegate {
+        delegate: String,
     },
     MakePayment {
         payable_uuid: String,
+        refund_overpayment: Option<bool>,
+        memo: Option<String>,
+        on_behalf_of: Option<String>,
+    },
+    MakeBatchPayment {
+        payments: Vec<BatchPaymentV1>,
+    },
+    SetDenomDisplayMetadata {
+        denom: String,
+        symbol: String,
+        exponent: u32,
+    },
+    RemoveDenomDisplayMetadata {
+        denom: String,
+    },
+    StageMigrationParams {
+        params: MigrateMsg,
+    },
+    CleanupOrphanedAttributes {
+        scope_ids: Vec<String>,
+    },
+    SetPayableTypeSupplyCheck {
+        payable_type: String,
+        enabled: bool,
+    },
+    Subscribe {
+        payable_uuid: String,
+    },
+    ScheduleFeeHoliday {
+        holiday_id: String,
+        start_time: Timestamp,
+        end_time: Timestamp,
+        payable_types: Vec<String>,
+        onboarding_cost: Uint128,
+    },
+    CancelFeeHoliday {
+        holiday_id: String,
+    },
+    CancelPayable {
+        payable_uuid: String,
+    },
+    TransferPayable {
+        payable_uuid: String,
+        new_owner: String,
+    },
+    SetPaused {
+        paused: bool,
+    },
+    PatchPayableMeta {
+        payable_uuid: String,
+        patch: PayableMetaPatchV1,
+        reason: String,
+    },
+    UpsertPayableTypeConfig {
+        payable_type: String,
+        onboarding_cost: Uint128,
+        onboarding_denom: String,
+        fee_percent: Decimal,
+        default_oracle: Option<String>,
+        registrant_requirement: Option<RegistrantRequirementV1>,
+    },
+    AddSupportedPayableType {
+        payable_type: String,
+    },
+    RemoveSupportedPayableType {
+        payable_type: String,
+    },
+    DeclinePayable {
+        payable_uuid: String,
+    },
+    SetPaymentVolumeCap {
+        denom: String,
+        max_volume: Uint128,
+        window_seconds: u64,
+    },
+    ResetPaymentVolume {
+        denom: String,
+    },
+    ReleasePayment {
+        payable_uuid: String,
+        payment_id: u64,
+    },
+    ReclaimEscrow {
+        payable_uuid: String,
+        payment_id: u64,
+    },
+    RaiseDispute {
+        payable_uuid: String,
+        reason: String,
+    },
+    ResolveDispute {
+        payable_uuid: String,
+        resolution: String,
+        adjusted_total: Option<Uint128>,
+    },
+    ReassignOracleBulk {
+        old_oracle_address: String,
+        new_oracle_address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    PrunePayable {
+        payable_uuid: String,
+    },
+    RenewOracleApproval {
+        payable_uuid: String,
+        external_reference_id: Option<String>,
+        risk_score: Option<u32>,
+        approval_valid_for: Option<u64>,
+    },
+    SetQuarantineConfig {
+        flagged_payers: Vec<String>,
+        large_payment_thresholds: Vec<Coin>,
+    },
+    ReleaseQuarantinedPayment {
+        payable_uuid: String,
+        payment_id: u64,
+    },
+    RefundQuarantinedPayment {
+        payable_uuid: String,
+        payment_id: u64,
+    },
+    ProposePayment {
+        payable_uuid: String,
+        memo: Option<String>,
+        on_behalf_of: Option<String>,
+    },
+    ApprovePayment {
+        payable_uuid: String,
+    },
+    CancelProposedPayment {
+        payable_uuid: String,
+    },
+    RecordExternalSettlement {
+        payable_uuid: String,
+        amount: Uint128,
+        reference: String,
+    },
+    ReRegisterPayable {
+        payable: RegisterPayableV2,
+    },
+    ReversePayment {
+        payable_uuid: String,
+        payment_id: u64,
+        reason: String,
+    },
+    SetRiskBands {
+        risk_bands: Vec<RiskBandV1>,
+    },
+    FlagErroneousPayment {
+        payable_uuid: String,
+        payment_id: u64,
+    },
+    SetPayableFrozen {
+        payable_uuid: String,
+        frozen: bool,
+    },
+    SetPayableTypeFrozen {
+        payable_type: String,
+        frozen: bool,
+    },
+    RevertStaleApproval {
+        payable_uuid: String,
+    },
+    SetReferralConfig {
+        fee_share_bps: u64,
+        referrers: Vec<String>,
+    },
+    GrantPaymentAllowance {
+        payable_uuid: String,
+        denom: String,
+        max_total: Uint128,
+        pull_amount: Uint128,
+        pull_interval_seconds: u64,
+        first_pull_at: Option<Timestamp>,
+        expires_at: Option<Timestamp>,
+    },
+    RevokePaymentAllowance {
+        payable_uuid: String,
+    },
+    PullAllowancePayment {
+        payable_uuid: String,
+        payer: String,
+    },
+    SettleNetting {
+        payable_uuids: Vec<String>,
+    },
+    ClaimCancellationRefund {
+        payable_uuid: String,
+        payment_id: u64,
+    },
+    FinalizeCancellation {
+        payable_uuid: String,
     },
 }
 impl ExecuteMsg {
@@ -82,6 +432,20 @@ impl ExecuteMsg {
                 oracle_address,
                 payable_denom,
                 payable_total,
+                custom_fields,
+                priority_fee,
+                oracle_gas_rebate,
+                minimum_payment_amount,
+                expiration_time,
+                accepted_denoms,
+                payment_schedule,
+                late_fee,
+                escrow_timeout_seconds,
+                authorized_payers,
+                interest,
+                payee_splits,
+                registration_nonce,
+                referrer,
             } => Ok(RegisterPayableV2 {
                 payable_type,
                 payable_uuid,
@@ -89,390 +453,3967 @@ impl ExecuteMsg {
                 oracle_address,
                 payable_denom,
                 payable_total,
+                custom_fields,
+                priority_fee,
+                oracle_gas_rebate,
+                minimum_payment_amount,
+                expiration_time,
+                accepted_denoms,
+                payment_schedule,
+                late_fee,
+                escrow_timeout_seconds,
+                authorized_payers,
+                interest,
+                payee_splits,
+                registration_nonce,
+                referrer,
             }),
             _ => ContractError::std_err("expected RegisterPayable message type").to_result(),
         }
     }
+    pub fn to_register_payables(self) -> Result<RegisterPayablesV1, ContractError> {
+        match self {
+            ExecuteMsg::RegisterPayables { payables } => Ok(RegisterPayablesV1 { payables }),
+            _ => ContractError::std_err("expected RegisterPayables message type").to_result(),
+        }
+    }
+    pub fn to_update_payable_total(self) -> Result<UpdatePayableTotalV1, ContractError> {
+        match self {
+            ExecuteMsg::UpdatePayableTotal {
+                payable_uuid,
+                new_total,
+            } => Ok(UpdatePayableTotalV1 {
+                payable_uuid,
+                new_total,
+            }),
+            _ => ContractError::std_err("expected UpdatePayableTotal message type").to_result(),
+        }
+    }
+    pub fn to_change_oracle(self) -> Result<ChangeOracleV1, ContractError> {
+        match self {
+            ExecuteMsg::ChangeOracle {
+                payable_uuid,
+                new_oracle_address,
+            } => Ok(ChangeOracleV1 {
+                payable_uuid,
+                new_oracle_address,
+            }),
+            _ => ContractError::std_err("expected ChangeOracle message type").to_result(),
+        }
+    }
+    pub fn to_restructure_payable(self) -> Result<RestructurePayableV1, ContractError> {
+        match self {
+            ExecuteMsg::RestructurePayable {
+                payable_uuid,
+                new_total,
+            } => Ok(RestructurePayableV1 {
+                payable_uuid,
+                new_total,
+            }),
+            _ => ContractError::std_err("expected RestructurePayable message type").to_result(),
+        }
+    }
+    pub fn to_write_off_payable(self) -> Result<WriteOffPayableV1, ContractError> {
+        match self {
+            ExecuteMsg::WriteOffPayable {
+                payable_uuid,
+                write_off_reason,
+            } => Ok(WriteOffPayableV1 {
+                payable_uuid,
+                write_off_reason,
+            }),
+            _ => ContractError::std_err("expected WriteOffPayable message type").to_result(),
+        }
+    }
+    pub fn to_expire_payable(self) -> Result<ExpirePayableV1, ContractError> {
+        match self {
+            ExecuteMsg::ExpirePayable { payable_uuid } => Ok(ExpirePayableV1 { payable_uuid }),
+            _ => ContractError::std_err("expected ExpirePayable message type").to_result(),
+        }
+    }
     pub fn to_oracle_approval(self) -> Result<OracleApprovalV1, ContractError> {
         match self {
-            ExecuteMsg::OracleApproval { payable_uuid } => Ok(OracleApprovalV1 { payable_uuid }),
+            ExecuteMsg::OracleApproval {
+                payable_uuid,
+                external_reference_id,
+                risk_score,
+                approval_valid_for,
+            } => Ok(OracleApprovalV1 {
+                payable_uuid,
+                external_reference_id,
+                risk_score,
+                approval_valid_for,
+            }),
             _ => ContractError::std_err("expected OracleApproval message type").to_result(),
         }
     }
-    pub fn to_make_payment(self) -> Result<MakePaymentV1, ContractError> {
+    pub fn to_add_oracle_delegate(self) -> Result<AddOracleDelegateV1, ContractError> {
         match self {
-            ExecuteMsg::MakePayment { payable_uuid } => Ok(MakePaymentV1 { payable_uuid }),
-            _ => ContractError::std_err("expected MakePayment message type").to_result(),
+            ExecuteMsg::AddOracleDelegate { delegate } => Ok(AddOracleDelegateV1 { delegate }),
+            _ => ContractError::std_err("expected AddOracleDelegate message type").to_result(),
         }
     }
-}
-impl ValidatedMsg for ExecuteMsg {
-    fn validate(&self) -> Result<(), ContractError> {
-        let mut invalid_fields: Vec<&str> = vec![];
+    pub fn to_remove_oracle_delegate(self) -> Result<RemoveOracleDelegateV1, ContractError> {
         match self {
-            ExecuteMsg::RegisterPayable {
-                payable_type,
-                payable_uuid,
-                scope_id,
-                oracle_address,
-                payable_denom,
-                payable_total,
-            } => {
-                if payable_type.is_empty() {
-                    invalid_fields.push("payable_type");
-                }
-                if payable_uuid.is_empty() {
-                    invalid_fields.push("payable_uuid");
-                }
-                if scope_id.is_empty() {
-                    invalid_fields.push("scope_id");
-                }
-                if oracle_address.is_empty() {
-                    invalid_fields.push("oracle_address");
-                }
-                if payable_denom.is_empty() {
-                    invalid_fields.push("payable_denom");
-                }
-                if payable_total.u128() == 0 {
-                    invalid_fields.push("payable_total");
-                }
-            }
-            ExecuteMsg::OracleApproval { payable_uuid } => {
-                if payable_uuid.is_empty() {
-                    invalid_fields.push("payable_uuid");
-                }
-            }
-            ExecuteMsg::MakePayment { payable_uuid } => {
-                if payable_uuid.is_empty() {
-                    invalid_fields.push("payable_uuid");
-                }
+            ExecuteMsg::RemoveOracleDelegate { delegate } => {
+                Ok(RemoveOracleDelegateV1 { delegate })
             }
-        };
-        if !invalid_fields.is_empty() {
-            ContractError::invalid_fields(invalid_fields).to_result()
-        } else {
-            Ok(())
+            _ => ContractError::std_err("expected RemoveOracleDelegate message type").to_result(),
         }
     }
-}
-
-/// A message sent to query contract config state.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum QueryMsg {
-    QueryState {},
-    QueryPayableByScopeId { scope_id: String },
-    QueryPayableByUuid { payable_uuid: String },
-}
-impl ValidatedMsg for QueryMsg {
-    fn validate(&self) -> Result<(), ContractError> {
-        let mut invalid_fields: Vec<&str> = vec![];
+    pub fn to_make_payment(self) -> Result<MakePaymentV1, ContractError> {
         match self {
-            QueryMsg::QueryState {} => (),
-            QueryMsg::QueryPayableByScopeId { scope_id } => {
-                if scope_id.is_empty() {
-                    invalid_fields.push("scope_id");
-                }
-            }
-            QueryMsg::QueryPayableByUuid { payable_uuid } => {
-                if payable_uuid.is_empty() {
-                    invalid_fields.push("payable_uuid");
-                }
-            }
-        };
-        if !invalid_fields.is_empty() {
-            ContractError::invalid_fields(invalid_fields).to_result()
-        } else {
-            Ok(())
+            ExecuteMsg::MakePayment {
+                payable_uuid,
+                refund_overpayment,
+                memo,
+                on_behalf_of,
+            } => Ok(MakePaymentV1 {
+                payable_uuid,
+                refund_overpayment: refund_overpayment.unwrap_or(false),
+                memo,
+                on_behalf_of,
+            }),
+            _ => ContractError::std_err("expected MakePayment message type").to_result(),
         }
     }
-}
-
-/// A type alias for contract state.
-pub type QueryResponse = StateV2;
-
-/// Migrate the contract
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub struct MigrateMsg {
-    pub onboarding_cost: Option<String>,
-    pub onboarding_denom: Option<String>,
-    pub fee_collection_address: Option<String>,
-    pub fee_percent: Option<Decimal>,
-    pub is_local: Option<bool>,
-}
-impl ValidatedMsg for MigrateMsg {
-    fn validate(&self) -> Result<(), ContractError> {
-        let mut invalid_fields: Vec<&str> = vec![];
-        if let Some(cost) = &self.onboarding_cost {
-            if cost.parse::<u128>().is_err() {
-                invalid_fields.push("onboarding_cost");
-            }
+    pub fn to_make_batch_payment(self) -> Result<MakeBatchPaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::MakeBatchPayment { payments } => Ok(MakeBatchPaymentV1 { payments }),
+            _ => ContractError::std_err("expected MakeBatchPayment message type").to_result(),
         }
-        if let Some(denom) = &self.onboarding_denom {
-            if denom.is_empty() {
-                invalid_fields.push("onboarding_denom");
+    }
+    pub fn to_set_denom_display_metadata(self) -> Result<SetDenomDisplayMetadataV1, ContractError> {
+        match self {
+            ExecuteMsg::SetDenomDisplayMetadata {
+                denom,
+                symbol,
+                exponent,
+            } => Ok(SetDenomDisplayMetadataV1 {
+                denom,
+                symbol,
+                exponent,
+            }),
+            _ => {
+                ContractError::std_err("expected SetDenomDisplayMetadata message type").to_result()
             }
         }
-        if let Some(fee_addr) = &self.fee_collection_address {
-            if fee_addr.is_empty() {
-                invalid_fields.push("fee_collection_address");
+    }
+    pub fn to_remove_denom_display_metadata(
+        self,
+    ) -> Result<RemoveDenomDisplayMetadataV1, ContractError> {
+        match self {
+            ExecuteMsg::RemoveDenomDisplayMetadata { denom } => {
+                Ok(RemoveDenomDisplayMetadataV1 { denom })
             }
+            _ => ContractError::std_err("expected RemoveDenomDisplayMetadata message type")
+                .to_result(),
         }
-        if let Some(fee_percent) = &self.fee_percent {
-            if fee_percent > &Decimal::one() {
-                invalid_fields.push("fee_percent");
-            }
+    }
+    pub fn to_stage_migration_params(self) -> Result<StageMigrationParamsV1, ContractError> {
+        match self {
+            ExecuteMsg::StageMigrationParams { params } => Ok(StageMigrationParamsV1 { params }),
+            _ => ContractError::std_err("expected StageMigrationParams message type").to_result(),
         }
-        if !invalid_fields.is_empty() {
-            ContractError::invalid_fields(invalid_fields).to_result()
-        } else {
-            Ok(())
+    }
+    pub fn to_cleanup_orphaned_attributes(
+        self,
+    ) -> Result<CleanupOrphanedAttributesV1, ContractError> {
+        match self {
+            ExecuteMsg::CleanupOrphanedAttributes { scope_ids } => {
+                Ok(CleanupOrphanedAttributesV1 { scope_ids })
+            }
+            _ => ContractError::std_err("expected CleanupOrphanedAttributes message type")
+                .to_result(),
         }
     }
-}
-impl MigrateMsg {
-    /// Converts the message from a contract message into a parsed and converted message for
-    /// processing in downstream migration code.
-    pub fn to_migrate_contract_v2<T: CustomQuery>(
+    pub fn to_set_payable_type_supply_check(
         self,
-        deps: &Deps<T>,
-    ) -> Result<MigrateContractV2, ContractError> {
-        // Unbox mapped fields, convert and validate, and re-box if necessary. Otherwise,
-        // pass-through to None
-        let onboarding_cost = if let Some(cost) = self.onboarding_cost {
-            Some(to_uint128(cost)?)
-        } else {
-            None
-        };
-        let fee_collection_address = if let Some(fee_addr) = self.fee_collection_address {
-            Some(deps.api.addr_validate(fee_addr.as_str())?)
-        } else {
-            None
-        };
-        Ok(MigrateContractV2 {
-            onboarding_cost,
-            onboarding_denom: self.onboarding_denom,
-            fee_collection_address,
-            fee_percent: self.fee_percent,
-            is_local: self.is_local,
-        })
+    ) -> Result<SetPayableTypeSupplyCheckV1, ContractError> {
+        match self {
+            ExecuteMsg::SetPayableTypeSupplyCheck {
+                payable_type,
+                enabled,
+            } => Ok(SetPayableTypeSupplyCheckV1 {
+                payable_type,
+                enabled,
+            }),
+            _ => ContractError::std_err("expected SetPayableTypeSupplyCheck message type")
+                .to_result(),
+        }
+    }
+    pub fn to_subscribe(self) -> Result<SubscribeV1, ContractError> {
+        match self {
+            ExecuteMsg::Subscribe { payable_uuid } => Ok(SubscribeV1 { payable_uuid }),
+            _ => ContractError::std_err("expected Subscribe message type").to_result(),
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::core::error::ContractError;
-    use crate::core::msg::ExecuteMsg::{MakePayment, OracleApproval};
-    use crate::core::msg::QueryMsg::{QueryPayableByUuid, QueryState};
-    use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg};
-    use crate::util::traits::ValidatedMsg;
-    use cosmwasm_std::{Decimal, Uint128};
-    use provwasm_mocks::mock_dependencies;
+    pub fn to_schedule_fee_holiday(self) -> Result<ScheduleFeeHolidayV1, ContractError> {
+        match self {
+            ExecuteMsg::ScheduleFeeHoliday {
+                holiday_id,
+                start_time,
+                end_time,
+                payable_types,
+                onboarding_cost,
+            } => Ok(ScheduleFeeHolidayV1 {
+                holiday_id,
+                start_time,
+                end_time,
+                payable_types,
+                onboarding_cost,
+            }),
+            _ => ContractError::std_err("expected ScheduleFeeHoliday message type").to_result(),
+        }
+    }
 
-    #[test]
-    fn test_valid_init_msg() {
-        get_valid_init_msg()
-            .validate()
-            .expect("a populated init msg should pass validation");
+    pub fn to_cancel_fee_holiday(self) -> Result<CancelFeeHolidayV1, ContractError> {
+        match self {
+            ExecuteMsg::CancelFeeHoliday { holiday_id } => Ok(CancelFeeHolidayV1 { holiday_id }),
+            _ => ContractError::std_err("expected CancelFeeHoliday message type").to_result(),
+        }
     }
 
-    #[test]
-    fn test_invalid_init_msg_contract_name() {
-        let mut msg = get_valid_init_msg();
-        // Empty string bad
-        msg.contract_name = String::new();
-        test_invalid_msg(&msg, "contract_name");
+    pub fn to_cancel_payable(self) -> Result<CancelPayableV1, ContractError> {
+        match self {
+            ExecuteMsg::CancelPayable { payable_uuid } => Ok(CancelPayableV1 { payable_uuid }),
+            _ => ContractError::std_err("expected CancelPayable message type").to_result(),
+        }
     }
 
-    #[test]
-    fn test_invalid_init_msg_onboarding_cost() {
-        let mut msg = get_valid_init_msg();
-        // Non-numbers bad
+    pub fn to_transfer_payable(self) -> Result<TransferPayableV1, ContractError> {
+        match self {
+            ExecuteMsg::TransferPayable {
+                payable_uuid,
+                new_owner,
+            } => Ok(TransferPayableV1 {
+                payable_uuid,
+                new_owner,
+            }),
+            _ => ContractError::std_err("expected TransferPayable message type").to_result(),
+        }
+    }
+
+    pub fn to_set_paused(self) -> Result<SetPausedV1, ContractError> {
+        match self {
+            ExecuteMsg::SetPaused { paused } => Ok(SetPausedV1 { paused }),
+            _ => ContractError::std_err("expected SetPaused message type").to_result(),
+        }
+    }
+
+    pub fn to_patch_payable_meta(self) -> Result<PatchPayableMetaV1, ContractError> {
+        match self {
+            ExecuteMsg::PatchPayableMeta {
+                payable_uuid,
+                patch,
+                reason,
+            } => Ok(PatchPayableMetaV1 {
+                payable_uuid,
+                patch,
+                reason,
+            }),
+            _ => ContractError::std_err("expected PatchPayableMeta message type").to_result(),
+        }
+    }
+
+    pub fn to_upsert_payable_type_config(self) -> Result<UpsertPayableTypeConfigV1, ContractError> {
+        match self {
+            ExecuteMsg::UpsertPayableTypeConfig {
+                payable_type,
+                onboarding_cost,
+                onboarding_denom,
+                fee_percent,
+                default_oracle,
+                registrant_requirement,
+            } => Ok(UpsertPayableTypeConfigV1 {
+                payable_type,
+                onboarding_cost,
+                onboarding_denom,
+                fee_percent,
+                default_oracle,
+                registrant_requirement,
+            }),
+            _ => {
+                ContractError::std_err("expected UpsertPayableTypeConfig message type").to_result()
+            }
+        }
+    }
+
+    pub fn to_add_supported_payable_type(self) -> Result<AddSupportedPayableTypeV1, ContractError> {
+        match self {
+            ExecuteMsg::AddSupportedPayableType { payable_type } => {
+                Ok(AddSupportedPayableTypeV1 { payable_type })
+            }
+            _ => {
+                ContractError::std_err("expected AddSupportedPayableType message type").to_result()
+            }
+        }
+    }
+
+    pub fn to_remove_supported_payable_type(
+        self,
+    ) -> Result<RemoveSupportedPayableTypeV1, ContractError> {
+        match self {
+            ExecuteMsg::RemoveSupportedPayableType { payable_type } => {
+                Ok(RemoveSupportedPayableTypeV1 { payable_type })
+            }
+            _ => ContractError::std_err("expected RemoveSupportedPayableType message type")
+                .to_result(),
+        }
+    }
+
+    pub fn to_decline_payable(self) -> Result<DeclinePayableV1, ContractError> {
+        match self {
+            ExecuteMsg::DeclinePayable { payable_uuid } => Ok(DeclinePayableV1 { payable_uuid }),
+            _ => ContractError::std_err("expected DeclinePayable message type").to_result(),
+        }
+    }
+
+    pub fn to_set_payment_volume_cap(self) -> Result<SetPaymentVolumeCapV1, ContractError> {
+        match self {
+            ExecuteMsg::SetPaymentVolumeCap {
+                denom,
+                max_volume,
+                window_seconds,
+            } => Ok(SetPaymentVolumeCapV1 {
+                denom,
+                max_volume,
+                window_seconds,
+            }),
+            _ => ContractError::std_err("expected SetPaymentVolumeCap message type").to_result(),
+        }
+    }
+
+    pub fn to_reset_payment_volume(self) -> Result<ResetPaymentVolumeV1, ContractError> {
+        match self {
+            ExecuteMsg::ResetPaymentVolume { denom } => Ok(ResetPaymentVolumeV1 { denom }),
+            _ => ContractError::std_err("expected ResetPaymentVolume message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "escrow")]
+    pub fn to_release_payment(self) -> Result<ReleasePaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::ReleasePayment {
+                payable_uuid,
+                payment_id,
+            } => Ok(ReleasePaymentV1 {
+                payable_uuid,
+                payment_id,
+            }),
+            _ => ContractError::std_err("expected ReleasePayment message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "escrow")]
+    pub fn to_reclaim_escrow(self) -> Result<ReclaimEscrowV1, ContractError> {
+        match self {
+            ExecuteMsg::ReclaimEscrow {
+                payable_uuid,
+                payment_id,
+            } => Ok(ReclaimEscrowV1 {
+                payable_uuid,
+                payment_id,
+            }),
+            _ => ContractError::std_err("expected ReclaimEscrow message type").to_result(),
+        }
+    }
+
+    pub fn to_raise_dispute(self) -> Result<RaiseDisputeV1, ContractError> {
+        match self {
+            ExecuteMsg::RaiseDispute {
+                payable_uuid,
+                reason,
+            } => Ok(RaiseDisputeV1 {
+                payable_uuid,
+                reason,
+            }),
+            _ => ContractError::std_err("expected RaiseDispute message type").to_result(),
+        }
+    }
+
+    pub fn to_resolve_dispute(self) -> Result<ResolveDisputeV1, ContractError> {
+        match self {
+            ExecuteMsg::ResolveDispute {
+                payable_uuid,
+                resolution,
+                adjusted_total,
+            } => Ok(ResolveDisputeV1 {
+                payable_uuid,
+                resolution,
+                adjusted_total,
+            }),
+            _ => ContractError::std_err("expected ResolveDispute message type").to_result(),
+        }
+    }
+
+    pub fn to_reassign_oracle_bulk(self) -> Result<ReassignOracleBulkV1, ContractError> {
+        match self {
+            ExecuteMsg::ReassignOracleBulk {
+                old_oracle_address,
+                new_oracle_address,
+                start_after,
+                limit,
+            } => Ok(ReassignOracleBulkV1 {
+                old_oracle_address,
+                new_oracle_address,
+                start_after,
+                limit,
+            }),
+            _ => ContractError::std_err("expected ReassignOracleBulk message type").to_result(),
+        }
+    }
+
+    pub fn to_prune_payable(self) -> Result<PrunePayableV1, ContractError> {
+        match self {
+            ExecuteMsg::PrunePayable { payable_uuid } => Ok(PrunePayableV1 { payable_uuid }),
+            _ => ContractError::std_err("expected PrunePayable message type").to_result(),
+        }
+    }
+
+    pub fn to_renew_oracle_approval(self) -> Result<RenewOracleApprovalV1, ContractError> {
+        match self {
+            ExecuteMsg::RenewOracleApproval {
+                payable_uuid,
+                external_reference_id,
+                risk_score,
+                approval_valid_for,
+            } => Ok(RenewOracleApprovalV1 {
+                payable_uuid,
+                external_reference_id,
+                risk_score,
+                approval_valid_for,
+            }),
+            _ => ContractError::std_err("expected RenewOracleApproval message type").to_result(),
+        }
+    }
+
+    pub fn to_set_quarantine_config(self) -> Result<SetQuarantineConfigV1, ContractError> {
+        match self {
+            ExecuteMsg::SetQuarantineConfig {
+                flagged_payers,
+                large_payment_thresholds,
+            } => Ok(SetQuarantineConfigV1 {
+                flagged_payers,
+                large_payment_thresholds,
+            }),
+            _ => ContractError::std_err("expected SetQuarantineConfig message type").to_result(),
+        }
+    }
+
+    pub fn to_release_quarantined_payment(
+        self,
+    ) -> Result<ReleaseQuarantinedPaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::ReleaseQuarantinedPayment {
+                payable_uuid,
+                payment_id,
+            } => Ok(ReleaseQuarantinedPaymentV1 {
+                payable_uuid,
+                payment_id,
+            }),
+            _ => ContractError::std_err("expected ReleaseQuarantinedPayment message type")
+                .to_result(),
+        }
+    }
+
+    pub fn to_refund_quarantined_payment(
+        self,
+    ) -> Result<RefundQuarantinedPaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::RefundQuarantinedPayment {
+                payable_uuid,
+                payment_id,
+            } => Ok(RefundQuarantinedPaymentV1 {
+                payable_uuid,
+                payment_id,
+            }),
+            _ => {
+                ContractError::std_err("expected RefundQuarantinedPayment message type").to_result()
+            }
+        }
+    }
+
+    pub fn to_propose_payment(self) -> Result<ProposePaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::ProposePayment {
+                payable_uuid,
+                memo,
+                on_behalf_of,
+            } => Ok(ProposePaymentV1 {
+                payable_uuid,
+                memo,
+                on_behalf_of,
+            }),
+            _ => ContractError::std_err("expected ProposePayment message type").to_result(),
+        }
+    }
+
+    pub fn to_approve_payment(self) -> Result<ApprovePaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::ApprovePayment { payable_uuid } => Ok(ApprovePaymentV1 { payable_uuid }),
+            _ => ContractError::std_err("expected ApprovePayment message type").to_result(),
+        }
+    }
+
+    pub fn to_cancel_proposed_payment(self) -> Result<CancelProposedPaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::CancelProposedPayment { payable_uuid } => {
+                Ok(CancelProposedPaymentV1 { payable_uuid })
+            }
+            _ => ContractError::std_err("expected CancelProposedPayment message type").to_result(),
+        }
+    }
+
+    pub fn to_record_external_settlement(
+        self,
+    ) -> Result<RecordExternalSettlementV1, ContractError> {
+        match self {
+            ExecuteMsg::RecordExternalSettlement {
+                payable_uuid,
+                amount,
+                reference,
+            } => Ok(RecordExternalSettlementV1 {
+                payable_uuid,
+                amount,
+                reference,
+            }),
+            _ => {
+                ContractError::std_err("expected RecordExternalSettlement message type").to_result()
+            }
+        }
+    }
+
+    pub fn to_re_register_payable(self) -> Result<ReRegisterPayableV1, ContractError> {
+        match self {
+            ExecuteMsg::ReRegisterPayable { payable } => Ok(ReRegisterPayableV1 { payable }),
+            _ => ContractError::std_err("expected ReRegisterPayable message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "escrow")]
+    pub fn to_reverse_payment(self) -> Result<ReversePaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::ReversePayment {
+                payable_uuid,
+                payment_id,
+                reason,
+            } => Ok(ReversePaymentV1 {
+                payable_uuid,
+                payment_id,
+                reason,
+            }),
+            _ => ContractError::std_err("expected ReversePayment message type").to_result(),
+        }
+    }
+
+    pub fn to_set_risk_bands(self) -> Result<SetRiskBandsV1, ContractError> {
+        match self {
+            ExecuteMsg::SetRiskBands { risk_bands } => Ok(SetRiskBandsV1 { risk_bands }),
+            _ => ContractError::std_err("expected SetRiskBands message type").to_result(),
+        }
+    }
+
+    pub fn to_flag_erroneous_payment(self) -> Result<FlagErroneousPaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::FlagErroneousPayment {
+                payable_uuid,
+                payment_id,
+            } => Ok(FlagErroneousPaymentV1 {
+                payable_uuid,
+                payment_id,
+            }),
+            _ => ContractError::std_err("expected FlagErroneousPayment message type").to_result(),
+        }
+    }
+
+    pub fn to_set_payable_frozen(self) -> Result<SetPayableFrozenV1, ContractError> {
+        match self {
+            ExecuteMsg::SetPayableFrozen {
+                payable_uuid,
+                frozen,
+            } => Ok(SetPayableFrozenV1 {
+                payable_uuid,
+                frozen,
+            }),
+            _ => ContractError::std_err("expected SetPayableFrozen message type").to_result(),
+        }
+    }
+
+    pub fn to_set_payable_type_frozen(self) -> Result<SetPayableTypeFrozenV1, ContractError> {
+        match self {
+            ExecuteMsg::SetPayableTypeFrozen {
+                payable_type,
+                frozen,
+            } => Ok(SetPayableTypeFrozenV1 {
+                payable_type,
+                frozen,
+            }),
+            _ => ContractError::std_err("expected SetPayableTypeFrozen message type").to_result(),
+        }
+    }
+
+    pub fn to_revert_stale_approval(self) -> Result<RevertStaleApprovalV1, ContractError> {
+        match self {
+            ExecuteMsg::RevertStaleApproval { payable_uuid } => {
+                Ok(RevertStaleApprovalV1 { payable_uuid })
+            }
+            _ => ContractError::std_err("expected RevertStaleApproval message type").to_result(),
+        }
+    }
+
+    pub fn to_set_referral_config(self) -> Result<SetReferralConfigV1, ContractError> {
+        match self {
+            ExecuteMsg::SetReferralConfig {
+                fee_share_bps,
+                referrers,
+            } => Ok(SetReferralConfigV1 {
+                fee_share_bps,
+                referrers,
+            }),
+            _ => ContractError::std_err("expected SetReferralConfig message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "allowance")]
+    pub fn to_grant_payment_allowance(self) -> Result<GrantPaymentAllowanceV1, ContractError> {
+        match self {
+            ExecuteMsg::GrantPaymentAllowance {
+                payable_uuid,
+                denom,
+                max_total,
+                pull_amount,
+                pull_interval_seconds,
+                first_pull_at,
+                expires_at,
+            } => Ok(GrantPaymentAllowanceV1 {
+                payable_uuid,
+                denom,
+                max_total,
+                pull_amount,
+                pull_interval_seconds,
+                first_pull_at,
+                expires_at,
+            }),
+            _ => ContractError::std_err("expected GrantPaymentAllowance message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "allowance")]
+    pub fn to_revoke_payment_allowance(self) -> Result<RevokePaymentAllowanceV1, ContractError> {
+        match self {
+            ExecuteMsg::RevokePaymentAllowance { payable_uuid } => {
+                Ok(RevokePaymentAllowanceV1 { payable_uuid })
+            }
+            _ => ContractError::std_err("expected RevokePaymentAllowance message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "allowance")]
+    pub fn to_pull_allowance_payment(self) -> Result<PullAllowancePaymentV1, ContractError> {
+        match self {
+            ExecuteMsg::PullAllowancePayment {
+                payable_uuid,
+                payer,
+            } => Ok(PullAllowancePaymentV1 {
+                payable_uuid,
+                payer,
+            }),
+            _ => ContractError::std_err("expected PullAllowancePayment message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "escrow")]
+    pub fn to_settle_netting(self) -> Result<SettleNettingV1, ContractError> {
+        match self {
+            ExecuteMsg::SettleNetting { payable_uuids } => Ok(SettleNettingV1 { payable_uuids }),
+            _ => ContractError::std_err("expected SettleNetting message type").to_result(),
+        }
+    }
+
+    #[cfg(feature = "escrow")]
+    pub fn to_claim_cancellation_refund(self) -> Result<ClaimCancellationRefundV1, ContractError> {
+        match self {
+            ExecuteMsg::ClaimCancellationRefund {
+                payable_uuid,
+                payment_id,
+            } => Ok(ClaimCancellationRefundV1 {
+                payable_uuid,
+                payment_id,
+            }),
+            _ => {
+                ContractError::std_err("expected ClaimCancellationRefund message type").to_result()
+            }
+        }
+    }
+
+    #[cfg(feature = "escrow")]
+    pub fn to_finalize_cancellation(self) -> Result<FinalizeCancellationV1, ContractError> {
+        match self {
+            ExecuteMsg::FinalizeCancellation { payable_uuid } => {
+                Ok(FinalizeCancellationV1 { payable_uuid })
+            }
+            _ => ContractError::std_err("expected FinalizeCancellation message type").to_result(),
+        }
+    }
+}
+impl FieldValidation for ExecuteMsg {
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        match self {
+            ExecuteMsg::RegisterPayable { .. } => match self.clone().to_register_payable() {
+                Ok(register) => invalid_register_payable_fields(&register.fields()),
+                Err(_) => vec![],
+            },
+            ExecuteMsg::ReRegisterPayable { payable } => {
+                invalid_register_payable_fields(&payable.fields())
+            }
+            ExecuteMsg::RegisterPayables { payables } => invalid_register_payables_fields(
+                &payables
+                    .iter()
+                    .map(|payable| payable.fields())
+                    .collect::<Vec<RegisterPayableFields>>(),
+            ),
+            ExecuteMsg::UpdatePayableTotal {
+                payable_uuid,
+                new_total,
+            }
+            | ExecuteMsg::RestructurePayable {
+                payable_uuid,
+                new_total,
+            } => invalid_payable_total_fields(payable_uuid, new_total.u128()),
+            ExecuteMsg::ChangeOracle {
+                payable_uuid,
+                new_oracle_address,
+            } => {
+                invalid_payable_text_fields(payable_uuid, new_oracle_address, "new_oracle_address")
+            }
+            ExecuteMsg::WriteOffPayable {
+                payable_uuid,
+                write_off_reason,
+            } => invalid_payable_text_fields(payable_uuid, write_off_reason, "write_off_reason"),
+            ExecuteMsg::OracleApproval {
+                payable_uuid,
+                external_reference_id,
+                risk_score,
+                approval_valid_for,
+            }
+            | ExecuteMsg::RenewOracleApproval {
+                payable_uuid,
+                external_reference_id,
+                risk_score,
+                approval_valid_for,
+            } => invalid_oracle_approval_fields(
+                payable_uuid,
+                external_reference_id.as_deref(),
+                *risk_score,
+                *approval_valid_for,
+            ),
+            ExecuteMsg::AddOracleDelegate { delegate }
+            | ExecuteMsg::RemoveOracleDelegate { delegate } => {
+                invalid_empty_fields(delegate, "delegate")
+            }
+            ExecuteMsg::MakePayment {
+                payable_uuid,
+                memo,
+                on_behalf_of,
+                ..
+            }
+            | ExecuteMsg::ProposePayment {
+                payable_uuid,
+                memo,
+                on_behalf_of,
+            } => invalid_payment_fields(payable_uuid, memo.as_deref(), on_behalf_of.as_deref()),
+            ExecuteMsg::MakeBatchPayment { payments } => invalid_batch_payment_fields(
+                &payments
+                    .iter()
+                    .map(|payment| BatchPaymentFields {
+                        payable_uuid: &payment.payable_uuid,
+                        amount: payment.amount.u128(),
+                    })
+                    .collect::<Vec<BatchPaymentFields>>(),
+            ),
+            ExecuteMsg::SetDenomDisplayMetadata {
+                denom,
+                symbol,
+                exponent,
+            } => invalid_denom_display_metadata_fields(denom, symbol, *exponent),
+            ExecuteMsg::RemoveDenomDisplayMetadata { denom }
+            | ExecuteMsg::ResetPaymentVolume { denom } => invalid_denom_fields(denom),
+            // Staged params are held to the same rules as the migration they will be applied in
+            ExecuteMsg::StageMigrationParams { params } => params.invalid_fields(),
+            ExecuteMsg::CleanupOrphanedAttributes { scope_ids } => {
+                invalid_cleanup_orphaned_attributes_fields(scope_ids)
+            }
+            ExecuteMsg::SetPayableTypeSupplyCheck { payable_type, .. }
+            | ExecuteMsg::SetPayableTypeFrozen { payable_type, .. }
+            | ExecuteMsg::AddSupportedPayableType { payable_type }
+            | ExecuteMsg::RemoveSupportedPayableType { payable_type } => {
+                invalid_empty_fields(payable_type, "payable_type")
+            }
+            ExecuteMsg::ScheduleFeeHoliday {
+                holiday_id,
+                start_time,
+                end_time,
+                payable_types,
+                ..
+            } => invalid_fee_holiday_fields(
+                holiday_id,
+                start_time.nanos(),
+                end_time.nanos(),
+                payable_types,
+            ),
+            ExecuteMsg::CancelFeeHoliday { holiday_id } => {
+                invalid_empty_fields(holiday_id, "holiday_id")
+            }
+            ExecuteMsg::TransferPayable {
+                payable_uuid,
+                new_owner,
+            } => invalid_payable_text_fields(payable_uuid, new_owner, "new_owner"),
+            // A bool flag cannot be malformed
+            ExecuteMsg::SetPaused { .. } => vec![],
+            ExecuteMsg::PatchPayableMeta {
+                payable_uuid,
+                patch,
+                reason,
+            } => invalid_patch_payable_meta_fields(payable_uuid, patch.scope_id.as_deref(), reason),
+            ExecuteMsg::UpsertPayableTypeConfig {
+                payable_type,
+                onboarding_cost,
+                onboarding_denom,
+                fee_percent,
+                default_oracle,
+                registrant_requirement,
+            } => invalid_payable_type_config_fields(&PayableTypeConfigFields {
+                payable_type,
+                onboarding_cost: onboarding_cost.u128(),
+                onboarding_denom,
+                fee_percent: fee_percent.atomics().u128(),
+                default_oracle: default_oracle.as_deref(),
+                requires_unspecified_owner_role: matches!(
+                    registrant_requirement,
+                    Some(RegistrantRequirementV1::OwnerRole {
+                        role: PartyType::Unspecified,
+                    })
+                ),
+            }),
+            ExecuteMsg::SetPaymentVolumeCap {
+                denom,
+                max_volume,
+                window_seconds,
+            } => invalid_payment_volume_cap_fields(denom, max_volume.u128(), *window_seconds),
+            ExecuteMsg::ExpirePayable { payable_uuid }
+            | ExecuteMsg::Subscribe { payable_uuid }
+            | ExecuteMsg::CancelPayable { payable_uuid }
+            | ExecuteMsg::DeclinePayable { payable_uuid }
+            | ExecuteMsg::PrunePayable { payable_uuid }
+            | ExecuteMsg::ReleasePayment { payable_uuid, .. }
+            | ExecuteMsg::ReclaimEscrow { payable_uuid, .. }
+            | ExecuteMsg::ReleaseQuarantinedPayment { payable_uuid, .. }
+            | ExecuteMsg::RefundQuarantinedPayment { payable_uuid, .. }
+            | ExecuteMsg::FlagErroneousPayment { payable_uuid, .. }
+            | ExecuteMsg::SetPayableFrozen { payable_uuid, .. }
+            | ExecuteMsg::RevertStaleApproval { payable_uuid }
+            | ExecuteMsg::ApprovePayment { payable_uuid }
+            | ExecuteMsg::CancelProposedPayment { payable_uuid }
+            | ExecuteMsg::RevokePaymentAllowance { payable_uuid }
+            | ExecuteMsg::ClaimCancellationRefund { payable_uuid, .. }
+            | ExecuteMsg::FinalizeCancellation { payable_uuid } => {
+                invalid_payable_uuid_fields(payable_uuid)
+            }
+            ExecuteMsg::RecordExternalSettlement {
+                payable_uuid,
+                amount,
+                reference,
+            } => invalid_external_settlement_fields(payable_uuid, amount.u128(), reference),
+            ExecuteMsg::RaiseDispute {
+                payable_uuid,
+                reason,
+            }
+            | ExecuteMsg::ReversePayment {
+                payable_uuid,
+                reason,
+                ..
+            } => invalid_payable_text_fields(payable_uuid, reason, "reason"),
+            ExecuteMsg::ResolveDispute {
+                payable_uuid,
+                resolution,
+                adjusted_total,
+            } => invalid_resolve_dispute_fields(
+                payable_uuid,
+                resolution,
+                adjusted_total.map(|total| total.u128()),
+            ),
+            ExecuteMsg::ReassignOracleBulk {
+                old_oracle_address,
+                new_oracle_address,
+                start_after,
+                limit,
+            } => invalid_reassign_oracle_bulk_fields(
+                old_oracle_address,
+                new_oracle_address,
+                start_after.as_deref(),
+                *limit,
+            ),
+            ExecuteMsg::SetQuarantineConfig {
+                flagged_payers,
+                large_payment_thresholds,
+            } => invalid_quarantine_config_fields(
+                flagged_payers,
+                &large_payment_thresholds
+                    .iter()
+                    .map(|threshold| ThresholdFields {
+                        denom: &threshold.denom,
+                        amount: threshold.amount.u128(),
+                    })
+                    .collect::<Vec<ThresholdFields>>(),
+            ),
+            ExecuteMsg::SetRiskBands { risk_bands } => invalid_risk_bands_fields(
+                &risk_bands
+                    .iter()
+                    .map(|band| RiskBandFields {
+                        min_score: band.min_score,
+                        holdback_percent: band
+                            .holdback_percent
+                            .map(|percent| percent.atomics().u128()),
+                        large_payment_threshold: band
+                            .large_payment_threshold
+                            .map(|threshold| threshold.u128()),
+                    })
+                    .collect::<Vec<RiskBandFields>>(),
+            ),
+            ExecuteMsg::SetReferralConfig {
+                fee_share_bps,
+                referrers,
+            } => invalid_referral_config_fields(*fee_share_bps, referrers),
+            ExecuteMsg::GrantPaymentAllowance {
+                payable_uuid,
+                denom,
+                max_total,
+                pull_amount,
+                pull_interval_seconds,
+                first_pull_at,
+                expires_at,
+            } => invalid_payment_allowance_fields(&PaymentAllowanceFields {
+                payable_uuid,
+                denom,
+                max_total: max_total.u128(),
+                pull_amount: pull_amount.u128(),
+                pull_interval_seconds: *pull_interval_seconds,
+                first_pull_at: first_pull_at.map(|time| time.nanos()),
+                expires_at: expires_at.map(|time| time.nanos()),
+            }),
+            ExecuteMsg::PullAllowancePayment {
+                payable_uuid,
+                payer,
+            } => invalid_payable_text_fields(payable_uuid, payer, "payer"),
+            ExecuteMsg::SettleNetting { payable_uuids } => {
+                invalid_settle_netting_fields(payable_uuids)
+            }
+        }
+    }
+}
+
+/// A message sent to query contract config state.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    QueryState {},
+    QueryPayableByScopeId {
+        scope_id: String,
+    },
+    QueryPayableByUuid {
+        payable_uuid: String,
+    },
+    QueryEffectiveFees {
+        payable_type: String,
+    },
+    QueryPayableTimeline {
+        payable_uuid: String,
+    },
+    QueryApiDescriptor {},
+    QueryMetricsSnapshot {},
+    QueryPaymentHistory {
+        payable_uuid: String,
+    },
+    QueryFeeHolidays {},
+    ExportPayments {
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
+    QueryPayableDiff {
+        payable_uuid: String,
+        from_seq: u64,
+        to_seq: u64,
+    },
+    QueryPayableInstallments {
+        payable_uuid: String,
+    },
+    QueryPayableAuthorizedPayers {
+        payable_uuid: String,
+    },
+    QueryQuarantineConfig {},
+    QueryQuarantinedPayments {
+        payable_uuid: String,
+    },
+    QueryOverdueApprovals {
+        limit: Option<u32>,
+    },
+    QueryInstantiateTemplate {},
+    QueryPayableInterest {
+        payable_uuid: String,
+    },
+    QueryRiskBands {},
+    QueryPayableTypeFrozen {
+        payable_type: String,
+    },
+    QueryAllPayables {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryPaymentVelocity {
+        denom: String,
+    },
+    QueryReferrerRevenue {
+        referrer: String,
+    },
+    QueryAdminOverview {},
+    QueryRegistrationQuote {
+        payable_type: String,
+    },
+    QueryPaymentAllowances {
+        payable_uuid: String,
+    },
+    SimulatePayment {
+        payable_uuid: String,
+        amount: Uint128,
+        denom: String,
+        // The account that would send the payment
+        payer: String,
+        refund_overpayment: Option<bool>,
+    },
+    QueryPayments {
+        payable_uuid: Option<String>,
+        payer: Option<String>,
+        // Only payments made strictly after this time are included
+        after_time: Option<Timestamp>,
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
+    QueryPayable {
+        // Either the payable's uuid or the bech32 address of its scope
+        identifier: String,
+    },
+    QueryScopeAttributes {
+        scope_id: String,
+    },
+    QueryEscrowTotals {
+        // Limits the totals to a single payable type, instead of every payable type
+        payable_type: Option<String>,
+    },
+}
+impl FieldValidation for QueryMsg {
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        match self {
+            QueryMsg::QueryState {}
+            | QueryMsg::QueryApiDescriptor {}
+            | QueryMsg::QueryMetricsSnapshot {}
+            | QueryMsg::QueryFeeHolidays {}
+            | QueryMsg::QueryQuarantineConfig {}
+            | QueryMsg::QueryInstantiateTemplate {}
+            | QueryMsg::QueryRiskBands {}
+            | QueryMsg::QueryAdminOverview {} => vec![],
+            QueryMsg::QueryPayableByScopeId { scope_id }
+            | QueryMsg::QueryScopeAttributes { scope_id } => invalid_scope_id_fields(scope_id),
+            QueryMsg::QueryPayableByUuid { payable_uuid }
+            | QueryMsg::QueryPayableTimeline { payable_uuid }
+            | QueryMsg::QueryPaymentHistory { payable_uuid }
+            | QueryMsg::QueryPayableInstallments { payable_uuid }
+            | QueryMsg::QueryPayableAuthorizedPayers { payable_uuid }
+            | QueryMsg::QueryQuarantinedPayments { payable_uuid }
+            | QueryMsg::QueryPaymentAllowances { payable_uuid }
+            | QueryMsg::QueryPayableInterest { payable_uuid } => {
+                invalid_payable_uuid_fields(payable_uuid)
+            }
+            QueryMsg::QueryEffectiveFees { payable_type }
+            | QueryMsg::QueryPayableTypeFrozen { payable_type }
+            | QueryMsg::QueryRegistrationQuote { payable_type } => {
+                invalid_empty_fields(payable_type, "payable_type")
+            }
+            QueryMsg::QueryOverdueApprovals { limit } => {
+                invalid_limit_fields(*limit, MAX_OVERDUE_APPROVALS_LIMIT)
+            }
+            QueryMsg::ExportPayments { start_after, limit } => invalid_page_fields(
+                start_after
+                    .as_ref()
+                    .map(|(payable_uuid, _)| payable_uuid.as_str()),
+                *limit,
+                MAX_EXPORT_PAYMENTS_LIMIT,
+            ),
+            QueryMsg::QueryPayableDiff {
+                payable_uuid,
+                from_seq,
+                to_seq,
+            } => invalid_payable_diff_fields(payable_uuid, *from_seq, *to_seq),
+            QueryMsg::QueryAllPayables { start_after, limit } => {
+                invalid_page_fields(start_after.as_deref(), *limit, MAX_ALL_PAYABLES_LIMIT)
+            }
+            QueryMsg::QueryPaymentVelocity { denom } => invalid_denom_fields(denom),
+            QueryMsg::QueryReferrerRevenue { referrer } => {
+                invalid_empty_fields(referrer, "referrer")
+            }
+            QueryMsg::SimulatePayment {
+                payable_uuid,
+                amount,
+                denom,
+                payer,
+                ..
+            } => invalid_simulate_payment_fields(payable_uuid, amount.u128(), denom, payer),
+            QueryMsg::QueryPayments {
+                payable_uuid,
+                payer,
+                start_after,
+                limit,
+                ..
+            } => invalid_query_payments_fields(
+                payable_uuid.as_deref(),
+                payer.as_deref(),
+                start_after
+                    .as_ref()
+                    .map(|(payable_uuid, _)| payable_uuid.as_str()),
+                *limit,
+            ),
+            QueryMsg::QueryPayable { identifier } => invalid_payable_identifier_fields(identifier),
+            QueryMsg::QueryEscrowTotals { payable_type } => {
+                payable_type.as_deref().map_or(vec![], |payable_type| {
+                    invalid_empty_fields(payable_type, "payable_type")
+                })
+            }
+        }
+    }
+}
+
+/// The contract configuration returned by QueryState.  This is kept separate from the StateV2
+/// struct held in storage so that the response keeps its shape as the stored state is migrated.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StateQueryResponse {
+    pub contract_name: String,
+    pub onboarding_cost: Uint128,
+    pub onboarding_denom: String,
+    pub fee_collection_address: Addr,
+    pub fee_percent: Decimal,
+    pub is_local: bool,
+    pub payment_batch_window: Option<PaymentBatchWindowResponse>,
+    pub admin: Option<Addr>,
+    pub migration_timelock_seconds: Option<u64>,
+    pub oracle_funds_tolerance: Option<FundsToleranceResponse>,
+    pub minimum_payment_amount: Option<Uint128>,
+    pub is_paused: bool,
+    pub settled_retention_seconds: Option<u64>,
+    pub approval_valid_duration: Option<u64>,
+    pub oracle_approval_sla_seconds: Option<u64>,
+    pub large_payment_threshold: Option<Uint128>,
+    pub payment_reversal_window_seconds: Option<u64>,
+    pub provenance_msg_version: ProvenanceMsgVersion,
+    pub authorized_registrars: Vec<Addr>,
+}
+impl From<StateV2> for StateQueryResponse {
+    fn from(state: StateV2) -> Self {
+        StateQueryResponse {
+            contract_name: state.contract_name,
+            onboarding_cost: state.onboarding_cost,
+            onboarding_denom: state.onboarding_denom,
+            fee_collection_address: state.fee_collection_address,
+            fee_percent: state.fee_percent,
+            is_local: state.is_local,
+            payment_batch_window: state.payment_batch_window.map(Into::into),
+            admin: state.admin,
+            migration_timelock_seconds: state.migration_timelock_seconds,
+            oracle_funds_tolerance: state.oracle_funds_tolerance.map(Into::into),
+            minimum_payment_amount: state.minimum_payment_amount,
+            is_paused: state.is_paused,
+            settled_retention_seconds: state.settled_retention_seconds,
+            approval_valid_duration: state.approval_valid_duration,
+            oracle_approval_sla_seconds: state.oracle_approval_sla_seconds,
+            large_payment_threshold: state.large_payment_threshold,
+            payment_reversal_window_seconds: state.payment_reversal_window_seconds,
+            provenance_msg_version: state.provenance_msg_version,
+            authorized_registrars: state.authorized_registrars,
+        }
+    }
+}
+
+/// The payment batch window reported by QueryState.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentBatchWindowResponse {
+    pub change_threshold: Decimal,
+    pub block_interval: u64,
+}
+impl From<PaymentBatchWindowV1> for PaymentBatchWindowResponse {
+    fn from(window: PaymentBatchWindowV1) -> Self {
+        PaymentBatchWindowResponse {
+            change_threshold: window.change_threshold,
+            block_interval: window.block_interval,
+        }
+    }
+}
+
+/// The oracle funds tolerance reported by QueryState.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FundsToleranceResponse {
+    pub denom: String,
+    pub max_amount: Uint128,
+}
+impl From<FundsToleranceV1> for FundsToleranceResponse {
+    fn from(tolerance: FundsToleranceV1) -> Self {
+        FundsToleranceResponse {
+            denom: tolerance.denom,
+            max_amount: tolerance.max_amount,
+        }
+    }
+}
+
+/// A payable returned by QueryPayable, QueryPayableByUuid, and QueryPayableByScopeId.  This is
+/// kept separate from the PayableScopeAttribute written to each scope so that the response keeps
+/// its shape as the attribute changes, and every field is always present, even when it is empty.
+/// The custom fields, denom display metadata, and priority fee are held in local storage rather
+/// than on the attribute, so they are left empty by the conversion and filled in by the queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableQueryResponse {
+    pub payable_type: String,
+    pub payable_uuid: String,
+    pub scope_id: String,
+    pub oracle_address: Addr,
+    pub payable_denom: String,
+    pub payable_total_owed: Uint128,
+    pub payable_remaining_owed: Uint128,
+    pub oracle_approved: bool,
+    pub oracle_approval_context: Option<OracleApprovalContextResponse>,
+    pub expiration_time: Option<Timestamp>,
+    pub custom_fields: Option<Binary>,
+    pub payable_denom_metadata: Option<DenomDisplayMetadataResponse>,
+    pub priority_fee: Option<Uint128>,
+    pub is_written_off: bool,
+    pub write_off_reason: Option<String>,
+    pub payee: Option<Addr>,
+    pub accepted_denoms: Vec<String>,
+    pub payment_schedule: Vec<PaymentInstallmentResponse>,
+    pub is_declined: bool,
+    pub late_fee: Option<LateFeeResponse>,
+    pub late_fee_accrual: Option<AccrualResponse>,
+    pub dispute: Option<PayableDisputeResponse>,
+    pub settled_at: Option<Timestamp>,
+    pub interest: Option<InterestTermsResponse>,
+    pub interest_accrual: Option<AccrualResponse>,
+    pub is_frozen: bool,
+}
+impl From<PayableScopeAttribute> for PayableQueryResponse {
+    fn from(attribute: PayableScopeAttribute) -> Self {
+        PayableQueryResponse {
+            payable_type: attribute.payable_type,
+            payable_uuid: attribute.payable_uuid,
+            scope_id: attribute.scope_id,
+            oracle_address: attribute.oracle_address,
+            payable_denom: attribute.payable_denom,
+            payable_total_owed: attribute.payable_total_owed,
+            payable_remaining_owed: attribute.payable_remaining_owed,
+            oracle_approved: attribute.oracle_approved,
+            oracle_approval_context: attribute.oracle_approval_context.map(Into::into),
+            expiration_time: attribute.expiration_time,
+            custom_fields: None,
+            payable_denom_metadata: None,
+            priority_fee: None,
+            is_written_off: attribute.is_written_off,
+            write_off_reason: attribute.write_off_reason,
+            payee: attribute.payee,
+            accepted_denoms: attribute.accepted_denoms,
+            payment_schedule: attribute
+                .payment_schedule
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            is_declined: attribute.is_declined,
+            late_fee: attribute.late_fee.map(Into::into),
+            late_fee_accrual: attribute.late_fee_accrual.map(Into::into),
+            dispute: attribute.dispute.map(Into::into),
+            settled_at: attribute.settled_at,
+            interest: attribute.interest.map(Into::into),
+            interest_accrual: attribute.interest_accrual.map(Into::into),
+            is_frozen: attribute.is_frozen,
+        }
+    }
+}
+
+/// The details of a payable's oracle approval reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OracleApprovalContextResponse {
+    pub oracle_address: Addr,
+    pub block_height: u64,
+    pub external_reference_id: Option<String>,
+    pub delegate_address: Option<Addr>,
+    pub approved_at: Option<Timestamp>,
+    pub risk_score: Option<u32>,
+    pub approval_valid_for: Option<u64>,
+}
+impl From<OracleApprovalContextV1> for OracleApprovalContextResponse {
+    fn from(context: OracleApprovalContextV1) -> Self {
+        OracleApprovalContextResponse {
+            oracle_address: context.oracle_address,
+            block_height: context.block_height,
+            external_reference_id: context.external_reference_id,
+            delegate_address: context.delegate_address,
+            approved_at: context.approved_at,
+            risk_score: context.risk_score,
+            approval_valid_for: context.approval_valid_for,
+        }
+    }
+}
+
+/// The display information for a payable's denom reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DenomDisplayMetadataResponse {
+    pub symbol: String,
+    pub exponent: u32,
+}
+impl From<DenomDisplayMetadataV1> for DenomDisplayMetadataResponse {
+    fn from(metadata: DenomDisplayMetadataV1) -> Self {
+        DenomDisplayMetadataResponse {
+            symbol: metadata.symbol,
+            exponent: metadata.exponent,
+        }
+    }
+}
+
+/// An installment of a payable's schedule reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentInstallmentResponse {
+    pub due_time: Timestamp,
+    pub amount: Uint128,
+}
+impl From<PaymentInstallmentV1> for PaymentInstallmentResponse {
+    fn from(installment: PaymentInstallmentV1) -> Self {
+        PaymentInstallmentResponse {
+            due_time: installment.due_time,
+            amount: installment.amount,
+        }
+    }
+}
+
+/// A payable's late fee terms reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LateFeeResponse {
+    pub due_time: Timestamp,
+    pub daily_rate: Decimal,
+}
+impl From<LateFeeV1> for LateFeeResponse {
+    fn from(late_fee: LateFeeV1) -> Self {
+        LateFeeResponse {
+            due_time: late_fee.due_time,
+            daily_rate: late_fee.daily_rate,
+        }
+    }
+}
+
+/// A payable's interest terms reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterestTermsResponse {
+    pub start_time: Timestamp,
+    pub annual_rate: Decimal,
+    pub compounding: InterestCompounding,
+}
+impl From<InterestTermsV1> for InterestTermsResponse {
+    fn from(interest: InterestTermsV1) -> Self {
+        InterestTermsResponse {
+            start_time: interest.start_time,
+            annual_rate: interest.annual_rate,
+            compounding: interest.compounding,
+        }
+    }
+}
+
+/// The late fees or interest charged against a payable, reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccrualResponse {
+    pub accrued_through: Timestamp,
+    pub amount_accrued: Uint128,
+    pub amount_paid: Uint128,
+}
+impl From<LateFeeAccrualV1> for AccrualResponse {
+    fn from(accrual: LateFeeAccrualV1) -> Self {
+        AccrualResponse {
+            accrued_through: accrual.accrued_through,
+            amount_accrued: accrual.amount_accrued,
+            amount_paid: accrual.amount_paid,
+        }
+    }
+}
+impl From<InterestAccrualV1> for AccrualResponse {
+    fn from(accrual: InterestAccrualV1) -> Self {
+        AccrualResponse {
+            accrued_through: accrual.accrued_through,
+            amount_accrued: accrual.amount_accrued,
+            amount_paid: accrual.amount_paid,
+        }
+    }
+}
+
+/// A dispute raised against a payable, reported by the payable queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableDisputeResponse {
+    pub raised_by: Addr,
+    pub reason: String,
+    pub raised_at: Timestamp,
+}
+impl From<PayableDisputeV1> for PayableDisputeResponse {
+    fn from(dispute: PayableDisputeV1) -> Self {
+        PayableDisputeResponse {
+            raised_by: dispute.raised_by,
+            reason: dispute.reason,
+            raised_at: dispute.raised_at,
+        }
+    }
+}
+
+/// The onboarding cost that a registrant would be charged for a payable, and how it is split
+/// between the fee collection address and the oracle.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EffectiveFeesResponse {
+    // Whether registering a payable total will fail if it exceeds its marker denom's total supply
+    pub is_supply_checked: bool,
+    pub onboarding_cost: Uint128,
+    pub onboarding_denom: String,
+    pub fee_amount: Uint128,
+    pub oracle_amount: Uint128,
+    pub fee_holiday_id: Option<String>,
+}
+
+/// A flat list of contract metrics, named in the Prometheus exposition style so that an off-chain
+/// exporter can scrape the list without any per-metric handling.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetricsSnapshotResponse {
+    pub block_height: u64,
+    pub metrics: Vec<MetricValue>,
+}
+
+/// A single metric within a snapshot.  Labels are embedded in the name, ex:
+/// contract_balance{denom="nhash"}.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetricValue {
+    pub name: String,
+    pub value: Uint128,
+}
+
+/// Every fee holiday that is in effect or scheduled to begin, ordered by start time.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeHolidaysResponse {
+    pub fee_holidays: Vec<FeeHolidayV1>,
+}
+
+/// Every payment recorded against a payable, in the order that they were made.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentHistoryResponse {
+    pub payable_uuid: String,
+    pub payments: Vec<PaymentRecordV1>,
+}
+
+/// A single payment recorded against a payable, keyed by the cursor that locates it in an export.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExportedPaymentV1 {
+    pub payable_uuid: String,
+    pub sequence: u64,
+    pub payment: PaymentRecordV1,
+}
+
+/// A page of payments recorded across all payables, in payable uuid and then sequence order.  When
+/// the page is full, next_start_after holds the cursor to provide to retrieve the next page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExportPaymentsResponse {
+    pub payments: Vec<ExportedPaymentV1>,
+    pub next_start_after: Option<(String, u64)>,
+}
+
+/// A single payable field whose value differs between two snapshots, rendered as strings.  A value
+/// of None indicates that the field was unset in that snapshot.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableFieldChangeV1 {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// The fields of a payable that changed between two of its snapshots, along with the sequence of
+/// the most recent snapshot so that callers know the range available to compare.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableDiffResponse {
+    pub payable_uuid: String,
+    pub from_seq: u64,
+    pub from_taken_at: Timestamp,
+    pub to_seq: u64,
+    pub to_taken_at: Timestamp,
+    pub latest_seq: u64,
+    pub changes: Vec<PayableFieldChangeV1>,
+}
+
+/// The progress of a single installment in a payable's payment schedule.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstallmentStatusV1 {
+    pub due_time: Timestamp,
+    pub amount: Uint128,
+    pub amount_paid: Uint128,
+    pub is_overdue: bool,
+}
+
+/// Every installment in a payable's payment schedule, in due order, with the total amount that is
+/// overdue as of the block time of the query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableInstallmentsResponse {
+    pub payable_uuid: String,
+    pub installments: Vec<InstallmentStatusV1>,
+    pub overdue_amount: Uint128,
+}
+
+/// The addresses allowed to make payments against a payable.  Empty when the payable was registered
+/// without an allowlist, in which case any address may pay it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableAuthorizedPayersResponse {
+    pub payable_uuid: String,
+    pub is_restricted: bool,
+    pub authorized_payers: Vec<Addr>,
+}
+
+/// The heuristics that suspicious payments are screened with.  Absent when quarantine is disabled.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuarantineConfigResponse {
+    pub quarantine_config: Option<QuarantineConfigV1>,
+}
+
+/// A payment held in quarantine, keyed on its id in its payable's payment history.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuarantinedPaymentEntryV1 {
+    pub payment_id: u64,
+    pub payment: QuarantinedPaymentV1,
+}
+
+/// Every payment made against a payable that is still held in quarantine, in the order that they
+/// were made.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuarantinedPaymentsResponse {
+    pub payable_uuid: String,
+    pub quarantined_payments: Vec<QuarantinedPaymentEntryV1>,
+}
+
+/// Every payer's standing allowance for the contract to pull scheduled payments against a payable,
+/// in payer order.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentAllowancesResponse {
+    pub payable_uuid: String,
+    pub allowances: Vec<PaymentAllowanceV1>,
+}
+
+/// A payable that has been awaiting oracle approval for longer than the contract's approval SLA.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OverdueApprovalV1 {
+    pub payable_uuid: String,
+    pub oracle_address: Addr,
+    pub registered_at: Timestamp,
+    // The number of seconds that have passed since the SLA was breached
+    pub overdue_seconds: u64,
+}
+
+/// The payables whose oracle approval is overdue, longest waiting first, along with the total
+/// number overdue in case the limit truncated the list.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OverdueApprovalsResponse {
+    pub oracle_approval_sla_seconds: u64,
+    pub total_overdue: u64,
+    pub overdue_approvals: Vec<OverdueApprovalV1>,
+}
+
+/// The interest charged against a payable as of the block time of the query, including interest
+/// that has accrued since the last payment but has not yet been added to the amount owed.  Payables
+/// registered without interest terms accrue no interest.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableInterestResponse {
+    pub payable_uuid: String,
+    pub interest: Option<InterestTermsV1>,
+    pub principal_remaining: Uint128,
+    pub interest_accrued: Uint128,
+    pub interest_paid: Uint128,
+    pub interest_outstanding: Uint128,
+}
+
+/// The bands that payments are currently keyed off by their payable's risk score, ordered by their
+/// minimum score.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RiskBandsResponse {
+    pub risk_bands: Vec<RiskBandV1>,
+}
+
+/// Whether or not payables of a payable type are frozen by the contract admin.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableTypeFrozenResponse {
+    pub payable_type: String,
+    pub frozen: bool,
+}
+
+/// A payable registered with the contract.  The scope attribute is omitted if it can no longer be
+/// found on the payable's scope.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegisteredPayableV1 {
+    pub payable_uuid: String,
+    pub scope_id: String,
+    pub scope_attribute: Option<PayableScopeAttribute>,
+}
+
+/// A page of payables registered with the contract, in payable uuid order.  When the page is full,
+/// next_start_after holds the cursor to provide to retrieve the next page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AllPayablesResponse {
+    pub payables: Vec<RegisteredPayableV1>,
+    pub next_start_after: Option<String>,
+}
+
+/// The number and total amount of payments made in a denom over a recent window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentVelocityWindowV1 {
+    pub window_seconds: u64,
+    pub payment_count: u64,
+    pub payment_volume: Uint128,
+}
+
+/// The payment velocity of a denom over recent windows, ordered from shortest to longest, alongside
+/// the denom's payment volume cap and the volume observed in its current cap window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentVelocityResponse {
+    pub denom: String,
+    pub windows: Vec<PaymentVelocityWindowV1>,
+    pub volume_cap: Option<PaymentVolumeCapV1>,
+    pub volume_cap_window: Option<PaymentVolumeWindowV1>,
+}
+
+/// The revenue share that a referrer has been paid for the registrations it referred, in each
+/// onboarding denom, alongside whether it can currently be named as a referrer and the share of the
+/// collected fee that it would be paid.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferrerRevenueResponse {
+    pub referrer: String,
+    pub is_referrer: bool,
+    pub fee_share_bps: Option<u64>,
+    pub revenue: Vec<ReferrerRevenueV1>,
+}
+
+/// A payment volume cap whose breaker has tripped, rejecting payments in its denom until the window
+/// ends or the admin resets it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TrippedVolumeCapV1 {
+    pub denom: String,
+    pub volume: Uint128,
+    pub window_end: Timestamp,
+}
+
+/// The contract's control plane as of the block time of the query: its configuration and the roles
+/// that it grants, the optional features that it has enabled, the proposals awaiting a decision,
+/// and the breakers that are halting activity.  Allows operational tooling to render the contract
+/// from a single query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AdminOverviewResponse {
+    // Includes the admin, the authorized registrars, and whether or not the contract is paused
+    pub state: StateV2,
+    pub enabled_features: Vec<String>,
+    // Includes the partners that registrations may name as their referrer
+    pub referral_config: Option<ReferralConfigV1>,
+    pub quarantine_config: Option<QuarantineConfigV1>,
+    pub staged_migration: Option<StagedMigrationV1>,
+    // Payments above the large payment threshold that await their oracle's approval
+    pub proposed_payment_count: u64,
+    // Payments that await the admin's release or refund
+    pub quarantined_payment_count: u64,
+    pub frozen_payable_types: Vec<String>,
+    pub tripped_volume_caps: Vec<TrippedVolumeCapV1>,
+}
+
+/// The exact funds that a registration of a payable type must send at the block time of the query,
+/// and how they are split between the fee collector and the oracle.  Any priority fee or oracle gas
+/// rebate named by the registration is charged on top of the onboarding cost.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegistrationQuoteResponse {
+    pub payable_type: String,
+    // Registrations of unsupported payable types are rejected regardless of the funds sent
+    pub is_supported: bool,
+    pub onboarding_cost: Uint128,
+    pub onboarding_denom: String,
+    pub fee_amount: Uint128,
+    pub oracle_amount: Uint128,
+    pub fee_bps: Uint128,
+    pub fee_holiday_id: Option<String>,
+    // The payable type's overrides of the contract's onboarding cost, denom, and fee percent
+    pub payable_type_config: Option<PayableTypeConfigV1>,
+}
+
+/// A page of payments matching a payment query's filters, in payable uuid and then sequence order.
+/// When the scan behind the page stopped before the end of the payment history, next_start_after
+/// holds the cursor to provide to continue it.  A page can be partial, or even empty, while a
+/// cursor is still returned.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentsResponse {
+    pub payments: Vec<ExportedPaymentV1>,
+    pub next_start_after: Option<(String, u64)>,
+}
+
+/// The result of running a payment through make_payment without changing state.  Exactly one of
+/// the outcome and the error is present.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentSimulationResponse {
+    pub payable_uuid: String,
+    pub outcome: Option<SimulatedPaymentV1>,
+    pub error: Option<SimulatedPaymentErrorV1>,
+}
+
+/// The state that an accepted payment would leave its payable in.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulatedPaymentV1 {
+    pub remaining_owed: Uint128,
+    // The portion of the funds returned to the payer, only non-zero for overpayments with refunds
+    // requested
+    pub refund: Uint128,
+    pub payee: Addr,
+}
+
+/// The error that make_payment would reject a payment with.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulatedPaymentErrorV1 {
+    // The name of the contract error, ex: PaymentTooLarge
+    pub kind: String,
+    pub message: String,
+}
+
+/// Every attribute found on a scope under the contract's name, as they are stored.  A registered
+/// payable's scope holds exactly one, so any other count indicates that the attributes have drifted
+/// from what the contract wrote.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScopeAttributesResponse {
+    pub scope_id: String,
+    // The contract name that the attributes are stored under
+    pub attribute_name: String,
+    pub attribute_count: u32,
+    pub attributes: Vec<RawScopeAttributeV1>,
+}
+
+/// A single attribute found on a scope under the contract's name.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RawScopeAttributeV1 {
+    pub value_type: AttributeValueType,
+    // The attribute's value as text.  Any bytes that are not valid UTF-8 are replaced
+    pub raw_json: String,
+    // The uuid of the payable that the value describes, when it is a valid payable attribute
+    pub payable_uuid: Option<String>,
+    // Why the value could not be read as a payable attribute, when it is not a valid one
+    pub parse_error: Option<String>,
+}
+
+/// The payments held in escrow by the contract, totalled per payable type and denom, alongside the
+/// sum of every listed total in each denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EscrowTotalsResponse {
+    pub escrow_totals: Vec<EscrowTotalV1>,
+    pub denom_totals: Vec<Coin>,
+}
+
+/// Migrate the contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrateMsg {
+    pub onboarding_cost: Option<String>,
+    pub onboarding_denom: Option<String>,
+    pub fee_collection_address: Option<String>,
+    pub fee_percent: Option<Decimal>,
+    pub is_local: Option<bool>,
+    pub payment_batch_window: Option<PaymentBatchWindowV1>,
+    pub disable_payment_batching: Option<bool>,
+    pub admin_address: Option<String>,
+    pub migration_timelock_seconds: Option<u64>,
+    pub disable_migration_timelock: Option<bool>,
+    pub oracle_funds_tolerance: Option<FundsToleranceV1>,
+    pub disable_oracle_funds_tolerance: Option<bool>,
+    pub minimum_payment_amount: Option<Uint128>,
+    pub disable_minimum_payment: Option<bool>,
+    pub settled_retention_seconds: Option<u64>,
+    pub disable_settled_pruning: Option<bool>,
+    pub approval_valid_duration: Option<u64>,
+    pub disable_approval_expiry: Option<bool>,
+    pub oracle_approval_sla_seconds: Option<u64>,
+    pub disable_oracle_approval_sla: Option<bool>,
+    pub large_payment_threshold: Option<Uint128>,
+    pub disable_large_payment_threshold: Option<bool>,
+    pub payment_reversal_window_seconds: Option<u64>,
+    pub disable_payment_reversal: Option<bool>,
+    pub provenance_msg_version: Option<ProvenanceMsgVersion>,
+    // Replaces the authorized registrars.  An empty list removes all of them
+    pub authorized_registrars: Option<Vec<String>>,
+}
+impl FieldValidation for MigrateMsg {
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        invalid_migrate_fields(&MigrateFields {
+            onboarding_cost: self.onboarding_cost.as_deref(),
+            onboarding_denom: self.onboarding_denom.as_deref(),
+            fee_collection_address: self.fee_collection_address.as_deref(),
+            fee_percent: self
+                .fee_percent
+                .map(|fee_percent| fee_percent.atomics().u128()),
+            payment_batch_window: self
+                .payment_batch_window
+                .as_ref()
+                .map(|window| window.fields()),
+            disable_payment_batching: self.disable_payment_batching == Some(true),
+            admin_address: self.admin_address.as_deref(),
+            migration_timelock_seconds: self.migration_timelock_seconds,
+            disable_migration_timelock: self.disable_migration_timelock == Some(true),
+            oracle_funds_tolerance: self
+                .oracle_funds_tolerance
+                .as_ref()
+                .map(|tolerance| tolerance.fields()),
+            disable_oracle_funds_tolerance: self.disable_oracle_funds_tolerance == Some(true),
+            minimum_payment_amount: self.minimum_payment_amount.map(|amount| amount.u128()),
+            disable_minimum_payment: self.disable_minimum_payment == Some(true),
+            settled_retention_seconds: self.settled_retention_seconds,
+            disable_settled_pruning: self.disable_settled_pruning == Some(true),
+            approval_valid_duration: self.approval_valid_duration,
+            disable_approval_expiry: self.disable_approval_expiry == Some(true),
+            oracle_approval_sla_seconds: self.oracle_approval_sla_seconds,
+            disable_oracle_approval_sla: self.disable_oracle_approval_sla == Some(true),
+            large_payment_threshold: self.large_payment_threshold.map(|amount| amount.u128()),
+            disable_large_payment_threshold: self.disable_large_payment_threshold == Some(true),
+            payment_reversal_window_seconds: self.payment_reversal_window_seconds,
+            disable_payment_reversal: self.disable_payment_reversal == Some(true),
+            authorized_registrars: self.authorized_registrars.as_deref(),
+        })
+    }
+}
+impl MigrateMsg {
+    /// Converts the message from a contract message into a parsed and converted message for
+    /// processing in downstream migration code.
+    pub fn to_migrate_contract_v2<T: CustomQuery>(
+        self,
+        deps: &Deps<T>,
+    ) -> Result<MigrateContractV2, ContractError> {
+        // Unbox mapped fields, convert and validate, and re-box if necessary. Otherwise,
+        // pass-through to None
+        let onboarding_cost = if let Some(cost) = self.onboarding_cost {
+            Some(to_uint128(cost)?)
+        } else {
+            None
+        };
+        let fee_collection_address = if let Some(fee_addr) = self.fee_collection_address {
+            Some(deps.api.addr_validate(fee_addr.as_str())?)
+        } else {
+            None
+        };
+        let admin_address = if let Some(admin_addr) = self.admin_address {
+            Some(deps.api.addr_validate(admin_addr.as_str())?)
+        } else {
+            None
+        };
+        let authorized_registrars = if let Some(registrars) = self.authorized_registrars {
+            Some(
+                registrars
+                    .iter()
+                    .map(|registrar| deps.api.addr_validate(registrar))
+                    .collect::<StdResult<Vec<Addr>>>()?,
+            )
+        } else {
+            None
+        };
+        Ok(MigrateContractV2 {
+            onboarding_cost,
+            onboarding_denom: self.onboarding_denom,
+            fee_collection_address,
+            fee_percent: self.fee_percent,
+            is_local: self.is_local,
+            payment_batch_window: self.payment_batch_window,
+            disable_payment_batching: self.disable_payment_batching,
+            admin_address,
+            migration_timelock_seconds: self.migration_timelock_seconds,
+            disable_migration_timelock: self.disable_migration_timelock,
+            oracle_funds_tolerance: self.oracle_funds_tolerance,
+            disable_oracle_funds_tolerance: self.disable_oracle_funds_tolerance,
+            minimum_payment_amount: self.minimum_payment_amount,
+            disable_minimum_payment: self.disable_minimum_payment,
+            settled_retention_seconds: self.settled_retention_seconds,
+            disable_settled_pruning: self.disable_settled_pruning,
+            approval_valid_duration: self.approval_valid_duration,
+            disable_approval_expiry: self.disable_approval_expiry,
+            oracle_approval_sla_seconds: self.oracle_approval_sla_seconds,
+            disable_oracle_approval_sla: self.disable_oracle_approval_sla,
+            large_payment_threshold: self.large_payment_threshold,
+            disable_large_payment_threshold: self.disable_large_payment_threshold,
+            payment_reversal_window_seconds: self.payment_reversal_window_seconds,
+            disable_payment_reversal: self.disable_payment_reversal,
+            provenance_msg_version: self.provenance_msg_version,
+            authorized_registrars,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg::{MakePayment, OracleApproval};
+    use crate::core::msg::QueryMsg::{
+        ExportPayments, QueryAllPayables, QueryEffectiveFees, QueryEscrowTotals,
+        QueryOverdueApprovals, QueryPayable, QueryPayableByUuid, QueryPayableDiff,
+        QueryPayableTypeFrozen, QueryPaymentVelocity, QueryPayments, QueryRegistrationQuote,
+        QueryScopeAttributes, QueryState, SimulatePayment,
+    };
+    use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg};
+    use crate::core::state::{
+        FundsToleranceV1, InterestTermsV1, LateFeeV1, PayableMetaPatchV1, PayeeSplitV1,
+        PaymentBatchWindowV1, PaymentInstallmentV1, RegistrantRequirementV1, RiskBandV1,
+    };
+    use crate::execute::make_batch_payment::BatchPaymentV1;
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::util::interest::{InterestCompounding, SECONDS_PER_YEAR};
+    use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+    use crate::util::traits::ValidatedMsg;
+    use crate::validation::execute::{
+        MAX_DENOM_DISPLAY_EXPONENT, MAX_EXTERNAL_REFERENCE_ID_LENGTH,
+        MAX_EXTERNAL_SETTLEMENT_REFERENCE_LENGTH, MAX_NETTING_PAYABLES, MAX_PAYMENT_MEMO_LENGTH,
+        MAX_REASSIGN_ORACLE_LIMIT, MAX_REFERRAL_FEE_SHARE_BPS, MAX_REFERRERS, MAX_RISK_BANDS,
+        MAX_RISK_SCORE, MIN_ALLOWANCE_PULL_INTERVAL_SECONDS,
+    };
+    use crate::validation::query::{
+        MAX_ALL_PAYABLES_LIMIT, MAX_EXPORT_PAYMENTS_LIMIT, MAX_OVERDUE_APPROVALS_LIMIT,
+        MAX_QUERY_PAYMENTS_LIMIT,
+    };
+    use crate::validation::register::{
+        MAX_BATCH_REGISTRATIONS, MAX_CUSTOM_FIELDS_BYTES, MAX_PAYEE_SPLITS,
+        MAX_REGISTRATION_NONCE_LENGTH,
+    };
+    use cosmwasm_std::{coin, Binary, Coin, Decimal, Timestamp, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::PartyType;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_valid_init_msg() {
+        get_valid_init_msg()
+            .validate()
+            .expect("a populated init msg should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_contract_name() {
+        let mut msg = get_valid_init_msg();
+        // Empty string bad
+        msg.contract_name = String::new();
+        test_invalid_msg(&msg, "contract_name");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_onboarding_cost() {
+        let mut msg = get_valid_init_msg();
+        // Non-numbers bad
         msg.onboarding_cost = "word".to_string();
         test_invalid_msg(&msg, "onboarding_cost");
-        // Negative numbers bad
-        msg.onboarding_cost = "-1".to_string();
+        // Negative numbers bad
+        msg.onboarding_cost = "-1".to_string();
+        test_invalid_msg(&msg, "onboarding_cost");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_fee_collection_address() {
+        let mut msg = get_valid_init_msg();
+        // Empty string bad
+        msg.fee_collection_address = String::new();
+        test_invalid_msg(&msg, "fee_collection_address");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_fee_percent() {
+        let mut msg = get_valid_init_msg();
+        // Over 100% bad
+        msg.fee_percent = Decimal::percent(101);
+        test_invalid_msg(&msg, "fee_percent");
+        let mut msg = get_valid_init_msg();
+        // A split that leaves sub-unit dust bad
+        msg.fee_percent = Decimal::from_str("0.3333").unwrap();
+        test_invalid_msg(&msg, "fee_percent");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_migration_timelock_seconds() {
+        let mut msg = get_valid_init_msg();
+        // Zero timelock bad
+        msg.migration_timelock_seconds = Some(0);
+        test_invalid_msg(&msg, "migration_timelock_seconds");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_minimum_payment_amount() {
+        let mut msg = get_valid_init_msg();
+        // Zero minimum bad - omit the minimum instead
+        msg.minimum_payment_amount = Some(Uint128::zero());
+        test_invalid_msg(&msg, "minimum_payment_amount");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_approval_valid_duration() {
+        let mut msg = get_valid_init_msg();
+        // Zero duration bad - omit the duration instead
+        msg.approval_valid_duration = Some(0);
+        test_invalid_msg(&msg, "approval_valid_duration");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_oracle_approval_sla_seconds() {
+        let mut msg = get_valid_init_msg();
+        // Zero SLA bad - omit the SLA instead
+        msg.oracle_approval_sla_seconds = Some(0);
+        test_invalid_msg(&msg, "oracle_approval_sla_seconds");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_large_payment_threshold() {
+        let mut msg = get_valid_init_msg();
+        // Zero threshold bad - omit the threshold instead
+        msg.large_payment_threshold = Some(Uint128::zero());
+        test_invalid_msg(&msg, "large_payment_threshold");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_payment_reversal_window_seconds() {
+        let mut msg = get_valid_init_msg();
+        // Zero window bad - omit the window instead
+        msg.payment_reversal_window_seconds = Some(0);
+        test_invalid_msg(&msg, "payment_reversal_window_seconds");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_authorized_registrars() {
+        let mut msg = get_valid_init_msg();
+        msg.authorized_registrars = Some(vec![String::new()]);
+        test_invalid_msg(&msg, "authorized_registrars");
+    }
+
+    #[test]
+    fn test_invalid_init_msg_payment_batch_window() {
+        let mut msg = get_valid_init_msg();
+        // Zero threshold bad
+        msg.payment_batch_window = Some(PaymentBatchWindowV1 {
+            change_threshold: Decimal::zero(),
+            block_interval: 10,
+        });
+        test_invalid_msg(&msg, "payment_batch_window");
+        // Over 100% threshold bad
+        msg.payment_batch_window = Some(PaymentBatchWindowV1 {
+            change_threshold: Decimal::percent(101),
+            block_interval: 10,
+        });
+        test_invalid_msg(&msg, "payment_batch_window");
+        // Zero block interval bad
+        msg.payment_batch_window = Some(PaymentBatchWindowV1 {
+            change_threshold: Decimal::percent(10),
+            block_interval: 0,
+        });
+        test_invalid_msg(&msg, "payment_batch_window");
+    }
+
+    #[test]
+    fn test_valid_execute_register_payable() {
+        get_valid_register_payable()
+            .to_enum()
+            .validate()
+            .expect("a populated register payable should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_payable_type() {
+        let mut msg = get_valid_register_payable();
+        // Empty string bad
+        msg.payable_type = String::new();
+        test_invalid_msg(&msg.to_enum(), "payable_type");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_payable_uuid() {
+        let mut msg = get_valid_register_payable();
+        // Empty string bad
+        msg.payable_uuid = String::new();
+        test_invalid_msg(&msg.to_enum(), "payable_uuid");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_scope_id() {
+        let mut msg = get_valid_register_payable();
+        // Empty string bad
+        msg.scope_id = String::new();
+        test_invalid_msg(&msg.to_enum(), "scope_id");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_payable_denom() {
+        let mut msg = get_valid_register_payable();
+        // Empty string bad
+        msg.payable_denom = String::new();
+        test_invalid_msg(&msg.to_enum(), "payable_denom");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_payable_total() {
+        let mut msg = get_valid_register_payable();
+        // Zero bad
+        msg.payable_total = Uint128::zero();
+        test_invalid_msg(&msg.to_enum(), "payable_total");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_custom_fields() {
+        let mut msg = get_valid_register_payable();
+        // Empty binary bad
+        msg.custom_fields = Some(Binary::default());
+        test_invalid_msg(&msg.to_enum(), "custom_fields");
+        let mut msg = get_valid_register_payable();
+        // Too large bad
+        msg.custom_fields = Some(Binary::from(vec![0u8; MAX_CUSTOM_FIELDS_BYTES + 1]));
+        test_invalid_msg(&msg.to_enum(), "custom_fields");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_priority_fee() {
+        let mut msg = get_valid_register_payable();
+        // Zero bad - omit the fee instead
+        msg.priority_fee = Some(Uint128::zero());
+        test_invalid_msg(&msg.to_enum(), "priority_fee");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_oracle_gas_rebate() {
+        let mut msg = get_valid_register_payable();
+        // Zero bad - omit the rebate instead
+        msg.oracle_gas_rebate = Some(Uint128::zero());
+        test_invalid_msg(&msg.to_enum(), "oracle_gas_rebate");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_minimum_payment_amount() {
+        let mut msg = get_valid_register_payable();
+        // Zero bad - omit the minimum instead
+        msg.minimum_payment_amount = Some(Uint128::zero());
+        test_invalid_msg(&msg.to_enum(), "minimum_payment_amount");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_accepted_denoms() {
+        let mut msg = get_valid_register_payable();
+        // Empty bad - omit the accepted denoms instead
+        msg.accepted_denoms = Some(vec![]);
+        test_invalid_msg(&msg.to_enum(), "accepted_denoms");
+        let mut msg = get_valid_register_payable();
+        msg.accepted_denoms = Some(vec![String::new()]);
+        test_invalid_msg(&msg.to_enum(), "accepted_denoms");
+        // The payable denom is always accepted, so repeating it is bad
+        let mut msg = get_valid_register_payable();
+        msg.accepted_denoms = Some(vec![msg.payable_denom.clone()]);
+        test_invalid_msg(&msg.to_enum(), "accepted_denoms");
+        let mut msg = get_valid_register_payable();
+        msg.accepted_denoms = Some(vec!["uusdf".to_string(), "uusdf".to_string()]);
+        test_invalid_msg(&msg.to_enum(), "accepted_denoms");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_payment_schedule() {
+        let mut msg = get_valid_register_payable();
+        // Empty bad - omit the payment schedule instead
+        msg.payment_schedule = Some(vec![]);
+        test_invalid_msg(&msg.to_enum(), "payment_schedule");
+        let mut msg = get_valid_register_payable();
+        msg.payment_schedule = Some(vec![
+            installment(1_600_000_000, 128),
+            installment(1_650_000_000, 0),
+        ]);
+        test_invalid_msg(&msg.to_enum(), "payment_schedule");
+        // Installments must be listed in due order, without sharing a due time
+        let mut msg = get_valid_register_payable();
+        msg.payment_schedule = Some(vec![
+            installment(1_650_000_000, 64),
+            installment(1_600_000_000, 64),
+        ]);
+        test_invalid_msg(&msg.to_enum(), "payment_schedule");
+        let mut msg = get_valid_register_payable();
+        msg.payment_schedule = Some(vec![
+            installment(1_600_000_000, 64),
+            installment(1_600_000_000, 64),
+        ]);
+        test_invalid_msg(&msg.to_enum(), "payment_schedule");
+        // The installments must cover exactly the payable total
+        let mut msg = get_valid_register_payable();
+        msg.payment_schedule = Some(vec![installment(1_600_000_000, 127)]);
+        test_invalid_msg(&msg.to_enum(), "payment_schedule");
+        let mut msg = get_valid_register_payable();
+        msg.payment_schedule = Some(vec![installment(1_600_000_000, 129)]);
+        test_invalid_msg(&msg.to_enum(), "payment_schedule");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_referrer() {
+        let mut msg = get_valid_register_payable();
+        msg.referrer = Some(String::new());
+        test_invalid_msg(&msg.to_enum(), "referrer");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_late_fee() {
+        let late_fee = |due_seconds: u64, daily_rate: Decimal| LateFeeV1 {
+            due_time: Timestamp::from_seconds(due_seconds),
+            daily_rate,
+        };
+        let mut msg = get_valid_register_payable();
+        msg.late_fee = Some(late_fee(1_650_000_000, Decimal::zero()));
+        test_invalid_msg(&msg.to_enum(), "late_fee");
+        let mut msg = get_valid_register_payable();
+        msg.late_fee = Some(late_fee(1_650_000_000, Decimal::percent(101)));
+        test_invalid_msg(&msg.to_enum(), "late_fee");
+        // Late fees that would only begin to accrue once the payable expires could never be paid
+        let mut msg = get_valid_register_payable();
+        msg.late_fee = Some(late_fee(1_700_000_000, Decimal::permille(5)));
+        test_invalid_msg(&msg.to_enum(), "late_fee");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_interest() {
+        let interest = |start_seconds: u64, annual_rate: Decimal| InterestTermsV1 {
+            start_time: Timestamp::from_seconds(start_seconds),
+            annual_rate,
+            compounding: InterestCompounding::Simple,
+        };
+        let mut msg = get_valid_register_payable();
+        msg.interest = Some(interest(1_600_000_000, Decimal::zero()));
+        test_invalid_msg(&msg.to_enum(), "interest");
+        let mut msg = get_valid_register_payable();
+        msg.interest = Some(interest(1_600_000_000, Decimal::percent(101)));
+        test_invalid_msg(&msg.to_enum(), "interest");
+        let mut msg = get_valid_register_payable();
+        msg.interest = Some(interest(1_700_000_000, Decimal::percent(8)));
+        test_invalid_msg(&msg.to_enum(), "interest");
+        // Daily compounding at 100% overflows well before a century of interest can be calculated
+        let mut msg = get_valid_register_payable();
+        msg.expiration_time = Some(Timestamp::from_seconds(
+            1_600_000_000 + 100 * SECONDS_PER_YEAR,
+        ));
+        msg.interest = Some(InterestTermsV1 {
+            compounding: InterestCompounding::Daily,
+            ..interest(1_600_000_000, Decimal::one())
+        });
+        test_invalid_msg(&msg.to_enum(), "interest");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_escrow_timeout_seconds() {
+        let mut msg = get_valid_register_payable();
+        msg.escrow_timeout_seconds = Some(0);
+        test_invalid_msg(&msg.to_enum(), "escrow_timeout_seconds");
+    }
+
+    #[test]
+    #[cfg(not(feature = "escrow"))]
+    fn test_invalid_execute_register_payable_escrow_timeout_seconds_without_escrow() {
+        let mut msg = get_valid_register_payable();
+        msg.escrow_timeout_seconds = Some(86400);
+        test_invalid_msg(&msg.to_enum(), "escrow_timeout_seconds");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_authorized_payers() {
+        let mut msg = get_valid_register_payable();
+        msg.authorized_payers = Some(vec![]);
+        test_invalid_msg(&msg.to_enum(), "authorized_payers");
+        let mut msg = get_valid_register_payable();
+        msg.authorized_payers = Some(vec!["counterparty".to_string(), String::new()]);
+        test_invalid_msg(&msg.to_enum(), "authorized_payers");
+        let mut msg = get_valid_register_payable();
+        msg.authorized_payers = Some(vec!["counterparty".to_string(), "counterparty".to_string()]);
+        test_invalid_msg(&msg.to_enum(), "authorized_payers");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_registration_nonce() {
+        let mut msg = get_valid_register_payable();
+        msg.registration_nonce = Some(String::new());
+        test_invalid_msg(&msg.to_enum(), "registration_nonce");
+        let mut msg = get_valid_register_payable();
+        msg.registration_nonce = Some("n".repeat(MAX_REGISTRATION_NONCE_LENGTH + 1));
+        test_invalid_msg(&msg.to_enum(), "registration_nonce");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payable_malformed_fields() {
+        let mut msg = get_valid_register_payable();
+        msg.payable_uuid = "not-a-uuid".to_string();
+        test_invalid_msg(&msg.to_enum(), "payable_uuid");
+        let mut msg = get_valid_register_payable();
+        // Checksum mismatch
+        msg.scope_id = "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl9".to_string();
+        test_invalid_msg(&msg.to_enum(), "scope_id");
+        let mut msg = get_valid_register_payable();
+        msg.payable_denom = "n hash".to_string();
+        test_invalid_msg(&msg.to_enum(), "payable_denom");
+    }
+
+    #[test]
+    fn test_invalid_execute_register_payables() {
+        let payable = |payable_uuid: &str, scope_id: &str| RegisterPayableV2 {
+            payable_type: "test".to_string(),
+            payable_uuid: payable_uuid.to_string(),
+            scope_id: scope_id.to_string(),
+            oracle_address: "oracle-addr".to_string(),
+            payable_denom: "nhash".to_string(),
+            payable_total: Uint128::new(128),
+            custom_fields: None,
+            priority_fee: None,
+            oracle_gas_rebate: None,
+            minimum_payment_amount: None,
+            expiration_time: None,
+            accepted_denoms: None,
+            payment_schedule: None,
+            late_fee: None,
+            escrow_timeout_seconds: None,
+            authorized_payers: None,
+            interest: None,
+            payee_splits: None,
+            registration_nonce: None,
+            referrer: None,
+        };
+        ExecuteMsg::RegisterPayables {
+            payables: vec![
+                payable(
+                    "86c224de-8f81-11ec-9277-0353b82d7772",
+                    "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+                ),
+                payable(
+                    "4cb54a6e-83ab-11ec-a486-eb4f069082c5",
+                    "scope1qpxt2jnwsw43rm9ysm457p5sstzs6pcylq",
+                ),
+            ],
+        }
+        .validate()
+        .expect("a batch of distinct, populated payables should pass validation");
+        // Empty bad
+        test_invalid_msg(
+            &ExecuteMsg::RegisterPayables { payables: vec![] },
+            "payables",
+        );
+        // Too many bad
+        test_invalid_msg(
+            &ExecuteMsg::RegisterPayables {
+                payables: vec![
+                    payable(
+                        "86c224de-8f81-11ec-9277-0353b82d7772",
+                        "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+                    );
+                    MAX_BATCH_REGISTRATIONS + 1
+                ],
+            },
+            "payables",
+        );
+        // Duplicate payable uuids bad
+        test_invalid_msg(
+            &ExecuteMsg::RegisterPayables {
+                payables: vec![
+                    payable(
+                        "86c224de-8f81-11ec-9277-0353b82d7772",
+                        "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+                    ),
+                    payable(
+                        "86c224de-8f81-11ec-9277-0353b82d7772",
+                        "scope1qpxt2jnwsw43rm9ysm457p5sstzs6pcylq",
+                    ),
+                ],
+            },
+            "payables",
+        );
+        // Duplicate scopes bad
+        test_invalid_msg(
+            &ExecuteMsg::RegisterPayables {
+                payables: vec![
+                    payable(
+                        "86c224de-8f81-11ec-9277-0353b82d7772",
+                        "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+                    ),
+                    payable(
+                        "4cb54a6e-83ab-11ec-a486-eb4f069082c5",
+                        "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+                    ),
+                ],
+            },
+            "payables",
+        );
+        // Each payable is held to the same rules as a single registration
+        test_invalid_msg(
+            &ExecuteMsg::RegisterPayables {
+                payables: vec![payable(
+                    "not-a-uuid",
+                    "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+                )],
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_update_payable_total() {
+        let update = |payable_uuid: &str, new_total: u128| ExecuteMsg::UpdatePayableTotal {
+            payable_uuid: payable_uuid.to_string(),
+            new_total: Uint128::new(new_total),
+        };
+        update("86c224de-8f81-11ec-9277-0353b82d7772", 100)
+            .validate()
+            .expect("a populated update should pass validation");
+        test_invalid_msg(&update("not-a-uuid", 100), "payable_uuid");
+        // Zero bad
+        test_invalid_msg(
+            &update("86c224de-8f81-11ec-9277-0353b82d7772", 0),
+            "new_total",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_change_oracle() {
+        let change = |payable_uuid: &str, new_oracle_address: &str| ExecuteMsg::ChangeOracle {
+            payable_uuid: payable_uuid.to_string(),
+            new_oracle_address: new_oracle_address.to_string(),
+        };
+        change("86c224de-8f81-11ec-9277-0353b82d7772", "new-oracle")
+            .validate()
+            .expect("a populated change should pass validation");
+        test_invalid_msg(&change("not-a-uuid", "new-oracle"), "payable_uuid");
+        test_invalid_msg(
+            &change("86c224de-8f81-11ec-9277-0353b82d7772", ""),
+            "new_oracle_address",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_restructure_payable() {
+        let restructure = |payable_uuid: &str, new_total: u128| ExecuteMsg::RestructurePayable {
+            payable_uuid: payable_uuid.to_string(),
+            new_total: Uint128::new(new_total),
+        };
+        restructure("86c224de-8f81-11ec-9277-0353b82d7772", 100)
+            .validate()
+            .expect("a populated restructure should pass validation");
+        test_invalid_msg(&restructure("not-a-uuid", 100), "payable_uuid");
+        test_invalid_msg(
+            &restructure("86c224de-8f81-11ec-9277-0353b82d7772", 0),
+            "new_total",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_write_off_payable() {
+        let write_off = |payable_uuid: &str, write_off_reason: &str| ExecuteMsg::WriteOffPayable {
+            payable_uuid: payable_uuid.to_string(),
+            write_off_reason: write_off_reason.to_string(),
+        };
+        write_off("86c224de-8f81-11ec-9277-0353b82d7772", "settled")
+            .validate()
+            .expect("a populated write off should pass validation");
+        test_invalid_msg(&write_off("not-a-uuid", "settled"), "payable_uuid");
+        test_invalid_msg(
+            &write_off("86c224de-8f81-11ec-9277-0353b82d7772", ""),
+            "write_off_reason",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_expire_payable() {
+        ExecuteMsg::ExpirePayable {
+            payable_uuid: "86c224de-8f81-11ec-9277-0353b82d7772".to_string(),
+        }
+        .validate()
+        .expect("a valid payable uuid should pass validation");
+        test_invalid_msg(
+            &ExecuteMsg::ExpirePayable {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_oracle_approval() {
+        OracleApproval {
+            payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+            external_reference_id: Some("validation-job-1".to_string()),
+            risk_score: None,
+            approval_valid_for: None,
+        }
+        .validate()
+        .expect("a populated oracle approval should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_execute_oracle_approval_payable_uuid() {
+        test_invalid_msg(
+            &OracleApproval {
+                payable_uuid: String::new(),
+                external_reference_id: None,
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_oracle_approval_external_reference_id() {
+        // Empty bad - omit the reference id instead
+        test_invalid_msg(
+            &OracleApproval {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                external_reference_id: Some(String::new()),
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            "external_reference_id",
+        );
+        // Too long bad
+        test_invalid_msg(
+            &OracleApproval {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                external_reference_id: Some("a".repeat(MAX_EXTERNAL_REFERENCE_ID_LENGTH + 1)),
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            "external_reference_id",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_oracle_approval_approval_valid_for() {
+        test_invalid_msg(
+            &OracleApproval {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                external_reference_id: None,
+                risk_score: None,
+                approval_valid_for: Some(0),
+            },
+            "approval_valid_for",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_oracle_approval_risk_score() {
+        let approval = |risk_score: u32| OracleApproval {
+            payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+            external_reference_id: None,
+            risk_score: Some(risk_score),
+            approval_valid_for: None,
+        };
+        approval(MAX_RISK_SCORE)
+            .validate()
+            .expect("the highest risk score should pass validation");
+        test_invalid_msg(&approval(MAX_RISK_SCORE + 1), "risk_score");
+    }
+
+    #[test]
+    fn test_invalid_execute_oracle_delegates() {
+        ExecuteMsg::AddOracleDelegate {
+            delegate: "hot-key".to_string(),
+        }
+        .validate()
+        .expect("a populated delegate should pass validation");
+        // Empty string bad
+        test_invalid_msg(
+            &ExecuteMsg::AddOracleDelegate {
+                delegate: String::new(),
+            },
+            "delegate",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::RemoveOracleDelegate {
+                delegate: String::new(),
+            },
+            "delegate",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_make_payment() {
+        MakePayment {
+            payable_uuid: "07933e94-8f83-11ec-a3e4-dbff515bf8c5".to_string(),
+            refund_overpayment: Some(true),
+            memo: Some("INV-1042".to_string()),
+            on_behalf_of: Some("client".to_string()),
+        }
+        .validate()
+        .expect("a populated make payment msg should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_execute_make_payment_payable_uuid() {
+        test_invalid_msg(
+            &MakePayment {
+                payable_uuid: String::new(),
+                refund_overpayment: None,
+                memo: None,
+                on_behalf_of: None,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &MakePayment {
+                payable_uuid: "07933e94-8f83-11ec-a3e4-dbff515bf8c5".to_string(),
+                refund_overpayment: None,
+                memo: None,
+                on_behalf_of: Some(String::new()),
+            },
+            "on_behalf_of",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_make_payment_memo() {
+        let make_payment = |memo: String| MakePayment {
+            payable_uuid: "07933e94-8f83-11ec-a3e4-dbff515bf8c5".to_string(),
+            refund_overpayment: None,
+            memo: Some(memo),
+            on_behalf_of: None,
+        };
+        test_invalid_msg(&make_payment(String::new()), "memo");
+        test_invalid_msg(
+            &make_payment("a".repeat(MAX_PAYMENT_MEMO_LENGTH + 1)),
+            "memo",
+        );
+        make_payment("a".repeat(MAX_PAYMENT_MEMO_LENGTH))
+            .validate()
+            .expect("a memo at the maximum length should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_execute_make_batch_payment() {
+        let payment = |payable_uuid: &str, amount: u128| BatchPaymentV1 {
+            payable_uuid: payable_uuid.to_string(),
+            amount: Uint128::new(amount),
+        };
+        ExecuteMsg::MakeBatchPayment {
+            payments: vec![
+                payment("07933e94-8f83-11ec-a3e4-dbff515bf8c5", 100),
+                payment("d6219342-8f82-11ec-a7cf-1fe3b2eb3267", 50),
+            ],
+        }
+        .validate()
+        .expect("a batch of distinct, populated payments should pass validation");
+        // Empty bad
+        test_invalid_msg(
+            &ExecuteMsg::MakeBatchPayment { payments: vec![] },
+            "payments",
+        );
+        // Duplicate payables bad
+        test_invalid_msg(
+            &ExecuteMsg::MakeBatchPayment {
+                payments: vec![
+                    payment("07933e94-8f83-11ec-a3e4-dbff515bf8c5", 100),
+                    payment("07933e94-8f83-11ec-a3e4-dbff515bf8c5", 50),
+                ],
+            },
+            "payments",
+        );
+        // Bad uuid bad
+        test_invalid_msg(
+            &ExecuteMsg::MakeBatchPayment {
+                payments: vec![payment("not-a-uuid", 100)],
+            },
+            "payable_uuid",
+        );
+        // Zero amount bad
+        test_invalid_msg(
+            &ExecuteMsg::MakeBatchPayment {
+                payments: vec![payment("07933e94-8f83-11ec-a3e4-dbff515bf8c5", 0)],
+            },
+            "amount",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_set_denom_display_metadata() {
+        set_denom_display_metadata("usdf", "USDF", 2)
+            .validate()
+            .expect("a populated set denom display metadata msg should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_execute_set_denom_display_metadata_denom() {
+        test_invalid_msg(&set_denom_display_metadata("", "USDF", 2), "denom");
+    }
+
+    #[test]
+    fn test_invalid_execute_set_denom_display_metadata_symbol() {
+        test_invalid_msg(&set_denom_display_metadata("usdf", "", 2), "symbol");
+    }
+
+    #[test]
+    fn test_invalid_execute_set_denom_display_metadata_exponent() {
+        test_invalid_msg(
+            &set_denom_display_metadata("usdf", "USDF", MAX_DENOM_DISPLAY_EXPONENT + 1),
+            "exponent",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_remove_denom_display_metadata_denom() {
+        test_invalid_msg(
+            &ExecuteMsg::RemoveDenomDisplayMetadata {
+                denom: String::new(),
+            },
+            "denom",
+        );
+    }
+
+    #[test]
+    fn test_valid_query_query_state() {
+        QueryState {}
+            .validate()
+            .expect("a populated query state msg should pass validation");
+    }
+
+    #[test]
+    fn test_valid_query_query_payable_by_uuid() {
+        QueryPayableByUuid {
+            payable_uuid: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+        }
+        .validate()
+        .expect("a populated query payable should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_query_query_by_uuid_payable_uuid() {
+        test_invalid_msg(
+            &QueryPayableByUuid {
+                payable_uuid: String::new(),
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_query_payable_identifier() {
+        QueryPayable {
+            identifier: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+        }
+        .validate()
+        .expect("a payable uuid should pass validation");
+        QueryPayable {
+            identifier: "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8".to_string(),
+        }
+        .validate()
+        .expect("a scope address should pass validation");
+        test_invalid_msg(
+            &QueryPayable {
+                identifier: "not-a-payable".to_string(),
+            },
+            "identifier",
+        );
+        test_invalid_msg(
+            &QueryPayable {
+                identifier: "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl9".to_string(),
+            },
+            "identifier",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_scope_attributes() {
+        QueryScopeAttributes {
+            scope_id: "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8".to_string(),
+        }
+        .validate()
+        .expect("a scope address should pass validation");
+        test_invalid_msg(
+            &QueryScopeAttributes {
+                scope_id: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+            },
+            "scope_id",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_escrow_totals_payable_type() {
+        QueryEscrowTotals { payable_type: None }
+            .validate()
+            .expect("escrow totals for every payable type should pass validation");
+        test_invalid_msg(
+            &QueryEscrowTotals {
+                payable_type: Some(String::new()),
+            },
+            "payable_type",
+        );
+    }
+
+    #[test]
+    fn test_valid_query_effective_fees() {
+        QueryEffectiveFees {
+            payable_type: "invoice".to_string(),
+        }
+        .validate()
+        .expect("a populated query effective fees msg should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_query_effective_fees_payable_type() {
+        test_invalid_msg(
+            &QueryEffectiveFees {
+                payable_type: String::new(),
+            },
+            "payable_type",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_all_payables() {
+        QueryAllPayables {
+            start_after: Some("3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string()),
+            limit: Some(MAX_ALL_PAYABLES_LIMIT),
+        }
+        .validate()
+        .expect("a populated all payables msg should pass validation");
+        test_invalid_msg(
+            &QueryAllPayables {
+                start_after: Some("not a uuid".to_string()),
+                limit: None,
+            },
+            "start_after",
+        );
+        test_invalid_msg(
+            &QueryAllPayables {
+                start_after: None,
+                limit: Some(0),
+            },
+            "limit",
+        );
+        test_invalid_msg(
+            &QueryAllPayables {
+                start_after: None,
+                limit: Some(MAX_ALL_PAYABLES_LIMIT + 1),
+            },
+            "limit",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_payment_velocity() {
+        QueryPaymentVelocity {
+            denom: "nhash".to_string(),
+        }
+        .validate()
+        .expect("a valid denom should pass validation");
+        test_invalid_msg(
+            &QueryPaymentVelocity {
+                denom: String::new(),
+            },
+            "denom",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_export_payments() {
+        ExportPayments {
+            start_after: Some(("3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(), 4)),
+            limit: Some(MAX_EXPORT_PAYMENTS_LIMIT),
+        }
+        .validate()
+        .expect("a populated export payments msg should pass validation");
+        test_invalid_msg(
+            &ExportPayments {
+                start_after: Some(("not a uuid".to_string(), 4)),
+                limit: None,
+            },
+            "start_after",
+        );
+        test_invalid_msg(
+            &ExportPayments {
+                start_after: None,
+                limit: Some(0),
+            },
+            "limit",
+        );
+        test_invalid_msg(
+            &ExportPayments {
+                start_after: None,
+                limit: Some(MAX_EXPORT_PAYMENTS_LIMIT + 1),
+            },
+            "limit",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_overdue_approvals() {
+        QueryOverdueApprovals {
+            limit: Some(MAX_OVERDUE_APPROVALS_LIMIT),
+        }
+        .validate()
+        .expect("a limit at the maximum should pass validation");
+        test_invalid_msg(&QueryOverdueApprovals { limit: Some(0) }, "limit");
+        test_invalid_msg(
+            &QueryOverdueApprovals {
+                limit: Some(MAX_OVERDUE_APPROVALS_LIMIT + 1),
+            },
+            "limit",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_payable_type_frozen() {
+        test_invalid_msg(
+            &QueryPayableTypeFrozen {
+                payable_type: String::new(),
+            },
+            "payable_type",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_registration_quote() {
+        test_invalid_msg(
+            &QueryRegistrationQuote {
+                payable_type: String::new(),
+            },
+            "payable_type",
+        );
+    }
+
+    #[test]
+    fn test_invalid_simulate_payment() {
+        test_invalid_msg(
+            &SimulatePayment {
+                payable_uuid: "not a uuid".to_string(),
+                amount: Uint128::new(100),
+                denom: "nhash".to_string(),
+                payer: "payer".to_string(),
+                refund_overpayment: None,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &SimulatePayment {
+                payable_uuid: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+                amount: Uint128::zero(),
+                denom: "nhash".to_string(),
+                payer: "payer".to_string(),
+                refund_overpayment: None,
+            },
+            "amount",
+        );
+        test_invalid_msg(
+            &SimulatePayment {
+                payable_uuid: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+                amount: Uint128::new(100),
+                denom: String::new(),
+                payer: String::new(),
+                refund_overpayment: None,
+            },
+            "denom",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_payments() {
+        QueryPayments {
+            payable_uuid: Some("3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string()),
+            payer: None,
+            after_time: None,
+            start_after: Some(("3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(), 4)),
+            limit: Some(MAX_QUERY_PAYMENTS_LIMIT),
+        }
+        .validate()
+        .expect("a cursor into the requested payable should pass validation");
+        test_invalid_msg(
+            &QueryPayments {
+                payable_uuid: Some("3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string()),
+                payer: None,
+                after_time: None,
+                start_after: Some(("4cb54a6e-83ab-11ec-a486-eb4f069082c5".to_string(), 0)),
+                limit: None,
+            },
+            "start_after",
+        );
+        test_invalid_msg(
+            &QueryPayments {
+                payable_uuid: None,
+                payer: Some(String::new()),
+                after_time: None,
+                start_after: None,
+                limit: None,
+            },
+            "payer",
+        );
+        test_invalid_msg(
+            &QueryPayments {
+                payable_uuid: None,
+                payer: None,
+                after_time: None,
+                start_after: None,
+                limit: Some(MAX_QUERY_PAYMENTS_LIMIT + 1),
+            },
+            "limit",
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_payable_diff() {
+        QueryPayableDiff {
+            payable_uuid: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+            from_seq: 2,
+            to_seq: 2,
+        }
+        .validate()
+        .expect("comparing a snapshot to itself should pass validation");
+        test_invalid_msg(
+            &QueryPayableDiff {
+                payable_uuid: "not a uuid".to_string(),
+                from_seq: 0,
+                to_seq: 1,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &QueryPayableDiff {
+                payable_uuid: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+                from_seq: 3,
+                to_seq: 1,
+            },
+            "to_seq",
+        );
+    }
+
+    #[test]
+    fn test_valid_migrate() {
+        MigrateMsg {
+            onboarding_cost: None,
+            onboarding_denom: None,
+            fee_collection_address: None,
+            fee_percent: None,
+            is_local: None,
+            payment_batch_window: None,
+            disable_payment_batching: None,
+            admin_address: None,
+            migration_timelock_seconds: None,
+            disable_migration_timelock: None,
+            oracle_funds_tolerance: None,
+            disable_oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            disable_minimum_payment: None,
+            settled_retention_seconds: None,
+            disable_settled_pruning: None,
+            approval_valid_duration: None,
+            disable_approval_expiry: None,
+            oracle_approval_sla_seconds: None,
+            disable_oracle_approval_sla: None,
+            large_payment_threshold: None,
+            disable_large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            disable_payment_reversal: None,
+            provenance_msg_version: None,
+            authorized_registrars: None,
+        }
+        .validate()
+        .expect("a migrate msg with no fields populated should pass validation");
+        get_valid_migrate_msg()
+            .validate()
+            .expect("a migrate msg with all fields populated should pass validation");
+    }
+
+    #[test]
+    fn test_invalid_migrate_onboarding_cost() {
+        let mut msg = get_valid_migrate_msg();
+        // NaN bad
+        msg.onboarding_cost = Some("not a number".to_string());
+        test_invalid_msg(&msg, "onboarding_cost");
+        // Below zero bad
+        msg.onboarding_cost = Some("-1".to_string());
         test_invalid_msg(&msg, "onboarding_cost");
     }
 
     #[test]
-    fn test_invalid_init_msg_fee_collection_address() {
-        let mut msg = get_valid_init_msg();
+    fn test_invalid_migrate_onboarding_denom() {
+        let mut msg = get_valid_migrate_msg();
         // Empty string bad
-        msg.fee_collection_address = String::new();
+        msg.onboarding_denom = Some(String::new());
+        test_invalid_msg(&msg, "onboarding_denom");
+    }
+
+    #[test]
+    fn test_invalid_migrate_fee_collection_address() {
+        let mut msg = get_valid_migrate_msg();
+        // Empty string bad
+        msg.fee_collection_address = Some(String::new());
         test_invalid_msg(&msg, "fee_collection_address");
     }
 
     #[test]
-    fn test_invalid_init_msg_fee_percent() {
-        let mut msg = get_valid_init_msg();
-        // Over 100% bad
-        msg.fee_percent = Decimal::percent(101);
-        test_invalid_msg(&msg, "fee_percent");
+    fn test_invalid_migrate_admin_address() {
+        let mut msg = get_valid_migrate_msg();
+        // Empty string bad
+        msg.admin_address = Some(String::new());
+        test_invalid_msg(&msg, "admin_address");
+    }
+
+    #[test]
+    fn test_invalid_migrate_fee_percent() {
+        let mut msg = get_valid_migrate_msg();
+        // Above 100 percent bad
+        msg.fee_percent = Some(Decimal::percent(101));
+        test_invalid_msg(&msg, "fee_percent");
+    }
+
+    #[test]
+    fn test_invalid_migrate_migration_timelock_seconds() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero timelock bad
+        msg.migration_timelock_seconds = Some(0);
+        test_invalid_msg(&msg, "migration_timelock_seconds");
+        // Enabling and disabling at the same time bad
+        msg.migration_timelock_seconds = Some(3600);
+        msg.disable_migration_timelock = Some(true);
+        test_invalid_msg(&msg, "migration_timelock_seconds");
+    }
+
+    #[test]
+    fn test_invalid_migrate_oracle_funds_tolerance() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero max amount bad
+        msg.oracle_funds_tolerance = Some(FundsToleranceV1 {
+            denom: "nhash".to_string(),
+            max_amount: Uint128::zero(),
+        });
+        test_invalid_msg(&msg, "oracle_funds_tolerance");
+        // Invalid denom bad
+        msg.oracle_funds_tolerance = Some(FundsToleranceV1 {
+            denom: String::new(),
+            max_amount: Uint128::new(10),
+        });
+        test_invalid_msg(&msg, "oracle_funds_tolerance");
+        // Enabling and disabling at the same time bad
+        msg.oracle_funds_tolerance = Some(FundsToleranceV1 {
+            denom: "nhash".to_string(),
+            max_amount: Uint128::new(10),
+        });
+        msg.disable_oracle_funds_tolerance = Some(true);
+        test_invalid_msg(&msg, "oracle_funds_tolerance");
+    }
+
+    #[test]
+    fn test_invalid_migrate_minimum_payment_amount() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero minimum bad
+        msg.minimum_payment_amount = Some(Uint128::zero());
+        test_invalid_msg(&msg, "minimum_payment_amount");
+        // Enabling and disabling at the same time bad
+        msg.minimum_payment_amount = Some(Uint128::new(10));
+        msg.disable_minimum_payment = Some(true);
+        test_invalid_msg(&msg, "minimum_payment_amount");
+    }
+
+    #[test]
+    fn test_invalid_migrate_settled_retention_seconds() {
+        let mut msg = get_valid_migrate_msg();
+        // Enabling and disabling at the same time bad
+        msg.settled_retention_seconds = Some(86_400);
+        msg.disable_settled_pruning = Some(true);
+        test_invalid_msg(&msg, "settled_retention_seconds");
+    }
+
+    #[test]
+    fn test_invalid_migrate_approval_valid_duration() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero duration bad
+        msg.approval_valid_duration = Some(0);
+        test_invalid_msg(&msg, "approval_valid_duration");
+        // Enabling and disabling at the same time bad
+        msg.approval_valid_duration = Some(86_400);
+        msg.disable_approval_expiry = Some(true);
+        test_invalid_msg(&msg, "approval_valid_duration");
+    }
+
+    #[test]
+    fn test_invalid_migrate_oracle_approval_sla_seconds() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero SLA bad
+        msg.oracle_approval_sla_seconds = Some(0);
+        test_invalid_msg(&msg, "oracle_approval_sla_seconds");
+        // Setting and removing at the same time bad
+        msg.oracle_approval_sla_seconds = Some(172_800);
+        msg.disable_oracle_approval_sla = Some(true);
+        test_invalid_msg(&msg, "oracle_approval_sla_seconds");
+    }
+
+    #[test]
+    fn test_invalid_migrate_large_payment_threshold() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero threshold bad
+        msg.large_payment_threshold = Some(Uint128::zero());
+        test_invalid_msg(&msg, "large_payment_threshold");
+        // Setting and removing at the same time bad
+        msg.large_payment_threshold = Some(Uint128::new(50_000));
+        msg.disable_large_payment_threshold = Some(true);
+        test_invalid_msg(&msg, "large_payment_threshold");
+    }
+
+    #[test]
+    fn test_invalid_migrate_payment_reversal_window_seconds() {
+        let mut msg = get_valid_migrate_msg();
+        // Zero window bad
+        msg.payment_reversal_window_seconds = Some(0);
+        test_invalid_msg(&msg, "payment_reversal_window_seconds");
+        // Setting and removing at the same time bad
+        msg.payment_reversal_window_seconds = Some(3600);
+        msg.disable_payment_reversal = Some(true);
+        test_invalid_msg(&msg, "payment_reversal_window_seconds");
+    }
+
+    #[test]
+    fn test_invalid_migrate_authorized_registrars() {
+        let mut msg = get_valid_migrate_msg();
+        msg.authorized_registrars = Some(vec![]);
+        msg.validate()
+            .expect("an empty list should pass validation, removing all registrars");
+        msg.authorized_registrars = Some(vec!["registrar".to_string(), String::new()]);
+        test_invalid_msg(&msg, "authorized_registrars");
+    }
+
+    #[test]
+    fn test_invalid_execute_stage_migration_params() {
+        let mut params = get_valid_migrate_msg();
+        params.onboarding_cost = Some("not a number".to_string());
+        test_invalid_msg(
+            &ExecuteMsg::StageMigrationParams { params },
+            "onboarding_cost",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_cleanup_orphaned_attributes() {
+        // Empty list bad
+        test_invalid_msg(
+            &ExecuteMsg::CleanupOrphanedAttributes { scope_ids: vec![] },
+            "scope_ids",
+        );
+        // Any non-scope address bad
+        test_invalid_msg(
+            &ExecuteMsg::CleanupOrphanedAttributes {
+                scope_ids: vec![
+                    "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8".to_string(),
+                    "not-a-scope".to_string(),
+                ],
+            },
+            "scope_ids",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_set_payable_type_supply_check() {
+        test_invalid_msg(
+            &ExecuteMsg::SetPayableTypeSupplyCheck {
+                payable_type: String::new(),
+                enabled: true,
+            },
+            "payable_type",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_set_payable_type_frozen() {
+        test_invalid_msg(
+            &ExecuteMsg::SetPayableTypeFrozen {
+                payable_type: String::new(),
+                frozen: true,
+            },
+            "payable_type",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_subscribe() {
+        ExecuteMsg::Subscribe {
+            payable_uuid: "86c224de-8f81-11ec-9277-0353b82d7772".to_string(),
+        }
+        .validate()
+        .expect("a populated subscribe msg should pass validation");
+        test_invalid_msg(
+            &ExecuteMsg::Subscribe {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_schedule_fee_holiday() {
+        let valid_msg = || ExecuteMsg::ScheduleFeeHoliday {
+            holiday_id: "launch-week".to_string(),
+            start_time: Timestamp::from_seconds(100),
+            end_time: Timestamp::from_seconds(200),
+            payable_types: vec![],
+            onboarding_cost: Uint128::zero(),
+        };
+        valid_msg()
+            .validate()
+            .expect("a populated schedule fee holiday msg should pass validation");
+        let mut msg = valid_msg();
+        if let ExecuteMsg::ScheduleFeeHoliday { holiday_id, .. } = &mut msg {
+            holiday_id.clear();
+        }
+        test_invalid_msg(&msg, "holiday_id");
+        // A holiday must end after it starts
+        let mut msg = valid_msg();
+        if let ExecuteMsg::ScheduleFeeHoliday { end_time, .. } = &mut msg {
+            *end_time = Timestamp::from_seconds(100);
+        }
+        test_invalid_msg(&msg, "end_time");
+        let mut msg = valid_msg();
+        if let ExecuteMsg::ScheduleFeeHoliday { payable_types, .. } = &mut msg {
+            payable_types.push(String::new());
+        }
+        test_invalid_msg(&msg, "payable_types");
+    }
+
+    #[test]
+    fn test_invalid_execute_cancel_fee_holiday() {
+        test_invalid_msg(
+            &ExecuteMsg::CancelFeeHoliday {
+                holiday_id: String::new(),
+            },
+            "holiday_id",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_cancel_payable() {
+        test_invalid_msg(
+            &ExecuteMsg::CancelPayable {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_transfer_payable() {
+        test_invalid_msg(
+            &ExecuteMsg::TransferPayable {
+                payable_uuid: "not-a-uuid".to_string(),
+                new_owner: "factor".to_string(),
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::TransferPayable {
+                payable_uuid: "86c224de-8f81-11ec-9277-0353b82d7772".to_string(),
+                new_owner: String::new(),
+            },
+            "new_owner",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_patch_payable_meta() {
+        let patch_meta = |scope_id: Option<&str>, reason: &str| ExecuteMsg::PatchPayableMeta {
+            payable_uuid: "86c224de-8f81-11ec-9277-0353b82d7772".to_string(),
+            patch: PayableMetaPatchV1 {
+                scope_id: scope_id.map(|scope_id| scope_id.to_string()),
+            },
+            reason: reason.to_string(),
+        };
+        patch_meta(Some("scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8"), "wedged")
+            .validate()
+            .expect("a populated patch should pass validation");
+        test_invalid_msg(&patch_meta(None, "wedged"), "patch");
+        test_invalid_msg(&patch_meta(Some("not-a-scope"), "wedged"), "patch");
+        test_invalid_msg(
+            &patch_meta(Some("scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8"), ""),
+            "reason",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_upsert_payable_type_config() {
+        let upsert = |payable_type: &str, denom: &str, percent: u64, oracle: Option<&str>| {
+            ExecuteMsg::UpsertPayableTypeConfig {
+                payable_type: payable_type.to_string(),
+                onboarding_cost: Uint128::new(100),
+                onboarding_denom: denom.to_string(),
+                fee_percent: Decimal::percent(percent),
+                default_oracle: oracle.map(|oracle| oracle.to_string()),
+                registrant_requirement: Some(RegistrantRequirementV1::OwnerRole {
+                    role: PartyType::Originator,
+                }),
+            }
+        };
+        upsert("invoice", "nhash", 75, Some("oracle"))
+            .validate()
+            .expect("a populated config should pass validation");
+        test_invalid_msg(&upsert("", "nhash", 75, None), "payable_type");
+        test_invalid_msg(&upsert("invoice", "", 75, None), "onboarding_denom");
+        test_invalid_msg(&upsert("invoice", "nhash", 101, None), "fee_percent");
+        test_invalid_msg(&upsert("invoice", "nhash", 75, Some("")), "default_oracle");
+        let mut unspecified_role = upsert("invoice", "nhash", 75, None);
+        if let ExecuteMsg::UpsertPayableTypeConfig {
+            registrant_requirement,
+            ..
+        } = &mut unspecified_role
+        {
+            *registrant_requirement = Some(RegistrantRequirementV1::OwnerRole {
+                role: PartyType::Unspecified,
+            });
+        }
+        test_invalid_msg(&unspecified_role, "registrant_requirement");
+    }
+
+    #[test]
+    fn test_invalid_execute_supported_payable_type() {
+        test_invalid_msg(
+            &ExecuteMsg::AddSupportedPayableType {
+                payable_type: String::new(),
+            },
+            "payable_type",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::RemoveSupportedPayableType {
+                payable_type: String::new(),
+            },
+            "payable_type",
+        );
     }
 
     #[test]
-    fn test_valid_execute_register_payable() {
-        get_valid_register_payable()
-            .to_enum()
-            .validate()
-            .expect("a populated register payable should pass validation");
+    fn test_invalid_execute_decline_payable() {
+        test_invalid_msg(
+            &ExecuteMsg::DeclinePayable {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_register_payable_payable_type() {
-        let mut msg = get_valid_register_payable();
-        // Empty string bad
-        msg.payable_type = String::new();
-        test_invalid_msg(&msg.to_enum(), "payable_type");
+    fn test_invalid_execute_payment_volume_cap() {
+        let set_cap =
+            |denom: &str, max_volume: u128, window_seconds: u64| ExecuteMsg::SetPaymentVolumeCap {
+                denom: denom.to_string(),
+                max_volume: Uint128::new(max_volume),
+                window_seconds,
+            };
+        set_cap("nhash", 1000, 86400)
+            .validate()
+            .expect("a populated cap should pass validation");
+        test_invalid_msg(&set_cap("", 1000, 86400), "denom");
+        test_invalid_msg(&set_cap("nhash", 0, 86400), "max_volume");
+        test_invalid_msg(&set_cap("nhash", 1000, 0), "window_seconds");
+        test_invalid_msg(
+            &ExecuteMsg::ResetPaymentVolume {
+                denom: String::new(),
+            },
+            "denom",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_register_payable_payable_uuid() {
-        let mut msg = get_valid_register_payable();
-        // Empty string bad
-        msg.payable_uuid = String::new();
-        test_invalid_msg(&msg.to_enum(), "payable_uuid");
+    fn test_invalid_execute_escrowed_payment() {
+        test_invalid_msg(
+            &ExecuteMsg::ReleasePayment {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::ReclaimEscrow {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::ReversePayment {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
+                reason: "sent to the wrong payable".to_string(),
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::ReversePayment {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                payment_id: 0,
+                reason: String::new(),
+            },
+            "reason",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_register_payable_scope_id() {
-        let mut msg = get_valid_register_payable();
-        // Empty string bad
-        msg.scope_id = String::new();
-        test_invalid_msg(&msg.to_enum(), "scope_id");
+    fn test_invalid_execute_dispute() {
+        let resolve = |resolution: &str, adjusted_total: Option<u128>| ExecuteMsg::ResolveDispute {
+            payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+            resolution: resolution.to_string(),
+            adjusted_total: adjusted_total.map(Uint128::new),
+        };
+        resolve("settled", Some(500))
+            .validate()
+            .expect("a populated resolution should pass validation");
+        test_invalid_msg(&resolve("", None), "resolution");
+        test_invalid_msg(&resolve("settled", Some(0)), "adjusted_total");
+        test_invalid_msg(
+            &ExecuteMsg::RaiseDispute {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                reason: String::new(),
+            },
+            "reason",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::RaiseDispute {
+                payable_uuid: "not-a-uuid".to_string(),
+                reason: "amount is wrong".to_string(),
+            },
+            "payable_uuid",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_register_payable_payable_denom() {
-        let mut msg = get_valid_register_payable();
-        // Empty string bad
-        msg.payable_denom = String::new();
-        test_invalid_msg(&msg.to_enum(), "payable_denom");
+    fn test_invalid_execute_reassign_oracle_bulk() {
+        let reassign = |new_oracle_address: &str, start_after: Option<&str>, limit: Option<u32>| {
+            ExecuteMsg::ReassignOracleBulk {
+                old_oracle_address: "old-oracle".to_string(),
+                new_oracle_address: new_oracle_address.to_string(),
+                start_after: start_after.map(|uuid| uuid.to_string()),
+                limit,
+            }
+        };
+        reassign(
+            "new-oracle",
+            Some("d6219342-8f82-11ec-a7cf-1fe3b2eb3267"),
+            Some(MAX_REASSIGN_ORACLE_LIMIT),
+        )
+        .validate()
+        .expect("a populated reassignment should pass validation");
+        test_invalid_msg(&reassign("", None, None), "new_oracle_address");
+        test_invalid_msg(&reassign("old-oracle", None, None), "new_oracle_address");
+        test_invalid_msg(
+            &reassign("new-oracle", Some("not-a-uuid"), None),
+            "start_after",
+        );
+        test_invalid_msg(&reassign("new-oracle", None, Some(0)), "limit");
+        test_invalid_msg(
+            &reassign("new-oracle", None, Some(MAX_REASSIGN_ORACLE_LIMIT + 1)),
+            "limit",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_register_payable_payable_total() {
-        let mut msg = get_valid_register_payable();
-        // Zero bad
-        msg.payable_total = Uint128::zero();
-        test_invalid_msg(&msg.to_enum(), "payable_total");
+    fn test_invalid_execute_prune_payable() {
+        ExecuteMsg::PrunePayable {
+            payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+        }
+        .validate()
+        .expect("a valid payable uuid should pass validation");
+        test_invalid_msg(
+            &ExecuteMsg::PrunePayable {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
     }
 
     #[test]
-    fn test_valid_execute_oracle_approval() {
-        OracleApproval {
+    fn test_invalid_execute_renew_oracle_approval() {
+        ExecuteMsg::RenewOracleApproval {
             payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+            external_reference_id: None,
+            risk_score: None,
+            approval_valid_for: None,
         }
         .validate()
-        .expect("a populated oracle approval should pass validation");
+        .expect("a renewal without an external reference id should pass validation");
+        test_invalid_msg(
+            &ExecuteMsg::RenewOracleApproval {
+                payable_uuid: "not-a-uuid".to_string(),
+                external_reference_id: None,
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::RenewOracleApproval {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                external_reference_id: Some(String::new()),
+                risk_score: None,
+                approval_valid_for: None,
+            },
+            "external_reference_id",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_oracle_approval_payable_uuid() {
+    fn test_invalid_execute_quarantine() {
+        let set_config =
+            |flagged_payers: Vec<&str>, thresholds: Vec<Coin>| ExecuteMsg::SetQuarantineConfig {
+                flagged_payers: flagged_payers.into_iter().map(String::from).collect(),
+                large_payment_thresholds: thresholds,
+            };
+        set_config(vec!["flagged"], vec![coin(1000, "nhash")])
+            .validate()
+            .expect("populated heuristics should pass validation");
+        set_config(vec![], vec![])
+            .validate()
+            .expect("empty heuristics should pass validation to disable quarantine");
+        test_invalid_msg(&set_config(vec![""], vec![]), "flagged_payers");
         test_invalid_msg(
-            &OracleApproval {
-                payable_uuid: String::new(),
+            &set_config(vec!["flagged", "flagged"], vec![]),
+            "flagged_payers",
+        );
+        test_invalid_msg(
+            &set_config(vec![], vec![coin(0, "nhash")]),
+            "large_payment_thresholds",
+        );
+        test_invalid_msg(
+            &set_config(vec![], vec![coin(1000, "nhash"), coin(2000, "nhash")]),
+            "large_payment_thresholds",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::ReleaseQuarantinedPayment {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::RefundQuarantinedPayment {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
             },
             "payable_uuid",
         );
     }
 
     #[test]
-    fn test_valid_execute_make_payment() {
-        MakePayment {
-            payable_uuid: "07933e94-8f83-11ec-a3e4-dbff515bf8c5".to_string(),
-        }
+    fn test_invalid_execute_set_risk_bands() {
+        let band =
+            |min_score: u32, holdback_percent: Option<&str>, threshold: Option<u128>| RiskBandV1 {
+                min_score,
+                holdback_percent: holdback_percent.map(|pct| Decimal::from_str(pct).unwrap()),
+                large_payment_threshold: threshold.map(Uint128::new),
+            };
+        let set_bands = |risk_bands: Vec<RiskBandV1>| ExecuteMsg::SetRiskBands { risk_bands };
+        set_bands(vec![
+            band(0, None, Some(1000)),
+            band(500, Some("0.2"), Some(100)),
+        ])
         .validate()
-        .expect("a populated make payment msg should pass validation");
+        .expect("populated risk bands should pass validation");
+        set_bands(vec![])
+            .validate()
+            .expect("empty risk bands should pass validation to disable them");
+        // A band must apply at least one rule
+        test_invalid_msg(&set_bands(vec![band(0, None, None)]), "risk_bands");
+        test_invalid_msg(
+            &set_bands(vec![band(MAX_RISK_SCORE + 1, None, Some(100))]),
+            "risk_bands",
+        );
+        test_invalid_msg(
+            &set_bands(vec![
+                band(100, None, Some(100)),
+                band(100, Some("0.1"), None),
+            ]),
+            "risk_bands",
+        );
+        test_invalid_msg(&set_bands(vec![band(0, Some("0"), None)]), "risk_bands");
+        // Holding back the entire payment is quarantine, not a holdback
+        test_invalid_msg(&set_bands(vec![band(0, Some("1"), None)]), "risk_bands");
+        test_invalid_msg(&set_bands(vec![band(0, None, Some(0))]), "risk_bands");
+        test_invalid_msg(
+            &set_bands(
+                (0..=MAX_RISK_BANDS as u32)
+                    .map(|min_score| band(min_score, None, Some(100)))
+                    .collect(),
+            ),
+            "risk_bands",
+        );
     }
 
     #[test]
-    fn test_invalid_execute_make_payment_payable_uuid() {
+    fn test_invalid_execute_set_referral_config() {
+        let set_config = |fee_share_bps: u64, referrers: Vec<&str>| ExecuteMsg::SetReferralConfig {
+            fee_share_bps,
+            referrers: referrers.into_iter().map(|r| r.to_string()).collect(),
+        };
+        set_config(2000, vec!["partner-a", "partner-b"])
+            .validate()
+            .expect("a populated referral config should pass validation");
+        set_config(0, vec![])
+            .validate()
+            .expect("no referrers should pass validation to disable referrals");
+        test_invalid_msg(&set_config(0, vec!["partner-a"]), "fee_share_bps");
         test_invalid_msg(
-            &MakePayment {
-                payable_uuid: String::new(),
+            &set_config(MAX_REFERRAL_FEE_SHARE_BPS + 1, vec!["partner-a"]),
+            "fee_share_bps",
+        );
+        test_invalid_msg(&set_config(2000, vec![""]), "referrers");
+        test_invalid_msg(
+            &set_config(2000, vec!["partner-a", "partner-a"]),
+            "referrers",
+        );
+        let too_many_referrers = (0..=MAX_REFERRERS)
+            .map(|index| format!("partner-{}", index))
+            .collect::<Vec<String>>();
+        test_invalid_msg(
+            &set_config(
+                2000,
+                too_many_referrers.iter().map(String::as_str).collect(),
+            ),
+            "referrers",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_grant_payment_allowance() {
+        let grant = |max_total: u128, pull_amount: u128, pull_interval_seconds: u64| {
+            ExecuteMsg::GrantPaymentAllowance {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                denom: "nhash".to_string(),
+                max_total: Uint128::new(max_total),
+                pull_amount: Uint128::new(pull_amount),
+                pull_interval_seconds,
+                first_pull_at: None,
+                expires_at: None,
+            }
+        };
+        let interval = MIN_ALLOWANCE_PULL_INTERVAL_SECONDS;
+        grant(1000, 100, interval)
+            .validate()
+            .expect("a populated allowance should pass validation");
+        test_invalid_msg(&grant(0, 0, interval), "max_total");
+        test_invalid_msg(&grant(1000, 0, interval), "pull_amount");
+        test_invalid_msg(&grant(100, 101, interval), "pull_amount");
+        test_invalid_msg(&grant(1000, 100, interval - 1), "pull_interval_seconds");
+        let mut msg = grant(1000, 100, interval);
+        if let ExecuteMsg::GrantPaymentAllowance {
+            first_pull_at,
+            expires_at,
+            ..
+        } = &mut msg
+        {
+            *first_pull_at = Some(Timestamp::from_seconds(200));
+            *expires_at = Some(Timestamp::from_seconds(200));
+        }
+        test_invalid_msg(&msg, "expires_at");
+        test_invalid_msg(
+            &ExecuteMsg::PullAllowancePayment {
+                payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+                payer: String::new(),
+            },
+            "payer",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::RevokePaymentAllowance {
+                payable_uuid: "not-a-uuid".to_string(),
             },
             "payable_uuid",
         );
     }
 
     #[test]
-    fn test_valid_query_query_state() {
-        QueryState {}
-            .validate()
-            .expect("a populated query state msg should pass validation");
+    fn test_invalid_execute_flag_erroneous_payment() {
+        ExecuteMsg::FlagErroneousPayment {
+            payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+            payment_id: 0,
+        }
+        .validate()
+        .expect("a populated flag should pass validation");
+        test_invalid_msg(
+            &ExecuteMsg::FlagErroneousPayment {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
+            },
+            "payable_uuid",
+        );
     }
 
     #[test]
-    fn test_valid_query_query_payable_by_uuid() {
-        QueryPayableByUuid {
-            payable_uuid: "3ee3a636-8f83-11ec-8c26-6b8cbb24f4aa".to_string(),
+    fn test_invalid_execute_set_payable_frozen() {
+        ExecuteMsg::SetPayableFrozen {
+            payable_uuid: "d6219342-8f82-11ec-a7cf-1fe3b2eb3267".to_string(),
+            frozen: true,
         }
         .validate()
-        .expect("a populated query payable should pass validation");
+        .expect("a populated freeze should pass validation");
+        test_invalid_msg(
+            &ExecuteMsg::SetPayableFrozen {
+                payable_uuid: "not-a-uuid".to_string(),
+                frozen: true,
+            },
+            "payable_uuid",
+        );
     }
 
     #[test]
-    fn test_invalid_query_query_by_uuid_payable_uuid() {
+    fn test_invalid_execute_propose_payment() {
+        let propose = |payable_uuid: &str, memo: Option<&str>| ExecuteMsg::ProposePayment {
+            payable_uuid: payable_uuid.to_string(),
+            memo: memo.map(String::from),
+            on_behalf_of: None,
+        };
+        propose("200425c6-83ab-11ec-a486-eb4f069082c5", Some("invoice 42"))
+            .validate()
+            .expect("a proposal with a valid uuid and memo should pass validation");
+        test_invalid_msg(&propose("not-a-uuid", None), "payable_uuid");
         test_invalid_msg(
-            &QueryPayableByUuid {
-                payable_uuid: String::new(),
+            &propose("200425c6-83ab-11ec-a486-eb4f069082c5", Some("")),
+            "memo",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::ApprovePayment {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::CancelProposedPayment {
+                payable_uuid: "not-a-uuid".to_string(),
             },
             "payable_uuid",
         );
     }
 
     #[test]
-    fn test_valid_migrate() {
-        MigrateMsg {
-            onboarding_cost: None,
-            onboarding_denom: None,
-            fee_collection_address: None,
-            fee_percent: None,
-            is_local: None,
-        }
-        .validate()
-        .expect("a migrate msg with no fields populated should pass validation");
-        get_valid_migrate_msg()
+    fn test_invalid_execute_record_external_settlement() {
+        let settlement = |amount: u128, reference: &str| ExecuteMsg::RecordExternalSettlement {
+            payable_uuid: "200425c6-83ab-11ec-a486-eb4f069082c5".to_string(),
+            amount: Uint128::new(amount),
+            reference: reference.to_string(),
+        };
+        settlement(100, "wire-42")
             .validate()
-            .expect("a migrate msg with all fields populated should pass validation");
+            .expect("a settlement with an amount and reference should pass validation");
+        test_invalid_msg(&settlement(0, "wire-42"), "amount");
+        test_invalid_msg(&settlement(100, ""), "reference");
+        test_invalid_msg(
+            &settlement(
+                100,
+                &"a".repeat(MAX_EXTERNAL_SETTLEMENT_REFERENCE_LENGTH + 1),
+            ),
+            "reference",
+        );
     }
 
     #[test]
-    fn test_invalid_migrate_onboarding_cost() {
-        let mut msg = get_valid_migrate_msg();
-        // NaN bad
-        msg.onboarding_cost = Some("not a number".to_string());
-        test_invalid_msg(&msg, "onboarding_cost");
-        // Below zero bad
-        msg.onboarding_cost = Some("-1".to_string());
-        test_invalid_msg(&msg, "onboarding_cost");
+    fn test_invalid_execute_re_register_payable() {
+        let re_register = |builder: RegisterPayableBuilder| ExecuteMsg::ReRegisterPayable {
+            payable: builder.to_enum().to_register_payable().unwrap(),
+        };
+        re_register(get_valid_register_payable())
+            .validate()
+            .expect("a re-registration should be held to the same rules as a registration");
+        let mut builder = get_valid_register_payable();
+        builder.payable_uuid = "not-a-uuid".to_string();
+        test_invalid_msg(&re_register(builder), "payable_uuid");
+        let mut builder = get_valid_register_payable();
+        builder.payable_total = Uint128::zero();
+        test_invalid_msg(&re_register(builder), "payable_total");
     }
 
     #[test]
-    fn test_invalid_migrate_onboarding_denom() {
-        let mut msg = get_valid_migrate_msg();
-        // Empty string bad
-        msg.onboarding_denom = Some(String::new());
-        test_invalid_msg(&msg, "onboarding_denom");
+    fn test_invalid_execute_register_payable_payee_splits() {
+        let with_splits = |payee_splits: Vec<PayeeSplitV1>| {
+            let mut builder = get_valid_register_payable();
+            builder.payee_splits = Some(payee_splits);
+            builder.to_enum()
+        };
+        // No payees bad - omit the splits instead
+        test_invalid_msg(&with_splits(vec![]), "payee_splits");
+        // Zero weight bad
+        test_invalid_msg(
+            &with_splits(vec![payee_split("lender-a", 1), payee_split("lender-b", 0)]),
+            "payee_splits",
+        );
+        // Duplicate payee bad
+        test_invalid_msg(
+            &with_splits(vec![payee_split("lender-a", 1), payee_split("lender-a", 2)]),
+            "payee_splits",
+        );
+        // Too many payees bad
+        test_invalid_msg(
+            &with_splits(
+                (0..=MAX_PAYEE_SPLITS)
+                    .map(|index| payee_split(&format!("lender-{}", index), 1))
+                    .collect(),
+            ),
+            "payee_splits",
+        );
     }
 
     #[test]
-    fn test_invalid_migrate_fee_collection_address() {
-        let mut msg = get_valid_migrate_msg();
-        // Empty string bad
-        msg.fee_collection_address = Some(String::new());
-        test_invalid_msg(&msg, "fee_collection_address");
+    fn test_invalid_execute_settle_netting() {
+        let settle_netting = |payable_uuids: Vec<&str>| ExecuteMsg::SettleNetting {
+            payable_uuids: payable_uuids
+                .into_iter()
+                .map(|uuid| uuid.to_string())
+                .collect(),
+        };
+        // No payables bad
+        test_invalid_msg(&settle_netting(vec![]), "payable_uuids");
+        // Duplicate payables bad
+        test_invalid_msg(
+            &settle_netting(vec![
+                "200425c6-83ab-11ec-a486-eb4f069e6a4d",
+                "200425c6-83ab-11ec-a486-eb4f069e6a4d",
+            ]),
+            "payable_uuids",
+        );
+        // Too many payables bad
+        test_invalid_msg(
+            &ExecuteMsg::SettleNetting {
+                payable_uuids: (0..=MAX_NETTING_PAYABLES)
+                    .map(|index| format!("200425c6-83ab-11ec-a486-{:012}", index))
+                    .collect(),
+            },
+            "payable_uuids",
+        );
+        // Non-uuid payable bad
+        test_invalid_msg(&settle_netting(vec!["not-a-uuid"]), "payable_uuid");
     }
 
     #[test]
-    fn test_invalid_migrate_fee_percent() {
+    fn test_invalid_execute_pending_cancellation() {
+        test_invalid_msg(
+            &ExecuteMsg::ClaimCancellationRefund {
+                payable_uuid: "not-a-uuid".to_string(),
+                payment_id: 0,
+            },
+            "payable_uuid",
+        );
+        test_invalid_msg(
+            &ExecuteMsg::FinalizeCancellation {
+                payable_uuid: "not-a-uuid".to_string(),
+            },
+            "payable_uuid",
+        );
+    }
+
+    #[test]
+    fn test_invalid_migrate_payment_batch_window() {
         let mut msg = get_valid_migrate_msg();
-        // Above 100 percent bad
-        msg.fee_percent = Some(Decimal::percent(101));
-        test_invalid_msg(&msg, "fee_percent");
+        // Zero block interval bad
+        msg.payment_batch_window = Some(PaymentBatchWindowV1 {
+            change_threshold: Decimal::percent(10),
+            block_interval: 0,
+        });
+        test_invalid_msg(&msg, "payment_batch_window");
+        // Enabling and disabling at the same time bad
+        msg.payment_batch_window = Some(PaymentBatchWindowV1 {
+            change_threshold: Decimal::percent(10),
+            block_interval: 10,
+        });
+        msg.disable_payment_batching = Some(true);
+        test_invalid_msg(&msg, "payment_batch_window");
     }
 
     #[test]
@@ -499,6 +4440,17 @@ mod tests {
             fee_collection_address: "addr".to_string(),
             fee_percent: Decimal::percent(50),
             is_local: Some(true),
+            payment_batch_window: None,
+            migration_timelock_seconds: Some(3600),
+            oracle_funds_tolerance: None,
+            minimum_payment_amount: Some(Uint128::new(10)),
+            settled_retention_seconds: Some(86_400),
+            approval_valid_duration: Some(2_592_000),
+            oracle_approval_sla_seconds: Some(172_800),
+            large_payment_threshold: Some(Uint128::new(50_000)),
+            payment_reversal_window_seconds: Some(3600),
+            provenance_msg_version: Some(ProvenanceMsgVersion::V2),
+            authorized_registrars: Some(vec!["onboarding-service".to_string()]),
         }
     }
 
@@ -509,6 +4461,20 @@ mod tests {
         oracle_address: String,
         payable_denom: String,
         payable_total: Uint128,
+        custom_fields: Option<Binary>,
+        priority_fee: Option<Uint128>,
+        oracle_gas_rebate: Option<Uint128>,
+        minimum_payment_amount: Option<Uint128>,
+        expiration_time: Option<Timestamp>,
+        accepted_denoms: Option<Vec<String>>,
+        payment_schedule: Option<Vec<PaymentInstallmentV1>>,
+        late_fee: Option<LateFeeV1>,
+        escrow_timeout_seconds: Option<u64>,
+        authorized_payers: Option<Vec<String>>,
+        interest: Option<InterestTermsV1>,
+        payee_splits: Option<Vec<PayeeSplitV1>>,
+        registration_nonce: Option<String>,
+        referrer: Option<String>,
     }
     impl RegisterPayableBuilder {
         fn to_enum(self) -> ExecuteMsg {
@@ -519,6 +4485,20 @@ mod tests {
                 oracle_address: self.oracle_address,
                 payable_denom: self.payable_denom,
                 payable_total: self.payable_total,
+                custom_fields: self.custom_fields,
+                priority_fee: self.priority_fee,
+                oracle_gas_rebate: self.oracle_gas_rebate,
+                minimum_payment_amount: self.minimum_payment_amount,
+                expiration_time: self.expiration_time,
+                accepted_denoms: self.accepted_denoms,
+                payment_schedule: self.payment_schedule,
+                late_fee: self.late_fee,
+                escrow_timeout_seconds: self.escrow_timeout_seconds,
+                authorized_payers: self.authorized_payers,
+                interest: self.interest,
+                payee_splits: self.payee_splits,
+                registration_nonce: self.registration_nonce,
+                referrer: self.referrer,
             }
         }
     }
@@ -527,10 +4507,56 @@ mod tests {
         RegisterPayableBuilder {
             payable_type: "test".to_string(),
             payable_uuid: "86c224de-8f81-11ec-9277-0353b82d7772".to_string(),
-            scope_id: "scope".to_string(),
+            scope_id: "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8".to_string(),
             oracle_address: "oracle-addr".to_string(),
             payable_denom: "nhash".to_string(),
             payable_total: Uint128::new(128),
+            custom_fields: Some(Binary::from(b"{\"product\":\"test\"}".to_vec())),
+            priority_fee: Some(Uint128::new(50)),
+            oracle_gas_rebate: Some(Uint128::new(10)),
+            minimum_payment_amount: Some(Uint128::new(5)),
+            expiration_time: Some(Timestamp::from_seconds(1_700_000_000)),
+            accepted_denoms: Some(vec!["uusdf".to_string()]),
+            payment_schedule: Some(vec![
+                installment(1_600_000_000, 64),
+                installment(1_650_000_000, 64),
+            ]),
+            late_fee: Some(LateFeeV1 {
+                due_time: Timestamp::from_seconds(1_650_000_000),
+                daily_rate: Decimal::permille(5),
+            }),
+            escrow_timeout_seconds: cfg!(feature = "escrow").then(|| 86400),
+            authorized_payers: Some(vec!["counterparty".to_string()]),
+            interest: Some(InterestTermsV1 {
+                start_time: Timestamp::from_seconds(1_600_000_000),
+                annual_rate: Decimal::percent(8),
+                compounding: InterestCompounding::Daily,
+            }),
+            payee_splits: Some(vec![payee_split("lender-a", 3), payee_split("lender-b", 1)]),
+            registration_nonce: Some("retry-1".to_string()),
+            referrer: Some("partner-wallet".to_string()),
+        }
+    }
+
+    fn payee_split(address: &str, weight: u64) -> PayeeSplitV1 {
+        PayeeSplitV1 {
+            address: address.to_string(),
+            weight,
+        }
+    }
+
+    fn installment(due_seconds: u64, amount: u128) -> PaymentInstallmentV1 {
+        PaymentInstallmentV1 {
+            due_time: Timestamp::from_seconds(due_seconds),
+            amount: Uint128::new(amount),
+        }
+    }
+
+    fn set_denom_display_metadata(denom: &str, symbol: &str, exponent: u32) -> ExecuteMsg {
+        ExecuteMsg::SetDenomDisplayMetadata {
+            denom: denom.to_string(),
+            symbol: symbol.to_string(),
+            exponent,
         }
     }
 
@@ -541,6 +4567,30 @@ mod tests {
             fee_collection_address: Some("address".to_string()),
             fee_percent: Some(Decimal::percent(50)),
             is_local: Some(false),
+            payment_batch_window: None,
+            disable_payment_batching: None,
+            admin_address: Some("admin".to_string()),
+            migration_timelock_seconds: Some(86_400),
+            disable_migration_timelock: None,
+            oracle_funds_tolerance: Some(FundsToleranceV1 {
+                denom: "nhash".to_string(),
+                max_amount: Uint128::new(10),
+            }),
+            disable_oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            disable_minimum_payment: None,
+            settled_retention_seconds: None,
+            disable_settled_pruning: None,
+            approval_valid_duration: None,
+            disable_approval_expiry: None,
+            oracle_approval_sla_seconds: None,
+            disable_oracle_approval_sla: None,
+            large_payment_threshold: None,
+            disable_large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            disable_payment_reversal: None,
+            provenance_msg_version: None,
+            authorized_registrars: Some(vec!["onboarding-service".to_string()]),
         }
     }
 
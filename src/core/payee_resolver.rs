@@ -0,0 +1,236 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+#[cfg(feature = "escrow")]
+use crate::core::state::load_payable_escrow_timeout;
+use crate::core::state::{load_payable_payee_splits, PayableScopeAttribute, PayeeSplitPaymentV1};
+use crate::util::constants::PAYEE_SPLIT_AMOUNTS_KEY;
+use crate::util::provenance_util::ProvenanceUtil;
+use cosmwasm_std::{Addr, Attribute, CosmosMsg, Deps, Storage, Timestamp};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+/// Where the funds of a payment made against a payable are sent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaymentDestination {
+    /// Sent directly to the payee.
+    Payee(Addr),
+    /// Held by the contract until it is released to the payee, or reclaimed by the payer once the
+    /// reclaimable time has passed.
+    #[cfg(feature = "escrow")]
+    Escrow {
+        payee: Addr,
+        reclaimable_at: Timestamp,
+    },
+}
+impl PaymentDestination {
+    /// The address that ultimately receives the payment, unless an escrowed payment is reclaimed.
+    pub fn payee(&self) -> &Addr {
+        match self {
+            PaymentDestination::Payee(payee) => payee,
+            #[cfg(feature = "escrow")]
+            PaymentDestination::Escrow { payee, .. } => payee,
+        }
+    }
+}
+
+/// Resolves the payee of a payable: its new owner if it has been transferred, or the value owner of
+/// its scope otherwise.  Every execution that pays out to or authorizes the payee should resolve it
+/// here, so that the rules cannot drift between them.
+pub fn resolve_payee<T: ProvenanceUtil>(
+    deps: &Deps<ProvenanceQuery>,
+    provenance_util: &T,
+    scope_attribute: &PayableScopeAttribute,
+) -> Result<Addr, ContractError> {
+    match &scope_attribute.payee {
+        Some(payee) => Ok(payee.clone()),
+        None => Ok(provenance_util
+            .get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?
+            .value_owner_address),
+    }
+}
+
+/// Resolves where a payment made against the payable at the given time should be sent.  Payables
+/// registered in escrow mode hold payments in the contract for their escrow timeout, and all others
+/// send payments directly to the payee.  Builds without the escrow feature cannot register escrow
+/// mode payables, so they always send payments directly to the payee.
+pub fn resolve_payment_destination<T: ProvenanceUtil>(
+    deps: &Deps<ProvenanceQuery>,
+    provenance_util: &T,
+    scope_attribute: &PayableScopeAttribute,
+    #[cfg_attr(not(feature = "escrow"), allow(unused_variables))] paid_at: Timestamp,
+) -> Result<PaymentDestination, ContractError> {
+    let payee = resolve_payee(deps, provenance_util, scope_attribute)?;
+    #[cfg(feature = "escrow")]
+    if let Some(escrow_timeout_seconds) =
+        load_payable_escrow_timeout(deps.storage, &scope_attribute.payable_uuid)?
+    {
+        return Ok(PaymentDestination::Escrow {
+            payee,
+            reclaimable_at: paid_at.plus_seconds(escrow_timeout_seconds),
+        });
+    }
+    Ok(PaymentDestination::Payee(payee))
+}
+
+/// The transfers that send a payment to its payable's payees.
+pub struct PayeePayout {
+    pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    // The amount sent to each payee, only present for payables registered with payee splits
+    pub payee_splits: Option<Vec<PayeeSplitPaymentV1>>,
+}
+impl PayeePayout {
+    /// Describes the amount sent to each payee, for payables registered with payee splits.
+    pub fn attributes(&self) -> Vec<Attribute> {
+        match &self.payee_splits {
+            Some(payee_splits) => vec![Attribute::new(
+                PAYEE_SPLIT_AMOUNTS_KEY,
+                payee_splits
+                    .iter()
+                    .map(|split| format!("{}:{}", split.address, split.amount))
+                    .collect::<Vec<String>>()
+                    .join(","),
+            )],
+            None => vec![],
+        }
+    }
+}
+
+/// Creates the transfers that send a payment to the payable's resolved payee.  Payables registered
+/// with payee splits instead divide the payment among their payees by weight, with one transfer
+/// per payee, and any payee whose portion rounds down to nothing is skipped.  Every execution that
+/// sends a payment on to the payee should pay out here, so that syndicated payables are always
+/// divided.
+pub fn payout_to_payees(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payee: &Addr,
+    payment: &Money,
+) -> Result<PayeePayout, ContractError> {
+    let payee_splits = match load_payable_payee_splits(storage, payable_uuid)? {
+        Some(payee_splits) => payee_splits,
+        None => {
+            return Ok(PayeePayout {
+                messages: vec![payment.send_to(payee)],
+                payee_splits: None,
+            });
+        }
+    };
+    let portions = payment.split_by_weights(
+        &payee_splits
+            .iter()
+            .map(|split| split.weight)
+            .collect::<Vec<u64>>(),
+    )?;
+    let split_payments = payee_splits
+        .into_iter()
+        .zip(portions)
+        .filter(|(_, portion)| !portion.is_zero())
+        .map(|(split, portion)| PayeeSplitPaymentV1 {
+            // Payee split addresses are validated at registration
+            address: Addr::unchecked(split.address),
+            amount: portion.amount,
+        })
+        .collect::<Vec<PayeeSplitPaymentV1>>();
+    Ok(PayeePayout {
+        messages: split_payments
+            .iter()
+            .map(|split| Money::new(split.amount, &payment.denom).send_to(&split.address))
+            .collect(),
+        payee_splits: Some(split_payments),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::payee_resolver::{
+        resolve_payee, resolve_payment_destination, PaymentDestination,
+    };
+    #[cfg(feature = "escrow")]
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_resolve_payee() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let mut scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            Addr::unchecked(DEFAULT_INFO_NAME),
+            resolve_payee(&deps.as_ref(), &provenance_util, &scope_attribute).unwrap(),
+            "a payable that was never transferred should pay its scope's value owner",
+        );
+        scope_attribute.payee = Some(Addr::unchecked("new-owner"));
+        assert_eq!(
+            Addr::unchecked("new-owner"),
+            resolve_payee(&deps.as_ref(), &provenance_util, &scope_attribute).unwrap(),
+            "a transferred payable should pay its new owner",
+        );
+    }
+
+    #[test]
+    fn test_resolve_payment_destination() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        let paid_at = mock_env().block.time;
+        assert_eq!(
+            PaymentDestination::Payee(Addr::unchecked(DEFAULT_INFO_NAME)),
+            resolve_payment_destination(
+                &deps.as_ref(),
+                &provenance_util,
+                &scope_attribute,
+                paid_at,
+            )
+            .unwrap(),
+            "payments should be sent directly to the payee outside of escrow mode",
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "escrow")]
+    fn test_resolve_payment_destination_in_escrow_mode() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    escrow_timeout_seconds: Some(60),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        let paid_at = mock_env().block.time;
+        let destination = resolve_payment_destination(
+            &deps.as_ref(),
+            &provenance_util,
+            &scope_attribute,
+            paid_at,
+        )
+        .unwrap();
+        assert_eq!(
+            PaymentDestination::Escrow {
+                payee: Addr::unchecked(DEFAULT_INFO_NAME),
+                reclaimable_at: paid_at.plus_seconds(60),
+            },
+            destination,
+            "payments should be escrowed until the escrow timeout in escrow mode",
+        );
+        assert_eq!(&Addr::unchecked(DEFAULT_INFO_NAME), destination.payee());
+    }
+}
@@ -1,14 +1,110 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Addr, Decimal, Storage, Uint128};
+use crate::core::error::ContractError;
+#[cfg(feature = "allowance")]
+use crate::core::keys::PAYMENT_ALLOWANCE_NAMESPACE;
+use crate::core::keys::{
+    CONFIG_KEY_V2, DENOM_DISPLAY_METADATA_NAMESPACE, FEE_HOLIDAYS_NAMESPACE,
+    FROZEN_PAYABLE_TYPES_NAMESPACE, HELD_ORACLE_FEE_NAMESPACE, ORACLE_APPROVAL_CONTEXT_NAMESPACE,
+    ORACLE_DELEGATES_NAMESPACE, ORACLE_GAS_REBATE_NAMESPACE, PAYABLE_AUTHORIZED_PAYERS_NAMESPACE,
+    PAYABLE_CUSTOM_FIELDS_NAMESPACE, PAYABLE_META_PATCH_AUDIT_NAMESPACE,
+    PAYABLE_META_PATCH_PROPOSAL_NAMESPACE, PAYABLE_META_V2_KEY, PAYABLE_MINIMUM_PAYMENT_NAMESPACE,
+    PAYABLE_PAYEE_SPLITS_NAMESPACE, PAYABLE_PRIORITY_FEE_NAMESPACE, PAYABLE_SNAPSHOTS_NAMESPACE,
+    PAYABLE_SUBSCRIBERS_NAMESPACE, PAYABLE_SUBSCRIBER_COUNTS_NAMESPACE, PAYABLE_TIMELINE_NAMESPACE,
+    PAYABLE_TYPE_CONFIG_NAMESPACE, PAYMENT_BATCH_NAMESPACE, PAYMENT_HISTORY_NAMESPACE,
+    PAYMENT_REVERSAL_NAMESPACE, PAYMENT_VELOCITY_NAMESPACE, PAYMENT_VOLUME_CAP_NAMESPACE,
+    PAYMENT_VOLUME_WINDOW_NAMESPACE, PROPOSED_PAYMENT_NAMESPACE, QUARANTINED_PAYMENT_NAMESPACE,
+    QUARANTINE_CONFIG_NAMESPACE, REFERRAL_CONFIG_NAMESPACE, REFERRER_REVENUE_NAMESPACE,
+    REGISTRATION_NONCE_NAMESPACE, RESTRUCTURE_PROPOSAL_NAMESPACE, RISK_BANDS_NAMESPACE,
+    SUPPLY_CHECKED_PAYABLE_TYPES_NAMESPACE, SUPPORTED_PAYABLE_TYPES_NAMESPACE,
+};
+#[cfg(feature = "escrow")]
+use crate::core::keys::{
+    ESCROWED_PAYMENT_NAMESPACE, ESCROW_TOTALS_NAMESPACE, PAYABLE_ESCROW_TIMEOUT_NAMESPACE,
+    PENDING_CANCELLATION_NAMESPACE,
+};
+use crate::core::money::Money;
+use crate::util::interest::{calculate_accrued_interest, InterestCompounding, SECONDS_PER_DAY};
+use crate::util::provenance_msg_factory::{ProvenanceMsgFactory, ProvenanceMsgVersion};
+use crate::validation::init::{FundsToleranceFields, PaymentBatchWindowFields};
+use cosmwasm_std::{
+    Addr, Binary, BlockInfo, Coin, Decimal, MessageInfo, Order, StdError, StdResult, Storage,
+    Timestamp, Uint128,
+};
 use cosmwasm_storage::{
     bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
     Singleton,
 };
+use cw_storage_plus::{Bound, Item, Map};
+use provwasm_std::{PartyType, Scope};
+
+const PAYMENT_BATCHES: Map<&str, PaymentBatchV1> = Map::new(PAYMENT_BATCH_NAMESPACE);
+const PAYABLE_CUSTOM_FIELDS: Map<&str, Binary> = Map::new(PAYABLE_CUSTOM_FIELDS_NAMESPACE);
+const DENOM_DISPLAY_METADATA: Map<&str, DenomDisplayMetadataV1> =
+    Map::new(DENOM_DISPLAY_METADATA_NAMESPACE);
+const PAYABLE_PRIORITY_FEES: Map<&str, PayablePriorityFeeV1> =
+    Map::new(PAYABLE_PRIORITY_FEE_NAMESPACE);
+const ORACLE_GAS_REBATES: Map<&str, OracleGasRebateV1> = Map::new(ORACLE_GAS_REBATE_NAMESPACE);
+const PAYABLE_MINIMUM_PAYMENTS: Map<&str, Uint128> = Map::new(PAYABLE_MINIMUM_PAYMENT_NAMESPACE);
+const RESTRUCTURE_PROPOSALS: Map<&str, RestructureProposalV1> =
+    Map::new(RESTRUCTURE_PROPOSAL_NAMESPACE);
+const SUPPLY_CHECKED_PAYABLE_TYPES: Map<&str, bool> =
+    Map::new(SUPPLY_CHECKED_PAYABLE_TYPES_NAMESPACE);
+const FROZEN_PAYABLE_TYPES: Map<&str, bool> = Map::new(FROZEN_PAYABLE_TYPES_NAMESPACE);
+const ORACLE_APPROVAL_CONTEXTS: Map<&str, OracleApprovalContextV1> =
+    Map::new(ORACLE_APPROVAL_CONTEXT_NAMESPACE);
+const ORACLE_DELEGATES: Map<(&str, &str), bool> = Map::new(ORACLE_DELEGATES_NAMESPACE);
+const PAYABLE_TIMELINES: Map<&str, PayableTimelineV1> = Map::new(PAYABLE_TIMELINE_NAMESPACE);
+const PAYABLE_SUBSCRIBERS: Map<(&str, &str), bool> = Map::new(PAYABLE_SUBSCRIBERS_NAMESPACE);
+const PAYABLE_SUBSCRIBER_COUNTS: Map<&str, u64> = Map::new(PAYABLE_SUBSCRIBER_COUNTS_NAMESPACE);
+const PAYMENT_HISTORY: Map<(&str, u64), PaymentRecordV1> = Map::new(PAYMENT_HISTORY_NAMESPACE);
+const FEE_HOLIDAYS: Map<&str, FeeHolidayV1> = Map::new(FEE_HOLIDAYS_NAMESPACE);
+const HELD_ORACLE_FEES: Map<&str, HeldOracleFeeV1> = Map::new(HELD_ORACLE_FEE_NAMESPACE);
+const PAYABLE_TYPE_CONFIGS: Map<&str, PayableTypeConfigV1> =
+    Map::new(PAYABLE_TYPE_CONFIG_NAMESPACE);
+const SUPPORTED_PAYABLE_TYPES: Map<&str, bool> = Map::new(SUPPORTED_PAYABLE_TYPES_NAMESPACE);
+const PAYABLE_SNAPSHOTS: Map<(&str, u64), PayableSnapshotV1> =
+    Map::new(PAYABLE_SNAPSHOTS_NAMESPACE);
+const PAYABLE_META_PATCH_PROPOSALS: Map<&str, PayableMetaPatchProposalV1> =
+    Map::new(PAYABLE_META_PATCH_PROPOSAL_NAMESPACE);
+const PAYABLE_META_PATCH_AUDIT: Map<(&str, u64), PayableMetaPatchRecordV1> =
+    Map::new(PAYABLE_META_PATCH_AUDIT_NAMESPACE);
+const PAYMENT_VOLUME_CAPS: Map<&str, PaymentVolumeCapV1> = Map::new(PAYMENT_VOLUME_CAP_NAMESPACE);
+const PAYMENT_VOLUME_WINDOWS: Map<&str, PaymentVolumeWindowV1> =
+    Map::new(PAYMENT_VOLUME_WINDOW_NAMESPACE);
+#[cfg(feature = "escrow")]
+const PAYABLE_ESCROW_TIMEOUTS: Map<&str, u64> = Map::new(PAYABLE_ESCROW_TIMEOUT_NAMESPACE);
+const PAYABLE_AUTHORIZED_PAYERS: Map<&str, Vec<Addr>> =
+    Map::new(PAYABLE_AUTHORIZED_PAYERS_NAMESPACE);
+const PAYABLE_PAYEE_SPLITS: Map<&str, Vec<PayeeSplitV1>> = Map::new(PAYABLE_PAYEE_SPLITS_NAMESPACE);
+const REGISTRATION_NONCES: Map<&str, RegistrationNonceV1> = Map::new(REGISTRATION_NONCE_NAMESPACE);
+#[cfg(feature = "escrow")]
+const ESCROWED_PAYMENTS: Map<(&str, u64), EscrowedPaymentV1> = Map::new(ESCROWED_PAYMENT_NAMESPACE);
+#[cfg(feature = "escrow")]
+const PENDING_CANCELLATIONS: Map<&str, PendingCancellationV1> =
+    Map::new(PENDING_CANCELLATION_NAMESPACE);
+#[cfg(feature = "escrow")]
+const ESCROW_TOTALS: Map<(&str, &str), EscrowTotalV1> = Map::new(ESCROW_TOTALS_NAMESPACE);
+const PAYMENT_REVERSALS: Map<(&str, u64), PaymentReversalV1> = Map::new(PAYMENT_REVERSAL_NAMESPACE);
+const QUARANTINE_CONFIG: Item<QuarantineConfigV1> = Item::new(QUARANTINE_CONFIG_NAMESPACE);
+const RISK_BANDS: Item<Vec<RiskBandV1>> = Item::new(RISK_BANDS_NAMESPACE);
+const QUARANTINED_PAYMENTS: Map<(&str, u64), QuarantinedPaymentV1> =
+    Map::new(QUARANTINED_PAYMENT_NAMESPACE);
+const PROPOSED_PAYMENTS: Map<&str, ProposedPaymentV1> = Map::new(PROPOSED_PAYMENT_NAMESPACE);
+const PAYMENT_VELOCITY: Map<(&str, u64), PaymentVelocityBucketV1> =
+    Map::new(PAYMENT_VELOCITY_NAMESPACE);
+#[cfg(feature = "allowance")]
+const PAYMENT_ALLOWANCES: Map<(&str, &str), PaymentAllowanceV1> =
+    Map::new(PAYMENT_ALLOWANCE_NAMESPACE);
+const REFERRAL_CONFIG: Item<ReferralConfigV1> = Item::new(REFERRAL_CONFIG_NAMESPACE);
+const REFERRER_REVENUE: Map<(&str, &str), ReferrerRevenueV1> = Map::new(REFERRER_REVENUE_NAMESPACE);
 
-pub static CONFIG_KEY_V2: &[u8] = b"config_v2";
-pub static PAYABLE_META_V2_KEY: &[u8] = b"payable_meta_v2";
+/// The width of each bucket that payment velocity is counted in.
+pub const PAYMENT_VELOCITY_BUCKET_SECONDS: u64 = 3600;
+/// The number of payment velocity buckets retained for each denom, covering the longest window that
+/// payment velocity is reported over.
+pub const PAYMENT_VELOCITY_RETAINED_BUCKETS: u64 = 24;
 
 /// Stores all relevant data about the contract. Modifiable only partially by migrations
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -25,13 +121,139 @@ pub struct StateV2 {
     pub fee_percent: Decimal,
     // Whether nor not the contract is running locally.  Skips some important checks if enabled, which expedites testing
     pub is_local: bool,
+    // When set, payments only rewrite the scope attribute once the window's thresholds are crossed
+    pub payment_batch_window: Option<PaymentBatchWindowV1>,
+    // The address allowed to manage contract-level configuration through executes.  Contracts
+    // instantiated before admin support have no admin until one is provided via migration
+    pub admin: Option<Addr>,
+    // When set, migrations that change state must first be staged by the admin and can only be
+    // applied once this many seconds have passed
+    pub migration_timelock_seconds: Option<u64>,
+    // When set, oracle approvals accept and refund small amounts of a single denom instead of
+    // rejecting any attached funds
+    pub oracle_funds_tolerance: Option<FundsToleranceV1>,
+    // When set, payments below this amount are rejected unless they settle the payable's remaining
+    // balance.  Individual payables may override it at registration
+    pub minimum_payment_amount: Option<Uint128>,
+    // When set, registrations, oracle approvals, and payments are rejected until the admin resumes
+    // the contract.  Queries and administrative executions are unaffected
+    #[serde(default)]
+    pub is_paused: bool,
+    // When set, payables that have been paid off may be pruned from the contract once this many
+    // seconds have passed since they were settled
+    pub settled_retention_seconds: Option<u64>,
+    // When set, oracle approvals lapse this many seconds after they are made, and payments are
+    // rejected until the oracle renews the approval
+    pub approval_valid_duration: Option<u64>,
+    // When set, payables still awaiting oracle approval this many seconds after registration are
+    // reported as breaching the oracle's approval SLA
+    pub oracle_approval_sla_seconds: Option<u64>,
+    // When set, payments larger than this amount must be proposed by the payer and approved by the
+    // payable's oracle before they are sent to the payee
+    pub large_payment_threshold: Option<Uint128>,
+    // When set, the admin may reverse an escrowed payment within this many seconds of it being
+    // made, returning it to its payer
+    pub payment_reversal_window_seconds: Option<u64>,
+    // The format of the name and attribute messages sent to the Provenance modules, which varies
+    // by the chain version of the environment that the contract runs in
+    #[serde(default)]
+    pub provenance_msg_version: ProvenanceMsgVersion,
+    // Addresses, such as delegated onboarding services, that may register payables on behalf of
+    // scope owners without being an owner of the scope themselves
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authorized_registrars: Vec<Addr>,
 }
+impl StateV2 {
+    pub fn is_admin(&self, address: &Addr) -> bool {
+        self.admin.as_ref() == Some(address)
+    }
 
-pub fn config_v2(storage: &mut dyn Storage) -> Singleton<StateV2> {
+    /// Ensures that an admin-only execute was sent by the contract admin without any funds, as
+    /// every admin execute is free.
+    pub fn check_admin(&self, info: &MessageInfo) -> Result<(), ContractError> {
+        if !info.funds.is_empty() {
+            return Err(ContractError::FundsPresent);
+        }
+        if !self.is_admin(&info.sender) {
+            return Err(ContractError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub fn is_authorized_registrar(&self, address: &Addr) -> bool {
+        self.authorized_registrars.contains(address)
+    }
+
+    /// Produces a factory for the Provenance messages sent by the contract, in the format of the
+    /// configured message version.
+    pub fn provenance_msg_factory(&self) -> ProvenanceMsgFactory {
+        ProvenanceMsgFactory::new(self.provenance_msg_version)
+    }
+
+    /// Produces a ContractPaused error if the admin has paused the contract, for use by any
+    /// execution that moves payables or funds through their lifecycle.
+    pub fn check_not_paused(&self) -> Result<(), ContractError> {
+        if self.is_paused {
+            ContractError::ContractPaused.to_result()
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Defines when payments should be written to a payable's scope attribute.  Payments that fall
+/// within the window are accumulated in local storage instead, which avoids rewriting the attribute
+/// for payables that receive many small payments.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentBatchWindowV1 {
+    // The attribute is rewritten when the remaining owed has dropped by more than this percentage of
+    // the payable's total owed since the last write
+    pub change_threshold: Decimal,
+    // The attribute is rewritten when this many blocks have elapsed since the first deferred payment
+    pub block_interval: u64,
+}
+impl PaymentBatchWindowV1 {
+    pub fn fields(&self) -> PaymentBatchWindowFields {
+        PaymentBatchWindowFields {
+            change_threshold: self.change_threshold.atomics().u128(),
+            block_interval: self.block_interval,
+        }
+    }
+}
+
+/// Defines the funds that can be attached to an otherwise-free execute without failing it.  Funds
+/// within the tolerance are refunded to the sender.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FundsToleranceV1 {
+    // The only denom that may be attached
+    pub denom: String,
+    // The largest total amount of the denom that may be attached
+    pub max_amount: Uint128,
+}
+impl FundsToleranceV1 {
+    pub fn fields(&self) -> FundsToleranceFields<'_> {
+        FundsToleranceFields {
+            denom: &self.denom,
+            max_amount: self.max_amount.u128(),
+        }
+    }
+
+    /// Determines if every provided coin is of the tolerated denom, and if their total does not
+    /// exceed the max amount.
+    pub fn allows(&self, funds: &[Coin]) -> bool {
+        match Money::zero(&self.denom).checked_add_coins(funds) {
+            Ok(total) => total.amount <= self.max_amount,
+            // Either a coin of another denom was provided, or the total overflowed
+            Err(_) => false,
+        }
+    }
+}
+
+pub fn config_v2(storage: &mut dyn Storage) -> Singleton<'_, StateV2> {
     singleton(storage, CONFIG_KEY_V2)
 }
 
-pub fn config_read_v2(storage: &dyn Storage) -> ReadonlySingleton<StateV2> {
+pub fn config_read_v2(storage: &dyn Storage) -> ReadonlySingleton<'_, StateV2> {
     singleton_read(storage, CONFIG_KEY_V2)
 }
 
@@ -55,6 +277,296 @@ pub struct PayableScopeAttribute {
     // Whether or not the oracle has reviewed the structure of the payable and determine if it is
     // a valid payable
     pub oracle_approved: bool,
+    // Details about the oracle approval, written to the attribute when the approval occurs.
+    // Attributes for payables approved before this was tracked will not include it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oracle_approval_context: Option<OracleApprovalContextV1>,
+    // The time after which the payable can no longer be approved or paid, and can be expired by
+    // anyone if it has not received any payments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration_time: Option<Timestamp>,
+    // Whether or not the payee has forgiven the remaining balance of the payable.  A written off
+    // payable has no remaining owed amount and can no longer accept payments
+    #[serde(default)]
+    pub is_written_off: bool,
+    // The reason provided by the payee when the payable was written off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_off_reason: Option<String>,
+    // The address that receives payments after the payable has been transferred.  Payables that
+    // have never been transferred pay their scope's value owner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payee: Option<Addr>,
+    // Additional denoms that the payable accepts for payment.  The payable_denom is canonical: the
+    // amounts owed are tracked in it, and payments in these denoms are applied to it one-to-one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accepted_denoms: Vec<String>,
+    // The installments that the payable is expected to be paid in, ordered by due time.  Payments
+    // are applied to installments in order, so an installment is satisfied once the amount paid
+    // covers it and every installment before it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payment_schedule: Vec<PaymentInstallmentV1>,
+    // Whether or not the payee has refused the payable.  A declined payable can no longer be
+    // approved or paid
+    #[serde(default)]
+    pub is_declined: bool,
+    // The late fee terms set at registration.  Late fees accrue lazily, when payments are made
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub late_fee: Option<LateFeeV1>,
+    // The late fees accrued against the payable so far, populated once its due time has passed
+    // and a payment has been made
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub late_fee_accrual: Option<LateFeeAccrualV1>,
+    // The open dispute raised against the payable, if any.  A disputed payable cannot be paid
+    // until its oracle or the contract admin resolves the dispute
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dispute: Option<PayableDisputeV1>,
+    // The block time of the payment that paid off the payable.  Cleared if the payable is reopened
+    // by a reclaimed escrow payment or a total adjustment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settled_at: Option<Timestamp>,
+    // The interest terms set at registration.  Like late fees, interest accrues lazily, when
+    // payments are made
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interest: Option<InterestTermsV1>,
+    // The interest accrued against the payable so far, populated once a payment has been made after
+    // the interest start time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interest_accrual: Option<InterestAccrualV1>,
+    // Whether or not the payable's oracle has halted payment activity on it.  A frozen payable
+    // cannot be paid until its oracle unfreezes it
+    #[serde(default)]
+    pub is_frozen: bool,
+}
+
+impl PayableScopeAttribute {
+    /// Calculates the late fees that have accrued since the payable's due time, or since they were
+    /// last accrued, without applying them.  Fees accrue once per full day overdue, at the daily
+    /// rate of the remaining owed amount, so any partial day carries over to the next accrual.
+    /// Fractional amounts are rounded down so that late fees are never over-charged.
+    pub fn late_fees_due(&self, now: Timestamp) -> Result<Uint128, ContractError> {
+        let late_fee = match &self.late_fee {
+            Some(late_fee) => late_fee,
+            None => return Ok(Uint128::zero()),
+        };
+        let days_overdue = self.late_fee_days_overdue(late_fee, now);
+        let rate_atomics = late_fee
+            .daily_rate
+            .atomics()
+            .checked_mul(days_overdue.into())
+            .map_err(StdError::from)?;
+        self.payable_remaining_owed
+            .checked_multiply_ratio(rate_atomics, Decimal::one().atomics())
+            .map_err(|e| ContractError::std_err(format!("late fee calculation failed: {}", e)))
+    }
+
+    /// Adds the late fees that have accrued as of the given time to the total and remaining owed
+    /// amounts, and advances the accrual by each full day that was charged.  Returns the amount of
+    /// late fees added.
+    pub fn accrue_late_fees(&mut self, now: Timestamp) -> Result<Uint128, ContractError> {
+        let late_fee = match &self.late_fee {
+            Some(late_fee) => late_fee,
+            None => return Ok(Uint128::zero()),
+        };
+        let days_overdue = self.late_fee_days_overdue(late_fee, now);
+        if days_overdue == 0 {
+            return Ok(Uint128::zero());
+        }
+        let fees_due = self.late_fees_due(now)?;
+        let accrual = self
+            .late_fee_accrual
+            .get_or_insert_with(|| LateFeeAccrualV1 {
+                accrued_through: late_fee.due_time,
+                amount_accrued: Uint128::zero(),
+                amount_paid: Uint128::zero(),
+            });
+        accrual.accrued_through = accrual
+            .accrued_through
+            .plus_seconds(days_overdue * SECONDS_PER_DAY);
+        accrual.amount_accrued = accrual
+            .amount_accrued
+            .checked_add(fees_due)
+            .map_err(StdError::from)?;
+        self.payable_total_owed = self
+            .payable_total_owed
+            .checked_add(fees_due)
+            .map_err(StdError::from)?;
+        self.payable_remaining_owed = self
+            .payable_remaining_owed
+            .checked_add(fees_due)
+            .map_err(StdError::from)?;
+        Ok(fees_due)
+    }
+
+    /// Calculates the interest that has accrued on the remaining principal since the interest
+    /// start time, or since it was last accrued, without applying it.  Accrued late fees and
+    /// interest are excluded from the principal, so the terms' compounding only applies within the
+    /// period since the last accrual.  Fractional amounts are rounded down so that interest is
+    /// never over-charged.
+    pub fn interest_due(&self, now: Timestamp) -> Result<Uint128, ContractError> {
+        let interest = match &self.interest {
+            Some(interest) => interest,
+            None => return Ok(Uint128::zero()),
+        };
+        calculate_accrued_interest(
+            self.principal_remaining(),
+            interest.annual_rate,
+            now.seconds()
+                .saturating_sub(self.interest_accrued_through(interest).seconds()),
+            &interest.compounding,
+        )
+    }
+
+    /// Adds the interest that has accrued as of the given time to the total and remaining owed
+    /// amounts, and advances the accrual to the given time.  Returns the amount of interest added.
+    pub fn accrue_interest(&mut self, now: Timestamp) -> Result<Uint128, ContractError> {
+        let interest = match &self.interest {
+            Some(interest) => interest,
+            None => return Ok(Uint128::zero()),
+        };
+        if now <= self.interest_accrued_through(interest) {
+            return Ok(Uint128::zero());
+        }
+        let interest_due = self.interest_due(now)?;
+        let accrual = self
+            .interest_accrual
+            .get_or_insert_with(|| InterestAccrualV1 {
+                accrued_through: interest.start_time,
+                amount_accrued: Uint128::zero(),
+                amount_paid: Uint128::zero(),
+            });
+        accrual.accrued_through = now;
+        accrual.amount_accrued = accrual
+            .amount_accrued
+            .checked_add(interest_due)
+            .map_err(StdError::from)?;
+        self.payable_total_owed = self
+            .payable_total_owed
+            .checked_add(interest_due)
+            .map_err(StdError::from)?;
+        self.payable_remaining_owed = self
+            .payable_remaining_owed
+            .checked_add(interest_due)
+            .map_err(StdError::from)?;
+        Ok(interest_due)
+    }
+
+    /// The portion of the remaining owed amount that is neither an outstanding late fee nor
+    /// outstanding interest.
+    pub fn principal_remaining(&self) -> Uint128 {
+        let late_fees_outstanding = self
+            .late_fee_accrual
+            .as_ref()
+            .map_or(Uint128::zero(), |accrual| accrual.amount_outstanding());
+        let interest_outstanding = self
+            .interest_accrual
+            .as_ref()
+            .map_or(Uint128::zero(), |accrual| accrual.amount_outstanding());
+        self.payable_remaining_owed
+            .saturating_sub(late_fees_outstanding)
+            .saturating_sub(interest_outstanding)
+    }
+
+    /// The time through which interest has been charged, or the interest start time if it has
+    /// never been accrued.
+    fn interest_accrued_through(&self, interest: &InterestTermsV1) -> Timestamp {
+        self.interest_accrual
+            .as_ref()
+            .map_or(interest.start_time, |accrual| accrual.accrued_through)
+    }
+
+    /// The number of full days between the last accrual (or the due time) and the given time.  A
+    /// paid off payable accrues nothing further.
+    fn late_fee_days_overdue(&self, late_fee: &LateFeeV1, now: Timestamp) -> u64 {
+        if self.payable_remaining_owed.is_zero() {
+            return 0;
+        }
+        let accrued_through = self
+            .late_fee_accrual
+            .as_ref()
+            .map_or(late_fee.due_time, |accrual| accrual.accrued_through);
+        now.seconds().saturating_sub(accrued_through.seconds()) / SECONDS_PER_DAY
+    }
+
+    /// Determines if payments can be made to the payable in the given denom: its payable_denom or
+    /// any of its additional accepted denoms.
+    pub fn accepts_denom(&self, denom: &str) -> bool {
+        self.payable_denom == denom
+            || self
+                .accepted_denoms
+                .iter()
+                .any(|accepted| accepted == denom)
+    }
+
+    /// Splits the amount applied to the payable so far across its payment schedule, filling each
+    /// installment in due order before moving on to the next.  The amount applied is everything no
+    /// longer owed, so the balance forgiven by a write off also satisfies installments.
+    pub fn installment_amounts_paid(&self) -> Vec<Uint128> {
+        let mut amount_applied = self
+            .payable_total_owed
+            .saturating_sub(self.payable_remaining_owed);
+        self.payment_schedule
+            .iter()
+            .map(|installment| {
+                let amount_paid = installment.amount.min(amount_applied);
+                amount_applied -= amount_paid;
+                amount_paid
+            })
+            .collect()
+    }
+
+    /// Counts the installments in the payment schedule that have been paid in full.
+    pub fn installments_satisfied(&self) -> usize {
+        self.payment_schedule
+            .iter()
+            .zip(self.installment_amounts_paid())
+            .take_while(|(installment, amount_paid)| *amount_paid == installment.amount)
+            .count()
+    }
+
+    /// Determines when the payable's oracle approval lapses under the contract's approval valid
+    /// duration.  Approvals that were made before their time was recorded never lapse.
+    pub fn approval_lapses_at(&self, approval_valid_duration: Option<u64>) -> Option<Timestamp> {
+        let approved_at = self.oracle_approval_context.as_ref()?.approved_at?;
+        Some(approved_at.plus_seconds(approval_valid_duration?))
+    }
+
+    /// Determines when the payable's oracle approval goes stale if no payment has been made under
+    /// the validity window that the oracle approved it with.  Approvals without one never go stale.
+    pub fn approval_valid_until(&self) -> Option<Timestamp> {
+        let approval_context = self.oracle_approval_context.as_ref()?;
+        Some(
+            approval_context
+                .approved_at?
+                .plus_seconds(approval_context.approval_valid_for?),
+        )
+    }
+
+    /// The risk score that the payable's oracle assigned it at its latest approval, if any.
+    pub fn risk_score(&self) -> Option<u32> {
+        self.oracle_approval_context.as_ref()?.risk_score
+    }
+
+    /// Determines if the payable's expiration time has passed.  Payables without an expiration
+    /// time never expire.
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        match self.expiration_time {
+            Some(expiration_time) => now > expiration_time,
+            None => false,
+        }
+    }
+
+    /// Produces a PayableExpired error if the payable's expiration time has passed, for use by
+    /// any execution that cannot proceed on an expired payable.
+    pub fn check_not_expired(&self, now: Timestamp) -> Result<(), ContractError> {
+        match self.expiration_time {
+            Some(expiration_time) if now > expiration_time => ContractError::PayableExpired {
+                payable_uuid: self.payable_uuid.clone(),
+                expiration_time,
+            }
+            .to_result(),
+            _ => Ok(()),
+        }
+    }
 }
 
 /// This struct is used to link a payable uuid to a scope id to allow querying for PayableScopeAttribute
@@ -67,10 +579,1976 @@ pub struct PayableMetaV2 {
     pub scope_id: String,
 }
 
-pub fn payable_meta_storage_v2(storage: &mut dyn Storage) -> Bucket<PayableMetaV2> {
+pub fn payable_meta_storage_v2(storage: &mut dyn Storage) -> Bucket<'_, PayableMetaV2> {
     bucket(storage, PAYABLE_META_V2_KEY)
 }
 
-pub fn payable_meta_storage_read_v2(storage: &dyn Storage) -> ReadonlyBucket<PayableMetaV2> {
+pub fn payable_meta_storage_read_v2(storage: &dyn Storage) -> ReadonlyBucket<'_, PayableMetaV2> {
     bucket_read(storage, PAYABLE_META_V2_KEY)
 }
+
+/// Loads up to the limit of registered payables in payable uuid order, beginning after the given
+/// payable uuid.
+pub fn load_payable_meta_page(
+    storage: &dyn Storage,
+    start_after: Option<&str>,
+    limit: usize,
+) -> StdResult<Vec<PayableMetaV2>> {
+    // Bucket ranges include their start key, so the cursor itself is skipped if it is returned
+    payable_meta_storage_read_v2(storage)
+        .range(
+            start_after.map(|uuid| uuid.as_bytes()),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, meta)| meta))
+        .filter(|item| match (item, start_after) {
+            (Ok(meta), Some(start_after)) => meta.payable_uuid != start_after,
+            _ => true,
+        })
+        .take(limit)
+        .collect()
+}
+
+/// Tracks payments that have been applied to a payable but have not yet been written to its scope
+/// attribute due to the contract's payment batch window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentBatchV1 {
+    // The unique identifier for the payable that the deferred payments were made against
+    pub payable_uuid: String,
+    // The amount of payable_denom left unpaid on the payable after all deferred payments
+    pub remaining_owed: Uint128,
+    // The amount of payable_denom left unpaid as currently recorded on the scope attribute
+    pub synced_remaining_owed: Uint128,
+    // The block height at which the first deferred payment in the batch was made
+    pub batch_start_height: u64,
+}
+
+pub fn load_payment_batch(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<PaymentBatchV1>> {
+    PAYMENT_BATCHES.may_load(storage, payable_uuid)
+}
+
+pub fn save_payment_batch(storage: &mut dyn Storage, batch: &PaymentBatchV1) -> StdResult<()> {
+    PAYMENT_BATCHES.save(storage, &batch.payable_uuid, batch)
+}
+
+pub fn remove_payment_batch(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYMENT_BATCHES.remove(storage, payable_uuid)
+}
+
+pub fn load_payable_custom_fields(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<Binary>> {
+    PAYABLE_CUSTOM_FIELDS.may_load(storage, payable_uuid)
+}
+
+pub fn save_payable_custom_fields(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    custom_fields: &Binary,
+) -> StdResult<()> {
+    PAYABLE_CUSTOM_FIELDS.save(storage, payable_uuid, custom_fields)
+}
+
+pub fn remove_payable_custom_fields(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_CUSTOM_FIELDS.remove(storage, payable_uuid)
+}
+
+/// An amount paid by a payable's registrant on top of the onboarding cost to have the payable
+/// prioritized for oracle approval.  It is held by the contract until the oracle approves the
+/// payable, or is returned to the registrant if the payable is removed before approval.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayablePriorityFeeV1 {
+    // The amount held for the oracle
+    pub amount: Uint128,
+    // The denom in which the fee was paid, which is the onboarding denom at registration
+    pub denom: String,
+}
+
+pub fn load_payable_priority_fee(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<PayablePriorityFeeV1>> {
+    PAYABLE_PRIORITY_FEES.may_load(storage, payable_uuid)
+}
+
+pub fn save_payable_priority_fee(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    priority_fee: &PayablePriorityFeeV1,
+) -> StdResult<()> {
+    PAYABLE_PRIORITY_FEES.save(storage, payable_uuid, priority_fee)
+}
+
+pub fn remove_payable_priority_fee(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_PRIORITY_FEES.remove(storage, payable_uuid)
+}
+
+pub fn load_payable_minimum_payment(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<Uint128>> {
+    PAYABLE_MINIMUM_PAYMENTS.may_load(storage, payable_uuid)
+}
+
+pub fn save_payable_minimum_payment(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    minimum_payment_amount: &Uint128,
+) -> StdResult<()> {
+    PAYABLE_MINIMUM_PAYMENTS.save(storage, payable_uuid, minimum_payment_amount)
+}
+
+pub fn remove_payable_minimum_payment(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_MINIMUM_PAYMENTS.remove(storage, payable_uuid)
+}
+
+/// Pending new terms for an approved payable.  The terms are only applied once both the payee and
+/// the oracle have confirmed them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RestructureProposalV1 {
+    // The unique identifier for the payable that the proposal restructures
+    pub payable_uuid: String,
+    // The total owed that the payable will have once the proposal is applied
+    pub new_total: Uint128,
+    // The scope owner that confirmed the terms on behalf of the payee, if any
+    pub payee_confirmation: Option<Addr>,
+    // The oracle address that confirmed the terms, if any
+    pub oracle_confirmation: Option<Addr>,
+}
+
+pub fn load_restructure_proposal(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<RestructureProposalV1>> {
+    RESTRUCTURE_PROPOSALS.may_load(storage, payable_uuid)
+}
+
+pub fn save_restructure_proposal(
+    storage: &mut dyn Storage,
+    proposal: &RestructureProposalV1,
+) -> StdResult<()> {
+    RESTRUCTURE_PROPOSALS.save(storage, &proposal.payable_uuid, proposal)
+}
+
+pub fn remove_restructure_proposal(storage: &mut dyn Storage, payable_uuid: &str) {
+    RESTRUCTURE_PROPOSALS.remove(storage, payable_uuid)
+}
+
+/// An amount of the onboarding denom earmarked by a payable's registrant to cover its oracle's gas
+/// costs.  It is held by the contract until the oracle approves the payable, or is returned to the
+/// registrant if the payable is removed before approval.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OracleGasRebateV1 {
+    // The address that funded the rebate, and that will receive it back if it is never paid out
+    pub registrant: Addr,
+    // The amount held for the oracle
+    pub amount: Uint128,
+    // The denom in which the rebate was paid, which is the onboarding denom at registration
+    pub denom: String,
+}
+
+pub fn load_oracle_gas_rebate(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<OracleGasRebateV1>> {
+    ORACLE_GAS_REBATES.may_load(storage, payable_uuid)
+}
+
+pub fn save_oracle_gas_rebate(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    rebate: &OracleGasRebateV1,
+) -> StdResult<()> {
+    ORACLE_GAS_REBATES.save(storage, payable_uuid, rebate)
+}
+
+pub fn remove_oracle_gas_rebate(storage: &mut dyn Storage, payable_uuid: &str) {
+    ORACLE_GAS_REBATES.remove(storage, payable_uuid)
+}
+
+/// Describes how amounts of a denom should be rendered to users, ex: a symbol of "USDF" and an
+/// exponent of 2 would display 100000 base units as 1,000.00 USDF.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DenomDisplayMetadataV1 {
+    // The human-readable symbol for the denom
+    pub symbol: String,
+    // The number of decimal places between the base unit and the display unit
+    pub exponent: u32,
+}
+
+pub fn load_denom_display_metadata(
+    storage: &dyn Storage,
+    denom: &str,
+) -> StdResult<Option<DenomDisplayMetadataV1>> {
+    DENOM_DISPLAY_METADATA.may_load(storage, denom)
+}
+
+pub fn save_denom_display_metadata(
+    storage: &mut dyn Storage,
+    denom: &str,
+    metadata: &DenomDisplayMetadataV1,
+) -> StdResult<()> {
+    DENOM_DISPLAY_METADATA.save(storage, denom, metadata)
+}
+
+pub fn remove_denom_display_metadata(storage: &mut dyn Storage, denom: &str) {
+    DENOM_DISPLAY_METADATA.remove(storage, denom)
+}
+
+/// Determines if registrations of the given payable type must have a payable total that does not
+/// exceed the total supply of their marker denom.
+pub fn is_payable_type_supply_checked(
+    storage: &dyn Storage,
+    payable_type: &str,
+) -> StdResult<bool> {
+    SUPPLY_CHECKED_PAYABLE_TYPES
+        .may_load(storage, payable_type)
+        .map(|checked| checked.unwrap_or(false))
+}
+
+/// Enables or disables the marker supply check for a payable type.  Disabled types are removed from
+/// storage entirely, because the check is disabled by default.
+pub fn set_payable_type_supply_checked(
+    storage: &mut dyn Storage,
+    payable_type: &str,
+    checked: bool,
+) -> StdResult<()> {
+    if checked {
+        SUPPLY_CHECKED_PAYABLE_TYPES.save(storage, payable_type, &true)
+    } else {
+        SUPPLY_CHECKED_PAYABLE_TYPES.remove(storage, payable_type);
+        Ok(())
+    }
+}
+
+/// Determines if payables of the given payable type have been frozen by the contract admin, which
+/// prevents them from being approved or paid.
+pub fn is_payable_type_frozen(storage: &dyn Storage, payable_type: &str) -> StdResult<bool> {
+    FROZEN_PAYABLE_TYPES
+        .may_load(storage, payable_type)
+        .map(|frozen| frozen.unwrap_or(false))
+}
+
+/// Freezes or unfreezes a payable type.  Unfrozen types are removed from storage entirely, because
+/// payable types are unfrozen by default.
+pub fn save_payable_type_frozen(
+    storage: &mut dyn Storage,
+    payable_type: &str,
+    frozen: bool,
+) -> StdResult<()> {
+    if frozen {
+        FROZEN_PAYABLE_TYPES.save(storage, payable_type, &true)
+    } else {
+        FROZEN_PAYABLE_TYPES.remove(storage, payable_type);
+        Ok(())
+    }
+}
+
+/// Loads every payable type frozen by the contract admin, in payable type order.
+pub fn load_frozen_payable_types(storage: &dyn Storage) -> StdResult<Vec<String>> {
+    FROZEN_PAYABLE_TYPES
+        .keys(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// A dispute raised by a party to a payable, such as a payer contesting the amount owed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableDisputeV1 {
+    pub raised_by: Addr,
+    pub reason: String,
+    pub raised_at: Timestamp,
+}
+
+/// The terms of the penalty charged on a payable that is not paid off by its due time.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LateFeeV1 {
+    // The time after which late fees begin to accrue
+    pub due_time: Timestamp,
+    // The portion of the remaining owed amount charged for each full day overdue
+    pub daily_rate: Decimal,
+}
+
+/// The late fees charged against a payable, tracked separately from its principal.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LateFeeAccrualV1 {
+    // The time through which late fees have been charged
+    pub accrued_through: Timestamp,
+    // The total amount of payable_denom charged in late fees, included in the total owed
+    pub amount_accrued: Uint128,
+    // The amount of payable_denom paid toward late fees.  Payments settle late fees first
+    pub amount_paid: Uint128,
+}
+
+impl LateFeeAccrualV1 {
+    /// The portion of the accrued late fees that has not yet been paid.
+    pub fn amount_outstanding(&self) -> Uint128 {
+        self.amount_accrued - self.amount_paid
+    }
+}
+
+/// The terms of the interest charged on a payable's remaining principal.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterestTermsV1 {
+    // The time after which interest begins to accrue
+    pub start_time: Timestamp,
+    // The portion of the remaining principal charged for each full year that passes
+    pub annual_rate: Decimal,
+    // How often accrued interest is compounded between accruals.  Terms set before compounding was
+    // selectable accrue simple interest
+    #[serde(default)]
+    pub compounding: InterestCompounding,
+}
+
+/// The interest charged against a payable, tracked separately from its principal.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterestAccrualV1 {
+    // The time through which interest has been charged
+    pub accrued_through: Timestamp,
+    // The total amount of payable_denom charged in interest, included in the total owed
+    pub amount_accrued: Uint128,
+    // The amount of payable_denom paid toward interest.  Payments settle interest before principal
+    pub amount_paid: Uint128,
+}
+
+impl InterestAccrualV1 {
+    /// The portion of the accrued interest that has not yet been paid.
+    pub fn amount_outstanding(&self) -> Uint128 {
+        self.amount_accrued - self.amount_paid
+    }
+}
+
+/// A portion of a payable's total that is expected to be paid by a given time.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentInstallmentV1 {
+    // The time by which the installment should be paid
+    pub due_time: Timestamp,
+    // The amount of payable_denom due in the installment
+    pub amount: Uint128,
+}
+
+/// Describes the oracle run that vouched for a payable, allowing approvals to be traced back to
+/// the oracle and any external validation job that produced them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OracleApprovalContextV1 {
+    // The address of the oracle that approved the payable
+    pub oracle_address: Addr,
+    // The block height at which the approval occurred
+    pub block_height: u64,
+    // An optional identifier provided by the oracle, ex: the id of its validation job
+    pub external_reference_id: Option<String>,
+    // The delegate of the oracle that submitted the approval on the oracle's behalf, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate_address: Option<Addr>,
+    // The block time at which the approval occurred, or was last renewed.  Approvals made before
+    // approval expiry was supported will not include it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved_at: Option<Timestamp>,
+    // The risk score that the oracle assigned the payable, if any.  Higher scores are riskier, and
+    // select the risk band that the payable's payments are keyed off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk_score: Option<u32>,
+    // The number of seconds after the approval within which a payment must be made.  If none is,
+    // the approval goes stale and the payable can be reverted to unapproved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval_valid_for: Option<u64>,
+}
+
+pub fn load_oracle_approval_context(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<OracleApprovalContextV1>> {
+    ORACLE_APPROVAL_CONTEXTS.may_load(storage, payable_uuid)
+}
+
+pub fn save_oracle_approval_context(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    context: &OracleApprovalContextV1,
+) -> StdResult<()> {
+    ORACLE_APPROVAL_CONTEXTS.save(storage, payable_uuid, context)
+}
+
+pub fn remove_oracle_approval_context(storage: &mut dyn Storage, payable_uuid: &str) {
+    ORACLE_APPROVAL_CONTEXTS.remove(storage, payable_uuid)
+}
+
+/// Loads the approval context of every approved payable.  Intended for aggregate queries only, as
+/// the cost grows with the number of payables.
+pub fn load_all_oracle_approval_contexts(
+    storage: &dyn Storage,
+) -> StdResult<Vec<OracleApprovalContextV1>> {
+    ORACLE_APPROVAL_CONTEXTS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, context)| context))
+        .collect()
+}
+
+/// Determines if the delegate has been granted authority to approve payables on behalf of the oracle.
+pub fn is_oracle_delegate(storage: &dyn Storage, oracle: &Addr, delegate: &Addr) -> bool {
+    ORACLE_DELEGATES.has(storage, (oracle.as_str(), delegate.as_str()))
+}
+
+pub fn add_oracle_delegate(
+    storage: &mut dyn Storage,
+    oracle: &Addr,
+    delegate: &Addr,
+) -> StdResult<()> {
+    ORACLE_DELEGATES.save(storage, (oracle.as_str(), delegate.as_str()), &true)
+}
+
+pub fn remove_oracle_delegate(storage: &mut dyn Storage, oracle: &Addr, delegate: &Addr) {
+    ORACLE_DELEGATES.remove(storage, (oracle.as_str(), delegate.as_str()))
+}
+
+/// Records when each lifecycle event occurred for a payable.  Payables registered before timelines
+/// were tracked only contain the events that have occurred since.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableTimelineV1 {
+    // The unique identifier for the payable that the timeline describes
+    pub payable_uuid: String,
+    // The block time at which the payable was registered
+    pub registered_at: Option<Timestamp>,
+    // The block time at which the oracle approved the payable
+    pub approved_at: Option<Timestamp>,
+    // The block time of each payment made against the payable, in the order they were made
+    pub payment_times: Vec<Timestamp>,
+    // The block time of the payment that brought the remaining owed to zero
+    pub completed_at: Option<Timestamp>,
+    // The memo attached to each payment that included one, in the order they were made.  Timelines
+    // recorded before memos were supported will not include this
+    #[serde(default)]
+    pub payment_memos: Vec<PaymentMemoV1>,
+    // The block time at which the payee declined the payable
+    #[serde(default)]
+    pub declined_at: Option<Timestamp>,
+    // The block time at which the registrant cancelled the payable
+    #[serde(default)]
+    pub cancelled_at: Option<Timestamp>,
+    // The block time at which the payable was expired and removed from the contract
+    #[serde(default)]
+    pub expired_at: Option<Timestamp>,
+}
+impl PayableTimelineV1 {
+    pub fn new(payable_uuid: impl Into<String>) -> PayableTimelineV1 {
+        PayableTimelineV1 {
+            payable_uuid: payable_uuid.into(),
+            registered_at: None,
+            approved_at: None,
+            payment_times: vec![],
+            completed_at: None,
+            payment_memos: vec![],
+            declined_at: None,
+            cancelled_at: None,
+            expired_at: None,
+        }
+    }
+}
+
+/// A memo that a payer attached to a payment, such as an invoice reference.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentMemoV1 {
+    // The block time of the payment that the memo was attached to
+    pub paid_at: Timestamp,
+    // The memo provided by the payer
+    pub memo: String,
+}
+
+pub fn load_payable_timeline(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<PayableTimelineV1>> {
+    PAYABLE_TIMELINES.may_load(storage, payable_uuid)
+}
+
+/// Applies the update to the payable's stored timeline, starting a new timeline if none exists.
+pub fn update_payable_timeline<F: FnOnce(&mut PayableTimelineV1)>(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    update: F,
+) -> StdResult<()> {
+    let mut timeline = load_payable_timeline(storage, payable_uuid)?
+        .unwrap_or_else(|| PayableTimelineV1::new(payable_uuid));
+    update(&mut timeline);
+    PAYABLE_TIMELINES.save(storage, payable_uuid, &timeline)
+}
+
+pub fn remove_payable_timeline(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_TIMELINES.remove(storage, payable_uuid)
+}
+
+/// Loads the timeline of every payable that has one.  Intended for aggregate queries only, as the
+/// cost grows with the number of payables.
+pub fn load_all_payable_timelines(storage: &dyn Storage) -> StdResult<Vec<PayableTimelineV1>> {
+    PAYABLE_TIMELINES
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, timeline)| timeline))
+        .collect()
+}
+
+/// A single payment made against a payable, recorded in the order that payments were made.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentRecordV1 {
+    // The address that sent the payment
+    pub payer: Addr,
+    // The amount applied to the payable, excluding any refunded overpayment
+    pub amount: Uint128,
+    // The denom in which the payment was made
+    pub denom: String,
+    // The block time at which the payment was made
+    pub paid_at: Timestamp,
+    // The client that a third party, such as a factoring agent, made the payment for.  Payments
+    // recorded before attribution was supported will not include this
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Addr>,
+    // The external ledger reference, ex: a wire confirmation id, of a portion of the payable that
+    // was settled outside of the chain.  No funds were sent on chain for these records
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_reference: Option<String>,
+    // The amount sent to each payee of a payable registered with payee splits, once the payment
+    // has been sent.  Payments to payables without payee splits will not include this
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payee_splits: Option<Vec<PayeeSplitPaymentV1>>,
+    // The portion of the amount that settled outstanding late fees.  Payments recorded before the
+    // split was tracked are treated as having settled none
+    #[serde(default, skip_serializing_if = "Uint128::is_zero")]
+    pub late_fees_paid: Uint128,
+    // The portion of the amount that settled outstanding interest
+    #[serde(default, skip_serializing_if = "Uint128::is_zero")]
+    pub interest_paid: Uint128,
+    // How the payment was sent on, once it has been released from escrow by settlement netting.
+    // Payments that were never netted will not include this
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub netting: Option<PaymentNettingV1>,
+}
+
+/// The portion of a single payment that was sent to one of its payable's payees.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayeeSplitPaymentV1 {
+    pub address: Addr,
+    pub amount: Uint128,
+}
+
+/// Appends a payment to the payable's history, keyed on the payable and the next sequence number.
+/// Returns the sequence number, which identifies the payment within the payable.
+pub fn append_payment_record(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    record: &PaymentRecordV1,
+) -> StdResult<u64> {
+    let sequence = match PAYMENT_HISTORY
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Descending)
+        .next()
+    {
+        Some(last_sequence) => last_sequence? + 1,
+        None => 0,
+    };
+    PAYMENT_HISTORY.save(storage, (payable_uuid, sequence), record)?;
+    Ok(sequence)
+}
+
+/// Loads a single payment made against the payable by the sequence number that identifies it.
+pub fn load_payment_record(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> StdResult<Option<PaymentRecordV1>> {
+    PAYMENT_HISTORY.may_load(storage, (payable_uuid, payment_id))
+}
+
+/// Records the amounts sent to each payee for a payment that was held by the contract before it
+/// was sent, such as an escrowed or quarantined payment.  The amounts are appended to any already
+/// recorded, as a payment partially held back by its risk band reaches the payees in two parts.
+pub fn record_payment_payee_splits(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+    payee_splits: &[PayeeSplitPaymentV1],
+) -> StdResult<()> {
+    PAYMENT_HISTORY.update(storage, (payable_uuid, payment_id), |record| match record {
+        Some(mut record) => {
+            record
+                .payee_splits
+                .get_or_insert_with(Vec::new)
+                .extend_from_slice(payee_splits);
+            Ok(record)
+        }
+        None => Err(StdError::not_found("PaymentRecordV1")),
+    })?;
+    Ok(())
+}
+
+/// Records how an escrowed payment was settled by netting on its payment history record.
+pub fn record_payment_netting(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+    netting: &PaymentNettingV1,
+) -> StdResult<()> {
+    PAYMENT_HISTORY.update(storage, (payable_uuid, payment_id), |record| match record {
+        Some(mut record) => {
+            record.netting = Some(netting.clone());
+            Ok(record)
+        }
+        None => Err(StdError::not_found("PaymentRecordV1")),
+    })?;
+    Ok(())
+}
+
+/// Loads every payment made against the payable, in the order that they were made.
+pub fn load_payment_history(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Vec<PaymentRecordV1>> {
+    PAYMENT_HISTORY
+        .prefix(payable_uuid)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, record)| record))
+        .collect()
+}
+
+/// Loads up to the given number of payments across every payable, ordered by payable uuid and then
+/// by the order in which each payable's payments were made.  Each payment is keyed on its payable
+/// uuid and sequence number, which can be used as the start_after cursor for the next page.
+pub fn load_payment_history_page(
+    storage: &dyn Storage,
+    start_after: Option<(&str, u64)>,
+    limit: usize,
+) -> StdResult<Vec<((String, u64), PaymentRecordV1)>> {
+    PAYMENT_HISTORY
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Scans up to scan_limit payments in the same order as load_payment_history_page, restricted to a
+/// single payable when one is provided, and produces up to limit of them that satisfy the filter.
+/// The key of the last payment scanned is produced alongside them whenever the scan stopped before
+/// reaching the end of the history, as the cursor from which to resume it.
+pub fn scan_payment_history(
+    storage: &dyn Storage,
+    payable_uuid: Option<&str>,
+    start_after: Option<(&str, u64)>,
+    scan_limit: usize,
+    limit: usize,
+    filter: impl Fn(&PaymentRecordV1) -> bool,
+) -> StdResult<PaymentHistoryScan> {
+    let min = match (start_after, payable_uuid) {
+        (Some(start_after), _) => Some(Bound::exclusive(start_after)),
+        (None, Some(payable_uuid)) => Some(Bound::inclusive((payable_uuid, 0))),
+        (None, None) => None,
+    };
+    let max = payable_uuid.map(|payable_uuid| Bound::inclusive((payable_uuid, u64::MAX)));
+    let mut scan = PaymentHistoryScan {
+        payments: vec![],
+        next_start_after: None,
+    };
+    for (scanned, item) in PAYMENT_HISTORY
+        .range(storage, min, max, Order::Ascending)
+        .enumerate()
+    {
+        if scanned == scan_limit || scan.payments.len() == limit {
+            return Ok(scan);
+        }
+        let (key, payment) = item?;
+        scan.next_start_after = Some(key.clone());
+        if filter(&payment) {
+            scan.payments.push((key, payment));
+        }
+    }
+    scan.next_start_after = None;
+    Ok(scan)
+}
+
+/// The payments produced by a payment history scan, and the cursor to resume it from, if any.
+pub struct PaymentHistoryScan {
+    pub payments: Vec<((String, u64), PaymentRecordV1)>,
+    pub next_start_after: Option<(String, u64)>,
+}
+
+pub fn is_payable_subscriber(storage: &dyn Storage, payable_uuid: &str, subscriber: &Addr) -> bool {
+    PAYABLE_SUBSCRIBERS.has(storage, (payable_uuid, subscriber.as_str()))
+}
+
+/// Registers the subscriber's interest in the payable and increments the payable's subscriber
+/// count.  The count is tracked separately so that events never need to range over every
+/// subscriber.
+pub fn add_payable_subscriber(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    subscriber: &Addr,
+) -> StdResult<()> {
+    PAYABLE_SUBSCRIBERS.save(storage, (payable_uuid, subscriber.as_str()), &true)?;
+    PAYABLE_SUBSCRIBER_COUNTS.update(storage, payable_uuid, |count| -> StdResult<u64> {
+        Ok(count.unwrap_or_default() + 1)
+    })?;
+    Ok(())
+}
+
+pub fn load_payable_subscriber_count(storage: &dyn Storage, payable_uuid: &str) -> StdResult<u64> {
+    Ok(PAYABLE_SUBSCRIBER_COUNTS
+        .may_load(storage, payable_uuid)?
+        .unwrap_or_default())
+}
+
+/// Loads up to the given number of the payable's subscribers, ordered by address.
+pub fn load_payable_subscribers(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    limit: usize,
+) -> StdResult<Vec<Addr>> {
+    PAYABLE_SUBSCRIBERS
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Ascending)
+        .take(limit)
+        .map(|subscriber| subscriber.map(Addr::unchecked))
+        .collect()
+}
+
+pub fn remove_payable_subscribers(storage: &mut dyn Storage, payable_uuid: &str) -> StdResult<()> {
+    let subscribers = PAYABLE_SUBSCRIBERS
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for subscriber in subscribers {
+        PAYABLE_SUBSCRIBERS.remove(storage, (payable_uuid, subscriber.as_str()));
+    }
+    PAYABLE_SUBSCRIBER_COUNTS.remove(storage, payable_uuid);
+    Ok(())
+}
+
+/// An admin-scheduled window during which registrations are charged a reduced onboarding cost.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeHolidayV1 {
+    // A unique identifier chosen by the admin, used to reschedule or cancel the holiday
+    pub holiday_id: String,
+    // The block time at which the holiday begins, inclusive
+    pub start_time: Timestamp,
+    // The block time at which the holiday ends, exclusive
+    pub end_time: Timestamp,
+    // The payable types that receive the reduced cost.  An empty list applies to all payable types
+    pub payable_types: Vec<String>,
+    // The onboarding cost charged in place of the configured cost, in the onboarding denom
+    pub onboarding_cost: Uint128,
+}
+impl FeeHolidayV1 {
+    /// Determines if a registration of the given payable type at the given time falls within this
+    /// holiday.
+    pub fn applies_to(&self, payable_type: &str, now: Timestamp) -> bool {
+        now >= self.start_time
+            && now < self.end_time
+            && (self.payable_types.is_empty()
+                || self.payable_types.iter().any(|t| t == payable_type))
+    }
+}
+
+pub fn load_fee_holiday(
+    storage: &dyn Storage,
+    holiday_id: &str,
+) -> StdResult<Option<FeeHolidayV1>> {
+    FEE_HOLIDAYS.may_load(storage, holiday_id)
+}
+
+pub fn save_fee_holiday(storage: &mut dyn Storage, holiday: &FeeHolidayV1) -> StdResult<()> {
+    FEE_HOLIDAYS.save(storage, &holiday.holiday_id, holiday)
+}
+
+pub fn remove_fee_holiday(storage: &mut dyn Storage, holiday_id: &str) {
+    FEE_HOLIDAYS.remove(storage, holiday_id)
+}
+
+/// Loads every scheduled fee holiday, ordered by holiday id.  Ended holidays are never consulted
+/// again, so callers are expected to filter on the end time.
+pub fn load_all_fee_holidays(storage: &dyn Storage) -> StdResult<Vec<FeeHolidayV1>> {
+    FEE_HOLIDAYS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, holiday)| holiday))
+        .collect()
+}
+
+/// The portion of a payable's onboarding cost that the contract retained for its oracle.  It is
+/// paid to the oracle on approval, or returned to the registrant if the payable is cancelled before
+/// approval.  Payables registered before the retained amount was tracked have no record, and their
+/// oracle is paid the currently configured amount instead.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HeldOracleFeeV1 {
+    // The address that paid the onboarding cost
+    pub registrant: Addr,
+    // The amount retained for the oracle
+    pub amount: Uint128,
+    // The denom in which the onboarding cost was charged, which follows the payable type's config
+    pub denom: String,
+}
+
+pub fn load_held_oracle_fee(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<HeldOracleFeeV1>> {
+    HELD_ORACLE_FEES.may_load(storage, payable_uuid)
+}
+
+pub fn save_held_oracle_fee(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    held_fee: &HeldOracleFeeV1,
+) -> StdResult<()> {
+    HELD_ORACLE_FEES.save(storage, payable_uuid, held_fee)
+}
+
+pub fn remove_held_oracle_fee(storage: &mut dyn Storage, payable_uuid: &str) {
+    HELD_ORACLE_FEES.remove(storage, payable_uuid)
+}
+
+/// The fields of a payable's local meta that may be corrected by a meta patch.  Any field left
+/// unset is not changed.  The payable uuid is the key to the meta, so it can never be patched.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableMetaPatchV1 {
+    // The scope that actually holds the payable's scope attribute
+    pub scope_id: Option<String>,
+}
+impl PayableMetaPatchV1 {
+    pub fn is_empty(&self) -> bool {
+        self.scope_id.is_none()
+    }
+}
+
+/// A pending patch to a payable's local meta.  The patch is only applied once both the contract
+/// admin and the payable's oracle have confirmed the same patch and reason.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableMetaPatchProposalV1 {
+    // The unique identifier for the payable that the proposal patches
+    pub payable_uuid: String,
+    // The fields to be written to the payable's meta
+    pub patch: PayableMetaPatchV1,
+    // The justification for the patch, recorded in the audit log once applied
+    pub reason: String,
+    // The admin address that confirmed the patch, if any
+    pub admin_confirmation: Option<Addr>,
+    // The oracle address that confirmed the patch, if any
+    pub oracle_confirmation: Option<Addr>,
+}
+
+pub fn load_payable_meta_patch_proposal(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<PayableMetaPatchProposalV1>> {
+    PAYABLE_META_PATCH_PROPOSALS.may_load(storage, payable_uuid)
+}
+
+pub fn save_payable_meta_patch_proposal(
+    storage: &mut dyn Storage,
+    proposal: &PayableMetaPatchProposalV1,
+) -> StdResult<()> {
+    PAYABLE_META_PATCH_PROPOSALS.save(storage, &proposal.payable_uuid, proposal)
+}
+
+pub fn remove_payable_meta_patch_proposal(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_META_PATCH_PROPOSALS.remove(storage, payable_uuid)
+}
+
+/// An applied patch to a payable's local meta.  Records are never removed, so the audit log
+/// outlives the payable itself.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableMetaPatchRecordV1 {
+    // The meta as it was before the patch was applied
+    pub previous_meta: PayableMetaV2,
+    // The meta as it was written by the patch
+    pub patched_meta: PayableMetaV2,
+    // The justification provided for the patch
+    pub reason: String,
+    // The admin address that confirmed the patch
+    pub admin: Addr,
+    // The oracle address that confirmed the patch
+    pub oracle: Addr,
+    // The block time at which the patch was applied
+    pub patched_at: Timestamp,
+}
+
+/// Appends an applied patch to the payable's meta patch audit log, keyed on the payable and the
+/// next sequence number.
+pub fn append_payable_meta_patch_record(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    record: &PayableMetaPatchRecordV1,
+) -> StdResult<()> {
+    let sequence = match PAYABLE_META_PATCH_AUDIT
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Descending)
+        .next()
+    {
+        Some(last_sequence) => last_sequence? + 1,
+        None => 0,
+    };
+    PAYABLE_META_PATCH_AUDIT.save(storage, (payable_uuid, sequence), record)
+}
+
+/// Loads every patch applied to the payable's meta, in the order that they were applied.
+pub fn load_payable_meta_patch_audit(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Vec<PayableMetaPatchRecordV1>> {
+    PAYABLE_META_PATCH_AUDIT
+        .prefix(payable_uuid)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, record)| record))
+        .collect()
+}
+
+/// Registration economics for a single payable type.  When a payable type has a config, its
+/// registrations are charged its onboarding cost, denom, and fee percent instead of the values in
+/// the contract state.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableTypeConfigV1 {
+    // The payable type that the config applies to, and the key to the config
+    pub payable_type: String,
+    // Cost to onboard each payable of the type
+    pub onboarding_cost: Uint128,
+    // Coin type for the onboarding charge
+    pub onboarding_denom: String,
+    // Percentage of the onboarding cost that is taken as fee
+    pub fee_percent: Decimal,
+    // The oracle assigned to registrations of the type that do not name an oracle
+    pub default_oracle: Option<Addr>,
+    // When set, registrations of the type must be sent by a scope party that satisfies it, instead
+    // of any of the scope's owners
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registrant_requirement: Option<RegistrantRequirementV1>,
+}
+
+/// Restricts which of a scope's parties may register a payable against it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistrantRequirementV1 {
+    // The sender must be listed as an owner of the scope with the given role
+    OwnerRole { role: PartyType },
+    // The sender must be the scope's value owner
+    ValueOwner,
+}
+impl RegistrantRequirementV1 {
+    pub fn is_satisfied_by(&self, scope: &Scope, sender: &Addr) -> bool {
+        match self {
+            RegistrantRequirementV1::OwnerRole { role } => scope
+                .owners
+                .iter()
+                .any(|owner| owner.address == *sender && owner.role == *role),
+            RegistrantRequirementV1::ValueOwner => scope.value_owner_address == *sender,
+        }
+    }
+
+    /// Describes the check that the requirement makes, for reporting which check a sender failed.
+    pub fn describe(&self) -> String {
+        match self {
+            RegistrantRequirementV1::OwnerRole { role } => {
+                format!("scope owner with role {:?}", role).to_lowercase()
+            }
+            RegistrantRequirementV1::ValueOwner => "scope value owner".to_string(),
+        }
+    }
+}
+
+pub fn load_payable_type_config(
+    storage: &dyn Storage,
+    payable_type: &str,
+) -> StdResult<Option<PayableTypeConfigV1>> {
+    PAYABLE_TYPE_CONFIGS.may_load(storage, payable_type)
+}
+
+/// Loads every payable type config, in payable type order.
+pub fn load_all_payable_type_configs(storage: &dyn Storage) -> StdResult<Vec<PayableTypeConfigV1>> {
+    PAYABLE_TYPE_CONFIGS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, config)| config))
+        .collect()
+}
+
+pub fn save_payable_type_config(
+    storage: &mut dyn Storage,
+    config: &PayableTypeConfigV1,
+) -> StdResult<()> {
+    PAYABLE_TYPE_CONFIGS.save(storage, &config.payable_type, config)
+}
+
+/// Determines if the admin has allowed payables of the given type to be registered.
+pub fn is_payable_type_supported(storage: &dyn Storage, payable_type: &str) -> bool {
+    SUPPORTED_PAYABLE_TYPES.has(storage, payable_type)
+}
+
+pub fn add_supported_payable_type(storage: &mut dyn Storage, payable_type: &str) -> StdResult<()> {
+    SUPPORTED_PAYABLE_TYPES.save(storage, payable_type, &true)
+}
+
+pub fn remove_supported_payable_type(storage: &mut dyn Storage, payable_type: &str) {
+    SUPPORTED_PAYABLE_TYPES.remove(storage, payable_type)
+}
+
+/// The key fields of a payable's scope attribute, captured each time the attribute is written so
+/// that changes to the payable can be traced without reconstructing them from events.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayableSnapshotV1 {
+    // The block time at which the attribute was written
+    pub taken_at: Timestamp,
+    // The block height at which the attribute was written
+    pub block_height: u64,
+    pub scope_id: String,
+    pub oracle_address: Addr,
+    pub payable_denom: String,
+    pub payable_total_owed: Uint128,
+    pub payable_remaining_owed: Uint128,
+    pub oracle_approved: bool,
+    pub expiration_time: Option<Timestamp>,
+    pub is_written_off: bool,
+    pub payee: Option<Addr>,
+    #[serde(default)]
+    pub is_declined: bool,
+    #[serde(default)]
+    pub is_disputed: bool,
+    #[serde(default)]
+    pub is_frozen: bool,
+    // The fees charged when the payable was registered.  Only set on the registration snapshot
+    #[serde(default)]
+    pub registration_fees: Option<RegistrationFeesV1>,
+}
+
+/// The fee percent and onboarding cost actually charged for a single payable's registration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegistrationFeesV1 {
+    // The fee percent applied to the onboarding cost, expressed in basis points
+    pub effective_fee_bps: Uint128,
+    pub onboarding_cost_charged: Money,
+}
+
+/// Captures the key fields of the attribute being written and appends them to the payable's
+/// snapshots, keyed on the payable and the next sequence number.  Returns the sequence number of
+/// the new snapshot.
+pub fn record_payable_snapshot(
+    storage: &mut dyn Storage,
+    attribute: &PayableScopeAttribute,
+    block: &BlockInfo,
+) -> StdResult<u64> {
+    save_payable_snapshot(storage, attribute, block, None)
+}
+
+/// Records the snapshot taken when a payable is registered, along with the fees that its
+/// registration was charged.  Returns the sequence number of the new snapshot.
+pub fn record_registration_snapshot(
+    storage: &mut dyn Storage,
+    attribute: &PayableScopeAttribute,
+    block: &BlockInfo,
+    registration_fees: RegistrationFeesV1,
+) -> StdResult<u64> {
+    save_payable_snapshot(storage, attribute, block, Some(registration_fees))
+}
+
+fn save_payable_snapshot(
+    storage: &mut dyn Storage,
+    attribute: &PayableScopeAttribute,
+    block: &BlockInfo,
+    registration_fees: Option<RegistrationFeesV1>,
+) -> StdResult<u64> {
+    let sequence = match load_latest_payable_snapshot_sequence(storage, &attribute.payable_uuid)? {
+        Some(last_sequence) => last_sequence + 1,
+        None => 0,
+    };
+    PAYABLE_SNAPSHOTS.save(
+        storage,
+        (&attribute.payable_uuid, sequence),
+        &PayableSnapshotV1 {
+            taken_at: block.time,
+            block_height: block.height,
+            scope_id: attribute.scope_id.clone(),
+            oracle_address: attribute.oracle_address.clone(),
+            payable_denom: attribute.payable_denom.clone(),
+            payable_total_owed: attribute.payable_total_owed,
+            payable_remaining_owed: attribute.payable_remaining_owed,
+            oracle_approved: attribute.oracle_approved,
+            expiration_time: attribute.expiration_time,
+            is_written_off: attribute.is_written_off,
+            payee: attribute.payee.clone(),
+            is_declined: attribute.is_declined,
+            is_disputed: attribute.dispute.is_some(),
+            is_frozen: attribute.is_frozen,
+            registration_fees,
+        },
+    )?;
+    Ok(sequence)
+}
+
+pub fn load_payable_snapshot(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    sequence: u64,
+) -> StdResult<Option<PayableSnapshotV1>> {
+    PAYABLE_SNAPSHOTS.may_load(storage, (payable_uuid, sequence))
+}
+
+/// Loads the payable's most recent snapshot, which reflects its current scope attribute unless
+/// payments have been deferred by the payment batch window.
+pub fn load_latest_payable_snapshot(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<PayableSnapshotV1>> {
+    PAYABLE_SNAPSHOTS
+        .prefix(payable_uuid)
+        .range(storage, None, None, Order::Descending)
+        .map(|item| item.map(|(_, snapshot)| snapshot))
+        .next()
+        .transpose()
+}
+
+/// Finds the sequence number of the payable's most recent snapshot, if it has any.
+pub fn load_latest_payable_snapshot_sequence(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<u64>> {
+    PAYABLE_SNAPSHOTS
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()
+}
+
+pub fn remove_payable_snapshots(storage: &mut dyn Storage, payable_uuid: &str) -> StdResult<()> {
+    let sequences = PAYABLE_SNAPSHOTS
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for sequence in sequences {
+        PAYABLE_SNAPSHOTS.remove(storage, (payable_uuid, sequence));
+    }
+    Ok(())
+}
+
+/// Limits the total amount of a denom that can be paid across all payables within a rolling window,
+/// bounding how much a compromised payer key can move before the admin is able to intervene.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentVolumeCapV1 {
+    // The payment volume in the denom that may be exceeded at most once per window
+    pub max_volume: Uint128,
+    // The length of each window, starting from the first payment made after the previous one ended
+    pub window_seconds: u64,
+}
+
+/// The payment volume of a denom observed in its current cap window.  Once the volume exceeds the
+/// cap, the breaker trips and further payments in the denom are rejected until the window rolls
+/// over or the admin resets it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentVolumeWindowV1 {
+    pub window_start: Timestamp,
+    pub volume: Uint128,
+    pub is_tripped: bool,
+}
+impl PaymentVolumeWindowV1 {
+    pub fn window_end(&self, cap: &PaymentVolumeCapV1) -> Timestamp {
+        self.window_start.plus_seconds(cap.window_seconds)
+    }
+}
+
+pub fn load_payment_volume_cap(
+    storage: &dyn Storage,
+    denom: &str,
+) -> StdResult<Option<PaymentVolumeCapV1>> {
+    PAYMENT_VOLUME_CAPS.may_load(storage, denom)
+}
+
+pub fn save_payment_volume_cap(
+    storage: &mut dyn Storage,
+    denom: &str,
+    cap: &PaymentVolumeCapV1,
+) -> StdResult<()> {
+    PAYMENT_VOLUME_CAPS.save(storage, denom, cap)
+}
+
+pub fn load_payment_volume_window(
+    storage: &dyn Storage,
+    denom: &str,
+) -> StdResult<Option<PaymentVolumeWindowV1>> {
+    PAYMENT_VOLUME_WINDOWS.may_load(storage, denom)
+}
+
+pub fn save_payment_volume_window(
+    storage: &mut dyn Storage,
+    denom: &str,
+    window: &PaymentVolumeWindowV1,
+) -> StdResult<()> {
+    PAYMENT_VOLUME_WINDOWS.save(storage, denom, window)
+}
+
+pub fn remove_payment_volume_window(storage: &mut dyn Storage, denom: &str) {
+    PAYMENT_VOLUME_WINDOWS.remove(storage, denom)
+}
+
+/// Loads the current cap window of every denom that has been paid since its cap was set, in denom
+/// order.  Windows are only rolled over by payments, so callers are expected to compare the window
+/// end to the block time.
+pub fn load_all_payment_volume_windows(
+    storage: &dyn Storage,
+) -> StdResult<Vec<(String, PaymentVolumeWindowV1)>> {
+    PAYMENT_VOLUME_WINDOWS
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// The number and total amount of payments made in a denom, across all payables, over a span of
+/// time.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct PaymentVelocityBucketV1 {
+    pub payment_count: u64,
+    pub payment_volume: Uint128,
+}
+
+/// Counts a payment towards the velocity bucket of its denom that contains the current block time,
+/// and removes any of the denom's buckets that have aged out of the retained range.
+pub fn record_payment_velocity(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    payment: &Money,
+) -> StdResult<()> {
+    let current_bucket = block.time.seconds() / PAYMENT_VELOCITY_BUCKET_SECONDS;
+    PAYMENT_VELOCITY.update(
+        storage,
+        (&payment.denom, current_bucket),
+        |bucket| -> StdResult<PaymentVelocityBucketV1> {
+            let mut bucket = bucket.unwrap_or_default();
+            bucket.payment_count += 1;
+            bucket.payment_volume += payment.amount;
+            Ok(bucket)
+        },
+    )?;
+    let oldest_retained_bucket =
+        current_bucket.saturating_sub(PAYMENT_VELOCITY_RETAINED_BUCKETS - 1);
+    let expired_buckets = PAYMENT_VELOCITY
+        .prefix(&payment.denom)
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(oldest_retained_bucket)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<u64>>>()?;
+    for bucket in expired_buckets {
+        PAYMENT_VELOCITY.remove(storage, (&payment.denom, bucket));
+    }
+    Ok(())
+}
+
+/// Sums the payment velocity of a denom over the given number of most recent buckets, including the
+/// bucket that contains the current block time.
+pub fn load_payment_velocity(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    denom: &str,
+    bucket_count: u64,
+) -> StdResult<PaymentVelocityBucketV1> {
+    let current_bucket = block.time.seconds() / PAYMENT_VELOCITY_BUCKET_SECONDS;
+    PAYMENT_VELOCITY
+        .prefix(denom)
+        .range(
+            storage,
+            Some(Bound::inclusive(
+                current_bucket.saturating_sub(bucket_count.saturating_sub(1)),
+            )),
+            Some(Bound::inclusive(current_bucket)),
+            Order::Ascending,
+        )
+        .try_fold(PaymentVelocityBucketV1::default(), |mut total, item| {
+            let (_, bucket) = item?;
+            total.payment_count += bucket.payment_count;
+            total.payment_volume += bucket.payment_volume;
+            Ok(total)
+        })
+}
+
+#[cfg(feature = "escrow")]
+pub fn load_payable_escrow_timeout(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<u64>> {
+    PAYABLE_ESCROW_TIMEOUTS.may_load(storage, payable_uuid)
+}
+
+#[cfg(feature = "escrow")]
+pub fn save_payable_escrow_timeout(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    escrow_timeout_seconds: u64,
+) -> StdResult<()> {
+    PAYABLE_ESCROW_TIMEOUTS.save(storage, payable_uuid, &escrow_timeout_seconds)
+}
+
+#[cfg(feature = "escrow")]
+pub fn remove_payable_escrow_timeout(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_ESCROW_TIMEOUTS.remove(storage, payable_uuid)
+}
+
+pub fn load_payable_authorized_payers(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<Vec<Addr>>> {
+    PAYABLE_AUTHORIZED_PAYERS.may_load(storage, payable_uuid)
+}
+
+pub fn save_payable_authorized_payers(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    authorized_payers: &[Addr],
+) -> StdResult<()> {
+    PAYABLE_AUTHORIZED_PAYERS.save(storage, payable_uuid, &authorized_payers.to_vec())
+}
+
+pub fn remove_payable_authorized_payers(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_AUTHORIZED_PAYERS.remove(storage, payable_uuid)
+}
+
+/// One of the payees that divide the proceeds of a syndicated payable.  Each payee receives the
+/// share of every payment that its weight makes up of the total weight of all payees.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PayeeSplitV1 {
+    pub address: String,
+    pub weight: u64,
+}
+
+pub fn load_payable_payee_splits(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<Vec<PayeeSplitV1>>> {
+    PAYABLE_PAYEE_SPLITS.may_load(storage, payable_uuid)
+}
+
+pub fn save_payable_payee_splits(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    payee_splits: &[PayeeSplitV1],
+) -> StdResult<()> {
+    PAYABLE_PAYEE_SPLITS.save(storage, payable_uuid, &payee_splits.to_vec())
+}
+
+pub fn remove_payable_payee_splits(storage: &mut dyn Storage, payable_uuid: &str) {
+    PAYABLE_PAYEE_SPLITS.remove(storage, payable_uuid)
+}
+
+/// The client-supplied nonce that a payable was registered with, which allows an identical retry
+/// of the registration to be recognized rather than rejected as a duplicate.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegistrationNonceV1 {
+    pub nonce: String,
+    // The address that registered the payable.  Only its retries are recognized
+    pub registered_by: Addr,
+    // The sha256 hash of the registration message.  Only identical retries are recognized
+    pub payload_hash: Binary,
+}
+
+pub fn load_registration_nonce(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<RegistrationNonceV1>> {
+    REGISTRATION_NONCES.may_load(storage, payable_uuid)
+}
+
+pub fn save_registration_nonce(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    registration_nonce: &RegistrationNonceV1,
+) -> StdResult<()> {
+    REGISTRATION_NONCES.save(storage, payable_uuid, registration_nonce)
+}
+
+pub fn remove_registration_nonce(storage: &mut dyn Storage, payable_uuid: &str) {
+    REGISTRATION_NONCES.remove(storage, payable_uuid)
+}
+
+/// A payment held by the contract for a payable registered in escrow mode, until the payee or
+/// oracle releases it or the payer reclaims it.
+#[cfg(feature = "escrow")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EscrowedPaymentV1 {
+    // The address that sent the payment, and to which a reclaimed payment is returned
+    pub payer: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The block time after which the payer may reclaim the payment if it has not been released
+    pub reclaimable_at: Timestamp,
+}
+
+#[cfg(feature = "escrow")]
+pub fn load_escrowed_payment(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> StdResult<Option<EscrowedPaymentV1>> {
+    ESCROWED_PAYMENTS.may_load(storage, (payable_uuid, payment_id))
+}
+
+/// Holds a payment in escrow and counts it towards the escrow total of its payable type and denom.
+#[cfg(feature = "escrow")]
+pub fn save_escrowed_payment(
+    storage: &mut dyn Storage,
+    payable_type: &str,
+    payable_uuid: &str,
+    payment_id: u64,
+    escrowed_payment: &EscrowedPaymentV1,
+) -> StdResult<()> {
+    ESCROWED_PAYMENTS.save(storage, (payable_uuid, payment_id), escrowed_payment)?;
+    ESCROW_TOTALS.update(
+        storage,
+        (payable_type, &escrowed_payment.denom),
+        |total| -> StdResult<EscrowTotalV1> {
+            let mut total = total.unwrap_or_else(|| EscrowTotalV1 {
+                payable_type: payable_type.to_string(),
+                denom: escrowed_payment.denom.clone(),
+                payment_count: 0,
+                amount: Uint128::zero(),
+            });
+            total.payment_count += 1;
+            total.amount = total.amount.checked_add(escrowed_payment.amount)?;
+            Ok(total)
+        },
+    )?;
+    Ok(())
+}
+
+/// Removes a payment from escrow and deducts it from the escrow total of its payable type and
+/// denom.  Totals are removed once they no longer hold any payments.
+#[cfg(feature = "escrow")]
+pub fn remove_escrowed_payment(
+    storage: &mut dyn Storage,
+    payable_type: &str,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> StdResult<()> {
+    let escrowed_payment = match load_escrowed_payment(storage, payable_uuid, payment_id)? {
+        Some(escrowed_payment) => escrowed_payment,
+        None => return Ok(()),
+    };
+    ESCROWED_PAYMENTS.remove(storage, (payable_uuid, payment_id));
+    let key = (payable_type, escrowed_payment.denom.as_str());
+    if let Some(mut total) = ESCROW_TOTALS.may_load(storage, key)? {
+        total.payment_count = total.payment_count.saturating_sub(1);
+        total.amount = total.amount.checked_sub(escrowed_payment.amount)?;
+        if total.payment_count == 0 {
+            ESCROW_TOTALS.remove(storage, key);
+        } else {
+            ESCROW_TOTALS.save(storage, key, &total)?;
+        }
+    }
+    Ok(())
+}
+
+/// Loads every payment that the contract holds in escrow for the payable, keyed by payment id.
+#[cfg(feature = "escrow")]
+pub fn load_escrowed_payments(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Vec<(u64, EscrowedPaymentV1)>> {
+    ESCROWED_PAYMENTS
+        .prefix(payable_uuid)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Determines whether or not the contract still holds any escrowed payments for the payable.
+#[cfg(feature = "escrow")]
+pub fn has_escrowed_payments(storage: &dyn Storage, payable_uuid: &str) -> bool {
+    ESCROWED_PAYMENTS
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// Builds without the escrow feature never hold payments in escrow.
+#[cfg(not(feature = "escrow"))]
+pub fn has_escrowed_payments(_storage: &dyn Storage, _payable_uuid: &str) -> bool {
+    false
+}
+
+/// Determines whether or not the contract holds the given payment in escrow.
+#[cfg(feature = "escrow")]
+pub fn is_payment_escrowed(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> StdResult<bool> {
+    Ok(ESCROWED_PAYMENTS.has(storage, (payable_uuid, payment_id)))
+}
+
+/// Builds without the escrow feature never hold payments in escrow.
+#[cfg(not(feature = "escrow"))]
+pub fn is_payment_escrowed(
+    _storage: &dyn Storage,
+    _payable_uuid: &str,
+    _payment_id: u64,
+) -> StdResult<bool> {
+    Ok(false)
+}
+
+/// The payments that the contract holds in escrow for payables of a single type, in a single denom.
+/// Updated as payments enter and leave escrow, so that the funds held can be monitored without
+/// scanning every escrowed payment.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EscrowTotalV1 {
+    pub payable_type: String,
+    pub denom: String,
+    pub payment_count: u64,
+    pub amount: Uint128,
+}
+
+/// Loads the escrow totals of the given payable type, or of every payable type when none is given,
+/// ordered by payable type and then denom.
+#[cfg(feature = "escrow")]
+pub fn load_escrow_totals(
+    storage: &dyn Storage,
+    payable_type: Option<&str>,
+) -> StdResult<Vec<EscrowTotalV1>> {
+    match payable_type {
+        Some(payable_type) => ESCROW_TOTALS
+            .prefix(payable_type)
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, total)| total))
+            .collect(),
+        None => ESCROW_TOTALS
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, total)| total))
+            .collect(),
+    }
+}
+
+/// How long the payers of a payable pending cancellation have to claim their escrowed payments
+/// before the cancellation can be finalized, in seconds.
+#[cfg(feature = "escrow")]
+pub const CANCELLATION_CLAIM_PERIOD_SECONDS: u64 = 7 * SECONDS_PER_DAY;
+
+/// A cancellation requested for a payable after its oracle approved it.  Payments and releases are
+/// halted while it is pending, so that the payers can claim back their escrowed payments before the
+/// cancellation is finalized.
+#[cfg(feature = "escrow")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingCancellationV1 {
+    // The scope owner that requested the cancellation
+    pub requested_by: Addr,
+    pub requested_at: Timestamp,
+    // The block time at and after which the cancellation may be finalized
+    pub finalizable_at: Timestamp,
+}
+
+#[cfg(feature = "escrow")]
+pub fn load_pending_cancellation(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<PendingCancellationV1>> {
+    PENDING_CANCELLATIONS.may_load(storage, payable_uuid)
+}
+
+#[cfg(feature = "escrow")]
+pub fn save_pending_cancellation(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    pending_cancellation: &PendingCancellationV1,
+) -> StdResult<()> {
+    PENDING_CANCELLATIONS.save(storage, payable_uuid, pending_cancellation)
+}
+
+#[cfg(feature = "escrow")]
+pub fn remove_pending_cancellation(storage: &mut dyn Storage, payable_uuid: &str) {
+    PENDING_CANCELLATIONS.remove(storage, payable_uuid)
+}
+
+/// How an escrowed payment was settled by netting it against the escrowed payments that its payee
+/// made to its payer.  The offset portion cancels out an equal amount owed to the payer, so it is
+/// returned to the payer in place of those payments, and only the net portion reaches the payee.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentNettingV1 {
+    // The payee of the payment, against whose escrowed payments to the payer it was netted
+    pub counterparty: Addr,
+    pub offset_amount: Uint128,
+    pub net_amount: Uint128,
+    pub netted_at: Timestamp,
+}
+
+/// The audit record of an escrowed payment that the admin returned to its payer, or of a payment
+/// that the admin or oracle flagged as erroneous, keyed on the same payable uuid and payment id as
+/// the payment's history record.  The history record is retained, so a reversal record marks its
+/// payment as never having been applied.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentReversalV1 {
+    // The address that made the payment, to which a reversed escrowed payment was returned
+    pub payer: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The admin or oracle address that reversed the payment
+    pub reversed_by: Addr,
+    pub reason: String,
+    pub reversed_at: Timestamp,
+}
+
+pub fn load_payment_reversal(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> StdResult<Option<PaymentReversalV1>> {
+    PAYMENT_REVERSALS.may_load(storage, (payable_uuid, payment_id))
+}
+
+pub fn save_payment_reversal(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+    reversal: &PaymentReversalV1,
+) -> StdResult<()> {
+    PAYMENT_REVERSALS.save(storage, (payable_uuid, payment_id), reversal)
+}
+
+/// Heuristics that route suspicious payments into quarantine, where the contract holds them until
+/// the admin releases them to the payee or refunds them to the payer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuarantineConfigV1 {
+    // Payments sent by, or on behalf of, any of these addresses are quarantined
+    pub flagged_payers: Vec<Addr>,
+    // Payments in any of these denoms of at least the listed amount are quarantined
+    pub large_payment_thresholds: Vec<Coin>,
+}
+impl QuarantineConfigV1 {
+    /// Describes the first heuristic that the payment trips, if any.
+    pub fn quarantine_reason(
+        &self,
+        payer: &Addr,
+        on_behalf_of: Option<&Addr>,
+        payment: &Money,
+    ) -> Option<String> {
+        if let Some(flagged_payer) = std::iter::once(payer)
+            .chain(on_behalf_of)
+            .find(|address| self.flagged_payers.contains(address))
+        {
+            return Some(format!(
+                "payment involves flagged address [{}]",
+                flagged_payer
+            ));
+        }
+        self.large_payment_thresholds
+            .iter()
+            .find(|threshold| {
+                threshold.denom == payment.denom && payment.amount >= threshold.amount
+            })
+            .map(|threshold| format!("payment meets large payment threshold [{}]", threshold))
+    }
+}
+
+pub fn load_quarantine_config(storage: &dyn Storage) -> StdResult<Option<QuarantineConfigV1>> {
+    QUARANTINE_CONFIG.may_load(storage)
+}
+
+pub fn save_quarantine_config(
+    storage: &mut dyn Storage,
+    quarantine_config: &QuarantineConfigV1,
+) -> StdResult<()> {
+    QUARANTINE_CONFIG.save(storage, quarantine_config)
+}
+
+pub fn remove_quarantine_config(storage: &mut dyn Storage) {
+    QUARANTINE_CONFIG.remove(storage)
+}
+
+/// The partners that may be named as the referrer of a registration, and the share of the collected
+/// fee that is routed to them for each registration they refer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralConfigV1 {
+    // The portion of each referred registration's collected fee sent to its referrer, in basis points
+    pub fee_share_bps: u64,
+    pub referrers: Vec<Addr>,
+}
+impl ReferralConfigV1 {
+    /// Finds the allowlisted referrer with the given address, if any.
+    pub fn find_referrer(&self, address: &str) -> Option<&Addr> {
+        self.referrers
+            .iter()
+            .find(|referrer| referrer.as_str() == address)
+    }
+}
+
+pub fn load_referral_config(storage: &dyn Storage) -> StdResult<Option<ReferralConfigV1>> {
+    REFERRAL_CONFIG.may_load(storage)
+}
+
+pub fn save_referral_config(
+    storage: &mut dyn Storage,
+    referral_config: &ReferralConfigV1,
+) -> StdResult<()> {
+    REFERRAL_CONFIG.save(storage, referral_config)
+}
+
+pub fn remove_referral_config(storage: &mut dyn Storage) {
+    REFERRAL_CONFIG.remove(storage)
+}
+
+/// The registrations a partner has referred in a single onboarding denom, and the total revenue
+/// share they have been paid for them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferrerRevenueV1 {
+    pub denom: String,
+    pub referral_count: u64,
+    pub total_revenue: Uint128,
+}
+
+/// Counts a referred registration towards its referrer's revenue in the share's denom.  Revenue is
+/// retained after a referrer is removed from the allowlist, so that partners can still be reported
+/// on.
+pub fn record_referrer_revenue(
+    storage: &mut dyn Storage,
+    referrer: &Addr,
+    share: &Money,
+) -> StdResult<()> {
+    REFERRER_REVENUE.update(
+        storage,
+        (referrer.as_str(), &share.denom),
+        |revenue| -> StdResult<ReferrerRevenueV1> {
+            let mut revenue = revenue.unwrap_or_else(|| ReferrerRevenueV1 {
+                denom: share.denom.clone(),
+                referral_count: 0,
+                total_revenue: Uint128::zero(),
+            });
+            revenue.referral_count += 1;
+            revenue.total_revenue = revenue.total_revenue.checked_add(share.amount)?;
+            Ok(revenue)
+        },
+    )?;
+    Ok(())
+}
+
+/// Loads the revenue that a referrer has been paid in every denom, ordered by denom.
+pub fn load_referrer_revenue(
+    storage: &dyn Storage,
+    referrer: &str,
+) -> StdResult<Vec<ReferrerRevenueV1>> {
+    REFERRER_REVENUE
+        .prefix(referrer)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, revenue)| revenue))
+        .collect()
+}
+
+/// Payment rules applied to payables whose oracle assigned them a risk score of at least the band's
+/// minimum score.  A payable falls into the band with the highest minimum score that it meets.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RiskBandV1 {
+    // The lowest risk score that falls into the band
+    pub min_score: u32,
+    // The portion of each payment sent directly to the payee that is instead quarantined until the
+    // admin releases or refunds it
+    pub holdback_percent: Option<Decimal>,
+    // Payments larger than this amount require oracle co-signing, replacing the contract's large
+    // payment threshold
+    pub large_payment_threshold: Option<Uint128>,
+}
+
+pub fn load_risk_bands(storage: &dyn Storage) -> StdResult<Vec<RiskBandV1>> {
+    Ok(RISK_BANDS.may_load(storage)?.unwrap_or_default())
+}
+
+pub fn save_risk_bands(storage: &mut dyn Storage, risk_bands: &[RiskBandV1]) -> StdResult<()> {
+    RISK_BANDS.save(storage, &risk_bands.to_vec())
+}
+
+pub fn remove_risk_bands(storage: &mut dyn Storage) {
+    RISK_BANDS.remove(storage)
+}
+
+/// Loads the risk band that the payable falls into, if its oracle assigned it a risk score.
+pub fn load_payable_risk_band(
+    storage: &dyn Storage,
+    scope_attribute: &PayableScopeAttribute,
+) -> StdResult<Option<RiskBandV1>> {
+    let risk_score = match scope_attribute.risk_score() {
+        Some(risk_score) => risk_score,
+        None => return Ok(None),
+    };
+    Ok(load_risk_bands(storage)?
+        .into_iter()
+        .filter(|band| band.min_score <= risk_score)
+        .max_by_key(|band| band.min_score))
+}
+
+/// A payment held by the contract because it tripped a quarantine heuristic, until the admin
+/// releases it to the payee or refunds it to the payer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuarantinedPaymentV1 {
+    pub payer: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The heuristic that the payment tripped
+    pub reason: String,
+    pub quarantined_at: Timestamp,
+}
+
+pub fn load_quarantined_payment(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> StdResult<Option<QuarantinedPaymentV1>> {
+    QUARANTINED_PAYMENTS.may_load(storage, (payable_uuid, payment_id))
+}
+
+/// Loads every payment quarantined for the payable, keyed on its id in the payable's payment
+/// history.
+pub fn load_quarantined_payments(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Vec<(u64, QuarantinedPaymentV1)>> {
+    QUARANTINED_PAYMENTS
+        .prefix(payable_uuid)
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+pub fn save_quarantined_payment(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+    quarantined_payment: &QuarantinedPaymentV1,
+) -> StdResult<()> {
+    QUARANTINED_PAYMENTS.save(storage, (payable_uuid, payment_id), quarantined_payment)
+}
+
+pub fn remove_quarantined_payment(storage: &mut dyn Storage, payable_uuid: &str, payment_id: u64) {
+    QUARANTINED_PAYMENTS.remove(storage, (payable_uuid, payment_id))
+}
+
+/// Counts the payments held in quarantine across all payables.  Intended for aggregate queries
+/// only, as the cost grows with the number of quarantined payments.
+pub fn count_quarantined_payments(storage: &dyn Storage) -> u64 {
+    QUARANTINED_PAYMENTS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .count() as u64
+}
+
+/// Determines whether or not the contract still holds any quarantined payments for the payable.
+pub fn has_quarantined_payments(storage: &dyn Storage, payable_uuid: &str) -> bool {
+    QUARANTINED_PAYMENTS
+        .prefix(payable_uuid)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// A payer's standing authorization for the contract to pull scheduled payments against a payable
+/// from the payer's account.  The pulls are made through an authz grant that the payer must also
+/// give the contract on chain, and are triggered by any keeper once they fall due.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PaymentAllowanceV1 {
+    pub payer: Addr,
+    pub denom: String,
+    // The most that may be pulled over the life of the allowance
+    pub max_total: Uint128,
+    // The amount pulled so far
+    pub pulled_total: Uint128,
+    // The amount pulled at each scheduled pull, or less if it would exceed the max total or the
+    // payable's remaining owed amount
+    pub pull_amount: Uint128,
+    pub pull_interval_seconds: u64,
+    pub next_pull_at: Timestamp,
+    // When set, no pulls are made at or after this time
+    pub expires_at: Option<Timestamp>,
+}
+impl PaymentAllowanceV1 {
+    pub fn remaining(&self) -> Uint128 {
+        self.max_total.saturating_sub(self.pulled_total)
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// Schedules the pull after the one made at the given time.  Pulls that a keeper missed are
+    /// skipped rather than made up, so a late crank never pulls more than once.
+    pub fn schedule_next_pull(&mut self, now: Timestamp) {
+        let missed_intervals = (now.seconds() - self.next_pull_at.seconds().min(now.seconds()))
+            / self.pull_interval_seconds;
+        self.next_pull_at = self
+            .next_pull_at
+            .plus_seconds(self.pull_interval_seconds * (missed_intervals + 1));
+    }
+}
+
+#[cfg(feature = "allowance")]
+pub fn load_payment_allowance(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payer: &Addr,
+) -> StdResult<Option<PaymentAllowanceV1>> {
+    PAYMENT_ALLOWANCES.may_load(storage, (payable_uuid, payer.as_str()))
+}
+
+/// Loads every payment allowance granted against the payable, in payer order.
+#[cfg(feature = "allowance")]
+pub fn load_payment_allowances(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Vec<PaymentAllowanceV1>> {
+    PAYMENT_ALLOWANCES
+        .prefix(payable_uuid)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, allowance)| allowance))
+        .collect()
+}
+
+#[cfg(feature = "allowance")]
+pub fn save_payment_allowance(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    allowance: &PaymentAllowanceV1,
+) -> StdResult<()> {
+    PAYMENT_ALLOWANCES.save(storage, (payable_uuid, allowance.payer.as_str()), allowance)
+}
+
+#[cfg(feature = "allowance")]
+pub fn remove_payment_allowance(storage: &mut dyn Storage, payable_uuid: &str, payer: &Addr) {
+    PAYMENT_ALLOWANCES.remove(storage, (payable_uuid, payer.as_str()))
+}
+
+/// A payment larger than the contract's large payment threshold, held by the contract until the
+/// payable's oracle approves it or the proposal is cancelled.  Each payable holds at most one.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposedPaymentV1 {
+    // The address that sent the funds, and to which a cancelled proposal is returned
+    pub payer: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    pub memo: Option<String>,
+    pub on_behalf_of: Option<Addr>,
+    pub proposed_at: Timestamp,
+}
+
+pub fn load_proposed_payment(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Option<ProposedPaymentV1>> {
+    PROPOSED_PAYMENTS.may_load(storage, payable_uuid)
+}
+
+pub fn save_proposed_payment(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    proposed_payment: &ProposedPaymentV1,
+) -> StdResult<()> {
+    PROPOSED_PAYMENTS.save(storage, payable_uuid, proposed_payment)
+}
+
+pub fn remove_proposed_payment(storage: &mut dyn Storage, payable_uuid: &str) {
+    PROPOSED_PAYMENTS.remove(storage, payable_uuid)
+}
+
+/// Counts the proposed payments awaiting approval by their payable's oracle.  Intended for
+/// aggregate queries only, as the cost grows with the number of proposals.
+pub fn count_proposed_payments(storage: &dyn Storage) -> u64 {
+    PROPOSED_PAYMENTS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .count() as u64
+}
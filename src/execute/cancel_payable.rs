@@ -0,0 +1,403 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+#[cfg(feature = "escrow")]
+use crate::core::state::load_payable_escrow_timeout;
+use crate::core::state::{
+    config_read_v2, load_held_oracle_fee, load_oracle_gas_rebate, load_payable_priority_fee,
+    remove_held_oracle_fee, remove_oracle_gas_rebate, remove_payable_priority_fee,
+    update_payable_timeline,
+};
+use crate::execute::expire_payable::remove_payable_local_storage;
+#[cfg(feature = "escrow")]
+use crate::execute::pending_cancellation::start_pending_cancellation;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    ONBOARDING_FEE_REFUNDED_KEY, ORACLE_GAS_REBATE_REFUNDED_KEY, PAYABLE_CANCELLED_KEY,
+    PRIORITY_FEE_REFUNDED_KEY, SCOPE_ID_KEY,
+};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that its registrant would like to withdraw from the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CancelPayableV1 {
+    pub payable_uuid: String,
+}
+
+/// Parent function path for the contract to cancel a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn cancel_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    cancel: CancelPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    cancel_payable_with_util(deps, &ProvenanceUtilImpl, env, info, cancel)
+}
+
+/// Removes a payable that its oracle never approved from the contract with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender owns the payable's scope.
+/// - Ensures that the oracle has not approved the payable.  Payments require approval, so an
+///   unapproved payable has never been paid.  An approved payable that holds its payments in escrow
+///   is instead left pending cancellation, so that its payers can claim back their escrowed
+///   payments before the cancellation is finalized.
+/// - Returns every amount that the contract held for the oracle: the retained oracle portion of the
+///   onboarding cost, the priority fee, and the oracle gas rebate.  Payables registered before the
+///   retained portion was tracked have no record of it, so it cannot be returned for them.
+/// - Deletes the payable's attribute from its scope and removes its local storage, recording the
+///   cancellation time on its timeline, which is kept.
+pub fn cancel_payable_with_util<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    cancel: CancelPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &cancel.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: cancel.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    // Only an owner of the payable's scope may cancel it.  Skip this step locally, matching the
+    // registration process
+    if !state.is_local
+        && provenance_util
+            .get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?
+            .owners
+            .into_iter()
+            .filter(|owner| owner.address == info.sender)
+            .count()
+            == 0
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    if scope_attribute.oracle_approved {
+        #[cfg(feature = "escrow")]
+        if load_payable_escrow_timeout(deps.storage, &scope_attribute.payable_uuid)?.is_some() {
+            return start_pending_cancellation(deps.storage, &env, &info.sender, &scope_attribute);
+        }
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "payables approved by their oracle cannot be cancelled".into(),
+        }
+        .to_result();
+    }
+    let payable_uuid = scope_attribute.payable_uuid.as_str();
+    let response = refund_held_oracle_funds(
+        deps.storage,
+        payable_uuid,
+        Some(&info.sender),
+        ResponseBuilder::for_payable(&scope_attribute),
+    )?
+    // Subscribers are notified of the cancellation before their subscriptions are cleared
+    .notify_subscribers(deps.storage, payable_uuid)?;
+    remove_payable_local_storage(deps.storage, payable_uuid)?;
+    update_payable_timeline(deps.storage, payable_uuid, |timeline| {
+        timeline.cancelled_at = Some(env.block.time);
+    })?;
+    Ok(response
+        .message(
+            state
+                .provenance_msg_factory()
+                .delete_scope_attributes(&scope_attribute.scope_id, &state.contract_name)?,
+        )
+        .attribute(PAYABLE_CANCELLED_KEY, &scope_attribute.payable_uuid)
+        .attribute(SCOPE_ID_KEY, &scope_attribute.scope_id)
+        .build())
+}
+
+/// Returns every amount that the contract holds for a payable's oracle to the payable's registrant:
+/// the retained oracle portion of the onboarding cost, the priority fee, and the oracle gas rebate,
+/// and removes the record of each.  The priority fee is not stored with its payer, so it follows
+/// the retained oracle fee back to its registrant, falling back to the provided address for
+/// payables registered before the retained fee was tracked.  Approval pays all of these amounts to
+/// the oracle, so this must only be used for payables that the oracle has not approved.  The
+/// refunds are added to the provided response.
+pub(crate) fn refund_held_oracle_funds(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    fallback_refund_address: Option<&Addr>,
+    mut response: ResponseBuilder,
+) -> Result<ResponseBuilder, ContractError> {
+    let mut refund_address = fallback_refund_address.cloned();
+    if let Some(held_fee) = load_held_oracle_fee(storage, payable_uuid)? {
+        let refund_amount = Money::new(held_fee.amount, &held_fee.denom);
+        if !refund_amount.is_zero() {
+            response = response
+                .message(refund_amount.send_to(&held_fee.registrant))
+                .attribute(ONBOARDING_FEE_REFUNDED_KEY, refund_amount.to_string());
+        }
+        refund_address = Some(held_fee.registrant);
+        remove_held_oracle_fee(storage, payable_uuid);
+    }
+    if let (Some(priority_fee), Some(refund_address)) = (
+        load_payable_priority_fee(storage, payable_uuid)?,
+        refund_address,
+    ) {
+        let refund_amount = Money::new(priority_fee.amount, &priority_fee.denom);
+        response = response
+            .message(refund_amount.send_to(&refund_address))
+            .attribute(PRIORITY_FEE_REFUNDED_KEY, refund_amount.to_string());
+        remove_payable_priority_fee(storage, payable_uuid);
+    }
+    if let Some(rebate) = load_oracle_gas_rebate(storage, payable_uuid)? {
+        let refund_amount = Money::new(rebate.amount, &rebate.denom);
+        response = response
+            .message(refund_amount.send_to(&rebate.registrant))
+            .attribute(ORACLE_GAS_REBATE_REFUNDED_KEY, refund_amount.to_string());
+        remove_oracle_gas_rebate(storage, payable_uuid);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        config_v2, load_held_oracle_fee, load_payable_timeline, payable_meta_storage_read_v2,
+        remove_held_oracle_fee,
+    };
+    use crate::execute::cancel_payable::{cancel_payable_with_util, CancelPayableV1};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        ONBOARDING_FEE_REFUNDED_KEY, ORACLE_GAS_REBATE_REFUNDED_KEY, PAYABLE_CANCELLED_KEY,
+        PRIORITY_FEE_REFUNDED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Response, StdResult, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_cancel_payable_refunds_held_funds() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // The onboarding cost of 100 retains 25 for the oracle, and the priority fee and gas rebate
+        // are held on top of it
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                env: mock_env(),
+                info: mock_info(DEFAULT_INFO_NAME, &[coin(160, DEFAULT_ONBOARDING_DENOM)]),
+                register_payable: RegisterPayableV2 {
+                    priority_fee: Some(Uint128::new(50)),
+                    oracle_gas_rebate: Some(Uint128::new(10)),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = test_cancel_payable(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_CANCELLED_KEY),
+            "the PAYABLE_CANCELLED_KEY value should equate to the payable uuid",
+        );
+        for (key, amount) in [
+            (ONBOARDING_FEE_REFUNDED_KEY, 25),
+            (PRIORITY_FEE_REFUNDED_KEY, 50),
+            (ORACLE_GAS_REBATE_REFUNDED_KEY, 10),
+        ] {
+            assert_eq!(
+                format!("{}/{}", amount, DEFAULT_ONBOARDING_DENOM),
+                single_attribute_for_key(&response, key),
+                "the refunded amount should be emitted for {}",
+                key,
+            );
+            assert!(
+                response.messages.iter().any(|msg| msg.msg
+                    == CosmosMsg::Bank(BankMsg::Send {
+                        to_address: DEFAULT_INFO_NAME.to_string(),
+                        amount: vec![coin(amount, DEFAULT_ONBOARDING_DENOM)],
+                    })),
+                "{} should be returned to the registrant",
+                amount,
+            );
+        }
+        assert!(
+            payable_meta_storage_read_v2(&deps.storage)
+                .may_load(DEFAULT_PAYABLE_UUID.as_bytes())
+                .unwrap()
+                .is_none(),
+            "the payable's meta should be removed",
+        );
+        assert!(
+            load_held_oracle_fee(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the held oracle fee should be removed",
+        );
+        assert_eq!(
+            Some(mock_env().block.time),
+            load_payable_timeline(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expect("the payable's timeline should be kept")
+                .cancelled_at,
+            "the cancellation time should be recorded on the payable's timeline",
+        );
+    }
+
+    #[test]
+    fn test_cancel_payable_refunds_held_funds_in_registration_denom() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                env: mock_env(),
+                info: mock_info(DEFAULT_INFO_NAME, &[coin(160, DEFAULT_ONBOARDING_DENOM)]),
+                register_payable: RegisterPayableV2 {
+                    priority_fee: Some(Uint128::new(50)),
+                    oracle_gas_rebate: Some(Uint128::new(10)),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        // Simulate a migration changing the onboarding denom after the funds were held
+        config_v2(&mut deps.storage)
+            .update(|mut state| -> StdResult<_> {
+                state.onboarding_denom = "newdenom".to_string();
+                Ok(state)
+            })
+            .unwrap();
+        let response = test_cancel_payable(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        let refunds = response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                vec![coin(25, DEFAULT_ONBOARDING_DENOM)],
+                vec![coin(50, DEFAULT_ONBOARDING_DENOM)],
+                vec![coin(10, DEFAULT_ONBOARDING_DENOM)],
+            ],
+            refunds,
+            "every held amount should be refunded in the denom it was received in",
+        );
+    }
+
+    #[test]
+    fn test_cancel_payable_without_held_fee_record() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Simulate a payable registered before retained oracle fees were tracked
+        remove_held_oracle_fee(&mut deps.storage, DEFAULT_PAYABLE_UUID);
+        let response = test_cancel_payable(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != ONBOARDING_FEE_REFUNDED_KEY),
+            "no onboarding fee can be refunded without a record of it",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_CANCELLED_KEY),
+            "the payable should still be cancelled",
+        );
+    }
+
+    #[test]
+    fn test_cancel_payable_after_oracle_approval() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let error =
+            test_cancel_payable(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "an approved payable should not be cancellable, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_cancel_payable_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error =
+            test_cancel_payable(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should be reported as not found, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = cancel_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, DEFAULT_ONBOARDING_DENOM)]),
+            cancel_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+        // Hand the scope to a different owner after registration
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "another-guy"));
+        let error =
+            test_cancel_payable(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "a non-owner should not be able to cancel the payable, but got: {:?}",
+            error,
+        );
+    }
+
+    fn cancel_default() -> CancelPayableV1 {
+        CancelPayableV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+        }
+    }
+
+    fn test_cancel_payable(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        cancel_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            cancel_default(),
+        )
+    }
+}
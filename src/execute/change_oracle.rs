@@ -0,0 +1,285 @@
+use crate::core::error::ContractError;
+use crate::core::state::config_read_v2;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{ORACLE_CHANGED_KEY, PREVIOUS_ORACLE_ADDRESS_KEY};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the fields required to reassign an unapproved payable to a different oracle.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ChangeOracleV1 {
+    pub payable_uuid: String,
+    pub new_oracle_address: String,
+}
+
+/// Parent function path for the contract to change a payable's oracle.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn change_oracle(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    change: ChangeOracleV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    change_oracle_with_util(deps, &ProvenanceUtilImpl, env, info, change)
+}
+
+/// Reassigns a registered payable to a new oracle with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the new oracle address is valid.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender is the contract admin or an owner of the payable's scope.
+/// - Ensures that the current oracle has not yet approved the payable.
+/// - Rewrites the scope attribute with the new oracle address.  The oracle's share of the
+///   onboarding fees is held by the contract until approval, so it is paid to the new oracle.
+pub fn change_oracle_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    change: ChangeOracleV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let new_oracle_address = deps.api.addr_validate(&change.new_oracle_address)?;
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &change.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: change.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    // The admin may always reassign an oracle, which allows payables to be recovered when their
+    // oracle is decommissioned.  Otherwise, the sender must own the scope, with the check skipped
+    // locally to match the registration process
+    if !state.is_admin(&info.sender)
+        && !state.is_local
+        && provenance_util
+            .get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?
+            .owners
+            .into_iter()
+            .filter(|owner| owner.address == info.sender)
+            .count()
+            == 0
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    if scope_attribute.oracle_approved {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the oracle cannot be changed after oracle approval".into(),
+        }
+        .to_result();
+    }
+    if scope_attribute.oracle_address == new_oracle_address {
+        return ContractError::invalid_fields(vec!["new_oracle_address"]).to_result();
+    }
+    let previous_oracle_address = scope_attribute.oracle_address;
+    scope_attribute.oracle_address = new_oracle_address;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(ORACLE_CHANGED_KEY, &scope_attribute.payable_uuid)
+        .attribute(
+            PREVIOUS_ORACLE_ADDRESS_KEY,
+            previous_oracle_address.as_str(),
+        )
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::change_oracle::{change_oracle_with_util, ChangeOracleV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_CONTRACT_NAME, DEFAULT_INFO_NAME, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
+        DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        ORACLE_ADDRESS_KEY, ORACLE_CHANGED_KEY, PREVIOUS_ORACLE_ADDRESS_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const NEW_ORACLE_ADDRESS: &str = "new-oracle";
+
+    #[test]
+    fn test_change_oracle_by_scope_owner() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "scope-owner"));
+        let response = test_change_oracle(&mut deps, &provenance_util, "scope-owner").unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, ORACLE_CHANGED_KEY),
+            "the ORACLE_CHANGED_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            DEFAULT_ORACLE_ADDRESS,
+            single_attribute_for_key(&response, PREVIOUS_ORACLE_ADDRESS_KEY),
+            "the PREVIOUS_ORACLE_ADDRESS_KEY value should equate to the original oracle",
+        );
+        assert_eq!(
+            NEW_ORACLE_ADDRESS,
+            single_attribute_for_key(&response, ORACLE_ADDRESS_KEY),
+            "the ORACLE_ADDRESS_KEY value should equate to the new oracle",
+        );
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            NEW_ORACLE_ADDRESS,
+            attribute.oracle_address.as_str(),
+            "the scope attribute should be rewritten with the new oracle",
+        );
+        let error =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "the previous oracle should no longer be able to approve, but got: {:?}",
+            error,
+        );
+        test_oracle_approval(
+            &mut deps,
+            &provenance_util,
+            TestOracleApproval {
+                info: mock_info(NEW_ORACLE_ADDRESS, &[]),
+                ..Default::default()
+            },
+        )
+        .expect("the new oracle should be able to approve the payable");
+    }
+
+    #[test]
+    fn test_change_oracle_by_admin() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // The admin no longer owns the scope, but can still reassign the oracle
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "scope-owner"));
+        test_change_oracle(&mut deps, &provenance_util, DEFAULT_INFO_NAME)
+            .expect("the contract admin should be able to change the oracle");
+    }
+
+    #[test]
+    fn test_change_oracle_unauthorized_sender() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = test_change_oracle(&mut deps, &provenance_util, "stranger").unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "a sender that is neither admin nor scope owner should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_change_oracle_after_approval() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let error = test_change_oracle(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "an approved payable's oracle should not be changeable, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_change_oracle_rejects_funds_and_same_oracle() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = change_oracle_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            change_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "expected funds to be rejected, but got: {:?}",
+            error,
+        );
+        let error = change_oracle_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            ChangeOracleV1 {
+                new_oracle_address: DEFAULT_ORACLE_ADDRESS.to_string(),
+                ..change_default()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "changing to the current oracle should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_change_oracle_missing_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = test_change_oracle(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "expected an unregistered payable to be reported as not found, but got: {:?}",
+            error,
+        );
+    }
+
+    fn change_default() -> ChangeOracleV1 {
+        ChangeOracleV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            new_oracle_address: NEW_ORACLE_ADDRESS.to_string(),
+        }
+    }
+
+    fn test_change_oracle(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = change_oracle_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            change_default(),
+        );
+        provenance_util.bind_captured_attribute_named(deps, DEFAULT_CONTRACT_NAME);
+        response
+    }
+}
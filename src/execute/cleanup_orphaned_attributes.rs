@@ -0,0 +1,170 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, payable_meta_storage_read_v2, PayableScopeAttribute};
+use crate::util::constants::ORPHANED_ATTRIBUTE_REMOVED_KEY;
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, Attribute, CosmosMsg, DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the scopes that the contract admin would like to have checked for attributes that no
+/// longer correlate to a payable in local storage.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CleanupOrphanedAttributesV1 {
+    pub scope_ids: Vec<String>,
+}
+
+/// Removes contract-named attributes from scopes that no longer have a registered payable with the
+/// following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - For each scope, finds all attributes written under the contract's name.  If none of them
+///   reference a payable whose local meta still links to the scope, the attributes are deleted.
+/// - Scopes without contract attributes, or with attributes for a live payable, are skipped.
+pub fn cleanup_orphaned_attributes(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    cleanup: CleanupOrphanedAttributesV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let mut attributes: Vec<Attribute> = vec![];
+    let meta_storage = payable_meta_storage_read_v2(deps.storage);
+    for scope_id in cleanup.scope_ids {
+        let scope_attributes = ProvenanceQuerier::new(&deps.querier)
+            .get_json_attributes::<Addr, String, PayableScopeAttribute>(
+                Addr::unchecked(&scope_id),
+                state.contract_name.clone(),
+            )?;
+        if scope_attributes.is_empty() {
+            continue;
+        }
+        let mut has_live_payable = false;
+        for attribute in scope_attributes.iter() {
+            if let Some(meta) = meta_storage.may_load(attribute.payable_uuid.as_bytes())? {
+                if meta.scope_id == scope_id {
+                    has_live_payable = true;
+                }
+            }
+        }
+        if has_live_payable {
+            continue;
+        }
+        messages.push(
+            state
+                .provenance_msg_factory()
+                .delete_scope_attributes(&scope_id, &state.contract_name)?,
+        );
+        attributes.push(Attribute::new(ORPHANED_ATTRIBUTE_REMOVED_KEY, scope_id));
+    }
+    Ok(ResponseBuilder::new()
+        .messages(messages)
+        .attributes(attributes)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::payable_meta_storage_v2;
+    use crate::execute::cleanup_orphaned_attributes::{
+        cleanup_orphaned_attributes, CleanupOrphanedAttributesV1,
+    };
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_CONTRACT_NAME,
+        DEFAULT_INFO_NAME, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::ORPHANED_ATTRIBUTE_REMOVED_KEY;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{Addr, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{AttributeMsgParams, ProvenanceMsg, ProvenanceMsgParams};
+
+    #[test]
+    fn test_cleanup_removes_orphaned_attribute() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Simulate the payable's local meta being removed while its attribute remains on the scope
+        payable_meta_storage_v2(deps.as_mut().storage).remove(DEFAULT_PAYABLE_UUID.as_bytes());
+        let response = cleanup_orphaned_attributes(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_cleanup(),
+        )
+        .expect("the admin should be able to clean up orphaned attributes");
+        assert_eq!(
+            DEFAULT_SCOPE_ID,
+            single_attribute_for_key(&response, ORPHANED_ATTRIBUTE_REMOVED_KEY),
+            "the cleaned scope should be emitted as an attribute",
+        );
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single delete attribute message should be emitted",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Custom(ProvenanceMsg {
+                params:
+                    ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteAttribute {
+                        address,
+                        name,
+                    }),
+                ..
+            }) => {
+                assert_eq!(
+                    &Addr::unchecked(DEFAULT_SCOPE_ID),
+                    address,
+                    "the attribute should be deleted from the orphaned scope",
+                );
+                assert_eq!(
+                    DEFAULT_CONTRACT_NAME, name,
+                    "only attributes under the contract's name should be deleted",
+                );
+            }
+            msg => panic!("unexpected message emitted during cleanup: {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn test_cleanup_skips_live_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response = cleanup_orphaned_attributes(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_cleanup(),
+        )
+        .unwrap();
+        assert!(
+            response.messages.is_empty() && response.attributes.is_empty(),
+            "attributes for registered payables should never be removed",
+        );
+    }
+
+    #[test]
+    fn test_cleanup_orphaned_attributes_unauthorized() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = cleanup_orphaned_attributes(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            default_cleanup(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to clean up attributes, but got: {:?}",
+            error,
+        );
+    }
+
+    fn default_cleanup() -> CleanupOrphanedAttributesV1 {
+        CleanupOrphanedAttributesV1 {
+            scope_ids: vec![DEFAULT_SCOPE_ID.to_string()],
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use crate::core::error::ContractError;
+use crate::core::payee_resolver::resolve_payee;
+use crate::core::state::{config_read_v2, update_payable_timeline};
+use crate::execute::cancel_payable::refund_held_oracle_funds;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::PAYABLE_DECLINED_KEY;
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that its payee refuses, such as one registered against them without their
+/// consent.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DeclinePayableV1 {
+    pub payable_uuid: String,
+}
+
+/// Parent function path for the contract to decline a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn decline_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    decline: DeclinePayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    decline_payable_with_util(deps, &ProvenanceUtilImpl, env, info, decline)
+}
+
+/// Marks a payable as declined by its payee with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender is the payable's payee: the value owner of its scope, or its new
+///   owner if it has been transferred.
+/// - Ensures that the payable has not already been declined or written off, and that no payments
+///   have been made against it.
+/// - Returns every amount that the contract held for the oracle to the registrant, if the oracle
+///   has not yet approved the payable.  An approved payable has already paid these to its oracle.
+/// - Rewrites the scope attribute flagged as declined, and records the decline time on the
+///   payable's timeline.  Declined payables can no longer be approved or paid.
+pub fn decline_payable_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    decline: DeclinePayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &decline.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: decline.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    let payee = resolve_payee(&deps.as_ref(), provenance_util, &scope_attribute)?;
+    // Skip the payee check locally, matching the registration process
+    if !state.is_local && payee != info.sender {
+        return Err(ContractError::Unauthorized);
+    }
+    let invalid_reason = if scope_attribute.is_declined {
+        Some("the payable has already been declined")
+    } else if scope_attribute.is_written_off {
+        Some("written off payables cannot be declined")
+    } else if scope_attribute.payable_remaining_owed != scope_attribute.payable_total_owed {
+        Some("payables that have received payments cannot be declined")
+    } else {
+        None
+    };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: invalid_reason.into(),
+        }
+        .to_result();
+    }
+    let response = ResponseBuilder::for_payable(&scope_attribute);
+    let response = if scope_attribute.oracle_approved {
+        response
+    } else {
+        refund_held_oracle_funds(deps.storage, &scope_attribute.payable_uuid, None, response)?
+    };
+    scope_attribute.is_declined = true;
+    update_payable_timeline(deps.storage, &scope_attribute.payable_uuid, |timeline| {
+        timeline.declined_at = Some(env.block.time);
+    })?;
+    Ok(response
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYABLE_DECLINED_KEY, &scope_attribute.payable_uuid)
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_held_oracle_fee, load_payable_timeline};
+    use crate::execute::decline_payable::{decline_payable_with_util, DeclinePayableV1};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_CONTRACT_NAME, DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_UUID,
+        DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        ONBOARDING_FEE_REFUNDED_KEY, PAYABLE_DECLINED_KEY, PRIORITY_FEE_REFUNDED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_decline_payable_refunds_held_funds_and_blocks_approval() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // The onboarding cost of 100 retains 25 for the oracle, and the priority fee is held on
+        // top of it
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                info: mock_info(DEFAULT_INFO_NAME, &[coin(150, DEFAULT_ONBOARDING_DENOM)]),
+                register_payable: RegisterPayableV2 {
+                    priority_fee: Some(Uint128::new(50)),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = test_decline(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_DECLINED_KEY),
+            "the PAYABLE_DECLINED_KEY value should equate to the payable uuid",
+        );
+        for (key, amount) in [
+            (ONBOARDING_FEE_REFUNDED_KEY, 25),
+            (PRIORITY_FEE_REFUNDED_KEY, 50),
+        ] {
+            assert_eq!(
+                format!("{}/{}", amount, DEFAULT_ONBOARDING_DENOM),
+                single_attribute_for_key(&response, key),
+                "the refunded amount should be emitted for {}",
+                key,
+            );
+            assert!(
+                response.messages.iter().any(|msg| msg.msg
+                    == CosmosMsg::Bank(BankMsg::Send {
+                        to_address: DEFAULT_INFO_NAME.to_string(),
+                        amount: vec![coin(amount, DEFAULT_ONBOARDING_DENOM)],
+                    })),
+                "{} should be returned to the registrant",
+                amount,
+            );
+        }
+        assert!(
+            load_held_oracle_fee(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the held oracle fee should be removed once refunded",
+        );
+        assert!(
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_declined,
+            "the scope attribute should be flagged as declined",
+        );
+        assert_eq!(
+            Some(mock_env().block.time),
+            load_payable_timeline(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .unwrap()
+                .declined_at,
+            "the decline time should be recorded on the payable's timeline",
+        );
+        let failure =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected approval of a declined payable to be rejected, but got: {:?}",
+            failure,
+        );
+        let failure = test_decline(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected a second decline to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_decline_approved_payable_blocks_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response = test_decline(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != ONBOARDING_FEE_REFUNDED_KEY),
+            "the oracle fee has already been paid out for an approved payable",
+        );
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::NotReadyForPayment { .. }),
+            "expected a payment to a declined payable to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_decline_payable_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let failure = decline_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            DeclinePayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::FundsPresent),
+            "expected funds to be rejected, but got: {:?}",
+            failure,
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        let failure = test_decline(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected a paid payable to be rejected, but got: {:?}",
+            failure,
+        );
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "another-guy"));
+        let failure = test_decline(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::Unauthorized),
+            "expected a sender that is not the value owner to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    fn test_decline(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = decline_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            DeclinePayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        );
+        provenance_util.bind_captured_attribute_named(deps, DEFAULT_CONTRACT_NAME);
+        response
+    }
+}
@@ -0,0 +1,228 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, load_denom_display_metadata, remove_denom_display_metadata as remove_metadata,
+    save_denom_display_metadata, DenomDisplayMetadataV1,
+};
+use crate::util::constants::{
+    DENOM_EXPONENT_KEY, DENOM_METADATA_REMOVED_KEY, DENOM_METADATA_SET_KEY, DENOM_SYMBOL_KEY,
+};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains all relevant fields required in order for the contract admin to define how a denom
+/// should be displayed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetDenomDisplayMetadataV1 {
+    pub denom: String,
+    pub symbol: String,
+    pub exponent: u32,
+}
+
+/// Contains all relevant fields required in order for the contract admin to remove the display
+/// metadata for a denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoveDenomDisplayMetadataV1 {
+    pub denom: String,
+}
+
+/// Creates or replaces the display metadata for a denom with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the metadata in local storage, keyed on the denom.
+pub fn set_denom_display_metadata(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_metadata: SetDenomDisplayMetadataV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    save_denom_display_metadata(
+        deps.storage,
+        &set_metadata.denom,
+        &DenomDisplayMetadataV1 {
+            symbol: set_metadata.symbol.clone(),
+            exponent: set_metadata.exponent,
+        },
+    )?;
+    Ok(ResponseBuilder::new()
+        .attribute(DENOM_METADATA_SET_KEY, &set_metadata.denom)
+        .attribute(DENOM_SYMBOL_KEY, &set_metadata.symbol)
+        .attribute(DENOM_EXPONENT_KEY, set_metadata.exponent.to_string())
+        .build())
+}
+
+/// Removes existing display metadata for a denom with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that metadata exists for the denom, and then removes it from local storage.
+pub fn remove_denom_display_metadata(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    remove: RemoveDenomDisplayMetadataV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    if load_denom_display_metadata(deps.storage, &remove.denom)?.is_none() {
+        return ContractError::std_err(format!(
+            "no display metadata exists for denom [{}]",
+            remove.denom
+        ))
+        .to_result();
+    }
+    remove_metadata(deps.storage, &remove.denom);
+    Ok(ResponseBuilder::new()
+        .attribute(DENOM_METADATA_REMOVED_KEY, &remove.denom)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{DenomDisplayMetadataResponse, PayableQueryResponse, QueryMsg};
+    use crate::core::state::load_denom_display_metadata;
+    use crate::execute::denom_display_metadata::{
+        remove_denom_display_metadata, set_denom_display_metadata, RemoveDenomDisplayMetadataV1,
+        SetDenomDisplayMetadataV1,
+    };
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_INFO_NAME,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        DENOM_EXPONENT_KEY, DENOM_METADATA_REMOVED_KEY, DENOM_METADATA_SET_KEY, DENOM_SYMBOL_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_set_denom_display_metadata() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let response = set_denom_display_metadata(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_set_metadata(),
+        )
+        .expect("the admin should be able to set denom display metadata");
+        assert_eq!(
+            DEFAULT_PAYABLE_DENOM,
+            single_attribute_for_key(&response, DENOM_METADATA_SET_KEY),
+            "the denom should be emitted as the set attribute",
+        );
+        assert_eq!(
+            "HASH",
+            single_attribute_for_key(&response, DENOM_SYMBOL_KEY),
+            "the symbol should be emitted as an attribute",
+        );
+        assert_eq!(
+            "9",
+            single_attribute_for_key(&response, DENOM_EXPONENT_KEY),
+            "the exponent should be emitted as an attribute",
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let payable_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryPayableByUuid {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap();
+        let payable = from_binary::<PayableQueryResponse>(&payable_binary).unwrap();
+        assert_eq!(
+            Some(DenomDisplayMetadataResponse {
+                symbol: "HASH".to_string(),
+                exponent: 9,
+            }),
+            payable.payable_denom_metadata,
+            "the payable query should include the display metadata for the payable's denom",
+        );
+    }
+
+    #[test]
+    fn test_set_denom_display_metadata_unauthorized() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = set_denom_display_metadata(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            default_set_metadata(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to set denom display metadata, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_set_denom_display_metadata_funds_present() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = set_denom_display_metadata(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, DEFAULT_ONBOARDING_DENOM)]),
+            default_set_metadata(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected when setting denom display metadata, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_remove_denom_display_metadata() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        set_denom_display_metadata(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_set_metadata(),
+        )
+        .unwrap();
+        let response = remove_denom_display_metadata(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_remove_metadata(),
+        )
+        .expect("the admin should be able to remove existing denom display metadata");
+        assert_eq!(
+            DEFAULT_PAYABLE_DENOM,
+            single_attribute_for_key(&response, DENOM_METADATA_REMOVED_KEY),
+            "the denom should be emitted as the removed attribute",
+        );
+        assert!(
+            load_denom_display_metadata(deps.as_ref().storage, DEFAULT_PAYABLE_DENOM)
+                .unwrap()
+                .is_none(),
+            "the metadata should be removed from storage",
+        );
+        remove_denom_display_metadata(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_remove_metadata(),
+        )
+        .expect_err("removing metadata that does not exist should fail");
+    }
+
+    fn default_set_metadata() -> SetDenomDisplayMetadataV1 {
+        SetDenomDisplayMetadataV1 {
+            denom: DEFAULT_PAYABLE_DENOM.to_string(),
+            symbol: "HASH".to_string(),
+            exponent: 9,
+        }
+    }
+
+    fn default_remove_metadata() -> RemoveDenomDisplayMetadataV1 {
+        RemoveDenomDisplayMetadataV1 {
+            denom: DEFAULT_PAYABLE_DENOM.to_string(),
+        }
+    }
+}
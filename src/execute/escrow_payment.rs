@@ -0,0 +1,751 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::payee_resolver::{payout_to_payees, resolve_payee};
+use crate::core::state::{
+    config_read_v2, load_escrowed_payment, load_payment_record, load_pending_cancellation,
+    record_payment_payee_splits, remove_escrowed_payment, save_payment_reversal, EscrowedPaymentV1,
+    PayableScopeAttribute, PaymentRecordV1, PaymentReversalV1,
+};
+use crate::execute::flag_erroneous_payment::restore_payment;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    ESCROW_RECLAIMED_KEY, PAYEE_KEY, PAYER_KEY, PAYMENT_AMOUNT_KEY, PAYMENT_ID_KEY,
+    PAYMENT_RELEASED_KEY, PAYMENT_REVERSAL_REASON_KEY, PAYMENT_REVERSED_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{BlockInfo, CosmosMsg, DepsMut, Env, MessageInfo, Response, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the escrowed payment that the payee or oracle would like to send to the payee.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReleasePaymentV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+}
+
+/// Contains the escrowed payment that its payer would like returned after it went unreleased.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReclaimEscrowV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+}
+
+/// Contains the escrowed payment that the admin would like returned to its payer, ex: a payment
+/// sent to the wrong payable, along with the reason for the reversal.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReversePaymentV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+    pub reason: String,
+}
+
+/// Parent function path for the contract to release an escrowed payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn release_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    release: ReleasePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    release_payment_with_util(deps, &ProvenanceUtilImpl, info, release)
+}
+
+/// Sends an escrowed payment to its payable's payee with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Ensures that the payable is not pending cancellation.  Its payers may claim back their escrowed
+///   payments until the cancellation is finalized.
+/// - Ensures that the payment is still held in escrow.
+/// - Verifies that the sender is the payable's payee or its oracle.  The payee is the value owner
+///   of the payable's scope, or its new owner if it has been transferred.
+/// - Removes the payment from escrow and sends it to the payee.
+pub fn release_payment_with_util<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    info: MessageInfo,
+    release: ReleasePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &release.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: release.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if load_pending_cancellation(deps.storage, &release.payable_uuid)?.is_some() {
+        return ContractError::InvalidPayable {
+            payable_uuid: release.payable_uuid,
+            invalid_reason:
+                "escrowed payments cannot be released while the payable is pending cancellation"
+                    .into(),
+        }
+        .to_result();
+    }
+    let escrowed_payment =
+        load_escrowed_payment_or_err(deps.storage, &release.payable_uuid, release.payment_id)?;
+    let payee = resolve_payee(&deps.as_ref(), provenance_util, &scope_attribute)?;
+    if info.sender != payee && info.sender != scope_attribute.oracle_address {
+        return Err(ContractError::Unauthorized);
+    }
+    remove_escrowed_payment(
+        deps.storage,
+        &scope_attribute.payable_type,
+        &release.payable_uuid,
+        release.payment_id,
+    )?;
+    let payment = Money::new(escrowed_payment.amount, &escrowed_payment.denom);
+    let payee_payout = payout_to_payees(deps.storage, &release.payable_uuid, &payee, &payment)?;
+    if let Some(payee_splits) = &payee_payout.payee_splits {
+        record_payment_payee_splits(
+            deps.storage,
+            &release.payable_uuid,
+            release.payment_id,
+            payee_splits,
+        )?;
+    }
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .attributes(payee_payout.attributes())
+        .messages(payee_payout.messages)
+        .attribute(PAYMENT_RELEASED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PAYMENT_ID_KEY, release.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, payment.amount.to_string())
+        .attribute(PAYEE_KEY, payee.as_str())
+        .build())
+}
+
+/// Parent function path for the contract to reclaim an escrowed payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn reclaim_escrow(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    reclaim: ReclaimEscrowV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    reclaim_escrow_with_util(deps, &ProvenanceUtilImpl, env, info, reclaim)
+}
+
+/// Returns an unreleased escrowed payment to its payer with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Ensures that the payment is still held in escrow.
+/// - Verifies that the sender is the payer of the payment, and that its escrow timeout has passed.
+/// - Removes the payment from escrow and sends it back to the payer.
+/// - Adds the payment back to the payable's remaining owed amount and rewrites the scope attribute,
+///   as the payee never received it.
+pub fn reclaim_escrow_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    reclaim: ReclaimEscrowV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &reclaim.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: reclaim.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    let escrowed_payment =
+        load_escrowed_payment_or_err(deps.storage, &reclaim.payable_uuid, reclaim.payment_id)?;
+    if info.sender != escrowed_payment.payer {
+        return Err(ContractError::Unauthorized);
+    }
+    if env.block.time < escrowed_payment.reclaimable_at {
+        return ContractError::std_err(format!(
+            "escrowed payment [{}] for payable [{}] cannot be reclaimed until {}",
+            reclaim.payment_id, reclaim.payable_uuid, escrowed_payment.reclaimable_at,
+        ))
+        .to_result();
+    }
+    let messages = return_escrowed_payment(
+        deps.branch(),
+        provenance_util,
+        &state.contract_name,
+        &mut scope_attribute,
+        reclaim.payment_id,
+        &escrowed_payment,
+        &env.block,
+    )?;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .messages(messages)
+        .attribute(ESCROW_RECLAIMED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PAYMENT_ID_KEY, reclaim.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, escrowed_payment.amount.to_string())
+        .attribute(PAYER_KEY, escrowed_payment.payer.as_str())
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .build())
+}
+
+/// Parent function path for the contract to reverse an escrowed payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn reverse_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    reverse: ReversePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    reverse_payment_with_util(deps, &ProvenanceUtilImpl, env, info, reverse)
+}
+
+/// Returns an erroneous escrowed payment to its payer before it is released with the following
+/// steps:
+/// - Verifies that no funds were sent, and that the sender is the contract's admin.
+/// - Ensures that payment reversal has been enabled by configuring a reversal window.
+/// - Ensures that the payable targeted has been registered.
+/// - Ensures that the payment is still held in escrow, and that it was made within the reversal
+///   window.  Released payments have already reached the payee and cannot be reversed.
+/// - Removes the payment from escrow and sends it back to the payer.
+/// - Adds the payment back to the payable's remaining owed amount and rewrites the scope attribute,
+///   as the payee never received it.
+/// - Records the admin, reason and time of the reversal alongside the payment's history.
+pub fn reverse_payment_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    reverse: ReversePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    let reversal_window_seconds = match state.payment_reversal_window_seconds {
+        Some(window_seconds) => window_seconds,
+        None => {
+            return ContractError::std_err("payment reversal is not enabled for this contract")
+                .to_result();
+        }
+    };
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &reverse.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: reverse.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    let escrowed_payment =
+        load_escrowed_payment_or_err(deps.storage, &reverse.payable_uuid, reverse.payment_id)?;
+    let payment_record =
+        load_escrowed_payment_record(deps.storage, &reverse.payable_uuid, reverse.payment_id)?;
+    let reversible_until = payment_record.paid_at.plus_seconds(reversal_window_seconds);
+    if env.block.time > reversible_until {
+        return ContractError::std_err(format!(
+            "escrowed payment [{}] for payable [{}] could only be reversed until {}",
+            reverse.payment_id, reverse.payable_uuid, reversible_until,
+        ))
+        .to_result();
+    }
+    remove_escrowed_payment(
+        deps.storage,
+        &scope_attribute.payable_type,
+        &reverse.payable_uuid,
+        reverse.payment_id,
+    )?;
+    restore_payment(
+        deps.storage,
+        &mut scope_attribute,
+        &payment_record,
+        escrowed_payment.amount,
+        &env.block,
+    )?;
+    save_payment_reversal(
+        deps.storage,
+        &reverse.payable_uuid,
+        reverse.payment_id,
+        &PaymentReversalV1 {
+            payer: escrowed_payment.payer.clone(),
+            amount: escrowed_payment.amount,
+            denom: escrowed_payment.denom.clone(),
+            reversed_by: info.sender,
+            reason: reverse.reason.clone(),
+            reversed_at: env.block.time,
+        },
+    )?;
+    let payment = Money::new(escrowed_payment.amount, &escrowed_payment.denom);
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .message(payment.send_to(&escrowed_payment.payer))
+        .attribute(PAYMENT_REVERSED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PAYMENT_ID_KEY, reverse.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, payment.amount.to_string())
+        .attribute(PAYER_KEY, escrowed_payment.payer.as_str())
+        .attribute(PAYMENT_REVERSAL_REASON_KEY, reverse.reason)
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .build())
+}
+
+/// Removes a payment from escrow and adds it back to the payable's remaining owed amount, as the
+/// payee never received it.  Produces the messages that rewrite the scope attribute and send the
+/// payment back to its payer.
+pub(crate) fn return_escrowed_payment<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    contract_name: &str,
+    scope_attribute: &mut PayableScopeAttribute,
+    payment_id: u64,
+    escrowed_payment: &EscrowedPaymentV1,
+    block: &BlockInfo,
+) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    let payment_record =
+        load_escrowed_payment_record(deps.storage, &scope_attribute.payable_uuid, payment_id)?;
+    remove_escrowed_payment(
+        deps.storage,
+        &scope_attribute.payable_type,
+        &scope_attribute.payable_uuid,
+        payment_id,
+    )?;
+    restore_payment(
+        deps.storage,
+        scope_attribute,
+        &payment_record,
+        escrowed_payment.amount,
+        block,
+    )?;
+    let mut messages = write_through_payable(
+        deps.branch(),
+        provenance_util,
+        scope_attribute,
+        contract_name,
+    )?;
+    messages.push(
+        Money::new(escrowed_payment.amount, &escrowed_payment.denom)
+            .send_to(&escrowed_payment.payer),
+    );
+    Ok(messages)
+}
+
+pub(crate) fn load_escrowed_payment_or_err(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> Result<EscrowedPaymentV1, ContractError> {
+    match load_escrowed_payment(storage, payable_uuid, payment_id)? {
+        Some(escrowed_payment) => Ok(escrowed_payment),
+        None => ContractError::std_err(format!(
+            "no escrowed payment [{}] exists for payable [{}]",
+            payment_id, payable_uuid,
+        ))
+        .to_result(),
+    }
+}
+
+/// Escrowed payments always append a history record under their payment id, which holds the split
+/// of the payment between late fees, interest, and principal.
+fn load_escrowed_payment_record(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payment_id: u64,
+) -> Result<PaymentRecordV1, ContractError> {
+    load_payment_record(storage, payable_uuid, payment_id)?.ok_or_else(|| {
+        ContractError::std_err(format!(
+            "no payment [{}] was recorded for payable [{}]",
+            payment_id, payable_uuid,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_escrow_totals, load_payment_batch, load_payment_reversal, PaymentBatchWindowV1,
+    };
+    use crate::execute::escrow_payment::{
+        reclaim_escrow_with_util, release_payment_with_util, reverse_payment_with_util,
+        ReclaimEscrowV1, ReleasePaymentV1, ReversePaymentV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        ESCROW_RECLAIMED_KEY, PAYEE_KEY, PAYMENT_ESCROWED_KEY, PAYMENT_ID_KEY,
+        PAYMENT_RELEASED_KEY, PAYMENT_REVERSAL_REASON_KEY, PAYMENT_REVERSED_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Decimal, Env, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const ESCROW_TIMEOUT_SECONDS: u64 = 3600;
+    const REVERSAL_WINDOW_SECONDS: u64 = 600;
+
+    #[test]
+    fn test_release_escrowed_payment() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .expect("the payment should be escrowed");
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, PAYMENT_ESCROWED_KEY),
+            "the first payment should be escrowed under the first payment id",
+        );
+        assert!(
+            !response
+                .messages
+                .iter()
+                .any(|msg| matches!(msg.msg, CosmosMsg::Bank(_))),
+            "an escrowed payment should not be sent to the payee",
+        );
+        let error = release(&mut deps, &provenance_util, "some-random-guy", 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the payee or oracle should be able to release a payment, but got: {:?}",
+            error,
+        );
+        let response = release(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 0)
+            .expect("the oracle should be able to release the payment");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_RELEASED_KEY),
+        );
+        assert_eq!("0", single_attribute_for_key(&response, PAYMENT_ID_KEY));
+        assert_eq!(
+            DEFAULT_INFO_NAME,
+            single_attribute_for_key(&response, PAYEE_KEY),
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(DEFAULT_INFO_NAME, to_address);
+                assert_eq!(&vec![coin(400, DEFAULT_PAYABLE_DENOM)], amount);
+            }
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        }
+        assert!(
+            load_escrow_totals(deps.as_ref().storage, None)
+                .unwrap()
+                .is_empty(),
+            "a released payment should be deducted from the escrow totals",
+        );
+        let error = release(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "a released payment should no longer be held in escrow, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_reclaim_escrowed_payment() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        let error =
+            reclaim(&mut deps, &provenance_util, mock_env(), DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "the payment should not be reclaimable before its escrow timeout, but got: {:?}",
+            error,
+        );
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(ESCROW_TIMEOUT_SECONDS);
+        let error = reclaim(
+            &mut deps,
+            &provenance_util,
+            later_env.clone(),
+            "some-random-guy",
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the payer should be able to reclaim a payment, but got: {:?}",
+            error,
+        );
+        let response = reclaim(&mut deps, &provenance_util, later_env, DEFAULT_INFO_NAME)
+            .expect("the payer should be able to reclaim the payment after its timeout");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, ESCROW_RECLAIMED_KEY),
+        );
+        assert_eq!(
+            "1000",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the reclaimed payment should be owed again",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(400, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the payment should be returned to the payer",
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        assert_eq!(
+            1000,
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .payable_remaining_owed
+                .u128(),
+            "the rewritten attribute should include the reclaimed payment in the remaining owed",
+        );
+    }
+
+    #[test]
+    fn test_reverse_escrowed_payment() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(1000),
+        )
+        .unwrap();
+        let error = reverse(
+            &mut deps,
+            &provenance_util,
+            mock_env(),
+            DEFAULT_ORACLE_ADDRESS,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to reverse a payment, but got: {:?}",
+            error,
+        );
+        let mut late_env = mock_env();
+        late_env.block.time = late_env
+            .block
+            .time
+            .plus_seconds(REVERSAL_WINDOW_SECONDS + 1);
+        let error = reverse(&mut deps, &provenance_util, late_env, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "the payment should not be reversible after the reversal window, but got: {:?}",
+            error,
+        );
+        let response = reverse(&mut deps, &provenance_util, mock_env(), DEFAULT_INFO_NAME)
+            .expect("the admin should be able to reverse the payment within the window");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_REVERSED_KEY),
+        );
+        assert_eq!(
+            "sent to the wrong payable",
+            single_attribute_for_key(&response, PAYMENT_REVERSAL_REASON_KEY),
+        );
+        assert_eq!(
+            "1000",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the reversed payment should be owed again",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(1000, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the payment should be returned to the payer",
+        );
+        let reversal = load_payment_reversal(&deps.storage, DEFAULT_PAYABLE_UUID, 0)
+            .unwrap()
+            .expect("the reversal should be recorded for audit");
+        assert_eq!(DEFAULT_INFO_NAME, reversal.reversed_by.as_str());
+        assert_eq!(mock_env().block.time, reversal.reversed_at);
+        provenance_util.bind_captured_attribute(&mut deps);
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(1000, attribute.payable_remaining_owed.u128());
+        assert_eq!(
+            None, attribute.settled_at,
+            "the payable settled by the reversed payment should be reopened",
+        );
+        let error = release(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "a reversed payment should no longer be held in escrow, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_reclaim_interleaved_with_batched_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(50),
+                    block_interval: 100,
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    escrow_timeout_seconds: Some(ESCROW_TIMEOUT_SECONDS),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        for amount in [100, 150] {
+            test_make_payment(
+                &mut deps,
+                &provenance_util,
+                TestMakePayment::default_with_amount(amount),
+            )
+            .unwrap();
+        }
+        assert!(
+            load_payment_batch(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_some(),
+            "both payments should have their attribute writes deferred into a batch",
+        );
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(ESCROW_TIMEOUT_SECONDS);
+        let response = reclaim(&mut deps, &provenance_util, later_env, DEFAULT_INFO_NAME).unwrap();
+        assert_eq!(
+            "850",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the reclaim should restore the first payment on top of the batched second payment",
+        );
+        assert!(
+            load_payment_batch(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the reclaim's attribute write should clear the payment batch",
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(850),
+        )
+        .expect("a payment of the reported remaining owed amount should be accepted");
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the payment following the reclaim should see the restored remaining owed amount",
+        );
+    }
+
+    fn setup_escrowed_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(
+            deps,
+            InstArgs {
+                payment_reversal_window_seconds: Some(REVERSAL_WINDOW_SECONDS),
+                ..Default::default()
+            },
+        );
+        test_register_payable(
+            deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    escrow_timeout_seconds: Some(ESCROW_TIMEOUT_SECONDS),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn release(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        payment_id: u64,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        release_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_info(sender, &[]),
+            ReleasePaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id,
+            },
+        )
+    }
+
+    fn reclaim(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        env: Env,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        reclaim_escrow_with_util(
+            deps.as_mut(),
+            provenance_util,
+            env,
+            mock_info(sender, &[]),
+            ReclaimEscrowV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id: 0,
+            },
+        )
+    }
+
+    fn reverse(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        env: Env,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        reverse_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            env,
+            mock_info(sender, &[]),
+            ReversePaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id: 0,
+                reason: "sent to the wrong payable".to_string(),
+            },
+        )
+    }
+}
@@ -0,0 +1,471 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, load_payment_batch, payable_meta_storage_v2, remove_held_oracle_fee,
+    remove_oracle_approval_context, remove_oracle_gas_rebate, remove_payable_authorized_payers,
+    remove_payable_custom_fields, remove_payable_meta_patch_proposal,
+    remove_payable_minimum_payment, remove_payable_payee_splits, remove_payable_priority_fee,
+    remove_payable_snapshots, remove_payable_subscribers, remove_registration_nonce,
+    remove_restructure_proposal, update_payable_timeline,
+};
+#[cfg(feature = "escrow")]
+use crate::core::state::{remove_payable_escrow_timeout, remove_pending_cancellation};
+use crate::execute::cancel_payable::refund_held_oracle_funds;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{PAYABLE_EXPIRED_KEY, SCOPE_ID_KEY};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that the sender would like to have removed from the contract after its
+/// expiration time has passed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExpirePayableV1 {
+    pub payable_uuid: String,
+}
+
+/// Removes an expired, unpaid payable from the contract with the following steps:
+/// - Verifies that no funds were sent.  Any sender may expire a payable.
+/// - Ensures that the payable targeted has been registered.
+/// - Ensures that the payable's expiration time has passed.
+/// - Ensures that no payments have been made, including payments with deferred attribute writes.
+/// - Deletes the payable's attribute from its scope.
+/// - Refunds every amount still held for the oracle to the payable's registrant: the retained
+///   oracle portion of the onboarding cost, the priority fee, and the oracle gas rebate.  Approval
+///   pays out and clears these, so they only remain for payables that were never approved.
+/// - Records the expiration time on the payable's timeline.
+/// - Removes the payable's meta, custom fields, priority fee, minimum payment, held oracle fee,
+///   oracle approval context, restructure proposal, and subscribers from local storage.  The
+///   timeline is kept so that the expiration remains queryable.
+pub fn expire_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    expire: ExpirePayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &expire.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: expire.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if !scope_attribute.is_expired(env.block.time) {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the payable has not expired".into(),
+        }
+        .to_result();
+    }
+    // A payment batch only exists while a payment's attribute write is deferred, in which case the
+    // remaining owed amount on the attribute does not yet reflect the payment
+    if scope_attribute.payable_remaining_owed != scope_attribute.payable_total_owed
+        || load_payment_batch(deps.storage, &scope_attribute.payable_uuid)?.is_some()
+    {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "payables that have received payments cannot be expired".into(),
+        }
+        .to_result();
+    }
+    let response = refund_held_oracle_funds(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+        None,
+        ResponseBuilder::for_payable(&scope_attribute),
+    )?
+    // Subscribers are notified of the expiration before their subscriptions are cleared
+    .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?;
+    remove_payable_local_storage(deps.storage, &scope_attribute.payable_uuid)?;
+    update_payable_timeline(deps.storage, &scope_attribute.payable_uuid, |timeline| {
+        timeline.expired_at = Some(env.block.time);
+    })?;
+    Ok(response
+        .message(
+            state
+                .provenance_msg_factory()
+                .delete_scope_attributes(&scope_attribute.scope_id, &state.contract_name)?,
+        )
+        .attribute(PAYABLE_EXPIRED_KEY, &scope_attribute.payable_uuid)
+        .attribute(SCOPE_ID_KEY, &scope_attribute.scope_id)
+        .build())
+}
+
+/// Removes every record that the contract keeps in local storage for a payable that is leaving the
+/// contract before any payments were made.  The payable's timeline is kept, so that its removal
+/// remains queryable.
+pub(crate) fn remove_payable_local_storage(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<()> {
+    payable_meta_storage_v2(storage).remove(payable_uuid.as_bytes());
+    remove_payable_custom_fields(storage, payable_uuid);
+    remove_payable_priority_fee(storage, payable_uuid);
+    remove_oracle_approval_context(storage, payable_uuid);
+    remove_oracle_gas_rebate(storage, payable_uuid);
+    remove_payable_minimum_payment(storage, payable_uuid);
+    #[cfg(feature = "escrow")]
+    remove_payable_escrow_timeout(storage, payable_uuid);
+    #[cfg(feature = "escrow")]
+    remove_pending_cancellation(storage, payable_uuid);
+    remove_payable_authorized_payers(storage, payable_uuid);
+    remove_payable_payee_splits(storage, payable_uuid);
+    remove_registration_nonce(storage, payable_uuid);
+    remove_held_oracle_fee(storage, payable_uuid);
+    remove_restructure_proposal(storage, payable_uuid);
+    remove_payable_meta_patch_proposal(storage, payable_uuid);
+    remove_payable_snapshots(storage, payable_uuid)?;
+    remove_payable_subscribers(storage, payable_uuid)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_held_oracle_fee, load_oracle_gas_rebate, load_payable_custom_fields,
+        load_payable_priority_fee, load_payable_subscriber_count, load_payable_timeline,
+        payable_meta_storage_read_v2, OracleGasRebateV1,
+    };
+    use crate::execute::expire_payable::{expire_payable, ExpirePayableV1};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::execute::subscribe::{subscribe, SubscribeV1};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        ONBOARDING_FEE_REFUNDED_KEY, ORACLE_GAS_REBATE_REFUNDED_KEY, PAYABLE_EXPIRED_KEY,
+        PRIORITY_FEE_REFUNDED_KEY, SUBSCRIBER_COUNT_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Addr, BankMsg, Binary, CosmosMsg, Env, Timestamp, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_expire_payable_success() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        register_expiring_payable(&mut deps, &provenance_util);
+        subscribe(
+            deps.as_mut(),
+            mock_info("bot", &[]),
+            SubscribeV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap();
+        let response = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() + 1),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_EXPIRED_KEY),
+            "the PAYABLE_EXPIRED_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, SUBSCRIBER_COUNT_KEY),
+            "subscribers should be notified of the expiration",
+        );
+        assert_eq!(
+            0,
+            load_payable_subscriber_count(&deps.storage, DEFAULT_PAYABLE_UUID).unwrap(),
+            "the payable's subscriptions should be removed",
+        );
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "expected a message to refund the held oracle fee and one to delete the scope attribute",
+        );
+        assert!(
+            payable_meta_storage_read_v2(&deps.storage)
+                .may_load(DEFAULT_PAYABLE_UUID.as_bytes())
+                .unwrap()
+                .is_none(),
+            "the payable's meta should be removed",
+        );
+        assert!(
+            load_payable_custom_fields(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the payable's custom fields should be removed",
+        );
+        assert_eq!(
+            Some(env_at(expiration_seconds() + 1).block.time),
+            load_payable_timeline(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expect("the payable's timeline should be kept")
+                .expired_at,
+            "the expiration time should be recorded on the payable's timeline",
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_refunds_gas_rebate() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    oracle_gas_rebate: Some(Uint128::new(10)),
+                    expiration_time: Some(Timestamp::from_seconds(expiration_seconds())),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                info: mock_info(DEFAULT_INFO_NAME, &[coin(110, DEFAULT_ONBOARDING_DENOM)]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            Some(OracleGasRebateV1 {
+                registrant: Addr::unchecked(DEFAULT_INFO_NAME),
+                amount: Uint128::new(10),
+                denom: DEFAULT_ONBOARDING_DENOM.to_string(),
+            }),
+            load_oracle_gas_rebate(&deps.storage, DEFAULT_PAYABLE_UUID).unwrap(),
+            "the gas rebate should be recorded in the ledger with its registrant",
+        );
+        let response = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() + 1),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap();
+        assert_eq!(
+            format!("10/{}", DEFAULT_ONBOARDING_DENOM),
+            single_attribute_for_key(&response, ORACLE_GAS_REBATE_REFUNDED_KEY),
+            "the refunded gas rebate should be emitted as an attribute",
+        );
+        let refunds = response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(
+            refunds.contains(&(
+                DEFAULT_INFO_NAME.to_string(),
+                vec![coin(10, DEFAULT_ONBOARDING_DENOM)]
+            )),
+            "the gas rebate should be refunded to the registrant rather than the sender",
+        );
+        assert!(
+            load_oracle_gas_rebate(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the gas rebate should be cleared from the ledger",
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_refunds_held_oracle_fee_and_priority_fee() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // The onboarding cost of 100 retains 25 for the oracle, and the priority fee is held on top
+        // of it
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    priority_fee: Some(Uint128::new(50)),
+                    expiration_time: Some(Timestamp::from_seconds(expiration_seconds())),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                info: mock_info(DEFAULT_INFO_NAME, &[coin(150, DEFAULT_ONBOARDING_DENOM)]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() + 1),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap();
+        for (key, amount) in [
+            (ONBOARDING_FEE_REFUNDED_KEY, 25),
+            (PRIORITY_FEE_REFUNDED_KEY, 50),
+        ] {
+            assert_eq!(
+                format!("{}/{}", amount, DEFAULT_ONBOARDING_DENOM),
+                single_attribute_for_key(&response, key),
+                "the refunded amount should be emitted for {}",
+                key,
+            );
+            assert!(
+                response.messages.iter().any(|msg| msg.msg
+                    == CosmosMsg::Bank(BankMsg::Send {
+                        to_address: DEFAULT_INFO_NAME.to_string(),
+                        amount: vec![coin(amount, DEFAULT_ONBOARDING_DENOM)],
+                    })),
+                "{} should be returned to the registrant rather than the sender",
+                amount,
+            );
+        }
+        assert!(
+            load_held_oracle_fee(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the held oracle fee should be cleared",
+        );
+        assert!(
+            load_payable_priority_fee(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the priority fee should be cleared",
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_not_yet_expired() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        register_expiring_payable(&mut deps, &provenance_util);
+        // The payable is still valid at the exact moment of expiration
+        let failure = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds()),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected an unexpired payable to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_without_expiration_time() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let failure = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() * 2),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected a payable without an expiration time to never expire, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_with_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        register_expiring_payable(&mut deps, &provenance_util);
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(&mut deps, &provenance_util, TestMakePayment::default()).unwrap();
+        let failure = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() + 1),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected a payable with payments to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_rejects_funds() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        register_expiring_payable(&mut deps, &provenance_util);
+        let failure = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() + 1),
+            mock_info("anyone", &[coin(1, "nhash")]),
+            expire_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::FundsPresent),
+            "expected funds to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_expire_payable_missing_payable() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let failure = expire_payable(
+            deps.as_mut(),
+            env_at(expiration_seconds() + 1),
+            mock_info("anyone", &[]),
+            expire_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::PayableNotFound { .. }),
+            "expected an unregistered payable to be reported as not found, but got: {:?}",
+            failure,
+        );
+    }
+
+    fn register_expiring_payable(deps: &mut MockOwnedDeps, provenance_util: &MockProvenanceUtil) {
+        test_register_payable(
+            deps,
+            provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    custom_fields: Some(Binary::from(b"{\"product\":\"test\"}".to_vec())),
+                    expiration_time: Some(Timestamp::from_seconds(expiration_seconds())),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    }
+
+    /// Places the expiration a day after the default mock env's block time
+    fn expiration_seconds() -> u64 {
+        mock_env().block.time.seconds() + 86_400
+    }
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env
+    }
+
+    fn expire_default() -> ExpirePayableV1 {
+        ExpirePayableV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+        }
+    }
+}
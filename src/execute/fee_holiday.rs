@@ -0,0 +1,489 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, load_all_payable_type_configs, load_fee_holiday, load_payable_type_config,
+    load_referral_config, remove_fee_holiday, save_fee_holiday, FeeHolidayV1, StateV2,
+};
+use crate::util::constants::{
+    FEE_HOLIDAY_CANCELLED_KEY, FEE_HOLIDAY_ONBOARDING_COST_KEY, FEE_HOLIDAY_SCHEDULED_KEY,
+};
+use crate::util::fees::{is_fee_split_exact, is_referral_share_exact};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{
+    Decimal, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Timestamp, Uint128,
+};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::ops::Mul;
+
+/// Contains all relevant fields required in order for the contract admin to schedule a window of
+/// reduced onboarding costs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScheduleFeeHolidayV1 {
+    pub holiday_id: String,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub payable_types: Vec<String>,
+    pub onboarding_cost: Uint128,
+}
+
+/// Contains the id of a fee holiday that the contract admin would like to cancel.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CancelFeeHolidayV1 {
+    pub holiday_id: String,
+}
+
+/// Schedules a fee holiday with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the holiday has not already ended.
+/// - Ensures that the holiday's onboarding cost is lower than the configured onboarding cost, and
+///   that the fee percent of every payable type it covers, as well as any referral share of the
+///   resulting fee, splits it into whole base units.
+/// - Stores the holiday in local storage, keyed on its id.  Scheduling an existing id replaces the
+///   previous holiday, allowing the admin to reschedule it.
+pub fn schedule_fee_holiday(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    schedule: ScheduleFeeHolidayV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    if schedule.end_time <= env.block.time {
+        return ContractError::invalid_fields(vec!["end_time"]).to_result();
+    }
+    let fee_share_bps = load_referral_config(deps.storage)?.map(|config| config.fee_share_bps);
+    let is_split_exact = |fee_percent: &Decimal| {
+        is_fee_split_exact(schedule.onboarding_cost, *fee_percent)
+            && fee_share_bps.is_none_or(|fee_share_bps| {
+                is_referral_share_exact(schedule.onboarding_cost.mul(*fee_percent), fee_share_bps)
+            })
+    };
+    if schedule.onboarding_cost >= state.onboarding_cost
+        || !holiday_fee_percents(deps.storage, &state, &schedule.payable_types)?
+            .iter()
+            .all(is_split_exact)
+    {
+        return ContractError::invalid_fields(vec!["onboarding_cost"]).to_result();
+    }
+    let holiday = FeeHolidayV1 {
+        holiday_id: schedule.holiday_id,
+        start_time: schedule.start_time,
+        end_time: schedule.end_time,
+        payable_types: schedule.payable_types,
+        onboarding_cost: schedule.onboarding_cost,
+    };
+    save_fee_holiday(deps.storage, &holiday)?;
+    Ok(ResponseBuilder::new()
+        .attribute(FEE_HOLIDAY_SCHEDULED_KEY, &holiday.holiday_id)
+        .attribute(
+            FEE_HOLIDAY_ONBOARDING_COST_KEY,
+            Money::new(holiday.onboarding_cost, &state.onboarding_denom).to_string(),
+        )
+        .build())
+}
+
+/// The fee percents that a holiday's onboarding cost can be split by.  Payable types without a config
+/// are charged the contract's fee percent, while configured types are charged their own.  Holidays
+/// never apply to types configured with another denom, so those are skipped.  A holiday without any
+/// payable types covers the contract's fee percent and every config.
+fn holiday_fee_percents(
+    storage: &dyn Storage,
+    state: &StateV2,
+    payable_types: &[String],
+) -> StdResult<Vec<Decimal>> {
+    let mut fee_percents = vec![];
+    if payable_types.is_empty() {
+        fee_percents.push(state.fee_percent);
+        for config in load_all_payable_type_configs(storage)? {
+            if config.onboarding_denom == state.onboarding_denom {
+                fee_percents.push(config.fee_percent);
+            }
+        }
+        return Ok(fee_percents);
+    }
+    for payable_type in payable_types {
+        match load_payable_type_config(storage, payable_type)? {
+            Some(config) if config.onboarding_denom == state.onboarding_denom => {
+                fee_percents.push(config.fee_percent)
+            }
+            Some(_) => (),
+            None => fee_percents.push(state.fee_percent),
+        }
+    }
+    Ok(fee_percents)
+}
+
+/// Cancels a fee holiday with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the holiday was previously scheduled.
+/// - Removes the holiday from local storage.  Payables registered during the holiday keep the
+///   reduced cost that they were charged.
+pub fn cancel_fee_holiday(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    cancel: CancelFeeHolidayV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    if load_fee_holiday(deps.storage, &cancel.holiday_id)?.is_none() {
+        return ContractError::FeeHolidayNotFound {
+            holiday_id: cancel.holiday_id,
+        }
+        .to_result();
+    }
+    remove_fee_holiday(deps.storage, &cancel.holiday_id);
+    Ok(ResponseBuilder::new()
+        .attribute(FEE_HOLIDAY_CANCELLED_KEY, &cancel.holiday_id)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_fee_holiday, load_held_oracle_fee, save_payable_type_config, save_referral_config,
+        PayableTypeConfigV1, ReferralConfigV1,
+    };
+    use crate::execute::fee_holiday::{
+        cancel_fee_holiday, schedule_fee_holiday, CancelFeeHolidayV1, ScheduleFeeHolidayV1,
+    };
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_INFO_NAME,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_TYPE,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        FEE_HOLIDAY_CANCELLED_KEY, FEE_HOLIDAY_KEY, FEE_HOLIDAY_ONBOARDING_COST_KEY,
+        FEE_HOLIDAY_SCHEDULED_KEY, ONBOARDING_COST_CHARGED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    const HOLIDAY_ID: &str = "launch-week";
+    const LOAN_PAYABLE_TYPE: &str = "loan";
+
+    #[test]
+    fn test_schedule_and_cancel_fee_holiday() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(20, vec![]),
+        )
+        .expect("the admin should be able to schedule a fee holiday");
+        assert_eq!(
+            HOLIDAY_ID,
+            single_attribute_for_key(&response, FEE_HOLIDAY_SCHEDULED_KEY),
+            "the holiday id should be emitted as an attribute",
+        );
+        assert_eq!(
+            "20/nhash",
+            single_attribute_for_key(&response, FEE_HOLIDAY_ONBOARDING_COST_KEY),
+            "the holiday's onboarding cost should be emitted as an attribute",
+        );
+        assert!(
+            load_fee_holiday(deps.as_ref().storage, HOLIDAY_ID)
+                .unwrap()
+                .is_some(),
+            "the holiday should be stored",
+        );
+        let response =
+            cancel_fee_holiday(deps.as_mut(), mock_info(DEFAULT_INFO_NAME, &[]), cancel())
+                .expect("the admin should be able to cancel a fee holiday");
+        assert_eq!(
+            HOLIDAY_ID,
+            single_attribute_for_key(&response, FEE_HOLIDAY_CANCELLED_KEY),
+            "the cancelled holiday id should be emitted as an attribute",
+        );
+        assert!(
+            load_fee_holiday(deps.as_ref().storage, HOLIDAY_ID)
+                .unwrap()
+                .is_none(),
+            "the holiday should be removed",
+        );
+        let error = cancel_fee_holiday(deps.as_mut(), mock_info(DEFAULT_INFO_NAME, &[]), cancel())
+            .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FeeHolidayNotFound { .. }),
+            "cancelling a holiday that is not scheduled should fail, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_schedule_fee_holiday_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not-the-admin", &[]),
+            schedule(0, vec![]),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to schedule a holiday, but got: {:?}",
+            error,
+        );
+        let error = schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, DEFAULT_ONBOARDING_DENOM)]),
+            schedule(0, vec![]),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+        // The default onboarding cost is 100, so a holiday must charge less than that.  A cost of
+        // 10 would split into 7.5 for the fee collector at the default 75% fee
+        for onboarding_cost in [100, 150, 10] {
+            let error = schedule_fee_holiday(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(DEFAULT_INFO_NAME, &[]),
+                schedule(onboarding_cost, vec![]),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidFields { .. }),
+                "an onboarding cost of {} should be rejected, but got: {:?}",
+                onboarding_cost,
+                error,
+            );
+        }
+        let mut ended = schedule(0, vec![]);
+        ended.end_time = mock_env().block.time;
+        let error = schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            ended,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "a holiday that has already ended should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_schedule_fee_holiday_checks_payable_type_fee_percents() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        save_payable_type_config(
+            deps.as_mut().storage,
+            &loan_config(DEFAULT_ONBOARDING_DENOM, Decimal::percent(33)),
+        )
+        .unwrap();
+        // A cost of 20 splits evenly at the default 75% fee, but not at the loan type's 33% fee
+        for payable_types in [vec![LOAN_PAYABLE_TYPE.to_string()], vec![]] {
+            let error = schedule_fee_holiday(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(DEFAULT_INFO_NAME, &[]),
+                schedule(20, payable_types.clone()),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidFields { .. }),
+                "a holiday covering {:?} should be rejected for the loan type's fee percent, but got: {:?}",
+                payable_types,
+                error,
+            );
+        }
+        schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(20, vec![DEFAULT_PAYABLE_TYPE.to_string()]),
+        )
+        .expect("a holiday that does not cover the loan type should use the default fee percent");
+        save_payable_type_config(
+            deps.as_mut().storage,
+            &loan_config("usdf", Decimal::percent(33)),
+        )
+        .unwrap();
+        schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(20, vec![]),
+        )
+        .expect("a payable type configured with another denom should not be checked");
+    }
+
+    #[test]
+    fn test_schedule_fee_holiday_checks_referral_share() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        save_referral_config(
+            deps.as_mut().storage,
+            &ReferralConfigV1 {
+                fee_share_bps: 1000,
+                referrers: vec![Addr::unchecked("partner-wallet")],
+            },
+        )
+        .unwrap();
+        // A cost of 20 collects a fee of 15, of which a 10% referral share would be 1.5
+        let error = schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(20, vec![]),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "a holiday whose referral share is not a whole number should be rejected, but got: {:?}",
+            error,
+        );
+        schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(40, vec![]),
+        )
+        .expect("a holiday collecting a fee of 30 should split into a referral share of 3");
+    }
+
+    #[test]
+    fn test_register_during_fee_holiday() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(
+                20,
+                vec!["other-type".to_string(), DEFAULT_PAYABLE_TYPE.to_string()],
+            ),
+        )
+        .unwrap();
+        let response =
+            test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+                .unwrap();
+        assert_eq!(
+            "20/nhash",
+            single_attribute_for_key(&response, ONBOARDING_COST_CHARGED_KEY),
+            "the holiday's onboarding cost should be charged",
+        );
+        assert_eq!(
+            HOLIDAY_ID,
+            single_attribute_for_key(&response, FEE_HOLIDAY_KEY),
+            "the applied holiday should be emitted as an attribute",
+        );
+        assert_eq!(
+            Uint128::new(5),
+            load_held_oracle_fee(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expect("the held oracle fee should be recorded")
+                .amount,
+            "the reduced amount held for the oracle should be recorded",
+        );
+        // The registrant sent the full default cost of 100, so the difference is refunded
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(80, DEFAULT_ONBOARDING_DENOM)],
+                })),
+            "the funds beyond the holiday cost should be refunded",
+        );
+        let response =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap();
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_ORACLE_ADDRESS.to_string(),
+                    amount: vec![coin(5, DEFAULT_ONBOARDING_DENOM)],
+                })),
+            "the oracle should only be paid the amount held during the holiday",
+        );
+        assert!(
+            load_held_oracle_fee(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the held oracle fee should be cleared on approval",
+        );
+    }
+
+    #[test]
+    fn test_register_outside_fee_holiday() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // A holiday for a different payable type does not apply
+        schedule_fee_holiday(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            schedule(0, vec!["other-type".to_string()]),
+        )
+        .unwrap();
+        let response =
+            test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+                .unwrap();
+        assert_eq!(
+            "100/nhash",
+            single_attribute_for_key(&response, ONBOARDING_COST_CHARGED_KEY),
+            "the configured onboarding cost should be charged",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != FEE_HOLIDAY_KEY),
+            "no holiday should be emitted when none applied",
+        );
+        assert_eq!(
+            Uint128::new(25),
+            load_held_oracle_fee(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expect("the held oracle fee should be recorded")
+                .amount,
+            "the configured oracle amount should be held outside of a holiday",
+        );
+    }
+
+    fn schedule(onboarding_cost: u128, payable_types: Vec<String>) -> ScheduleFeeHolidayV1 {
+        let now = mock_env().block.time;
+        ScheduleFeeHolidayV1 {
+            holiday_id: HOLIDAY_ID.to_string(),
+            start_time: now.minus_seconds(60),
+            end_time: now.plus_seconds(60),
+            payable_types,
+            onboarding_cost: Uint128::new(onboarding_cost),
+        }
+    }
+
+    fn loan_config(onboarding_denom: &str, fee_percent: Decimal) -> PayableTypeConfigV1 {
+        PayableTypeConfigV1 {
+            payable_type: LOAN_PAYABLE_TYPE.to_string(),
+            onboarding_cost: Uint128::new(100),
+            onboarding_denom: onboarding_denom.to_string(),
+            fee_percent,
+            default_oracle: None,
+            registrant_requirement: None,
+        }
+    }
+
+    fn cancel() -> CancelFeeHolidayV1 {
+        CancelFeeHolidayV1 {
+            holiday_id: HOLIDAY_ID.to_string(),
+        }
+    }
+}
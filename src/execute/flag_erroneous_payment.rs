@@ -0,0 +1,457 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, is_payment_escrowed, load_payment_record, load_payment_reversal,
+    load_quarantined_payment, record_payable_snapshot, save_payment_reversal,
+    update_payable_timeline, PayableScopeAttribute, PaymentRecordV1, PaymentReversalV1,
+};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    PAYER_KEY, PAYMENT_AMOUNT_KEY, PAYMENT_FLAGGED_ERRONEOUS_KEY, PAYMENT_ID_KEY,
+    TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{BlockInfo, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The reason recorded on the reversal of a payment flagged as erroneous.
+const ERRONEOUS_PAYMENT_REASON: &str = "payment flagged as erroneous";
+
+/// Contains the payment that the contract admin or the payable's oracle would like removed from
+/// the amount paid on a payable, ex: an external settlement that was recorded in error.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FlagErroneousPaymentV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+}
+
+/// Parent function path for the contract to flag an erroneous payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn flag_erroneous_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    flag: FlagErroneousPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    flag_erroneous_payment_with_util(deps, &ProvenanceUtilImpl, env, info, flag)
+}
+
+/// Reverses a payment that was applied to a payable in error with the following steps:
+/// - Verifies that no funds were sent.  No funds move on chain, as any clawback of the payment is
+///   settled outside of the contract.
+/// - Ensures that the payable targeted has been registered and has not been written off.  Flagging
+///   a payment would otherwise reopen a payable that was closed out.
+/// - Verifies that the sender is the contract admin or the payable's oracle.
+/// - Ensures that the payment was recorded in the payable's payment history, and that it has not
+///   already been reversed.
+/// - Ensures that the payment is not held in escrow or quarantine.  Held payments never reached
+///   the payee, and are instead reversed or refunded to their payer.
+/// - Adds the payment back to the payable's remaining owed amount and rewrites the scope attribute,
+///   reopening the payable if the payment had settled it.
+/// - Records the sender and time of the reversal alongside the payment's history.
+pub fn flag_erroneous_payment_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    flag: FlagErroneousPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &flag.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: flag.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if scope_attribute.is_written_off {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "payments on written off payables cannot be flagged as erroneous"
+                .into(),
+        }
+        .to_result();
+    }
+    if !state.is_admin(&info.sender) && info.sender != scope_attribute.oracle_address {
+        return Err(ContractError::Unauthorized);
+    }
+    let payment_record = load_payment_record(deps.storage, &flag.payable_uuid, flag.payment_id)?
+        .ok_or_else(|| {
+            ContractError::std_err(format!(
+                "no payment [{}] was recorded for payable [{}]",
+                flag.payment_id, flag.payable_uuid,
+            ))
+        })?;
+    let invalid_reason =
+        if load_payment_reversal(deps.storage, &flag.payable_uuid, flag.payment_id)?.is_some() {
+            Some("has already been reversed")
+        } else if is_payment_escrowed(deps.storage, &flag.payable_uuid, flag.payment_id)? {
+            Some("is held in escrow and must be reversed instead")
+        } else if load_quarantined_payment(deps.storage, &flag.payable_uuid, flag.payment_id)?
+            .is_some()
+        {
+            Some("is held in quarantine and must be refunded instead")
+        } else {
+            None
+        };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::std_err(format!(
+            "payment [{}] for payable [{}] {}",
+            flag.payment_id, flag.payable_uuid, invalid_reason,
+        ))
+        .to_result();
+    }
+    restore_payment(
+        deps.storage,
+        &mut scope_attribute,
+        &payment_record,
+        payment_record.amount,
+        &env.block,
+    )?;
+    save_payment_reversal(
+        deps.storage,
+        &flag.payable_uuid,
+        flag.payment_id,
+        &PaymentReversalV1 {
+            payer: payment_record.payer.clone(),
+            amount: payment_record.amount,
+            denom: payment_record.denom,
+            reversed_by: info.sender,
+            reason: ERRONEOUS_PAYMENT_REASON.to_string(),
+            reversed_at: env.block.time,
+        },
+    )?;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYMENT_FLAGGED_ERRONEOUS_KEY, &scope_attribute.payable_uuid)
+        .attribute(PAYMENT_ID_KEY, flag.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, payment_record.amount.to_string())
+        .attribute(PAYER_KEY, payment_record.payer.as_str())
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .build())
+}
+
+/// Adds a payment that never reached the payee, ex: one that left escrow or was flagged as
+/// erroneous, back to the payable's remaining owed amount, reopening the payable if the payment had
+/// settled it.  The amount may be only a portion of the recorded payment, ex: a refunded holdback.
+/// It is taken back from the principal that the payment settled first, then from its interest and
+/// late fees, the reverse of the order the payment was applied in, and any interest or late fees
+/// taken back are outstanding again.  The restored attribute must be written through with
+/// write_through_payable.
+pub(crate) fn restore_payment(
+    storage: &mut dyn Storage,
+    scope_attribute: &mut PayableScopeAttribute,
+    payment: &PaymentRecordV1,
+    amount: Uint128,
+    block: &BlockInfo,
+) -> StdResult<()> {
+    let principal_paid = payment
+        .amount
+        .saturating_sub(payment.late_fees_paid)
+        .saturating_sub(payment.interest_paid);
+    let interest_restored = amount
+        .saturating_sub(principal_paid)
+        .min(payment.interest_paid);
+    let late_fees_restored = amount
+        .saturating_sub(principal_paid)
+        .saturating_sub(interest_restored)
+        .min(payment.late_fees_paid);
+    if let Some(accrual) = &mut scope_attribute.interest_accrual {
+        accrual.amount_paid = accrual.amount_paid.checked_sub(interest_restored)?;
+    }
+    if let Some(accrual) = &mut scope_attribute.late_fee_accrual {
+        accrual.amount_paid = accrual.amount_paid.checked_sub(late_fees_restored)?;
+    }
+    scope_attribute.payable_remaining_owed = scope_attribute
+        .payable_total_owed
+        .min(scope_attribute.payable_remaining_owed.checked_add(amount)?);
+    scope_attribute.settled_at = None;
+    update_payable_timeline(storage, &scope_attribute.payable_uuid, |timeline| {
+        timeline.completed_at = None;
+    })?;
+    record_payable_snapshot(storage, scope_attribute, block)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_payment_record, load_payment_reversal, InterestTermsV1, LateFeeV1,
+    };
+    use crate::execute::flag_erroneous_payment::{
+        flag_erroneous_payment_with_util, FlagErroneousPaymentV1, ERRONEOUS_PAYMENT_REASON,
+    };
+    use crate::execute::record_external_settlement::{
+        record_external_settlement_with_util, RecordExternalSettlementV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::execute::write_off_payable::{write_off_payable_with_util, WriteOffPayableV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        PAYMENT_FLAGGED_ERRONEOUS_KEY, PAYMENT_INTEREST_KEY, PAYMENT_LATE_FEE_KEY,
+        PAYMENT_PRINCIPAL_KEY, TOTAL_REMAINING_KEY,
+    };
+    use crate::util::interest::{InterestCompounding, SECONDS_PER_YEAR};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, CosmosMsg, Decimal, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_flag_erroneous_payment_restores_remaining_owed() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL),
+        )
+        .unwrap();
+        let error = flag(&mut deps, &provenance_util, "stranger").unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin or oracle should be able to flag a payment, but got: {:?}",
+            error,
+        );
+        let response = flag(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS)
+            .expect("the oracle should be able to flag the payment");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_FLAGGED_ERRONEOUS_KEY),
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL.to_string(),
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the flagged payment should be added back to the remaining owed amount",
+        );
+        assert!(
+            response
+                .messages
+                .iter()
+                .all(|msg| !matches!(msg.msg, CosmosMsg::Bank(_))),
+            "flagging a payment should not move any funds",
+        );
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            Uint128::new(DEFAULT_PAYABLE_TOTAL),
+            scope_attribute.payable_remaining_owed,
+        );
+        assert!(
+            scope_attribute.settled_at.is_none(),
+            "the payable should be reopened after its settling payment was flagged",
+        );
+        let reversal = load_payment_reversal(deps.as_ref().storage, DEFAULT_PAYABLE_UUID, 0)
+            .unwrap()
+            .expect("a reversal should be recorded for the flagged payment");
+        assert_eq!(
+            Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+            reversal.reversed_by
+        );
+        assert_eq!(Addr::unchecked(DEFAULT_INFO_NAME), reversal.payer);
+        assert_eq!(ERRONEOUS_PAYMENT_REASON, reversal.reason);
+        let error = flag(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "a payment should not be flagged twice, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_flag_erroneous_payment_reopens_late_fees_and_interest() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    late_fee: Some(LateFeeV1 {
+                        due_time: mock_env().block.time.minus_seconds(3 * 86400 + 100),
+                        daily_rate: Decimal::permille(5),
+                    }),
+                    interest: Some(InterestTermsV1 {
+                        start_time: mock_env().block.time.minus_seconds(SECONDS_PER_YEAR),
+                        annual_rate: Decimal::percent(10),
+                        compounding: InterestCompounding::Simple,
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        // 15 in late fees and 100 in interest are settled before 85 of principal
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .unwrap();
+        flag(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS)
+            .expect("the oracle should be able to flag the payment");
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(1115, scope_attribute.payable_remaining_owed.u128());
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL,
+            scope_attribute.principal_remaining().u128(),
+            "the late fees and interest that the payment settled should be outstanding again",
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(115),
+        )
+        .unwrap();
+        assert_eq!(
+            ("15", "100", "0"),
+            (
+                single_attribute_for_key(&response, PAYMENT_LATE_FEE_KEY),
+                single_attribute_for_key(&response, PAYMENT_INTEREST_KEY),
+                single_attribute_for_key(&response, PAYMENT_PRINCIPAL_KEY),
+            ),
+            "the next payment should settle the reopened late fees and interest first",
+        );
+    }
+
+    #[test]
+    fn test_flag_erroneous_external_settlement_reopens_late_fees() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    late_fee: Some(LateFeeV1 {
+                        due_time: mock_env().block.time.minus_seconds(3 * 86400 + 100),
+                        daily_rate: Decimal::permille(5),
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        // 15 in late fees are settled before 85 of principal
+        record_external_settlement_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            RecordExternalSettlementV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                amount: Uint128::new(100),
+                reference: "wire-12345".to_string(),
+            },
+        )
+        .unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let settlement_record = load_payment_record(deps.as_ref().storage, DEFAULT_PAYABLE_UUID, 0)
+            .unwrap()
+            .expect("the settlement should be recorded in the payment history");
+        assert_eq!(
+            Uint128::new(15),
+            settlement_record.late_fees_paid,
+            "the settlement record should include the late fees that it paid",
+        );
+        flag(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS)
+            .expect("the oracle should be able to flag the settlement");
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(1015, scope_attribute.payable_remaining_owed.u128());
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL,
+            scope_attribute.principal_remaining().u128(),
+            "the late fees that the settlement paid should be outstanding again",
+        );
+    }
+
+    #[test]
+    fn test_flag_erroneous_payment_rejects_written_off_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        write_off_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            WriteOffPayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                write_off_reason: "uncollectable".to_string(),
+            },
+        )
+        .unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let error = flag(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payment on a written off payable should not be flagged, but got: {:?}",
+            error,
+        );
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert!(
+            scope_attribute.payable_remaining_owed.is_zero(),
+            "the written off payable should not be reopened",
+        );
+    }
+
+    fn flag(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = flag_erroneous_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            FlagErroneousPaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id: 0,
+            },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+}
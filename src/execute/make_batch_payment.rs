@@ -0,0 +1,263 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::config_read_v2;
+use crate::execute::make_payment::{
+    apply_payment, load_payable_for_payment, PaymentContext, PaymentDetails,
+};
+use crate::util::constants::{
+    BATCH_PAYMENT_COUNT_KEY, BATCH_PAYMENT_EVENT, BATCH_PAYMENT_TOTAL_KEY, PAYER_KEY,
+};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{coin, Coin, DepsMut, Env, Event, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A single payment within a batch, declaring the amount of the payable's denom to pay.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BatchPaymentV1 {
+    pub payable_uuid: String,
+    pub amount: Uint128,
+}
+
+/// Contains all relevant fields required in order to pay multiple payables in a single execution.
+pub struct MakeBatchPaymentV1 {
+    pub payments: Vec<BatchPaymentV1>,
+}
+
+/// Parent function path for the contract to make a batch payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn make_batch_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    batch: MakeBatchPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    make_batch_payment_with_util(deps, &ProvenanceUtilImpl, env, info, batch)
+}
+
+/// Makes payments on multiple registered payables with the following steps:
+/// - Ensures that the contract is not paused.
+/// - Verifies that each payable has been registered and approved by its oracle.
+/// - Verifies that the funds provided exactly match the sum of the declared amounts for each
+///   payable's denom.
+/// - Applies each payment independently, exactly as a single payment would be applied.
+/// - Emits the attributes of each payment, plus a single payable_batch_payment event containing the
+///   number of payments made and the total funds paid.
+pub fn make_batch_payment_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    batch: MakeBatchPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let mut declared_funds: BTreeMap<String, u128> = BTreeMap::new();
+    let mut loaded_payments = vec![];
+    for payment in batch.payments {
+        let scope_attribute = load_payable_for_payment(&deps.as_ref(), payment.payable_uuid)?;
+        *declared_funds
+            .entry(scope_attribute.payable_denom.clone())
+            .or_insert(0) += payment.amount.u128();
+        let payment_amount = Money::new(payment.amount, &scope_attribute.payable_denom);
+        loaded_payments.push((scope_attribute, payment_amount));
+    }
+    let mut provided_funds: BTreeMap<String, u128> = BTreeMap::new();
+    for provided in info.funds.iter() {
+        *provided_funds.entry(provided.denom.clone()).or_insert(0) += provided.amount.u128();
+    }
+    if declared_funds != provided_funds {
+        return ContractError::FundsMismatch {
+            expected: funds_to_string(&declared_funds),
+            provided: funds_to_string(&provided_funds),
+        }
+        .to_result();
+    }
+    let mut response = ResponseBuilder::new();
+    let payment_count = loaded_payments.len();
+    for (scope_attribute, payment_amount) in loaded_payments {
+        let applied_payment = apply_payment(
+            deps.branch(),
+            provenance_util,
+            &env,
+            &PaymentContext {
+                state: &state,
+                payer: &info.sender,
+                details: PaymentDetails::default(),
+            },
+            scope_attribute,
+            payment_amount,
+        )?;
+        response = response
+            .messages(applied_payment.messages)
+            .attributes(applied_payment.attributes);
+    }
+    Ok(response
+        .event(
+            Event::new(BATCH_PAYMENT_EVENT)
+                .add_attribute(BATCH_PAYMENT_COUNT_KEY, payment_count.to_string())
+                .add_attribute(BATCH_PAYMENT_TOTAL_KEY, funds_to_string(&declared_funds))
+                .add_attribute(PAYER_KEY, info.sender.as_str()),
+        )
+        .build())
+}
+
+/// Renders a collection of denom amounts in the standard cosmos coin list format: ex "100nhash,5usd".
+fn funds_to_string(funds: &BTreeMap<String, u128>) -> String {
+    funds
+        .iter()
+        .map(|(denom, amount)| coin(*amount, denom))
+        .map(|funds_coin: Coin| funds_coin.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::make_batch_payment::{
+        make_batch_payment_with_util, BatchPaymentV1, MakeBatchPaymentV1,
+    };
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_PAYABLE_DENOM,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        BATCH_PAYMENT_COUNT_KEY, BATCH_PAYMENT_EVENT, BATCH_PAYMENT_TOTAL_KEY, PAYMENT_MADE_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_make_batch_payment_success() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response = make_batch_payment_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info("payer", &[coin(200, DEFAULT_PAYABLE_DENOM)]),
+            batch_of(vec![(DEFAULT_PAYABLE_UUID, 200)]),
+        )
+        .expect("a batch payment with matching funds should succeed");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_MADE_KEY),
+            "the individual payment attributes should be emitted",
+        );
+        assert_eq!(
+            "800",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the payable's remaining owed should be reduced by its payment amount",
+        );
+        assert_eq!(
+            1,
+            response.events.len(),
+            "a single aggregate event should be emitted"
+        );
+        let event = response.events.first().unwrap();
+        assert_eq!(
+            BATCH_PAYMENT_EVENT, event.ty,
+            "the batch payment event should be emitted"
+        );
+        assert_eq!(
+            "1",
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == BATCH_PAYMENT_COUNT_KEY)
+                .unwrap()
+                .value,
+            "the event should include the number of payables paid",
+        );
+        assert_eq!(
+            "200nhash",
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == BATCH_PAYMENT_TOTAL_KEY)
+                .unwrap()
+                .value,
+            "the event should include the total funds paid",
+        );
+    }
+
+    #[test]
+    fn test_make_batch_payment_funds_mismatch() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let error = make_batch_payment_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(
+                "payer",
+                &[coin(200, DEFAULT_PAYABLE_DENOM), coin(10, "otherdenom")],
+            ),
+            batch_of(vec![(DEFAULT_PAYABLE_UUID, 200)]),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::FundsMismatch { expected, provided } => {
+                assert_eq!(
+                    "200nhash", expected,
+                    "the declared total should be reported"
+                );
+                assert_eq!(
+                    "200nhash,10otherdenom", provided,
+                    "the provided total should be reported",
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_make_batch_payment_rejects_batch_with_unknown_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let error = make_batch_payment_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info("payer", &[coin(201, DEFAULT_PAYABLE_DENOM)]),
+            batch_of(vec![
+                (DEFAULT_PAYABLE_UUID, 200),
+                ("09798cd6-83ad-11ec-b485-eff659cf8387", 1),
+            ]),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::PayableNotFound { payable_uuid } => assert_eq!(
+                "09798cd6-83ad-11ec-b485-eff659cf8387", payable_uuid,
+                "the unknown payable should be reported, and the whole batch rejected",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+
+    fn batch_of(payments: Vec<(&str, u128)>) -> MakeBatchPaymentV1 {
+        MakeBatchPaymentV1 {
+            payments: payments
+                .into_iter()
+                .map(|(payable_uuid, amount)| BatchPaymentV1 {
+                    payable_uuid: payable_uuid.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        }
+    }
+}
@@ -1,64 +1,204 @@
 use crate::core::error::ContractError;
-use crate::core::state::config_read_v2;
+use crate::core::money::Money;
+use crate::core::payee_resolver::{
+    payout_to_payees, resolve_payment_destination, PaymentDestination,
+};
+use crate::core::state::{
+    append_payment_record, config_read_v2, is_payable_type_frozen, load_payable_authorized_payers,
+    load_payable_minimum_payment, load_payable_risk_band, load_payment_batch,
+    load_quarantine_config, record_payable_snapshot, record_payment_velocity, save_payment_batch,
+    save_quarantined_payment, update_payable_timeline, PayableScopeAttribute, PaymentBatchV1,
+    PaymentMemoV1, PaymentRecordV1, QuarantinedPaymentV1, StateV2,
+};
+#[cfg(feature = "escrow")]
+use crate::core::state::{load_pending_cancellation, save_escrowed_payment, EscrowedPaymentV1};
+use crate::execute::payment_volume_cap::track_payment_volume;
+use crate::execute::revert_stale_approval::is_approval_stale;
+use crate::execute::subscribe::get_subscriber_attributes;
 use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+#[cfg(feature = "escrow")]
+use crate::util::constants::PAYMENT_ESCROWED_KEY;
 use crate::util::constants::{
-    ORACLE_ADDRESS_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY, PAYEE_KEY, PAYER_KEY,
-    PAYMENT_AMOUNT_KEY, PAYMENT_MADE_KEY, TOTAL_REMAINING_KEY,
+    ATTRIBUTE_WRITE_DEFERRED_KEY, INSTALLMENT_PAID_KEY, INTEREST_ACCRUED_KEY, LATE_FEE_ACCRUED_KEY,
+    PAYABLE_SETTLED_KEY, PAYEE_KEY, PAYER_KEY, PAYMENT_AMOUNT_KEY, PAYMENT_HOLDBACK_KEY,
+    PAYMENT_INTEREST_KEY, PAYMENT_LATE_FEE_KEY, PAYMENT_MADE_KEY, PAYMENT_MEMO_KEY,
+    PAYMENT_ON_BEHALF_OF_KEY, PAYMENT_PRINCIPAL_KEY, PAYMENT_QUARANTINED_KEY, PAYMENT_REFUND_KEY,
+    PAYMENT_VOLUME_CAP_TRIPPED_KEY, QUARANTINE_REASON_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::{payable_attributes, ResponseBuilder};
+use cosmwasm_std::{
+    Addr, Attribute, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    Storage, Uint128,
 };
-use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
-use cosmwasm_std::{coin, BankMsg, CosmosMsg, DepsMut, MessageInfo, Response};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 /// Contains all relevant fields required in order to make a payment for a payable.
 pub struct MakePaymentV1 {
     pub payable_uuid: String,
+    // When true, funds beyond the remaining owed amount are refunded instead of rejected
+    pub refund_overpayment: bool,
+    // An optional reference, such as an invoice number, recorded with the payment
+    pub memo: Option<String>,
+    // The client that the sender is paying for, when the sender is a third party such as a
+    // factoring agent
+    pub on_behalf_of: Option<String>,
 }
 
 /// Parent function path for the contract to register a payable.  Ensures that the ProvenanceUtilImpl
 /// is the implementation used for this functionality outside of tests.
 pub fn make_payment(
     deps: DepsMut<ProvenanceQuery>,
+    env: Env,
     info: MessageInfo,
     make_payment: MakePaymentV1,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    make_payment_with_util(deps, &ProvenanceUtilImpl, info, make_payment)
+    make_payment_with_util(deps, &ProvenanceUtilImpl, env, info, make_payment)
 }
 
 /// Makes a payment on a registered payable with the following steps:
+/// - Ensures that the contract is not paused.
 /// - Verifies that the oracle has approved for the payable.
+/// - Verifies that the oracle's approval has not lapsed, if the contract has an approval valid
+///   duration.  A lapsed approval must be renewed by the oracle before payments resume.
+/// - Verifies that the oracle's approval has not gone stale, if the oracle approved the payable
+///   with a validity window.  A stale approval must be reverted and re-validated by the oracle.
+/// - Verifies that the payable has not expired.
 /// - Verifies that the payable has been registered with the contract.
-/// - Verifies that all funds provided are in the denomination required by the payable.
-/// - Verifies that the funds provided are <= payable total owed, but > 0.
-/// - Subtracts the payment amount from the total amount owed on the scope attribute.
-/// - Sends the amount of funds provided to the value owner of the payable's scope.
+/// - Verifies that all funds provided are in a single denomination accepted by the payable: its
+///   payable denom or one of its additional accepted denoms.
+/// - Verifies that the funds provided meet the payable's minimum payment amount (or the contract's,
+///   if the payable has none), unless they settle the remaining owed amount.
+/// - Verifies that the funds provided are <= payable total owed, but > 0.  Accepted denoms are
+///   applied to the amount owed one-to-one, without conversion.  If the sender requested
+///   overpayment refunds, the payment is instead capped at the remaining owed amount and the excess
+///   is returned to the sender.
+/// - Subtracts the payment amount from the total amount owed on the scope attribute, deferring the
+///   attribute write if the payment falls within the contract's payment batch window.
+/// - Sends the amount of funds provided to the payable's payee, or to the value owner of the
+///   payable's scope if it has never been transferred.  Payables registered in escrow mode instead
+///   hold the funds in the contract, keyed on the payment's id in the payable's payment history,
+///   until the payee or oracle releases them or the payer reclaims them after the escrow timeout.
+/// - Holds the funds in the contract instead if the payment trips one of the contract's quarantine
+///   heuristics, keyed on the payment's id, until the admin releases or refunds them.  The payment
+///   is still applied to the remaining owed amount, and is added back if it is refunded.
+/// - Records the payer's memo, if provided, in the payable's timeline.
+/// - Records the client that the payment was made on behalf of, if provided, in the payable's
+///   payment history.  The sender remains the payer, and must be authorized to pay the payable.
+/// - Marks the payable as settled when the payment pays it off, after which it can be pruned from
+///   the contract once the contract's settled retention window has passed.
+/// - Accrues any late fees owed on the payable since its due time, adding them to the amount owed.
+///   Payments settle outstanding late fees before the principal, and the split between the two is
+///   emitted for payables with late fee terms.
+/// - Accrues simple interest on the remaining principal since the payable's interest start time,
+///   adding it to the amount owed.  Payments settle outstanding interest after late fees but
+///   before the principal, and the portion applied to interest is emitted for payables with
+///   interest terms.
+/// - Emits the index of each installment in the payable's payment schedule, if it has one, that
+///   the payment satisfied.
+/// - Verifies that the sender is one of the payable's authorized payers, if it was registered with
+///   an allowlist.
+/// - Adds the payment to the volume of its denom's payment volume cap window, if the denom is
+///   capped.  Payments are rejected while the cap's breaker is tripped, and the payment that trips
+///   it emits the denom.
+/// - Rejects payments larger than the contract's large payment threshold, if it has one.  These
+///   must instead be proposed by the payer and approved by the payable's oracle.
+/// - Keys the payment off the risk band that the payable's oracle risk score falls into, if any.
+///   The band's large payment threshold replaces the contract's, and its holdback percent of each
+///   payment sent directly to the payee is held in quarantine until the admin releases or refunds
+///   it, under the payment's id.
 pub fn make_payment_with_util<T: ProvenanceUtil>(
     deps: DepsMut<ProvenanceQuery>,
     provenance_util: &T,
+    env: Env,
     info: MessageInfo,
     make_payment: MakePaymentV1,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    let mut scope_attribute =
-        match query_payable_attribute_by_uuid(&deps.as_ref(), &make_payment.payable_uuid) {
-            Ok(attr) => {
-                if !attr.oracle_approved {
-                    return Err(ContractError::NotReadyForPayment {
-                        payable_uuid: attr.payable_uuid,
-                        not_ready_reason: "Payable missing oracle approval".into(),
-                    });
-                }
-                attr
-            }
-            Err(_) => {
-                return Err(ContractError::PayableNotFound {
-                    payable_uuid: make_payment.payable_uuid,
-                });
-            }
-        };
-    let invalid_funds = info
-        .funds
+    let made_payment = process_payment(deps, provenance_util, &env, &info, make_payment)?;
+    let mut response = ResponseBuilder::new()
+        .messages(made_payment.applied_payment.messages)
+        .attributes(made_payment.applied_payment.attributes);
+    if !made_payment.refund.is_zero() {
+        response = response
+            .message(made_payment.refund.send_to(&info.sender))
+            .attribute(PAYMENT_REFUND_KEY, made_payment.refund.to_string());
+    }
+    Ok(response.build())
+}
+
+/// A payment applied by make_payment, along with the portion of the funds refunded to the sender.
+pub(crate) struct MadePayment {
+    pub applied_payment: AppliedPayment,
+    pub refund: Money,
+}
+
+/// Validates and applies a payment made with the given funds, producing everything that the
+/// response needs but the refund transfer.  Shared with payment simulations, which run it against
+/// storage that discards its writes.
+pub(crate) fn process_payment<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: &Env,
+    info: &MessageInfo,
+    make_payment: MakePaymentV1,
+) -> Result<MadePayment, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let on_behalf_of = make_payment
+        .on_behalf_of
+        .map(|client| deps.api.addr_validate(&client))
+        .transpose()?;
+    let scope_attribute = load_payable_for_payment(&deps.as_ref(), make_payment.payable_uuid)?;
+    let mut payment = payment_from_funds(&scope_attribute, &info.funds)?;
+    // A payable that is already paid off still rejects the payment outright, as there is nothing to
+    // apply it to.  Accepted denoms settle the remaining owed amount one-to-one, including any late
+    // fees and interest that the payment will accrue
+    let remaining_owed = Money::new(
+        scope_attribute.payable_remaining_owed
+            + scope_attribute.late_fees_due(env.block.time)?
+            + scope_attribute.interest_due(env.block.time)?,
+        &payment.denom,
+    );
+    let mut refund = Money::zero(&payment.denom);
+    if make_payment.refund_overpayment
+        && !remaining_owed.is_zero()
+        && payment.amount > remaining_owed.amount
+    {
+        refund = payment.checked_sub(&remaining_owed)?;
+        payment = remaining_owed;
+    }
+    let applied_payment = apply_payment(
+        deps,
+        provenance_util,
+        env,
+        &PaymentContext {
+            state: &state,
+            payer: &info.sender,
+            details: PaymentDetails {
+                memo: make_payment.memo.as_deref(),
+                on_behalf_of: on_behalf_of.as_ref(),
+                co_signed: false,
+            },
+        },
+        scope_attribute,
+        payment,
+    )?;
+    Ok(MadePayment {
+        applied_payment,
+        refund,
+    })
+}
+
+/// Sums the funds provided into a single payment, verifying that all funds are in one of the
+/// payable's accepted denoms and that the payment is not empty.
+pub(crate) fn payment_from_funds(
+    scope_attribute: &PayableScopeAttribute,
+    funds: &[Coin],
+) -> Result<Money, ContractError> {
+    let invalid_funds = funds
         .iter()
         .filter_map(|coin| {
-            if coin.denom != scope_attribute.payable_denom {
+            if !scope_attribute.accepts_denom(&coin.denom) {
                 Some(coin.denom.clone())
             } else {
                 None
@@ -67,53 +207,519 @@ pub fn make_payment_with_util<T: ProvenanceUtil>(
         .collect::<Vec<String>>();
     if !invalid_funds.is_empty() {
         return Err(ContractError::InvalidFundsProvided {
-            valid_denom: scope_attribute.payable_denom,
+            valid_denom: scope_attribute.payable_denom.clone(),
             invalid_denoms: invalid_funds,
         });
     }
-    // Now that all funds are verified equivalent to our payment denomination, sum all amounts to
-    // derive the total provided
-    let payment_amount = info
-        .funds
-        .into_iter()
-        .fold(0u128, |acc, coin| acc + coin.amount.u128());
+    // Now that all funds are verified to be in accepted denominations, sum all amounts to derive
+    // the total provided.  A payment is made in a single denom, so summing in the denom of the
+    // first coin rejects any mix of accepted denoms
+    let payment_denom = funds
+        .first()
+        .map_or(&scope_attribute.payable_denom, |coin| &coin.denom);
+    let payment = Money::zero(payment_denom).checked_add_coins(funds)?;
     // u128 values can never be negative.  Invalid coin in funds would be rejected outright before the
     // function executes.
-    if payment_amount == 0 {
+    if payment.is_zero() {
         return Err(ContractError::NoFundsProvided {
-            valid_denom: scope_attribute.payable_denom,
+            valid_denom: scope_attribute.payable_denom.clone(),
+        });
+    }
+    Ok(payment)
+}
+
+/// Fetches the target payable's scope attribute, verifying that it has been registered and approved
+/// by its oracle.
+pub(crate) fn load_payable_for_payment(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: String,
+) -> Result<PayableScopeAttribute, ContractError> {
+    match query_payable_attribute_by_uuid(deps, &payable_uuid) {
+        Ok(attr) => {
+            if !attr.oracle_approved {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable missing oracle approval".into(),
+                });
+            }
+            if attr.is_written_off {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable has been written off".into(),
+                });
+            }
+            if attr.is_declined {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable has been declined by its payee".into(),
+                });
+            }
+            if attr.dispute.is_some() {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable is under dispute".into(),
+                });
+            }
+            if attr.is_frozen {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable has been frozen by its oracle".into(),
+                });
+            }
+            if is_payable_type_frozen(deps.storage, &attr.payable_type)? {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable type has been frozen by the contract admin".into(),
+                });
+            }
+            #[cfg(feature = "escrow")]
+            if load_pending_cancellation(deps.storage, &attr.payable_uuid)?.is_some() {
+                return Err(ContractError::NotReadyForPayment {
+                    payable_uuid: attr.payable_uuid,
+                    not_ready_reason: "Payable is pending cancellation".into(),
+                });
+            }
+            Ok(attr)
+        }
+        Err(_) => Err(ContractError::PayableNotFound { payable_uuid }),
+    }
+}
+
+/// The messages and attributes produced by applying a single payment to a payable.
+pub(crate) struct AppliedPayment {
+    pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    pub attributes: Vec<Attribute>,
+    // The payable's remaining owed amount after the payment
+    pub remaining_owed: Uint128,
+    // The account that the payment was made out to, even if it is held in escrow or quarantine
+    pub payee: Addr,
+}
+
+/// The contract state and the parties behind a payment that is applied to a payable.
+pub(crate) struct PaymentContext<'a> {
+    pub state: &'a StateV2,
+    pub payer: &'a Addr,
+    pub details: PaymentDetails<'a>,
+}
+
+/// Optional context provided by the payer and recorded alongside a payment.
+#[derive(Default)]
+pub(crate) struct PaymentDetails<'a> {
+    pub memo: Option<&'a str>,
+    pub on_behalf_of: Option<&'a Addr>,
+    // Whether or not the payable's oracle approved the payment, allowing it to exceed the
+    // contract's large payment threshold
+    pub co_signed: bool,
+}
+
+/// Applies a payment amount, already verified to have been provided in one of the payable's
+/// accepted denoms, to a loaded payable.  Ensures that the payable has not expired, that its oracle
+/// approval has not lapsed or gone stale, that the payer is authorized to pay it, that the payment
+/// meets the minimum payment amount unless it settles the payable, that the payment does not exceed
+/// the remaining owed amount, and that the payment was co-signed if it exceeds the large payment
+/// threshold of the payable's risk band or the contract, updates the payable's timeline, payment
+/// history, and attribute (or payment batch), and creates the transfer to the payee, less any
+/// holdback of the payable's risk band.
+pub(crate) fn apply_payment<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: &Env,
+    context: &PaymentContext,
+    mut scope_attribute: PayableScopeAttribute,
+    payment: Money,
+) -> Result<AppliedPayment, ContractError> {
+    let (state, payer, details) = (context.state, context.payer, &context.details);
+    scope_attribute.check_not_expired(env.block.time)?;
+    if let Some(lapses_at) = scope_attribute.approval_lapses_at(state.approval_valid_duration) {
+        if env.block.time >= lapses_at {
+            return Err(ContractError::NotReadyForPayment {
+                payable_uuid: scope_attribute.payable_uuid,
+                not_ready_reason: format!(
+                    "Oracle approval lapsed at {} and must be renewed",
+                    lapses_at
+                ),
+            });
+        }
+    }
+    if is_approval_stale(deps.storage, &scope_attribute, env.block.time)? {
+        return Err(ContractError::NotReadyForPayment {
+            payable_uuid: scope_attribute.payable_uuid,
+            not_ready_reason:
+                "Oracle approval went stale without a payment and must be re-validated".into(),
         });
     }
+    if let Some(authorized_payers) =
+        load_payable_authorized_payers(deps.storage, &scope_attribute.payable_uuid)?
+    {
+        if !authorized_payers.contains(payer) {
+            return ContractError::PayerNotAuthorized {
+                payable_uuid: scope_attribute.payable_uuid,
+                payer: payer.to_string(),
+            }
+            .to_result();
+        }
+    }
+    let late_fees_accrued = scope_attribute.accrue_late_fees(env.block.time)?;
+    let interest_accrued = scope_attribute.accrue_interest(env.block.time)?;
+    let payment_amount = payment.amount.u128();
     if payment_amount > scope_attribute.payable_remaining_owed.u128() {
         return Err(ContractError::PaymentTooLarge {
             total_owed: scope_attribute.payable_remaining_owed.u128(),
             amount_provided: payment_amount,
         });
     }
-    let scope = provenance_util.get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?;
-    let payee = scope.value_owner_address;
-    let payment_message = CosmosMsg::Bank(BankMsg::Send {
-        to_address: payee.to_string(),
-        amount: vec![coin(payment_amount, &scope_attribute.payable_denom)],
-    });
-    // Subtract payment amount from tracked total
+    let risk_band = load_payable_risk_band(deps.storage, &scope_attribute)?;
+    // A threshold set on the payable's risk band takes precedence over the contract's threshold
+    let large_payment_threshold = risk_band
+        .as_ref()
+        .and_then(|band| band.large_payment_threshold)
+        .or(state.large_payment_threshold);
+    if let Some(large_payment_threshold) = large_payment_threshold {
+        if !details.co_signed && payment.amount > large_payment_threshold {
+            return Err(ContractError::PaymentRequiresCoSign {
+                large_payment_threshold: large_payment_threshold.u128(),
+                amount_provided: payment_amount,
+            });
+        }
+    }
+    // A minimum set on the payable at registration takes precedence over the contract's minimum
+    let minimum_payment =
+        load_payable_minimum_payment(deps.storage, &scope_attribute.payable_uuid)?
+            .or(state.minimum_payment_amount);
+    if let Some(minimum_payment) = minimum_payment {
+        if payment_amount < minimum_payment.u128()
+            && payment_amount < scope_attribute.payable_remaining_owed.u128()
+        {
+            return Err(ContractError::PaymentTooSmall {
+                minimum_payment: minimum_payment.u128(),
+                amount_provided: payment_amount,
+            });
+        }
+    }
+    let payment_volume_cap_tripped = track_payment_volume(deps.storage, env, &payment)?;
+    record_payment_velocity(deps.storage, &env.block, &payment)?;
+    let previously_satisfied_installments = scope_attribute.installments_satisfied();
+    // Subtract payment amount from tracked total.  The total is tracked in the payable denom, and
+    // payments in any accepted denom are applied to it one-to-one
     scope_attribute.payable_remaining_owed =
-        (scope_attribute.payable_remaining_owed.u128() - payment_amount).into();
-    // Load state to derive payable type and contract name
-    let state = config_read_v2(deps.storage).load()?;
-    let upsert_attribute_msgs =
-        provenance_util.upsert_attribute_to_scope(&scope_attribute, &state.contract_name)?;
-    Ok(Response::new()
-        .add_message(payment_message)
-        .add_messages(upsert_attribute_msgs.to_vec())
-        .add_attribute(PAYMENT_MADE_KEY, &scope_attribute.payable_uuid)
-        .add_attribute(PAYABLE_TYPE_KEY, &scope_attribute.payable_type)
-        .add_attribute(PAYABLE_UUID_KEY, &scope_attribute.payable_uuid)
-        .add_attribute(ORACLE_ADDRESS_KEY, &scope_attribute.oracle_address)
-        .add_attribute(PAYMENT_AMOUNT_KEY, payment_amount.to_string())
-        .add_attribute(TOTAL_REMAINING_KEY, scope_attribute.payable_remaining_owed)
-        .add_attribute(PAYER_KEY, &info.sender.to_string())
-        .add_attribute(PAYEE_KEY, payee.as_str()))
+        Money::new(scope_attribute.payable_remaining_owed, &payment.denom)
+            .checked_sub(&payment)?
+            .amount;
+    // Outstanding late fees are settled first, then outstanding interest, then the principal
+    let late_fees_paid = match &mut scope_attribute.late_fee_accrual {
+        Some(accrual) => {
+            let late_fees_paid = accrual.amount_outstanding().min(payment.amount);
+            accrual.amount_paid += late_fees_paid;
+            late_fees_paid
+        }
+        None => Uint128::zero(),
+    };
+    let interest_paid = match &mut scope_attribute.interest_accrual {
+        Some(accrual) => {
+            let interest_paid = accrual
+                .amount_outstanding()
+                .min(payment.amount - late_fees_paid);
+            accrual.amount_paid += interest_paid;
+            interest_paid
+        }
+        None => Uint128::zero(),
+    };
+    if scope_attribute.payable_remaining_owed.is_zero() {
+        scope_attribute.settled_at = Some(env.block.time);
+    }
+    let destination = resolve_payment_destination(
+        &deps.as_ref(),
+        provenance_util,
+        &scope_attribute,
+        env.block.time,
+    )?;
+    let payee = destination.payee().clone();
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let mut attributes: Vec<Attribute> = vec![];
+    update_payable_timeline(deps.storage, &scope_attribute.payable_uuid, |timeline| {
+        timeline.payment_times.push(env.block.time);
+        if let Some(memo) = details.memo {
+            timeline.payment_memos.push(PaymentMemoV1 {
+                paid_at: env.block.time,
+                memo: memo.to_string(),
+            });
+        }
+        if scope_attribute.payable_remaining_owed.is_zero() {
+            timeline.completed_at = Some(env.block.time);
+        }
+    })?;
+    let quarantine_reason = load_quarantine_config(deps.storage)?
+        .and_then(|config| config.quarantine_reason(payer, details.on_behalf_of, &payment));
+    // Payments sent directly to the payee are divided among its payee splits before they are
+    // recorded, while held payments are divided, and their records updated, once they are released.
+    // The holdback of the payable's risk band is held in quarantine rather than sent
+    let (payee_payout, holdback) = match (&quarantine_reason, &destination) {
+        (None, PaymentDestination::Payee(payee)) => {
+            let holdback = risk_band
+                .as_ref()
+                .and_then(|band| band.holdback_percent)
+                .map(|percent| Money::new(payment.amount * percent, &payment.denom))
+                .filter(|holdback| !holdback.is_zero());
+            let payout_amount = match &holdback {
+                Some(holdback) => payment.checked_sub(holdback)?,
+                None => payment.clone(),
+            };
+            let payee_payout = payout_to_payees(
+                deps.storage,
+                &scope_attribute.payable_uuid,
+                payee,
+                &payout_amount,
+            )?;
+            (Some(payee_payout), holdback)
+        }
+        _ => (None, None),
+    };
+    let payment_id = append_payment_record(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+        &PaymentRecordV1 {
+            payer: payer.clone(),
+            amount: payment.amount,
+            denom: payment.denom.clone(),
+            paid_at: env.block.time,
+            on_behalf_of: details.on_behalf_of.cloned(),
+            external_reference: None,
+            payee_splits: payee_payout
+                .as_ref()
+                .and_then(|payout| payout.payee_splits.clone()),
+            late_fees_paid,
+            interest_paid,
+            netting: None,
+        },
+    )?;
+    if let Some(mut payee_payout) = payee_payout {
+        attributes.append(&mut payee_payout.attributes());
+        messages.append(&mut payee_payout.messages);
+    }
+    match (quarantine_reason, destination) {
+        (Some(reason), _) => {
+            save_quarantined_payment(
+                deps.storage,
+                &scope_attribute.payable_uuid,
+                payment_id,
+                &QuarantinedPaymentV1 {
+                    payer: payer.clone(),
+                    amount: payment.amount,
+                    denom: payment.denom.clone(),
+                    reason: reason.clone(),
+                    quarantined_at: env.block.time,
+                },
+            )?;
+            attributes.push(Attribute::new(
+                PAYMENT_QUARANTINED_KEY,
+                payment_id.to_string(),
+            ));
+            attributes.push(Attribute::new(QUARANTINE_REASON_KEY, reason));
+        }
+        // The payout to the payee was created before the payment was recorded
+        (None, PaymentDestination::Payee(_)) => {
+            if let Some(holdback) = holdback {
+                let reason = format!(
+                    "risk score [{}] holds back [{}] of each payment",
+                    scope_attribute.risk_score().unwrap_or_default(),
+                    risk_band
+                        .and_then(|band| band.holdback_percent)
+                        .unwrap_or_default(),
+                );
+                save_quarantined_payment(
+                    deps.storage,
+                    &scope_attribute.payable_uuid,
+                    payment_id,
+                    &QuarantinedPaymentV1 {
+                        payer: payer.clone(),
+                        amount: holdback.amount,
+                        denom: holdback.denom.clone(),
+                        reason: reason.clone(),
+                        quarantined_at: env.block.time,
+                    },
+                )?;
+                attributes.push(Attribute::new(
+                    PAYMENT_QUARANTINED_KEY,
+                    payment_id.to_string(),
+                ));
+                attributes.push(Attribute::new(QUARANTINE_REASON_KEY, reason));
+                attributes.push(Attribute::new(
+                    PAYMENT_HOLDBACK_KEY,
+                    holdback.amount.to_string(),
+                ));
+            }
+        }
+        #[cfg(feature = "escrow")]
+        (None, PaymentDestination::Escrow { reclaimable_at, .. }) => {
+            save_escrowed_payment(
+                deps.storage,
+                &scope_attribute.payable_type,
+                &scope_attribute.payable_uuid,
+                payment_id,
+                &EscrowedPaymentV1 {
+                    payer: payer.clone(),
+                    amount: payment.amount,
+                    denom: payment.denom.clone(),
+                    reclaimable_at,
+                },
+            )?;
+            attributes.push(Attribute::new(PAYMENT_ESCROWED_KEY, payment_id.to_string()));
+        }
+    }
+    // Late fee and interest accruals are tracked on the attribute alone, so any change to either is
+    // written through
+    let write_attribute = !late_fees_accrued.is_zero()
+        || !late_fees_paid.is_zero()
+        || !interest_accrued.is_zero()
+        || !interest_paid.is_zero()
+        || should_write_attribute(deps.storage, env, state, &scope_attribute, payment_amount)?;
+    if write_attribute {
+        record_payable_snapshot(deps.storage, &scope_attribute, &env.block)?;
+        messages.append(&mut write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?);
+    } else {
+        attributes.push(Attribute::new(
+            ATTRIBUTE_WRITE_DEFERRED_KEY,
+            &scope_attribute.payable_uuid,
+        ));
+    }
+    attributes.push(Attribute::new(
+        PAYMENT_MADE_KEY,
+        &scope_attribute.payable_uuid,
+    ));
+    attributes.extend(payable_attributes(&scope_attribute));
+    attributes.push(Attribute::new(
+        PAYMENT_AMOUNT_KEY,
+        payment_amount.to_string(),
+    ));
+    attributes.push(Attribute::new(
+        TOTAL_REMAINING_KEY,
+        scope_attribute.payable_remaining_owed.to_string(),
+    ));
+    attributes.push(Attribute::new(PAYER_KEY, payer.as_str()));
+    attributes.push(Attribute::new(PAYEE_KEY, payee.as_str()));
+    if let Some(memo) = details.memo {
+        attributes.push(Attribute::new(PAYMENT_MEMO_KEY, memo));
+    }
+    if let Some(on_behalf_of) = details.on_behalf_of {
+        attributes.push(Attribute::new(
+            PAYMENT_ON_BEHALF_OF_KEY,
+            on_behalf_of.as_str(),
+        ));
+    }
+    if scope_attribute.settled_at.is_some() {
+        attributes.push(Attribute::new(
+            PAYABLE_SETTLED_KEY,
+            &scope_attribute.payable_uuid,
+        ));
+    }
+    if scope_attribute.late_fee.is_some() || scope_attribute.interest.is_some() {
+        attributes.push(Attribute::new(
+            PAYMENT_PRINCIPAL_KEY,
+            (payment.amount - late_fees_paid - interest_paid).to_string(),
+        ));
+    }
+    if scope_attribute.late_fee.is_some() {
+        attributes.push(Attribute::new(
+            PAYMENT_LATE_FEE_KEY,
+            late_fees_paid.to_string(),
+        ));
+    }
+    if scope_attribute.interest.is_some() {
+        attributes.push(Attribute::new(
+            PAYMENT_INTEREST_KEY,
+            interest_paid.to_string(),
+        ));
+    }
+    if !late_fees_accrued.is_zero() {
+        attributes.push(Attribute::new(
+            LATE_FEE_ACCRUED_KEY,
+            late_fees_accrued.to_string(),
+        ));
+    }
+    if !interest_accrued.is_zero() {
+        attributes.push(Attribute::new(
+            INTEREST_ACCRUED_KEY,
+            interest_accrued.to_string(),
+        ));
+    }
+    if payment_volume_cap_tripped {
+        attributes.push(Attribute::new(
+            PAYMENT_VOLUME_CAP_TRIPPED_KEY,
+            &payment.denom,
+        ));
+    }
+    for installment_index in
+        previously_satisfied_installments..scope_attribute.installments_satisfied()
+    {
+        attributes.push(Attribute::new(
+            INSTALLMENT_PAID_KEY,
+            installment_index.to_string(),
+        ));
+    }
+    attributes.append(&mut get_subscriber_attributes(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+    )?);
+    Ok(AppliedPayment {
+        messages,
+        attributes,
+        remaining_owed: scope_attribute.payable_remaining_owed,
+        payee,
+    })
+}
+
+/// Determines whether or not a payment should be written to the payable's scope attribute.  Without
+/// a payment batch window configured, every payment is written.  Otherwise, the new remaining owed
+/// amount is held in a local payment batch until the window's change threshold or block interval
+/// is exceeded, or the payable has been paid off.  The batch is cleared by write_through_payable
+/// whenever a write occurs.
+fn should_write_attribute(
+    storage: &mut dyn Storage,
+    env: &Env,
+    state: &StateV2,
+    scope_attribute: &PayableScopeAttribute,
+    payment_amount: u128,
+) -> Result<bool, ContractError> {
+    let window = match &state.payment_batch_window {
+        Some(window) if !scope_attribute.payable_remaining_owed.is_zero() => window,
+        // Batching disabled or the payable is paid off - always write through
+        _ => return Ok(true),
+    };
+    let mut batch = match load_payment_batch(storage, &scope_attribute.payable_uuid)? {
+        Some(batch) => batch,
+        // The first deferred payment starts a new batch from the value currently on the attribute
+        None => PaymentBatchV1 {
+            payable_uuid: scope_attribute.payable_uuid.clone(),
+            remaining_owed: scope_attribute.payable_remaining_owed,
+            synced_remaining_owed: scope_attribute
+                .payable_remaining_owed
+                .checked_add(payment_amount.into())
+                .map_err(StdError::from)?,
+            batch_start_height: env.block.height,
+        },
+    };
+    // Accruals can raise the remaining owed amount above the value last written, so the change is
+    // measured in either direction
+    let amount_changed = batch
+        .synced_remaining_owed
+        .u128()
+        .abs_diff(scope_attribute.payable_remaining_owed.u128());
+    let threshold_exceeded = scope_attribute.payable_total_owed.is_zero()
+        || Decimal::from_ratio(amount_changed, scope_attribute.payable_total_owed.u128())
+            > window.change_threshold;
+    let interval_elapsed =
+        env.block.height.saturating_sub(batch.batch_start_height) >= window.block_interval;
+    if threshold_exceeded || interval_elapsed {
+        return Ok(true);
+    }
+    batch.remaining_owed = scope_attribute.payable_remaining_owed;
+    save_payment_batch(storage, &batch)?;
+    Ok(false)
 }
 
 #[cfg(test)]
@@ -121,21 +727,31 @@ mod tests {
     use crate::contract::query;
     use crate::core::error::ContractError;
     use crate::core::msg::QueryMsg;
-    use crate::core::state::PayableScopeAttribute;
+    use crate::core::state::{
+        load_payable_timeline, load_payment_batch, load_payment_history, InterestTermsV1,
+        LateFeeV1, PayableScopeAttribute, PayeeSplitPaymentV1, PayeeSplitV1, PaymentBatchWindowV1,
+        PaymentInstallmentV1, PaymentMemoV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
     use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
     use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
     use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
     use crate::testutil::test_utilities::{
-        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_CONTRACT_NAME,
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_CONTRACT_NAME,
         DEFAULT_INFO_NAME, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_TOTAL,
         DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
     };
     use crate::util::constants::{
-        ORACLE_ADDRESS_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY, PAYEE_KEY, PAYER_KEY,
-        PAYMENT_AMOUNT_KEY, PAYMENT_MADE_KEY, TOTAL_REMAINING_KEY,
+        ATTRIBUTE_WRITE_DEFERRED_KEY, INSTALLMENT_PAID_KEY, INTEREST_ACCRUED_KEY,
+        LATE_FEE_ACCRUED_KEY, ORACLE_ADDRESS_KEY, PAYABLE_SETTLED_KEY, PAYABLE_TYPE_KEY,
+        PAYABLE_UUID_KEY, PAYEE_KEY, PAYEE_SPLIT_AMOUNTS_KEY, PAYER_KEY, PAYMENT_AMOUNT_KEY,
+        PAYMENT_INTEREST_KEY, PAYMENT_LATE_FEE_KEY, PAYMENT_MADE_KEY, PAYMENT_MEMO_KEY,
+        PAYMENT_ON_BEHALF_OF_KEY, PAYMENT_PRINCIPAL_KEY, PAYMENT_REFUND_KEY, TOTAL_REMAINING_KEY,
     };
+    use crate::util::interest::{InterestCompounding, SECONDS_PER_YEAR};
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{from_binary, BankMsg, CosmosMsg};
+    use cosmwasm_std::{coin, from_binary, Addr, BankMsg, CosmosMsg, Decimal, Timestamp, Uint128};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{
         AttributeMsgParams, AttributeValueType, ProvenanceMsg, ProvenanceMsgParams,
@@ -158,7 +774,7 @@ mod tests {
         .unwrap();
         provenance_util.bind_captured_attribute(&mut deps);
         assert_eq!(
-            8,
+            9,
             payment_response.attributes.len(),
             "expected all attributes to be added to the response"
         );
@@ -167,6 +783,11 @@ mod tests {
             single_attribute_for_key(&payment_response, PAYMENT_MADE_KEY),
             "expected the payment made key to be added to the response",
         );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&payment_response, PAYABLE_SETTLED_KEY),
+            "expected the payable settled key to be added to the response",
+        );
         assert_eq!(
             DEFAULT_PAYABLE_TYPE,
             single_attribute_for_key(&payment_response, PAYABLE_TYPE_KEY),
@@ -471,132 +1092,547 @@ mod tests {
     }
 
     #[test]
-    fn test_execute_make_payment_missing_payable_uuid() {
+    fn test_execute_make_payment_after_expiration() {
         let mut deps = mock_dependencies(&[]);
         let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
-        // No need to do anything upfront because we're going to target a non-existent payable
-        let failure =
-            test_make_payment(&mut deps, &provenance_util, TestMakePayment::default()).unwrap_err();
-        match failure {
-            ContractError::PayableNotFound { payable_uuid } => {
-                assert_eq!(
-                    DEFAULT_PAYABLE_UUID,
-                    payable_uuid.as_str(),
-                    "the output message should reflect the invalid input uuid"
-                );
-            }
-            _ => panic!("unexpected error when invalid payable uuid provided"),
-        };
+        let expiration_time = mock_env().block.time.plus_seconds(60);
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    expiration_time: Some(expiration_time),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let mut env = mock_env();
+        env.block.time = expiration_time.plus_seconds(1);
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::PayableExpired { .. }),
+            "expected a payment after expiration to be rejected, but got: {:?}",
+            failure,
+        );
     }
 
     #[test]
-    fn test_execute_make_payment_invalid_coin_provided() {
+    fn test_execute_make_payment_in_accepted_denom() {
         let mut deps = mock_dependencies(&[]);
         let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
-        // Register the default payable
-        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
-        // Mark the oracle as approved
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    accepted_denoms: Some(vec!["uusdf".to_string()]),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
         test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
-        // Wrong coin
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender("payer-guy", 400, "uusdf"),
+        )
+        .expect("a payment in an accepted denom should succeed");
+        assert_eq!(
+            "600",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the payment should be applied to the amount owed one-to-one",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(400, "uusdf")],
+                })),
+            "the payment should be sent to the payee in the denom that it was made in",
+        );
         let failure = test_make_payment(
             &mut deps,
             &provenance_util,
-            TestMakePayment::default_with_denom("fakecoin"),
+            TestMakePayment {
+                info: mock_info(
+                    "payer-guy",
+                    &[coin(100, DEFAULT_PAYABLE_DENOM), coin(100, "uusdf")],
+                ),
+                ..Default::default()
+            },
         )
         .unwrap_err();
-        match failure {
-            ContractError::InvalidFundsProvided {
-                valid_denom,
-                invalid_denoms,
-            } => {
-                assert_eq!(
-                    DEFAULT_PAYABLE_DENOM,
-                    valid_denom.as_str(),
-                    "the default payable denom should be returned as the valid type"
-                );
-                assert_eq!(
-                    1,
-                    invalid_denoms.len(),
-                    "one invalid denomination was provided, and it should be returned"
-                );
-                let invalid_denom = invalid_denoms.first().unwrap();
-                assert_eq!(
-                    "fakecoin",
-                    invalid_denom.as_str(),
-                    "the invalid denomination provided should be returned in the error"
-                );
-            }
-            _ => panic!("unexpected error occurred when invalid coin was provided"),
-        }
+        assert!(
+            matches!(failure, ContractError::DenomMismatch { .. }),
+            "a payment that mixes accepted denoms should be rejected, but got: {:?}",
+            failure,
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender("payer-guy", 600, DEFAULT_PAYABLE_DENOM),
+        )
+        .expect("the payable denom should remain accepted");
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "payments in either denom should settle the payable",
+        );
     }
 
     #[test]
-    fn test_execute_make_payment_no_funds_provided() {
+    fn test_execute_make_payment_satisfies_installments() {
         let mut deps = mock_dependencies(&[]);
         let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
-        // Register the default payable for payment
-        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
-        // Mark the oracle as approved
-        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
-        let failure = test_make_payment(
+        let installment = |due_seconds: u64, amount: u128| PaymentInstallmentV1 {
+            due_time: Timestamp::from_seconds(due_seconds),
+            amount: Uint128::new(amount),
+        };
+        test_register_payable(
             &mut deps,
             &provenance_util,
-            TestMakePayment {
-                info: mock_info("payer-guy", &[]),
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    payment_schedule: Some(vec![
+                        installment(1_600_000_000, 300),
+                        installment(1_650_000_000, 300),
+                        installment(1_700_000_000, 400),
+                    ]),
+                    ..TestRegisterPayable::default_register_payable()
+                },
                 ..Default::default()
             },
         )
-        .unwrap_err();
-        match failure {
-            ContractError::NoFundsProvided { valid_denom } => {
-                assert_eq!(
-                    DEFAULT_PAYABLE_DENOM,
-                    valid_denom.as_str(),
-                    "the correct denomination should be reflected when no funds are provided"
-                );
-            }
-            _ => panic!("unexpected error received when no funds provided"),
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let installments_paid = |amount: u128, deps: &mut MockOwnedDeps| {
+            test_make_payment(
+                deps,
+                &provenance_util,
+                TestMakePayment::default_with_amount(amount),
+            )
+            .expect("the payment should succeed")
+            .attributes
+            .into_iter()
+            .filter(|attribute| attribute.key == INSTALLMENT_PAID_KEY)
+            .map(|attribute| attribute.value)
+            .collect::<Vec<String>>()
         };
+        assert!(
+            installments_paid(200, &mut deps).is_empty(),
+            "a payment that does not cover the first installment should not satisfy it",
+        );
+        assert_eq!(
+            vec!["0", "1"],
+            installments_paid(500, &mut deps),
+            "a payment covering multiple installments should emit each of them",
+        );
+        assert_eq!(
+            vec!["2"],
+            installments_paid(300, &mut deps),
+            "the final payment should satisfy the final installment",
+        );
     }
 
     #[test]
-    fn test_execute_make_payment_zero_coin_provided() {
+    fn test_execute_make_payment_rejects_unauthorized_payer() {
         let mut deps = mock_dependencies(&[]);
         let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
-        // Register the default payable for payment
-        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
-        // Mark the oracle as approved
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    authorized_payers: Some(vec!["counterparty".to_string()]),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
         test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
-        let failure = test_make_payment(
+        let error = test_make_payment(
             &mut deps,
             &provenance_util,
-            TestMakePayment::default_with_amount(0),
+            TestMakePayment::default_with_amount(100),
         )
         .unwrap_err();
-        match failure {
-            ContractError::NoFundsProvided { valid_denom } => {
-                assert_eq!(
-                    DEFAULT_PAYABLE_DENOM,
-                    valid_denom.as_str(),
-                    "the correct denomination should be reflected when no funds are provided"
-                );
+        match error {
+            ContractError::PayerNotAuthorized {
+                payable_uuid,
+                payer,
+            } => {
+                assert_eq!(DEFAULT_PAYABLE_UUID, payable_uuid);
+                assert_eq!(DEFAULT_INFO_NAME, payer);
             }
-            _ => panic!("unexpected error received when zero funds provided"),
+            _ => panic!("unexpected error encountered: {:?}", error),
         };
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender("counterparty", 100, DEFAULT_PAYABLE_DENOM),
+        )
+        .expect("an authorized payer should be able to make a payment");
+        assert_eq!(
+            "counterparty",
+            single_attribute_for_key(&response, PAYER_KEY),
+        );
     }
 
     #[test]
-    fn test_execute_make_payment_too_many_funds_provided() {
+    fn test_execute_make_payment_split_among_payees() {
         let mut deps = mock_dependencies(&[]);
         let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
-        // Register the default payable for payment
-        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
-        // Mark the oracle as approved
+        let payee_split = |address: &str, weight: u64| PayeeSplitV1 {
+            address: address.to_string(),
+            weight,
+        };
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    payee_splits: Some(vec![
+                        payee_split("lender-a", 1),
+                        payee_split("lender-b", 1),
+                        payee_split("lender-c", 1),
+                    ]),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
         test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
-        let failure = test_make_payment(
+        let response = test_make_payment(
             &mut deps,
             &provenance_util,
-            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL + 1),
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        let bank_sends = response
+            .messages
+            .iter()
+            .filter_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.as_str(), amount[0].amount.u128()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<(&str, u128)>>();
+        assert_eq!(
+            vec![("lender-a", 34), ("lender-b", 33), ("lender-c", 33)],
+            bank_sends,
+            "each payee should be sent its share, with the remainder going to the first payee",
+        );
+        assert_eq!(
+            "lender-a:34,lender-b:33,lender-c:33",
+            single_attribute_for_key(&response, PAYEE_SPLIT_AMOUNTS_KEY),
+        );
+        let history = load_payment_history(&deps.storage, DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            Some(vec![
+                PayeeSplitPaymentV1 {
+                    address: Addr::unchecked("lender-a"),
+                    amount: Uint128::new(34),
+                },
+                PayeeSplitPaymentV1 {
+                    address: Addr::unchecked("lender-b"),
+                    amount: Uint128::new(33),
+                },
+                PayeeSplitPaymentV1 {
+                    address: Addr::unchecked("lender-c"),
+                    amount: Uint128::new(33),
+                },
+            ]),
+            history[0].payee_splits,
+            "the split should be recorded in the payment history",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_accrues_late_fees() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    late_fee: Some(LateFeeV1 {
+                        due_time: mock_env().block.time.minus_seconds(3 * 86400 + 100),
+                        daily_rate: Decimal::permille(5),
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("the payment should succeed");
+        assert_eq!(
+            "15",
+            single_attribute_for_key(&response, LATE_FEE_ACCRUED_KEY),
+            "three full days overdue at half a percent per day should accrue 15 in late fees",
+        );
+        assert_eq!(
+            "15",
+            single_attribute_for_key(&response, PAYMENT_LATE_FEE_KEY),
+            "the accrued late fees should be paid before the principal",
+        );
+        assert_eq!(
+            "85",
+            single_attribute_for_key(&response, PAYMENT_PRINCIPAL_KEY),
+            "the rest of the payment should be applied to the principal",
+        );
+        assert_eq!(
+            "915",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the remaining owed amount should include the accrued late fees",
+        );
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(1015, scope_attribute.payable_total_owed.u128());
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("the second payment should succeed");
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attribute| attribute.key == LATE_FEE_ACCRUED_KEY),
+            "no further late fees should accrue within the same day",
+        );
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, PAYMENT_LATE_FEE_KEY),
+            "late fees that were already paid should not be charged again",
+        );
+        assert_eq!(
+            "815",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY)
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_accrues_interest() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    interest: Some(InterestTermsV1 {
+                        start_time: mock_env().block.time.minus_seconds(SECONDS_PER_YEAR),
+                        annual_rate: Decimal::percent(10),
+                        compounding: InterestCompounding::Simple,
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(150),
+        )
+        .expect("the payment should succeed");
+        assert_eq!(
+            "100",
+            single_attribute_for_key(&response, INTEREST_ACCRUED_KEY),
+            "a full year at ten percent should accrue 100 in interest",
+        );
+        assert_eq!(
+            "100",
+            single_attribute_for_key(&response, PAYMENT_INTEREST_KEY),
+            "the accrued interest should be paid before the principal",
+        );
+        assert_eq!(
+            "50",
+            single_attribute_for_key(&response, PAYMENT_PRINCIPAL_KEY),
+            "the rest of the payment should be applied to the principal",
+        );
+        assert_eq!(
+            "950",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("the second payment should succeed");
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attribute| attribute.key == INTEREST_ACCRUED_KEY),
+            "no further interest should accrue without time passing",
+        );
+        assert_eq!(
+            "100",
+            single_attribute_for_key(&response, PAYMENT_PRINCIPAL_KEY),
+            "interest that was already paid should not be charged again",
+        );
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(1100, scope_attribute.payable_total_owed.u128());
+        assert_eq!(850, scope_attribute.principal_remaining().u128());
+    }
+
+    #[test]
+    fn test_execute_make_payment_missing_payable_uuid() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // No need to do anything upfront because we're going to target a non-existent payable
+        let failure =
+            test_make_payment(&mut deps, &provenance_util, TestMakePayment::default()).unwrap_err();
+        match failure {
+            ContractError::PayableNotFound { payable_uuid } => {
+                assert_eq!(
+                    DEFAULT_PAYABLE_UUID,
+                    payable_uuid.as_str(),
+                    "the output message should reflect the invalid input uuid"
+                );
+            }
+            _ => panic!("unexpected error when invalid payable uuid provided"),
+        };
+    }
+
+    #[test]
+    fn test_execute_make_payment_invalid_coin_provided() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // Register the default payable
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Mark the oracle as approved
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        // Wrong coin
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_denom("fakecoin"),
+        )
+        .unwrap_err();
+        match failure {
+            ContractError::InvalidFundsProvided {
+                valid_denom,
+                invalid_denoms,
+            } => {
+                assert_eq!(
+                    DEFAULT_PAYABLE_DENOM,
+                    valid_denom.as_str(),
+                    "the default payable denom should be returned as the valid type"
+                );
+                assert_eq!(
+                    1,
+                    invalid_denoms.len(),
+                    "one invalid denomination was provided, and it should be returned"
+                );
+                let invalid_denom = invalid_denoms.first().unwrap();
+                assert_eq!(
+                    "fakecoin",
+                    invalid_denom.as_str(),
+                    "the invalid denomination provided should be returned in the error"
+                );
+            }
+            _ => panic!("unexpected error occurred when invalid coin was provided"),
+        }
+    }
+
+    #[test]
+    fn test_execute_make_payment_no_funds_provided() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // Register the default payable for payment
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Mark the oracle as approved
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                info: mock_info("payer-guy", &[]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        match failure {
+            ContractError::NoFundsProvided { valid_denom } => {
+                assert_eq!(
+                    DEFAULT_PAYABLE_DENOM,
+                    valid_denom.as_str(),
+                    "the correct denomination should be reflected when no funds are provided"
+                );
+            }
+            _ => panic!("unexpected error received when no funds provided"),
+        };
+    }
+
+    #[test]
+    fn test_execute_make_payment_zero_coin_provided() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // Register the default payable for payment
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Mark the oracle as approved
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(0),
+        )
+        .unwrap_err();
+        match failure {
+            ContractError::NoFundsProvided { valid_denom } => {
+                assert_eq!(
+                    DEFAULT_PAYABLE_DENOM,
+                    valid_denom.as_str(),
+                    "the correct denomination should be reflected when no funds are provided"
+                );
+            }
+            _ => panic!("unexpected error received when zero funds provided"),
+        };
+    }
+
+    #[test]
+    fn test_execute_make_payment_too_many_funds_provided() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // Register the default payable for payment
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Mark the oracle as approved
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL + 1),
         )
         .unwrap_err();
         match failure {
@@ -617,4 +1653,490 @@ mod tests {
             _ => panic!("unexpected error received when too many funds provided"),
         };
     }
+
+    #[test]
+    fn test_execute_make_payment_refunds_overpayment_when_requested() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let mut payment = TestMakePayment::default_full_sender(
+            "payer-guy",
+            DEFAULT_PAYABLE_TOTAL + 5,
+            DEFAULT_PAYABLE_DENOM,
+        );
+        payment.make_payment.refund_overpayment = true;
+        let payment_response = test_make_payment(&mut deps, &provenance_util, payment).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL.to_string(),
+            single_attribute_for_key(&payment_response, PAYMENT_AMOUNT_KEY),
+            "only the remaining owed amount should be paid",
+        );
+        assert_eq!(
+            format!("5/{}", DEFAULT_PAYABLE_DENOM),
+            single_attribute_for_key(&payment_response, PAYMENT_REFUND_KEY),
+            "the excess should be emitted as a refund",
+        );
+        let transfers = payment_response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_INFO_NAME.to_string(),
+                    vec![coin(DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_DENOM)]
+                ),
+                (
+                    "payer-guy".to_string(),
+                    vec![coin(5, DEFAULT_PAYABLE_DENOM)]
+                ),
+            ],
+            transfers,
+            "the payee should receive the remaining owed and the payer should receive the excess",
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert!(
+            attribute.payable_remaining_owed.is_zero(),
+            "the payable should be paid off",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_with_memo() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let mut payment = TestMakePayment::default_with_amount(100);
+        payment.make_payment.memo = Some("INV-1042".to_string());
+        let payment_response = test_make_payment(&mut deps, &provenance_util, payment).unwrap();
+        assert_eq!(
+            "INV-1042",
+            single_attribute_for_key(&payment_response, PAYMENT_MEMO_KEY),
+            "the memo should be emitted as an attribute",
+        );
+        let payment_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        assert!(
+            payment_response
+                .attributes
+                .iter()
+                .all(|attribute| attribute.key != PAYMENT_MEMO_KEY),
+            "no memo attribute should be emitted for a payment without a memo",
+        );
+        let timeline = load_payable_timeline(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+            .unwrap()
+            .expect("the payable's timeline should be stored");
+        assert_eq!(
+            vec![PaymentMemoV1 {
+                paid_at: mock_env().block.time,
+                memo: "INV-1042".to_string(),
+            }],
+            timeline.payment_memos,
+            "only the memo of the payment that included one should be stored",
+        );
+        assert_eq!(
+            2,
+            timeline.payment_times.len(),
+            "both payments should be recorded in the timeline",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_on_behalf_of_client() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let mut payment =
+            TestMakePayment::default_full_sender("factor", 100, DEFAULT_PAYABLE_DENOM);
+        payment.make_payment.on_behalf_of = Some("client".to_string());
+        let payment_response = test_make_payment(&mut deps, &provenance_util, payment).unwrap();
+        assert_eq!(
+            "factor",
+            single_attribute_for_key(&payment_response, PAYER_KEY),
+            "the sender should remain the payer",
+        );
+        assert_eq!(
+            "client",
+            single_attribute_for_key(&payment_response, PAYMENT_ON_BEHALF_OF_KEY),
+            "the client should be emitted as an attribute distinct from the payer",
+        );
+        let payment_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        assert!(
+            payment_response
+                .attributes
+                .iter()
+                .all(|attribute| attribute.key != PAYMENT_ON_BEHALF_OF_KEY),
+            "no client should be emitted for a payment made on the payer's own behalf",
+        );
+        let history = load_payment_history(deps.as_ref().storage, DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            vec![Some(Addr::unchecked("client")), None],
+            history
+                .into_iter()
+                .map(|record| record.on_behalf_of)
+                .collect::<Vec<_>>(),
+            "only the payment made for a client should record it in the payment history",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_below_contract_minimum() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                minimum_payment_amount: Some(Uint128::new(100)),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(99),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                failure,
+                ContractError::PaymentTooSmall {
+                    minimum_payment: 100,
+                    amount_provided: 99,
+                }
+            ),
+            "a payment below the contract minimum should be rejected, but got: {:?}",
+            failure,
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL - 1),
+        )
+        .expect("a payment meeting the minimum should be accepted");
+        provenance_util.bind_captured_attribute(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(1),
+        )
+        .expect("a payment below the minimum that settles the payable should be accepted");
+    }
+
+    #[test]
+    fn test_execute_make_payment_below_payable_minimum() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                minimum_payment_amount: Some(Uint128::new(100)),
+                ..Default::default()
+            },
+        );
+        let mut register = TestRegisterPayable::default();
+        register.register_payable.minimum_payment_amount = Some(Uint128::new(10));
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(10),
+        )
+        .expect("the payable's own minimum should take precedence over the contract minimum");
+        provenance_util.bind_captured_attribute(&mut deps);
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(9),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::PaymentTooSmall { .. }),
+            "a payment below the payable's minimum should be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_refund_rejects_paid_off_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(&mut deps, &provenance_util, TestMakePayment::default()).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let mut payment = TestMakePayment::default_with_amount(5);
+        payment.make_payment.refund_overpayment = true;
+        let failure = test_make_payment(&mut deps, &provenance_util, payment).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::PaymentTooLarge { .. }),
+            "a paid off payable should not accept a payment to refund, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_batched_until_threshold_exceeded() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(50),
+                    block_interval: 100,
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let deferred_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        assert_eq!(
+            1,
+            deferred_response.messages.len(),
+            "only the payment bank send should be emitted when the attribute write is deferred",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&deferred_response, ATTRIBUTE_WRITE_DEFERRED_KEY),
+            "the deferred attribute write should be indicated in the response",
+        );
+        assert_eq!(
+            "900",
+            single_attribute_for_key(&deferred_response, TOTAL_REMAINING_KEY),
+            "the total remaining should reflect the deferred payment",
+        );
+        let batched_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            900,
+            batched_attribute.payable_remaining_owed.u128(),
+            "queries should reflect the payments held in the batch",
+        );
+        // 600 of 1000 paid since the last write crosses the 50% threshold
+        let written_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(500),
+        )
+        .unwrap();
+        assert_eq!(
+            3,
+            written_response.messages.len(),
+            "the payment and attribute swap messages should be emitted once the threshold is exceeded",
+        );
+        assert!(
+            written_response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != ATTRIBUTE_WRITE_DEFERRED_KEY),
+            "the deferred attribute should not be emitted when the attribute is written",
+        );
+        let written_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        provenance_util.assert_attribute_matches_latest(&written_attribute);
+        assert_eq!(
+            400,
+            written_attribute.payable_remaining_owed.u128(),
+            "the written attribute should include all batched payments",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_batched_until_interval_elapsed() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(50),
+                    block_interval: 10,
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let deferred_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        assert_eq!(
+            1,
+            deferred_response.messages.len(),
+            "the first small payment should be deferred",
+        );
+        let mut env = mock_env();
+        env.block.height += 10;
+        let written_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env,
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            3,
+            written_response.messages.len(),
+            "the attribute should be written once the block interval has elapsed",
+        );
+        let written_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            800,
+            written_attribute.payable_remaining_owed.u128(),
+            "the written attribute should include both payments",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_batched_then_late_fees_accrue() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(50),
+                    block_interval: 100,
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    late_fee: Some(LateFeeV1 {
+                        due_time: mock_env().block.time.plus_seconds(86400),
+                        daily_rate: Decimal::percent(5),
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let deferred_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(10),
+        )
+        .unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&deferred_response, ATTRIBUTE_WRITE_DEFERRED_KEY),
+            "the payment before the due time should be deferred into a batch",
+        );
+        // Three days past the due time at the same block height, so only the accrual can trigger
+        // the write
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(4 * 86400 + 100);
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env,
+                ..TestMakePayment::default_with_amount(10)
+            },
+        )
+        .expect("a payment after late fees accrue on top of a pending batch should succeed");
+        assert_eq!(
+            "148",
+            single_attribute_for_key(&response, LATE_FEE_ACCRUED_KEY),
+            "three full days overdue at five percent per day should accrue on the batched balance",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != ATTRIBUTE_WRITE_DEFERRED_KEY),
+            "the accrued late fees should be written through",
+        );
+        assert!(
+            load_payment_batch(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the write should clear the payment batch",
+        );
+        let written_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        provenance_util.assert_attribute_matches_latest(&written_attribute);
+        assert_eq!(
+            1128,
+            written_attribute.payable_remaining_owed.u128(),
+            "the written attribute should include both payments and the accrued late fees",
+        );
+    }
+
+    #[test]
+    fn test_execute_make_payment_batched_written_when_paid_off() {
+        let mut deps = mock_dependencies(&[]);
+        // A 100% threshold can never be exceeded, so only the payoff can trigger the write
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(100),
+                    block_interval: 1000,
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        let payoff_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL - 100),
+        )
+        .unwrap();
+        assert_eq!(
+            3,
+            payoff_response.messages.len(),
+            "the attribute should always be written when the payable is paid off",
+        );
+        let written_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        provenance_util.assert_attribute_matches_latest(&written_attribute);
+        assert_eq!(
+            0,
+            written_attribute.payable_remaining_owed.u128(),
+            "the written attribute should show the payable as paid off",
+        );
+    }
 }
@@ -1,3 +1,46 @@
+pub mod cancel_payable;
+pub mod change_oracle;
+pub mod cleanup_orphaned_attributes;
+pub mod decline_payable;
+pub mod denom_display_metadata;
+#[cfg(feature = "escrow")]
+pub mod escrow_payment;
+pub mod expire_payable;
+pub mod fee_holiday;
+pub mod flag_erroneous_payment;
+pub mod make_batch_payment;
 pub mod make_payment;
 pub mod oracle_approval;
+pub mod oracle_delegate;
+pub mod patch_payable_meta;
+pub mod payable_dispute;
+pub mod payable_freeze;
+pub mod payable_type_config;
+pub mod payable_type_freeze;
+pub mod payable_type_supply_check;
+#[cfg(feature = "allowance")]
+pub mod payment_allowance;
+pub mod payment_volume_cap;
+#[cfg(feature = "escrow")]
+pub mod pending_cancellation;
+pub mod propose_payment;
+pub mod prune_payable;
+pub mod quarantine;
+pub mod re_register_payable;
+pub mod reassign_oracle_bulk;
+pub mod record_external_settlement;
+pub mod referral;
 pub mod register_payable;
+pub mod register_payables;
+pub mod restructure_payable;
+pub mod revert_stale_approval;
+pub mod risk_bands;
+pub mod set_paused;
+#[cfg(feature = "escrow")]
+pub mod settle_netting;
+pub mod stage_migration_params;
+pub mod subscribe;
+pub mod supported_payable_type;
+pub mod transfer_payable;
+pub mod update_payable_total;
+pub mod write_off_payable;
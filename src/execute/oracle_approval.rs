@@ -1,50 +1,99 @@
 use crate::core::error::ContractError;
-use crate::core::state::config_read_v2;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, is_oracle_delegate, is_payable_type_frozen, load_held_oracle_fee,
+    load_oracle_gas_rebate, load_payable_priority_fee, load_payable_timeline,
+    remove_held_oracle_fee, remove_oracle_gas_rebate, save_oracle_approval_context,
+    update_payable_timeline, OracleApprovalContextV1, PayableScopeAttribute, StateV2,
+};
 use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
 use crate::util::constants::{
-    ORACLE_ADDRESS_KEY, ORACLE_APPROVED_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY,
+    APPROVAL_LAPSES_AT_KEY, APPROVAL_VALID_UNTIL_KEY, ORACLE_APPROVAL_RENEWED_KEY,
+    ORACLE_APPROVED_KEY, ORACLE_DELEGATE_KEY, ORACLE_EXTERNAL_REFERENCE_KEY, ORACLE_RISK_SCORE_KEY,
+    REFUND_AMOUNT_KEY,
 };
-use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
-use cosmwasm_std::{coin, BankMsg, CosmosMsg, DepsMut, MessageInfo, Response};
+use crate::util::fees::calculate_effective_fees;
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, Attribute, CosmosMsg, DepsMut, Env, MessageInfo, Response, Storage};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::ops::Mul;
 
 /// Contains all relevant fields required in order for an oracle address to mark a payable as
 /// approved and ready for payment.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct OracleApprovalV1 {
     pub payable_uuid: String,
+    pub external_reference_id: Option<String>,
+    pub risk_score: Option<u32>,
+    pub approval_valid_for: Option<u64>,
+}
+
+/// Contains all relevant fields required in order for an oracle address to renew its approval of a
+/// payable once the approval has lapsed, or before it does.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RenewOracleApprovalV1 {
+    pub payable_uuid: String,
+    pub external_reference_id: Option<String>,
+    pub risk_score: Option<u32>,
+    pub approval_valid_for: Option<u64>,
 }
 
 /// Parent function path for the contract to mark an oracle approval.  Ensures that the
 /// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
 pub fn oracle_approval(
     deps: DepsMut<ProvenanceQuery>,
+    env: Env,
     info: MessageInfo,
     oracle_approval: OracleApprovalV1,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    oracle_approval_with_util(deps, &ProvenanceUtilImpl, info, oracle_approval)
+    oracle_approval_with_util(deps, &ProvenanceUtilImpl, env, info, oracle_approval)
 }
 
 /// Stamps an oracle approval on the target payable with the following steps:
-/// - Verifies that no funds were send (oracle approvals are free).
+/// - Ensures that the contract is not paused.
+/// - Verifies that no funds were send (oracle approvals are free), unless the funds fall within the
+///   contract's oracle funds tolerance, in which case they are refunded to the sender.
 /// - Ensures that the oracle has not yet approved of this transaction.
 /// - Ensures that the payable targeted has been registered.
-/// - Ensures that the sender address is the oracle listed on the payable's scope attribute.
-/// - Sends the oracle fee to the oracle for performing its stamp.
+/// - Ensures that the sender address is the oracle listed on the payable's scope attribute, or a
+///   delegate of that oracle.
+/// - Ensures that the payable has not expired or been declined by its payee.
+/// - Sends the oracle fee, plus any priority fee and oracle gas rebate paid at registration, to the
+///   oracle for performing its stamp.  The gas rebate is then cleared from local storage.
+/// - Records the approving oracle, block height and time, optional external reference id and risk
+///   score, and submitting delegate (if any) in local storage and on the attribute.  If the
+///   contract has an approval valid duration, the time at which the approval lapses is emitted.
+///   The risk score selects the risk band that the payable's payments are keyed off.  If the
+///   oracle provides a validity window, the time at which the approval goes stale without a
+///   payment is emitted.
+/// - Pays nothing to the oracle when re-validating a payable whose stale approval was reverted, as
+///   its fees were paid out on its original approval.
 /// - Updates the attribute on the scope to indicate that the oracle approved successfully.
+/// - Records the approval time in the payable's timeline.
 pub fn oracle_approval_with_util<T: ProvenanceUtil>(
-    deps: DepsMut<ProvenanceQuery>,
+    mut deps: DepsMut<ProvenanceQuery>,
     provenance_util: &T,
+    env: Env,
     info: MessageInfo,
     oracle_approval: OracleApprovalV1,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
-    // Oracle approval should not require any funds
+    let mut attributes: Vec<Attribute> = vec![];
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    // Oracle approval should not require any funds.  If the chain forces fee coins along with the
+    // message, they are tolerated and refunded as long as they fit the configured tolerance
     if !info.funds.is_empty() {
-        return Err(ContractError::FundsPresent);
+        match &state.oracle_funds_tolerance {
+            Some(tolerance) if tolerance.allows(&info.funds) => {
+                let refund_amount = Money::zero(&tolerance.denom).checked_add_coins(&info.funds)?;
+                messages.push(refund_amount.send_to(&info.sender));
+                attributes.push(Attribute::new(REFUND_AMOUNT_KEY, refund_amount.to_string()));
+            }
+            _ => return Err(ContractError::FundsPresent),
+        }
     }
     let mut scope_attribute =
         match query_payable_attribute_by_uuid(&deps.as_ref(), &oracle_approval.payable_uuid) {
@@ -64,36 +113,249 @@ pub fn oracle_approval_with_util<T: ProvenanceUtil>(
                 .to_result();
             }
         };
-    // Only the designated oracle can mark an approval on a denomination
-    if info.sender != scope_attribute.oracle_address {
-        return Err(ContractError::Unauthorized);
+    let delegate_address = get_approving_delegate(deps.storage, &scope_attribute, &info.sender)?;
+    scope_attribute.check_not_expired(env.block.time)?;
+    if scope_attribute.is_declined {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "payables declined by their payee cannot be approved".into(),
+        }
+        .to_result();
+    }
+    if is_payable_type_frozen(deps.storage, &scope_attribute.payable_type)? {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "payables of a frozen payable type cannot be approved".into(),
+        }
+        .to_result();
     }
-    let state = config_read_v2(deps.storage).load()?;
     // The oracle is paid X on each approval, where X is the remaining amount after the fee is taken
-    // from the onboarding funds.
-    let oracle_withdraw_amount =
-        state.onboarding_cost - state.onboarding_cost.mul(state.fee_percent);
+    // from the onboarding funds, plus the priority fee and gas rebate if the registrant paid them.
+    // The amount retained at registration is paid, which may differ from the configured amount if
+    // the payable was registered during a fee holiday
+    let is_re_validation = load_payable_timeline(deps.storage, &scope_attribute.payable_uuid)?
+        .is_some_and(|timeline| timeline.approved_at.is_some());
+    let mut oracle_withdraw_amount = if is_re_validation {
+        Money::zero(&state.onboarding_denom)
+    } else {
+        match load_held_oracle_fee(deps.storage, &scope_attribute.payable_uuid)? {
+            Some(held_fee) => Money::new(held_fee.amount, &held_fee.denom),
+            None => calculate_effective_fees(&state).oracle_amount,
+        }
+    };
+    if let Some(priority_fee) =
+        load_payable_priority_fee(deps.storage, &scope_attribute.payable_uuid)?
+            .filter(|_| !is_re_validation)
+    {
+        oracle_withdraw_amount = oracle_withdraw_amount
+            .checked_add(&Money::new(priority_fee.amount, &priority_fee.denom))?;
+    }
+    if let Some(rebate) = load_oracle_gas_rebate(deps.storage, &scope_attribute.payable_uuid)? {
+        oracle_withdraw_amount =
+            oracle_withdraw_amount.checked_add(&Money::new(rebate.amount, &rebate.denom))?;
+    }
+    remove_oracle_gas_rebate(deps.storage, &scope_attribute.payable_uuid);
+    remove_held_oracle_fee(deps.storage, &scope_attribute.payable_uuid);
     // Only create a payment to the oracle if there were funds stored in the first place
-    if oracle_withdraw_amount.u128() > 0 {
-        messages.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: scope_attribute.oracle_address.clone().into(),
-            amount: vec![coin(oracle_withdraw_amount.u128(), state.onboarding_denom)],
-        }));
+    if !oracle_withdraw_amount.is_zero() {
+        messages.push(oracle_withdraw_amount.send_to(&scope_attribute.oracle_address));
     }
     scope_attribute.oracle_approved = true;
+    attributes.append(&mut record_approval_context(
+        deps.storage,
+        &env,
+        &state,
+        &mut scope_attribute,
+        ApprovalTerms {
+            external_reference_id: oracle_approval.external_reference_id,
+            risk_score: oracle_approval.risk_score,
+            approval_valid_for: oracle_approval.approval_valid_for,
+            delegate_address,
+        },
+    )?);
+    update_payable_timeline(deps.storage, &scope_attribute.payable_uuid, |timeline| {
+        timeline.approved_at = Some(env.block.time)
+    })?;
     // Add messages that will remove the current attribute and replace it with the attribute with an
     // oracle approval on it
-    messages.append(
-        &mut provenance_util
-            .upsert_attribute_to_scope(&scope_attribute, &state.contract_name)?
-            .to_vec(),
-    );
-    Ok(Response::new()
-        .add_messages(messages)
-        .add_attribute(ORACLE_APPROVED_KEY, &scope_attribute.payable_uuid)
-        .add_attribute(PAYABLE_TYPE_KEY, &scope_attribute.payable_type)
-        .add_attribute(PAYABLE_UUID_KEY, &scope_attribute.payable_uuid)
-        .add_attribute(ORACLE_ADDRESS_KEY, scope_attribute.oracle_address.as_str()))
+    messages.append(&mut write_through_payable(
+        deps.branch(),
+        provenance_util,
+        &scope_attribute,
+        &state.contract_name,
+    )?);
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(messages)
+        .attributes(attributes)
+        .attribute(ORACLE_APPROVED_KEY, &scope_attribute.payable_uuid)
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+/// Parent function path for the contract to renew an oracle approval.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn renew_oracle_approval(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    renewal: RenewOracleApprovalV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    renew_oracle_approval_with_util(deps, &ProvenanceUtilImpl, env, info, renewal)
+}
+
+/// Renews the oracle approval on an approved payable with the following steps:
+/// - Ensures that the contract is not paused.
+/// - Verifies that no funds were sent.  The oracle was paid for its initial approval, so renewals
+///   are free.
+/// - Ensures that the payable targeted has been registered and approved.
+/// - Ensures that the sender address is the oracle listed on the payable's scope attribute, or a
+///   delegate of that oracle.
+/// - Ensures that the payable has not expired, been declined by its payee, or been written off.
+/// - Replaces the approval context in local storage and on the attribute with the renewal's, which
+///   restarts the contract's approval valid duration, and the renewal's validity window if it
+///   provides one, from the current block time.
+pub fn renew_oracle_approval_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    renewal: RenewOracleApprovalV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &renewal.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: renewal.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    let delegate_address = get_approving_delegate(deps.storage, &scope_attribute, &info.sender)?;
+    scope_attribute.check_not_expired(env.block.time)?;
+    let invalid_reason = if !scope_attribute.oracle_approved {
+        Some("only approved payables can have their approval renewed")
+    } else if scope_attribute.is_declined {
+        Some("payables declined by their payee cannot be approved")
+    } else if scope_attribute.is_written_off {
+        Some("written off payables cannot be approved")
+    } else if is_payable_type_frozen(deps.storage, &scope_attribute.payable_type)? {
+        Some("payables of a frozen payable type cannot be approved")
+    } else {
+        None
+    };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: invalid_reason.into(),
+        }
+        .to_result();
+    }
+    let attributes = record_approval_context(
+        deps.storage,
+        &env,
+        &state,
+        &mut scope_attribute,
+        ApprovalTerms {
+            external_reference_id: renewal.external_reference_id,
+            risk_score: renewal.risk_score,
+            approval_valid_for: renewal.approval_valid_for,
+            delegate_address,
+        },
+    )?;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attributes(attributes)
+        .attribute(ORACLE_APPROVAL_RENEWED_KEY, &scope_attribute.payable_uuid)
+        .build())
+}
+
+/// Only the designated oracle, or a delegate that it has granted authority, can mark an approval on
+/// a payable.  Produces the delegate when one is the sender.
+fn get_approving_delegate(
+    storage: &dyn Storage,
+    scope_attribute: &PayableScopeAttribute,
+    sender: &Addr,
+) -> Result<Option<Addr>, ContractError> {
+    if *sender == scope_attribute.oracle_address {
+        Ok(None)
+    } else if is_oracle_delegate(storage, &scope_attribute.oracle_address, sender) {
+        Ok(Some(sender.clone()))
+    } else {
+        Err(ContractError::Unauthorized)
+    }
+}
+
+/// The terms that an oracle, or its delegate, provides with an approval or a renewal.
+struct ApprovalTerms {
+    external_reference_id: Option<String>,
+    risk_score: Option<u32>,
+    approval_valid_for: Option<u64>,
+    // The delegate that made the approval on the oracle's behalf, if any
+    delegate_address: Option<Addr>,
+}
+
+/// Stores the context of an approval or renewal in local storage and on the attribute, producing
+/// the attributes that describe it.
+fn record_approval_context(
+    storage: &mut dyn Storage,
+    env: &Env,
+    state: &StateV2,
+    scope_attribute: &mut PayableScopeAttribute,
+    terms: ApprovalTerms,
+) -> Result<Vec<Attribute>, ContractError> {
+    let mut attributes: Vec<Attribute> = vec![];
+    let approval_context = OracleApprovalContextV1 {
+        oracle_address: scope_attribute.oracle_address.clone(),
+        block_height: env.block.height,
+        external_reference_id: terms.external_reference_id,
+        delegate_address: terms.delegate_address,
+        approved_at: Some(env.block.time),
+        risk_score: terms.risk_score,
+        approval_valid_for: terms.approval_valid_for,
+    };
+    save_oracle_approval_context(storage, &scope_attribute.payable_uuid, &approval_context)?;
+    if let Some(external_reference_id) = &approval_context.external_reference_id {
+        attributes.push(Attribute::new(
+            ORACLE_EXTERNAL_REFERENCE_KEY,
+            external_reference_id,
+        ));
+    }
+    if let Some(delegate_address) = &approval_context.delegate_address {
+        attributes.push(Attribute::new(ORACLE_DELEGATE_KEY, delegate_address));
+    }
+    if let Some(risk_score) = approval_context.risk_score {
+        attributes.push(Attribute::new(
+            ORACLE_RISK_SCORE_KEY,
+            risk_score.to_string(),
+        ));
+    }
+    scope_attribute.oracle_approval_context = Some(approval_context);
+    if let Some(lapses_at) = scope_attribute.approval_lapses_at(state.approval_valid_duration) {
+        attributes.push(Attribute::new(
+            APPROVAL_LAPSES_AT_KEY,
+            lapses_at.seconds().to_string(),
+        ));
+    }
+    if let Some(valid_until) = scope_attribute.approval_valid_until() {
+        attributes.push(Attribute::new(
+            APPROVAL_VALID_UNTIL_KEY,
+            valid_until.seconds().to_string(),
+        ));
+    }
+    Ok(attributes)
 }
 
 #[cfg(test)]
@@ -101,25 +363,39 @@ mod tests {
     use crate::contract::query;
     use crate::core::error::ContractError;
     use crate::core::msg::QueryMsg;
-    use crate::core::state::PayableScopeAttribute;
-    use crate::execute::oracle_approval::OracleApprovalV1;
+    use crate::core::state::{
+        config_v2, load_oracle_approval_context, load_oracle_gas_rebate, FundsToleranceV1,
+        OracleApprovalContextV1, PayableScopeAttribute,
+    };
+    use crate::execute::oracle_approval::{
+        renew_oracle_approval_with_util, OracleApprovalV1, RenewOracleApprovalV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
     use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
     use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
     use crate::testutil::test_utilities::{
-        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_CONTRACT_NAME,
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_CONTRACT_NAME,
         DEFAULT_FEE_COLLECTION_ADDRESS, DEFAULT_ONBOARDING_DENOM, DEFAULT_ORACLE_ADDRESS,
         DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
     };
     use crate::util::constants::{
-        ORACLE_ADDRESS_KEY, ORACLE_APPROVED_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY,
+        APPROVAL_LAPSES_AT_KEY, ORACLE_ADDRESS_KEY, ORACLE_APPROVAL_RENEWED_KEY,
+        ORACLE_APPROVED_KEY, ORACLE_EXTERNAL_REFERENCE_KEY, ORACLE_RISK_SCORE_KEY,
+        PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY, REFUND_AMOUNT_KEY,
     };
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{coin, from_binary, BankMsg, CosmosMsg, Decimal};
+    use cosmwasm_std::{
+        coin, from_binary, Addr, BankMsg, CosmosMsg, Decimal, Env, Response, StdResult, Uint128,
+    };
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{
         AttributeMsgParams, AttributeValueType, ProvenanceMsg, ProvenanceMsgParams,
     };
 
+    const APPROVAL_VALID_DURATION: u64 = 86_400;
+
     #[test]
     fn test_execute_oracle_approval_success() {
         let mut deps = mock_dependencies(&[]);
@@ -336,6 +612,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_execute_oracle_approval_records_approval_context() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let mut approval = TestOracleApproval::default();
+        approval.oracle_approval.external_reference_id = Some("validation-job-1".to_string());
+        approval.oracle_approval.risk_score = Some(250);
+        let response = test_oracle_approval(&mut deps, &provenance_util, approval).unwrap();
+        assert_eq!(
+            "validation-job-1",
+            single_attribute_for_key(&response, ORACLE_EXTERNAL_REFERENCE_KEY),
+            "the external reference id should be emitted as an attribute",
+        );
+        assert_eq!(
+            "250",
+            single_attribute_for_key(&response, ORACLE_RISK_SCORE_KEY),
+            "the risk score should be emitted as an attribute",
+        );
+        let expected_context = OracleApprovalContextV1 {
+            oracle_address: Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+            block_height: mock_env().block.height,
+            external_reference_id: Some("validation-job-1".to_string()),
+            delegate_address: None,
+            approved_at: Some(mock_env().block.time),
+            risk_score: Some(250),
+            approval_valid_for: None,
+        };
+        assert_eq!(
+            Some(expected_context.clone()),
+            load_oracle_approval_context(deps.as_ref().storage, DEFAULT_PAYABLE_UUID).unwrap(),
+            "the approval context should be stored for the payable",
+        );
+        let scope_attribute = from_binary::<PayableScopeAttribute>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayableByUuid {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(expected_context),
+            scope_attribute.oracle_approval_context,
+            "the approval context should be written to the scope attribute",
+        );
+    }
+
+    #[test]
+    fn test_execute_oracle_approval_pays_priority_fee_to_oracle() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let mut register = TestRegisterPayable::default_with_amount(150);
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        let approval_response =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap();
+        let oracle_payments = approval_response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(
+                DEFAULT_ORACLE_ADDRESS.to_string(),
+                vec![coin(75, DEFAULT_ONBOARDING_DENOM)]
+            )],
+            oracle_payments,
+            "the oracle should receive its 25 fee portion plus the 50 priority fee",
+        );
+    }
+
+    #[test]
+    fn test_execute_oracle_approval_pays_priority_fee_in_registration_denom() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let mut register = TestRegisterPayable::default_with_amount(150);
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        // Simulate a migration changing the onboarding denom after the fee was paid
+        config_v2(&mut deps.storage)
+            .update(|mut state| -> StdResult<_> {
+                state.onboarding_denom = "newdenom".to_string();
+                Ok(state)
+            })
+            .unwrap();
+        let approval_response =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap();
+        let oracle_payments = approval_response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(
+                DEFAULT_ORACLE_ADDRESS.to_string(),
+                vec![coin(75, DEFAULT_ONBOARDING_DENOM)]
+            )],
+            oracle_payments,
+            "the priority fee should be paid out in the denom it was received in",
+        );
+    }
+
+    #[test]
+    fn test_execute_oracle_approval_pays_gas_rebate_to_oracle() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let mut register = TestRegisterPayable::default_with_amount(160);
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        register.register_payable.oracle_gas_rebate = Some(Uint128::new(10));
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        let approval_response =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap();
+        let oracle_payments = approval_response
+            .messages
+            .into_iter()
+            .filter_map(|msg| match msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(
+                DEFAULT_ORACLE_ADDRESS.to_string(),
+                vec![coin(85, DEFAULT_ONBOARDING_DENOM)]
+            )],
+            oracle_payments,
+            "the oracle should receive its 25 fee portion, 50 priority fee, and 10 gas rebate",
+        );
+        assert!(
+            load_oracle_gas_rebate(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the gas rebate should be cleared from the ledger once it is paid out",
+        );
+    }
+
     #[test]
     fn test_execute_oracle_approval_fails_for_included_funds() {
         let mut deps = mock_dependencies(&[]);
@@ -369,6 +794,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_execute_oracle_approval_refunds_tolerated_funds() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                fee_percent: Decimal::percent(100),
+                oracle_funds_tolerance: Some(FundsToleranceV1 {
+                    denom: DEFAULT_ONBOARDING_DENOM.to_string(),
+                    max_amount: Uint128::new(10),
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response = test_oracle_approval(
+            &mut deps,
+            &provenance_util,
+            TestOracleApproval {
+                info: mock_info(
+                    DEFAULT_ORACLE_ADDRESS,
+                    &[coin(10, DEFAULT_ONBOARDING_DENOM.to_string())],
+                ),
+                ..Default::default()
+            },
+        )
+        .expect("funds within the tolerance should not cause the approval to fail");
+        assert_eq!(
+            "10/nhash",
+            single_attribute_for_key(&response, REFUND_AMOUNT_KEY),
+            "the refunded amount should be emitted as an attribute",
+        );
+        let refund = response
+            .messages
+            .iter()
+            .find_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    Some((to_address.clone(), amount.clone()))
+                }
+                _ => None,
+            })
+            .expect("a refund bank message should be included");
+        assert_eq!(
+            DEFAULT_ORACLE_ADDRESS, refund.0,
+            "the refund should be sent to the oracle",
+        );
+        assert_eq!(
+            vec![coin(10, DEFAULT_ONBOARDING_DENOM)],
+            refund.1,
+            "the refund should contain all tolerated funds",
+        );
+    }
+
+    #[test]
+    fn test_execute_oracle_approval_fails_for_funds_outside_tolerance() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                oracle_funds_tolerance: Some(FundsToleranceV1 {
+                    denom: DEFAULT_ONBOARDING_DENOM.to_string(),
+                    max_amount: Uint128::new(10),
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        for funds in [
+            vec![coin(11, DEFAULT_ONBOARDING_DENOM)],
+            vec![coin(1, "otherdenom")],
+        ] {
+            let error = test_oracle_approval(
+                &mut deps,
+                &provenance_util,
+                TestOracleApproval {
+                    info: mock_info(DEFAULT_ORACLE_ADDRESS, &funds),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::FundsPresent),
+                "funds outside of the tolerance should be rejected: {:?}",
+                funds,
+            );
+        }
+    }
+
     #[test]
     fn test_execute_oracle_approval_fails_for_invalid_sender_address() {
         let mut deps = mock_dependencies(&[]);
@@ -391,6 +904,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_execute_oracle_approval_fails_after_expiration() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let expiration_time = mock_env().block.time.plus_seconds(60);
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    expiration_time: Some(expiration_time),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.time = expiration_time.plus_seconds(1);
+        let failure = test_oracle_approval(
+            &mut deps,
+            &provenance_util,
+            TestOracleApproval {
+                env,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::PayableExpired { .. }),
+            "expected an approval after expiration to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
     #[test]
     fn test_execute_oracle_approval_fails_for_duplicate_execution() {
         let mut deps = mock_dependencies(&[]);
@@ -426,6 +974,9 @@ mod tests {
             TestOracleApproval {
                 oracle_approval: OracleApprovalV1 {
                     payable_uuid: "09798cd6-83ad-11ec-b485-eff659cf8387".to_string(),
+                    external_reference_id: None,
+                    risk_score: None,
+                    approval_valid_for: None,
                 },
                 ..Default::default()
             },
@@ -442,4 +993,123 @@ mod tests {
             _ => panic!("unexpected error occurred during execution"),
         }
     }
+
+    #[test]
+    fn test_lapsed_oracle_approval_blocks_payments_until_renewed() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                approval_valid_duration: Some(APPROVAL_VALID_DURATION),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = renew(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_ORACLE_ADDRESS,
+            mock_env(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "an unapproved payable should not have its approval renewed, but got: {:?}",
+            error,
+        );
+        let approval_response =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap();
+        let lapses_at = mock_env().block.time.plus_seconds(APPROVAL_VALID_DURATION);
+        assert_eq!(
+            lapses_at.seconds().to_string(),
+            single_attribute_for_key(&approval_response, APPROVAL_LAPSES_AT_KEY),
+        );
+        let mut lapsed_env = mock_env();
+        lapsed_env.block.time = lapses_at;
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: lapsed_env.clone(),
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotReadyForPayment { .. }),
+            "a payable with a lapsed approval should not accept payments, but got: {:?}",
+            error,
+        );
+        let error = renew(
+            &mut deps,
+            &provenance_util,
+            "some-random-guy",
+            lapsed_env.clone(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the oracle should be able to renew its approval, but got: {:?}",
+            error,
+        );
+        let renewal_response = renew(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_ORACLE_ADDRESS,
+            lapsed_env.clone(),
+        )
+        .expect("the oracle should be able to renew a lapsed approval");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&renewal_response, ORACLE_APPROVAL_RENEWED_KEY),
+        );
+        assert_eq!(
+            lapses_at
+                .plus_seconds(APPROVAL_VALID_DURATION)
+                .seconds()
+                .to_string(),
+            single_attribute_for_key(&renewal_response, APPROVAL_LAPSES_AT_KEY),
+        );
+        assert_eq!(
+            Some(lapses_at),
+            load_oracle_approval_context(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .and_then(|context| context.approved_at),
+            "the renewal should replace the approval time in local storage",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: lapsed_env,
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .expect("payments should resume once the approval is renewed");
+    }
+
+    fn renew(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        env: Env,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = renew_oracle_approval_with_util(
+            deps.as_mut(),
+            provenance_util,
+            env,
+            mock_info(sender, &[]),
+            RenewOracleApprovalV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                external_reference_id: None,
+                risk_score: None,
+                approval_valid_for: None,
+            },
+        );
+        if response.is_ok() {
+            provenance_util.bind_captured_attribute(deps);
+        }
+        response
+    }
 }
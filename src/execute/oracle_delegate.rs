@@ -0,0 +1,257 @@
+use crate::core::error::ContractError;
+use crate::core::state::{add_oracle_delegate, is_oracle_delegate, remove_oracle_delegate};
+use crate::util::constants::{
+    ORACLE_ADDRESS_KEY, ORACLE_DELEGATE_ADDED_KEY, ORACLE_DELEGATE_REMOVED_KEY,
+};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the address that an oracle would like to grant approval authority for its payables.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddOracleDelegateV1 {
+    pub delegate: String,
+}
+
+/// Contains the address that an oracle would like to revoke approval authority from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoveOracleDelegateV1 {
+    pub delegate: String,
+}
+
+/// Grants a delegate authority to approve the sender's payables with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the delegate is a valid address other than the sender.
+/// - Stores the delegation in local storage, keyed on the sender and the delegate.  Any address can
+///   act as an oracle, so delegations are only ever consulted for payables that name the sender as
+///   their oracle.
+pub fn add_delegate(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    add: AddOracleDelegateV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let delegate = deps.api.addr_validate(&add.delegate)?;
+    if delegate == info.sender {
+        return ContractError::invalid_fields(vec!["delegate"]).to_result();
+    }
+    add_oracle_delegate(deps.storage, &info.sender, &delegate)?;
+    Ok(ResponseBuilder::new()
+        .attribute(ORACLE_DELEGATE_ADDED_KEY, delegate.as_str())
+        .attribute(ORACLE_ADDRESS_KEY, info.sender.as_str())
+        .build())
+}
+
+/// Revokes a delegate's authority to approve the sender's payables with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the delegate was previously granted authority by the sender.
+/// - Removes the delegation from local storage.
+pub fn remove_delegate(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    remove: RemoveOracleDelegateV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let delegate = deps.api.addr_validate(&remove.delegate)?;
+    if !is_oracle_delegate(deps.storage, &info.sender, &delegate) {
+        return ContractError::DelegateNotFound {
+            oracle_address: info.sender.to_string(),
+            delegate: delegate.to_string(),
+        }
+        .to_result();
+    }
+    remove_oracle_delegate(deps.storage, &info.sender, &delegate);
+    Ok(ResponseBuilder::new()
+        .attribute(ORACLE_DELEGATE_REMOVED_KEY, delegate.as_str())
+        .attribute(ORACLE_ADDRESS_KEY, info.sender.as_str())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{is_oracle_delegate, load_oracle_approval_context};
+    use crate::execute::oracle_delegate::{
+        add_delegate, remove_delegate, AddOracleDelegateV1, RemoveOracleDelegateV1,
+    };
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_ORACLE_ADDRESS,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        ORACLE_ADDRESS_KEY, ORACLE_DELEGATE_ADDED_KEY, ORACLE_DELEGATE_KEY,
+        ORACLE_DELEGATE_REMOVED_KEY,
+    };
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coin, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    const DELEGATE_ADDRESS: &str = "hot-key";
+
+    #[test]
+    fn test_add_and_remove_delegate() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = add_delegate(
+            deps.as_mut(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            add_default(),
+        )
+        .unwrap();
+        assert_eq!(
+            DELEGATE_ADDRESS,
+            single_attribute_for_key(&response, ORACLE_DELEGATE_ADDED_KEY),
+            "the delegate should be emitted as an attribute",
+        );
+        assert_eq!(
+            DEFAULT_ORACLE_ADDRESS,
+            single_attribute_for_key(&response, ORACLE_ADDRESS_KEY),
+            "the delegating oracle should be emitted as an attribute",
+        );
+        assert!(
+            is_oracle_delegate(
+                deps.as_ref().storage,
+                &Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+                &Addr::unchecked(DELEGATE_ADDRESS),
+            ),
+            "the delegation should be stored",
+        );
+        let response = remove_delegate(
+            deps.as_mut(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            remove_default(),
+        )
+        .unwrap();
+        assert_eq!(
+            DELEGATE_ADDRESS,
+            single_attribute_for_key(&response, ORACLE_DELEGATE_REMOVED_KEY),
+            "the removed delegate should be emitted as an attribute",
+        );
+        assert!(
+            !is_oracle_delegate(
+                deps.as_ref().storage,
+                &Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+                &Addr::unchecked(DELEGATE_ADDRESS),
+            ),
+            "the delegation should be removed",
+        );
+    }
+
+    #[test]
+    fn test_add_delegate_rejects_self_and_funds() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = add_delegate(
+            deps.as_mut(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            AddOracleDelegateV1 {
+                delegate: DEFAULT_ORACLE_ADDRESS.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "an oracle should not be able to delegate to itself, but got: {:?}",
+            error,
+        );
+        let error = add_delegate(
+            deps.as_mut(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[coin(10, "nhash")]),
+            add_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_remove_delegate_not_found() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        // Delegations are scoped to the oracle that granted them
+        add_delegate(
+            deps.as_mut(),
+            mock_info("another-oracle", &[]),
+            add_default(),
+        )
+        .unwrap();
+        let error = remove_delegate(
+            deps.as_mut(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            remove_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::DelegateNotFound { .. }),
+            "removing a delegate that was never added should fail, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_delegate_can_approve_for_oracle() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let delegate_approval = || TestOracleApproval {
+            info: mock_info(DELEGATE_ADDRESS, &[]),
+            ..Default::default()
+        };
+        let error =
+            test_oracle_approval(&mut deps, &provenance_util, delegate_approval()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "an address should not be able to approve before it is delegated, but got: {:?}",
+            error,
+        );
+        add_delegate(
+            deps.as_mut(),
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            add_default(),
+        )
+        .unwrap();
+        let response =
+            test_oracle_approval(&mut deps, &provenance_util, delegate_approval()).unwrap();
+        assert_eq!(
+            DELEGATE_ADDRESS,
+            single_attribute_for_key(&response, ORACLE_DELEGATE_KEY),
+            "the delegate should be emitted on the approval",
+        );
+        let context = load_oracle_approval_context(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+            .unwrap()
+            .expect("the approval context should be stored");
+        assert_eq!(
+            Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+            context.oracle_address,
+            "the approval should still be attributed to the payable's oracle",
+        );
+        assert_eq!(
+            Some(Addr::unchecked(DELEGATE_ADDRESS)),
+            context.delegate_address,
+            "the delegate that submitted the approval should be recorded",
+        );
+    }
+
+    fn add_default() -> AddOracleDelegateV1 {
+        AddOracleDelegateV1 {
+            delegate: DELEGATE_ADDRESS.to_string(),
+        }
+    }
+
+    fn remove_default() -> RemoveOracleDelegateV1 {
+        RemoveOracleDelegateV1 {
+            delegate: DELEGATE_ADDRESS.to_string(),
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    append_payable_meta_patch_record, config_read_v2, load_payable_meta_patch_proposal,
+    payable_meta_storage_read_v2, payable_meta_storage_v2, remove_payable_meta_patch_proposal,
+    save_payable_meta_patch_proposal, PayableMetaPatchProposalV1, PayableMetaPatchRecordV1,
+    PayableMetaPatchV1, PayableMetaV2,
+};
+use crate::query::query_payable_by_scope_id::query_payable_attribute_by_scope_id;
+use crate::util::constants::{
+    META_PATCH_CONFIRMED_BY_KEY, META_PATCH_CONFIRMED_KEY, META_PATCH_PREVIOUS_SCOPE_ID_KEY,
+    META_PATCH_REASON_KEY, META_PATCH_SCOPE_ID_KEY, PAYABLE_META_PATCHED_KEY,
+};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the patch that the sender would like to confirm for a payable's local meta, along with
+/// the reason that the patch is required.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PatchPayableMetaV1 {
+    pub payable_uuid: String,
+    pub patch: PayableMetaPatchV1,
+    pub reason: String,
+}
+
+/// Records one party's confirmation of a patch to a payable's local meta, applying the patch once
+/// both the contract admin and the payable's oracle have confirmed it.  This allows wedged
+/// production data to be corrected without an ad-hoc migration, with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has local meta and that the patch changes it.
+/// - Ensures that the patched scope holds the payable's scope attribute, so a patch can never point
+///   the meta at a scope that does not contain the payable.
+/// - Verifies that the sender is the contract admin or the oracle named in the payable's scope
+///   attribute.  A sender holding both roles only confirms as the admin, so two distinct parties
+///   must always confirm.
+/// - Records the sender's confirmation.  Confirming a different patch or reason than the pending
+///   proposal replaces the proposal and discards any confirmation of the previous one.
+/// - Once both parties have confirmed, writes the patched meta, appends the previous and patched
+///   meta to the payable's audit log, and clears the proposal.
+pub fn patch_payable_meta(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    patch_meta: PatchPayableMetaV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let previous_meta = match payable_meta_storage_read_v2(deps.storage)
+        .may_load(patch_meta.payable_uuid.as_bytes())?
+    {
+        Some(meta) => meta,
+        None => {
+            return ContractError::PayableNotFound {
+                payable_uuid: patch_meta.payable_uuid,
+            }
+            .to_result();
+        }
+    };
+    let patched_meta = PayableMetaV2 {
+        payable_uuid: previous_meta.payable_uuid.clone(),
+        scope_id: patch_meta
+            .patch
+            .scope_id
+            .clone()
+            .unwrap_or_else(|| previous_meta.scope_id.clone()),
+    };
+    if patched_meta == previous_meta {
+        return ContractError::InvalidPayable {
+            payable_uuid: patch_meta.payable_uuid,
+            invalid_reason: "the patch does not change the payable's meta".into(),
+        }
+        .to_result();
+    }
+    let scope_attribute =
+        match query_payable_attribute_by_scope_id(&deps.as_ref(), &patched_meta.scope_id) {
+            Ok(attr) if attr.payable_uuid == patched_meta.payable_uuid => attr,
+            _ => {
+                return ContractError::InvalidPayable {
+                    payable_uuid: patch_meta.payable_uuid,
+                    invalid_reason: "the patched scope does not hold the payable's attribute"
+                        .into(),
+                }
+                .to_result();
+            }
+        };
+    let is_admin = state.is_admin(&info.sender);
+    let is_oracle = !is_admin && info.sender == scope_attribute.oracle_address;
+    if !is_admin && !is_oracle {
+        return Err(ContractError::Unauthorized);
+    }
+    let mut proposal =
+        match load_payable_meta_patch_proposal(deps.storage, &patched_meta.payable_uuid)? {
+            Some(proposal)
+                if proposal.patch == patch_meta.patch && proposal.reason == patch_meta.reason =>
+            {
+                proposal
+            }
+            _ => PayableMetaPatchProposalV1 {
+                payable_uuid: patched_meta.payable_uuid.clone(),
+                patch: patch_meta.patch,
+                reason: patch_meta.reason.clone(),
+                admin_confirmation: None,
+                oracle_confirmation: None,
+            },
+        };
+    if is_admin {
+        proposal.admin_confirmation = Some(info.sender.clone());
+    }
+    if is_oracle {
+        proposal.oracle_confirmation = Some(info.sender.clone());
+    }
+    let response = ResponseBuilder::for_payable(&scope_attribute)
+        .attribute(META_PATCH_CONFIRMED_KEY, &patched_meta.payable_uuid)
+        .attribute(META_PATCH_CONFIRMED_BY_KEY, info.sender.as_str())
+        .attribute(META_PATCH_REASON_KEY, &patch_meta.reason);
+    let (admin, oracle) = match (&proposal.admin_confirmation, &proposal.oracle_confirmation) {
+        (Some(admin), Some(oracle)) => (admin.clone(), oracle.clone()),
+        _ => {
+            save_payable_meta_patch_proposal(deps.storage, &proposal)?;
+            return Ok(response.build());
+        }
+    };
+    payable_meta_storage_v2(deps.storage)
+        .save(patched_meta.payable_uuid.as_bytes(), &patched_meta)?;
+    append_payable_meta_patch_record(
+        deps.storage,
+        &patched_meta.payable_uuid,
+        &PayableMetaPatchRecordV1 {
+            previous_meta: previous_meta.clone(),
+            patched_meta: patched_meta.clone(),
+            reason: patch_meta.reason,
+            admin,
+            oracle,
+            patched_at: env.block.time,
+        },
+    )?;
+    remove_payable_meta_patch_proposal(deps.storage, &patched_meta.payable_uuid);
+    Ok(response
+        .attribute(PAYABLE_META_PATCHED_KEY, &patched_meta.payable_uuid)
+        .attribute(META_PATCH_PREVIOUS_SCOPE_ID_KEY, &previous_meta.scope_id)
+        .attribute(META_PATCH_SCOPE_ID_KEY, &patched_meta.scope_id)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_payable_meta_patch_audit, load_payable_meta_patch_proposal, payable_meta_storage_v2,
+        PayableMetaPatchV1, PayableMetaV2,
+    };
+    use crate::execute::patch_payable_meta::{patch_payable_meta, PatchPayableMetaV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        META_PATCH_CONFIRMED_BY_KEY, META_PATCH_PREVIOUS_SCOPE_ID_KEY, META_PATCH_SCOPE_ID_KEY,
+        PAYABLE_META_PATCHED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Addr, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const WEDGED_SCOPE_ID: &str = "scope1qz3s7dvsaq4yxs4ha2ythqr3u5kq0x7cw6";
+    const PATCH_REASON: &str = "meta written against the wrong scope";
+
+    #[test]
+    fn test_patch_applies_after_both_confirmations() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        wedge_payable_meta(&mut deps);
+        assert!(
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).is_err(),
+            "the payable should not be found through its wedged meta",
+        );
+        let response = test_patch(&mut deps, DEFAULT_INFO_NAME, PATCH_REASON).unwrap();
+        assert_eq!(
+            DEFAULT_INFO_NAME,
+            single_attribute_for_key(&response, META_PATCH_CONFIRMED_BY_KEY),
+            "the admin should be emitted as the confirming party",
+        );
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == PAYABLE_META_PATCHED_KEY),
+            "the patch should not be applied with a single confirmation",
+        );
+        assert!(
+            load_payable_meta_patch_proposal(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_some(),
+            "the confirmation should be held in a pending proposal",
+        );
+        let response = test_patch(&mut deps, DEFAULT_ORACLE_ADDRESS, PATCH_REASON).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_META_PATCHED_KEY),
+            "the patch should be applied once both parties confirm",
+        );
+        assert_eq!(
+            WEDGED_SCOPE_ID,
+            single_attribute_for_key(&response, META_PATCH_PREVIOUS_SCOPE_ID_KEY),
+            "the scope id before the patch should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_SCOPE_ID,
+            single_attribute_for_key(&response, META_PATCH_SCOPE_ID_KEY),
+            "the scope id after the patch should be emitted",
+        );
+        query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+            .expect("the payable should be found through its patched meta");
+        assert!(
+            load_payable_meta_patch_proposal(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the proposal should be cleared once applied",
+        );
+        let audit =
+            load_payable_meta_patch_audit(deps.as_ref().storage, DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(1, audit.len(), "the applied patch should be audited");
+        let record = &audit[0];
+        assert_eq!(WEDGED_SCOPE_ID, record.previous_meta.scope_id);
+        assert_eq!(DEFAULT_SCOPE_ID, record.patched_meta.scope_id);
+        assert_eq!(PATCH_REASON, record.reason);
+        assert_eq!(Addr::unchecked(DEFAULT_INFO_NAME), record.admin);
+        assert_eq!(Addr::unchecked(DEFAULT_ORACLE_ADDRESS), record.oracle);
+        assert_eq!(mock_env().block.time, record.patched_at);
+    }
+
+    #[test]
+    fn test_patch_with_changed_reason_restarts_confirmation() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        wedge_payable_meta(&mut deps);
+        test_patch(&mut deps, DEFAULT_INFO_NAME, PATCH_REASON).unwrap();
+        let response = test_patch(&mut deps, DEFAULT_ORACLE_ADDRESS, "a different reason").unwrap();
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == PAYABLE_META_PATCHED_KEY),
+            "the patch should not be applied when the parties disagree on the reason",
+        );
+        let proposal =
+            load_payable_meta_patch_proposal(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .unwrap();
+        assert!(
+            proposal.admin_confirmation.is_none(),
+            "the admin's confirmation of the previous reason should be discarded",
+        );
+        assert!(
+            load_payable_meta_patch_audit(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_empty(),
+            "nothing should be audited until a patch is applied",
+        );
+    }
+
+    #[test]
+    fn test_patch_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = test_patch(&mut deps, DEFAULT_INFO_NAME, PATCH_REASON).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "a payable without meta should be reported as not found, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = test_patch(&mut deps, DEFAULT_INFO_NAME, PATCH_REASON).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a patch that changes nothing should be rejected, but got: {:?}",
+            error,
+        );
+        let error = patch_payable_meta(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            patch_to(WEDGED_SCOPE_ID, PATCH_REASON),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a patch to a scope without the payable's attribute should be rejected, but got: {:?}",
+            error,
+        );
+        wedge_payable_meta(&mut deps);
+        let error = test_patch(&mut deps, "some-support-engineer", PATCH_REASON).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin and the oracle should be able to confirm a patch, but got: {:?}",
+            error,
+        );
+        let error = patch_payable_meta(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            patch_to(DEFAULT_SCOPE_ID, PATCH_REASON),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    fn wedge_payable_meta(deps: &mut MockOwnedDeps) {
+        payable_meta_storage_v2(deps.as_mut().storage)
+            .save(
+                DEFAULT_PAYABLE_UUID.as_bytes(),
+                &PayableMetaV2 {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                    scope_id: WEDGED_SCOPE_ID.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    fn patch_to(scope_id: &str, reason: &str) -> PatchPayableMetaV1 {
+        PatchPayableMetaV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            patch: PayableMetaPatchV1 {
+                scope_id: Some(scope_id.to_string()),
+            },
+            reason: reason.to_string(),
+        }
+    }
+
+    fn test_patch(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        reason: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        patch_payable_meta(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            patch_to(DEFAULT_SCOPE_ID, reason),
+        )
+    }
+}
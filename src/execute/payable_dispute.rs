@@ -0,0 +1,385 @@
+use crate::core::error::ContractError;
+use crate::core::payee_resolver::resolve_payee;
+use crate::core::state::{
+    config_read_v2, load_payment_history, PayableDisputeV1, PayableScopeAttribute,
+};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    DISPUTE_RAISED_BY_KEY, DISPUTE_RAISED_KEY, DISPUTE_REASON_KEY, DISPUTE_RESOLUTION_KEY,
+    DISPUTE_RESOLVED_KEY, TOTAL_OWED_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that a party to it contests, and the reason that it is contested.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RaiseDisputeV1 {
+    pub payable_uuid: String,
+    pub reason: String,
+}
+
+/// Contains the outcome of a dispute, as decided by the payable's oracle or the contract admin.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ResolveDisputeV1 {
+    pub payable_uuid: String,
+    pub resolution: String,
+    // When provided, replaces the payable's total owed.  The amount already paid is kept, so the
+    // remaining owed amount is adjusted by the same difference
+    pub adjusted_total: Option<Uint128>,
+}
+
+/// Parent function path for the contract to raise a dispute.  Ensures that the ProvenanceUtilImpl
+/// is the implementation used for this functionality outside of tests.
+pub fn raise_dispute(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    raise: RaiseDisputeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    raise_dispute_with_util(deps, &ProvenanceUtilImpl, env, info, raise)
+}
+
+/// Flags a payable as disputed with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender is a party to the payable: its payee, its oracle, or an address that
+///   has made a payment against it.
+/// - Ensures that the payable is not already disputed, written off, or declined.
+/// - Rewrites the scope attribute with the dispute.  Disputed payables cannot be paid until the
+///   dispute is resolved.
+pub fn raise_dispute_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    raise: RaiseDisputeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute = load_disputable_payable(&deps.as_ref(), raise.payable_uuid)?;
+    let is_party = info.sender == scope_attribute.oracle_address
+        || info.sender == resolve_payee(&deps.as_ref(), provenance_util, &scope_attribute)?
+        || load_payment_history(deps.storage, &scope_attribute.payable_uuid)?
+            .iter()
+            .any(|record| record.payer == info.sender);
+    if !is_party {
+        return Err(ContractError::Unauthorized);
+    }
+    let invalid_reason = if scope_attribute.dispute.is_some() {
+        Some("the payable is already under dispute")
+    } else if scope_attribute.is_written_off {
+        Some("written off payables cannot be disputed")
+    } else if scope_attribute.is_declined {
+        Some("declined payables cannot be disputed")
+    } else {
+        None
+    };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: invalid_reason.into(),
+        }
+        .to_result();
+    }
+    scope_attribute.dispute = Some(PayableDisputeV1 {
+        raised_by: info.sender.clone(),
+        reason: raise.reason.clone(),
+        raised_at: env.block.time,
+    });
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(DISPUTE_RAISED_KEY, &scope_attribute.payable_uuid)
+        .attribute(DISPUTE_RAISED_BY_KEY, info.sender.as_str())
+        .attribute(DISPUTE_REASON_KEY, &raise.reason)
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+/// Parent function path for the contract to resolve a dispute.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn resolve_dispute(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    resolve: ResolveDisputeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    resolve_dispute_with_util(deps, &ProvenanceUtilImpl, env, info, resolve)
+}
+
+/// Closes the dispute on a payable with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered and is under dispute.
+/// - Verifies that the sender is the payable's oracle or the contract admin.
+/// - Replaces the total owed with the adjusted total, if provided, keeping the amount already paid.
+///   The adjusted total cannot be less than the amount already paid.
+/// - Rewrites the scope attribute without the dispute, allowing payments to resume.
+pub fn resolve_dispute_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    resolve: ResolveDisputeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute = load_disputable_payable(&deps.as_ref(), resolve.payable_uuid)?;
+    if info.sender != scope_attribute.oracle_address && !state.is_admin(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+    if scope_attribute.dispute.is_none() {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the payable is not under dispute".into(),
+        }
+        .to_result();
+    }
+    if let Some(adjusted_total) = resolve.adjusted_total {
+        let amount_paid =
+            scope_attribute.payable_total_owed - scope_attribute.payable_remaining_owed;
+        if adjusted_total < amount_paid {
+            return ContractError::InvalidPayable {
+                payable_uuid: scope_attribute.payable_uuid,
+                invalid_reason: format!(
+                    "the adjusted total [{}] is less than the amount already paid [{}]",
+                    adjusted_total, amount_paid,
+                ),
+            }
+            .to_result();
+        }
+        scope_attribute.payable_total_owed = adjusted_total;
+        scope_attribute.payable_remaining_owed = adjusted_total - amount_paid;
+        // An adjustment can reopen a settled payable, or settle it at the amount already paid
+        scope_attribute.settled_at = if scope_attribute.payable_remaining_owed.is_zero() {
+            scope_attribute.settled_at.or(Some(env.block.time))
+        } else {
+            None
+        };
+    }
+    scope_attribute.dispute = None;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(DISPUTE_RESOLVED_KEY, &scope_attribute.payable_uuid)
+        .attribute(DISPUTE_RESOLUTION_KEY, &resolve.resolution)
+        .attribute(
+            TOTAL_OWED_KEY,
+            scope_attribute.payable_total_owed.to_string(),
+        )
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+fn load_disputable_payable(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: String,
+) -> Result<PayableScopeAttribute, ContractError> {
+    match query_payable_attribute_by_uuid(deps, &payable_uuid) {
+        Ok(attr) => Ok(attr),
+        Err(_) => ContractError::PayableNotFound { payable_uuid }.to_result(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::payable_dispute::{
+        raise_dispute_with_util, resolve_dispute_with_util, RaiseDisputeV1, ResolveDisputeV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        DISPUTE_RAISED_BY_KEY, DISPUTE_RAISED_KEY, DISPUTE_REASON_KEY, DISPUTE_RESOLUTION_KEY,
+        DISPUTE_RESOLVED_KEY, TOTAL_OWED_KEY, TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_dispute_blocks_payments_until_resolved() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_paid_payable(&mut deps);
+        let response = raise(&mut deps, &provenance_util, DEFAULT_INFO_NAME)
+            .expect("the payee should be able to raise a dispute");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, DISPUTE_RAISED_KEY),
+        );
+        assert_eq!(
+            DEFAULT_INFO_NAME,
+            single_attribute_for_key(&response, DISPUTE_RAISED_BY_KEY),
+        );
+        assert_eq!(
+            "amount is wrong",
+            single_attribute_for_key(&response, DISPUTE_REASON_KEY),
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        let dispute = query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+            .unwrap()
+            .dispute
+            .expect("the dispute should be written to the scope attribute");
+        assert_eq!(mock_env().block.time, dispute.raised_at);
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotReadyForPayment { .. }),
+            "a disputed payable should not accept payments, but got: {:?}",
+            error,
+        );
+        let error = raise(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payable should not be disputed twice, but got: {:?}",
+            error,
+        );
+        let response = resolve(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_ORACLE_ADDRESS,
+            Some(800),
+        )
+        .expect("the oracle should be able to resolve the dispute");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, DISPUTE_RESOLVED_KEY),
+        );
+        assert_eq!(
+            "total reduced",
+            single_attribute_for_key(&response, DISPUTE_RESOLUTION_KEY),
+        );
+        assert_eq!("800", single_attribute_for_key(&response, TOTAL_OWED_KEY));
+        assert_eq!(
+            "500",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the amount already paid should be kept when the total is adjusted",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("payments should resume once the dispute is resolved");
+    }
+
+    #[test]
+    fn test_dispute_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_paid_payable(&mut deps);
+        let error = resolve(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, None).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payable that is not disputed should not be resolved, but got: {:?}",
+            error,
+        );
+        let error = raise(&mut deps, &provenance_util, "some-random-guy").unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only a party to the payable should be able to dispute it, but got: {:?}",
+            error,
+        );
+        raise(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let error = resolve(&mut deps, &provenance_util, "some-random-guy", None).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the oracle or admin should be able to resolve a dispute, but got: {:?}",
+            error,
+        );
+        let error = resolve(&mut deps, &provenance_util, DEFAULT_INFO_NAME, Some(200)).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "the total should not be adjusted below the amount already paid, but got: {:?}",
+            error,
+        );
+        resolve(&mut deps, &provenance_util, DEFAULT_INFO_NAME, None)
+            .expect("the admin should be able to resolve the dispute");
+    }
+
+    fn setup_paid_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(300),
+        )
+        .unwrap();
+        provenance_util
+    }
+
+    fn raise(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        raise_dispute_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            RaiseDisputeV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                reason: "amount is wrong".to_string(),
+            },
+        )
+    }
+
+    fn resolve(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        adjusted_total: Option<u128>,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = resolve_dispute_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            ResolveDisputeV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                resolution: "total reduced".to_string(),
+                adjusted_total: adjusted_total.map(Uint128::new),
+            },
+        );
+        if response.is_ok() {
+            provenance_util.bind_captured_attribute(deps);
+        }
+        response
+    }
+}
@@ -0,0 +1,180 @@
+use crate::core::error::ContractError;
+use crate::core::state::config_read_v2;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::PAYABLE_FROZEN_KEY;
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that its oracle would like to freeze or unfreeze.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetPayableFrozenV1 {
+    pub payable_uuid: String,
+    pub frozen: bool,
+}
+
+/// Parent function path for the contract to freeze or unfreeze a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn set_payable_frozen(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    set_frozen: SetPayableFrozenV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    set_payable_frozen_with_util(deps, &ProvenanceUtilImpl, env, info, set_frozen)
+}
+
+/// Freezes or unfreezes a single payable with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender is the payable's oracle.
+/// - Ensures that the payable is not already in the requested state.
+/// - Rewrites the scope attribute with the flag.  Frozen payables cannot be paid until their
+///   oracle unfreezes them, while the rest of the contract is unaffected.
+pub fn set_payable_frozen_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    set_frozen: SetPayableFrozenV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &set_frozen.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: set_frozen.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if info.sender != scope_attribute.oracle_address {
+        return Err(ContractError::Unauthorized);
+    }
+    if scope_attribute.is_frozen == set_frozen.frozen {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: if set_frozen.frozen {
+                "the payable is already frozen".into()
+            } else {
+                "the payable is not frozen".into()
+            },
+        }
+        .to_result();
+    }
+    scope_attribute.is_frozen = set_frozen.frozen;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYABLE_FROZEN_KEY, set_frozen.frozen.to_string())
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::payable_freeze::{set_payable_frozen_with_util, SetPayableFrozenV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::PAYABLE_FROZEN_KEY;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Response;
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_frozen_payable_rejects_payments_until_unfrozen() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let error = set_frozen(&mut deps, &provenance_util, DEFAULT_INFO_NAME, true).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the payable's oracle should be able to freeze it, but got: {:?}",
+            error,
+        );
+        let response = set_frozen(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, true)
+            .expect("the oracle should be able to freeze the payable");
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, PAYABLE_FROZEN_KEY)
+        );
+        assert!(
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_frozen,
+            "the flag should be written to the scope attribute",
+        );
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotReadyForPayment { .. }),
+            "a frozen payable should not accept payments, but got: {:?}",
+            error,
+        );
+        let error =
+            set_frozen(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, true).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a frozen payable should not be frozen again, but got: {:?}",
+            error,
+        );
+        let response = set_frozen(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, false)
+            .expect("the oracle should be able to unfreeze the payable");
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, PAYABLE_FROZEN_KEY)
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("payments should resume once the payable is unfrozen");
+    }
+
+    fn set_frozen(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        frozen: bool,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = set_payable_frozen_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            SetPayableFrozenV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                frozen,
+            },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+}
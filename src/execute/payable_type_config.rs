@@ -0,0 +1,347 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, save_payable_type_config, PayableTypeConfigV1, RegistrantRequirementV1,
+};
+use crate::util::constants::{
+    PAYABLE_TYPE_CONFIG_DEFAULT_ORACLE_KEY, PAYABLE_TYPE_CONFIG_FEE_PERCENT_KEY,
+    PAYABLE_TYPE_CONFIG_ONBOARDING_COST_KEY, PAYABLE_TYPE_CONFIG_REGISTRANT_REQUIREMENT_KEY,
+    PAYABLE_TYPE_CONFIG_UPSERTED_KEY,
+};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains all relevant fields required in order for the contract admin to configure the
+/// registration economics of a payable type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UpsertPayableTypeConfigV1 {
+    pub payable_type: String,
+    pub onboarding_cost: Uint128,
+    pub onboarding_denom: String,
+    pub fee_percent: Decimal,
+    pub default_oracle: Option<String>,
+    pub registrant_requirement: Option<RegistrantRequirementV1>,
+}
+
+/// Creates or replaces the config for a payable type with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the default oracle, if provided, is a valid address.
+/// - Stores the config in local storage, keyed on the payable type.  Subsequent registrations of
+///   the type are charged the configured onboarding cost, denom, and fee percent, and are assigned
+///   the default oracle when they do not name one.  When a registrant requirement is configured,
+///   registrations of the type must be sent by a scope party that satisfies it.  Payables that are
+///   already registered keep the fees that they were charged.
+pub fn upsert_payable_type_config(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    upsert: UpsertPayableTypeConfigV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    let default_oracle = match &upsert.default_oracle {
+        Some(default_oracle) => Some(deps.api.addr_validate(default_oracle)?),
+        None => None,
+    };
+    let config = PayableTypeConfigV1 {
+        payable_type: upsert.payable_type,
+        onboarding_cost: upsert.onboarding_cost,
+        onboarding_denom: upsert.onboarding_denom,
+        fee_percent: upsert.fee_percent,
+        default_oracle,
+        registrant_requirement: upsert.registrant_requirement,
+    };
+    save_payable_type_config(deps.storage, &config)?;
+    Ok(ResponseBuilder::new()
+        .attribute(PAYABLE_TYPE_CONFIG_UPSERTED_KEY, &config.payable_type)
+        .attribute(
+            PAYABLE_TYPE_CONFIG_ONBOARDING_COST_KEY,
+            Money::new(config.onboarding_cost, &config.onboarding_denom).to_string(),
+        )
+        .attribute(
+            PAYABLE_TYPE_CONFIG_FEE_PERCENT_KEY,
+            config.fee_percent.to_string(),
+        )
+        .optional_attribute(
+            PAYABLE_TYPE_CONFIG_DEFAULT_ORACLE_KEY,
+            config.default_oracle.as_ref(),
+        )
+        .optional_attribute(
+            PAYABLE_TYPE_CONFIG_REGISTRANT_REQUIREMENT_KEY,
+            config
+                .registrant_requirement
+                .as_ref()
+                .map(|requirement| requirement.describe()),
+        )
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_held_oracle_fee, load_payable_type_config, RegistrantRequirementV1,
+    };
+    use crate::execute::payable_type_config::{
+        upsert_payable_type_config, UpsertPayableTypeConfigV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_FEE_COLLECTION_ADDRESS, DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        ONBOARDING_COST_CHARGED_KEY, ORACLE_ADDRESS_KEY, PAYABLE_TYPE_CONFIG_DEFAULT_ORACLE_KEY,
+        PAYABLE_TYPE_CONFIG_ONBOARDING_COST_KEY,
+    };
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Decimal, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{Party, PartyType, ProvenanceMsg, Scope};
+
+    const TYPE_DENOM: &str = "usdf";
+    const TYPE_ORACLE: &str = "type-oracle";
+
+    #[test]
+    fn test_upsert_payable_type_config() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = test_upsert(&mut deps, DEFAULT_INFO_NAME, type_config(Some(TYPE_ORACLE)))
+            .expect("the admin should be able to configure a payable type");
+        assert_eq!(
+            format!("200/{}", TYPE_DENOM),
+            single_attribute_for_key(&response, PAYABLE_TYPE_CONFIG_ONBOARDING_COST_KEY),
+            "the configured onboarding cost should be emitted",
+        );
+        assert_eq!(
+            TYPE_ORACLE,
+            single_attribute_for_key(&response, PAYABLE_TYPE_CONFIG_DEFAULT_ORACLE_KEY),
+            "the default oracle should be emitted",
+        );
+        let config = load_payable_type_config(deps.as_ref().storage, DEFAULT_PAYABLE_TYPE)
+            .unwrap()
+            .expect("the config should be stored for the payable type");
+        assert_eq!(Uint128::new(200), config.onboarding_cost);
+        assert_eq!(Some(Addr::unchecked(TYPE_ORACLE)), config.default_oracle);
+        test_upsert(&mut deps, DEFAULT_INFO_NAME, type_config(None))
+            .expect("the admin should be able to replace the config");
+        assert_eq!(
+            None,
+            load_payable_type_config(deps.as_ref().storage, DEFAULT_PAYABLE_TYPE)
+                .unwrap()
+                .unwrap()
+                .default_oracle,
+            "the replaced config should no longer have a default oracle",
+        );
+    }
+
+    #[test]
+    fn test_upsert_payable_type_config_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = test_upsert(&mut deps, "not-the-admin", type_config(None)).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to configure a payable type, but got: {:?}",
+            error,
+        );
+        let error = upsert_payable_type_config(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, DEFAULT_ONBOARDING_DENOM)]),
+            type_config(None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_registration_uses_payable_type_config() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_upsert(&mut deps, DEFAULT_INFO_NAME, type_config(Some(TYPE_ORACLE))).unwrap();
+        // The contract's onboarding denom is no longer accepted for the configured type
+        let error = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable::default_with_coin(100, DEFAULT_ONBOARDING_DENOM),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFundsProvided { .. }),
+            "the contract's onboarding denom should be rejected, but got: {:?}",
+            error,
+        );
+        let mut register = TestRegisterPayable::default_with_coin(200, TYPE_DENOM);
+        register.register_payable.oracle_address = String::new();
+        let response = test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        assert_eq!(
+            format!("200/{}", TYPE_DENOM),
+            single_attribute_for_key(&response, ONBOARDING_COST_CHARGED_KEY),
+            "the configured onboarding cost should be charged",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_FEE_COLLECTION_ADDRESS.to_string(),
+                    amount: vec![coin(50, TYPE_DENOM)],
+                })),
+            "the configured fee percent should be sent to the fee collector",
+        );
+        assert_eq!(
+            TYPE_ORACLE,
+            single_attribute_for_key(&response, ORACLE_ADDRESS_KEY),
+            "the default oracle should be assigned when no oracle is named",
+        );
+        assert_eq!(
+            Addr::unchecked(TYPE_ORACLE),
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .oracle_address,
+            "the default oracle should be recorded on the scope attribute",
+        );
+        assert_eq!(
+            TYPE_DENOM,
+            load_held_oracle_fee(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .unwrap()
+                .denom,
+            "the oracle's share should be held in the configured denom",
+        );
+        let response = test_oracle_approval(
+            &mut deps,
+            &provenance_util,
+            TestOracleApproval {
+                info: mock_info(TYPE_ORACLE, &[]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: TYPE_ORACLE.to_string(),
+                    amount: vec![coin(150, TYPE_DENOM)],
+                })),
+            "the default oracle should be paid its share in the configured denom",
+        );
+    }
+
+    #[test]
+    fn test_registration_without_oracle_requires_default_oracle() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let mut register = TestRegisterPayable::default();
+        register.register_payable.oracle_address = String::new();
+        let error = test_register_payable(&mut deps, &provenance_util, register).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "an oracle should be required without a default oracle, but got: {:?}",
+            error,
+        );
+        test_upsert(&mut deps, DEFAULT_INFO_NAME, type_config(None)).unwrap();
+        let mut register = TestRegisterPayable::default_with_coin(200, TYPE_DENOM);
+        register.register_payable.priority_fee = Some(Uint128::new(10));
+        let error = test_register_payable(&mut deps, &provenance_util, register).unwrap_err();
+        assert!(
+            matches!(error, ContractError::DenomMismatch { .. }),
+            "held funds should be rejected for types charged in another denom, but got: {:?}",
+            error,
+        );
+        // The named oracle is kept when the type has no default oracle
+        let response = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable::default_with_coin(200, TYPE_DENOM),
+        )
+        .unwrap();
+        assert_eq!(
+            DEFAULT_ORACLE_ADDRESS,
+            single_attribute_for_key(&response, ORACLE_ADDRESS_KEY),
+        );
+    }
+
+    #[test]
+    fn test_registration_enforces_registrant_requirement() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let mut upsert = type_config(None);
+        upsert.registrant_requirement = Some(RegistrantRequirementV1::OwnerRole {
+            role: PartyType::Originator,
+        });
+        test_upsert(&mut deps, DEFAULT_INFO_NAME, upsert.clone()).unwrap();
+        // The default scope lists the sender as an owner with the owner role
+        let error = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable::default_with_coin(200, TYPE_DENOM),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::RegistrantRequirementNotMet { requirement, .. } => assert_eq!(
+                "scope owner with role originator", requirement,
+                "the error should report the role check that failed",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+        upsert.registrant_requirement = Some(RegistrantRequirementV1::ValueOwner);
+        test_upsert(&mut deps, DEFAULT_INFO_NAME, upsert.clone()).unwrap();
+        deps.querier.with_scope(Scope {
+            value_owner_address: Addr::unchecked("value-owner"),
+            ..get_duped_scope(DEFAULT_SCOPE_ID, DEFAULT_INFO_NAME)
+        });
+        let error = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable::default_with_coin(200, TYPE_DENOM),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::RegistrantRequirementNotMet { requirement, .. } => assert_eq!(
+                "scope value owner", requirement,
+                "the error should report the value owner check that failed",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+        deps.querier.with_scope(Scope {
+            owners: vec![Party {
+                address: Addr::unchecked("value-owner"),
+                role: PartyType::Owner,
+            }],
+            ..get_duped_scope(DEFAULT_SCOPE_ID, DEFAULT_INFO_NAME)
+        });
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable::default_with_coin(200, TYPE_DENOM),
+        )
+        .expect("the value owner should be able to register without being a scope owner");
+    }
+
+    fn type_config(default_oracle: Option<&str>) -> UpsertPayableTypeConfigV1 {
+        UpsertPayableTypeConfigV1 {
+            payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            onboarding_cost: Uint128::new(200),
+            onboarding_denom: TYPE_DENOM.to_string(),
+            fee_percent: Decimal::percent(25),
+            default_oracle: default_oracle.map(|oracle| oracle.to_string()),
+            registrant_requirement: None,
+        }
+    }
+
+    fn test_upsert(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        upsert: UpsertPayableTypeConfigV1,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        upsert_payable_type_config(deps.as_mut(), mock_info(sender, &[]), upsert)
+    }
+}
@@ -0,0 +1,150 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, save_payable_type_frozen};
+use crate::util::constants::{PAYABLE_TYPE_FROZEN_KEY, PAYABLE_TYPE_KEY};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable type that the contract admin would like to freeze or unfreeze, ex: when a
+/// validation flaw is discovered in the product that the type represents.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetPayableTypeFrozenV1 {
+    pub payable_type: String,
+    pub frozen: bool,
+}
+
+/// Freezes or unfreezes every payable of a type with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the flag in local storage, keyed on the payable type.  The flag is checked whenever a
+///   payable of the type is approved or paid, so no scope attributes are rewritten and the freeze
+///   takes effect for every existing and future payable of the type at once.
+pub fn set_payable_type_frozen(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_frozen: SetPayableTypeFrozenV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    save_payable_type_frozen(deps.storage, &set_frozen.payable_type, set_frozen.frozen)?;
+    Ok(ResponseBuilder::new()
+        .attribute(PAYABLE_TYPE_FROZEN_KEY, set_frozen.frozen.to_string())
+        .attribute(PAYABLE_TYPE_KEY, &set_frozen.payable_type)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PayableTypeFrozenResponse, QueryMsg};
+    use crate::execute::payable_type_freeze::{set_payable_type_frozen, SetPayableTypeFrozenV1};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_TYPE,
+    };
+    use crate::util::constants::{PAYABLE_TYPE_FROZEN_KEY, PAYABLE_TYPE_KEY};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_frozen_payable_type_rejects_approvals_and_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = set_frozen(&mut deps, DEFAULT_ORACLE_ADDRESS, true).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to freeze a payable type, but got: {:?}",
+            error,
+        );
+        let response = set_frozen(&mut deps, DEFAULT_INFO_NAME, true)
+            .expect("the admin should be able to freeze a payable type");
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, PAYABLE_TYPE_FROZEN_KEY)
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_TYPE,
+            single_attribute_for_key(&response, PAYABLE_TYPE_KEY)
+        );
+        assert!(
+            query_frozen(&deps).frozen,
+            "the status query should report the payable type as frozen",
+        );
+        let error =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "payables of a frozen type should not be approved, but got: {:?}",
+            error,
+        );
+        set_frozen(&mut deps, DEFAULT_INFO_NAME, false).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+            .expect("approvals should resume once the payable type is unfrozen");
+        set_frozen(&mut deps, DEFAULT_INFO_NAME, true).unwrap();
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotReadyForPayment { .. }),
+            "payables of a frozen type should not accept payments, but got: {:?}",
+            error,
+        );
+        let response = set_frozen(&mut deps, DEFAULT_INFO_NAME, false)
+            .expect("the admin should be able to unfreeze a payable type");
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, PAYABLE_TYPE_FROZEN_KEY)
+        );
+        assert!(
+            !query_frozen(&deps).frozen,
+            "the status query should report the payable type as unfrozen",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("payments should resume once the payable type is unfrozen");
+    }
+
+    fn set_frozen(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        frozen: bool,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        set_payable_type_frozen(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            SetPayableTypeFrozenV1 {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+                frozen,
+            },
+        )
+    }
+
+    fn query_frozen(deps: &MockOwnedDeps) -> PayableTypeFrozenResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayableTypeFrozen {
+                    payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,110 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, set_payable_type_supply_checked};
+use crate::util::constants::{SUPPLY_CHECK_ENABLED_KEY, SUPPLY_CHECK_PAYABLE_TYPE_KEY};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains all relevant fields required in order for the contract admin to enable or disable the
+/// marker supply check for registrations of a payable type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetPayableTypeSupplyCheckV1 {
+    pub payable_type: String,
+    pub enabled: bool,
+}
+
+/// Configures the marker supply check for a payable type with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the setting in local storage, keyed on the payable type.  When enabled, registrations
+///   of the type with a marker denom are rejected if their total exceeds the marker's supply.
+pub fn set_payable_type_supply_check(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_check: SetPayableTypeSupplyCheckV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    set_payable_type_supply_checked(deps.storage, &set_check.payable_type, set_check.enabled)?;
+    Ok(ResponseBuilder::new()
+        .attribute(SUPPLY_CHECK_PAYABLE_TYPE_KEY, &set_check.payable_type)
+        .attribute(SUPPLY_CHECK_ENABLED_KEY, set_check.enabled.to_string())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::is_payable_type_supply_checked;
+    use crate::execute::payable_type_supply_check::{
+        set_payable_type_supply_check, SetPayableTypeSupplyCheckV1,
+    };
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_INFO_NAME,
+        DEFAULT_PAYABLE_TYPE,
+    };
+    use crate::util::constants::{SUPPLY_CHECK_ENABLED_KEY, SUPPLY_CHECK_PAYABLE_TYPE_KEY};
+    use cosmwasm_std::testing::mock_info;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_set_payable_type_supply_check() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = set_payable_type_supply_check(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            set_check(true),
+        )
+        .expect("the admin should be able to enable the supply check");
+        assert_eq!(
+            DEFAULT_PAYABLE_TYPE,
+            single_attribute_for_key(&response, SUPPLY_CHECK_PAYABLE_TYPE_KEY),
+            "the payable type should be emitted as an attribute",
+        );
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, SUPPLY_CHECK_ENABLED_KEY),
+            "the enabled value should be emitted as an attribute",
+        );
+        assert!(
+            is_payable_type_supply_checked(deps.as_ref().storage, DEFAULT_PAYABLE_TYPE).unwrap(),
+            "the supply check should be enabled for the payable type",
+        );
+        set_payable_type_supply_check(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            set_check(false),
+        )
+        .expect("the admin should be able to disable the supply check");
+        assert!(
+            !is_payable_type_supply_checked(deps.as_ref().storage, DEFAULT_PAYABLE_TYPE).unwrap(),
+            "the supply check should be disabled for the payable type",
+        );
+    }
+
+    #[test]
+    fn test_set_payable_type_supply_check_unauthorized() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = set_payable_type_supply_check(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            set_check(true),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to configure the supply check, but got: {:?}",
+            error,
+        );
+    }
+
+    fn set_check(enabled: bool) -> SetPayableTypeSupplyCheckV1 {
+        SetPayableTypeSupplyCheckV1 {
+            payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            enabled,
+        }
+    }
+}
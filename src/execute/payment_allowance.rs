@@ -0,0 +1,642 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, load_payable_authorized_payers, load_payable_risk_band, load_payment_allowance,
+    remove_payment_allowance, save_payment_allowance, PaymentAllowanceV1,
+};
+use crate::execute::make_payment::{
+    apply_payment, load_payable_for_payment, PaymentContext, PaymentDetails,
+};
+use crate::util::authz::authz_send_msg;
+use crate::util::constants::{
+    PAYABLE_UUID_KEY, PAYER_KEY, PAYMENT_ALLOWANCE_CLOSED_KEY, PAYMENT_ALLOWANCE_GRANTED_KEY,
+    PAYMENT_ALLOWANCE_NEXT_PULL_KEY, PAYMENT_ALLOWANCE_PULLED_KEY, PAYMENT_ALLOWANCE_REMAINING_KEY,
+    PAYMENT_ALLOWANCE_REVOKED_KEY,
+};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Storage, Timestamp, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the schedule on which the sender would like the contract to pull payments for a payable
+/// from the sender's account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GrantPaymentAllowanceV1 {
+    pub payable_uuid: String,
+    pub denom: String,
+    pub max_total: Uint128,
+    pub pull_amount: Uint128,
+    pub pull_interval_seconds: u64,
+    // When omitted, the first pull falls due immediately
+    pub first_pull_at: Option<Timestamp>,
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Contains the payable whose payment allowance the sender would like to revoke.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevokePaymentAllowanceV1 {
+    pub payable_uuid: String,
+}
+
+/// Contains the payable and payer whose payment allowance is due to be pulled.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PullAllowancePaymentV1 {
+    pub payable_uuid: String,
+    pub payer: String,
+}
+
+/// Records the sender's allowance for the contract to pull scheduled payments against a payable,
+/// with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the contract is not paused.
+/// - Verifies that the payable has been registered and approved by its oracle, and that it accepts
+///   the allowance's denom.
+/// - Ensures that the sender is an authorized payer of the payable, if it restricts its payers.
+/// - Ensures that each pull stays within the large payment threshold of the payable's risk band or
+///   the contract, as pulled payments are never co-signed by the oracle.
+/// - Ensures that the allowance has not already expired, and that it expires after its first pull.
+/// - Stores the allowance, keyed on the payable and the sender.  Granting an allowance for a payable
+///   that the sender already has one for replaces it, resetting the amount pulled.
+///
+/// The allowance only records the schedule.  Pulls move funds through a send authorization that the
+/// sender must separately grant the contract with the authz module, and fail while it is missing.
+pub fn grant_payment_allowance(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    grant: GrantPaymentAllowanceV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let scope_attribute = load_payable_for_payment(&deps.as_ref(), grant.payable_uuid)?;
+    if !scope_attribute.accepts_denom(&grant.denom) {
+        return Err(ContractError::InvalidFundsProvided {
+            valid_denom: scope_attribute.payable_denom,
+            invalid_denoms: vec![grant.denom],
+        });
+    }
+    if let Some(authorized_payers) =
+        load_payable_authorized_payers(deps.storage, &scope_attribute.payable_uuid)?
+    {
+        if !authorized_payers.contains(&info.sender) {
+            return ContractError::PayerNotAuthorized {
+                payable_uuid: scope_attribute.payable_uuid,
+                payer: info.sender.to_string(),
+            }
+            .to_result();
+        }
+    }
+    // A threshold set on the payable's risk band takes precedence over the contract's threshold
+    if let Some(large_payment_threshold) = load_payable_risk_band(deps.storage, &scope_attribute)?
+        .and_then(|band| band.large_payment_threshold)
+        .or(state.large_payment_threshold)
+    {
+        if grant.pull_amount > large_payment_threshold {
+            return Err(ContractError::PaymentRequiresCoSign {
+                large_payment_threshold: large_payment_threshold.u128(),
+                amount_provided: grant.pull_amount.u128(),
+            });
+        }
+    }
+    let next_pull_at = grant.first_pull_at.map_or(env.block.time, |first_pull_at| {
+        first_pull_at.max(env.block.time)
+    });
+    if grant
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= next_pull_at)
+    {
+        return ContractError::invalid_fields(vec!["expires_at"]).to_result();
+    }
+    let allowance = PaymentAllowanceV1 {
+        payer: info.sender,
+        denom: grant.denom,
+        max_total: grant.max_total,
+        pulled_total: Uint128::zero(),
+        pull_amount: grant.pull_amount,
+        pull_interval_seconds: grant.pull_interval_seconds,
+        next_pull_at,
+        expires_at: grant.expires_at,
+    };
+    save_payment_allowance(deps.storage, &scope_attribute.payable_uuid, &allowance)?;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .attribute(PAYMENT_ALLOWANCE_GRANTED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PAYER_KEY, allowance.payer.as_str())
+        .attribute(
+            PAYMENT_ALLOWANCE_REMAINING_KEY,
+            allowance.max_total.to_string(),
+        )
+        .attribute(
+            PAYMENT_ALLOWANCE_NEXT_PULL_KEY,
+            allowance.next_pull_at.seconds().to_string(),
+        )
+        .build())
+}
+
+/// Removes the sender's payment allowance for a payable with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender has an allowance for the payable.
+/// - Removes the allowance, stopping all future pulls.  The payable does not need to be payable, so
+///   that an allowance can always be revoked.  The authz grant given to the contract is untouched
+///   and should be revoked separately.
+pub fn revoke_payment_allowance(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    revoke: RevokePaymentAllowanceV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    load_payment_allowance_or_err(deps.storage, &revoke.payable_uuid, &info.sender)?;
+    remove_payment_allowance(deps.storage, &revoke.payable_uuid, &info.sender);
+    Ok(ResponseBuilder::new()
+        .attribute(PAYMENT_ALLOWANCE_REVOKED_KEY, &revoke.payable_uuid)
+        .attribute(PAYABLE_UUID_KEY, &revoke.payable_uuid)
+        .attribute(PAYER_KEY, info.sender.as_str())
+        .build())
+}
+
+/// Parent function path for the contract to pull an allowance payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn pull_allowance_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    pull: PullAllowancePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    pull_allowance_payment_with_util(deps, &ProvenanceUtilImpl, env, info, pull)
+}
+
+/// Pulls a scheduled payment from a payer's account under their payment allowance, with the
+/// following steps.  Any address can trigger a pull, allowing keepers to crank allowances as they
+/// fall due:
+/// - Verifies that no funds were sent.
+/// - Ensures that the contract is not paused.
+/// - Ensures that the payer has an allowance for the payable.
+/// - Closes the allowance without pulling if it has expired, if the payable can no longer be found,
+///   or if the payable has been paid off.
+/// - Ensures that the allowance's next pull has fallen due.
+/// - Pulls the allowance's pull amount, capped at the amount remaining in the allowance and the
+///   amount remaining owed on the payable, from the payer's account to the contract with an authz
+///   exec, and applies it exactly as a direct payment from the payer would be applied.  If the
+///   payer's authz grant does not cover the pull, the entire pull is reverted.
+/// - Schedules the next pull one interval after the pull that fell due.  Pulls missed by keepers
+///   are skipped rather than made up.  The allowance is closed once it is exhausted or the payment
+///   pays off the payable.
+pub fn pull_allowance_payment_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    pull: PullAllowancePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let payer = deps.api.addr_validate(&pull.payer)?;
+    let mut allowance = load_payment_allowance_or_err(deps.storage, &pull.payable_uuid, &payer)?;
+    if allowance.is_expired(env.block.time) {
+        return close_allowance(deps.storage, &pull.payable_uuid, &payer, "expired");
+    }
+    let scope_attribute = match load_payable_for_payment(&deps.as_ref(), pull.payable_uuid.clone())
+    {
+        Ok(scope_attribute) => scope_attribute,
+        Err(ContractError::PayableNotFound { .. }) => {
+            return close_allowance(
+                deps.storage,
+                &pull.payable_uuid,
+                &payer,
+                "payable_not_found",
+            )
+        }
+        Err(e) => return Err(e),
+    };
+    let remaining_owed = scope_attribute.payable_remaining_owed
+        + scope_attribute.late_fees_due(env.block.time)?
+        + scope_attribute.interest_due(env.block.time)?;
+    if remaining_owed.is_zero() {
+        return close_allowance(deps.storage, &pull.payable_uuid, &payer, "settled");
+    }
+    if env.block.time < allowance.next_pull_at {
+        return ContractError::AllowancePullNotDue {
+            payable_uuid: pull.payable_uuid,
+            payer: payer.to_string(),
+            next_pull_at: allowance.next_pull_at,
+        }
+        .to_result();
+    }
+    let payment = Money::new(
+        allowance
+            .pull_amount
+            .min(allowance.remaining())
+            .min(remaining_owed),
+        &allowance.denom,
+    );
+    let settles_payable = payment.amount == remaining_owed;
+    let pull_msg = authz_send_msg(
+        &env.contract.address,
+        &payer,
+        &env.contract.address,
+        &payment,
+    );
+    let applied_payment = apply_payment(
+        deps.branch(),
+        provenance_util,
+        &env,
+        &PaymentContext {
+            state: &state,
+            payer: &payer,
+            details: PaymentDetails::default(),
+        },
+        scope_attribute,
+        payment.clone(),
+    )?;
+    allowance.pulled_total += payment.amount;
+    allowance.schedule_next_pull(env.block.time);
+    let mut response = ResponseBuilder::new()
+        // The pulled funds must reach the contract before they are sent on to the payee
+        .message(pull_msg)
+        .messages(applied_payment.messages)
+        .attributes(applied_payment.attributes)
+        .attribute(PAYMENT_ALLOWANCE_PULLED_KEY, &pull.payable_uuid)
+        .attribute(
+            PAYMENT_ALLOWANCE_REMAINING_KEY,
+            allowance.remaining().to_string(),
+        );
+    let exhausted = allowance.remaining().is_zero();
+    if settles_payable || exhausted || allowance.is_expired(allowance.next_pull_at) {
+        remove_payment_allowance(deps.storage, &pull.payable_uuid, &payer);
+        let reason = if settles_payable {
+            "settled"
+        } else if exhausted {
+            "exhausted"
+        } else {
+            "expired"
+        };
+        response = response.attribute(PAYMENT_ALLOWANCE_CLOSED_KEY, reason);
+    } else {
+        save_payment_allowance(deps.storage, &pull.payable_uuid, &allowance)?;
+        response = response.attribute(
+            PAYMENT_ALLOWANCE_NEXT_PULL_KEY,
+            allowance.next_pull_at.seconds().to_string(),
+        );
+    }
+    Ok(response.build())
+}
+
+/// Removes an allowance that can no longer be pulled.  The removal succeeds, rather than failing
+/// the pull, so that keepers clean up dead allowances instead of retrying them.
+fn close_allowance(
+    storage: &mut dyn Storage,
+    payable_uuid: &str,
+    payer: &Addr,
+    reason: &str,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    remove_payment_allowance(storage, payable_uuid, payer);
+    Ok(ResponseBuilder::new()
+        .attribute(PAYMENT_ALLOWANCE_CLOSED_KEY, reason)
+        .attribute(PAYABLE_UUID_KEY, payable_uuid)
+        .attribute(PAYER_KEY, payer.as_str())
+        .build())
+}
+
+fn load_payment_allowance_or_err(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+    payer: &Addr,
+) -> Result<PaymentAllowanceV1, ContractError> {
+    match load_payment_allowance(storage, payable_uuid, payer)? {
+        Some(allowance) => Ok(allowance),
+        None => ContractError::std_err(format!(
+            "no payment allowance from [{}] exists for payable [{}]",
+            payer, payable_uuid,
+        ))
+        .to_result(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PaymentAllowancesResponse, QueryMsg};
+    use crate::execute::payment_allowance::{
+        grant_payment_allowance, pull_allowance_payment_with_util, revoke_payment_allowance,
+        GrantPaymentAllowanceV1, PullAllowancePaymentV1, RevokePaymentAllowanceV1,
+    };
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_DENOM,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::authz::MSG_EXEC_TYPE_URL;
+    use crate::util::constants::{
+        PAYMENT_ALLOWANCE_CLOSED_KEY, PAYMENT_ALLOWANCE_GRANTED_KEY,
+        PAYMENT_ALLOWANCE_NEXT_PULL_KEY, PAYMENT_ALLOWANCE_PULLED_KEY,
+        PAYMENT_ALLOWANCE_REMAINING_KEY, PAYMENT_ALLOWANCE_REVOKED_KEY, PAYMENT_MADE_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, CosmosMsg, Env, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const LARGE_PAYMENT_THRESHOLD: u128 = 500;
+    const PAYER: &str = "payer";
+    const PULL_INTERVAL_SECONDS: u64 = 86400;
+
+    #[test]
+    fn test_grant_payment_allowance_validation() {
+        let mut deps = mock_dependencies(&[]);
+        setup_suite(&mut deps);
+        let error = grant_payment_allowance(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(PAYER, &[coin(1, DEFAULT_PAYABLE_DENOM)]),
+            default_grant(1000, 100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "granting an allowance should not accept funds, but got: {:?}",
+            error,
+        );
+        let error = grant(
+            &mut deps,
+            GrantPaymentAllowanceV1 {
+                denom: "nugget".to_string(),
+                ..default_grant(1000, 100)
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFundsProvided { .. }),
+            "an allowance in a denom the payable does not accept should be rejected, but got: {:?}",
+            error,
+        );
+        let error = grant(&mut deps, default_grant(1000, 600)).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PaymentRequiresCoSign { .. }),
+            "pulls over the large payment threshold should be rejected, but got: {:?}",
+            error,
+        );
+        let error = grant(
+            &mut deps,
+            GrantPaymentAllowanceV1 {
+                expires_at: Some(mock_env().block.time),
+                ..default_grant(1000, 100)
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFields { .. }),
+            "an allowance that expires before its first pull should be rejected, but got: {:?}",
+            error,
+        );
+        assert!(
+            query_allowances(&deps).allowances.is_empty(),
+            "no rejected allowance should be stored",
+        );
+        let response = grant(&mut deps, default_grant(1000, 100)).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_GRANTED_KEY),
+        );
+        assert_eq!(
+            mock_env().block.time.seconds().to_string(),
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_NEXT_PULL_KEY),
+            "an allowance without a first pull time should be due immediately",
+        );
+        let allowances = query_allowances(&deps).allowances;
+        assert_eq!(1, allowances.len());
+        assert_eq!(PAYER, allowances[0].payer.as_str());
+        assert_eq!(Uint128::new(1000), allowances[0].remaining());
+    }
+
+    #[test]
+    fn test_pull_allowance_payment() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_suite(&mut deps);
+        grant(&mut deps, default_grant(700, 400)).unwrap();
+        let response = pull(&mut deps, &provenance_util, mock_env()).unwrap();
+        assert!(
+            matches!(
+                &response.messages.first().unwrap().msg,
+                CosmosMsg::Stargate { type_url, .. } if type_url == MSG_EXEC_TYPE_URL
+            ),
+            "the pull should move the funds from the payer before paying them out",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_MADE_KEY),
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_PULLED_KEY),
+        );
+        assert_eq!(
+            "600",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+        );
+        assert_eq!(
+            "300",
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_REMAINING_KEY),
+        );
+        let error = pull(&mut deps, &provenance_util, mock_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::AllowancePullNotDue { .. }),
+            "a pull should not be made again before its interval elapses, but got: {:?}",
+            error,
+        );
+        // A keeper that misses pulls only makes a single pull when it catches up
+        let response = pull(
+            &mut deps,
+            &provenance_util,
+            env_after(PULL_INTERVAL_SECONDS * 3 + 10),
+        )
+        .unwrap();
+        assert_eq!(
+            "300",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the pull should be capped at the amount remaining in the allowance",
+        );
+        assert_eq!(
+            "exhausted",
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_CLOSED_KEY),
+        );
+        assert!(
+            query_allowances(&deps).allowances.is_empty(),
+            "an exhausted allowance should be removed",
+        );
+    }
+
+    #[test]
+    fn test_pull_allowance_payment_settles_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_suite(&mut deps);
+        grant(&mut deps, default_grant(5000, 500)).unwrap();
+        let response = pull(&mut deps, &provenance_util, mock_env()).unwrap();
+        assert_eq!(
+            (PULL_INTERVAL_SECONDS + mock_env().block.time.seconds()).to_string(),
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_NEXT_PULL_KEY),
+        );
+        let response = pull(
+            &mut deps,
+            &provenance_util,
+            env_after(PULL_INTERVAL_SECONDS),
+        )
+        .unwrap();
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY)
+        );
+        assert_eq!(
+            "settled",
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_CLOSED_KEY),
+        );
+        assert!(
+            query_allowances(&deps).allowances.is_empty(),
+            "an allowance for a paid off payable should be removed",
+        );
+    }
+
+    #[test]
+    fn test_expired_allowance_is_closed_without_pulling() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_suite(&mut deps);
+        let expires_at = mock_env().block.time.plus_seconds(PULL_INTERVAL_SECONDS);
+        grant(
+            &mut deps,
+            GrantPaymentAllowanceV1 {
+                first_pull_at: Some(expires_at.minus_seconds(10)),
+                expires_at: Some(expires_at),
+                ..default_grant(1000, 100)
+            },
+        )
+        .unwrap();
+        let response = pull(
+            &mut deps,
+            &provenance_util,
+            env_after(PULL_INTERVAL_SECONDS),
+        )
+        .unwrap();
+        assert!(
+            response.messages.is_empty(),
+            "no funds should be pulled for an expired allowance",
+        );
+        assert_eq!(
+            "expired",
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_CLOSED_KEY),
+        );
+        assert!(query_allowances(&deps).allowances.is_empty());
+    }
+
+    #[test]
+    fn test_revoke_payment_allowance() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_suite(&mut deps);
+        grant(&mut deps, default_grant(1000, 100)).unwrap();
+        revoke(&mut deps, "stranger")
+            .expect_err("only the payer's own allowance should be revocable");
+        let response = revoke(&mut deps, PAYER).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_ALLOWANCE_REVOKED_KEY),
+        );
+        pull(&mut deps, &provenance_util, mock_env())
+            .expect_err("a revoked allowance should no longer be pulled");
+        revoke(&mut deps, PAYER).expect_err("an allowance should not be revocable twice");
+    }
+
+    fn setup_suite(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(
+            deps,
+            InstArgs {
+                large_payment_threshold: Some(Uint128::new(LARGE_PAYMENT_THRESHOLD)),
+                ..Default::default()
+            },
+        );
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn default_grant(max_total: u128, pull_amount: u128) -> GrantPaymentAllowanceV1 {
+        GrantPaymentAllowanceV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            denom: DEFAULT_PAYABLE_DENOM.to_string(),
+            max_total: Uint128::new(max_total),
+            pull_amount: Uint128::new(pull_amount),
+            pull_interval_seconds: PULL_INTERVAL_SECONDS,
+            first_pull_at: None,
+            expires_at: None,
+        }
+    }
+
+    fn env_after(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+
+    fn grant(
+        deps: &mut MockOwnedDeps,
+        grant: GrantPaymentAllowanceV1,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        grant_payment_allowance(deps.as_mut(), mock_env(), mock_info(PAYER, &[]), grant)
+    }
+
+    fn pull(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        env: Env,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = pull_allowance_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            env,
+            mock_info("keeper", &[]),
+            PullAllowancePaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payer: PAYER.to_string(),
+            },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+
+    fn revoke(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        revoke_payment_allowance(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            RevokePaymentAllowanceV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+    }
+
+    fn query_allowances(deps: &MockOwnedDeps) -> PaymentAllowancesResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPaymentAllowances {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,335 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, load_payment_volume_cap, load_payment_volume_window,
+    remove_payment_volume_window, save_payment_volume_cap, save_payment_volume_window,
+    PaymentVolumeCapV1, PaymentVolumeWindowV1,
+};
+use crate::util::constants::{
+    PAYMENT_VOLUME_CAP_SET_KEY, PAYMENT_VOLUME_MAX_KEY, PAYMENT_VOLUME_RESET_KEY,
+    PAYMENT_VOLUME_WINDOW_KEY,
+};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Storage, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains all relevant fields required in order for the contract admin to cap the payment volume
+/// of a denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetPaymentVolumeCapV1 {
+    pub denom: String,
+    pub max_volume: Uint128,
+    pub window_seconds: u64,
+}
+
+/// Contains all relevant fields required in order for the contract admin to override a tripped
+/// payment volume cap.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ResetPaymentVolumeV1 {
+    pub denom: String,
+}
+
+/// Creates or replaces the payment volume cap for a denom with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the cap in local storage, keyed on the denom.  The volume already observed in the
+///   current window is kept, and is measured against the new cap by the next payment.
+pub fn set_payment_volume_cap(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_cap: SetPaymentVolumeCapV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    save_payment_volume_cap(
+        deps.storage,
+        &set_cap.denom,
+        &PaymentVolumeCapV1 {
+            max_volume: set_cap.max_volume,
+            window_seconds: set_cap.window_seconds,
+        },
+    )?;
+    Ok(ResponseBuilder::new()
+        .attribute(PAYMENT_VOLUME_CAP_SET_KEY, &set_cap.denom)
+        .attribute(PAYMENT_VOLUME_MAX_KEY, set_cap.max_volume.to_string())
+        .attribute(
+            PAYMENT_VOLUME_WINDOW_KEY,
+            set_cap.window_seconds.to_string(),
+        )
+        .build())
+}
+
+/// Overrides the payment volume cap for a denom with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the denom has a payment volume cap.
+/// - Discards the denom's current window, resetting a tripped breaker.  The next payment in the
+///   denom starts a new window.
+pub fn reset_payment_volume(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    reset: ResetPaymentVolumeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    if load_payment_volume_cap(deps.storage, &reset.denom)?.is_none() {
+        return ContractError::std_err(format!(
+            "no payment volume cap exists for denom [{}]",
+            reset.denom
+        ))
+        .to_result();
+    }
+    remove_payment_volume_window(deps.storage, &reset.denom);
+    Ok(ResponseBuilder::new()
+        .attribute(PAYMENT_VOLUME_RESET_KEY, &reset.denom)
+        .build())
+}
+
+/// Adds a payment to the volume of its denom's current cap window, starting a new window if the
+/// previous one has ended.  Payments are rejected while the breaker is tripped.  The payment that
+/// pushes the volume past the cap is accepted but trips the breaker, so that the trip is observable
+/// in its events.  Returns true when this payment tripped the breaker.
+pub(crate) fn track_payment_volume(
+    storage: &mut dyn Storage,
+    env: &Env,
+    payment: &Money,
+) -> Result<bool, ContractError> {
+    let cap = match load_payment_volume_cap(storage, &payment.denom)? {
+        Some(cap) => cap,
+        None => return Ok(false),
+    };
+    let mut window = match load_payment_volume_window(storage, &payment.denom)? {
+        Some(window) if env.block.time < window.window_end(&cap) => window,
+        _ => PaymentVolumeWindowV1 {
+            window_start: env.block.time,
+            volume: Uint128::zero(),
+            is_tripped: false,
+        },
+    };
+    if window.is_tripped {
+        return ContractError::PaymentVolumeCapExceeded {
+            denom: payment.denom.clone(),
+            window_end: window.window_end(&cap),
+        }
+        .to_result();
+    }
+    window.volume += payment.amount;
+    window.is_tripped = window.volume > cap.max_volume;
+    save_payment_volume_window(storage, &payment.denom, &window)?;
+    Ok(window.is_tripped)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_payment_volume_cap, PaymentVolumeCapV1};
+    use crate::execute::payment_volume_cap::{
+        reset_payment_volume, set_payment_volume_cap, ResetPaymentVolumeV1, SetPaymentVolumeCapV1,
+    };
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_PAYABLE_DENOM,
+    };
+    use crate::util::constants::{
+        PAYMENT_VOLUME_CAP_SET_KEY, PAYMENT_VOLUME_CAP_TRIPPED_KEY, PAYMENT_VOLUME_MAX_KEY,
+        PAYMENT_VOLUME_RESET_KEY, PAYMENT_VOLUME_WINDOW_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const WINDOW_SECONDS: u64 = 86400;
+
+    #[test]
+    fn test_set_payment_volume_cap() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = set_cap(&mut deps, 300).expect("the admin should be able to set a cap");
+        assert_eq!(
+            DEFAULT_PAYABLE_DENOM,
+            single_attribute_for_key(&response, PAYMENT_VOLUME_CAP_SET_KEY),
+        );
+        assert_eq!(
+            "300",
+            single_attribute_for_key(&response, PAYMENT_VOLUME_MAX_KEY)
+        );
+        assert_eq!(
+            WINDOW_SECONDS.to_string(),
+            single_attribute_for_key(&response, PAYMENT_VOLUME_WINDOW_KEY),
+        );
+        assert_eq!(
+            Some(PaymentVolumeCapV1 {
+                max_volume: Uint128::new(300),
+                window_seconds: WINDOW_SECONDS,
+            }),
+            load_payment_volume_cap(deps.as_ref().storage, DEFAULT_PAYABLE_DENOM).unwrap(),
+            "the cap should be stored for its denom",
+        );
+    }
+
+    #[test]
+    fn test_payment_volume_cap_trips_breaker() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_payable(&mut deps);
+        set_cap(&mut deps, 300).unwrap();
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(300),
+        )
+        .expect("a payment reaching the cap should be accepted");
+        assert!(
+            !response
+                .attributes
+                .iter()
+                .any(|attribute| attribute.key == PAYMENT_VOLUME_CAP_TRIPPED_KEY),
+            "reaching the cap without exceeding it should not trip the breaker",
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("the payment exceeding the cap should be accepted");
+        assert_eq!(
+            DEFAULT_PAYABLE_DENOM,
+            single_attribute_for_key(&response, PAYMENT_VOLUME_CAP_TRIPPED_KEY),
+            "the payment exceeding the cap should trip the breaker",
+        );
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::PaymentVolumeCapExceeded { denom, window_end } => {
+                assert_eq!(DEFAULT_PAYABLE_DENOM, denom);
+                assert_eq!(
+                    mock_env().block.time.plus_seconds(WINDOW_SECONDS),
+                    window_end
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(WINDOW_SECONDS);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: later_env,
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .expect("payments should resume once the window rolls over");
+    }
+
+    #[test]
+    fn test_reset_payment_volume() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_payable(&mut deps);
+        let error = reset(&mut deps, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "a denom without a cap should not be reset, but got: {:?}",
+            error,
+        );
+        set_cap(&mut deps, 100).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .unwrap();
+        let error = reset(&mut deps, "not-the-admin").unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to reset the breaker, but got: {:?}",
+            error,
+        );
+        let response = reset(&mut deps, DEFAULT_INFO_NAME).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_DENOM,
+            single_attribute_for_key(&response, PAYMENT_VOLUME_RESET_KEY),
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("payments should resume once the admin resets the breaker");
+    }
+
+    #[test]
+    fn test_set_payment_volume_cap_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = set_payment_volume_cap(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            default_set_cap(300),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to set a cap, but got: {:?}",
+            error,
+        );
+        let error = set_payment_volume_cap(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            default_set_cap(300),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    fn setup_approved_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn default_set_cap(max_volume: u128) -> SetPaymentVolumeCapV1 {
+        SetPaymentVolumeCapV1 {
+            denom: DEFAULT_PAYABLE_DENOM.to_string(),
+            max_volume: Uint128::new(max_volume),
+            window_seconds: WINDOW_SECONDS,
+        }
+    }
+
+    fn set_cap(
+        deps: &mut MockOwnedDeps,
+        max_volume: u128,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        set_payment_volume_cap(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            default_set_cap(max_volume),
+        )
+    }
+
+    fn reset(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        reset_payment_volume(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            ResetPaymentVolumeV1 {
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+            },
+        )
+    }
+}
@@ -0,0 +1,577 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, has_quarantined_payments, load_escrowed_payments, load_pending_cancellation,
+    load_proposed_payment, remove_escrowed_payment, remove_payment_batch,
+    save_pending_cancellation, update_payable_timeline, PayableScopeAttribute,
+    PendingCancellationV1, CANCELLATION_CLAIM_PERIOD_SECONDS,
+};
+use crate::execute::escrow_payment::{load_escrowed_payment_or_err, return_escrowed_payment};
+use crate::execute::expire_payable::remove_payable_local_storage;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    CANCELLATION_FINALIZABLE_AT_KEY, CANCELLATION_REFUND_CLAIMED_KEY,
+    CANCELLATION_REFUND_COUNT_KEY, PAYABLE_CANCELLATION_PENDING_KEY, PAYABLE_CANCELLED_KEY,
+    PAYER_KEY, PAYMENT_AMOUNT_KEY, PAYMENT_ID_KEY, SCOPE_ID_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the escrowed payment that its payer would like returned from a payable that is pending
+/// cancellation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimCancellationRefundV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+}
+
+/// Contains the payable whose pending cancellation the sender would like to finalize.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FinalizeCancellationV1 {
+    pub payable_uuid: String,
+}
+
+/// Starts the cancellation of a payable that its oracle approved and that holds its payments in
+/// escrow.  The payable is not removed immediately: payments, releases and nettings are halted, and
+/// its payers are given the claim period to take back their escrowed payments before anyone may
+/// finalize the cancellation.  Payables holding quarantined or proposed payments must have those
+/// resolved first, as they are not part of the escrow ledger.
+pub(crate) fn start_pending_cancellation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    scope_attribute: &PayableScopeAttribute,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let payable_uuid = scope_attribute.payable_uuid.as_str();
+    let invalid_reason = if load_pending_cancellation(storage, payable_uuid)?.is_some() {
+        Some("the payable is already pending cancellation")
+    } else if has_quarantined_payments(storage, payable_uuid) {
+        Some("payables with quarantined payments cannot be cancelled")
+    } else if load_proposed_payment(storage, payable_uuid)?.is_some() {
+        Some("payables with proposed payments cannot be cancelled")
+    } else {
+        None
+    };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid.clone(),
+            invalid_reason: invalid_reason.into(),
+        }
+        .to_result();
+    }
+    let finalizable_at = env
+        .block
+        .time
+        .plus_seconds(CANCELLATION_CLAIM_PERIOD_SECONDS);
+    save_pending_cancellation(
+        storage,
+        payable_uuid,
+        &PendingCancellationV1 {
+            requested_by: sender.clone(),
+            requested_at: env.block.time,
+            finalizable_at,
+        },
+    )?;
+    Ok(ResponseBuilder::for_payable(scope_attribute)
+        .notify_subscribers(storage, payable_uuid)?
+        .attribute(PAYABLE_CANCELLATION_PENDING_KEY, payable_uuid)
+        .attribute(CANCELLATION_FINALIZABLE_AT_KEY, finalizable_at.to_string())
+        .attribute(SCOPE_ID_KEY, &scope_attribute.scope_id)
+        .build())
+}
+
+/// Parent function path for the contract to claim a cancellation refund.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn claim_cancellation_refund(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    claim: ClaimCancellationRefundV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    claim_cancellation_refund_with_util(deps, &ProvenanceUtilImpl, env, info, claim)
+}
+
+/// Returns an escrowed payment to its payer from a payable that is pending cancellation with the
+/// following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered and is pending cancellation.  A payable
+///   whose cancellation was finalized has already returned every unclaimed payment.
+/// - Ensures that the payment is still held in escrow, and that the sender is its payer.  Unlike a
+///   reclaim, the payment's escrow timeout does not need to have passed.
+/// - Removes the payment from escrow and sends it back to the payer.
+/// - Adds the payment back to the payable's remaining owed amount and rewrites the scope attribute,
+///   as the payee never received it.
+pub fn claim_cancellation_refund_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    claim: ClaimCancellationRefundV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &claim.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: claim.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if load_pending_cancellation(deps.storage, &claim.payable_uuid)?.is_none() {
+        return ContractError::InvalidPayable {
+            payable_uuid: claim.payable_uuid,
+            invalid_reason: "refunds can only be claimed while the payable is pending cancellation"
+                .into(),
+        }
+        .to_result();
+    }
+    let escrowed_payment =
+        load_escrowed_payment_or_err(deps.storage, &claim.payable_uuid, claim.payment_id)?;
+    if info.sender != escrowed_payment.payer {
+        return Err(ContractError::Unauthorized);
+    }
+    let messages = return_escrowed_payment(
+        deps.branch(),
+        provenance_util,
+        &state.contract_name,
+        &mut scope_attribute,
+        claim.payment_id,
+        &escrowed_payment,
+        &env.block,
+    )?;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .messages(messages)
+        .attribute(
+            CANCELLATION_REFUND_CLAIMED_KEY,
+            &scope_attribute.payable_uuid,
+        )
+        .attribute(PAYMENT_ID_KEY, claim.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, escrowed_payment.amount.to_string())
+        .attribute(PAYER_KEY, escrowed_payment.payer.as_str())
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .build())
+}
+
+/// Completes the cancellation of a payable once its claim period has passed with the following
+/// steps:
+/// - Verifies that no funds were sent.  Any sender may finalize a cancellation.
+/// - Ensures that the payable targeted has been registered and is pending cancellation, and that
+///   its claim period has passed.
+/// - Removes every escrowed payment that its payer did not claim from escrow and sends it back to
+///   its payer.  The payable is being removed, so its remaining owed amount is not rewritten.
+/// - Deletes the payable's attribute from its scope and removes its local storage, recording the
+///   cancellation time on its timeline, which is kept.  Its payment history is retained for payment
+///   exports.
+pub fn finalize_cancellation(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    finalize: FinalizeCancellationV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &finalize.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: finalize.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    let payable_uuid = scope_attribute.payable_uuid.as_str();
+    let pending_cancellation = match load_pending_cancellation(deps.storage, payable_uuid)? {
+        Some(pending_cancellation) => pending_cancellation,
+        None => {
+            return ContractError::InvalidPayable {
+                payable_uuid: scope_attribute.payable_uuid,
+                invalid_reason: "the payable is not pending cancellation".into(),
+            }
+            .to_result();
+        }
+    };
+    if env.block.time < pending_cancellation.finalizable_at {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: format!(
+                "the cancellation cannot be finalized until {}",
+                pending_cancellation.finalizable_at,
+            ),
+        }
+        .to_result();
+    }
+    let mut response = ResponseBuilder::for_payable(&scope_attribute);
+    let escrowed_payments = load_escrowed_payments(deps.storage, payable_uuid)?;
+    for (payment_id, escrowed_payment) in escrowed_payments.iter() {
+        remove_escrowed_payment(
+            deps.storage,
+            &scope_attribute.payable_type,
+            payable_uuid,
+            *payment_id,
+        )?;
+        response = response.message(
+            Money::new(escrowed_payment.amount, &escrowed_payment.denom)
+                .send_to(&escrowed_payment.payer),
+        );
+    }
+    // Subscribers are notified of the cancellation before their subscriptions are cleared
+    let response = response.notify_subscribers(deps.storage, payable_uuid)?;
+    remove_payment_batch(deps.storage, payable_uuid);
+    remove_payable_local_storage(deps.storage, payable_uuid)?;
+    update_payable_timeline(deps.storage, payable_uuid, |timeline| {
+        timeline.cancelled_at = Some(env.block.time);
+    })?;
+    Ok(response
+        .message(
+            state
+                .provenance_msg_factory()
+                .delete_scope_attributes(&scope_attribute.scope_id, &state.contract_name)?,
+        )
+        .attribute(PAYABLE_CANCELLED_KEY, payable_uuid)
+        .attribute(SCOPE_ID_KEY, &scope_attribute.scope_id)
+        .attribute(
+            CANCELLATION_REFUND_COUNT_KEY,
+            escrowed_payments.len().to_string(),
+        )
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_escrow_totals, load_payable_timeline, payable_meta_storage_read_v2,
+        CANCELLATION_CLAIM_PERIOD_SECONDS,
+    };
+    use crate::execute::cancel_payable::{cancel_payable_with_util, CancelPayableV1};
+    use crate::execute::escrow_payment::{release_payment_with_util, ReleasePaymentV1};
+    use crate::execute::pending_cancellation::{
+        claim_cancellation_refund_with_util, finalize_cancellation, ClaimCancellationRefundV1,
+        FinalizeCancellationV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        CANCELLATION_FINALIZABLE_AT_KEY, CANCELLATION_REFUND_CLAIMED_KEY,
+        CANCELLATION_REFUND_COUNT_KEY, PAYABLE_CANCELLATION_PENDING_KEY, PAYABLE_CANCELLED_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Env, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const ESCROW_TIMEOUT_SECONDS: u64 = 3600;
+
+    #[test]
+    fn test_cancel_approved_payable_starts_pending_cancellation() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        let response = cancel(&mut deps, &provenance_util)
+            .expect("an approved payable that escrows its payments should be cancellable");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_CANCELLATION_PENDING_KEY),
+        );
+        assert_eq!(
+            mock_env()
+                .block
+                .time
+                .plus_seconds(CANCELLATION_CLAIM_PERIOD_SECONDS)
+                .to_string(),
+            single_attribute_for_key(&response, CANCELLATION_FINALIZABLE_AT_KEY),
+        );
+        assert!(
+            response.messages.is_empty(),
+            "no funds should move and the attribute should be kept until the cancellation is finalized",
+        );
+        query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+            .expect("the payable should remain registered while its cancellation is pending");
+        let error = cancel(&mut deps, &provenance_util).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a pending cancellation should not be restarted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_pending_cancellation_halts_payments_and_releases() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        cancel(&mut deps, &provenance_util).unwrap();
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotReadyForPayment { .. }),
+            "a payment should not be accepted while a refund could be claimed, but got: {:?}",
+            error,
+        );
+        let error = release_payment_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+            ReleasePaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id: 0,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payment should not be released while its payer may claim it back, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_claim_cancellation_refund() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        let error = claim(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "refunds should not be claimable without a pending cancellation, but got: {:?}",
+            error,
+        );
+        cancel(&mut deps, &provenance_util).unwrap();
+        let error = claim(&mut deps, &provenance_util, "some-random-guy", 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the payer should be able to claim a refund, but got: {:?}",
+            error,
+        );
+        let response = claim(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 0)
+            .expect("the payer should be able to claim a refund before the escrow timeout");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, CANCELLATION_REFUND_CLAIMED_KEY),
+        );
+        assert_eq!(
+            "1000",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the refunded payment should be owed again",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(400, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the payment should be returned to the payer",
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        assert_eq!(
+            1000,
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .payable_remaining_owed
+                .u128(),
+        );
+        let error = claim(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "a refunded payment should no longer be held in escrow, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_finalize_cancellation_refunds_unclaimed_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(300),
+        )
+        .unwrap();
+        let error = finalize(&mut deps, mock_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payable without a pending cancellation should not be finalized, but got: {:?}",
+            error,
+        );
+        cancel(&mut deps, &provenance_util).unwrap();
+        claim(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 0).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let mut early_env = mock_env();
+        early_env.block.time = early_env
+            .block
+            .time
+            .plus_seconds(CANCELLATION_CLAIM_PERIOD_SECONDS - 1);
+        let error = finalize(&mut deps, early_env).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "the cancellation should not be finalized during its claim period, but got: {:?}",
+            error,
+        );
+        let mut later_env = mock_env();
+        later_env.block.time = later_env
+            .block
+            .time
+            .plus_seconds(CANCELLATION_CLAIM_PERIOD_SECONDS);
+        let response = finalize(&mut deps, later_env.clone()).expect(
+            "any sender should be able to finalize the cancellation after its claim period",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_CANCELLED_KEY),
+        );
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, CANCELLATION_REFUND_COUNT_KEY),
+            "only the unclaimed payment should be refunded on finalization",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(300, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the unclaimed payment should be returned to its payer",
+        );
+        assert!(
+            load_escrow_totals(deps.as_ref().storage, None)
+                .unwrap()
+                .is_empty(),
+            "the refunded payments should be deducted from the escrow totals",
+        );
+        assert!(
+            payable_meta_storage_read_v2(deps.as_ref().storage)
+                .may_load(DEFAULT_PAYABLE_UUID.as_bytes())
+                .unwrap()
+                .is_none(),
+            "the payable meta should be removed",
+        );
+        assert_eq!(
+            Some(later_env.block.time),
+            load_payable_timeline(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expect("the timeline should be kept after the cancellation")
+                .cancelled_at,
+        );
+        let error = claim(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 1).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "refunds should not be claimable after the cancellation is finalized, but got: {:?}",
+            error,
+        );
+    }
+
+    fn setup_escrowed_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(
+            deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    escrow_timeout_seconds: Some(ESCROW_TIMEOUT_SECONDS),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn cancel(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        cancel_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            CancelPayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+    }
+
+    fn claim(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        payment_id: u64,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        claim_cancellation_refund_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            ClaimCancellationRefundV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id,
+            },
+        )
+    }
+
+    fn finalize(
+        deps: &mut MockOwnedDeps,
+        env: Env,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        finalize_cancellation(
+            deps.as_mut(),
+            env,
+            mock_info("some-random-guy", &[]),
+            FinalizeCancellationV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+    }
+}
@@ -0,0 +1,408 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, load_payable_risk_band, load_proposed_payment, remove_proposed_payment,
+    save_proposed_payment, ProposedPaymentV1,
+};
+use crate::execute::make_payment::{
+    apply_payment, load_payable_for_payment, payment_from_funds, PaymentContext, PaymentDetails,
+};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    PAYABLE_UUID_KEY, PAYER_KEY, PAYMENT_AMOUNT_KEY, PAYMENT_PROPOSED_KEY,
+    PROPOSED_PAYMENT_APPROVED_KEY, PROPOSED_PAYMENT_CANCELLED_KEY,
+};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that the sender would like to make a large payment against, pending its
+/// oracle's approval.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposePaymentV1 {
+    pub payable_uuid: String,
+    pub memo: Option<String>,
+    pub on_behalf_of: Option<String>,
+}
+
+/// Contains the payable whose proposed payment the oracle would like to send to the payee.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovePaymentV1 {
+    pub payable_uuid: String,
+}
+
+/// Contains the payable whose proposed payment should be returned to its payer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CancelProposedPaymentV1 {
+    pub payable_uuid: String,
+}
+
+/// Holds a payment larger than the contract's large payment threshold until the payable's oracle
+/// approves it, with the following steps:
+/// - Ensures that the contract is not paused.
+/// - Verifies that the payable has been registered and approved by its oracle, and that it does
+///   not already have a proposed payment awaiting approval.
+/// - Ensures that the payable's risk band, or the contract, has a large payment threshold.
+/// - Verifies that all funds provided are in a single denomination accepted by the payable.
+/// - Verifies that the funds provided exceed the large payment threshold, as smaller payments are
+///   made directly, and that they do not exceed the remaining owed amount.
+/// - Holds the funds in the contract until the oracle approves the payment, or until the payer or
+///   oracle cancels it.  The payment is not applied to the payable until it is approved.
+pub fn propose_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    propose: ProposePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let on_behalf_of = propose
+        .on_behalf_of
+        .map(|client| deps.api.addr_validate(&client))
+        .transpose()?;
+    let scope_attribute = load_payable_for_payment(&deps.as_ref(), propose.payable_uuid)?;
+    // A threshold set on the payable's risk band takes precedence over the contract's threshold
+    let large_payment_threshold = match load_payable_risk_band(deps.storage, &scope_attribute)?
+        .and_then(|band| band.large_payment_threshold)
+        .or(state.large_payment_threshold)
+    {
+        Some(large_payment_threshold) => large_payment_threshold,
+        None => return ContractError::std_err("payment co-signing is disabled").to_result(),
+    };
+    if load_proposed_payment(deps.storage, &scope_attribute.payable_uuid)?.is_some() {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "a proposed payment is already awaiting oracle approval".into(),
+        }
+        .to_result();
+    }
+    let payment = payment_from_funds(&scope_attribute, &info.funds)?;
+    if payment.amount <= large_payment_threshold {
+        return ContractError::std_err(format!(
+            "payments of at most [{}] do not require oracle approval and must be made directly",
+            large_payment_threshold,
+        ))
+        .to_result();
+    }
+    let remaining_owed = scope_attribute.payable_remaining_owed
+        + scope_attribute.late_fees_due(env.block.time)?
+        + scope_attribute.interest_due(env.block.time)?;
+    if payment.amount > remaining_owed {
+        return Err(ContractError::PaymentTooLarge {
+            total_owed: remaining_owed.u128(),
+            amount_provided: payment.amount.u128(),
+        });
+    }
+    save_proposed_payment(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+        &ProposedPaymentV1 {
+            payer: info.sender.clone(),
+            amount: payment.amount,
+            denom: payment.denom.clone(),
+            memo: propose.memo,
+            on_behalf_of,
+            proposed_at: env.block.time,
+        },
+    )?;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .attribute(PAYMENT_PROPOSED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PAYMENT_AMOUNT_KEY, payment.amount.to_string())
+        .attribute(PAYER_KEY, info.sender.as_str())
+        .build())
+}
+
+/// Parent function path for the contract to approve a proposed payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn approve_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    approve: ApprovePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    approve_payment_with_util(deps, &ProvenanceUtilImpl, env, info, approve)
+}
+
+/// Co-signs a proposed payment with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the contract is not paused.
+/// - Ensures that the payable has a proposed payment awaiting approval, and that the payable is
+///   still ready for payment.
+/// - Verifies that the sender is the payable's oracle.
+/// - Removes the proposal and applies the held funds exactly as a direct payment from the payer
+///   would be applied, sending them on to the payee.  If the payment can no longer be applied, the
+///   approval fails and the proposal remains until it is cancelled.
+pub fn approve_payment_with_util<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    approve: ApprovePaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let proposed_payment = load_proposed_payment_or_err(deps.storage, &approve.payable_uuid)?;
+    let scope_attribute = load_payable_for_payment(&deps.as_ref(), approve.payable_uuid)?;
+    if info.sender != scope_attribute.oracle_address {
+        return Err(ContractError::Unauthorized);
+    }
+    remove_proposed_payment(deps.storage, &scope_attribute.payable_uuid);
+    let payable_uuid = scope_attribute.payable_uuid.clone();
+    let applied_payment = apply_payment(
+        deps,
+        provenance_util,
+        &env,
+        &PaymentContext {
+            state: &state,
+            payer: &proposed_payment.payer,
+            details: PaymentDetails {
+                memo: proposed_payment.memo.as_deref(),
+                on_behalf_of: proposed_payment.on_behalf_of.as_ref(),
+                co_signed: true,
+            },
+        },
+        scope_attribute,
+        Money::new(proposed_payment.amount, &proposed_payment.denom),
+    )?;
+    Ok(ResponseBuilder::new()
+        .messages(applied_payment.messages)
+        .attributes(applied_payment.attributes)
+        .attribute(PROPOSED_PAYMENT_APPROVED_KEY, payable_uuid)
+        .build())
+}
+
+/// Returns a proposed payment to its payer with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable has a proposed payment awaiting approval.
+/// - Verifies that the sender is the payer of the proposed payment, or the payable's oracle.
+/// - Removes the proposal and sends the held funds back to the payer.
+pub fn cancel_proposed_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    cancel: CancelProposedPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let proposed_payment = load_proposed_payment_or_err(deps.storage, &cancel.payable_uuid)?;
+    // The payer may always recover their funds, even if the payable can no longer be found
+    if info.sender != proposed_payment.payer
+        && !query_payable_attribute_by_uuid(&deps.as_ref(), &cancel.payable_uuid)
+            .is_ok_and(|attr| attr.oracle_address == info.sender)
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    remove_proposed_payment(deps.storage, &cancel.payable_uuid);
+    let payment = Money::new(proposed_payment.amount, &proposed_payment.denom);
+    Ok(ResponseBuilder::new()
+        .message(payment.send_to(&proposed_payment.payer))
+        .attribute(PROPOSED_PAYMENT_CANCELLED_KEY, &cancel.payable_uuid)
+        .attribute(PAYABLE_UUID_KEY, &cancel.payable_uuid)
+        .attribute(PAYMENT_AMOUNT_KEY, payment.amount.to_string())
+        .attribute(PAYER_KEY, proposed_payment.payer.as_str())
+        .build())
+}
+
+fn load_proposed_payment_or_err(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> Result<ProposedPaymentV1, ContractError> {
+    match load_proposed_payment(storage, payable_uuid)? {
+        Some(proposed_payment) => Ok(proposed_payment),
+        None => ContractError::std_err(format!(
+            "no proposed payment exists for payable [{}]",
+            payable_uuid,
+        ))
+        .to_result(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::propose_payment::{
+        approve_payment_with_util, cancel_proposed_payment, propose_payment, ApprovePaymentV1,
+        CancelProposedPaymentV1, ProposePaymentV1,
+    };
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        PAYMENT_MADE_KEY, PAYMENT_PROPOSED_KEY, PROPOSED_PAYMENT_APPROVED_KEY,
+        PROPOSED_PAYMENT_CANCELLED_KEY, TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const LARGE_PAYMENT_THRESHOLD: u128 = 500;
+    const PAYER: &str = "payer";
+
+    #[test]
+    fn test_large_payment_requires_oracle_approval() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_suite(&mut deps);
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender(PAYER, 600, DEFAULT_PAYABLE_DENOM),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::PaymentRequiresCoSign { .. }),
+            "a direct payment over the threshold should be rejected, but got: {:?}",
+            error,
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender(PAYER, 100, DEFAULT_PAYABLE_DENOM),
+        )
+        .expect("a payment under the threshold should be made directly");
+        let response = propose(&mut deps, 600).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_PROPOSED_KEY),
+        );
+        assert!(
+            response.messages.is_empty(),
+            "the proposed funds should be held by the contract",
+        );
+        let error = approve(&mut deps, &provenance_util, PAYER).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the oracle should be able to approve a proposed payment, but got: {:?}",
+            error,
+        );
+        let response = approve(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PROPOSED_PAYMENT_APPROVED_KEY),
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYMENT_MADE_KEY),
+        );
+        assert_eq!(
+            "300",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(600, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the approved payment should be sent to the payee",
+        );
+        approve(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS)
+            .expect_err("an approved proposal should not be approvable twice");
+    }
+
+    #[test]
+    fn test_cancel_proposed_payment_refunds_payer() {
+        let mut deps = mock_dependencies(&[]);
+        setup_suite(&mut deps);
+        propose(&mut deps, LARGE_PAYMENT_THRESHOLD)
+            .expect_err("a payment at the threshold should be made directly");
+        propose(&mut deps, 600).unwrap();
+        let error = propose(&mut deps, 700).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payable should only hold one proposed payment, but got: {:?}",
+            error,
+        );
+        let error = cancel(&mut deps, "stranger").unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the payer or oracle should be able to cancel a proposal, but got: {:?}",
+            error,
+        );
+        let response = cancel(&mut deps, PAYER).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PROPOSED_PAYMENT_CANCELLED_KEY),
+        );
+        assert_eq!(
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: PAYER.to_string(),
+                amount: vec![coin(600, DEFAULT_PAYABLE_DENOM)],
+            }),
+            response.messages.first().unwrap().msg,
+            "the proposed funds should be returned to the payer",
+        );
+        propose(&mut deps, 600).expect("a new payment can be proposed after a cancellation");
+    }
+
+    fn setup_suite(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(
+            deps,
+            InstArgs {
+                large_payment_threshold: Some(Uint128::new(LARGE_PAYMENT_THRESHOLD)),
+                ..Default::default()
+            },
+        );
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn propose(
+        deps: &mut MockOwnedDeps,
+        amount: u128,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        propose_payment(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(PAYER, &[coin(amount, DEFAULT_PAYABLE_DENOM)]),
+            ProposePaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                memo: None,
+                on_behalf_of: None,
+            },
+        )
+    }
+
+    fn approve(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = approve_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            ApprovePaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+
+    fn cancel(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        cancel_proposed_payment(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            CancelProposedPaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+    }
+}
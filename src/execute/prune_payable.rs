@@ -0,0 +1,230 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, has_escrowed_payments, has_quarantined_payments, load_proposed_payment,
+    remove_payable_timeline, remove_payment_batch,
+};
+use crate::execute::expire_payable::remove_payable_local_storage;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{PAYABLE_PRUNED_KEY, SCOPE_ID_KEY, SETTLED_AT_KEY};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the settled payable that the sender would like to have removed from the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PrunePayableV1 {
+    pub payable_uuid: String,
+}
+
+/// Removes a settled payable from the contract with the following steps:
+/// - Verifies that no funds were sent.  Any sender may prune a payable.
+/// - Ensures that the contract has a settled retention window configured.
+/// - Ensures that the payable targeted has been registered and has been paid off.
+/// - Ensures that the retention window has passed since the payable was settled.
+/// - Ensures that the payable is not under dispute and that the contract holds no escrowed,
+///   quarantined or proposed payments for it.
+/// - Deletes the payable's attribute from its scope.
+/// - Removes the payable's meta and all other local storage, except for its payment history, which
+///   is retained for payment exports.
+pub fn prune_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    prune: PrunePayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let retention_seconds = match state.settled_retention_seconds {
+        Some(retention_seconds) => retention_seconds,
+        None => return ContractError::std_err("settled payable pruning is disabled").to_result(),
+    };
+    let scope_attribute = match query_payable_attribute_by_uuid(&deps.as_ref(), &prune.payable_uuid)
+    {
+        Ok(attr) => attr,
+        Err(_) => {
+            return ContractError::PayableNotFound {
+                payable_uuid: prune.payable_uuid,
+            }
+            .to_result();
+        }
+    };
+    let settled_at = match scope_attribute.settled_at {
+        Some(settled_at) => settled_at,
+        None => {
+            return ContractError::InvalidPayable {
+                payable_uuid: scope_attribute.payable_uuid,
+                invalid_reason: "only settled payables can be pruned".into(),
+            }
+            .to_result();
+        }
+    };
+    let prunable_at = settled_at.plus_seconds(retention_seconds);
+    let invalid_reason = if env.block.time < prunable_at {
+        Some(format!(
+            "settled payables cannot be pruned until {}",
+            prunable_at
+        ))
+    } else if scope_attribute.dispute.is_some() {
+        Some("disputed payables cannot be pruned".to_string())
+    } else if has_escrowed_payments(deps.storage, &scope_attribute.payable_uuid) {
+        Some("payables with escrowed payments cannot be pruned".to_string())
+    } else if has_quarantined_payments(deps.storage, &scope_attribute.payable_uuid) {
+        Some("payables with quarantined payments cannot be pruned".to_string())
+    } else if load_proposed_payment(deps.storage, &scope_attribute.payable_uuid)?.is_some() {
+        Some("payables with proposed payments cannot be pruned".to_string())
+    } else {
+        None
+    };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason,
+        }
+        .to_result();
+    }
+    // Subscribers are notified of the pruning before their subscriptions are cleared
+    let response = ResponseBuilder::for_payable(&scope_attribute)
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?;
+    remove_payment_batch(deps.storage, &scope_attribute.payable_uuid);
+    remove_payable_local_storage(deps.storage, &scope_attribute.payable_uuid)?;
+    remove_payable_timeline(deps.storage, &scope_attribute.payable_uuid);
+    Ok(response
+        .message(
+            state
+                .provenance_msg_factory()
+                .delete_scope_attributes(&scope_attribute.scope_id, &state.contract_name)?,
+        )
+        .attribute(PAYABLE_PRUNED_KEY, &scope_attribute.payable_uuid)
+        .attribute(SCOPE_ID_KEY, &scope_attribute.scope_id)
+        .attribute(SETTLED_AT_KEY, settled_at.seconds().to_string())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_payment_history, payable_meta_storage_read_v2};
+    use crate::execute::prune_payable::{prune_payable, PrunePayableV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_TOTAL,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{PAYABLE_PRUNED_KEY, PAYABLE_SETTLED_KEY};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Env, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const RETENTION_SECONDS: u64 = 86_400;
+
+    #[test]
+    fn test_prune_settled_payable_after_retention() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                settled_retention_seconds: Some(RETENTION_SECONDS),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let error = prune(&mut deps, mock_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "an unpaid payable should not be prunable, but got: {:?}",
+            error,
+        );
+        let payment_response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL),
+        )
+        .unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&payment_response, PAYABLE_SETTLED_KEY),
+            "the final payment should settle the payable",
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        assert_eq!(
+            Some(mock_env().block.time),
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .settled_at,
+        );
+        let error = prune(&mut deps, env_after_settlement(RETENTION_SECONDS - 1)).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a payable should not be prunable within the retention window, but got: {:?}",
+            error,
+        );
+        let response = prune(&mut deps, env_after_settlement(RETENTION_SECONDS)).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_PRUNED_KEY),
+        );
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "expected a single message to delete the scope attribute",
+        );
+        assert!(payable_meta_storage_read_v2(deps.as_ref().storage)
+            .may_load(DEFAULT_PAYABLE_UUID.as_bytes())
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            1,
+            load_payment_history(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .len(),
+            "the payment history should be retained",
+        );
+    }
+
+    #[test]
+    fn test_prune_payable_requires_retention_window() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(DEFAULT_PAYABLE_TOTAL),
+        )
+        .unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let error = prune(&mut deps, env_after_settlement(RETENTION_SECONDS)).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "pruning should be rejected without a retention window, but got: {:?}",
+            error,
+        );
+    }
+
+    fn prune(deps: &mut MockOwnedDeps, env: Env) -> Result<Response<ProvenanceMsg>, ContractError> {
+        prune_payable(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            PrunePayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+    }
+
+    fn env_after_settlement(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+}
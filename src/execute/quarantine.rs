@@ -0,0 +1,522 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::payee_resolver::{
+    payout_to_payees, resolve_payment_destination, PaymentDestination,
+};
+use crate::core::state::{
+    config_read_v2, load_payment_record, load_quarantined_payment, record_payment_payee_splits,
+    remove_quarantine_config, remove_quarantined_payment, save_quarantine_config,
+    PayableScopeAttribute, QuarantineConfigV1, QuarantinedPaymentV1,
+};
+#[cfg(feature = "escrow")]
+use crate::core::state::{save_escrowed_payment, EscrowedPaymentV1};
+use crate::execute::flag_erroneous_payment::restore_payment;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+#[cfg(feature = "escrow")]
+use crate::util::constants::PAYMENT_ESCROWED_KEY;
+use crate::util::constants::{
+    PAYEE_KEY, PAYER_KEY, PAYMENT_AMOUNT_KEY, PAYMENT_ID_KEY, QUARANTINED_PAYMENT_REFUNDED_KEY,
+    QUARANTINED_PAYMENT_RELEASED_KEY, QUARANTINE_CONFIG_SET_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Coin, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the heuristics that the contract admin would like suspicious payments screened with.
+/// Providing no flagged payers and no thresholds disables quarantine.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetQuarantineConfigV1 {
+    pub flagged_payers: Vec<String>,
+    pub large_payment_thresholds: Vec<Coin>,
+}
+
+/// Contains the quarantined payment that the contract admin would like sent on to the payee.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReleaseQuarantinedPaymentV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+}
+
+/// Contains the quarantined payment that the contract admin would like returned to its payer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RefundQuarantinedPaymentV1 {
+    pub payable_uuid: String,
+    pub payment_id: u64,
+}
+
+/// Replaces the contract's quarantine heuristics with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that each flagged payer is a valid address.
+/// - Stores the heuristics in local storage, or removes them if none were provided.  Payments that
+///   are already quarantined are unaffected.
+pub fn set_quarantine_config(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_config: SetQuarantineConfigV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    let is_enabled =
+        !set_config.flagged_payers.is_empty() || !set_config.large_payment_thresholds.is_empty();
+    if is_enabled {
+        let flagged_payers = set_config
+            .flagged_payers
+            .iter()
+            .map(|payer| deps.api.addr_validate(payer))
+            .collect::<StdResult<Vec<_>>>()?;
+        save_quarantine_config(
+            deps.storage,
+            &QuarantineConfigV1 {
+                flagged_payers,
+                large_payment_thresholds: set_config.large_payment_thresholds,
+            },
+        )?;
+    } else {
+        remove_quarantine_config(deps.storage);
+    }
+    Ok(ResponseBuilder::new()
+        .attribute(QUARANTINE_CONFIG_SET_KEY, is_enabled.to_string())
+        .build())
+}
+
+/// Parent function path for the contract to release a quarantined payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn release_quarantined_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    release: ReleaseQuarantinedPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    release_quarantined_payment_with_util(deps, &ProvenanceUtilImpl, env, info, release)
+}
+
+/// Sends a quarantined payment on to its payable's payee with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the payable targeted has been registered, and that the payment is still held in
+///   quarantine.
+/// - Removes the payment from quarantine and sends it to the payee.  Payables registered in escrow
+///   mode instead move the payment into escrow, with its escrow timeout starting from the release.
+pub fn release_quarantined_payment_with_util<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    release: ReleaseQuarantinedPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    let (scope_attribute, quarantined_payment) =
+        load_quarantined_payment_or_err(&deps.as_ref(), release.payable_uuid, release.payment_id)?;
+    remove_quarantined_payment(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+        release.payment_id,
+    );
+    let payment = Money::new(quarantined_payment.amount, &quarantined_payment.denom);
+    let destination = resolve_payment_destination(
+        &deps.as_ref(),
+        provenance_util,
+        &scope_attribute,
+        env.block.time,
+    )?;
+    let mut response = ResponseBuilder::for_payable(&scope_attribute);
+    match &destination {
+        PaymentDestination::Payee(payee) => {
+            let payee_payout =
+                payout_to_payees(deps.storage, &scope_attribute.payable_uuid, payee, &payment)?;
+            if let Some(payee_splits) = &payee_payout.payee_splits {
+                record_payment_payee_splits(
+                    deps.storage,
+                    &scope_attribute.payable_uuid,
+                    release.payment_id,
+                    payee_splits,
+                )?;
+            }
+            response = response
+                .attributes(payee_payout.attributes())
+                .messages(payee_payout.messages);
+        }
+        #[cfg(feature = "escrow")]
+        PaymentDestination::Escrow { reclaimable_at, .. } => {
+            save_escrowed_payment(
+                deps.storage,
+                &scope_attribute.payable_type,
+                &scope_attribute.payable_uuid,
+                release.payment_id,
+                &EscrowedPaymentV1 {
+                    payer: quarantined_payment.payer,
+                    amount: payment.amount,
+                    denom: payment.denom.clone(),
+                    reclaimable_at: *reclaimable_at,
+                },
+            )?;
+            response = response.attribute(PAYMENT_ESCROWED_KEY, release.payment_id.to_string());
+        }
+    }
+    Ok(response
+        .attribute(
+            QUARANTINED_PAYMENT_RELEASED_KEY,
+            &scope_attribute.payable_uuid,
+        )
+        .attribute(PAYMENT_ID_KEY, release.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, payment.amount.to_string())
+        .attribute(PAYEE_KEY, destination.payee().as_str())
+        .build())
+}
+
+/// Parent function path for the contract to refund a quarantined payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn refund_quarantined_payment(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    refund: RefundQuarantinedPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    refund_quarantined_payment_with_util(deps, &ProvenanceUtilImpl, env, info, refund)
+}
+
+/// Returns a quarantined payment to its payer with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the payable targeted has been registered, and that the payment is still held in
+///   quarantine.
+/// - Removes the payment from quarantine and sends it back to the payer.
+/// - Adds the payment back to the payable's remaining owed amount and rewrites the scope attribute,
+///   as the payee never received it.
+pub fn refund_quarantined_payment_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    refund: RefundQuarantinedPaymentV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    let (mut scope_attribute, quarantined_payment) =
+        load_quarantined_payment_or_err(&deps.as_ref(), refund.payable_uuid, refund.payment_id)?;
+    remove_quarantined_payment(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+        refund.payment_id,
+    );
+    // Quarantined payments, including holdbacks, are keyed on their payment's history record
+    let payment_id = refund.payment_id;
+    let payment_record =
+        load_payment_record(deps.storage, &scope_attribute.payable_uuid, payment_id)?.ok_or_else(
+            || {
+                ContractError::std_err(format!(
+                    "no payment [{}] was recorded for payable [{}]",
+                    payment_id, scope_attribute.payable_uuid,
+                ))
+            },
+        )?;
+    // A holdback is only a portion of its payment, so only the quarantined amount is restored
+    restore_payment(
+        deps.storage,
+        &mut scope_attribute,
+        &payment_record,
+        quarantined_payment.amount,
+        &env.block,
+    )?;
+    let payment = Money::new(quarantined_payment.amount, &quarantined_payment.denom);
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .message(payment.send_to(&quarantined_payment.payer))
+        .attribute(
+            QUARANTINED_PAYMENT_REFUNDED_KEY,
+            &scope_attribute.payable_uuid,
+        )
+        .attribute(PAYMENT_ID_KEY, refund.payment_id.to_string())
+        .attribute(PAYMENT_AMOUNT_KEY, payment.amount.to_string())
+        .attribute(PAYER_KEY, quarantined_payment.payer.as_str())
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .build())
+}
+
+fn load_quarantined_payment_or_err(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: String,
+    payment_id: u64,
+) -> Result<(PayableScopeAttribute, QuarantinedPaymentV1), ContractError> {
+    let scope_attribute = match query_payable_attribute_by_uuid(deps, &payable_uuid) {
+        Ok(attr) => attr,
+        Err(_) => return ContractError::PayableNotFound { payable_uuid }.to_result(),
+    };
+    match load_quarantined_payment(deps.storage, &payable_uuid, payment_id)? {
+        Some(quarantined_payment) => Ok((scope_attribute, quarantined_payment)),
+        None => ContractError::std_err(format!(
+            "no quarantined payment [{}] exists for payable [{}]",
+            payment_id, payable_uuid,
+        ))
+        .to_result(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{QuarantineConfigResponse, QueryMsg};
+    use crate::execute::quarantine::{
+        refund_quarantined_payment_with_util, release_quarantined_payment_with_util,
+        set_quarantine_config, RefundQuarantinedPaymentV1, ReleaseQuarantinedPaymentV1,
+        SetQuarantineConfigV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        PAYEE_KEY, PAYMENT_QUARANTINED_KEY, QUARANTINED_PAYMENT_REFUNDED_KEY,
+        QUARANTINED_PAYMENT_RELEASED_KEY, QUARANTINE_CONFIG_SET_KEY, QUARANTINE_REASON_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, BankMsg, CosmosMsg, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const FLAGGED_PAYER: &str = "flagged-payer";
+
+    #[test]
+    fn test_large_payment_quarantined_until_released() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_payable(&mut deps);
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .expect("a payment below the threshold should be accepted");
+        assert!(
+            response
+                .messages
+                .iter()
+                .any(|msg| matches!(msg.msg, CosmosMsg::Bank(_))),
+            "a payment below the threshold should be sent directly to the payee",
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(500),
+        )
+        .expect("a payment meeting the threshold should be accepted into quarantine");
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, PAYMENT_QUARANTINED_KEY),
+            "the second payment should be quarantined under its payment id",
+        );
+        assert_eq!(
+            "payment meets large payment threshold [500nhash]",
+            single_attribute_for_key(&response, QUARANTINE_REASON_KEY),
+        );
+        assert!(
+            !response
+                .messages
+                .iter()
+                .any(|msg| matches!(msg.msg, CosmosMsg::Bank(_))),
+            "a quarantined payment should not be sent to the payee",
+        );
+        let error = release(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 1).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to release a quarantined payment, but got: {:?}",
+            error,
+        );
+        let response = release(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 1)
+            .expect("the admin should be able to release the payment");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, QUARANTINED_PAYMENT_RELEASED_KEY),
+        );
+        assert_eq!(
+            DEFAULT_INFO_NAME,
+            single_attribute_for_key(&response, PAYEE_KEY),
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(DEFAULT_INFO_NAME, to_address);
+                assert_eq!(&vec![coin(500, DEFAULT_PAYABLE_DENOM)], amount);
+            }
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        }
+        let error = release(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 1).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "a released payment should no longer be quarantined, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_flagged_payer_payment_refunded() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_payable(&mut deps);
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender(FLAGGED_PAYER, 300, DEFAULT_PAYABLE_DENOM),
+        )
+        .unwrap();
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, PAYMENT_QUARANTINED_KEY),
+        );
+        assert_eq!(
+            "700",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "a quarantined payment should still be applied to the remaining owed amount",
+        );
+        let response = refund(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 0)
+            .expect("the admin should be able to refund the payment");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, QUARANTINED_PAYMENT_REFUNDED_KEY),
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL.to_string(),
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "a refunded payment should be added back to the remaining owed amount",
+        );
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: FLAGGED_PAYER.to_string(),
+                    amount: vec![coin(300, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the payment should be returned to its payer",
+        );
+        assert_eq!(
+            Uint128::new(DEFAULT_PAYABLE_TOTAL),
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .payable_remaining_owed,
+        );
+    }
+
+    #[test]
+    fn test_set_quarantine_config() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = set_config(&mut deps, DEFAULT_ORACLE_ADDRESS, vec![]).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to set the quarantine config, but got: {:?}",
+            error,
+        );
+        let response = set_config(&mut deps, DEFAULT_INFO_NAME, vec![FLAGGED_PAYER]).unwrap();
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, QUARANTINE_CONFIG_SET_KEY),
+        );
+        let quarantine_config = query_quarantine_config(&deps)
+            .quarantine_config
+            .expect("the quarantine config should be stored");
+        assert_eq!(1, quarantine_config.flagged_payers.len());
+        let response = set_config(&mut deps, DEFAULT_INFO_NAME, vec![]).unwrap();
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, QUARANTINE_CONFIG_SET_KEY),
+        );
+        assert!(
+            query_quarantine_config(&deps).quarantine_config.is_none(),
+            "empty heuristics should disable quarantine",
+        );
+    }
+
+    fn setup_approved_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        set_config(deps, DEFAULT_INFO_NAME, vec![FLAGGED_PAYER]).unwrap();
+        provenance_util
+    }
+
+    fn set_config(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        flagged_payers: Vec<&str>,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let large_payment_thresholds = if flagged_payers.is_empty() {
+            vec![]
+        } else {
+            vec![coin(500, DEFAULT_PAYABLE_DENOM)]
+        };
+        set_quarantine_config(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            SetQuarantineConfigV1 {
+                flagged_payers: flagged_payers.into_iter().map(String::from).collect(),
+                large_payment_thresholds,
+            },
+        )
+    }
+
+    fn query_quarantine_config(deps: &MockOwnedDeps) -> QuarantineConfigResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryQuarantineConfig {},
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn release(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        payment_id: u64,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        release_quarantined_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            ReleaseQuarantinedPaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id,
+            },
+        )
+    }
+
+    fn refund(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        payment_id: u64,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = refund_quarantined_payment_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            RefundQuarantinedPaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id,
+            },
+        );
+        if response.is_ok() {
+            provenance_util.bind_captured_attribute(deps);
+        }
+        response
+    }
+}
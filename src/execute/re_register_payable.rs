@@ -0,0 +1,298 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, has_escrowed_payments, has_quarantined_payments, load_payment_batch,
+    load_proposed_payment,
+};
+use crate::execute::cancel_payable::refund_held_oracle_funds;
+use crate::execute::expire_payable::remove_payable_local_storage;
+use crate::execute::referral::route_referral_shares;
+use crate::execute::register_payable::{
+    charge_registration_fees, record_payable_registration, RegisterPayableV2, RegistrationContext,
+};
+use crate::query::query_payable_by_scope_id::query_payable_attribute_by_scope_id;
+use crate::util::constants::PAYABLE_RE_REGISTERED_KEY;
+use crate::util::fees::calculate_registration_fees;
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+/// Contains the new registration for a payable whose previous registration was declined or expired.
+pub struct ReRegisterPayableV1 {
+    pub payable: RegisterPayableV2,
+}
+
+/// Parent function path for the contract to re-register a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn re_register_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    re_register: ReRegisterPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    re_register_payable_with_util(deps, &ProvenanceUtilImpl, env, info, re_register)
+}
+
+/// Replaces a payable's declined or expired registration with a new one with the following steps:
+/// - Ensures that the contract is not paused.
+/// - Ensures that the scope already holds an attribute for the same payable uuid.  Scopes without
+///   a registration must use RegisterPayable instead.
+/// - Ensures that the existing registration has been declined or has expired, and that no payments
+///   have been made, escrowed, quarantined or proposed against it.  Any other existing registration
+///   is a duplicate.
+/// - Returns every amount that the contract held for the oracle under the previous registration to
+///   its registrant, if the oracle never approved it.
+/// - Removes all local storage of the previous registration.
+/// - Charges registration fees and registers the payable exactly as RegisterPayable would, except
+///   that the existing attribute is replaced rather than rejected.  The new registration awaits
+///   oracle approval, and is no longer declined.
+pub fn re_register_payable_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    re_register: ReRegisterPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let register = re_register.payable;
+    let existing_attribute =
+        match query_payable_attribute_by_scope_id(&deps.as_ref(), &register.scope_id) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: register.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if existing_attribute.payable_uuid != register.payable_uuid {
+        return ContractError::InvalidPayable {
+            payable_uuid: register.payable_uuid,
+            invalid_reason: format!(
+                "scope [{}] is registered to payable [{}]",
+                register.scope_id, existing_attribute.payable_uuid,
+            ),
+        }
+        .to_result();
+    }
+    if !existing_attribute.is_declined && !existing_attribute.is_expired(env.block.time) {
+        return ContractError::DuplicateRegistration {
+            scope_id: register.scope_id,
+        }
+        .to_result();
+    }
+    // A payment batch only exists while a payment's attribute write is deferred, in which case the
+    // remaining owed amount on the attribute does not yet reflect the payment
+    if existing_attribute.payable_remaining_owed != existing_attribute.payable_total_owed
+        || load_payment_batch(deps.storage, &existing_attribute.payable_uuid)?.is_some()
+        || has_escrowed_payments(deps.storage, &existing_attribute.payable_uuid)
+        || has_quarantined_payments(deps.storage, &existing_attribute.payable_uuid)
+        || load_proposed_payment(deps.storage, &existing_attribute.payable_uuid)?.is_some()
+    {
+        return ContractError::InvalidPayable {
+            payable_uuid: register.payable_uuid,
+            invalid_reason: "payables that have received payments cannot be re-registered".into(),
+        }
+        .to_result();
+    }
+    let fees =
+        calculate_registration_fees(deps.storage, &state, &register.payable_type, env.block.time)?;
+    let referral_shares =
+        route_referral_shares(deps.storage, &[(register.referrer.as_ref(), &fees)])?;
+    let fee_output = charge_registration_fees(
+        &info,
+        &state,
+        std::slice::from_ref(&fees),
+        register.held_funds()?,
+        &referral_shares,
+    )?;
+    let response = ResponseBuilder::new();
+    let response = if existing_attribute.oracle_approved {
+        response
+    } else {
+        refund_held_oracle_funds(
+            deps.storage,
+            &existing_attribute.payable_uuid,
+            None,
+            response,
+        )?
+    };
+    remove_payable_local_storage(deps.storage, &existing_attribute.payable_uuid)?;
+    let registration_output = record_payable_registration(
+        deps.branch(),
+        provenance_util,
+        &env,
+        &RegistrationContext {
+            sender: &info.sender,
+            state: &state,
+            fees: &fees,
+            is_re_registration: true,
+        },
+        register,
+    )?;
+    Ok(response
+        .messages(fee_output.messages)
+        .messages(registration_output.messages)
+        .attributes(fee_output.attributes)
+        .attributes(registration_output.attributes)
+        .attribute(PAYABLE_RE_REGISTERED_KEY, &existing_attribute.payable_uuid)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::decline_payable::{decline_payable_with_util, DeclinePayableV1};
+    use crate::execute::re_register_payable::{re_register_payable_with_util, ReRegisterPayableV1};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        ONBOARDING_FEE_REFUNDED_KEY, PAYABLE_REGISTERED_KEY, PAYABLE_RE_REGISTERED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Env, Response, Timestamp, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_re_register_declined_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = re_register(
+            &mut deps,
+            &provenance_util,
+            mock_env(),
+            TestRegisterPayable::default_register_payable(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::DuplicateRegistration { .. }),
+            "a payable awaiting approval should not be re-registered, but got: {:?}",
+            error,
+        );
+        decline_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            DeclinePayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let response = re_register(
+            &mut deps,
+            &provenance_util,
+            mock_env(),
+            RegisterPayableV2 {
+                payable_total: Uint128::new(800),
+                ..TestRegisterPayable::default_register_payable()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_RE_REGISTERED_KEY),
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_REGISTERED_KEY),
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != ONBOARDING_FEE_REFUNDED_KEY),
+            "declining already refunded the held oracle fee of the previous registration",
+        );
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert!(!attribute.is_declined);
+        assert!(!attribute.oracle_approved);
+        assert_eq!(Uint128::new(800), attribute.payable_total_owed);
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+            .expect("the re-registered payable should be approvable");
+    }
+
+    #[test]
+    fn test_re_register_expired_payable_refunds_held_oracle_fee() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let expiration_time = mock_env().block.time.plus_seconds(60);
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    expiration_time: Some(expiration_time),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let error = re_register(
+            &mut deps,
+            &provenance_util,
+            mock_env(),
+            TestRegisterPayable::default_register_payable(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::DuplicateRegistration { .. }),
+            "an unexpired payable should not be re-registered, but got: {:?}",
+            error,
+        );
+        let response = re_register(
+            &mut deps,
+            &provenance_util,
+            env_at(expiration_time.plus_seconds(1)),
+            TestRegisterPayable::default_register_payable(),
+        )
+        .unwrap();
+        assert_eq!(
+            format!("25/{}", DEFAULT_ONBOARDING_DENOM),
+            single_attribute_for_key(&response, ONBOARDING_FEE_REFUNDED_KEY),
+            "the oracle fee held for the expired registration should be refunded",
+        );
+        assert_eq!(
+            None,
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expiration_time,
+        );
+    }
+
+    fn re_register(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        env: Env,
+        payable: RegisterPayableV2,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = re_register_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            env,
+            TestRegisterPayable::default().info,
+            ReRegisterPayableV1 { payable },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+
+    fn env_at(time: Timestamp) -> Env {
+        let mut env = mock_env();
+        env.block.time = time;
+        env
+    }
+}
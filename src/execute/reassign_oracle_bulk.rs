@@ -0,0 +1,273 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, payable_meta_storage_read_v2, record_payable_snapshot};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    BULK_REASSIGN_COMPLETE_KEY, BULK_REASSIGN_NEXT_START_AFTER_KEY, BULK_REASSIGN_REASSIGNED_KEY,
+    BULK_REASSIGN_SCANNED_KEY, ORACLE_ADDRESS_KEY, ORACLE_BULK_REASSIGNED_KEY, ORACLE_CHANGED_KEY,
+    PREVIOUS_ORACLE_ADDRESS_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Order, Response, StdResult};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The number of payables scanned by a single bulk reassignment when no limit is provided.
+const DEFAULT_REASSIGN_ORACLE_LIMIT: u32 = 25;
+
+/// Contains the oracle being decommissioned, its replacement, and the page of payables to scan.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReassignOracleBulkV1 {
+    pub old_oracle_address: String,
+    pub new_oracle_address: String,
+    // The uuid of the last payable scanned by the previous call, emitted as its next start after
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// Parent function path for the contract to reassign an oracle's payables in bulk.  Ensures that
+/// the ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn reassign_oracle_bulk(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    reassign: ReassignOracleBulkV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    reassign_oracle_bulk_with_util(deps, &ProvenanceUtilImpl, env, info, reassign)
+}
+
+/// Re-points a page of a retiring oracle's payables to a replacement oracle with the following
+/// steps:
+/// - Verifies that no funds were sent.
+/// - Verifies that the sender is the contract admin.
+/// - Ensures that both oracle addresses are valid.
+/// - Scans up to the limit of registered payables in uuid order, beginning after the provided
+///   cursor.  Payables whose scope attribute can no longer be found are skipped.
+/// - Rewrites the scope attribute of each scanned payable that is assigned to the old oracle and
+///   has not been approved, declined or written off.  The oracle's share of the onboarding fees is
+///   held by the contract until approval, so it is paid to the new oracle.
+/// - Emits the number of payables scanned and reassigned, and the cursor for the next call until
+///   every payable has been scanned.
+pub fn reassign_oracle_bulk_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    reassign: ReassignOracleBulkV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    let old_oracle_address = deps.api.addr_validate(&reassign.old_oracle_address)?;
+    let new_oracle_address = deps.api.addr_validate(&reassign.new_oracle_address)?;
+    let limit = reassign.limit.unwrap_or(DEFAULT_REASSIGN_ORACLE_LIMIT) as usize;
+    // Bucket ranges include their start key, so one extra payable is taken in case the cursor is
+    // the first key returned
+    let payable_uuids = payable_meta_storage_read_v2(deps.storage)
+        .range(
+            reassign.start_after.as_ref().map(|uuid| uuid.as_bytes()),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, meta)| meta.payable_uuid))
+        .filter(|item| match (item, &reassign.start_after) {
+            (Ok(payable_uuid), Some(start_after)) => payable_uuid != start_after,
+            _ => true,
+        })
+        .take(limit + 1)
+        .collect::<StdResult<Vec<String>>>()?;
+    let has_more = payable_uuids.len() > limit;
+    let scanned_uuids = &payable_uuids[..payable_uuids.len().min(limit)];
+    let mut response = ResponseBuilder::new();
+    let mut reassigned_count = 0;
+    for payable_uuid in scanned_uuids {
+        let mut scope_attribute =
+            match query_payable_attribute_by_uuid(&deps.as_ref(), payable_uuid) {
+                Ok(attr) => attr,
+                Err(_) => continue,
+            };
+        if scope_attribute.oracle_address != old_oracle_address
+            || scope_attribute.oracle_approved
+            || scope_attribute.is_declined
+            || scope_attribute.is_written_off
+        {
+            continue;
+        }
+        scope_attribute.oracle_address = new_oracle_address.clone();
+        record_payable_snapshot(deps.storage, &scope_attribute, &env.block)?;
+        response = response
+            .messages(write_through_payable(
+                deps.branch(),
+                provenance_util,
+                &scope_attribute,
+                &state.contract_name,
+            )?)
+            .attribute(ORACLE_CHANGED_KEY, &scope_attribute.payable_uuid);
+        reassigned_count += 1;
+    }
+    response = response
+        .attribute(ORACLE_BULK_REASSIGNED_KEY, old_oracle_address.as_str())
+        .attribute(PREVIOUS_ORACLE_ADDRESS_KEY, old_oracle_address.as_str())
+        .attribute(ORACLE_ADDRESS_KEY, new_oracle_address.as_str())
+        .attribute(BULK_REASSIGN_SCANNED_KEY, scanned_uuids.len().to_string())
+        .attribute(BULK_REASSIGN_REASSIGNED_KEY, reassigned_count.to_string())
+        .attribute(BULK_REASSIGN_COMPLETE_KEY, (!has_more).to_string());
+    if has_more {
+        if let Some(last_scanned) = scanned_uuids.last() {
+            response = response.attribute(BULK_REASSIGN_NEXT_START_AFTER_KEY, last_scanned);
+        }
+    }
+    Ok(response.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::reassign_oracle_bulk::{
+        reassign_oracle_bulk_with_util, ReassignOracleBulkV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        generate_payable_dataset, setup_test_suite, single_attribute_for_key, DatasetArgs,
+        InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        BULK_REASSIGN_COMPLETE_KEY, BULK_REASSIGN_NEXT_START_AFTER_KEY,
+        BULK_REASSIGN_REASSIGNED_KEY, BULK_REASSIGN_SCANNED_KEY, ORACLE_CHANGED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const NEW_ORACLE_ADDRESS: &str = "new-oracle";
+
+    #[test]
+    fn test_reassign_oracle_bulk_reassigns_unapproved_payables() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = reassign(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_ORACLE_ADDRESS,
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to reassign oracles in bulk, but got: {:?}",
+            error,
+        );
+        let response =
+            reassign(&mut deps, &provenance_util, DEFAULT_INFO_NAME, None, None).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, ORACLE_CHANGED_KEY),
+        );
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, BULK_REASSIGN_REASSIGNED_KEY),
+        );
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, BULK_REASSIGN_COMPLETE_KEY),
+        );
+        provenance_util.bind_captured_attribute(&mut deps);
+        assert_eq!(
+            Addr::unchecked(NEW_ORACLE_ADDRESS),
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .oracle_address,
+        );
+    }
+
+    #[test]
+    fn test_reassign_oracle_bulk_skips_approved_payables() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response =
+            reassign(&mut deps, &provenance_util, DEFAULT_INFO_NAME, None, None).unwrap();
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, BULK_REASSIGN_SCANNED_KEY),
+        );
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, BULK_REASSIGN_REASSIGNED_KEY),
+            "an approved payable should keep the oracle that approved it",
+        );
+        assert!(response.messages.is_empty());
+    }
+
+    #[test]
+    fn test_reassign_oracle_bulk_pages_through_every_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        generate_payable_dataset(
+            &mut deps,
+            DatasetArgs {
+                payable_count: 55,
+                ..DatasetArgs::default()
+            },
+        );
+        let mut start_after = None;
+        let mut scanned = 0;
+        let mut calls = 0;
+        loop {
+            let response = reassign(
+                &mut deps,
+                &provenance_util,
+                DEFAULT_INFO_NAME,
+                start_after,
+                Some(20),
+            )
+            .unwrap();
+            calls += 1;
+            scanned += single_attribute_for_key(&response, BULK_REASSIGN_SCANNED_KEY)
+                .parse::<usize>()
+                .unwrap();
+            start_after = response
+                .attributes
+                .iter()
+                .find(|attr| attr.key == BULK_REASSIGN_NEXT_START_AFTER_KEY)
+                .map(|attr| attr.value.clone());
+            if start_after.is_none() {
+                assert_eq!(
+                    "true",
+                    single_attribute_for_key(&response, BULK_REASSIGN_COMPLETE_KEY),
+                );
+                break;
+            }
+        }
+        assert_eq!(3, calls, "55 payables should take three pages of 20");
+        assert_eq!(55, scanned, "every payable should be scanned exactly once");
+    }
+
+    fn reassign(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        reassign_oracle_bulk_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            ReassignOracleBulkV1 {
+                old_oracle_address: DEFAULT_ORACLE_ADDRESS.to_string(),
+                new_oracle_address: NEW_ORACLE_ADDRESS.to_string(),
+                start_after,
+                limit,
+            },
+        )
+    }
+}
@@ -0,0 +1,281 @@
+use crate::core::error::ContractError;
+use crate::core::payee_resolver::resolve_payee;
+use crate::core::state::{
+    append_payment_record, config_read_v2, update_payable_timeline, PaymentRecordV1,
+};
+use crate::execute::make_payment::load_payable_for_payment;
+use crate::util::constants::{
+    EXTERNAL_SETTLEMENT_RECORDED_KEY, EXTERNAL_SETTLEMENT_REFERENCE_KEY, PAYABLE_SETTLED_KEY,
+    PAYER_KEY, PAYMENT_AMOUNT_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the portion of a payable that was settled outside of the chain, and the reference that
+/// identifies the settlement in the external ledger, ex: a wire confirmation id.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RecordExternalSettlementV1 {
+    pub payable_uuid: String,
+    pub amount: Uint128,
+    pub reference: String,
+}
+
+/// Parent function path for the contract to record an external settlement.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn record_external_settlement(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    settlement: RecordExternalSettlementV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    record_external_settlement_with_util(deps, &ProvenanceUtilImpl, env, info, settlement)
+}
+
+/// Reduces the amount owed on a payable by a settlement made outside of the chain with the
+/// following steps:
+/// - Verifies that no funds were sent.  No funds move on chain.
+/// - Ensures that the contract is not paused.
+/// - Verifies that the payable has been registered, approved by its oracle, and has not expired.
+/// - Verifies that the sender is the payable's payee or its oracle.
+/// - Accrues any late fees and interest owed on the payable, which the settlement pays before the
+///   principal.
+/// - Verifies that the settlement amount does not exceed the remaining owed amount.
+/// - Subtracts the settlement amount from the remaining owed amount and rewrites the scope
+///   attribute, marking the payable as settled if it has been paid off.
+/// - Records the settlement in the payable's payment history with its external reference, which
+///   distinguishes it from payments made on chain, along with the late fees and interest that it
+///   paid, so that flagging it as erroneous restores them.
+pub fn record_external_settlement_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    settlement: RecordExternalSettlementV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let mut scope_attribute = load_payable_for_payment(&deps.as_ref(), settlement.payable_uuid)?;
+    scope_attribute.check_not_expired(env.block.time)?;
+    let payee = resolve_payee(&deps.as_ref(), provenance_util, &scope_attribute)?;
+    if info.sender != payee && info.sender != scope_attribute.oracle_address {
+        return Err(ContractError::Unauthorized);
+    }
+    scope_attribute.accrue_late_fees(env.block.time)?;
+    scope_attribute.accrue_interest(env.block.time)?;
+    if settlement.amount > scope_attribute.payable_remaining_owed {
+        return Err(ContractError::PaymentTooLarge {
+            total_owed: scope_attribute.payable_remaining_owed.u128(),
+            amount_provided: settlement.amount.u128(),
+        });
+    }
+    scope_attribute.payable_remaining_owed -= settlement.amount;
+    // Outstanding late fees and interest are settled before the principal, exactly as with on
+    // chain payments
+    let late_fees_paid = match &mut scope_attribute.late_fee_accrual {
+        Some(accrual) => {
+            let late_fees_paid = accrual.amount_outstanding().min(settlement.amount);
+            accrual.amount_paid += late_fees_paid;
+            late_fees_paid
+        }
+        None => Uint128::zero(),
+    };
+    let interest_paid = match &mut scope_attribute.interest_accrual {
+        Some(accrual) => {
+            let interest_paid = accrual
+                .amount_outstanding()
+                .min(settlement.amount - late_fees_paid);
+            accrual.amount_paid += interest_paid;
+            interest_paid
+        }
+        None => Uint128::zero(),
+    };
+    if scope_attribute.payable_remaining_owed.is_zero() {
+        scope_attribute.settled_at = Some(env.block.time);
+    }
+    update_payable_timeline(deps.storage, &scope_attribute.payable_uuid, |timeline| {
+        timeline.payment_times.push(env.block.time);
+        if scope_attribute.payable_remaining_owed.is_zero() {
+            timeline.completed_at = Some(env.block.time);
+        }
+    })?;
+    append_payment_record(
+        deps.storage,
+        &scope_attribute.payable_uuid,
+        &PaymentRecordV1 {
+            payer: info.sender.clone(),
+            amount: settlement.amount,
+            denom: scope_attribute.payable_denom.clone(),
+            paid_at: env.block.time,
+            on_behalf_of: None,
+            external_reference: Some(settlement.reference.clone()),
+            payee_splits: None,
+            late_fees_paid,
+            interest_paid,
+            netting: None,
+        },
+    )?;
+    let mut response = ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(
+            EXTERNAL_SETTLEMENT_RECORDED_KEY,
+            &scope_attribute.payable_uuid,
+        )
+        .attribute(EXTERNAL_SETTLEMENT_REFERENCE_KEY, &settlement.reference)
+        .attribute(PAYMENT_AMOUNT_KEY, settlement.amount.to_string())
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .attribute(PAYER_KEY, info.sender.as_str());
+    if scope_attribute.settled_at.is_some() {
+        response = response.attribute(PAYABLE_SETTLED_KEY, &scope_attribute.payable_uuid);
+    }
+    Ok(response
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::load_payment_history;
+    use crate::execute::record_external_settlement::{
+        record_external_settlement_with_util, RecordExternalSettlementV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        EXTERNAL_SETTLEMENT_RECORDED_KEY, EXTERNAL_SETTLEMENT_REFERENCE_KEY, PAYABLE_SETTLED_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, CosmosMsg, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const WIRE_REFERENCE: &str = "wire-20220214-0042";
+
+    #[test]
+    fn test_record_external_settlement_reduces_remaining_owed() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        let error = record(&mut deps, &provenance_util, "stranger", 600).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the payee or oracle should record external settlements, but got: {:?}",
+            error,
+        );
+        let error = record(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 601).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PaymentTooLarge { .. }),
+            "a settlement should not exceed the remaining owed amount, but got: {:?}",
+            error,
+        );
+        let response = record(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 200).unwrap();
+        assert!(
+            response
+                .messages
+                .iter()
+                .all(|msg| !matches!(msg.msg, CosmosMsg::Bank(_))),
+            "an external settlement should not move any funds",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, EXTERNAL_SETTLEMENT_RECORDED_KEY),
+        );
+        assert_eq!(
+            WIRE_REFERENCE,
+            single_attribute_for_key(&response, EXTERNAL_SETTLEMENT_REFERENCE_KEY),
+        );
+        assert_eq!(
+            "400",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+        );
+        let response = record(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 400).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_SETTLED_KEY),
+            "the payee should be able to settle the rest of the payable externally",
+        );
+        assert!(
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .payable_remaining_owed
+                .is_zero()
+        );
+        let history = load_payment_history(deps.as_ref().storage, DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            vec![
+                (Addr::unchecked(DEFAULT_INFO_NAME), 400, None),
+                (
+                    Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+                    200,
+                    Some(WIRE_REFERENCE.to_string()),
+                ),
+                (
+                    Addr::unchecked(DEFAULT_INFO_NAME),
+                    400,
+                    Some(WIRE_REFERENCE.to_string()),
+                ),
+            ],
+            history
+                .into_iter()
+                .map(|payment| (
+                    payment.payer,
+                    payment.amount.u128(),
+                    payment.external_reference,
+                ))
+                .collect::<Vec<_>>(),
+            "external settlements should be distinguishable from on chain payments in history",
+        );
+    }
+
+    fn record(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        amount: u128,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = record_external_settlement_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            RecordExternalSettlementV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                amount: Uint128::new(amount),
+                reference: WIRE_REFERENCE.to_string(),
+            },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+}
@@ -0,0 +1,281 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::state::{
+    config_read_v2, load_referral_config, record_referrer_revenue, remove_referral_config,
+    save_referral_config, ReferralConfigV1,
+};
+use crate::util::constants::REFERRAL_CONFIG_SET_KEY;
+use crate::util::fees::EffectiveFees;
+use crate::util::response_builder::ResponseBuilder;
+use crate::validation::execute::MAX_REFERRAL_FEE_SHARE_BPS;
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response, StdResult, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the partners that the contract admin would like registrations to be able to name as
+/// their referrer, and the share of the collected fee that each referred registration pays them.
+/// Providing no referrers disables referrals.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetReferralConfigV1 {
+    pub fee_share_bps: u64,
+    pub referrers: Vec<String>,
+}
+
+/// The portion of a registration's collected fee that is routed to the partner that referred it.
+pub(crate) struct ReferralShare {
+    pub referrer: Addr,
+    pub share: Money,
+}
+
+/// Replaces the contract's referral config with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that each referrer is a valid address.
+/// - Stores the config in local storage, or removes it if no referrers were provided.  Revenue
+///   already paid to referrers remains queryable.
+pub fn set_referral_config(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_config: SetReferralConfigV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    let is_enabled = !set_config.referrers.is_empty();
+    if is_enabled {
+        let referrers = set_config
+            .referrers
+            .iter()
+            .map(|referrer| deps.api.addr_validate(referrer))
+            .collect::<StdResult<Vec<_>>>()?;
+        save_referral_config(
+            deps.storage,
+            &ReferralConfigV1 {
+                fee_share_bps: set_config.fee_share_bps,
+                referrers,
+            },
+        )?;
+    } else {
+        remove_referral_config(deps.storage);
+    }
+    Ok(ResponseBuilder::new()
+        .attribute(REFERRAL_CONFIG_SET_KEY, is_enabled.to_string())
+        .build())
+}
+
+/// Determines the referral share of each registration that names a referrer, and counts it towards
+/// that referrer's revenue.  Every named referrer must be allowlisted in the referral config.  The
+/// share is carved out of the portion of the onboarding cost sent to the fee collector, rounded
+/// down, so naming a referrer never changes the amount that a registrant is charged.
+pub(crate) fn route_referral_shares(
+    storage: &mut dyn Storage,
+    registrations: &[(Option<&String>, &EffectiveFees)],
+) -> Result<Vec<ReferralShare>, ContractError> {
+    if registrations.iter().all(|(referrer, _)| referrer.is_none()) {
+        return Ok(vec![]);
+    }
+    let referral_config = load_referral_config(storage)?;
+    let mut referral_shares = vec![];
+    for (referrer, fees) in registrations {
+        let referrer = match referrer {
+            Some(referrer) => referrer,
+            None => continue,
+        };
+        let (referrer, fee_share_bps) = match referral_config
+            .as_ref()
+            .and_then(|config| config.find_referrer(referrer).map(|addr| (addr, config)))
+        {
+            Some((addr, config)) => (addr.clone(), config.fee_share_bps),
+            None => {
+                return ContractError::ReferrerNotAllowed {
+                    referrer: referrer.to_string(),
+                }
+                .to_result()
+            }
+        };
+        let share = Money::new(
+            fees.fee_amount
+                .amount
+                .multiply_ratio(fee_share_bps, MAX_REFERRAL_FEE_SHARE_BPS),
+            &fees.fee_amount.denom,
+        );
+        record_referrer_revenue(storage, &referrer, &share)?;
+        referral_shares.push(ReferralShare { referrer, share });
+    }
+    Ok(referral_shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{QueryMsg, ReferrerRevenueResponse};
+    use crate::execute::referral::{set_referral_config, SetReferralConfigV1};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_FEE_COLLECTION_ADDRESS, DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM,
+        DEFAULT_ORACLE_ADDRESS,
+    };
+    use crate::util::constants::{REFERRAL_CONFIG_SET_KEY, REFERRAL_SHARE_KEY, REFERRER_KEY};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coins, from_binary, BankMsg, Coin, CosmosMsg, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const REFERRER: &str = "partner-wallet";
+
+    #[test]
+    fn test_set_referral_config() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error =
+            set_config(&mut deps, DEFAULT_ORACLE_ADDRESS, 1000, vec![REFERRER]).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to set the referral config, but got: {:?}",
+            error,
+        );
+        let response = set_config(&mut deps, DEFAULT_INFO_NAME, 1000, vec![REFERRER]).unwrap();
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, REFERRAL_CONFIG_SET_KEY)
+        );
+        let revenue = query_revenue(&deps, REFERRER);
+        assert!(revenue.is_referrer, "the referrer should be allowlisted");
+        assert_eq!(Some(1000), revenue.fee_share_bps);
+        let response = set_config(&mut deps, DEFAULT_INFO_NAME, 1000, vec![]).unwrap();
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, REFERRAL_CONFIG_SET_KEY)
+        );
+        let revenue = query_revenue(&deps, REFERRER);
+        assert!(
+            !revenue.is_referrer && revenue.fee_share_bps.is_none(),
+            "no referrers should disable referrals",
+        );
+    }
+
+    #[test]
+    fn test_referred_registration_shares_fee_with_referrer() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        set_config(&mut deps, DEFAULT_INFO_NAME, 2000, vec![REFERRER]).unwrap();
+        let response = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: referred_registration(),
+                ..Default::default()
+            },
+        )
+        .expect("a registration naming an allowlisted referrer should succeed");
+        assert_eq!(REFERRER, single_attribute_for_key(&response, REFERRER_KEY));
+        assert_eq!(
+            format!("15/{}", DEFAULT_ONBOARDING_DENOM),
+            single_attribute_for_key(&response, REFERRAL_SHARE_KEY),
+            "a fifth of the 75 collected fee should be shared with the referrer",
+        );
+        assert_eq!(
+            vec![coins(60, DEFAULT_ONBOARDING_DENOM)],
+            sends_to(&response, DEFAULT_FEE_COLLECTION_ADDRESS),
+            "the fee collector should receive the fee less the referral share",
+        );
+        assert_eq!(
+            vec![coins(15, DEFAULT_ONBOARDING_DENOM)],
+            sends_to(&response, REFERRER),
+            "the referrer should be paid its share of the fee",
+        );
+        let revenue = query_revenue(&deps, REFERRER).revenue;
+        assert_eq!(1, revenue.len());
+        assert_eq!(DEFAULT_ONBOARDING_DENOM, revenue[0].denom);
+        assert_eq!(1, revenue[0].referral_count);
+        assert_eq!(Uint128::new(15), revenue[0].total_revenue);
+    }
+
+    #[test]
+    fn test_registration_rejects_unlisted_referrer() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let register_with_referrer = |deps: &mut MockOwnedDeps| {
+            test_register_payable(
+                deps,
+                &provenance_util,
+                TestRegisterPayable {
+                    register_payable: referred_registration(),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err()
+        };
+        let error = register_with_referrer(&mut deps);
+        assert!(
+            matches!(error, ContractError::ReferrerNotAllowed { .. }),
+            "referrers should be rejected while referrals are disabled, but got: {:?}",
+            error,
+        );
+        set_config(&mut deps, DEFAULT_INFO_NAME, 2500, vec!["other-partner"]).unwrap();
+        let error = register_with_referrer(&mut deps);
+        assert!(
+            matches!(error, ContractError::ReferrerNotAllowed { .. }),
+            "referrers missing from the allowlist should be rejected, but got: {:?}",
+            error,
+        );
+        assert!(
+            query_revenue(&deps, REFERRER).revenue.is_empty(),
+            "no revenue should be recorded for a rejected referrer",
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+            .expect("registrations without a referrer should be unaffected");
+    }
+
+    fn referred_registration() -> RegisterPayableV2 {
+        RegisterPayableV2 {
+            referrer: Some(REFERRER.to_string()),
+            ..TestRegisterPayable::default_register_payable()
+        }
+    }
+
+    fn set_config(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        fee_share_bps: u64,
+        referrers: Vec<&str>,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        set_referral_config(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            SetReferralConfigV1 {
+                fee_share_bps,
+                referrers: referrers.into_iter().map(|r| r.to_string()).collect(),
+            },
+        )
+    }
+
+    fn query_revenue(deps: &MockOwnedDeps, referrer: &str) -> ReferrerRevenueResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryReferrerRevenue {
+                    referrer: referrer.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn sends_to(response: &Response<ProvenanceMsg>, address: &str) -> Vec<Vec<Coin>> {
+        response
+            .messages
+            .iter()
+            .filter_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) if to_address == address => {
+                    Some(amount.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -1,18 +1,41 @@
 use crate::core::error::ContractError;
+use crate::core::money::Money;
+#[cfg(feature = "escrow")]
+use crate::core::state::save_payable_escrow_timeout;
 use crate::core::state::{
-    config_read_v2, payable_meta_storage_v2, PayableMetaV2, PayableScopeAttribute, StateV2,
+    config_read_v2, is_payable_type_supply_checked, is_payable_type_supported,
+    load_payable_type_config, load_registration_nonce, payable_meta_storage_v2,
+    record_registration_snapshot, save_held_oracle_fee, save_oracle_gas_rebate,
+    save_payable_authorized_payers, save_payable_custom_fields, save_payable_minimum_payment,
+    save_payable_payee_splits, save_payable_priority_fee, save_registration_nonce,
+    update_payable_timeline, HeldOracleFeeV1, InterestTermsV1, LateFeeV1, OracleGasRebateV1,
+    PayableMetaV2, PayablePriorityFeeV1, PayableScopeAttribute, PayeeSplitV1, PaymentInstallmentV1,
+    RegistrationFeesV1, RegistrationNonceV1, StateV2,
 };
+use crate::execute::referral::{route_referral_shares, ReferralShare};
 use crate::util::constants::{
-    ORACLE_ADDRESS_KEY, ORACLE_FUNDS_KEPT, PAYABLE_REGISTERED_KEY, PAYABLE_TYPE_KEY,
-    PAYABLE_UUID_KEY, REFUND_AMOUNT_KEY, REGISTERED_DENOM_KEY, SCOPE_ID_KEY, TOTAL_OWED_KEY,
+    ACCEPTED_DENOMS_KEY, AUTHORIZED_PAYERS_KEY, EFFECTIVE_FEE_BPS_KEY, ESCROW_TIMEOUT_KEY,
+    EXPIRATION_TIME_KEY, FEE_HOLIDAY_KEY, INSTALLMENT_COUNT_KEY, INTEREST_RATE_KEY,
+    LATE_FEE_RATE_KEY, ONBOARDING_COST_CHARGED_KEY, ORACLE_ADDRESS_KEY, ORACLE_FUNDS_KEPT,
+    ORACLE_GAS_REBATE_KEY, PAYABLE_REGISTERED_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY,
+    PAYEE_SPLITS_KEY, PRIORITY_FEE_KEY, REFERRAL_SHARE_KEY, REFERRER_KEY, REFUND_AMOUNT_KEY,
+    REGISTERED_DENOM_KEY, REGISTRATION_RETRIED_KEY, SCOPE_ID_KEY, TOTAL_OWED_KEY,
+};
+use crate::util::fees::{calculate_effective_fees, calculate_registration_fees, EffectiveFees};
+use crate::util::interest::calculate_accrued_interest;
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use crate::validation::register::{
+    InstallmentFields, InterestFields, LateFeeFields, PayeeSplitFields, RegisterPayableFields,
 };
-use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
 use cosmwasm_std::{
-    coin, Addr, Attribute, BankMsg, CosmosMsg, DepsMut, MessageInfo, Response, Uint128,
+    to_binary, Addr, Attribute, BankMsg, Binary, CosmosMsg, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Timestamp, Uint128,
 };
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::ops::Mul;
 
 /// Contains all relevant fields required in order to register a payable with the contract and
@@ -25,8 +48,98 @@ pub struct RegisterPayableV2 {
     pub oracle_address: String,
     pub payable_denom: String,
     pub payable_total: Uint128,
+    pub custom_fields: Option<Binary>,
+    pub priority_fee: Option<Uint128>,
+    pub oracle_gas_rebate: Option<Uint128>,
+    pub minimum_payment_amount: Option<Uint128>,
+    pub expiration_time: Option<Timestamp>,
+    pub accepted_denoms: Option<Vec<String>>,
+    pub payment_schedule: Option<Vec<PaymentInstallmentV1>>,
+    pub late_fee: Option<LateFeeV1>,
+    pub escrow_timeout_seconds: Option<u64>,
+    pub authorized_payers: Option<Vec<String>>,
+    pub interest: Option<InterestTermsV1>,
+    pub payee_splits: Option<Vec<PayeeSplitV1>>,
+    pub registration_nonce: Option<String>,
+    pub referrer: Option<String>,
 }
 impl RegisterPayableV2 {
+    /// The total amount of the onboarding denom that registration holds in the contract for the
+    /// oracle, on top of the onboarding cost: the priority fee plus the oracle gas rebate.
+    pub fn held_funds(&self) -> Result<Uint128, ContractError> {
+        Ok(self
+            .priority_fee
+            .unwrap_or_else(Uint128::zero)
+            .checked_add(self.oracle_gas_rebate.unwrap_or_else(Uint128::zero))
+            .map_err(StdError::from)?)
+    }
+
+    /// The sha256 hash of the registration message, which identifies a retry of the exact same
+    /// registration.
+    pub fn payload_hash(&self) -> StdResult<Binary> {
+        Ok(Binary::from(
+            Sha256::digest(to_binary(self)?.as_slice()).to_vec(),
+        ))
+    }
+
+    /// The registration's fields as plain values, to be checked by the contract's validation rules.
+    pub fn fields(&self) -> RegisterPayableFields<'_> {
+        RegisterPayableFields {
+            payable_type: &self.payable_type,
+            payable_uuid: &self.payable_uuid,
+            scope_id: &self.scope_id,
+            payable_denom: &self.payable_denom,
+            payable_total: self.payable_total.u128(),
+            custom_fields: self.custom_fields.as_ref().map(|fields| fields.as_slice()),
+            priority_fee: self.priority_fee.map(|fee| fee.u128()),
+            oracle_gas_rebate: self.oracle_gas_rebate.map(|rebate| rebate.u128()),
+            minimum_payment_amount: self.minimum_payment_amount.map(|amount| amount.u128()),
+            expiration_time: self.expiration_time.map(|time| time.nanos()),
+            accepted_denoms: self.accepted_denoms.as_deref(),
+            payment_schedule: self.payment_schedule.as_ref().map(|schedule| {
+                schedule
+                    .iter()
+                    .map(|installment| InstallmentFields {
+                        due_time: installment.due_time.nanos(),
+                        amount: installment.amount.u128(),
+                    })
+                    .collect()
+            }),
+            late_fee: self.late_fee.as_ref().map(|late_fee| LateFeeFields {
+                due_time: late_fee.due_time.nanos(),
+                daily_rate: late_fee.daily_rate.atomics().u128(),
+            }),
+            escrow_timeout_seconds: self.escrow_timeout_seconds,
+            authorized_payers: self.authorized_payers.as_deref(),
+            interest: self.interest.as_ref().map(|interest| InterestFields {
+                start_time: interest.start_time.nanos(),
+                annual_rate: interest.annual_rate.atomics().u128(),
+                // Interest that starts after expiration is rejected without running the math
+                is_accrual_calculable: self.expiration_time.is_none_or(|expiration_time| {
+                    interest.start_time >= expiration_time
+                        || calculate_accrued_interest(
+                            self.payable_total,
+                            interest.annual_rate,
+                            expiration_time.seconds() - interest.start_time.seconds(),
+                            &interest.compounding,
+                        )
+                        .is_ok()
+                }),
+            }),
+            payee_splits: self.payee_splits.as_ref().map(|splits| {
+                splits
+                    .iter()
+                    .map(|split| PayeeSplitFields {
+                        address: &split.address,
+                        weight: split.weight,
+                    })
+                    .collect()
+            }),
+            registration_nonce: self.registration_nonce.as_deref(),
+            referrer: self.referrer.as_deref(),
+        }
+    }
+
     /// Due to the register message including all information required to drive the initial
     /// attribute that is placed on a scope, this function facilitates that transformation in a
     /// single line invocation.
@@ -40,6 +153,21 @@ impl RegisterPayableV2 {
             payable_total_owed: self.payable_total,
             payable_remaining_owed: self.payable_total,
             oracle_approved: false,
+            oracle_approval_context: None,
+            expiration_time: self.expiration_time,
+            is_written_off: false,
+            write_off_reason: None,
+            payee: None,
+            accepted_denoms: self.accepted_denoms.unwrap_or_default(),
+            payment_schedule: self.payment_schedule.unwrap_or_default(),
+            is_declined: false,
+            late_fee: self.late_fee,
+            late_fee_accrual: None,
+            dispute: None,
+            settled_at: None,
+            interest: self.interest,
+            interest_accrual: None,
+            is_frozen: false,
         }
     }
 }
@@ -48,61 +176,298 @@ impl RegisterPayableV2 {
 /// is the implementation used for this functionality outside of tests.
 pub fn register_payable(
     deps: DepsMut<ProvenanceQuery>,
+    env: Env,
     info: MessageInfo,
     register: RegisterPayableV2,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    register_payable_with_util(deps, &ProvenanceUtilImpl, info, register)
+    register_payable_with_util(deps, &ProvenanceUtilImpl, env, info, register)
 }
 
 /// Registers a payable's uuid and scope with the contract with the following steps:
-/// - Charges the contract's configured fee for registration.
+/// - Ensures that the contract is not paused.
+/// - Acknowledges an identical retry of an earlier registration by the same sender with the same
+///   registration nonce without registering the payable again, refunding all funds sent with the
+///   retry.  A different registration that reuses the nonce is rejected.
+/// - Ensures that the payable type has been added to the admin's allowlist of supported types.
+/// - Charges the payable type's configured fee for registration, or the contract's if the type has
+///   no config, reduced by any fee holiday that covers the payable type, plus the optional priority
+///   fee and oracle gas rebate.  Both are held by the contract and paid to the oracle on approval.
+/// - Routes the configured share of the collected fee to the registration's referrer, if one is
+///   named.  The referrer must be allowlisted, and the share is counted towards its revenue.
 /// - Refunds the registering entity if they provided too many funds.
-/// - Verifies that the related scope_id is owned by the sender.
+/// - Emits the fee percent and onboarding cost that were actually applied to the registration.
+/// - Verifies that the related scope_id is owned by the sender.  Payable types may instead require
+///   the sender to be a scope owner with a specific role, or the scope's value owner.  Authorized
+///   registrars skip this check, as they register payables on behalf of scope owners.
+/// - Assigns the payable type's default oracle if the registration does not name an oracle, and
+///   verifies that the oracle is not the contract itself.
+/// - Verifies that the optional expiration time has not already passed.
+/// - Verifies that the payable total does not exceed the total supply of its marker denom, if the
+///   supply check is enabled for the payable type.
 /// - Appends an attribute to the scope with all registered information under the contract's name.
 /// - Creates a link in local storage for scope_id and payable_uuid reverse lookups.
+/// - Stores any provided custom fields, priority fee, oracle gas rebate, minimum payment amount,
+///   and escrow timeout in local storage, keyed on the payable_uuid.  The rebate is recorded with
+///   the sender so that it can be refunded.
+/// - Records the portion of the onboarding cost retained for the oracle along with the sender, so
+///   that it can be refunded if the payable is cancelled before approval.
+/// - Records the registration nonce, if provided, along with the sender and a hash of the
+///   registration message.
+/// - Records the registration time in the payable's timeline.
 pub fn register_payable_with_util<T: ProvenanceUtil>(
-    deps: DepsMut<ProvenanceQuery>,
+    mut deps: DepsMut<ProvenanceQuery>,
     provenance_util: &T,
+    env: Env,
     info: MessageInfo,
     register: RegisterPayableV2,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    // Retried transactions would otherwise fail as duplicates after their first attempt succeeded.
+    // A nonce reused for a different registration is rejected rather than treated as a retry, so
+    // that its sender learns that the new registration never happened
+    if let Some(previous_nonce) = load_registration_nonce(deps.storage, &register.payable_uuid)? {
+        if register.registration_nonce.as_ref() == Some(&previous_nonce.nonce)
+            && previous_nonce.registered_by == info.sender
+        {
+            if previous_nonce.payload_hash != register.payload_hash()? {
+                return ContractError::RegistrationNonceReused {
+                    payable_uuid: register.payable_uuid,
+                    nonce: previous_nonce.nonce,
+                }
+                .to_result();
+            }
+            return Ok(get_registration_retry_response(
+                &info,
+                &register.payable_uuid,
+            ));
+        }
+    }
+    let fees =
+        calculate_registration_fees(deps.storage, &state, &register.payable_type, env.block.time)?;
+    let referral_shares =
+        route_referral_shares(deps.storage, &[(register.referrer.as_ref(), &fees)])?;
+    let fee_output = charge_registration_fees(
+        &info,
+        &state,
+        std::slice::from_ref(&fees),
+        register.held_funds()?,
+        &referral_shares,
+    )?;
+    let registration_output = record_payable_registration(
+        deps.branch(),
+        provenance_util,
+        &env,
+        &RegistrationContext {
+            sender: &info.sender,
+            state: &state,
+            fees: &fees,
+            is_re_registration: false,
+        },
+        register,
+    )?;
+    Ok(ResponseBuilder::new()
+        .messages(fee_output.messages)
+        .messages(registration_output.messages)
+        .attributes(fee_output.attributes)
+        .attributes(registration_output.attributes)
+        .build())
+}
+
+/// Produces the response to a retried registration, which refunds every coin sent with the retry
+/// because its payable was already charged for on the original registration.
+fn get_registration_retry_response(
+    info: &MessageInfo,
+    payable_uuid: &str,
+) -> Response<ProvenanceMsg> {
+    let mut response = ResponseBuilder::new()
+        .attribute(REGISTRATION_RETRIED_KEY, payable_uuid)
+        .attribute(PAYABLE_UUID_KEY, payable_uuid);
+    if !info.funds.is_empty() {
+        response = response
+            .message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: info.funds.clone(),
+            })
+            .attributes(
+                info.funds
+                    .iter()
+                    .map(|coin| Attribute::new(REFUND_AMOUNT_KEY, Money::from(coin).to_string())),
+            );
+    }
+    response.build()
+}
+
+/// The messages and attributes produced by a step of the registration process.
+pub(crate) struct RegistrationOutput {
+    pub messages: Vec<CosmosMsg<ProvenanceMsg>>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// Charges the onboarding cost of each payable being registered, refunds any excess funds, and
+/// holds the total priority fees and oracle gas rebates in the contract.  Referral shares are paid
+/// to their referrers out of the collected fee.  Emits the fee percent and onboarding cost that were
+/// actually applied.
+pub(crate) fn charge_registration_fees(
+    info: &MessageInfo,
+    state: &StateV2,
+    payable_fees: &[EffectiveFees],
+    held_funds_total: Uint128,
+    referral_shares: &[ReferralShare],
+) -> Result<RegistrationOutput, ContractError> {
     let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
     let mut attributes: Vec<Attribute> = vec![];
-    let state = config_read_v2(deps.storage).load()?;
-    let fee_charge_response = validate_fee_params_get_messages(&info, &state)?;
+    let fee_charge_response = validate_fee_params_get_messages(
+        info,
+        state,
+        payable_fees,
+        held_funds_total,
+        referral_shares,
+    )?;
     if let Some(fee_message) = fee_charge_response.fee_charge_message {
         messages.push(fee_message);
         attributes.push(Attribute::new(
             ORACLE_FUNDS_KEPT,
-            format!(
-                "{}/{}",
-                fee_charge_response.oracle_fee_amount_kept, state.onboarding_denom
-            ),
+            fee_charge_response.oracle_fee_amount_kept.to_string(),
         ));
     }
+    for referral in referral_shares {
+        if !referral.share.is_zero() {
+            messages.push(referral.share.send_to(&referral.referrer));
+        }
+        attributes.push(Attribute::new(REFERRER_KEY, &referral.referrer));
+        attributes.push(Attribute::new(
+            REFERRAL_SHARE_KEY,
+            referral.share.to_string(),
+        ));
+    }
+    attributes.push(Attribute::new(
+        EFFECTIVE_FEE_BPS_KEY,
+        fee_charge_response.effective_fee_bps.to_string(),
+    ));
+    attributes.push(Attribute::new(
+        ONBOARDING_COST_CHARGED_KEY,
+        fee_charge_response.onboarding_cost_charged.to_string(),
+    ));
     if let Some(refund_message) = fee_charge_response.fee_refund_message {
         messages.push(refund_message);
         attributes.push(Attribute::new(
             REFUND_AMOUNT_KEY,
-            format!(
-                "{}/{}",
-                fee_charge_response.refund_amount, state.onboarding_denom
-            ),
+            fee_charge_response.refund_amount.to_string(),
         ));
     }
+    Ok(RegistrationOutput {
+        messages,
+        attributes,
+    })
+}
+
+/// The registrant and charged fees behind a single payable registration.
+pub(crate) struct RegistrationContext<'a> {
+    pub sender: &'a Addr,
+    pub state: &'a StateV2,
+    pub fees: &'a EffectiveFees,
+    // Re-registrations replace the payable's existing attribute on the scope
+    pub is_re_registration: bool,
+}
+
+/// Records a single payable whose registration fees have already been charged: verifies the payable
+/// type, scope ownership and the marker supply, tags the scope with the payable's attribute, and
+/// writes all of the payable's local storage.  Re-registrations replace the payable's existing
+/// attribute on the scope, and must only be used once its previous local storage is removed.
+pub(crate) fn record_payable_registration<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: &Env,
+    context: &RegistrationContext,
+    mut register: RegisterPayableV2,
+) -> Result<RegistrationOutput, ContractError> {
+    let (sender, state, fees) = (context.sender, context.state, context.fees);
+    let mut messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    let mut attributes: Vec<Attribute> = vec![];
+    if !is_payable_type_supported(deps.storage, &register.payable_type) {
+        return ContractError::UnsupportedPayableType {
+            payable_type: register.payable_type,
+        }
+        .to_result();
+    }
+    let type_config = load_payable_type_config(deps.storage, &register.payable_type)?;
+    // A registration that does not name an oracle is assigned its payable type's default oracle
+    if register.oracle_address.is_empty() {
+        register.oracle_address = match type_config
+            .as_ref()
+            .and_then(|config| config.default_oracle.as_ref())
+        {
+            Some(default_oracle) => default_oracle.to_string(),
+            None => return ContractError::invalid_fields(vec!["oracle_address"]).to_result(),
+        };
+    }
+    // The oracle's share of the onboarding cost is held in the contract until approval, so an
+    // oracle that is the contract itself would trap those funds
+    if register.oracle_address == env.contract.address.as_str() {
+        return ContractError::invalid_fields(vec!["oracle_address"]).to_result();
+    }
     // If the sender's address is not listed as an owner address on the target scope for the payable,
-    // then they are not authorized to register this payable.
+    // then they are not authorized to register this payable.  The payable type may narrow this to
+    // a specific owner role or to the scope's value owner.  Authorized registrars register payables
+    // on behalf of scope owners, so they are not checked.
     // Skip this step locally - creating a scope is an unnecessary piece of testing this
-    if !state.is_local
-        && provenance_util
-            .get_scope_by_id(&deps.querier, &register.scope_id)?
-            .owners
-            .into_iter()
-            .filter(|owner| owner.address == info.sender)
-            .count()
-            == 0
-    {
-        return Err(ContractError::Unauthorized);
+    if !state.is_local && !state.is_authorized_registrar(sender) {
+        let scope = provenance_util.get_scope_by_id(&deps.querier, &register.scope_id)?;
+        match type_config.and_then(|config| config.registrant_requirement) {
+            Some(requirement) if !requirement.is_satisfied_by(&scope, sender) => {
+                return ContractError::RegistrantRequirementNotMet {
+                    scope_id: scope.scope_id,
+                    sender: sender.to_string(),
+                    requirement: requirement.describe(),
+                }
+                .to_result();
+            }
+            Some(_) => {}
+            None if !scope.owners.iter().any(|owner| owner.address == *sender) => {
+                return Err(ContractError::Unauthorized);
+            }
+            None => {}
+        }
+    }
+    // A payable that is already expired could never be approved or paid
+    if let Some(expiration_time) = register.expiration_time {
+        if expiration_time <= env.block.time {
+            return ContractError::InvalidPayable {
+                payable_uuid: register.payable_uuid,
+                invalid_reason: "the expiration time must be in the future".into(),
+            }
+            .to_result();
+        }
+        attributes.push(Attribute::new(
+            EXPIRATION_TIME_KEY,
+            expiration_time.seconds().to_string(),
+        ));
+    }
+    if is_payable_type_supply_checked(deps.storage, &register.payable_type)? {
+        validate_payable_total_within_supply(
+            &deps,
+            provenance_util,
+            &register.payable_denom,
+            register.payable_total,
+        )?;
+    }
+    let priority_fee = register.priority_fee.unwrap_or_else(Uint128::zero);
+    if !priority_fee.is_zero() {
+        attributes.push(Attribute::new(
+            PRIORITY_FEE_KEY,
+            Money::new(priority_fee, &state.onboarding_denom).to_string(),
+        ));
+    }
+    if let Some(fee_holiday_id) = &fees.fee_holiday_id {
+        attributes.push(Attribute::new(FEE_HOLIDAY_KEY, fee_holiday_id));
+    }
+    let oracle_gas_rebate = register.oracle_gas_rebate.unwrap_or_else(Uint128::zero);
+    if !oracle_gas_rebate.is_zero() {
+        attributes.push(Attribute::new(
+            ORACLE_GAS_REBATE_KEY,
+            Money::new(oracle_gas_rebate, &state.onboarding_denom).to_string(),
+        ));
     }
     // Ensure that this payable registration can be picked up by event key
     attributes.push(Attribute::new(
@@ -114,21 +479,122 @@ pub fn register_payable_with_util<T: ProvenanceUtil>(
     attributes.push(Attribute::new(ORACLE_ADDRESS_KEY, &register.oracle_address));
     attributes.push(Attribute::new(
         TOTAL_OWED_KEY,
-        &register.payable_total.to_string(),
+        register.payable_total.to_string(),
     ));
     attributes.push(Attribute::new(
         REGISTERED_DENOM_KEY,
         &register.payable_denom,
     ));
     attributes.push(Attribute::new(SCOPE_ID_KEY, &register.scope_id));
+    if let Some(accepted_denoms) = &register.accepted_denoms {
+        attributes.push(Attribute::new(
+            ACCEPTED_DENOMS_KEY,
+            accepted_denoms.join(","),
+        ));
+    }
+    if let Some(late_fee) = &register.late_fee {
+        attributes.push(Attribute::new(
+            LATE_FEE_RATE_KEY,
+            late_fee.daily_rate.to_string(),
+        ));
+    }
+    if let Some(interest) = &register.interest {
+        attributes.push(Attribute::new(
+            INTEREST_RATE_KEY,
+            interest.annual_rate.to_string(),
+        ));
+    }
+    if let Some(escrow_timeout_seconds) = register.escrow_timeout_seconds {
+        attributes.push(Attribute::new(
+            ESCROW_TIMEOUT_KEY,
+            escrow_timeout_seconds.to_string(),
+        ));
+    }
+    if let Some(payment_schedule) = &register.payment_schedule {
+        attributes.push(Attribute::new(
+            INSTALLMENT_COUNT_KEY,
+            payment_schedule.len().to_string(),
+        ));
+    }
+    let authorized_payers = register
+        .authorized_payers
+        .take()
+        .map(|payers| {
+            payers
+                .iter()
+                .map(|payer| deps.api.addr_validate(payer))
+                .collect::<StdResult<Vec<Addr>>>()
+        })
+        .transpose()?;
+    if let Some(authorized_payers) = &authorized_payers {
+        attributes.push(Attribute::new(
+            AUTHORIZED_PAYERS_KEY,
+            authorized_payers
+                .iter()
+                .map(|payer| payer.as_str())
+                .collect::<Vec<&str>>()
+                .join(","),
+        ));
+    }
+    let payee_splits = register
+        .payee_splits
+        .take()
+        .map(|splits| {
+            splits
+                .into_iter()
+                .map(|split| {
+                    Ok(PayeeSplitV1 {
+                        address: deps.api.addr_validate(&split.address)?.into_string(),
+                        weight: split.weight,
+                    })
+                })
+                .collect::<StdResult<Vec<PayeeSplitV1>>>()
+        })
+        .transpose()?;
+    if let Some(payee_splits) = &payee_splits {
+        attributes.push(Attribute::new(
+            PAYEE_SPLITS_KEY,
+            payee_splits
+                .iter()
+                .map(|split| format!("{}:{}", split.address, split.weight))
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+    }
+    // Custom fields and the minimum payment live in local storage rather than on the scope
+    // attribute, so they are pulled out before the register message is consumed
+    let custom_fields = register.custom_fields.clone();
+    let payload_hash = register.payload_hash()?;
+    let registration_nonce = register.registration_nonce.take();
+    let minimum_payment_amount = register.minimum_payment_amount;
+    #[cfg(feature = "escrow")]
+    let escrow_timeout_seconds = register.escrow_timeout_seconds;
     // Tag the scope with an attribute that contains all information about its current payable
     // status
     let scope_attribute = register.to_scope_attribute();
-    messages.push(provenance_util.get_add_initial_attribute_to_scope_msg(
-        &deps.as_ref(),
+    if context.is_re_registration {
+        messages.append(&mut write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?);
+    } else {
+        messages.push(provenance_util.get_add_initial_attribute_to_scope_msg(
+            &deps.as_ref(),
+            &scope_attribute,
+            &state.contract_name,
+        )?);
+    }
+    record_registration_snapshot(
+        deps.storage,
         &scope_attribute,
-        &state.contract_name,
-    )?);
+        &env.block,
+        RegistrationFeesV1 {
+            effective_fee_bps: fees.fee_bps,
+            onboarding_cost_charged: fees.onboarding_cost.clone(),
+        },
+    )?;
     // Store a link between the payable's uuid and the scope id in local storage for queries
     let payable_meta = PayableMetaV2 {
         payable_uuid: scope_attribute.payable_uuid,
@@ -136,94 +602,233 @@ pub fn register_payable_with_util<T: ProvenanceUtil>(
     };
     let mut meta_storage = payable_meta_storage_v2(deps.storage);
     meta_storage.save(payable_meta.payable_uuid.as_bytes(), &payable_meta)?;
-    Ok(Response::new()
-        .add_messages(messages)
-        .add_attributes(attributes))
+    if let Some(custom_fields) = custom_fields {
+        save_payable_custom_fields(deps.storage, &payable_meta.payable_uuid, &custom_fields)?;
+    }
+    // The retained oracle amount is recorded even when zero, so that approval never pays out the
+    // configured amount for a payable registered during a fee holiday
+    save_held_oracle_fee(
+        deps.storage,
+        &payable_meta.payable_uuid,
+        &HeldOracleFeeV1 {
+            registrant: sender.clone(),
+            amount: fees.oracle_amount.amount,
+            denom: fees.oracle_amount.denom.clone(),
+        },
+    )?;
+    if !priority_fee.is_zero() {
+        save_payable_priority_fee(
+            deps.storage,
+            &payable_meta.payable_uuid,
+            &PayablePriorityFeeV1 {
+                amount: priority_fee,
+                denom: state.onboarding_denom.clone(),
+            },
+        )?;
+    }
+    if let Some(minimum_payment_amount) = minimum_payment_amount {
+        save_payable_minimum_payment(
+            deps.storage,
+            &payable_meta.payable_uuid,
+            &minimum_payment_amount,
+        )?;
+    }
+    // Validation rejects escrow timeouts in builds without the escrow feature
+    #[cfg(feature = "escrow")]
+    if let Some(escrow_timeout_seconds) = escrow_timeout_seconds {
+        save_payable_escrow_timeout(
+            deps.storage,
+            &payable_meta.payable_uuid,
+            escrow_timeout_seconds,
+        )?;
+    }
+    if let Some(authorized_payers) = authorized_payers {
+        save_payable_authorized_payers(
+            deps.storage,
+            &payable_meta.payable_uuid,
+            &authorized_payers,
+        )?;
+    }
+    if let Some(payee_splits) = payee_splits {
+        save_payable_payee_splits(deps.storage, &payable_meta.payable_uuid, &payee_splits)?;
+    }
+    if let Some(nonce) = registration_nonce {
+        save_registration_nonce(
+            deps.storage,
+            &payable_meta.payable_uuid,
+            &RegistrationNonceV1 {
+                nonce,
+                registered_by: sender.clone(),
+                payload_hash,
+            },
+        )?;
+    }
+    if !oracle_gas_rebate.is_zero() {
+        save_oracle_gas_rebate(
+            deps.storage,
+            &payable_meta.payable_uuid,
+            &OracleGasRebateV1 {
+                registrant: sender.clone(),
+                amount: oracle_gas_rebate,
+                denom: state.onboarding_denom.clone(),
+            },
+        )?;
+    }
+    update_payable_timeline(deps.storage, &payable_meta.payable_uuid, |timeline| {
+        timeline.registered_at = Some(env.block.time)
+    })?;
+    Ok(RegistrationOutput {
+        messages,
+        attributes,
+    })
+}
+
+/// Ensures that a payable does not claim more of its denom than can possibly exist.  Only marker
+/// denoms have a queryable total supply, so a denom that cannot be resolved to a marker is not
+/// checked.
+pub(crate) fn validate_payable_total_within_supply<T: ProvenanceUtil>(
+    deps: &DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    payable_denom: &str,
+    payable_total: Uint128,
+) -> Result<(), ContractError> {
+    let marker = match provenance_util.get_marker_by_denom(&deps.querier, payable_denom) {
+        Ok(marker) => marker,
+        Err(_) => return Ok(()),
+    };
+    // Marker supply is reported as a Decimal, but is always a whole number of base units
+    let total_supply = Uint128::new(1).mul(marker.total_supply);
+    if payable_total > total_supply {
+        return ContractError::PayableTotalExceedsSupply {
+            payable_total: payable_total.u128(),
+            total_supply: total_supply.u128(),
+            denom: payable_denom.to_string(),
+        }
+        .to_result();
+    }
+    Ok(())
 }
 
 /// A helper struct that contains all output relevant to charging a fee for registration.
 struct FeeChargeResponse {
     fee_charge_message: Option<CosmosMsg<ProvenanceMsg>>,
     fee_refund_message: Option<CosmosMsg<ProvenanceMsg>>,
-    refund_amount: u128,
-    oracle_fee_amount_kept: u128,
+    refund_amount: Money,
+    oracle_fee_amount_kept: Money,
+    effective_fee_bps: u128,
+    onboarding_cost_charged: Money,
 }
 
 /// Digests all relevant input and creates the appropriate fee messages (including an optional
-/// refund to the sender if required).
+/// refund to the sender if required).  Each payable's onboarding cost is charged.  Held funds
+/// (priority fees and oracle gas rebates) must be sent on top of the onboarding cost, but are not
+/// charged here - they stay in the contract until the oracle approves.  Every payable must be
+/// charged in the same onboarding denom, and held funds are only accepted alongside the contract's
+/// onboarding denom, as that is the denom in which they are paid out.  A batch spanning payable
+/// types with different fee percents emits the fee percent of its first payable.
 fn validate_fee_params_get_messages(
     info: &MessageInfo,
     state: &StateV2,
+    payable_fees: &[EffectiveFees],
+    held_funds: Uint128,
+    referral_shares: &[ReferralShare],
 ) -> Result<FeeChargeResponse, ContractError> {
+    let onboarding_denom = match payable_fees.first() {
+        Some(fees) => fees.onboarding_cost.denom.clone(),
+        None => state.onboarding_denom.clone(),
+    };
+    if let Some(fees) = payable_fees
+        .iter()
+        .find(|fees| fees.onboarding_cost.denom != onboarding_denom)
+    {
+        return ContractError::DenomMismatch {
+            expected_denom: onboarding_denom,
+            provided_denom: fees.onboarding_cost.denom.clone(),
+        }
+        .to_result();
+    }
+    if !held_funds.is_zero() && onboarding_denom != state.onboarding_denom {
+        return ContractError::DenomMismatch {
+            expected_denom: state.onboarding_denom.clone(),
+            provided_denom: onboarding_denom,
+        }
+        .to_result();
+    }
     let invalid_funds = info
         .funds
         .iter()
-        .filter(|coin| coin.denom != state.onboarding_denom)
+        .filter(|coin| coin.denom != onboarding_denom)
         .map(|coin| coin.denom.clone())
         .collect::<Vec<String>>();
     if !invalid_funds.is_empty() {
         return Err(ContractError::InvalidFundsProvided {
-            valid_denom: state.onboarding_denom.clone(),
+            valid_denom: onboarding_denom.clone(),
             invalid_denoms: invalid_funds,
         });
     }
-    let onboarding_cost = state.onboarding_cost;
+    let mut onboarding_cost = Money::zero(&onboarding_denom);
+    let mut fee_amount = Money::zero(&onboarding_denom);
+    let mut oracle_amount = Money::zero(&onboarding_denom);
+    for fees in payable_fees {
+        onboarding_cost = onboarding_cost.checked_add(&fees.onboarding_cost)?;
+        fee_amount = fee_amount.checked_add(&fees.fee_amount)?;
+        oracle_amount = oracle_amount.checked_add(&fees.oracle_amount)?;
+    }
+    // Referral shares are carved out of the fee, so the fee collector receives what remains
+    for referral in referral_shares {
+        fee_amount = fee_amount.checked_sub(&referral.share)?;
+    }
+    let amount_needed = onboarding_cost.checked_add(&Money::new(held_funds, &onboarding_denom))?;
     let funds_sent = match info
-        .clone()
         .funds
-        .into_iter()
-        .find(|coin| coin.denom == state.onboarding_denom)
+        .iter()
+        .find(|coin| coin.denom == onboarding_denom)
     {
         Some(coin) => {
-            let amount_sent = coin.amount;
-            if onboarding_cost > amount_sent {
+            let amount_sent = Money::from(coin);
+            if amount_needed.amount > amount_sent.amount {
                 return Err(ContractError::InsufficientFundsProvided {
-                    amount_needed: onboarding_cost.u128(),
-                    amount_provided: amount_sent.u128(),
+                    amount_needed: amount_needed.amount.u128(),
+                    amount_provided: amount_sent.amount.u128(),
                 });
             } else {
                 amount_sent
             }
         }
         None => {
-            if onboarding_cost.u128() > 0 {
+            if !amount_needed.is_zero() {
                 return Err(ContractError::NoFundsProvided {
-                    valid_denom: state.onboarding_denom.clone(),
+                    valid_denom: onboarding_denom.clone(),
                 });
             } else {
-                Uint128::zero()
+                Money::zero(&onboarding_denom)
             }
         }
     };
-    // The collected fee is the fee percent * the onboarding cost.  The remaining amount will stay in
-    // the contract's account, waiting for the oracle to withdraw it
-    let fee_collected_amount = onboarding_cost.mul(state.fee_percent);
-    let fee_charge_message = if fee_collected_amount.u128() > 0 {
-        Some(CosmosMsg::Bank(BankMsg::Send {
-            to_address: state.fee_collection_address.clone().into(),
-            amount: vec![coin(
-                fee_collected_amount.u128(),
-                state.onboarding_denom.clone(),
-            )],
-        }))
+    // The fee amount is sent to the fee collector.  The oracle amount will stay in the contract's
+    // account, waiting for the oracle to withdraw it
+    let fee_charge_message = if !fee_amount.is_zero() {
+        Some(fee_amount.send_to(&state.fee_collection_address))
     } else {
         None
     };
     // If any excess funds are sent beyond the onboarding cost, they should be refunded to the sender
-    let refund_amount = funds_sent - onboarding_cost;
-    let fee_refund_message = if refund_amount.u128() > 0 {
-        Some(CosmosMsg::Bank(BankMsg::Send {
-            to_address: info.sender.clone().into(),
-            amount: vec![coin(refund_amount.u128(), state.onboarding_denom.clone())],
-        }))
+    let refund_amount = funds_sent.checked_sub(&amount_needed)?;
+    let fee_refund_message = if !refund_amount.is_zero() {
+        Some(refund_amount.send_to(&info.sender))
     } else {
         None
     };
     Ok(FeeChargeResponse {
         fee_charge_message,
         fee_refund_message,
-        refund_amount: refund_amount.u128(),
-        oracle_fee_amount_kept: (onboarding_cost - fee_collected_amount).u128(),
+        refund_amount,
+        oracle_fee_amount_kept: oracle_amount,
+        effective_fee_bps: match payable_fees.first() {
+            Some(fees) => fees.fee_bps.u128(),
+            None => calculate_effective_fees(state).fee_bps.u128(),
+        },
+        onboarding_cost_charged: onboarding_cost,
     })
 }
 
@@ -231,8 +836,15 @@ fn validate_fee_params_get_messages(
 mod tests {
     use crate::core::error::ContractError;
     use crate::core::error::ContractError::Std;
-    use crate::core::state::PayableScopeAttribute;
+    use crate::core::money::Money;
+    use crate::core::state::{
+        add_supported_payable_type, load_latest_payable_snapshot, load_payable_priority_fee,
+        load_payable_snapshot, set_payable_type_supply_checked, PayablePriorityFeeV1,
+        PayableScopeAttribute, RegistrationFeesV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
     use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
     use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
     use crate::testutil::test_utilities::{
         get_duped_scope, setup_test_suite, single_attribute_for_key, test_instantiate, InstArgs,
@@ -241,16 +853,20 @@ mod tests {
         DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
     };
     use crate::util::constants::{
+        EFFECTIVE_FEE_BPS_KEY, EXPIRATION_TIME_KEY, ONBOARDING_COST_CHARGED_KEY,
         ORACLE_ADDRESS_KEY, ORACLE_FUNDS_KEPT, PAYABLE_REGISTERED_KEY, PAYABLE_TYPE_KEY,
-        PAYABLE_UUID_KEY, REFUND_AMOUNT_KEY, REGISTERED_DENOM_KEY, SCOPE_ID_KEY, TOTAL_OWED_KEY,
+        PAYABLE_UUID_KEY, PRIORITY_FEE_KEY, REFUND_AMOUNT_KEY, REGISTERED_DENOM_KEY,
+        REGISTRATION_RETRIED_KEY, SCOPE_ID_KEY, TOTAL_OWED_KEY,
     };
-    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
     use cosmwasm_std::StdError::GenericErr;
-    use cosmwasm_std::{from_binary, BankMsg, CosmosMsg};
+    use cosmwasm_std::{coins, from_binary, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{
-        AttributeMsgParams, AttributeValueType, ProvenanceMsg, ProvenanceMsgParams,
+        AttributeMsgParams, AttributeValueType, Marker, MarkerStatus, MarkerType, ProvenanceMsg,
+        ProvenanceMsgParams,
     };
+    use std::str::FromStr;
 
     #[test]
     fn test_register_valid_no_refund() {
@@ -261,7 +877,7 @@ mod tests {
             test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
                 .unwrap();
         assert_eq!(
-            8,
+            10,
             response.attributes.len(),
             "expected all registration attributes to be recorded"
         );
@@ -305,6 +921,16 @@ mod tests {
             single_attribute_for_key(&response, ORACLE_FUNDS_KEPT),
             "the oracle funds kept should equal to total amount sent (100) - total amount sent * fee percent (75%)"
         );
+        assert_eq!(
+            "7500",
+            single_attribute_for_key(&response, EFFECTIVE_FEE_BPS_KEY),
+            "the EFFECTIVE_FEE_BPS_KEY value should equate to the fee percent (75%) in basis points",
+        );
+        assert_eq!(
+            "100/nhash",
+            single_attribute_for_key(&response, ONBOARDING_COST_CHARGED_KEY),
+            "the ONBOARDING_COST_CHARGED_KEY value should equate to the onboarding cost charged",
+        );
         assert_eq!(
             2,
             response.messages.len(),
@@ -354,7 +980,7 @@ mod tests {
         )
         .unwrap();
         assert_eq!(
-            9,
+            11,
             response.attributes.len(),
             "expected all registration attributes to be recorded"
         );
@@ -449,6 +1075,198 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_register_valid_with_priority_fee() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // Send the onboarding cost (100) + the priority fee (50) + 10 extra to be refunded
+        let mut register = TestRegisterPayable::default_with_amount(160);
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        let response = test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        assert_eq!(
+            "50/nhash",
+            single_attribute_for_key(&response, PRIORITY_FEE_KEY),
+            "the PRIORITY_FEE_KEY value should equate to the priority fee paid",
+        );
+        assert_eq!(
+            "100/nhash",
+            single_attribute_for_key(&response, ONBOARDING_COST_CHARGED_KEY),
+            "the priority fee should not be included in the onboarding cost charged",
+        );
+        assert_eq!(
+            "10/nhash",
+            single_attribute_for_key(&response, REFUND_AMOUNT_KEY),
+            "only the funds beyond the onboarding cost and priority fee should be refunded",
+        );
+        assert_eq!(
+            Some(PayablePriorityFeeV1 {
+                amount: Uint128::new(50),
+                denom: DEFAULT_ONBOARDING_DENOM.to_string(),
+            }),
+            load_payable_priority_fee(deps.as_ref().storage, DEFAULT_PAYABLE_UUID).unwrap(),
+            "the priority fee should be recorded for the payable with the denom it was paid in",
+        );
+    }
+
+    #[test]
+    fn test_register_records_fees_in_registration_snapshot() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let mut register = TestRegisterPayable::default_with_amount(150);
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        let snapshot = load_payable_snapshot(deps.as_ref().storage, DEFAULT_PAYABLE_UUID, 0)
+            .unwrap()
+            .expect("registration should record the payable's first snapshot");
+        assert_eq!(
+            Some(RegistrationFeesV1 {
+                effective_fee_bps: Uint128::new(7500),
+                onboarding_cost_charged: Money::new(100u128, DEFAULT_ONBOARDING_DENOM),
+            }),
+            snapshot.registration_fees,
+            "the registration snapshot should record the fee percent and onboarding cost charged",
+        );
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        assert_eq!(
+            None,
+            load_latest_payable_snapshot(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .expect("approval should record a snapshot")
+                .registration_fees,
+            "only the registration snapshot should carry registration fees",
+        );
+    }
+
+    #[test]
+    fn test_register_priority_fee_insufficient_funds_provided() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        // The onboarding cost is covered, but the priority fee is not
+        let mut register = TestRegisterPayable::default_with_amount(120);
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        let error = test_register_payable(&mut deps, &provenance_util, register).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::InsufficientFundsProvided {
+                    amount_needed: 150,
+                    amount_provided: 120,
+                }
+            ),
+            "the priority fee should be required on top of the onboarding cost, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_register_payable_total_exceeds_marker_supply() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        deps.querier
+            .with_markers(vec![get_marker(DEFAULT_PAYABLE_DENOM, "500")]);
+        set_payable_type_supply_checked(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE, true).unwrap();
+        let error =
+            test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+                .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::PayableTotalExceedsSupply {
+                    payable_total: 1000,
+                    total_supply: 500,
+                    ..
+                }
+            ),
+            "a payable total above the marker supply should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_register_supply_check_disabled_for_payable_type() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        deps.querier
+            .with_markers(vec![get_marker(DEFAULT_PAYABLE_DENOM, "500")]);
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+            .expect("registration should succeed when the supply check is not enabled");
+    }
+
+    #[test]
+    fn test_register_supply_check_skips_non_marker_denom() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        set_payable_type_supply_checked(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE, true).unwrap();
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+            .expect("registration should succeed when the payable denom is not a marker");
+    }
+
+    #[test]
+    fn test_register_contract_as_oracle() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let failure = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    oracle_address: MOCK_CONTRACT_ADDR.to_string(),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        match failure {
+            ContractError::InvalidFields { fields } => assert_eq!(
+                vec!["oracle_address".to_string()],
+                fields,
+                "the oracle address should be rejected when it is the contract's address",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", failure),
+        };
+    }
+
+    #[test]
+    fn test_register_expiration_time_in_past() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let failure = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    expiration_time: Some(mock_env().block.time),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected an already-expired payable to be rejected, but got: {:?}",
+            failure,
+        );
+        let response = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    expiration_time: Some(mock_env().block.time.plus_seconds(1)),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            mock_env().block.time.plus_seconds(1).seconds().to_string(),
+            single_attribute_for_key(&response, EXPIRATION_TIME_KEY),
+            "the EXPIRATION_TIME_KEY value should equate to the expiration time in seconds",
+        );
+    }
+
     #[test]
     fn test_register_invalid_fund_denom() {
         let mut deps = mock_dependencies(&[]);
@@ -535,6 +1353,7 @@ mod tests {
         // Skip registering a fake scope, causing the contract to fail to find one. Using test_instantiate
         // instead of setup_test_suite will skip mocking a targeted scope
         test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        add_supported_payable_type(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE).unwrap();
         let failure = test_register_payable(
             &mut deps,
             &MockProvenanceUtil::new(),
@@ -554,6 +1373,7 @@ mod tests {
     fn test_register_invalid_sender() {
         let mut deps = mock_dependencies(&[]);
         test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        add_supported_payable_type(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE).unwrap();
         // Register a scope with a different owner than the sender to simulate the situation
         deps.querier
             .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "another-guy"));
@@ -568,4 +1388,120 @@ mod tests {
             "the error should show that the sender is unauthorized to make this request"
         );
     }
+
+    #[test]
+    fn test_register_retry_with_registration_nonce() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let with_nonce = |sender: &str, nonce: &str| {
+            let mut register = TestRegisterPayable::default_with_sender(sender);
+            register.register_payable.registration_nonce = Some(nonce.to_string());
+            register
+        };
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            with_nonce(DEFAULT_INFO_NAME, "n1"),
+        )
+        .expect("the original registration should succeed");
+        let response = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            with_nonce(DEFAULT_INFO_NAME, "n1"),
+        )
+        .expect("an identical retry should succeed without registering again");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, REGISTRATION_RETRIED_KEY),
+        );
+        assert_eq!(
+            format!("100/{}", DEFAULT_ONBOARDING_DENOM),
+            single_attribute_for_key(&response, REFUND_AMOUNT_KEY),
+        );
+        match &response.messages.as_slice() {
+            [msg] => match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                    assert_eq!(DEFAULT_INFO_NAME, to_address);
+                    assert_eq!(&coins(100, DEFAULT_ONBOARDING_DENOM), amount);
+                }
+                msg => panic!("unexpected message emitted for a retry: {:?}", msg),
+            },
+            msgs => panic!("a retry should only refund its funds, but got: {:?}", msgs),
+        }
+        let mut changed_registration = with_nonce(DEFAULT_INFO_NAME, "n1");
+        changed_registration.register_payable.payable_total = Uint128::new(5000);
+        let error =
+            test_register_payable(&mut deps, &provenance_util, changed_registration).unwrap_err();
+        assert!(
+            matches!(error, ContractError::RegistrationNonceReused { .. }),
+            "a different registration reusing the nonce should be rejected, but got: {:?}",
+            error,
+        );
+        let error = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            with_nonce(DEFAULT_INFO_NAME, "n2"),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::DuplicateRegistration { .. }),
+            "a registration with a different nonce should be a duplicate, but got: {:?}",
+            error,
+        );
+        let error = test_register_payable(
+            &mut deps,
+            &provenance_util,
+            with_nonce("other-registrant", "n1"),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the original registrant's retries should be recognized, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_register_authorized_registrar() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                authorized_registrars: Some(vec!["onboarding-service".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        add_supported_payable_type(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE).unwrap();
+        // The scope is owned by an address other than the registrar
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, DEFAULT_INFO_NAME));
+        let response = test_register_payable(
+            &mut deps,
+            &MockProvenanceUtil::new(),
+            TestRegisterPayable::default_with_sender("onboarding-service"),
+        )
+        .expect("an authorized registrar should be able to register a scope that it does not own");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_REGISTERED_KEY),
+        );
+    }
+
+    fn get_marker(denom: &str, total_supply: &str) -> Marker {
+        Marker {
+            address: Addr::unchecked("marker-address"),
+            allow_forced_transfer: false,
+            coins: vec![],
+            account_number: 1,
+            sequence: 0,
+            manager: String::new(),
+            permissions: vec![],
+            status: MarkerStatus::Active,
+            denom: denom.to_string(),
+            total_supply: Decimal::from_str(total_supply).unwrap(),
+            marker_type: MarkerType::Coin,
+            supply_fixed: true,
+        }
+    }
 }
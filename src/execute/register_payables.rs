@@ -0,0 +1,336 @@
+use crate::core::error::ContractError;
+use crate::core::state::config_read_v2;
+use crate::execute::referral::route_referral_shares;
+use crate::execute::register_payable::{
+    charge_registration_fees, record_payable_registration, RegisterPayableV2, RegistrationContext,
+};
+use crate::util::fees::calculate_registration_fees;
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+
+/// Contains all payables to be registered with the contract in a single execution.
+pub struct RegisterPayablesV1 {
+    pub payables: Vec<RegisterPayableV2>,
+}
+
+/// Parent function path for the contract to register multiple payables.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn register_payables(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    register: RegisterPayablesV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    register_payables_with_util(deps, &ProvenanceUtilImpl, env, info, register)
+}
+
+/// Registers multiple payables with the contract with the following steps:
+/// - Ensures that the contract is not paused.
+/// - Charges each payable the onboarding cost configured for its payable type, or the contract's
+///   if the type has no config, reduced by any fee holiday that covers the type, plus the sum of
+///   all optional priority fees and oracle gas rebates, refunding any excess funds.  Every payable
+///   in the batch must be charged in the same onboarding denom.
+/// - Routes the configured share of each referred payable's collected fee to its referrer.
+/// - Registers each payable exactly as a single registration would, including verifying that the
+///   sender owns each related scope.
+/// - Emits the registration attributes of every payable.
+pub fn register_payables_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    register: RegisterPayablesV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_not_paused()?;
+    let mut held_funds_total = Uint128::zero();
+    let mut payable_fees = vec![];
+    for payable in register.payables.iter() {
+        held_funds_total = held_funds_total
+            .checked_add(payable.held_funds()?)
+            .map_err(StdError::from)?;
+        payable_fees.push(calculate_registration_fees(
+            deps.storage,
+            &state,
+            &payable.payable_type,
+            env.block.time,
+        )?);
+    }
+    let referral_shares = route_referral_shares(
+        deps.storage,
+        &register
+            .payables
+            .iter()
+            .map(|payable| payable.referrer.as_ref())
+            .zip(payable_fees.iter())
+            .collect::<Vec<_>>(),
+    )?;
+    let fee_output = charge_registration_fees(
+        &info,
+        &state,
+        &payable_fees,
+        held_funds_total,
+        &referral_shares,
+    )?;
+    let mut response = ResponseBuilder::new()
+        .messages(fee_output.messages)
+        .attributes(fee_output.attributes);
+    for (payable, fees) in register.payables.into_iter().zip(payable_fees.iter()) {
+        let registration_output = record_payable_registration(
+            deps.branch(),
+            provenance_util,
+            &env,
+            &RegistrationContext {
+                sender: &info.sender,
+                state: &state,
+                fees,
+                is_re_registration: false,
+            },
+            payable,
+        )?;
+        response = response
+            .messages(registration_output.messages)
+            .attributes(registration_output.attributes);
+    }
+    Ok(response.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{add_supported_payable_type, payable_meta_storage_read_v2};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::execute::register_payables::{register_payables_with_util, RegisterPayablesV1};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::register_payable_helpers::TestRegisterPayable;
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, test_instantiate, InstArgs,
+        DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID,
+        DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        ONBOARDING_COST_CHARGED_KEY, ORACLE_FUNDS_KEPT, PAYABLE_REGISTERED_KEY, PRIORITY_FEE_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    const SECOND_PAYABLE_UUID: &str = "4cb54a6e-83ab-11ec-a486-eb4f069082c5";
+    const SECOND_SCOPE_ID: &str = "scope1qpxt2jnwsw43rm9ysm457p5sstzs6pcylq";
+
+    #[test]
+    fn test_register_payables_success() {
+        let mut deps = mock_dependencies(&[]);
+        // Only a single scope can be mocked at a time, so local mode is used to skip the scope
+        // ownership checks for the second payable
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                is_local: true,
+                ..Default::default()
+            },
+        );
+        let response = register_payables_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(200, DEFAULT_ONBOARDING_DENOM)]),
+            RegisterPayablesV1 {
+                payables: vec![
+                    TestRegisterPayable::default_register_payable(),
+                    second_register_payable(),
+                ],
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            "200/nhash",
+            single_attribute_for_key(&response, ONBOARDING_COST_CHARGED_KEY),
+            "the onboarding cost should be charged once for each payable",
+        );
+        assert_eq!(
+            "50/nhash",
+            single_attribute_for_key(&response, ORACLE_FUNDS_KEPT),
+            "the oracle funds kept should equal the total charged (200) - total charged * fee percent (75%)",
+        );
+        let registered_uuids = response
+            .attributes
+            .iter()
+            .filter(|attr| attr.key == PAYABLE_REGISTERED_KEY)
+            .map(|attr| attr.value.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            vec![DEFAULT_PAYABLE_UUID, SECOND_PAYABLE_UUID],
+            registered_uuids,
+            "a registration attribute should be emitted for each payable, in order",
+        );
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "expected a single fee charge and an attribute message for each payable",
+        );
+        let fee_amount = response
+            .messages
+            .iter()
+            .find_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
+                    Some(amount.first().unwrap().amount)
+                }
+                _ => None,
+            })
+            .expect("a fee charge message should be included");
+        assert_eq!(
+            150,
+            fee_amount.u128(),
+            "the fee charged should be 75% of the total onboarding cost of 200",
+        );
+        let meta_storage = payable_meta_storage_read_v2(&deps.storage);
+        assert_eq!(
+            DEFAULT_SCOPE_ID,
+            meta_storage
+                .load(DEFAULT_PAYABLE_UUID.as_bytes())
+                .unwrap()
+                .scope_id,
+            "the first payable's meta should be stored",
+        );
+        assert_eq!(
+            SECOND_SCOPE_ID,
+            meta_storage
+                .load(SECOND_PAYABLE_UUID.as_bytes())
+                .unwrap()
+                .scope_id,
+            "the second payable's meta should be stored",
+        );
+    }
+
+    #[test]
+    fn test_register_payables_charges_summed_priority_fees() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                is_local: true,
+                ..Default::default()
+            },
+        );
+        let mut second_payable = second_register_payable();
+        second_payable.priority_fee = Some(Uint128::new(25));
+        let failure = register_payables_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(200, DEFAULT_ONBOARDING_DENOM)]),
+            RegisterPayablesV1 {
+                payables: vec![
+                    TestRegisterPayable::default_register_payable(),
+                    second_payable.clone(),
+                ],
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                failure,
+                ContractError::InsufficientFundsProvided {
+                    amount_needed: 225,
+                    amount_provided: 200,
+                }
+            ),
+            "the priority fee should be required on top of the onboarding cost, but got: {:?}",
+            failure,
+        );
+        let response = register_payables_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(225, DEFAULT_ONBOARDING_DENOM)]),
+            RegisterPayablesV1 {
+                payables: vec![
+                    TestRegisterPayable::default_register_payable(),
+                    second_payable,
+                ],
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            "25/nhash",
+            single_attribute_for_key(&response, PRIORITY_FEE_KEY),
+            "only the payable with a priority fee should emit a priority fee attribute",
+        );
+    }
+
+    #[test]
+    fn test_register_payables_insufficient_funds_provided() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                is_local: true,
+                ..Default::default()
+            },
+        );
+        let failure = register_payables_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(100, DEFAULT_ONBOARDING_DENOM)]),
+            RegisterPayablesV1 {
+                payables: vec![
+                    TestRegisterPayable::default_register_payable(),
+                    second_register_payable(),
+                ],
+            },
+        )
+        .unwrap_err();
+        match failure {
+            ContractError::InsufficientFundsProvided {
+                amount_needed,
+                amount_provided,
+            } => {
+                assert_eq!(
+                    200, amount_needed,
+                    "expected the amount needed to be the onboarding cost for both payables"
+                );
+                assert_eq!(
+                    100, amount_provided,
+                    "expected the amount provided to reflect the funds sent"
+                );
+            }
+            _ => panic!("unexpected contract error encountered: {:?}", failure),
+        };
+    }
+
+    #[test]
+    fn test_register_payables_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        add_supported_payable_type(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE).unwrap();
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "another-guy"));
+        let failure = register_payables_with_util(
+            deps.as_mut(),
+            &MockProvenanceUtil::new(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(100, DEFAULT_ONBOARDING_DENOM)]),
+            RegisterPayablesV1 {
+                payables: vec![TestRegisterPayable::default_register_payable()],
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::Unauthorized),
+            "the error should show that the sender does not own the scope, but got: {:?}",
+            failure,
+        );
+    }
+
+    fn second_register_payable() -> RegisterPayableV2 {
+        RegisterPayableV2 {
+            payable_uuid: SECOND_PAYABLE_UUID.to_string(),
+            scope_id: SECOND_SCOPE_ID.to_string(),
+            ..TestRegisterPayable::default_register_payable()
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, load_restructure_proposal, remove_restructure_proposal,
+    save_restructure_proposal, RestructureProposalV1,
+};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    PAYABLE_RESTRUCTURED_KEY, PREVIOUS_TOTAL_OWED_KEY, RESTRUCTURE_CONFIRMED_BY_KEY,
+    RESTRUCTURE_CONFIRMED_KEY, TOTAL_OWED_KEY, TOTAL_REMAINING_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the terms that the sender would like to confirm for restructuring an approved payable.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RestructurePayableV1 {
+    pub payable_uuid: String,
+    pub new_total: Uint128,
+}
+
+/// Parent function path for the contract to restructure a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn restructure_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    restructure: RestructurePayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    restructure_payable_with_util(deps, &ProvenanceUtilImpl, env, info, restructure)
+}
+
+/// Records one party's confirmation of new terms for an approved payable, applying the terms once
+/// both the payee and the oracle have confirmed them, with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered, approved by its oracle, and not written
+///   off.  Unapproved payables can be amended by their scope owner alone via UpdatePayableTotal.
+/// - Verifies that the sender is the payable's oracle or an owner of its scope (the payee).
+/// - Ensures that the new total differs from the current total and exceeds the amount already paid.
+/// - Records the sender's confirmation.  Confirming different terms than the pending proposal
+///   replaces the proposal and discards any confirmation of the previous terms.
+/// - Once both parties have confirmed, rewrites the scope attribute with the new total owed and a
+///   remaining owed that preserves all payments made, then clears the proposal.
+pub fn restructure_payable_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    restructure: RestructurePayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &restructure.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: restructure.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if !scope_attribute.oracle_approved {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "only approved payables can be restructured".into(),
+        }
+        .to_result();
+    }
+    if scope_attribute.is_written_off {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "written off payables cannot be restructured".into(),
+        }
+        .to_result();
+    }
+    let is_oracle = info.sender == scope_attribute.oracle_address;
+    // Scope ownership cannot be verified locally, matching the registration process, so any
+    // sender other than the oracle is treated as the payee
+    let is_payee = if state.is_local {
+        !is_oracle
+    } else {
+        provenance_util
+            .get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?
+            .owners
+            .into_iter()
+            .any(|owner| owner.address == info.sender)
+    };
+    if !is_oracle && !is_payee {
+        return Err(ContractError::Unauthorized);
+    }
+    if restructure.new_total == scope_attribute.payable_total_owed {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the new total must differ from the current total".into(),
+        }
+        .to_result();
+    }
+    let amount_paid = scope_attribute.payable_total_owed - scope_attribute.payable_remaining_owed;
+    if restructure.new_total <= amount_paid {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the new total must exceed the amount already paid".into(),
+        }
+        .to_result();
+    }
+    let mut proposal = match load_restructure_proposal(deps.storage, &scope_attribute.payable_uuid)?
+    {
+        Some(proposal) if proposal.new_total == restructure.new_total => proposal,
+        _ => RestructureProposalV1 {
+            payable_uuid: scope_attribute.payable_uuid.clone(),
+            new_total: restructure.new_total,
+            payee_confirmation: None,
+            oracle_confirmation: None,
+        },
+    };
+    if is_payee {
+        proposal.payee_confirmation = Some(info.sender.clone());
+    }
+    if is_oracle {
+        proposal.oracle_confirmation = Some(info.sender.clone());
+    }
+    let response = ResponseBuilder::for_payable(&scope_attribute)
+        .attribute(RESTRUCTURE_CONFIRMED_KEY, &scope_attribute.payable_uuid)
+        .attribute(RESTRUCTURE_CONFIRMED_BY_KEY, info.sender.as_str())
+        .attribute(TOTAL_OWED_KEY, restructure.new_total.to_string())
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?;
+    if proposal.payee_confirmation.is_none() || proposal.oracle_confirmation.is_none() {
+        save_restructure_proposal(deps.storage, &proposal)?;
+        return Ok(response.build());
+    }
+    let previous_total = scope_attribute.payable_total_owed;
+    scope_attribute.payable_total_owed = restructure.new_total;
+    scope_attribute.payable_remaining_owed = restructure.new_total - amount_paid;
+    scope_attribute.settled_at = None;
+    remove_restructure_proposal(deps.storage, &scope_attribute.payable_uuid);
+    Ok(response
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYABLE_RESTRUCTURED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PREVIOUS_TOTAL_OWED_KEY, previous_total.to_string())
+        .attribute(
+            TOTAL_REMAINING_KEY,
+            scope_attribute.payable_remaining_owed.to_string(),
+        )
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::load_restructure_proposal;
+    use crate::execute::restructure_payable::{
+        restructure_payable_with_util, RestructurePayableV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_CONTRACT_NAME,
+        DEFAULT_INFO_NAME, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        PAYABLE_RESTRUCTURED_KEY, PREVIOUS_TOTAL_OWED_KEY, RESTRUCTURE_CONFIRMED_BY_KEY,
+        TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_restructure_applies_after_both_confirmations() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        setup_approved_payable(&mut deps, &provenance_util);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .unwrap();
+        let response =
+            test_restructure(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 600).unwrap();
+        assert_eq!(
+            DEFAULT_ORACLE_ADDRESS,
+            single_attribute_for_key(&response, RESTRUCTURE_CONFIRMED_BY_KEY),
+            "the confirming oracle should be emitted as an attribute",
+        );
+        assert!(
+            response.messages.is_empty(),
+            "no attribute should be written until both parties confirm",
+        );
+        let response =
+            test_restructure(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 600).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_RESTRUCTURED_KEY),
+            "the PAYABLE_RESTRUCTURED_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL.to_string(),
+            single_attribute_for_key(&response, PREVIOUS_TOTAL_OWED_KEY),
+            "the PREVIOUS_TOTAL_OWED_KEY value should equate to the registered total",
+        );
+        assert_eq!(
+            "400",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the remaining owed should preserve the payment already made",
+        );
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            600,
+            attribute.payable_total_owed.u128(),
+            "the scope attribute should be rewritten with the new total owed",
+        );
+        assert_eq!(
+            400,
+            attribute.payable_remaining_owed.u128(),
+            "the scope attribute should be rewritten with the new remaining owed",
+        );
+        assert!(
+            load_restructure_proposal(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the proposal should be removed once applied",
+        );
+    }
+
+    #[test]
+    fn test_restructure_with_different_terms_resets_confirmations() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        test_restructure(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 600).unwrap();
+        test_restructure(&mut deps, &provenance_util, DEFAULT_ORACLE_ADDRESS, 700).unwrap();
+        let proposal = load_restructure_proposal(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+            .unwrap()
+            .expect("the proposal should be stored");
+        assert_eq!(
+            700,
+            proposal.new_total.u128(),
+            "the proposal should be replaced by the new terms",
+        );
+        assert_eq!(
+            None, proposal.payee_confirmation,
+            "the payee's confirmation of the previous terms should be discarded",
+        );
+        assert_eq!(
+            Some(Addr::unchecked(DEFAULT_ORACLE_ADDRESS)),
+            proposal.oracle_confirmation,
+            "the oracle's confirmation should be recorded",
+        );
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL,
+            attribute.payable_total_owed.u128(),
+            "the total owed should be unchanged while the terms are unconfirmed",
+        );
+    }
+
+    #[test]
+    fn test_restructure_rejects_unrelated_sender() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        let failure =
+            test_restructure(&mut deps, &provenance_util, "some-stranger", 600).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::Unauthorized),
+            "expected an unrelated sender to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_restructure_rejects_unapproved_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let failure =
+            test_restructure(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 600).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected an unapproved payable to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_restructure_rejects_total_not_exceeding_amount_paid() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .unwrap();
+        let failure =
+            test_restructure(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 200).unwrap_err();
+        match failure {
+            ContractError::InvalidPayable { invalid_reason, .. } => {
+                assert!(
+                    invalid_reason.contains("already paid"),
+                    "expected the failure to reference the amount paid, but got: {}",
+                    invalid_reason,
+                );
+            }
+            _ => panic!("unexpected contract error encountered: {:?}", failure),
+        }
+    }
+
+    fn setup_approved_suite(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        setup_approved_payable(deps, &provenance_util);
+        provenance_util
+    }
+
+    fn setup_approved_payable(deps: &mut MockOwnedDeps, provenance_util: &MockProvenanceUtil) {
+        test_register_payable(deps, provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, provenance_util, TestOracleApproval::default()).unwrap();
+    }
+
+    fn test_restructure(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        new_total: u128,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = restructure_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            RestructurePayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                new_total: Uint128::new(new_total),
+            },
+        );
+        provenance_util.bind_captured_attribute_named(deps, DEFAULT_CONTRACT_NAME);
+        response
+    }
+}
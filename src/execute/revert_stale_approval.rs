@@ -0,0 +1,270 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    config_read_v2, load_payable_timeline, remove_oracle_approval_context, PayableScopeAttribute,
+};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::APPROVAL_REVERTED_KEY;
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult, Storage, Timestamp};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable that the sender would like to revert to unapproved after its oracle
+/// approval went stale.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevertStaleApprovalV1 {
+    pub payable_uuid: String,
+}
+
+/// Parent function path for the contract to revert a stale approval.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn revert_stale_approval(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    revert: RevertStaleApprovalV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    revert_stale_approval_with_util(deps, &ProvenanceUtilImpl, env, info, revert)
+}
+
+/// Reverts a payable with a stale oracle approval to unapproved with the following steps:
+/// - Verifies that no funds were sent.  Any sender may revert a stale approval.
+/// - Ensures that the payable targeted has been registered.
+/// - Ensures that the payable's approval is stale: the oracle approved it with a validity window,
+///   the window has ended, and no payment was made within it.
+/// - Removes the approval context from local storage and rewrites the scope attribute without the
+///   approval.  The payable must be approved by its oracle again before it can be paid, and the
+///   oracle is not paid again for the re-validation.
+pub fn revert_stale_approval_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    revert: RevertStaleApprovalV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &revert.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: revert.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    if !is_approval_stale(deps.storage, &scope_attribute, env.block.time)? {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the payable's oracle approval is not stale".into(),
+        }
+        .to_result();
+    }
+    scope_attribute.oracle_approved = false;
+    scope_attribute.oracle_approval_context = None;
+    remove_oracle_approval_context(deps.storage, &scope_attribute.payable_uuid);
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(APPROVAL_REVERTED_KEY, &scope_attribute.payable_uuid)
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+/// Determines if an approved payable's validity window has ended without a payment being made
+/// since its approval.  Approvals without a validity window never go stale.
+pub(crate) fn is_approval_stale(
+    storage: &dyn Storage,
+    scope_attribute: &PayableScopeAttribute,
+    now: Timestamp,
+) -> StdResult<bool> {
+    let (approved_at, valid_until) = match (
+        scope_attribute
+            .oracle_approval_context
+            .as_ref()
+            .and_then(|context| context.approved_at),
+        scope_attribute.approval_valid_until(),
+    ) {
+        (Some(approved_at), Some(valid_until)) if scope_attribute.oracle_approved => {
+            (approved_at, valid_until)
+        }
+        _ => return Ok(false),
+    };
+    if now < valid_until {
+        return Ok(false);
+    }
+    Ok(
+        !load_payable_timeline(storage, &scope_attribute.payable_uuid)?.is_some_and(|timeline| {
+            timeline
+                .payment_times
+                .iter()
+                .any(|paid_at| *paid_at >= approved_at && *paid_at < valid_until)
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::revert_stale_approval::{
+        revert_stale_approval_with_util, RevertStaleApprovalV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{APPROVAL_REVERTED_KEY, APPROVAL_VALID_UNTIL_KEY};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{BankMsg, CosmosMsg, Env, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const APPROVAL_VALID_FOR: u64 = 3600;
+
+    #[test]
+    fn test_stale_approval_reverted_until_re_validated() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_payable(&mut deps);
+        let error = revert(&mut deps, &provenance_util, mock_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "an approval within its validity window should not be reverted, but got: {:?}",
+            error,
+        );
+        let stale_env = stale_env();
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: stale_env.clone(),
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotReadyForPayment { .. }),
+            "a stale approval should not authorize payments, but got: {:?}",
+            error,
+        );
+        let response = revert(&mut deps, &provenance_util, stale_env.clone())
+            .expect("any sender should be able to revert a stale approval");
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, APPROVAL_REVERTED_KEY),
+        );
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert!(
+            !scope_attribute.oracle_approved && scope_attribute.oracle_approval_context.is_none(),
+            "the payable should be reverted to unapproved",
+        );
+        let response = test_oracle_approval(
+            &mut deps,
+            &provenance_util,
+            TestOracleApproval {
+                env: stale_env.clone(),
+                ..TestOracleApproval::default()
+            },
+        )
+        .expect("the oracle should be able to re-validate the payable");
+        assert!(
+            response
+                .messages
+                .iter()
+                .all(|msg| !matches!(msg.msg, CosmosMsg::Bank(BankMsg::Send { .. }))),
+            "the oracle should not be paid again for a re-validation",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: stale_env,
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .expect("payments should resume once the payable is re-validated");
+    }
+
+    #[test]
+    fn test_approval_with_payment_in_window_is_not_stale() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_payable(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        let error = revert(&mut deps, &provenance_util, stale_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "an approval that was followed by a payment should not go stale, but got: {:?}",
+            error,
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: stale_env(),
+                ..TestMakePayment::default_with_amount(100)
+            },
+        )
+        .expect("payments should continue after the window once a payment was made within it");
+    }
+
+    fn setup_approved_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let mut approval = TestOracleApproval::default();
+        approval.oracle_approval.approval_valid_for = Some(APPROVAL_VALID_FOR);
+        let response = test_oracle_approval(deps, &provenance_util, approval).unwrap();
+        assert_eq!(
+            mock_env()
+                .block
+                .time
+                .plus_seconds(APPROVAL_VALID_FOR)
+                .seconds()
+                .to_string(),
+            single_attribute_for_key(&response, APPROVAL_VALID_UNTIL_KEY),
+        );
+        provenance_util
+    }
+
+    fn stale_env() -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(APPROVAL_VALID_FOR);
+        env
+    }
+
+    fn revert(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        env: Env,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = revert_stale_approval_with_util(
+            deps.as_mut(),
+            provenance_util,
+            env,
+            mock_info("anyone", &[]),
+            RevertStaleApprovalV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        );
+        provenance_util.bind_captured_attribute(deps);
+        response
+    }
+}
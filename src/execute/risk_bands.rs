@@ -0,0 +1,247 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, remove_risk_bands, save_risk_bands, RiskBandV1};
+use crate::util::constants::RISK_BANDS_SET_KEY;
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the bands that the contract admin would like payments keyed off, by the risk score that
+/// each payable's oracle assigned it.  Providing no bands disables risk-based payment rules.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetRiskBandsV1 {
+    pub risk_bands: Vec<RiskBandV1>,
+}
+
+/// Replaces the contract's risk bands with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the bands in local storage, ordered by their minimum score, or removes them if none
+///   were provided.  The new bands apply to every subsequent payment, including payments on
+///   payables that were approved before the update, while held back payments are unaffected.
+pub fn set_risk_bands(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_bands: SetRiskBandsV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    let mut risk_bands = set_bands.risk_bands;
+    if risk_bands.is_empty() {
+        remove_risk_bands(deps.storage);
+    } else {
+        risk_bands.sort_by_key(|band| band.min_score);
+        save_risk_bands(deps.storage, &risk_bands)?;
+    }
+    Ok(ResponseBuilder::new()
+        .attribute(RISK_BANDS_SET_KEY, risk_bands.len().to_string())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{QueryMsg, RiskBandsResponse};
+    use crate::core::state::RiskBandV1;
+    use crate::execute::quarantine::{
+        release_quarantined_payment_with_util, ReleaseQuarantinedPaymentV1,
+    };
+    use crate::execute::risk_bands::{set_risk_bands, SetRiskBandsV1};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        PAYMENT_HOLDBACK_KEY, PAYMENT_QUARANTINED_KEY, RISK_BANDS_SET_KEY, TOTAL_REMAINING_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, BankMsg, Coin, CosmosMsg, Decimal, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_set_risk_bands() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = set_bands(&mut deps, DEFAULT_ORACLE_ADDRESS, vec![]).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to set the risk bands, but got: {:?}",
+            error,
+        );
+        let response = set_bands(
+            &mut deps,
+            DEFAULT_INFO_NAME,
+            vec![threshold_band(500, 100), threshold_band(0, 1000)],
+        )
+        .unwrap();
+        assert_eq!("2", single_attribute_for_key(&response, RISK_BANDS_SET_KEY));
+        assert_eq!(
+            vec![0, 500],
+            query_risk_bands(&deps)
+                .risk_bands
+                .iter()
+                .map(|band| band.min_score)
+                .collect::<Vec<u32>>(),
+            "the risk bands should be stored in order of their minimum score",
+        );
+        let response = set_bands(&mut deps, DEFAULT_INFO_NAME, vec![]).unwrap();
+        assert_eq!("0", single_attribute_for_key(&response, RISK_BANDS_SET_KEY));
+        assert!(
+            query_risk_bands(&deps).risk_bands.is_empty(),
+            "empty risk bands should disable risk-based payment rules",
+        );
+    }
+
+    #[test]
+    fn test_risk_band_holdback_quarantined_until_released() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_scored_payable(
+            &mut deps,
+            600,
+            vec![
+                threshold_band(0, 1000),
+                RiskBandV1 {
+                    min_score: 500,
+                    holdback_percent: Some(Decimal::percent(20)),
+                    large_payment_threshold: None,
+                },
+            ],
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(500),
+        )
+        .expect("a payment on a risky payable should be accepted");
+        assert_eq!(
+            "100",
+            single_attribute_for_key(&response, PAYMENT_HOLDBACK_KEY),
+            "the band's holdback percent of the payment should be held back",
+        );
+        assert_eq!(
+            "0",
+            single_attribute_for_key(&response, PAYMENT_QUARANTINED_KEY),
+            "the holdback should be quarantined under the payment's id",
+        );
+        assert_eq!(
+            "500",
+            single_attribute_for_key(&response, TOTAL_REMAINING_KEY),
+            "the entire payment should be applied to the remaining owed amount",
+        );
+        assert_eq!(
+            vec![coin(400, DEFAULT_PAYABLE_DENOM)],
+            bank_sends(&response),
+            "only the portion of the payment that was not held back should reach the payee",
+        );
+        let response = release_quarantined_payment_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            ReleaseQuarantinedPaymentV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                payment_id: 0,
+            },
+        )
+        .expect("the admin should be able to release the holdback");
+        assert_eq!(
+            vec![coin(100, DEFAULT_PAYABLE_DENOM)],
+            bank_sends(&response),
+            "the released holdback should be sent to the payee",
+        );
+    }
+
+    #[test]
+    fn test_risk_band_large_payment_threshold() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_scored_payable(
+            &mut deps,
+            700,
+            vec![threshold_band(0, 1000), threshold_band(500, 100)],
+        );
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::PaymentRequiresCoSign {
+                large_payment_threshold,
+                ..
+            } => assert_eq!(
+                100, large_payment_threshold,
+                "the threshold of the band with the highest minimum score met should apply",
+            ),
+            error => panic!("unexpected error occurred during execution: {:?}", error),
+        }
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .expect("a payment at the band's threshold should be made directly");
+        assert_eq!(
+            vec![coin(100, DEFAULT_PAYABLE_DENOM)],
+            bank_sends(&response),
+            "a band without a holdback should send the entire payment to the payee",
+        );
+    }
+
+    fn setup_scored_payable(
+        deps: &mut MockOwnedDeps,
+        risk_score: u32,
+        risk_bands: Vec<RiskBandV1>,
+    ) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let mut approval = TestOracleApproval::default();
+        approval.oracle_approval.risk_score = Some(risk_score);
+        test_oracle_approval(deps, &provenance_util, approval).unwrap();
+        set_bands(deps, DEFAULT_INFO_NAME, risk_bands).unwrap();
+        provenance_util
+    }
+
+    fn threshold_band(min_score: u32, large_payment_threshold: u128) -> RiskBandV1 {
+        RiskBandV1 {
+            min_score,
+            holdback_percent: None,
+            large_payment_threshold: Some(Uint128::new(large_payment_threshold)),
+        }
+    }
+
+    fn set_bands(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        risk_bands: Vec<RiskBandV1>,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        set_risk_bands(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            SetRiskBandsV1 { risk_bands },
+        )
+    }
+
+    fn query_risk_bands(deps: &MockOwnedDeps) -> RiskBandsResponse {
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::QueryRiskBands {}).unwrap())
+            .unwrap()
+    }
+
+    fn bank_sends(response: &Response<ProvenanceMsg>) -> Vec<Coin> {
+        response
+            .messages
+            .iter()
+            .filter_map(|msg| match &msg.msg {
+                CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+}
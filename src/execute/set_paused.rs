@@ -0,0 +1,164 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, config_v2};
+use crate::util::constants::CONTRACT_PAUSED_KEY;
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains all relevant fields required in order for the contract admin to pause or resume
+/// registrations, oracle approvals and payments.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SetPausedV1 {
+    pub paused: bool,
+}
+
+/// Pauses or resumes the contract with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the flag in the contract state.  While paused, payable registrations, oracle approvals
+///   and payments are rejected.  Queries and all other executions remain available.
+pub fn set_paused(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    set_paused: SetPausedV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let mut state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    state.is_paused = set_paused.paused;
+    config_v2(deps.storage).save(&state)?;
+    Ok(ResponseBuilder::new()
+        .attribute(CONTRACT_PAUSED_KEY, set_paused.paused.to_string())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::config_read_v2;
+    use crate::execute::set_paused::{set_paused, SetPausedV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::CONTRACT_PAUSED_KEY;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coin, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_set_paused() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = pause(&mut deps, DEFAULT_INFO_NAME, true)
+            .expect("the admin should be able to pause the contract");
+        assert_eq!(
+            "true",
+            single_attribute_for_key(&response, CONTRACT_PAUSED_KEY),
+            "the paused value should be emitted as an attribute",
+        );
+        assert!(
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .is_paused,
+            "the contract should be paused",
+        );
+        pause(&mut deps, DEFAULT_INFO_NAME, false)
+            .expect("the admin should be able to resume the contract");
+        assert!(
+            !config_read_v2(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .is_paused,
+            "the contract should be resumed",
+        );
+    }
+
+    #[test]
+    fn test_set_paused_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = pause(&mut deps, "not-the-admin", true).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to pause the contract, but got: {:?}",
+            error,
+        );
+        let error = set_paused(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            SetPausedV1 { paused: true },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_paused_contract_rejects_payable_activity() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        pause(&mut deps, DEFAULT_INFO_NAME, true).unwrap();
+        let error =
+            test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+                .unwrap_err();
+        assert!(
+            matches!(error, ContractError::ContractPaused),
+            "registration should be rejected while paused, but got: {:?}",
+            error,
+        );
+        pause(&mut deps, DEFAULT_INFO_NAME, false).unwrap();
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        pause(&mut deps, DEFAULT_INFO_NAME, true).unwrap();
+        let error =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap_err();
+        assert!(
+            matches!(error, ContractError::ContractPaused),
+            "oracle approval should be rejected while paused, but got: {:?}",
+            error,
+        );
+        pause(&mut deps, DEFAULT_INFO_NAME, false).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        pause(&mut deps, DEFAULT_INFO_NAME, true).unwrap();
+        let error = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_sender("payer"),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::ContractPaused),
+            "payments should be rejected while paused, but got: {:?}",
+            error,
+        );
+        let attribute = query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+            .expect("queries should remain available while paused");
+        assert!(
+            attribute.oracle_approved,
+            "the approved payable should be queryable while paused",
+        );
+    }
+
+    fn pause(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+        paused: bool,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        set_paused(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            SetPausedV1 { paused },
+        )
+    }
+}
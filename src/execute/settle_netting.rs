@@ -0,0 +1,517 @@
+use crate::core::error::ContractError;
+use crate::core::payee_resolver::resolve_payee;
+use crate::core::state::{
+    config_read_v2, load_escrowed_payments, load_payable_payee_splits, load_pending_cancellation,
+    record_payment_netting, remove_escrowed_payment, EscrowedPaymentV1, PaymentNettingV1,
+};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    NETTED_PAYMENT_EVENT, NETTING_NET_AMOUNT_KEY, NETTING_OFFSET_AMOUNT_KEY,
+    NETTING_TRANSFER_COUNT_KEY, PAYABLE_UUID_KEY, PAYEE_KEY, PAYER_KEY, PAYMENT_AMOUNT_KEY,
+    PAYMENT_ID_KEY, SETTLEMENT_NETTED_KEY,
+};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{
+    coin, Addr, BankMsg, CosmosMsg, DepsMut, Env, Event, MessageInfo, Response, StdResult, Uint128,
+};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cmp::min;
+use std::collections::BTreeMap;
+
+/// Contains the payables whose escrowed payments the admin would like to settle by netting the
+/// obligations between each payer and payee against each other.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SettleNettingV1 {
+    pub payable_uuids: Vec<String>,
+}
+
+/// An escrowed payment included in a settlement, alongside the payable that it was made against.
+struct NettedPayment {
+    payable_type: String,
+    payable_uuid: String,
+    payment_id: u64,
+    payee: Addr,
+    escrowed_payment: EscrowedPaymentV1,
+}
+impl NettedPayment {
+    /// Identifies the obligation that the payment counts towards: its payer, payee and denom.
+    fn obligation(&self) -> (Addr, Addr, String) {
+        (
+            self.escrowed_payment.payer.clone(),
+            self.payee.clone(),
+            self.escrowed_payment.denom.clone(),
+        )
+    }
+
+    /// Identifies the opposing obligation, which the payment's obligation is netted against.
+    fn opposing_obligation(&self) -> (Addr, Addr, String) {
+        (
+            self.payee.clone(),
+            self.escrowed_payment.payer.clone(),
+            self.escrowed_payment.denom.clone(),
+        )
+    }
+}
+
+/// How a settlement's payments are netted: the portion of each payment that is offset against its
+/// opposing obligation, and everything owed to each address once the offset portions are returned
+/// to their payers.
+struct NettingPlan {
+    // In the same order as the payments that the plan was built from
+    offset_amounts: Vec<Uint128>,
+    transfers: BTreeMap<Addr, BTreeMap<String, Uint128>>,
+}
+impl NettingPlan {
+    /// Offsets the total owed by each payer to each payee in each denom against the total owed in
+    /// the opposite direction, up to the smaller of the two.  Each obligation's offset is drawn down
+    /// by its payments in order.
+    fn new(payments: &[NettedPayment]) -> StdResult<NettingPlan> {
+        let mut obligations: BTreeMap<(Addr, Addr, String), Uint128> = BTreeMap::new();
+        for payment in payments {
+            let total = obligations
+                .entry(payment.obligation())
+                .or_insert_with(Uint128::zero);
+            *total = total.checked_add(payment.escrowed_payment.amount)?;
+        }
+        let mut remaining_offsets = payments
+            .iter()
+            .map(|payment| {
+                let opposing_total = obligations
+                    .get(&payment.opposing_obligation())
+                    .copied()
+                    .unwrap_or_else(Uint128::zero);
+                (
+                    payment.obligation(),
+                    min(obligations[&payment.obligation()], opposing_total),
+                )
+            })
+            .collect::<BTreeMap<(Addr, Addr, String), Uint128>>();
+        let mut plan = NettingPlan {
+            offset_amounts: vec![],
+            transfers: BTreeMap::new(),
+        };
+        for payment in payments {
+            let remaining_offset = remaining_offsets
+                .entry(payment.obligation())
+                .or_insert_with(Uint128::zero);
+            let offset_amount = min(*remaining_offset, payment.escrowed_payment.amount);
+            *remaining_offset -= offset_amount;
+            plan.add_transfer(&payment.escrowed_payment.payer, payment, offset_amount)?;
+            plan.add_transfer(
+                &payment.payee,
+                payment,
+                payment.escrowed_payment.amount - offset_amount,
+            )?;
+            plan.offset_amounts.push(offset_amount);
+        }
+        Ok(plan)
+    }
+
+    fn add_transfer(
+        &mut self,
+        address: &Addr,
+        payment: &NettedPayment,
+        amount: Uint128,
+    ) -> StdResult<()> {
+        if !amount.is_zero() {
+            let transfer = self
+                .transfers
+                .entry(address.clone())
+                .or_default()
+                .entry(payment.escrowed_payment.denom.clone())
+                .or_insert_with(Uint128::zero);
+            *transfer = transfer.checked_add(amount)?;
+        }
+        Ok(())
+    }
+
+    /// A single transfer per address, containing everything that it is owed in every denom.
+    fn messages(&self) -> Vec<CosmosMsg<ProvenanceMsg>> {
+        self.transfers
+            .iter()
+            .map(|(address, amounts)| {
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: address.to_string(),
+                    amount: amounts
+                        .iter()
+                        .map(|(denom, amount)| coin(amount.u128(), denom))
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Parent function path for the contract to settle escrowed payments by netting.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn settle_netting(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    settle: SettleNettingV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    settle_netting_with_util(deps, &ProvenanceUtilImpl, env, info, settle)
+}
+
+/// Settles the escrowed payments of multiple payables with as few transfers as possible, with the
+/// following steps:
+/// - Verifies that no funds were sent, that the sender is the contract's admin, and that the
+///   contract is not paused.
+/// - Ensures that each payable has been registered without payee splits.  Payments to syndicated
+///   payables must each be divided among their payees, so they are released individually.
+/// - Ensures that no payable is pending cancellation.  Its payers may be claiming back their escrowed
+///   payments.
+/// - Ensures that the payables hold at least one escrowed payment.
+/// - Totals the escrowed payments owed by each payer to each payee in each denom, and offsets the
+///   totals owed in opposite directions between the same two addresses up to the smaller of them.
+///   The offset portion of each payment is returned to its payer in place of the opposing payments,
+///   and only the net portion is sent to its payee.
+/// - Sends each address everything that it is owed in a single transfer.
+/// - Removes the payments from escrow, and records how each was netted on its payment history
+///   record.  The payments were applied to their payables when they were escrowed, so the scope
+///   attributes are left untouched.
+pub fn settle_netting_with_util<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    settle: SettleNettingV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    state.check_not_paused()?;
+    let mut netted_payments: Vec<NettedPayment> = vec![];
+    for payable_uuid in settle.payable_uuids {
+        let scope_attribute = match query_payable_attribute_by_uuid(&deps.as_ref(), &payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound { payable_uuid }.to_result();
+            }
+        };
+        if load_payable_payee_splits(deps.storage, &payable_uuid)?.is_some() {
+            return ContractError::std_err(format!(
+                "payable [{}] divides its payments among payee splits, so its escrowed payments must be released individually",
+                payable_uuid,
+            ))
+            .to_result();
+        }
+        if load_pending_cancellation(deps.storage, &payable_uuid)?.is_some() {
+            return ContractError::InvalidPayable {
+                payable_uuid,
+                invalid_reason:
+                    "escrowed payments cannot be settled while the payable is pending cancellation"
+                        .into(),
+            }
+            .to_result();
+        }
+        let payee = resolve_payee(&deps.as_ref(), provenance_util, &scope_attribute)?;
+        for (payment_id, escrowed_payment) in load_escrowed_payments(deps.storage, &payable_uuid)? {
+            netted_payments.push(NettedPayment {
+                payable_type: scope_attribute.payable_type.clone(),
+                payable_uuid: payable_uuid.clone(),
+                payment_id,
+                payee: payee.clone(),
+                escrowed_payment,
+            });
+        }
+    }
+    if netted_payments.is_empty() {
+        return ContractError::std_err("the payables do not hold any escrowed payments to net")
+            .to_result();
+    }
+    let plan = NettingPlan::new(&netted_payments)?;
+    let messages = plan.messages();
+    let mut response = ResponseBuilder::new()
+        .attribute(SETTLEMENT_NETTED_KEY, netted_payments.len().to_string())
+        .attribute(NETTING_TRANSFER_COUNT_KEY, messages.len().to_string())
+        .messages(messages);
+    for (payment, offset_amount) in netted_payments.iter().zip(plan.offset_amounts) {
+        let net_amount = payment.escrowed_payment.amount - offset_amount;
+        remove_escrowed_payment(
+            deps.storage,
+            &payment.payable_type,
+            &payment.payable_uuid,
+            payment.payment_id,
+        )?;
+        record_payment_netting(
+            deps.storage,
+            &payment.payable_uuid,
+            payment.payment_id,
+            &PaymentNettingV1 {
+                counterparty: payment.payee.clone(),
+                offset_amount,
+                net_amount,
+                netted_at: env.block.time,
+            },
+        )?;
+        response = response.event(
+            Event::new(NETTED_PAYMENT_EVENT)
+                .add_attribute(PAYABLE_UUID_KEY, &payment.payable_uuid)
+                .add_attribute(PAYMENT_ID_KEY, payment.payment_id.to_string())
+                .add_attribute(PAYER_KEY, payment.escrowed_payment.payer.as_str())
+                .add_attribute(PAYEE_KEY, payment.payee.as_str())
+                .add_attribute(
+                    PAYMENT_AMOUNT_KEY,
+                    payment.escrowed_payment.amount.to_string(),
+                )
+                .add_attribute(NETTING_OFFSET_AMOUNT_KEY, offset_amount.to_string())
+                .add_attribute(NETTING_NET_AMOUNT_KEY, net_amount.to_string()),
+        );
+    }
+    Ok(response.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_escrow_totals, load_escrowed_payments, load_payment_record, save_payable_payee_splits,
+        EscrowedPaymentV1, PayeeSplitV1, PaymentNettingV1,
+    };
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::execute::settle_netting::{
+        settle_netting_with_util, NettedPayment, NettingPlan, SettleNettingV1,
+    };
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME,
+        DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        NETTED_PAYMENT_EVENT, NETTING_TRANSFER_COUNT_KEY, SETTLEMENT_NETTED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, Addr, BankMsg, CosmosMsg, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const PARTNER: &str = "partner";
+    const PARTNER_PAYABLE_UUID: &str = "d6219342-8f82-11ec-a7cf-1fe3b2eb3267";
+
+    #[test]
+    fn test_netting_plan_offsets_opposing_obligations() {
+        // The partner owes the admin 500nhash across two payments and 50usd, while the admin owes
+        // the partner 300nhash
+        let payments = vec![
+            netted_payment(PARTNER, DEFAULT_INFO_NAME, 400, DEFAULT_PAYABLE_DENOM),
+            netted_payment(PARTNER, DEFAULT_INFO_NAME, 100, DEFAULT_PAYABLE_DENOM),
+            netted_payment(DEFAULT_INFO_NAME, PARTNER, 300, DEFAULT_PAYABLE_DENOM),
+            netted_payment(PARTNER, DEFAULT_INFO_NAME, 50, "usd"),
+        ];
+        let plan = NettingPlan::new(&payments).unwrap();
+        assert_eq!(
+            vec![300, 0, 300, 0],
+            plan.offset_amounts
+                .iter()
+                .map(|amount| amount.u128())
+                .collect::<Vec<u128>>(),
+            "the obligations should offset each other up to the smaller total, drawn down in payment order",
+        );
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: DEFAULT_INFO_NAME.to_string(),
+                    amount: vec![coin(500, DEFAULT_PAYABLE_DENOM), coin(50, "usd")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: PARTNER.to_string(),
+                    amount: coins(300, DEFAULT_PAYABLE_DENOM),
+                }),
+            ],
+            plan.messages(),
+            "the admin should receive its 200nhash net obligation, its offset 300nhash and the 50usd in one transfer, and the partner its offset 300nhash",
+        );
+    }
+
+    #[test]
+    fn test_settle_netting_releases_escrowed_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        pay(&mut deps, &provenance_util, PARTNER, 400);
+        pay(&mut deps, &provenance_util, "another-payer", 100);
+        let response = settle(&mut deps, &provenance_util, DEFAULT_INFO_NAME)
+            .expect("the admin should be able to net the escrowed payments");
+        assert_eq!(
+            "2",
+            single_attribute_for_key(&response, SETTLEMENT_NETTED_KEY)
+        );
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, NETTING_TRANSFER_COUNT_KEY),
+            "both payments should reach the payee in a single transfer",
+        );
+        assert_eq!(
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEFAULT_INFO_NAME.to_string(),
+                amount: coins(500, DEFAULT_PAYABLE_DENOM),
+            })],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.clone())
+                .collect::<Vec<CosmosMsg<ProvenanceMsg>>>(),
+        );
+        assert_eq!(
+            2,
+            response
+                .events
+                .iter()
+                .filter(|event| event.ty == NETTED_PAYMENT_EVENT)
+                .count(),
+            "an event should be emitted for each netted payment",
+        );
+        for (payment_id, amount) in [(0, 400), (1, 100)] {
+            assert_eq!(
+                Some(PaymentNettingV1 {
+                    counterparty: Addr::unchecked(DEFAULT_INFO_NAME),
+                    offset_amount: Uint128::zero(),
+                    net_amount: Uint128::new(amount),
+                    netted_at: mock_env().block.time,
+                }),
+                load_payment_record(deps.as_ref().storage, DEFAULT_PAYABLE_UUID, payment_id)
+                    .unwrap()
+                    .unwrap()
+                    .netting,
+                "the netting of payment [{}] should be recorded",
+                payment_id,
+            );
+        }
+        assert!(
+            load_escrowed_payments(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_empty(),
+            "the netted payments should no longer be held in escrow",
+        );
+        assert!(
+            load_escrow_totals(deps.as_ref().storage, None)
+                .unwrap()
+                .is_empty(),
+            "the netted payments should be deducted from the escrow totals",
+        );
+        let error = settle(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "payables without escrowed payments should not be netted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_settle_netting_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_escrowed_payable(&mut deps);
+        pay(&mut deps, &provenance_util, PARTNER, 400);
+        let error = settle(&mut deps, &provenance_util, PARTNER).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to net escrowed payments, but got: {:?}",
+            error,
+        );
+        let error = settle_netting_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            SettleNettingV1 {
+                payable_uuids: vec![PARTNER_PAYABLE_UUID.to_string()],
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "netting an unregistered payable should fail, but got: {:?}",
+            error,
+        );
+        save_payable_payee_splits(
+            deps.as_mut().storage,
+            DEFAULT_PAYABLE_UUID,
+            &[PayeeSplitV1 {
+                address: PARTNER.to_string(),
+                weight: 1,
+            }],
+        )
+        .unwrap();
+        let error = settle(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "payables with payee splits should be released individually, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            1,
+            load_escrowed_payments(deps.as_ref().storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .len(),
+            "a rejected settlement should leave the payment in escrow",
+        );
+    }
+
+    fn netted_payment(payer: &str, payee: &str, amount: u128, denom: &str) -> NettedPayment {
+        NettedPayment {
+            payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            payment_id: 0,
+            payee: Addr::unchecked(payee),
+            escrowed_payment: EscrowedPaymentV1 {
+                payer: Addr::unchecked(payer),
+                amount: Uint128::new(amount),
+                denom: denom.to_string(),
+                reclaimable_at: mock_env().block.time,
+            },
+        }
+    }
+
+    fn setup_escrowed_payable(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(
+            deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    escrow_timeout_seconds: Some(3600),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn pay(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        payer: &str,
+        amount: u128,
+    ) {
+        test_make_payment(
+            deps,
+            provenance_util,
+            TestMakePayment::default_full_sender(payer, amount, DEFAULT_PAYABLE_DENOM),
+        )
+        .expect("the payment should be escrowed");
+    }
+
+    fn settle(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        settle_netting_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            SettleNettingV1 {
+                payable_uuids: vec![DEFAULT_PAYABLE_UUID.to_string()],
+            },
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use crate::core::error::ContractError;
+use crate::core::msg::MigrateMsg;
+use crate::core::state::config_read_v2;
+use crate::migrate::migrate_contract::check_fee_split;
+use crate::migrate::staged_migration::{set_staged_migration, StagedMigrationV1};
+use crate::util::constants::MIGRATION_STAGED_KEY;
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the migration parameters that the admin intends to apply once the contract's
+/// migration timelock has elapsed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StageMigrationParamsV1 {
+    pub params: MigrateMsg,
+}
+
+/// Stages migration params for a future migration with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the contract has a migration timelock enabled and that the params change state.
+/// - Ensures that the resulting fee percent splits the onboarding cost into whole amounts.
+/// - Stores the converted params alongside the time at which the timelock elapses.  Staging new
+///   params replaces any previously-staged params and restarts the timelock.
+pub fn stage_migration_params(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    stage: StageMigrationParamsV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    state.check_admin(&info)?;
+    let timelock_seconds = match state.migration_timelock_seconds {
+        Some(timelock_seconds) => timelock_seconds,
+        None => {
+            return ContractError::InvalidMigration(
+                "migration params can only be staged when the migration timelock is enabled"
+                    .to_string(),
+            )
+            .to_result()
+        }
+    };
+    let params = stage.params.to_migrate_contract_v2(&deps.as_ref())?;
+    if !params.has_state_changes() {
+        return ContractError::InvalidMigration(
+            "staged migration params must include at least one state change".to_string(),
+        )
+        .to_result();
+    }
+    // Surface a lossy fee split now rather than when the timelock elapses
+    check_fee_split(
+        params.onboarding_cost.unwrap_or(state.onboarding_cost),
+        params.fee_percent.unwrap_or(state.fee_percent),
+    )?;
+    let ready_at = env.block.time.plus_seconds(timelock_seconds);
+    set_staged_migration(deps.storage, &StagedMigrationV1 { params, ready_at })?;
+    Ok(ResponseBuilder::new()
+        .attribute(MIGRATION_STAGED_KEY, ready_at.seconds().to_string())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::migrate;
+    use crate::core::error::ContractError;
+    use crate::core::msg::MigrateMsg;
+    use crate::core::state::config_read_v2;
+    use crate::execute::stage_migration_params::{stage_migration_params, StageMigrationParamsV1};
+    use crate::testutil::test_utilities::{
+        single_attribute_for_key, test_instantiate, InstArgs, DEFAULT_INFO_NAME,
+    };
+    use crate::util::constants::MIGRATION_STAGED_KEY;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_stage_and_apply_migration_params() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        let response = stage_migration_params(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            StageMigrationParamsV1 {
+                params: cost_change_params(),
+            },
+        )
+        .expect("the admin should be able to stage migration params");
+        let mut ready_env = mock_env();
+        ready_env.block.time = ready_env.block.time.plus_seconds(3600);
+        assert_eq!(
+            ready_env.block.time.seconds().to_string(),
+            single_attribute_for_key(&response, MIGRATION_STAGED_KEY),
+            "the time at which the staged params can be applied should be emitted",
+        );
+        migrate(deps.as_mut(), mock_env(), cost_change_params())
+            .expect_err("the staged params should not be applied before the timelock elapses");
+        migrate(deps.as_mut(), ready_env, cost_change_params())
+            .expect("the staged params should be applied once the timelock elapses");
+        assert_eq!(
+            Uint128::new(500),
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .onboarding_cost,
+            "the staged onboarding cost should be applied to the state",
+        );
+    }
+
+    #[test]
+    fn test_stage_migration_params_unauthorized() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        let error = stage_migration_params(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not-the-admin", &[]),
+            StageMigrationParamsV1 {
+                params: cost_change_params(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to stage migration params, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_stage_migration_params_without_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = stage_migration_params(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            StageMigrationParamsV1 {
+                params: cost_change_params(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMigration(_)),
+            "staging should be rejected when no timelock is enabled, but got: {:?}",
+            error,
+        );
+    }
+
+    fn timelocked_inst_args() -> InstArgs {
+        InstArgs {
+            migration_timelock_seconds: Some(3600),
+            ..Default::default()
+        }
+    }
+
+    fn cost_change_params() -> MigrateMsg {
+        MigrateMsg {
+            onboarding_cost: Some("500".to_string()),
+            onboarding_denom: None,
+            fee_collection_address: None,
+            fee_percent: None,
+            is_local: None,
+            payment_batch_window: None,
+            disable_payment_batching: None,
+            admin_address: None,
+            migration_timelock_seconds: None,
+            disable_migration_timelock: None,
+            oracle_funds_tolerance: None,
+            disable_oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            disable_minimum_payment: None,
+            settled_retention_seconds: None,
+            disable_settled_pruning: None,
+            approval_valid_duration: None,
+            disable_approval_expiry: None,
+            oracle_approval_sla_seconds: None,
+            disable_oracle_approval_sla: None,
+            large_payment_threshold: None,
+            disable_large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            disable_payment_reversal: None,
+            provenance_msg_version: None,
+            authorized_registrars: None,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    add_payable_subscriber, is_payable_subscriber, load_payable_subscriber_count,
+    load_payable_subscribers, payable_meta_storage_read_v2,
+};
+use crate::util::constants::{
+    PAYABLE_SUBSCRIBED_KEY, PAYABLE_UUID_KEY, SUBSCRIBERS_KEY, SUBSCRIBER_COUNT_KEY, SUBSCRIBER_KEY,
+};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Attribute, DepsMut, MessageInfo, Response, StdResult, Storage};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The largest number of subscribers listed on a single lifecycle event.  The full count is always
+/// emitted, so bots can tell when the list has been truncated.
+pub const MAX_EVENT_SUBSCRIBERS: usize = 10;
+
+/// Contains the payable that the sender would like to be listed on the lifecycle events of.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscribeV1 {
+    pub payable_uuid: String,
+}
+
+/// Registers the sender's interest in a payable with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Ensures that the sender is not already subscribed to the payable.
+/// - Stores the subscription in local storage.  Subsequent lifecycle events for the payable
+///   (approval, payments, total and oracle changes, restructures, write offs, and expiration)
+///   include the subscriber count and the first subscribers.
+pub fn subscribe(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    subscribe: SubscribeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(subscribe.payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound {
+            payable_uuid: subscribe.payable_uuid,
+        }
+        .to_result();
+    }
+    if is_payable_subscriber(deps.storage, &subscribe.payable_uuid, &info.sender) {
+        return ContractError::InvalidPayable {
+            payable_uuid: subscribe.payable_uuid,
+            invalid_reason: "the sender is already subscribed to the payable".into(),
+        }
+        .to_result();
+    }
+    add_payable_subscriber(deps.storage, &subscribe.payable_uuid, &info.sender)?;
+    Ok(ResponseBuilder::new()
+        .attribute(PAYABLE_SUBSCRIBED_KEY, &subscribe.payable_uuid)
+        .attribute(PAYABLE_UUID_KEY, &subscribe.payable_uuid)
+        .attribute(SUBSCRIBER_KEY, info.sender.as_str())
+        .build())
+}
+
+/// Produces the subscriber attributes included on every lifecycle event for a payable.  Payables
+/// without subscribers produce no attributes.
+pub(crate) fn get_subscriber_attributes(
+    storage: &dyn Storage,
+    payable_uuid: &str,
+) -> StdResult<Vec<Attribute>> {
+    let subscriber_count = load_payable_subscriber_count(storage, payable_uuid)?;
+    if subscriber_count == 0 {
+        return Ok(vec![]);
+    }
+    let subscribers = load_payable_subscribers(storage, payable_uuid, MAX_EVENT_SUBSCRIBERS)?
+        .into_iter()
+        .map(|subscriber| subscriber.into_string())
+        .collect::<Vec<String>>();
+    Ok(vec![
+        Attribute::new(SUBSCRIBER_COUNT_KEY, subscriber_count.to_string()),
+        Attribute::new(SUBSCRIBERS_KEY, subscribers.join(",")),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{is_payable_subscriber, load_payable_subscriber_count};
+    use crate::execute::subscribe::{subscribe, SubscribeV1, MAX_EVENT_SUBSCRIBERS};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{
+        PAYABLE_SUBSCRIBED_KEY, SUBSCRIBERS_KEY, SUBSCRIBER_COUNT_KEY, SUBSCRIBER_KEY,
+    };
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coin, Addr, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_subscribe_success() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response = test_subscribe(&mut deps, "bot").unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_SUBSCRIBED_KEY),
+            "the PAYABLE_SUBSCRIBED_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            "bot",
+            single_attribute_for_key(&response, SUBSCRIBER_KEY),
+            "the subscriber should be emitted as an attribute",
+        );
+        assert!(
+            is_payable_subscriber(
+                deps.as_ref().storage,
+                DEFAULT_PAYABLE_UUID,
+                &Addr::unchecked("bot"),
+            ),
+            "the subscription should be stored",
+        );
+        let error = test_subscribe(&mut deps, "bot").unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "subscribing twice should be rejected, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            1,
+            load_payable_subscriber_count(deps.as_ref().storage, DEFAULT_PAYABLE_UUID).unwrap(),
+            "a rejected duplicate should not be counted",
+        );
+    }
+
+    #[test]
+    fn test_subscribe_rejects_unknown_payable_and_funds() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = test_subscribe(&mut deps, "bot").unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "subscribing to an unknown payable should be rejected, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = subscribe(
+            deps.as_mut(),
+            mock_info("bot", &[coin(10, "nhash")]),
+            SubscribeV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_lifecycle_events_include_subscribers() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response =
+            test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default())
+                .unwrap();
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attribute| attribute.key != SUBSCRIBER_COUNT_KEY),
+            "no subscriber attributes should be emitted for a payable without subscribers",
+        );
+        let subscriber_count = MAX_EVENT_SUBSCRIBERS + 2;
+        for index in 0..subscriber_count {
+            test_subscribe(&mut deps, &format!("bot-{:02}", index)).unwrap();
+        }
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        assert_eq!(
+            subscriber_count.to_string(),
+            single_attribute_for_key(&response, SUBSCRIBER_COUNT_KEY),
+            "the full subscriber count should be emitted",
+        );
+        assert_eq!(
+            (0..MAX_EVENT_SUBSCRIBERS)
+                .map(|index| format!("bot-{:02}", index))
+                .collect::<Vec<String>>()
+                .join(","),
+            single_attribute_for_key(&response, SUBSCRIBERS_KEY),
+            "only the first subscribers should be listed",
+        );
+    }
+
+    fn test_subscribe(
+        deps: &mut MockOwnedDeps,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        subscribe(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            SubscribeV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+    }
+}
@@ -0,0 +1,217 @@
+use crate::core::error::ContractError;
+use crate::core::state::{
+    add_supported_payable_type, config_read_v2, is_payable_type_supported,
+    remove_supported_payable_type,
+};
+use crate::util::constants::{PAYABLE_TYPE_SUPPORTED_KEY, PAYABLE_TYPE_UNSUPPORTED_KEY};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the payable type that the contract admin would like to allow registrations of.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddSupportedPayableTypeV1 {
+    pub payable_type: String,
+}
+
+/// Contains the payable type that the contract admin would like to stop allowing registrations of.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoveSupportedPayableTypeV1 {
+    pub payable_type: String,
+}
+
+/// Adds a payable type to the registration allowlist with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Stores the payable type in local storage.  Only payables of a supported type can be
+///   registered.
+pub fn add_payable_type(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    add: AddSupportedPayableTypeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    add_supported_payable_type(deps.storage, &add.payable_type)?;
+    Ok(ResponseBuilder::new()
+        .attribute(PAYABLE_TYPE_SUPPORTED_KEY, &add.payable_type)
+        .build())
+}
+
+/// Removes a payable type from the registration allowlist with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the sender is the contract admin.
+/// - Ensures that the payable type is currently supported.
+/// - Removes the payable type from local storage.  Payables of the type that are already
+///   registered are unaffected, but no new payables of the type can be registered.
+pub fn remove_payable_type(
+    deps: DepsMut<ProvenanceQuery>,
+    info: MessageInfo,
+    remove: RemoveSupportedPayableTypeV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    config_read_v2(deps.storage).load()?.check_admin(&info)?;
+    if !is_payable_type_supported(deps.storage, &remove.payable_type) {
+        return ContractError::UnsupportedPayableType {
+            payable_type: remove.payable_type,
+        }
+        .to_result();
+    }
+    remove_supported_payable_type(deps.storage, &remove.payable_type);
+    Ok(ResponseBuilder::new()
+        .attribute(PAYABLE_TYPE_UNSUPPORTED_KEY, &remove.payable_type)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::is_payable_type_supported;
+    use crate::execute::supported_payable_type::{
+        add_payable_type, remove_payable_type, AddSupportedPayableTypeV1,
+        RemoveSupportedPayableTypeV1,
+    };
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, DEFAULT_INFO_NAME,
+        DEFAULT_PAYABLE_TYPE,
+    };
+    use crate::util::constants::{PAYABLE_TYPE_SUPPORTED_KEY, PAYABLE_TYPE_UNSUPPORTED_KEY};
+    use cosmwasm_std::coin;
+    use cosmwasm_std::testing::mock_info;
+    use provwasm_mocks::mock_dependencies;
+
+    const NEW_PAYABLE_TYPE: &str = "loan";
+
+    #[test]
+    fn test_add_and_remove_payable_type() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = add_payable_type(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            AddSupportedPayableTypeV1 {
+                payable_type: NEW_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .expect("the admin should be able to add a payable type");
+        assert_eq!(
+            NEW_PAYABLE_TYPE,
+            single_attribute_for_key(&response, PAYABLE_TYPE_SUPPORTED_KEY),
+            "the added payable type should be emitted as an attribute",
+        );
+        assert!(
+            is_payable_type_supported(deps.as_ref().storage, NEW_PAYABLE_TYPE),
+            "the payable type should be supported after being added",
+        );
+        let response = remove_payable_type(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            RemoveSupportedPayableTypeV1 {
+                payable_type: NEW_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .expect("the admin should be able to remove a payable type");
+        assert_eq!(
+            NEW_PAYABLE_TYPE,
+            single_attribute_for_key(&response, PAYABLE_TYPE_UNSUPPORTED_KEY),
+            "the removed payable type should be emitted as an attribute",
+        );
+        assert!(
+            !is_payable_type_supported(deps.as_ref().storage, NEW_PAYABLE_TYPE),
+            "the payable type should not be supported after being removed",
+        );
+        let error = remove_payable_type(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            RemoveSupportedPayableTypeV1 {
+                payable_type: NEW_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::UnsupportedPayableType { .. }),
+            "removing an unsupported payable type should fail, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_payable_type_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = add_payable_type(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            AddSupportedPayableTypeV1 {
+                payable_type: NEW_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to add a payable type, but got: {:?}",
+            error,
+        );
+        let error = remove_payable_type(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            RemoveSupportedPayableTypeV1 {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "only the admin should be able to remove a payable type, but got: {:?}",
+            error,
+        );
+        let error = add_payable_type(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            AddSupportedPayableTypeV1 {
+                payable_type: NEW_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_register_unsupported_payable_type() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        remove_payable_type(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            RemoveSupportedPayableTypeV1 {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap();
+        let error =
+            test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+                .unwrap_err();
+        match error {
+            ContractError::UnsupportedPayableType { payable_type } => assert_eq!(
+                DEFAULT_PAYABLE_TYPE, payable_type,
+                "the rejected payable type should be included in the error",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", error),
+        }
+        add_payable_type(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            AddSupportedPayableTypeV1 {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap();
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default())
+            .expect("registration should succeed once the payable type is supported");
+    }
+}
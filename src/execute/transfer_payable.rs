@@ -0,0 +1,329 @@
+use crate::core::error::ContractError;
+use crate::core::state::config_read_v2;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{NEW_PAYEE_KEY, PAYABLE_TRANSFERRED_KEY, PREVIOUS_PAYEE_KEY};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the fields required for a payable's current payee to hand the payable, and all of its
+/// future payments, to a new owner.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TransferPayableV1 {
+    pub payable_uuid: String,
+    pub new_owner: String,
+}
+
+/// Parent function path for the contract to transfer a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn transfer_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    transfer: TransferPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    transfer_payable_with_util(deps, &ProvenanceUtilImpl, env, info, transfer)
+}
+
+/// Transfers a payable to a new owner with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender is the payable's current payee.  A payable that has never been
+///   transferred is paid to its scope's value owner, so an owner of the scope must send the first
+///   transfer.
+/// - Ensures that the new owner is a valid address that is not already the payee.
+/// - Rewrites the scope attribute with the new owner as the payee.  All subsequent payments are
+///   sent to the new owner.  Any deferred payment writes are resolved by this write.
+pub fn transfer_payable_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    transfer: TransferPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &transfer.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: transfer.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    let scope = provenance_util.get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?;
+    let previous_payee = match &scope_attribute.payee {
+        Some(payee) => {
+            if *payee != info.sender {
+                return Err(ContractError::Unauthorized);
+            }
+            payee.clone()
+        }
+        None => {
+            // Skip the ownership check locally, matching the registration process
+            if !state.is_local
+                && !scope
+                    .owners
+                    .iter()
+                    .any(|owner| owner.address == info.sender)
+            {
+                return Err(ContractError::Unauthorized);
+            }
+            scope.value_owner_address
+        }
+    };
+    let new_owner = deps.api.addr_validate(&transfer.new_owner)?;
+    if new_owner == previous_payee {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the new owner is already the payee".into(),
+        }
+        .to_result();
+    }
+    scope_attribute.payee = Some(new_owner.clone());
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYABLE_TRANSFERRED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PREVIOUS_PAYEE_KEY, previous_payee.as_str())
+        .attribute(NEW_PAYEE_KEY, new_owner.as_str())
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{load_payment_batch, PaymentBatchWindowV1};
+    use crate::execute::transfer_payable::{transfer_payable_with_util, TransferPayableV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps, DEFAULT_CONTRACT_NAME,
+        DEFAULT_INFO_NAME, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{NEW_PAYEE_KEY, PAYABLE_TRANSFERRED_KEY, PREVIOUS_PAYEE_KEY};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Decimal, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const FACTOR_ADDRESS: &str = "factor";
+
+    #[test]
+    fn test_transfer_payable_routes_payments_to_new_owner() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response = test_transfer_payable(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_INFO_NAME,
+            FACTOR_ADDRESS,
+        )
+        .unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_TRANSFERRED_KEY),
+            "the PAYABLE_TRANSFERRED_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            DEFAULT_INFO_NAME,
+            single_attribute_for_key(&response, PREVIOUS_PAYEE_KEY),
+            "the scope's value owner should be emitted as the previous payee",
+        );
+        assert_eq!(
+            FACTOR_ADDRESS,
+            single_attribute_for_key(&response, NEW_PAYEE_KEY),
+            "the new owner should be emitted as the new payee",
+        );
+        assert_eq!(
+            Some(Addr::unchecked(FACTOR_ADDRESS)),
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .payee,
+            "the new owner should be recorded on the scope attribute",
+        );
+        let response = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_sender("payer"),
+        )
+        .unwrap();
+        assert!(
+            response.messages.iter().any(|msg| msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: FACTOR_ADDRESS.to_string(),
+                    amount: vec![coin(1000, DEFAULT_PAYABLE_DENOM)],
+                })),
+            "the payment should be sent to the new owner",
+        );
+    }
+
+    #[test]
+    fn test_transfer_payable_writes_through_payment_batch() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(50),
+                    block_interval: 100,
+                }),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        assert!(
+            load_payment_batch(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_some(),
+            "the payment's attribute write should be deferred into a batch",
+        );
+        test_transfer_payable(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_INFO_NAME,
+            FACTOR_ADDRESS,
+        )
+        .unwrap();
+        assert!(
+            load_payment_batch(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_none(),
+            "the transfer's attribute write should clear the payment batch",
+        );
+        let written_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        provenance_util.assert_attribute_matches_latest(&written_attribute);
+        assert_eq!(
+            900,
+            written_attribute.payable_remaining_owed.u128(),
+            "the batched payment should be written along with the new payee",
+        );
+    }
+
+    #[test]
+    fn test_transfer_payable_requires_current_payee() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error =
+            test_transfer_payable(&mut deps, &provenance_util, "not-an-owner", FACTOR_ADDRESS)
+                .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "a non-owner should not be able to transfer the payable, but got: {:?}",
+            error,
+        );
+        test_transfer_payable(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_INFO_NAME,
+            FACTOR_ADDRESS,
+        )
+        .unwrap();
+        // Once transferred, the scope owner no longer controls the payable's payments
+        let error = test_transfer_payable(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_INFO_NAME,
+            "another-guy",
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized),
+            "the previous payee should not be able to transfer the payable, but got: {:?}",
+            error,
+        );
+        let error =
+            test_transfer_payable(&mut deps, &provenance_util, FACTOR_ADDRESS, FACTOR_ADDRESS)
+                .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidPayable { .. }),
+            "a transfer to the current payee should be rejected, but got: {:?}",
+            error,
+        );
+        test_transfer_payable(&mut deps, &provenance_util, FACTOR_ADDRESS, "another-guy")
+            .expect("the new owner should be able to transfer the payable onward");
+    }
+
+    #[test]
+    fn test_transfer_payable_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = test_transfer_payable(
+            &mut deps,
+            &provenance_util,
+            DEFAULT_INFO_NAME,
+            FACTOR_ADDRESS,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should be reported as not found, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let error = transfer_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, DEFAULT_PAYABLE_DENOM)]),
+            transfer_to(FACTOR_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FundsPresent),
+            "funds should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    fn transfer_to(new_owner: &str) -> TransferPayableV1 {
+        TransferPayableV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            new_owner: new_owner.to_string(),
+        }
+    }
+
+    fn test_transfer_payable(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        new_owner: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = transfer_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            transfer_to(new_owner),
+        );
+        provenance_util.bind_captured_attribute_named(deps, DEFAULT_CONTRACT_NAME);
+        response
+    }
+}
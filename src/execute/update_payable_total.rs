@@ -0,0 +1,279 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, is_payable_type_supply_checked};
+use crate::execute::register_payable::validate_payable_total_within_supply;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{PAYABLE_TOTAL_UPDATED_KEY, PREVIOUS_TOTAL_OWED_KEY, TOTAL_OWED_KEY};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the fields required for a scope owner to amend the total owed on a payable that has not
+/// yet been approved by its oracle.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UpdatePayableTotalV1 {
+    pub payable_uuid: String,
+    pub new_total: Uint128,
+}
+
+/// Parent function path for the contract to update a payable's total.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn update_payable_total(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    update: UpdatePayableTotalV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    update_payable_total_with_util(deps, &ProvenanceUtilImpl, env, info, update)
+}
+
+/// Amends the total owed on a registered payable with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender owns the payable's scope.
+/// - Ensures that the oracle has not yet approved the payable and that no payments have been made.
+/// - Verifies that the new total does not exceed the total supply of the payable's marker denom, if
+///   the supply check is enabled for the payable type.
+/// - Rewrites the scope attribute with the new total owed and remaining owed amounts.
+pub fn update_payable_total_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    update: UpdatePayableTotalV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &update.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: update.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    // Only an owner of the payable's scope may amend it.  Skip this step locally, matching the
+    // registration process
+    if !state.is_local
+        && provenance_util
+            .get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?
+            .owners
+            .into_iter()
+            .filter(|owner| owner.address == info.sender)
+            .count()
+            == 0
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    if scope_attribute.oracle_approved {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the total cannot be changed after oracle approval".into(),
+        }
+        .to_result();
+    }
+    // Payments require oracle approval, so this should never happen, but a payable that has taken
+    // payments must never have its total rewritten out from under the payer
+    if scope_attribute.payable_remaining_owed != scope_attribute.payable_total_owed {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: "the total cannot be changed after payments have been made".into(),
+        }
+        .to_result();
+    }
+    if is_payable_type_supply_checked(deps.storage, &scope_attribute.payable_type)? {
+        validate_payable_total_within_supply(
+            &deps,
+            provenance_util,
+            &scope_attribute.payable_denom,
+            update.new_total,
+        )?;
+    }
+    let previous_total = scope_attribute.payable_total_owed;
+    scope_attribute.payable_total_owed = update.new_total;
+    scope_attribute.payable_remaining_owed = update.new_total;
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYABLE_TOTAL_UPDATED_KEY, &scope_attribute.payable_uuid)
+        .attribute(PREVIOUS_TOTAL_OWED_KEY, previous_total.to_string())
+        .attribute(TOTAL_OWED_KEY, update.new_total.to_string())
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::PayableScopeAttribute;
+    use crate::execute::update_payable_total::{
+        update_payable_total_with_util, UpdatePayableTotalV1,
+    };
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_CONTRACT_NAME, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
+        DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        PAYABLE_TOTAL_UPDATED_KEY, PREVIOUS_TOTAL_OWED_KEY, TOTAL_OWED_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    #[test]
+    fn test_update_payable_total_success() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response =
+            test_update_payable_total(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 750).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_TOTAL_UPDATED_KEY),
+            "the PAYABLE_TOTAL_UPDATED_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL.to_string(),
+            single_attribute_for_key(&response, PREVIOUS_TOTAL_OWED_KEY),
+            "the PREVIOUS_TOTAL_OWED_KEY value should equate to the registered total",
+        );
+        assert_eq!(
+            "750",
+            single_attribute_for_key(&response, TOTAL_OWED_KEY),
+            "the TOTAL_OWED_KEY value should equate to the new total",
+        );
+        let attribute: PayableScopeAttribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            750,
+            attribute.payable_total_owed.u128(),
+            "the scope attribute should be rewritten with the new total owed",
+        );
+        assert_eq!(
+            750,
+            attribute.payable_remaining_owed.u128(),
+            "the scope attribute should be rewritten with the new remaining owed",
+        );
+    }
+
+    #[test]
+    fn test_update_payable_total_rejects_funds() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let failure = update_payable_total_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            update_to(750),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::FundsPresent),
+            "expected funds to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_update_payable_total_missing_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let failure =
+            test_update_payable_total(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 750)
+                .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::PayableNotFound { .. }),
+            "expected an unregistered payable to be reported as not found, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_update_payable_total_non_owner_sender() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        // Hand the scope to a different owner after registration
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "another-guy"));
+        let failure =
+            test_update_payable_total(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 750)
+                .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::Unauthorized),
+            "expected a non-owner to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_update_payable_total_after_oracle_approval() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let failure =
+            test_update_payable_total(&mut deps, &provenance_util, DEFAULT_INFO_NAME, 750)
+                .unwrap_err();
+        match failure {
+            ContractError::InvalidPayable { invalid_reason, .. } => {
+                assert!(
+                    invalid_reason.contains("oracle approval"),
+                    "expected the failure to reference the oracle approval, but got: {}",
+                    invalid_reason,
+                );
+            }
+            _ => panic!("unexpected contract error encountered: {:?}", failure),
+        }
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL,
+            attribute.payable_total_owed.u128(),
+            "the total owed should be unchanged after a rejected update",
+        );
+    }
+
+    fn update_to(new_total: u128) -> UpdatePayableTotalV1 {
+        UpdatePayableTotalV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            new_total: Uint128::new(new_total),
+        }
+    }
+
+    fn test_update_payable_total(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+        new_total: u128,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = update_payable_total_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            update_to(new_total),
+        );
+        provenance_util.bind_captured_attribute_named(deps, DEFAULT_CONTRACT_NAME);
+        response
+    }
+}
@@ -0,0 +1,277 @@
+use crate::core::error::ContractError;
+use crate::core::state::{config_read_v2, remove_restructure_proposal};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::util::constants::{
+    PAYABLE_WRITTEN_OFF_KEY, WRITE_OFF_REASON_KEY, WRITTEN_OFF_AMOUNT_KEY,
+};
+use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Contains the fields required for a scope owner to forgive the remaining balance of a payable.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WriteOffPayableV1 {
+    pub payable_uuid: String,
+    pub write_off_reason: String,
+}
+
+/// Parent function path for the contract to write off a payable.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn write_off_payable(
+    deps: DepsMut<ProvenanceQuery>,
+    env: Env,
+    info: MessageInfo,
+    write_off: WriteOffPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    write_off_payable_with_util(deps, &ProvenanceUtilImpl, env, info, write_off)
+}
+
+/// Forgives the remaining balance of an approved payable with the following steps:
+/// - Verifies that no funds were sent.
+/// - Ensures that the payable targeted has been registered.
+/// - Verifies that the sender owns the payable's scope.
+/// - Ensures that the payable has been approved by its oracle, has not already been written off,
+///   and still has a remaining balance to forgive.
+/// - Rewrites the scope attribute with no remaining owed amount, flagged as written off with the
+///   provided reason.  Payments are rejected for written off payables.
+/// - Clears any pending payment batch and restructure proposal, as neither can apply to the
+///   payable afterward.
+pub fn write_off_payable_with_util<T: ProvenanceUtil>(
+    mut deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    info: MessageInfo,
+    write_off: WriteOffPayableV1,
+) -> Result<Response<ProvenanceMsg>, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsPresent);
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let mut scope_attribute =
+        match query_payable_attribute_by_uuid(&deps.as_ref(), &write_off.payable_uuid) {
+            Ok(attr) => attr,
+            Err(_) => {
+                return ContractError::PayableNotFound {
+                    payable_uuid: write_off.payable_uuid,
+                }
+                .to_result();
+            }
+        };
+    // Only an owner of the payable's scope may forgive its balance.  Skip this step locally,
+    // matching the registration process
+    if !state.is_local
+        && provenance_util
+            .get_scope_by_id(&deps.querier, &scope_attribute.scope_id)?
+            .owners
+            .into_iter()
+            .filter(|owner| owner.address == info.sender)
+            .count()
+            == 0
+    {
+        return Err(ContractError::Unauthorized);
+    }
+    let invalid_reason = if !scope_attribute.oracle_approved {
+        Some("only approved payables can be written off")
+    } else if scope_attribute.is_written_off {
+        Some("the payable has already been written off")
+    } else if scope_attribute.payable_remaining_owed.is_zero() {
+        Some("the payable has no remaining balance to write off")
+    } else {
+        None
+    };
+    if let Some(invalid_reason) = invalid_reason {
+        return ContractError::InvalidPayable {
+            payable_uuid: scope_attribute.payable_uuid,
+            invalid_reason: invalid_reason.into(),
+        }
+        .to_result();
+    }
+    let written_off_amount = scope_attribute.payable_remaining_owed;
+    scope_attribute.payable_remaining_owed = Uint128::zero();
+    scope_attribute.is_written_off = true;
+    scope_attribute.write_off_reason = Some(write_off.write_off_reason.clone());
+    remove_restructure_proposal(deps.storage, &scope_attribute.payable_uuid);
+    Ok(ResponseBuilder::for_payable(&scope_attribute)
+        .audit(deps.storage, &scope_attribute, &env.block)?
+        .messages(write_through_payable(
+            deps.branch(),
+            provenance_util,
+            &scope_attribute,
+            &state.contract_name,
+        )?)
+        .attribute(PAYABLE_WRITTEN_OFF_KEY, &scope_attribute.payable_uuid)
+        .attribute(WRITTEN_OFF_AMOUNT_KEY, written_off_amount.to_string())
+        .attribute(WRITE_OFF_REASON_KEY, write_off.write_off_reason)
+        .notify_subscribers(deps.storage, &scope_attribute.payable_uuid)?
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::execute::write_off_payable::{write_off_payable_with_util, WriteOffPayableV1};
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        get_duped_scope, setup_test_suite, single_attribute_for_key, InstArgs, MockOwnedDeps,
+        DEFAULT_CONTRACT_NAME, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use crate::util::constants::{
+        PAYABLE_WRITTEN_OFF_KEY, WRITE_OFF_REASON_KEY, WRITTEN_OFF_AMOUNT_KEY,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Response};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::ProvenanceMsg;
+
+    const WRITE_OFF_REASON: &str = "borrower settlement";
+
+    #[test]
+    fn test_write_off_payable_success() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(300),
+        )
+        .unwrap();
+        let response = test_write_off(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID,
+            single_attribute_for_key(&response, PAYABLE_WRITTEN_OFF_KEY),
+            "the PAYABLE_WRITTEN_OFF_KEY value should equate to the payable uuid",
+        );
+        assert_eq!(
+            "700",
+            single_attribute_for_key(&response, WRITTEN_OFF_AMOUNT_KEY),
+            "the WRITTEN_OFF_AMOUNT_KEY value should equate to the forgiven balance",
+        );
+        assert_eq!(
+            WRITE_OFF_REASON,
+            single_attribute_for_key(&response, WRITE_OFF_REASON_KEY),
+            "the WRITE_OFF_REASON_KEY value should equate to the provided reason",
+        );
+        let attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        assert!(
+            attribute.payable_remaining_owed.is_zero(),
+            "the scope attribute should be rewritten with no remaining owed",
+        );
+        assert!(
+            attribute.is_written_off,
+            "the scope attribute should be flagged as written off",
+        );
+        assert_eq!(
+            Some(WRITE_OFF_REASON.to_string()),
+            attribute.write_off_reason,
+            "the scope attribute should include the write off reason",
+        );
+    }
+
+    #[test]
+    fn test_write_off_payable_blocks_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        test_write_off(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap();
+        let failure = test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::NotReadyForPayment { .. }),
+            "expected a payment to a written off payable to be rejected, but got: {:?}",
+            failure,
+        );
+        let failure = test_write_off(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected a second write off to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_write_off_payable_rejects_funds() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        let failure = write_off_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[coin(10, "nhash")]),
+            write_off_default(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(failure, ContractError::FundsPresent),
+            "expected funds to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_write_off_payable_non_owner_sender() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_approved_suite(&mut deps);
+        deps.querier
+            .with_scope(get_duped_scope(DEFAULT_SCOPE_ID, "another-guy"));
+        let failure = test_write_off(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::Unauthorized),
+            "expected a non-owner to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    #[test]
+    fn test_write_off_payable_requires_oracle_approval() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let failure = test_write_off(&mut deps, &provenance_util, DEFAULT_INFO_NAME).unwrap_err();
+        assert!(
+            matches!(failure, ContractError::InvalidPayable { .. }),
+            "expected an unapproved payable to be rejected, but got: {:?}",
+            failure,
+        );
+    }
+
+    fn setup_approved_suite(deps: &mut MockOwnedDeps) -> MockProvenanceUtil {
+        let provenance_util = setup_test_suite(deps, InstArgs::default());
+        test_register_payable(deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util
+    }
+
+    fn write_off_default() -> WriteOffPayableV1 {
+        WriteOffPayableV1 {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            write_off_reason: WRITE_OFF_REASON.to_string(),
+        }
+    }
+
+    fn test_write_off(
+        deps: &mut MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        sender: &str,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let response = write_off_payable_with_util(
+            deps.as_mut(),
+            provenance_util,
+            mock_env(),
+            mock_info(sender, &[]),
+            write_off_default(),
+        );
+        provenance_util.bind_captured_attribute_named(deps, DEFAULT_CONTRACT_NAME);
+        response
+    }
+}
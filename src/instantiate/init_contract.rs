@@ -1,14 +1,17 @@
 use crate::core::error::ContractError;
 use crate::core::msg::InitMsg;
-use crate::core::state::{config_v2, StateV2};
-use crate::migrate::version_info::migrate_version_info;
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
-use provwasm_std::{bind_name, NameBinding, ProvenanceMsg, ProvenanceQuery};
+use crate::core::state::{config_read_v2, config_v2, StateV2};
+use crate::migrate::version_info::{get_version_info, migrate_version_info};
+use crate::util::provenance_msg_factory::ProvenanceMsgFactory;
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 
 /// Standard entrypoint for contract -> instantiate.  Generates the initial StateV2 value that
 /// drives and controls various configurations, and automatically binds the contract name to its
 /// address, ensuring that it and it alone has access to its spawned attributes on the registered
-/// payables' scopes.  Also establishes the initial version info storage.
+/// payables' scopes.  Also establishes the initial version info storage.  Instantiation is refused
+/// if the contract already has state or version info, preventing an accidental re-init.
 pub fn init_contract(
     deps: DepsMut<ProvenanceQuery>,
     env: Env,
@@ -20,30 +23,59 @@ pub fn init_contract(
         return ContractError::std_err("purchase funds are not allowed to be sent during init")
             .to_result();
     }
+    // Refuse to clobber the state and version info of a contract that has already been initialized
+    if config_read_v2(deps.storage).may_load()?.is_some() || get_version_info(deps.storage).is_ok()
+    {
+        return ContractError::AlreadyInitialized.to_result();
+    }
+    let fee_collection_address = deps
+        .api
+        .addr_validate(msg.fee_collection_address.as_str())?;
+    // Fees sent to the contract itself could never be withdrawn
+    if fee_collection_address == env.contract.address {
+        return ContractError::invalid_fields(vec!["fee_collection_address"]).to_result();
+    }
+    let authorized_registrars = msg
+        .authorized_registrars
+        .unwrap_or_default()
+        .iter()
+        .map(|registrar| deps.api.addr_validate(registrar))
+        .collect::<StdResult<Vec<Addr>>>()?;
     // Create and save contract config state. The name is used for setting attributes on user accounts
     config_v2(deps.storage).save(&StateV2 {
         contract_name: msg.contract_name.clone(),
         onboarding_cost: Uint128::new(msg.onboarding_cost.parse::<u128>().unwrap()),
         onboarding_denom: msg.onboarding_denom.clone(),
-        fee_collection_address: deps
-            .api
-            .addr_validate(msg.fee_collection_address.as_str())?,
+        fee_collection_address,
         fee_percent: msg.fee_percent,
         // Always default to non-local if the value is not provided
         is_local: msg.is_local.unwrap_or(false),
+        payment_batch_window: msg.payment_batch_window,
+        // The instantiating address manages contract-level configuration
+        admin: Some(info.sender.clone()),
+        migration_timelock_seconds: msg.migration_timelock_seconds,
+        oracle_funds_tolerance: msg.oracle_funds_tolerance,
+        minimum_payment_amount: msg.minimum_payment_amount,
+        is_paused: false,
+        settled_retention_seconds: msg.settled_retention_seconds,
+        approval_valid_duration: msg.approval_valid_duration,
+        oracle_approval_sla_seconds: msg.oracle_approval_sla_seconds,
+        large_payment_threshold: msg.large_payment_threshold,
+        payment_reversal_window_seconds: msg.payment_reversal_window_seconds,
+        // Always default to the original message format if the value is not provided
+        provenance_msg_version: msg.provenance_msg_version.unwrap_or_default(),
+        authorized_registrars,
     })?;
     // Create a message that will bind a restricted name to the contract address.
-    let bind_name_msg = bind_name(
-        &msg.contract_name,
-        env.contract.address,
-        NameBinding::Restricted,
-    )?;
+    let bind_name_msg = ProvenanceMsgFactory::new(msg.provenance_msg_version.unwrap_or_default())
+        .bind_contract_name(&msg.contract_name, env.contract.address)?;
     // Set the version info to the default contract values on instantiation
     migrate_version_info(deps.storage)?;
     // Dispatch messages and emit event attributes
-    Ok(Response::new()
-        .add_message(bind_name_msg)
-        .add_attribute("action", "init"))
+    Ok(ResponseBuilder::new()
+        .message(bind_name_msg)
+        .attribute("action", "init")
+        .build())
 }
 
 #[cfg(test)]
@@ -51,9 +83,11 @@ mod tests {
     use super::*;
     use crate::core::state::config_read_v2;
     use crate::migrate::version_info::{get_version_info, CONTRACT_NAME, CONTRACT_VERSION};
-    use crate::testutil::test_utilities::{test_instantiate, InstArgs, DEFAULT_ONBOARDING_DENOM};
-    use cosmwasm_std::testing::mock_info;
-    use cosmwasm_std::{coin, CosmosMsg, Decimal, StdError};
+    use crate::testutil::test_utilities::{
+        test_instantiate, InstArgs, DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM,
+    };
+    use cosmwasm_std::testing::{mock_info, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, Addr, CosmosMsg, Decimal, StdError};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{NameMsgParams, ProvenanceMsgParams};
 
@@ -114,6 +148,11 @@ mod tests {
             generated_state.fee_percent,
             "expected state to include the proper fee percent",
         );
+        assert_eq!(
+            Some(Addr::unchecked(DEFAULT_INFO_NAME)),
+            generated_state.admin,
+            "expected state to include the instantiating address as the admin",
+        );
         let version_info = get_version_info(deps.as_ref().storage).unwrap();
         assert_eq!(
             CONTRACT_NAME, version_info.contract,
@@ -171,4 +210,52 @@ mod tests {
             _ => panic!("unexpected error encountered when too high fee percent provided"),
         };
     }
+
+    #[test]
+    fn test_invalid_init_fee_collection_address_is_contract() {
+        let mut deps = mock_dependencies(&[]);
+        let err = test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                fee_collection_address: MOCK_CONTRACT_ADDR.into(),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::InvalidFields { fields } => assert_eq!(
+                vec!["fee_collection_address".to_string()],
+                fields,
+                "the fee collection address should be rejected when it is the contract's address",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", err),
+        };
+    }
+
+    #[test]
+    fn test_invalid_init_already_initialized() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let err = test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                onboarding_cost: "900".into(),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::AlreadyInitialized),
+            "re-instantiating the contract should be rejected, but got: {:?}",
+            err,
+        );
+        assert_eq!(
+            Uint128::new(100),
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .onboarding_cost,
+            "the original state should not be overwritten by a rejected re-instantiation",
+        );
+    }
 }
@@ -1,12 +1,20 @@
 #![warn(clippy::all)]
 // Public modules
+#[cfg(feature = "contract")]
 pub mod contract;
+#[cfg(feature = "contract")]
 pub mod core;
+#[cfg(feature = "contract")]
 pub mod execute;
+#[cfg(feature = "contract")]
 pub mod instantiate;
+#[cfg(feature = "contract")]
 pub mod migrate;
+#[cfg(feature = "contract")]
 pub mod query;
+#[cfg(feature = "contract")]
 pub mod util;
+pub mod validation;
 
 // Conditional modules
 #[cfg(feature = "enable-test-utils")]
@@ -1,12 +1,17 @@
 use crate::core::error::ContractError;
-use crate::core::state::config_v2;
+use crate::core::money::Money;
+use crate::core::state::{config_read_v2, config_v2, FundsToleranceV1, PaymentBatchWindowV1};
+use crate::migrate::staged_migration::{clear_staged_migration, get_staged_migration};
 use crate::migrate::version_info::{
     get_version_info, migrate_version_info, CONTRACT_NAME, CONTRACT_VERSION,
 };
 use crate::util::constants::{
     MIGRATION_CONTRACT_NAME, MIGRATION_CONTRACT_VERSION, MIGRATION_STATE_CHANGE_PREFIX,
 };
-use cosmwasm_std::{Addr, Attribute, Decimal, DepsMut, Response, Storage, Uint128};
+use crate::util::fees::is_fee_split_exact;
+use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+use crate::util::response_builder::ResponseBuilder;
+use cosmwasm_std::{Addr, Attribute, Decimal, DepsMut, Env, Response, Storage, Uint128};
 use provwasm_std::{ProvenanceMsg, ProvenanceQuery};
 use schemars::JsonSchema;
 use semver::Version;
@@ -21,6 +26,27 @@ pub struct MigrateContractV2 {
     pub fee_collection_address: Option<Addr>,
     pub fee_percent: Option<Decimal>,
     pub is_local: Option<bool>,
+    pub payment_batch_window: Option<PaymentBatchWindowV1>,
+    pub disable_payment_batching: Option<bool>,
+    pub admin_address: Option<Addr>,
+    pub migration_timelock_seconds: Option<u64>,
+    pub disable_migration_timelock: Option<bool>,
+    pub oracle_funds_tolerance: Option<FundsToleranceV1>,
+    pub disable_oracle_funds_tolerance: Option<bool>,
+    pub minimum_payment_amount: Option<Uint128>,
+    pub disable_minimum_payment: Option<bool>,
+    pub settled_retention_seconds: Option<u64>,
+    pub disable_settled_pruning: Option<bool>,
+    pub approval_valid_duration: Option<u64>,
+    pub disable_approval_expiry: Option<bool>,
+    pub oracle_approval_sla_seconds: Option<u64>,
+    pub disable_oracle_approval_sla: Option<bool>,
+    pub large_payment_threshold: Option<Uint128>,
+    pub disable_large_payment_threshold: Option<bool>,
+    pub payment_reversal_window_seconds: Option<u64>,
+    pub disable_payment_reversal: Option<bool>,
+    pub provenance_msg_version: Option<ProvenanceMsgVersion>,
+    pub authorized_registrars: Option<Vec<Addr>>,
 }
 impl MigrateContractV2 {
     /// Helper to derive an empty message for testing purposes.
@@ -31,6 +57,27 @@ impl MigrateContractV2 {
             fee_collection_address: None,
             fee_percent: None,
             is_local: None,
+            payment_batch_window: None,
+            disable_payment_batching: None,
+            admin_address: None,
+            migration_timelock_seconds: None,
+            disable_migration_timelock: None,
+            oracle_funds_tolerance: None,
+            disable_oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            disable_minimum_payment: None,
+            settled_retention_seconds: None,
+            disable_settled_pruning: None,
+            approval_valid_duration: None,
+            disable_approval_expiry: None,
+            oracle_approval_sla_seconds: None,
+            disable_oracle_approval_sla: None,
+            large_payment_threshold: None,
+            disable_large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            disable_payment_reversal: None,
+            provenance_msg_version: None,
+            authorized_registrars: None,
         }
     }
 
@@ -42,13 +89,36 @@ impl MigrateContractV2 {
             || self.fee_collection_address.is_some()
             || self.fee_percent.is_some()
             || self.is_local.is_some()
+            || self.payment_batch_window.is_some()
+            || self.disable_payment_batching == Some(true)
+            || self.admin_address.is_some()
+            || self.migration_timelock_seconds.is_some()
+            || self.disable_migration_timelock == Some(true)
+            || self.oracle_funds_tolerance.is_some()
+            || self.disable_oracle_funds_tolerance == Some(true)
+            || self.minimum_payment_amount.is_some()
+            || self.disable_minimum_payment == Some(true)
+            || self.settled_retention_seconds.is_some()
+            || self.disable_settled_pruning == Some(true)
+            || self.approval_valid_duration.is_some()
+            || self.disable_approval_expiry == Some(true)
+            || self.oracle_approval_sla_seconds.is_some()
+            || self.disable_oracle_approval_sla == Some(true)
+            || self.large_payment_threshold.is_some()
+            || self.disable_large_payment_threshold == Some(true)
+            || self.payment_reversal_window_seconds.is_some()
+            || self.disable_payment_reversal == Some(true)
+            || self.provenance_msg_version.is_some()
+            || self.authorized_registrars.is_some()
     }
 }
 
 /// Migrates the contract to a new version, utilizing the values within the msg param to determine
-/// which fields in the app state to change.
+/// which fields in the app state to change.  When the migration timelock is enabled, state changes
+/// are only accepted if they exactly match params staged by the admin and the timelock has elapsed.
 pub fn migrate_contract(
     deps: DepsMut<ProvenanceQuery>,
+    env: Env,
     migrate: MigrateContractV2,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
     // Ensure the provided version info stored in the contract is valid for the migration before
@@ -57,11 +127,14 @@ pub fn migrate_contract(
     let mut attributes: Vec<Attribute> = vec![];
     // Only load and modify the state if any optional values were provided during the migration
     if migrate.has_state_changes() {
-        let mut contract_config = config_v2(deps.storage);
-        let mut state = contract_config.load()?;
+        let mut state = config_read_v2(deps.storage).load()?;
+        if state.migration_timelock_seconds.is_some() {
+            check_staged_migration(deps.storage, &env, &migrate)?;
+            clear_staged_migration(deps.storage);
+        }
         // Conditionally modify each portion of the state that has a requested change
         if let Some(cost) = migrate.onboarding_cost {
-            attributes.push(state_change_attribute("onboarding_cost", &cost.to_string()));
+            attributes.push(state_change_attribute("onboarding_cost", cost.to_string()));
             state.onboarding_cost = cost;
         }
         if let Some(denom) = migrate.onboarding_denom {
@@ -69,16 +142,20 @@ pub fn migrate_contract(
             state.onboarding_denom = denom;
         }
         if let Some(fee_addr) = migrate.fee_collection_address {
+            // Fees sent to the contract itself could never be withdrawn
+            if fee_addr == env.contract.address {
+                return ContractError::invalid_fields(vec!["fee_collection_address"]).to_result();
+            }
             attributes.push(state_change_attribute(
                 "fee_collection_address",
-                &fee_addr.to_string(),
+                fee_addr.to_string(),
             ));
             state.fee_collection_address = fee_addr;
         }
         if let Some(fee_percent) = migrate.fee_percent {
             attributes.push(state_change_attribute(
                 "fee_percent",
-                &fee_percent.to_string(),
+                fee_percent.to_string(),
             ));
             state.fee_percent = fee_percent;
         }
@@ -86,8 +163,152 @@ pub fn migrate_contract(
             attributes.push(state_change_attribute("is_local", local.to_string()));
             state.is_local = local;
         }
+        if let Some(window) = migrate.payment_batch_window {
+            attributes.push(state_change_attribute(
+                "payment_batch_window",
+                format!("{}/{}", window.change_threshold, window.block_interval),
+            ));
+            state.payment_batch_window = Some(window);
+        }
+        if migrate.disable_payment_batching == Some(true) {
+            attributes.push(state_change_attribute("payment_batch_window", "disabled"));
+            state.payment_batch_window = None;
+        }
+        if let Some(admin) = migrate.admin_address {
+            attributes.push(state_change_attribute("admin", admin.as_str()));
+            state.admin = Some(admin);
+        }
+        if let Some(timelock_seconds) = migrate.migration_timelock_seconds {
+            attributes.push(state_change_attribute(
+                "migration_timelock_seconds",
+                timelock_seconds.to_string(),
+            ));
+            state.migration_timelock_seconds = Some(timelock_seconds);
+        }
+        if migrate.disable_migration_timelock == Some(true) {
+            attributes.push(state_change_attribute(
+                "migration_timelock_seconds",
+                "disabled",
+            ));
+            state.migration_timelock_seconds = None;
+        }
+        if let Some(tolerance) = migrate.oracle_funds_tolerance {
+            attributes.push(state_change_attribute(
+                "oracle_funds_tolerance",
+                Money::new(tolerance.max_amount, &tolerance.denom).to_string(),
+            ));
+            state.oracle_funds_tolerance = Some(tolerance);
+        }
+        if migrate.disable_oracle_funds_tolerance == Some(true) {
+            attributes.push(state_change_attribute("oracle_funds_tolerance", "disabled"));
+            state.oracle_funds_tolerance = None;
+        }
+        if let Some(minimum_payment_amount) = migrate.minimum_payment_amount {
+            attributes.push(state_change_attribute(
+                "minimum_payment_amount",
+                minimum_payment_amount.to_string(),
+            ));
+            state.minimum_payment_amount = Some(minimum_payment_amount);
+        }
+        if migrate.disable_minimum_payment == Some(true) {
+            attributes.push(state_change_attribute("minimum_payment_amount", "disabled"));
+            state.minimum_payment_amount = None;
+        }
+        if let Some(retention_seconds) = migrate.settled_retention_seconds {
+            attributes.push(state_change_attribute(
+                "settled_retention_seconds",
+                retention_seconds.to_string(),
+            ));
+            state.settled_retention_seconds = Some(retention_seconds);
+        }
+        if migrate.disable_settled_pruning == Some(true) {
+            attributes.push(state_change_attribute(
+                "settled_retention_seconds",
+                "disabled",
+            ));
+            state.settled_retention_seconds = None;
+        }
+        if let Some(approval_valid_duration) = migrate.approval_valid_duration {
+            attributes.push(state_change_attribute(
+                "approval_valid_duration",
+                approval_valid_duration.to_string(),
+            ));
+            state.approval_valid_duration = Some(approval_valid_duration);
+        }
+        if migrate.disable_approval_expiry == Some(true) {
+            attributes.push(state_change_attribute(
+                "approval_valid_duration",
+                "disabled",
+            ));
+            state.approval_valid_duration = None;
+        }
+        if let Some(oracle_approval_sla_seconds) = migrate.oracle_approval_sla_seconds {
+            attributes.push(state_change_attribute(
+                "oracle_approval_sla_seconds",
+                oracle_approval_sla_seconds.to_string(),
+            ));
+            state.oracle_approval_sla_seconds = Some(oracle_approval_sla_seconds);
+        }
+        if migrate.disable_oracle_approval_sla == Some(true) {
+            attributes.push(state_change_attribute(
+                "oracle_approval_sla_seconds",
+                "disabled",
+            ));
+            state.oracle_approval_sla_seconds = None;
+        }
+        if let Some(large_payment_threshold) = migrate.large_payment_threshold {
+            attributes.push(state_change_attribute(
+                "large_payment_threshold",
+                large_payment_threshold.to_string(),
+            ));
+            state.large_payment_threshold = Some(large_payment_threshold);
+        }
+        if migrate.disable_large_payment_threshold == Some(true) {
+            attributes.push(state_change_attribute(
+                "large_payment_threshold",
+                "disabled",
+            ));
+            state.large_payment_threshold = None;
+        }
+        if let Some(window_seconds) = migrate.payment_reversal_window_seconds {
+            attributes.push(state_change_attribute(
+                "payment_reversal_window_seconds",
+                window_seconds.to_string(),
+            ));
+            state.payment_reversal_window_seconds = Some(window_seconds);
+        }
+        if migrate.disable_payment_reversal == Some(true) {
+            attributes.push(state_change_attribute(
+                "payment_reversal_window_seconds",
+                "disabled",
+            ));
+            state.payment_reversal_window_seconds = None;
+        }
+        if let Some(provenance_msg_version) = migrate.provenance_msg_version {
+            attributes.push(state_change_attribute(
+                "provenance_msg_version",
+                provenance_msg_version.to_string(),
+            ));
+            state.provenance_msg_version = provenance_msg_version;
+        }
+        if let Some(registrars) = migrate.authorized_registrars {
+            attributes.push(state_change_attribute(
+                "authorized_registrars",
+                if registrars.is_empty() {
+                    "disabled".to_string()
+                } else {
+                    registrars
+                        .iter()
+                        .map(|registrar| registrar.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(",")
+                },
+            ));
+            state.authorized_registrars = registrars;
+        }
+        check_fee_split(state.onboarding_cost, state.fee_percent)?;
         // Persist all changes to the state after modifying them within this block
-        contract_config.save(&state)?;
+        config_v2(deps.storage).save(&state)?;
     }
     // Ensure that the new contract version is stored for future migrations to reference
     let new_version_info = migrate_version_info(deps.storage)?;
@@ -100,7 +321,7 @@ pub fn migrate_contract(
         MIGRATION_CONTRACT_VERSION,
         &new_version_info.version,
     ));
-    Ok(Response::new().add_attributes(attributes))
+    Ok(ResponseBuilder::new().attributes(attributes).build())
 }
 
 fn state_change_attribute(field_name: impl Into<String>, value: impl Into<String>) -> Attribute {
@@ -111,6 +332,54 @@ fn state_change_attr_name(field_name: impl Into<String>) -> String {
     format!("{}{}", MIGRATION_STATE_CHANGE_PREFIX, field_name.into())
 }
 
+/// Ensures that a migration never leaves the contract with an onboarding cost that the fee percent
+/// cannot split into whole base units.
+pub(crate) fn check_fee_split(
+    onboarding_cost: Uint128,
+    fee_percent: Decimal,
+) -> Result<(), ContractError> {
+    if !is_fee_split_exact(onboarding_cost, fee_percent) {
+        return ContractError::InvalidMigration(format!(
+            "fee percent [{}] does not split onboarding cost [{}] into whole amounts",
+            fee_percent, onboarding_cost,
+        ))
+        .to_result();
+    }
+    Ok(())
+}
+
+/// Ensures that the admin staged exactly these migration params and that the timelock they were
+/// staged with has elapsed.
+fn check_staged_migration(
+    storage: &dyn Storage,
+    env: &Env,
+    migrate: &MigrateContractV2,
+) -> Result<(), ContractError> {
+    let staged_migration = match get_staged_migration(storage)? {
+        Some(staged_migration) => staged_migration,
+        None => {
+            return ContractError::InvalidMigration(
+                "state changes must be staged while the migration timelock is enabled".to_string(),
+            )
+            .to_result()
+        }
+    };
+    if &staged_migration.params != migrate {
+        return ContractError::InvalidMigration(
+            "migration params do not match the staged migration params".to_string(),
+        )
+        .to_result();
+    }
+    if env.block.time < staged_migration.ready_at {
+        return ContractError::InvalidMigration(format!(
+            "the migration timelock has not elapsed. staged params can be applied at {}",
+            staged_migration.ready_at,
+        ))
+        .to_result();
+    }
+    Ok(())
+}
+
 fn check_valid_migration_versioning(storage: &mut dyn Storage) -> Result<(), ContractError> {
     let stored_version_info = get_version_info(storage)?;
     // If the contract name has changed or another contract attempts to overwrite this one, this
@@ -138,15 +407,22 @@ fn check_valid_migration_versioning(storage: &mut dyn Storage) -> Result<(), Con
 #[cfg(test)]
 mod tests {
     use crate::core::error::ContractError;
-    use crate::core::state::config_read_v2;
+    use crate::core::state::{config_read_v2, FundsToleranceV1, PaymentBatchWindowV1};
     use crate::migrate::migrate_contract::{
         migrate_contract, state_change_attr_name, state_change_attribute, MigrateContractV2,
     };
+    use crate::migrate::staged_migration::{
+        get_staged_migration, set_staged_migration, StagedMigrationV1,
+    };
     use crate::migrate::version_info::{
         get_version_info, set_version_info, VersionInfoV1, CONTRACT_NAME, CONTRACT_VERSION,
     };
-    use crate::testutil::test_utilities::{single_attribute_for_key, test_instantiate, InstArgs};
+    use crate::testutil::test_utilities::{
+        single_attribute_for_key, test_instantiate, InstArgs, MockOwnedDeps,
+    };
     use crate::util::constants::{MIGRATION_CONTRACT_NAME, MIGRATION_CONTRACT_VERSION};
+    use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+    use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
     use cosmwasm_std::{Addr, Decimal, Uint128};
     use provwasm_mocks::mock_dependencies;
 
@@ -204,6 +480,17 @@ mod tests {
             msg.has_state_changes(),
             "fee percent including a value should cause state changes",
         );
+        msg.fee_percent = None;
+        msg.disable_payment_batching = Some(false);
+        assert!(
+            !msg.has_state_changes(),
+            "declining to disable payment batching should not cause state changes",
+        );
+        msg.disable_payment_batching = Some(true);
+        assert!(
+            msg.has_state_changes(),
+            "disabling payment batching should cause state changes",
+        );
     }
 
     #[test]
@@ -217,7 +504,8 @@ mod tests {
             },
         )
         .unwrap();
-        let response = migrate_contract(deps.as_mut(), MigrateContractV2::empty()).unwrap();
+        let response =
+            migrate_contract(deps.as_mut(), mock_env(), MigrateContractV2::empty()).unwrap();
         assert!(
             response.messages.is_empty(),
             "no messages should be sent on migrate"
@@ -256,7 +544,8 @@ mod tests {
         // Instantiate the contract, automatically setting the version and contract name.
         // This can be seen working correctly in init_contract.rs > test_valid_init test
         test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
-        let response = migrate_contract(deps.as_mut(), MigrateContractV2::empty()).unwrap();
+        let response =
+            migrate_contract(deps.as_mut(), mock_env(), MigrateContractV2::empty()).unwrap();
         assert!(
             response.messages.is_empty(),
             "no messages should be sent on migrate"
@@ -295,12 +584,43 @@ mod tests {
         test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
         let response = migrate_contract(
             deps.as_mut(),
+            mock_env(),
             MigrateContractV2 {
-                onboarding_cost: Some(Uint128::new(134)),
+                onboarding_cost: Some(Uint128::new(150)),
                 onboarding_denom: Some("dogecoin".to_string()),
                 fee_collection_address: Some(Addr::unchecked("new-fee-addr")),
                 fee_percent: Some(Decimal::percent(12)),
                 is_local: Some(true),
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(25),
+                    block_interval: 100,
+                }),
+                disable_payment_batching: None,
+                admin_address: Some(Addr::unchecked("new-admin")),
+                migration_timelock_seconds: Some(3600),
+                disable_migration_timelock: None,
+                oracle_funds_tolerance: Some(FundsToleranceV1 {
+                    denom: "nhash".to_string(),
+                    max_amount: Uint128::new(10),
+                }),
+                disable_oracle_funds_tolerance: None,
+                minimum_payment_amount: Some(Uint128::new(25)),
+                disable_minimum_payment: None,
+                settled_retention_seconds: Some(604_800),
+                disable_settled_pruning: None,
+                approval_valid_duration: Some(2_592_000),
+                disable_approval_expiry: None,
+                oracle_approval_sla_seconds: Some(172_800),
+                disable_oracle_approval_sla: None,
+                large_payment_threshold: Some(Uint128::new(50_000)),
+                disable_large_payment_threshold: None,
+                payment_reversal_window_seconds: Some(3600),
+                disable_payment_reversal: None,
+                provenance_msg_version: Some(ProvenanceMsgVersion::V2),
+                authorized_registrars: Some(vec![
+                    Addr::unchecked("registrar-one"),
+                    Addr::unchecked("registrar-two"),
+                ]),
             },
         )
         .unwrap();
@@ -309,12 +629,12 @@ mod tests {
             "no messages should be sent on migrate"
         );
         assert_eq!(
-            7,
+            19,
             response.attributes.len(),
             "all migration attributes should be added because all fields were changed",
         );
         assert_eq!(
-            "134",
+            "150",
             single_attribute_for_key(
                 &response,
                 state_change_attr_name("onboarding_cost").as_str()
@@ -357,11 +677,104 @@ mod tests {
             single_attribute_for_key(&response, state_change_attr_name("is_local").as_str()),
             "the is local attribute should be added correctly",
         );
+        assert_eq!(
+            "0.25/100",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("payment_batch_window").as_str()
+            ),
+            "the payment batch window attribute should be added correctly",
+        );
+        assert_eq!(
+            "new-admin",
+            single_attribute_for_key(&response, state_change_attr_name("admin").as_str()),
+            "the admin attribute should be added correctly",
+        );
+        assert_eq!(
+            "3600",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("migration_timelock_seconds").as_str()
+            ),
+            "the migration timelock attribute should be added correctly",
+        );
+        assert_eq!(
+            "10/nhash",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("oracle_funds_tolerance").as_str()
+            ),
+            "the oracle funds tolerance attribute should be added correctly",
+        );
+        assert_eq!(
+            "25",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("minimum_payment_amount").as_str()
+            ),
+            "the minimum payment amount attribute should be added correctly",
+        );
+        assert_eq!(
+            "604800",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("settled_retention_seconds").as_str()
+            ),
+            "the settled retention attribute should be added correctly",
+        );
+        assert_eq!(
+            "2592000",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("approval_valid_duration").as_str()
+            ),
+            "the approval valid duration attribute should be added correctly",
+        );
+        assert_eq!(
+            "172800",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("oracle_approval_sla_seconds").as_str()
+            ),
+            "the oracle approval SLA attribute should be added correctly",
+        );
+        assert_eq!(
+            "50000",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("large_payment_threshold").as_str()
+            ),
+            "the large payment threshold attribute should be added correctly",
+        );
+        assert_eq!(
+            "3600",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("payment_reversal_window_seconds").as_str()
+            ),
+            "the payment reversal window attribute should be added correctly",
+        );
+        assert_eq!(
+            "v2",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("provenance_msg_version").as_str()
+            ),
+            "the provenance msg version attribute should be added correctly",
+        );
+        assert_eq!(
+            "registrar-one,registrar-two",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("authorized_registrars").as_str()
+            ),
+            "the authorized registrars attribute should be added correctly",
+        );
         let state = config_read_v2(deps.as_ref().storage)
             .load()
             .expect("state should load properly");
         assert_eq!(
-            Uint128::new(134),
+            Uint128::new(150),
             state.onboarding_cost,
             "onboarding cost should be properly updated in the state",
         );
@@ -384,6 +797,115 @@ mod tests {
             true, state.is_local,
             "is local should be properly updated in the state",
         );
+        assert_eq!(
+            Some(PaymentBatchWindowV1 {
+                change_threshold: Decimal::percent(25),
+                block_interval: 100,
+            }),
+            state.payment_batch_window,
+            "payment batch window should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(Addr::unchecked("new-admin")),
+            state.admin,
+            "admin should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(3600),
+            state.migration_timelock_seconds,
+            "migration timelock should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(FundsToleranceV1 {
+                denom: "nhash".to_string(),
+                max_amount: Uint128::new(10),
+            }),
+            state.oracle_funds_tolerance,
+            "oracle funds tolerance should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(Uint128::new(25)),
+            state.minimum_payment_amount,
+            "minimum payment amount should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(604_800),
+            state.settled_retention_seconds,
+            "settled retention should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(2_592_000),
+            state.approval_valid_duration,
+            "approval valid duration should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(172_800),
+            state.oracle_approval_sla_seconds,
+            "oracle approval SLA should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(Uint128::new(50_000)),
+            state.large_payment_threshold,
+            "large payment threshold should be properly updated in the state",
+        );
+        assert_eq!(
+            Some(3600),
+            state.payment_reversal_window_seconds,
+            "payment reversal window should be properly updated in the state",
+        );
+        assert_eq!(
+            ProvenanceMsgVersion::V2,
+            state.provenance_msg_version,
+            "provenance msg version should be properly updated in the state",
+        );
+        assert_eq!(
+            vec![
+                Addr::unchecked("registrar-one"),
+                Addr::unchecked("registrar-two"),
+            ],
+            state.authorized_registrars,
+            "authorized registrars should be properly updated in the state",
+        );
+    }
+
+    #[test]
+    fn test_successful_disable_payment_batching_migration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                payment_batch_window: Some(PaymentBatchWindowV1 {
+                    change_threshold: Decimal::percent(25),
+                    block_interval: 100,
+                }),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            MigrateContractV2 {
+                disable_payment_batching: Some(true),
+                ..MigrateContractV2::empty()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            "disabled",
+            single_attribute_for_key(
+                &response,
+                state_change_attr_name("payment_batch_window").as_str()
+            ),
+            "the payment batch window attribute should indicate that batching was disabled",
+        );
+        let state = config_read_v2(deps.as_ref().storage)
+            .load()
+            .expect("state should load properly");
+        assert!(
+            state.payment_batch_window.is_none(),
+            "the payment batch window should be removed from the state",
+        );
     }
 
     #[test]
@@ -397,7 +919,7 @@ mod tests {
             },
         )
         .unwrap();
-        match migrate_contract(deps.as_mut(), MigrateContractV2::empty()).unwrap_err() {
+        match migrate_contract(deps.as_mut(), mock_env(), MigrateContractV2::empty()).unwrap_err() {
             ContractError::InvalidContractName {
                 current_contract,
                 migration_contract,
@@ -428,7 +950,7 @@ mod tests {
             },
         )
         .unwrap();
-        match migrate_contract(deps.as_mut(), MigrateContractV2::empty()).unwrap_err() {
+        match migrate_contract(deps.as_mut(), mock_env(), MigrateContractV2::empty()).unwrap_err() {
             ContractError::InvalidContractVersion {
                 current_version,
                 migration_version,
@@ -447,4 +969,156 @@ mod tests {
             _ => panic!("unexpected error encountered"),
         };
     }
+
+    #[test]
+    fn test_failed_migration_for_lossy_fee_split() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        // The default fee percent of 75% cannot split 101nhash into whole amounts
+        let error = migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            MigrateContractV2 {
+                onboarding_cost: Some(Uint128::new(101)),
+                ..MigrateContractV2::empty()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMigration(_)),
+            "a lossy fee split should be rejected, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            Uint128::new(100),
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .unwrap()
+                .onboarding_cost,
+            "the onboarding cost should be unchanged after a rejected migration",
+        );
+    }
+
+    #[test]
+    fn test_failed_migration_for_contract_fee_collection_address() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let error = migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            MigrateContractV2 {
+                fee_collection_address: Some(Addr::unchecked(MOCK_CONTRACT_ADDR)),
+                ..MigrateContractV2::empty()
+            },
+        )
+        .unwrap_err();
+        match error {
+            ContractError::InvalidFields { fields } => assert_eq!(
+                vec!["fee_collection_address".to_string()],
+                fields,
+                "the fee collection address should be rejected when it is the contract's address",
+            ),
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+    }
+
+    #[test]
+    fn test_timelocked_migration_without_state_changes() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        migrate_contract(deps.as_mut(), mock_env(), MigrateContractV2::empty())
+            .expect("a migration without state changes should not require staged params");
+    }
+
+    #[test]
+    fn test_timelocked_migration_requires_staged_params() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        let error = migrate_contract(deps.as_mut(), mock_env(), timelocked_params()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMigration(_)),
+            "state changes without staged params should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_timelocked_migration_rejects_mismatched_params() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        stage_params(&mut deps, 0);
+        let error = migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            MigrateContractV2 {
+                onboarding_cost: Some(Uint128::new(999)),
+                ..MigrateContractV2::empty()
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMigration(_)),
+            "state changes that differ from the staged params should be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_timelocked_migration_before_timelock_elapses() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        stage_params(&mut deps, 1);
+        let error = migrate_contract(deps.as_mut(), mock_env(), timelocked_params()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMigration(_)),
+            "staged params should be rejected before the timelock elapses, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_timelocked_migration_after_timelock_elapses() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), timelocked_inst_args()).unwrap();
+        stage_params(&mut deps, 0);
+        migrate_contract(deps.as_mut(), mock_env(), timelocked_params())
+            .expect("staged params should be accepted once the timelock elapses");
+        let state = config_read_v2(deps.as_ref().storage).load().unwrap();
+        assert_eq!(
+            Uint128::new(500),
+            state.onboarding_cost,
+            "the staged state changes should be applied",
+        );
+        assert!(
+            get_staged_migration(deps.as_ref().storage)
+                .unwrap()
+                .is_none(),
+            "the staged params should be cleared after they are applied",
+        );
+    }
+
+    fn timelocked_inst_args() -> InstArgs {
+        InstArgs {
+            migration_timelock_seconds: Some(3600),
+            ..Default::default()
+        }
+    }
+
+    fn timelocked_params() -> MigrateContractV2 {
+        MigrateContractV2 {
+            onboarding_cost: Some(Uint128::new(500)),
+            ..MigrateContractV2::empty()
+        }
+    }
+
+    fn stage_params(deps: &mut MockOwnedDeps, ready_in_seconds: u64) {
+        set_staged_migration(
+            deps.as_mut().storage,
+            &StagedMigrationV1 {
+                params: timelocked_params(),
+                ready_at: mock_env().block.time.plus_seconds(ready_in_seconds),
+            },
+        )
+        .unwrap();
+    }
 }
@@ -1,2 +1,3 @@
 pub mod migrate_contract;
+pub mod staged_migration;
 pub mod version_info;
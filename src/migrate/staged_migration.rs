@@ -0,0 +1,42 @@
+use crate::core::error::ContractError;
+use crate::core::keys::STAGED_MIGRATION_NAMESPACE;
+use crate::migrate::migrate_contract::MigrateContractV2;
+use cosmwasm_std::{Storage, Timestamp};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const STAGED_MIGRATION: Item<StagedMigrationV1> = Item::new(STAGED_MIGRATION_NAMESPACE);
+
+/// Holds migration parameters that the admin has staged for review while the contract's migration
+/// timelock is enabled.  A migration with state changes is only accepted once its parameters match
+/// these exactly and the ready_at time has been reached.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StagedMigrationV1 {
+    pub params: MigrateContractV2,
+    pub ready_at: Timestamp,
+}
+
+/// Stores the staged migration, replacing (and restarting the timelock of) any previous value.
+pub fn set_staged_migration(
+    storage: &mut dyn Storage,
+    staged_migration: &StagedMigrationV1,
+) -> Result<(), ContractError> {
+    STAGED_MIGRATION
+        .save(storage, staged_migration)
+        .map_err(ContractError::Std)
+}
+
+/// Fetches the currently staged migration, if one exists.
+pub fn get_staged_migration(
+    storage: &dyn Storage,
+) -> Result<Option<StagedMigrationV1>, ContractError> {
+    STAGED_MIGRATION
+        .may_load(storage)
+        .map_err(ContractError::Std)
+}
+
+/// Removes the staged migration after it has been applied.
+pub fn clear_staged_migration(storage: &mut dyn Storage) {
+    STAGED_MIGRATION.remove(storage)
+}
@@ -1,4 +1,5 @@
 use crate::core::error::ContractError;
+use crate::core::keys::VERSION_INFO_NAMESPACE;
 use cosmwasm_std::Storage;
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
@@ -12,7 +13,6 @@ use serde::{Deserialize, Serialize};
 // before migrating, because it's important to be able to differentiate versions as they're applied.
 pub const CONTRACT_NAME: &str = env!("CARGO_CRATE_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
-const VERSION_INFO_NAMESPACE: &str = "version_info";
 const VERSION_INFO: Item<VersionInfoV1> = Item::new(VERSION_INFO_NAMESPACE);
 
 /// Holds both the contract's unique name and version.
@@ -1,3 +1,33 @@
+pub mod query_admin_overview;
+pub mod query_all_payables;
+pub mod query_api_descriptor;
+pub mod query_effective_fees;
+#[cfg(feature = "escrow")]
+pub mod query_escrow_totals;
+pub mod query_export_payments;
+pub mod query_fee_holidays;
+pub mod query_instantiate_template;
+pub mod query_metrics_snapshot;
+pub mod query_overdue_approvals;
+pub mod query_payable;
+pub mod query_payable_authorized_payers;
 pub mod query_payable_by_scope_id;
 pub mod query_payable_by_uuid;
+pub mod query_payable_diff;
+pub mod query_payable_installments;
+pub mod query_payable_interest;
+pub mod query_payable_timeline;
+pub mod query_payable_type_frozen;
+#[cfg(feature = "allowance")]
+pub mod query_payment_allowances;
+pub mod query_payment_history;
+pub mod query_payment_velocity;
+pub mod query_payments;
+pub mod query_quarantine_config;
+pub mod query_quarantined_payments;
+pub mod query_referrer_revenue;
+pub mod query_registration_quote;
+pub mod query_risk_bands;
+pub mod query_scope_attributes;
+pub mod query_simulate_payment;
 pub mod query_state;
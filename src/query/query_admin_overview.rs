@@ -0,0 +1,163 @@
+use crate::core::api::get_api_descriptor;
+use crate::core::error::ContractError;
+use crate::core::msg::{AdminOverviewResponse, TrippedVolumeCapV1};
+use crate::core::state::{
+    config_read_v2, count_proposed_payments, count_quarantined_payments,
+    load_all_payment_volume_windows, load_frozen_payable_types, load_payment_volume_cap,
+    load_quarantine_config, load_referral_config,
+};
+use crate::migrate::staged_migration::get_staged_migration;
+use cosmwasm_std::{to_binary, Binary, Deps, Env, StdResult};
+use provwasm_std::ProvenanceQuery;
+
+/// Aggregates the contract's configuration, roles, enabled features, pending proposals and tripped
+/// breakers into a single response for operational tooling.  The cost grows with the number of
+/// pending proposals and quarantined payments, so this is not intended for use by other contracts.
+pub fn query_admin_overview(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+) -> Result<Binary, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    // Breakers are only reset by the next payment once their window ends, so elapsed windows are
+    // filtered out here
+    let tripped_volume_caps = load_all_payment_volume_windows(deps.storage)?
+        .into_iter()
+        .filter(|(_, window)| window.is_tripped)
+        .map(|(denom, window)| {
+            Ok(load_payment_volume_cap(deps.storage, &denom)?
+                .map(|cap| window.window_end(&cap))
+                .filter(|window_end| env.block.time < *window_end)
+                .map(|window_end| TrippedVolumeCapV1 {
+                    denom,
+                    volume: window.volume,
+                    window_end,
+                }))
+        })
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+    Ok(to_binary(&AdminOverviewResponse {
+        enabled_features: get_api_descriptor(&state).enabled_features,
+        state,
+        referral_config: load_referral_config(deps.storage)?,
+        quarantine_config: load_quarantine_config(deps.storage)?,
+        staged_migration: get_staged_migration(deps.storage)?,
+        proposed_payment_count: count_proposed_payments(deps.storage),
+        quarantined_payment_count: count_quarantined_payments(deps.storage),
+        frozen_payable_types: load_frozen_payable_types(deps.storage)?,
+        tripped_volume_caps,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::{execute, query};
+    use crate::core::api::FEATURE_MIGRATION_TIMELOCK;
+    use crate::core::msg::{AdminOverviewResponse, ExecuteMsg, QueryMsg, TrippedVolumeCapV1};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_DENOM,
+        DEFAULT_PAYABLE_TYPE,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Env, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    const WINDOW_SECONDS: u64 = 86400;
+
+    #[test]
+    fn test_query_admin_overview() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                migration_timelock_seconds: Some(3600),
+                ..Default::default()
+            },
+        );
+        let overview = query_overview(&deps, mock_env());
+        assert_eq!(
+            vec![FEATURE_MIGRATION_TIMELOCK.to_string()],
+            overview.enabled_features,
+            "the features enabled by the contract's configuration should be reported",
+        );
+        assert!(
+            overview.referral_config.is_none()
+                && overview.quarantine_config.is_none()
+                && overview.staged_migration.is_none()
+                && overview.frozen_payable_types.is_empty()
+                && overview.tripped_volume_caps.is_empty(),
+            "a freshly-instantiated contract should have no pending proposals or tripped breakers",
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        admin_execute(
+            &mut deps,
+            ExecuteMsg::SetPaymentVolumeCap {
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                max_volume: Uint128::new(100),
+                window_seconds: WINDOW_SECONDS,
+            },
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .expect("the payment exceeding the cap should be accepted");
+        admin_execute(
+            &mut deps,
+            ExecuteMsg::SetPayableTypeFrozen {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+                frozen: true,
+            },
+        );
+        let overview = query_overview(&deps, mock_env());
+        assert_eq!(
+            Some(DEFAULT_INFO_NAME),
+            overview.state.admin.as_ref().map(|admin| admin.as_str()),
+            "the contract admin should be reported",
+        );
+        assert_eq!(
+            vec![DEFAULT_PAYABLE_TYPE.to_string()],
+            overview.frozen_payable_types,
+            "the frozen payable type should be reported",
+        );
+        assert_eq!(
+            vec![TrippedVolumeCapV1 {
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                volume: Uint128::new(200),
+                window_end: mock_env().block.time.plus_seconds(WINDOW_SECONDS),
+            }],
+            overview.tripped_volume_caps,
+            "the tripped payment volume cap should be reported",
+        );
+        assert_eq!(0, overview.proposed_payment_count);
+        assert_eq!(0, overview.quarantined_payment_count);
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(WINDOW_SECONDS);
+        assert!(
+            query_overview(&deps, later_env)
+                .tripped_volume_caps
+                .is_empty(),
+            "a breaker should no longer be reported once its window has ended",
+        );
+    }
+
+    fn admin_execute(deps: &mut MockOwnedDeps, msg: ExecuteMsg) {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            msg,
+        )
+        .expect("the admin execute should succeed");
+    }
+
+    fn query_overview(deps: &MockOwnedDeps, env: Env) -> AdminOverviewResponse {
+        from_binary(&query(deps.as_ref(), env, QueryMsg::QueryAdminOverview {}).unwrap()).unwrap()
+    }
+}
@@ -0,0 +1,126 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{AllPayablesResponse, RegisteredPayableV1};
+use crate::core::state::load_payable_meta_page;
+use crate::query::query_payable_by_scope_id::query_payable_attribute_by_scope_id;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// The number of payables returned by a page when the caller does not provide a limit.
+const DEFAULT_ALL_PAYABLES_LIMIT: u32 = 30;
+
+/// Lists every payable registered with the contract in payable uuid order, beginning after the
+/// provided uuid, so that indexers can enumerate payables directly from contract state.  Each
+/// payable's scope attribute is resolved alongside its uuid and scope id, and is omitted if it can
+/// no longer be found on the scope.  Whenever the page is full, the uuid of its last payable is
+/// returned as the cursor for the next page.
+pub fn query_all_payables(
+    deps: &Deps<ProvenanceQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_ALL_PAYABLES_LIMIT) as usize;
+    let payables = load_payable_meta_page(deps.storage, start_after.as_deref(), limit)?
+        .into_iter()
+        .map(|meta| RegisteredPayableV1 {
+            scope_attribute: query_payable_attribute_by_scope_id(deps, &meta.scope_id).ok(),
+            payable_uuid: meta.payable_uuid,
+            scope_id: meta.scope_id,
+        })
+        .collect::<Vec<_>>();
+    let next_start_after = if payables.len() == limit {
+        payables.last().map(|last| last.payable_uuid.clone())
+    } else {
+        None
+    };
+    Ok(to_binary(&AllPayablesResponse {
+        payables,
+        next_start_after,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{AllPayablesResponse, QueryMsg};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    const LATER_PAYABLE_UUID: &str = "4cb54a6e-83ab-11ec-a486-eb4f069082c5";
+    const LATER_SCOPE_ID: &str = "scope1qpxt2jnwsw43rm9ysm457p5sstzs6pcylq";
+
+    #[test]
+    fn test_query_all_payables_pages_in_uuid_order() {
+        let mut deps = mock_dependencies(&[]);
+        // Local mode skips the scope ownership check, as only the default scope is mocked.  The
+        // default payable is registered last so that its attribute is the one bound to the scope
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                is_local: true,
+                ..Default::default()
+            },
+        );
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    payable_uuid: LATER_PAYABLE_UUID.to_string(),
+                    scope_id: LATER_SCOPE_ID.to_string(),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let page = query_all(&deps, None, Some(1));
+        assert_eq!(1, page.payables.len());
+        assert_eq!(DEFAULT_PAYABLE_UUID, page.payables[0].payable_uuid);
+        assert_eq!(DEFAULT_SCOPE_ID, page.payables[0].scope_id);
+        assert_eq!(
+            Some(DEFAULT_PAYABLE_UUID.to_string()),
+            page.payables[0]
+                .scope_attribute
+                .as_ref()
+                .map(|attribute| attribute.payable_uuid.clone()),
+            "the payable's scope attribute should be resolved",
+        );
+        assert_eq!(
+            Some(DEFAULT_PAYABLE_UUID.to_string()),
+            page.next_start_after,
+            "a full page should produce a cursor for the next page",
+        );
+        let page = query_all(&deps, page.next_start_after, Some(1));
+        assert_eq!(1, page.payables.len());
+        assert_eq!(LATER_PAYABLE_UUID, page.payables[0].payable_uuid);
+        assert_eq!(LATER_SCOPE_ID, page.payables[0].scope_id);
+        let page = query_all(&deps, page.next_start_after, Some(1));
+        assert!(
+            page.payables.is_empty() && page.next_start_after.is_none(),
+            "no payables should remain after the last page",
+        );
+    }
+
+    fn query_all(
+        deps: &MockOwnedDeps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> AllPayablesResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryAllPayables { start_after, limit },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::core::api::get_api_descriptor;
+use crate::core::error::ContractError;
+use crate::core::state::config_read_v2;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Describes the messages supported by this contract version, alongside the optional features
+/// enabled by its current configuration.
+pub fn query_api_descriptor(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    Ok(to_binary(&get_api_descriptor(&state))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::api::{
+        is_execute_msg_compiled, is_query_msg_compiled, ApiDescriptorV1, API_DESCRIPTOR_VERSION,
+        FEATURE_MIGRATION_TIMELOCK, SUPPORTED_EXECUTE_MSGS, SUPPORTED_QUERY_MSGS,
+    };
+    #[cfg(not(feature = "escrow"))]
+    use crate::core::error::ContractError;
+    use crate::core::msg::QueryMsg;
+    use crate::migrate::version_info::{CONTRACT_NAME, CONTRACT_VERSION};
+    use crate::testutil::test_utilities::{test_instantiate, InstArgs};
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_api_descriptor() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                migration_timelock_seconds: Some(3600),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let descriptor = from_binary::<ApiDescriptorV1>(
+            &query(deps.as_ref(), mock_env(), QueryMsg::QueryApiDescriptor {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            API_DESCRIPTOR_VERSION, descriptor.descriptor_version,
+            "the descriptor format version should be reported",
+        );
+        assert_eq!(
+            CONTRACT_NAME, descriptor.contract_name,
+            "the contract name should be reported",
+        );
+        assert_eq!(
+            CONTRACT_VERSION, descriptor.contract_version,
+            "the contract version should be reported",
+        );
+        assert_eq!(
+            SUPPORTED_EXECUTE_MSGS
+                .iter()
+                .filter(|msg| is_execute_msg_compiled(msg))
+                .count(),
+            descriptor.execute_msgs.len(),
+            "every execute msg compiled into the contract should be reported",
+        );
+        assert_eq!(
+            SUPPORTED_QUERY_MSGS
+                .iter()
+                .filter(|msg| is_query_msg_compiled(msg))
+                .count(),
+            descriptor.query_msgs.len(),
+            "every query msg compiled into the contract should be reported",
+        );
+        assert_eq!(
+            vec![FEATURE_MIGRATION_TIMELOCK.to_string()],
+            descriptor.enabled_features,
+            "only the configured migration timelock should be reported as an enabled feature",
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "escrow"))]
+    fn test_escrow_queries_rejected_without_escrow() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        let descriptor = from_binary::<ApiDescriptorV1>(
+            &query(deps.as_ref(), mock_env(), QueryMsg::QueryApiDescriptor {}).unwrap(),
+        )
+        .unwrap();
+        assert!(
+            !descriptor
+                .query_msgs
+                .contains(&"query_escrow_totals".to_string()),
+            "escrow queries should not be reported when the escrow feature is not compiled in",
+        );
+        let error = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryEscrowTotals { payable_type: None },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::FeatureNotCompiled { .. }),
+            "expected escrow queries to be rejected, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::core::error::ContractError;
+use crate::core::msg::EffectiveFeesResponse;
+use crate::core::state::is_payable_type_supply_checked;
+use crate::query::query_registration_quote::quote_registration;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Derives the onboarding cost and its split between the fee collector and the oracle that any
+/// registrant would be charged to register a payable of the given type right now, including any fee
+/// holiday in effect.  The values are taken from the registration quote, so both queries always
+/// agree.  Referral shares are carved out of the fee amount and never change the charge, while
+/// priority fees and oracle gas rebates are chosen by the registrant and sent on top of it, so none
+/// of them are included.  The response also reports whether registered totals will be checked
+/// against the total supply of their marker denom.
+pub fn query_effective_fees(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    payable_type: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let quote = quote_registration(&deps, &env, payable_type.into())?;
+    Ok(to_binary(&EffectiveFeesResponse {
+        is_supply_checked: is_payable_type_supply_checked(deps.storage, &quote.payable_type)?,
+        onboarding_cost: quote.onboarding_cost,
+        onboarding_denom: quote.onboarding_denom,
+        fee_amount: quote.fee_amount,
+        oracle_amount: quote.oracle_amount,
+        fee_holiday_id: quote.fee_holiday_id,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{EffectiveFeesResponse, QueryMsg};
+    use crate::core::state::set_payable_type_supply_checked;
+    use crate::testutil::test_utilities::{
+        test_instantiate, InstArgs, DEFAULT_ONBOARDING_DENOM, DEFAULT_PAYABLE_TYPE,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Decimal};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_effective_fees() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                onboarding_cost: "200".to_string(),
+                fee_percent: Decimal::percent(40),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let fees_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryEffectiveFees {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+            },
+        )
+        .unwrap();
+        let fees = from_binary::<EffectiveFeesResponse>(&fees_binary).unwrap();
+        assert_eq!(
+            200,
+            fees.onboarding_cost.u128(),
+            "the onboarding cost should reflect the contract configuration",
+        );
+        assert_eq!(
+            DEFAULT_ONBOARDING_DENOM, fees.onboarding_denom,
+            "the onboarding denom should reflect the contract configuration",
+        );
+        assert_eq!(
+            80,
+            fees.fee_amount.u128(),
+            "the fee amount should be 40% of the onboarding cost",
+        );
+        assert_eq!(
+            120,
+            fees.oracle_amount.u128(),
+            "the oracle amount should be the remainder of the onboarding cost",
+        );
+        assert_eq!(
+            None, fees.fee_holiday_id,
+            "no fee holiday should be reported when none is scheduled",
+        );
+        assert!(
+            !fees.is_supply_checked,
+            "payable types are not supply checked by default",
+        );
+    }
+
+    #[test]
+    fn test_query_effective_fees_reports_supply_check() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(deps.as_mut(), InstArgs::default()).unwrap();
+        set_payable_type_supply_checked(&mut deps.storage, DEFAULT_PAYABLE_TYPE, true).unwrap();
+        let fees = from_binary::<EffectiveFeesResponse>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryEffectiveFees {
+                    payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(
+            fees.is_supply_checked,
+            "the payable total of a supply checked type should be reported as checked at registration",
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::core::error::ContractError;
+use crate::core::msg::EscrowTotalsResponse;
+use crate::core::state::load_escrow_totals;
+use cosmwasm_std::{to_binary, Binary, Coin, Deps, StdError};
+use provwasm_std::ProvenanceQuery;
+
+/// Produces the payments that the contract holds in escrow, totalled per payable type and denom, so
+/// that treasury monitoring can reconcile the contract's balances without scanning every escrowed
+/// payment.  The totals are maintained as payments enter and leave escrow.  Only compiled into
+/// builds with the escrow feature.
+pub fn query_escrow_totals(
+    deps: Deps<ProvenanceQuery>,
+    payable_type: Option<String>,
+) -> Result<Binary, ContractError> {
+    let escrow_totals = load_escrow_totals(deps.storage, payable_type.as_deref())?;
+    let mut denom_totals: Vec<Coin> = vec![];
+    for total in &escrow_totals {
+        match denom_totals
+            .iter_mut()
+            .find(|coin| coin.denom == total.denom)
+        {
+            Some(coin) => {
+                coin.amount = coin
+                    .amount
+                    .checked_add(total.amount)
+                    .map_err(StdError::from)?
+            }
+            None => denom_totals.push(Coin {
+                denom: total.denom.clone(),
+                amount: total.amount,
+            }),
+        }
+    }
+    denom_totals.sort_by(|first, second| first.denom.cmp(&second.denom));
+    Ok(to_binary(&EscrowTotalsResponse {
+        escrow_totals,
+        denom_totals,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{EscrowTotalsResponse, QueryMsg};
+    use crate::core::state::{remove_escrowed_payment, EscrowTotalV1};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_TYPE,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_escrow_totals() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        assert_eq!(
+            EscrowTotalsResponse {
+                escrow_totals: vec![],
+                denom_totals: vec![],
+            },
+            query_escrow_totals(&deps, None),
+            "no totals should be reported before any payment is escrowed",
+        );
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    escrow_timeout_seconds: Some(3600),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        for amount in [400, 100] {
+            test_make_payment(
+                &mut deps,
+                &provenance_util,
+                TestMakePayment::default_with_amount(amount),
+            )
+            .expect("the payment should be escrowed");
+        }
+        let response = query_escrow_totals(&deps, Some(DEFAULT_PAYABLE_TYPE));
+        assert_eq!(
+            vec![EscrowTotalV1 {
+                payable_type: DEFAULT_PAYABLE_TYPE.to_string(),
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                payment_count: 2,
+                amount: Uint128::new(500),
+            }],
+            response.escrow_totals,
+            "both escrowed payments should be counted towards the payable type's total",
+        );
+        assert_eq!(
+            vec![coin(500, DEFAULT_PAYABLE_DENOM)],
+            response.denom_totals
+        );
+        assert_eq!(
+            response,
+            query_escrow_totals(&deps, None),
+            "the payable type's total should be included in the totals of every payable type",
+        );
+        assert!(
+            query_escrow_totals(&deps, Some("other-type"))
+                .escrow_totals
+                .is_empty(),
+            "another payable type should not hold any escrowed payments",
+        );
+        remove_escrowed_payment(
+            deps.as_mut().storage,
+            DEFAULT_PAYABLE_TYPE,
+            DEFAULT_PAYABLE_UUID,
+            0,
+        )
+        .unwrap();
+        let response = query_escrow_totals(&deps, None);
+        assert_eq!(1, response.escrow_totals[0].payment_count);
+        assert_eq!(
+            vec![coin(100, DEFAULT_PAYABLE_DENOM)],
+            response.denom_totals,
+            "a payment leaving escrow should be deducted from the total",
+        );
+        remove_escrowed_payment(
+            deps.as_mut().storage,
+            DEFAULT_PAYABLE_TYPE,
+            DEFAULT_PAYABLE_UUID,
+            1,
+        )
+        .unwrap();
+        assert!(
+            query_escrow_totals(&deps, None).escrow_totals.is_empty(),
+            "a total should be removed once it no longer holds any payments",
+        );
+    }
+
+    fn query_escrow_totals(
+        deps: &MockOwnedDeps,
+        payable_type: Option<&str>,
+    ) -> EscrowTotalsResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryEscrowTotals {
+                    payable_type: payable_type.map(|payable_type| payable_type.to_string()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
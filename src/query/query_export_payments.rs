@@ -0,0 +1,154 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{ExportPaymentsResponse, ExportedPaymentV1};
+use crate::core::state::load_payment_history_page;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// The number of payments returned by an export page when the caller does not provide a limit.
+const DEFAULT_EXPORT_PAYMENTS_LIMIT: u32 = 100;
+
+/// Lists payments recorded against every payable in a stable key order (payable uuid, then payment
+/// sequence), beginning after the provided cursor.  Data warehouses can perform incremental
+/// extracts by passing the cursor of the last payment received, which is also returned as the next
+/// cursor whenever the page is full.  Payment history is never removed, so the payments of expired
+/// and cancelled payables are included.
+pub fn query_export_payments(
+    deps: &Deps<ProvenanceQuery>,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_EXPORT_PAYMENTS_LIMIT) as usize;
+    let payments = load_payment_history_page(
+        deps.storage,
+        start_after
+            .as_ref()
+            .map(|(payable_uuid, sequence)| (payable_uuid.as_str(), *sequence)),
+        limit,
+    )?
+    .into_iter()
+    .map(|((payable_uuid, sequence), payment)| ExportedPaymentV1 {
+        payable_uuid,
+        sequence,
+        payment,
+    })
+    .collect::<Vec<_>>();
+    let next_start_after = if payments.len() == limit {
+        payments
+            .last()
+            .map(|last| (last.payable_uuid.clone(), last.sequence))
+    } else {
+        None
+    };
+    Ok(to_binary(&ExportPaymentsResponse {
+        payments,
+        next_start_after,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{ExportPaymentsResponse, QueryMsg};
+    use crate::core::state::{append_payment_record, PaymentRecordV1};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    const LATER_PAYABLE_UUID: &str = "4cb54a6e-83ab-11ec-a486-eb4f069082c5";
+
+    #[test]
+    fn test_query_export_payments_pages_in_key_order() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        record_payment(&mut deps, LATER_PAYABLE_UUID, 100);
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, 10);
+        record_payment(&mut deps, LATER_PAYABLE_UUID, 200);
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, 20);
+        let page = export(&deps, None, Some(3));
+        assert_eq!(
+            vec![
+                (DEFAULT_PAYABLE_UUID.to_string(), 0, 10),
+                (DEFAULT_PAYABLE_UUID.to_string(), 1, 20),
+                (LATER_PAYABLE_UUID.to_string(), 0, 100),
+            ],
+            page.payments
+                .iter()
+                .map(|exported| (
+                    exported.payable_uuid.clone(),
+                    exported.sequence,
+                    exported.payment.amount.u128()
+                ))
+                .collect::<Vec<_>>(),
+            "payments should be ordered by payable uuid and then by sequence",
+        );
+        assert_eq!(
+            Some((LATER_PAYABLE_UUID.to_string(), 0)),
+            page.next_start_after,
+            "a full page should return the cursor of its last payment",
+        );
+        let page = export(&deps, page.next_start_after, Some(3));
+        assert_eq!(
+            1,
+            page.payments.len(),
+            "the final page should hold the remaining payment"
+        );
+        assert_eq!(200, page.payments[0].payment.amount.u128());
+        assert_eq!(
+            None, page.next_start_after,
+            "a partial page should not return a cursor",
+        );
+        assert_eq!(
+            4,
+            export(&deps, None, None).payments.len(),
+            "the default limit should cover every payment",
+        );
+    }
+
+    #[test]
+    fn test_query_export_payments_without_payments() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let page = export(&deps, None, None);
+        assert!(page.payments.is_empty(), "no payments should be exported");
+        assert_eq!(None, page.next_start_after);
+    }
+
+    fn record_payment(deps: &mut MockOwnedDeps, payable_uuid: &str, amount: u128) {
+        append_payment_record(
+            deps.as_mut().storage,
+            payable_uuid,
+            &PaymentRecordV1 {
+                payer: Addr::unchecked("payer"),
+                amount: Uint128::new(amount),
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                paid_at: mock_env().block.time,
+                on_behalf_of: None,
+                external_reference: None,
+                payee_splits: None,
+                late_fees_paid: Uint128::zero(),
+                interest_paid: Uint128::zero(),
+                netting: None,
+            },
+        )
+        .unwrap();
+    }
+
+    fn export(
+        deps: &MockOwnedDeps,
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    ) -> ExportPaymentsResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ExportPayments { start_after, limit },
+            )
+            .expect("the export should succeed"),
+        )
+        .expect("the export response should deserialize")
+    }
+}
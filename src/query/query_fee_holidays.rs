@@ -0,0 +1,77 @@
+use crate::core::error::ContractError;
+use crate::core::msg::FeeHolidaysResponse;
+use crate::core::state::load_all_fee_holidays;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Lists every fee holiday that is in effect or scheduled to begin, ordered by start time, so that
+/// pricing interfaces can show registrants when reduced onboarding costs will apply.  Holidays that
+/// have already ended are omitted.
+pub fn query_fee_holidays(deps: Deps<ProvenanceQuery>, env: Env) -> Result<Binary, ContractError> {
+    let mut fee_holidays = load_all_fee_holidays(deps.storage)?
+        .into_iter()
+        .filter(|holiday| holiday.end_time > env.block.time)
+        .collect::<Vec<_>>();
+    fee_holidays.sort_by_key(|holiday| holiday.start_time);
+    Ok(to_binary(&FeeHolidaysResponse { fee_holidays })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{FeeHolidaysResponse, QueryMsg};
+    use crate::execute::fee_holiday::{schedule_fee_holiday, ScheduleFeeHolidayV1};
+    use crate::testutil::test_utilities::{setup_test_suite, InstArgs, DEFAULT_INFO_NAME};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Env, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_fee_holidays() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        for (holiday_id, start_seconds, end_seconds) in [
+            ("later", 500, 600),
+            ("sooner", 200, 300),
+            ("ending", 50, 150),
+        ] {
+            schedule_fee_holiday(
+                deps.as_mut(),
+                env_at_seconds(0),
+                mock_info(DEFAULT_INFO_NAME, &[]),
+                ScheduleFeeHolidayV1 {
+                    holiday_id: holiday_id.to_string(),
+                    start_time: env_at_seconds(start_seconds).block.time,
+                    end_time: env_at_seconds(end_seconds).block.time,
+                    payable_types: vec![],
+                    onboarding_cost: Uint128::zero(),
+                },
+            )
+            .unwrap();
+        }
+        let response = from_binary::<FeeHolidaysResponse>(
+            &query(
+                deps.as_ref(),
+                env_at_seconds(150),
+                QueryMsg::QueryFeeHolidays {},
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            vec!["sooner", "later"],
+            response
+                .fee_holidays
+                .iter()
+                .map(|holiday| holiday.holiday_id.as_str())
+                .collect::<Vec<&str>>(),
+            "ended holidays should be omitted and the rest ordered by start time",
+        );
+    }
+
+    fn env_at_seconds(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+}
@@ -0,0 +1,83 @@
+use crate::core::error::ContractError;
+use crate::core::msg::InitMsg;
+use crate::core::state::config_read_v2;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Produces an InitMsg that instantiates a new contract with the same configuration as this one,
+/// so that staging clones of an instance can be created without reverse-engineering its state.
+/// Environment-specific addresses are left empty, and the fee collection address must be filled in
+/// before the template will pass validation.  The admin is always the instantiating sender, so it
+/// is not carried over.
+pub fn query_instantiate_template(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    Ok(to_binary(&InitMsg {
+        contract_name: state.contract_name,
+        onboarding_cost: state.onboarding_cost.to_string(),
+        onboarding_denom: state.onboarding_denom,
+        fee_collection_address: String::new(),
+        fee_percent: state.fee_percent,
+        is_local: Some(state.is_local),
+        payment_batch_window: state.payment_batch_window,
+        migration_timelock_seconds: state.migration_timelock_seconds,
+        oracle_funds_tolerance: state.oracle_funds_tolerance,
+        minimum_payment_amount: state.minimum_payment_amount,
+        settled_retention_seconds: state.settled_retention_seconds,
+        approval_valid_duration: state.approval_valid_duration,
+        oracle_approval_sla_seconds: state.oracle_approval_sla_seconds,
+        large_payment_threshold: state.large_payment_threshold,
+        payment_reversal_window_seconds: state.payment_reversal_window_seconds,
+        provenance_msg_version: Some(state.provenance_msg_version),
+        authorized_registrars: None,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{InitMsg, QueryMsg};
+    use crate::testutil::test_utilities::{
+        test_instantiate, InstArgs, DEFAULT_CONTRACT_NAME, DEFAULT_FEE_COLLECTION_ADDRESS,
+    };
+    use crate::util::traits::ValidatedMsg;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_instantiate_template_mirrors_configuration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate(
+            deps.as_mut(),
+            InstArgs {
+                minimum_payment_amount: Some(Uint128::new(10)),
+                approval_valid_duration: Some(86_400),
+                large_payment_threshold: Some(Uint128::new(5_000)),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryInstantiateTemplate {},
+        )
+        .unwrap();
+        let template: InitMsg = from_binary(&bin).unwrap();
+        assert_eq!(DEFAULT_CONTRACT_NAME, template.contract_name);
+        assert_eq!("100", template.onboarding_cost);
+        assert_eq!(Some(Uint128::new(10)), template.minimum_payment_amount);
+        assert_eq!(Some(86_400), template.approval_valid_duration);
+        assert_eq!(Some(Uint128::new(5_000)), template.large_payment_threshold);
+        assert!(
+            template.fee_collection_address.is_empty(),
+            "environment-specific addresses should not be carried over to the template",
+        );
+        InitMsg {
+            fee_collection_address: DEFAULT_FEE_COLLECTION_ADDRESS.to_string(),
+            ..template
+        }
+        .validate()
+        .expect("the template should be valid once its addresses are filled in");
+    }
+}
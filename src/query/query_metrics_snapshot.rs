@@ -0,0 +1,177 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{MetricValue, MetricsSnapshotResponse};
+use crate::core::state::{
+    load_all_oracle_approval_contexts, load_all_payable_timelines, payable_meta_storage_read_v2,
+};
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Order, Uint128};
+use provwasm_std::ProvenanceQuery;
+
+/// The number of trailing blocks within which oracle approvals are counted as recent.
+pub const RECENT_APPROVAL_BLOCK_WINDOW: u64 = 1000;
+
+/// Produces a flat snapshot of the contract's current counters and gauges for off-chain exporters:
+/// - The number of registered payables, and the number in each stage of their timeline.
+/// - The number of oracle approvals within the trailing RECENT_APPROVAL_BLOCK_WINDOW blocks.
+/// - The contract's balance in each denom, which represents all funds held in escrow.
+///
+/// Payables registered before timelines were tracked are reported as payables_untracked, so the
+/// status counts always sum to the registered count.
+pub fn query_metrics_snapshot(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+) -> Result<Binary, ContractError> {
+    let registered = payable_meta_storage_read_v2(deps.storage)
+        .range(None, None, Order::Ascending)
+        .count() as u128;
+    let timelines = load_all_payable_timelines(deps.storage)?;
+    let awaiting_approval = timelines
+        .iter()
+        .filter(|timeline| timeline.approved_at.is_none() && timeline.completed_at.is_none())
+        .count() as u128;
+    let awaiting_payment = timelines
+        .iter()
+        .filter(|timeline| timeline.approved_at.is_some() && timeline.completed_at.is_none())
+        .count() as u128;
+    let paid_off = timelines
+        .iter()
+        .filter(|timeline| timeline.completed_at.is_some())
+        .count() as u128;
+    let window_start = env
+        .block
+        .height
+        .saturating_sub(RECENT_APPROVAL_BLOCK_WINDOW);
+    let recent_approvals = load_all_oracle_approval_contexts(deps.storage)?
+        .into_iter()
+        .filter(|context| context.block_height > window_start)
+        .count() as u128;
+    let mut metrics = vec![
+        metric("payables_registered", registered),
+        metric("payables_awaiting_oracle_approval", awaiting_approval),
+        metric("payables_awaiting_payment", awaiting_payment),
+        metric("payables_paid_off", paid_off),
+        metric(
+            "payables_untracked",
+            registered.saturating_sub(timelines.len() as u128),
+        ),
+        metric(
+            format!(
+                "oracle_approvals_recent{{blocks=\"{}\"}}",
+                RECENT_APPROVAL_BLOCK_WINDOW
+            ),
+            recent_approvals,
+        ),
+    ];
+    let mut balances = deps.querier.query_all_balances(&env.contract.address)?;
+    balances.sort_by(|a, b| a.denom.cmp(&b.denom));
+    metrics.extend(balances.into_iter().map(|balance| MetricValue {
+        name: format!("contract_balance{{denom=\"{}\"}}", balance.denom),
+        value: balance.amount,
+    }));
+    Ok(to_binary(&MetricsSnapshotResponse {
+        block_height: env.block.height,
+        metrics,
+    })?)
+}
+
+fn metric(name: impl Into<String>, value: u128) -> MetricValue {
+    MetricValue {
+        name: name.into(),
+        value: Uint128::new(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{MetricsSnapshotResponse, QueryMsg};
+    use crate::query::query_metrics_snapshot::RECENT_APPROVAL_BLOCK_WINDOW;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{setup_test_suite, InstArgs, MockOwnedDeps};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_binary, Env};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_metrics_snapshot_empty() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let snapshot = query_snapshot(&deps, mock_env());
+        assert_eq!(
+            mock_env().block.height,
+            snapshot.block_height,
+            "the snapshot should report the block height at which it was taken",
+        );
+        assert_eq!(0, metric_value(&snapshot, "payables_registered"));
+        assert!(
+            snapshot
+                .metrics
+                .iter()
+                .all(|metric| !metric.name.starts_with("contract_balance")),
+            "no balance metrics should be emitted when the contract holds no funds",
+        );
+    }
+
+    #[test]
+    fn test_query_metrics_snapshot_counts() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let snapshot = query_snapshot(&deps, mock_env());
+        assert_eq!(1, metric_value(&snapshot, "payables_registered"));
+        assert_eq!(
+            1,
+            metric_value(&snapshot, "payables_awaiting_oracle_approval")
+        );
+        assert_eq!(0, metric_value(&snapshot, "payables_awaiting_payment"));
+        assert_eq!(0, metric_value(&snapshot, "payables_untracked"));
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let recent_approvals_name = format!(
+            "oracle_approvals_recent{{blocks=\"{}\"}}",
+            RECENT_APPROVAL_BLOCK_WINDOW
+        );
+        let snapshot = query_snapshot(&deps, mock_env());
+        assert_eq!(
+            0,
+            metric_value(&snapshot, "payables_awaiting_oracle_approval")
+        );
+        assert_eq!(1, metric_value(&snapshot, "payables_awaiting_payment"));
+        assert_eq!(1, metric_value(&snapshot, &recent_approvals_name));
+        let mut later_env = mock_env();
+        later_env.block.height += RECENT_APPROVAL_BLOCK_WINDOW;
+        assert_eq!(
+            0,
+            metric_value(&query_snapshot(&deps, later_env), &recent_approvals_name),
+            "approvals outside of the block window should no longer be counted as recent",
+        );
+    }
+
+    #[test]
+    fn test_query_metrics_snapshot_balances() {
+        let mut deps = mock_dependencies(&[coin(150, "nhash"), coin(20, "apple")]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let snapshot = query_snapshot(&deps, mock_env());
+        assert_eq!(
+            150,
+            metric_value(&snapshot, "contract_balance{denom=\"nhash\"}")
+        );
+        assert_eq!(
+            20,
+            metric_value(&snapshot, "contract_balance{denom=\"apple\"}")
+        );
+    }
+
+    fn query_snapshot(deps: &MockOwnedDeps, env: Env) -> MetricsSnapshotResponse {
+        from_binary(&query(deps.as_ref(), env, QueryMsg::QueryMetricsSnapshot {}).unwrap()).unwrap()
+    }
+
+    fn metric_value(snapshot: &MetricsSnapshotResponse, name: &str) -> u128 {
+        snapshot
+            .metrics
+            .iter()
+            .find(|metric| metric.name == name)
+            .unwrap_or_else(|| panic!("expected metric {} to be present", name))
+            .value
+            .u128()
+    }
+}
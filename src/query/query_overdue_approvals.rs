@@ -0,0 +1,195 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{OverdueApprovalV1, OverdueApprovalsResponse};
+use crate::core::state::{
+    config_read_v2, load_all_payable_timelines, load_latest_payable_snapshot,
+};
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// The number of overdue approvals returned when no limit is provided.
+const DEFAULT_OVERDUE_APPROVALS_LIMIT: u32 = 25;
+
+/// Lists the payables that have been awaiting oracle approval for longer than the contract's oracle
+/// approval SLA, longest waiting first, so that operations dashboards can page the responsible
+/// oracle directly from contract state.  Payables that have been declined, written off or have
+/// expired are no longer awaiting approval, and payables registered before timelines were tracked
+/// are omitted.  The total number of overdue payables is reported alongside the limited list.
+pub fn query_overdue_approvals(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let oracle_approval_sla_seconds = match config_read_v2(deps.storage)
+        .load()?
+        .oracle_approval_sla_seconds
+    {
+        Some(oracle_approval_sla_seconds) => oracle_approval_sla_seconds,
+        None => return ContractError::std_err("no oracle approval SLA is configured").to_result(),
+    };
+    let mut overdue_approvals = vec![];
+    for timeline in load_all_payable_timelines(deps.storage)? {
+        let registered_at = match timeline.registered_at {
+            Some(registered_at) if timeline.approved_at.is_none() => registered_at,
+            _ => continue,
+        };
+        let breached_at = registered_at.plus_seconds(oracle_approval_sla_seconds);
+        if env.block.time < breached_at {
+            continue;
+        }
+        let snapshot = match load_latest_payable_snapshot(deps.storage, &timeline.payable_uuid)? {
+            Some(snapshot) => snapshot,
+            None => continue,
+        };
+        if snapshot.oracle_approved
+            || snapshot.is_declined
+            || snapshot.is_written_off
+            || snapshot
+                .expiration_time
+                .is_some_and(|expiration_time| env.block.time >= expiration_time)
+        {
+            continue;
+        }
+        overdue_approvals.push(OverdueApprovalV1 {
+            payable_uuid: timeline.payable_uuid,
+            oracle_address: snapshot.oracle_address,
+            registered_at,
+            overdue_seconds: env.block.time.seconds() - breached_at.seconds(),
+        });
+    }
+    // Ties are broken by uuid, in which order the timelines were loaded
+    overdue_approvals.sort_by_key(|overdue| overdue.registered_at);
+    let total_overdue = overdue_approvals.len() as u64;
+    overdue_approvals.truncate(limit.unwrap_or(DEFAULT_OVERDUE_APPROVALS_LIMIT) as usize);
+    Ok(to_binary(&OverdueApprovalsResponse {
+        oracle_approval_sla_seconds,
+        total_overdue,
+        overdue_approvals,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{OverdueApprovalsResponse, QueryMsg};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        mock_default_scope, mock_scope, setup_test_suite, InstArgs, MockOwnedDeps,
+        DEFAULT_INFO_NAME, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Env};
+    use provwasm_mocks::mock_dependencies;
+
+    const SLA_SECONDS: u64 = 3600;
+    const LATER_PAYABLE_UUID: &str = "200425a4-8f85-11ec-9d1c-1b2bd4da3b7d";
+    const LATER_SCOPE_ID: &str = "scope1qzge0zaztu65tx5x5llv5xc9ztsqxlkwel";
+
+    #[test]
+    fn test_query_overdue_approvals_sorted_by_age() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                oracle_approval_sla_seconds: Some(SLA_SECONDS),
+                ..Default::default()
+            },
+        );
+        // The later payable sorts first by uuid, so the response must be ordered by age instead
+        mock_scope(&mut deps, LATER_SCOPE_ID, DEFAULT_INFO_NAME);
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                env: env_after_seconds(600),
+                register_payable: RegisterPayableV2 {
+                    payable_uuid: LATER_PAYABLE_UUID.to_string(),
+                    scope_id: LATER_SCOPE_ID.to_string(),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        mock_default_scope(&mut deps);
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response = query_overdue_approvals(&deps, env_after_seconds(SLA_SECONDS - 1), None)
+            .expect("the query should succeed while no approval is overdue");
+        assert_eq!(0, response.total_overdue);
+        let response =
+            query_overdue_approvals(&deps, env_after_seconds(SLA_SECONDS + 600), None).unwrap();
+        assert_eq!(SLA_SECONDS, response.oracle_approval_sla_seconds);
+        assert_eq!(2, response.total_overdue);
+        assert_eq!(
+            vec![DEFAULT_PAYABLE_UUID, LATER_PAYABLE_UUID],
+            response
+                .overdue_approvals
+                .iter()
+                .map(|overdue| overdue.payable_uuid.as_str())
+                .collect::<Vec<&str>>(),
+            "the longest waiting payable should be listed first",
+        );
+        let oldest = response.overdue_approvals.first().unwrap();
+        assert_eq!(600, oldest.overdue_seconds);
+        assert_eq!(
+            Addr::unchecked(DEFAULT_ORACLE_ADDRESS),
+            oldest.oracle_address
+        );
+        let response =
+            query_overdue_approvals(&deps, env_after_seconds(SLA_SECONDS + 600), Some(1)).unwrap();
+        assert_eq!(
+            2, response.total_overdue,
+            "the total should not be truncated by the limit",
+        );
+        assert_eq!(1, response.overdue_approvals.len());
+    }
+
+    #[test]
+    fn test_query_overdue_approvals_omits_approved_payables() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(
+            &mut deps,
+            InstArgs {
+                oracle_approval_sla_seconds: Some(SLA_SECONDS),
+                ..Default::default()
+            },
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response =
+            query_overdue_approvals(&deps, env_after_seconds(SLA_SECONDS * 2), None).unwrap();
+        assert_eq!(
+            0, response.total_overdue,
+            "an approved payable should not be overdue",
+        );
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = query_overdue_approvals(&deps, mock_env(), None).unwrap_err();
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "the query should be rejected without an approval SLA, but got: {:?}",
+            error,
+        );
+    }
+
+    fn query_overdue_approvals(
+        deps: &MockOwnedDeps,
+        env: Env,
+        limit: Option<u32>,
+    ) -> Result<OverdueApprovalsResponse, ContractError> {
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::QueryOverdueApprovals { limit },
+        )
+        .map(|binary| from_binary(&binary).unwrap())
+    }
+
+    fn env_after_seconds(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+}
@@ -0,0 +1,57 @@
+use crate::core::error::ContractError;
+use crate::query::query_payable_by_scope_id::query_payable_binary_by_scope_id;
+use crate::query::query_payable_by_uuid::query_payable_binary_by_uuid;
+use crate::validation::is_valid_uuid;
+use cosmwasm_std::{Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Finds a payable by either its uuid or its scope's bech32 address, so that clients holding only
+/// one of the two do not need to know which query to send.  The identifier is treated as a uuid if
+/// it is formatted as one, and as a scope address otherwise, which message validation guarantees.
+pub fn query_payable_binary(
+    deps: &Deps<ProvenanceQuery>,
+    identifier: String,
+) -> Result<Binary, ContractError> {
+    if is_valid_uuid(&identifier) {
+        query_payable_binary_by_uuid(deps, identifier)
+    } else {
+        query_payable_binary_by_scope_id(deps, identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::QueryMsg;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payable_by_either_identifier() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let query_binary = |msg: QueryMsg| query(deps.as_ref(), mock_env(), msg).unwrap();
+        let by_uuid = query_binary(QueryMsg::QueryPayableByUuid {
+            payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+        });
+        assert_eq!(
+            by_uuid,
+            query_binary(QueryMsg::QueryPayable {
+                identifier: DEFAULT_PAYABLE_UUID.to_string(),
+            }),
+            "a uuid identifier should produce the same response as the query by uuid",
+        );
+        assert_eq!(
+            by_uuid,
+            query_binary(QueryMsg::QueryPayable {
+                identifier: DEFAULT_SCOPE_ID.to_string(),
+            }),
+            "a scope address identifier should produce the same response as the query by uuid",
+        );
+    }
+}
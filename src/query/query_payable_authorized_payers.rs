@@ -0,0 +1,93 @@
+use crate::core::error::ContractError;
+use crate::core::msg::PayableAuthorizedPayersResponse;
+use crate::core::state::{load_payable_authorized_payers, payable_meta_storage_read_v2};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Lists the addresses that may make payments against a payable.  Payables registered without an
+/// allowlist are unrestricted, and report no authorized payers.
+pub fn query_payable_authorized_payers(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let authorized_payers = load_payable_authorized_payers(deps.storage, &payable_uuid)?;
+    Ok(to_binary(&PayableAuthorizedPayersResponse {
+        payable_uuid,
+        is_restricted: authorized_payers.is_some(),
+        authorized_payers: authorized_payers.unwrap_or_default(),
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PayableAuthorizedPayersResponse, QueryMsg};
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payable_authorized_payers() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = query_authorized_payers(&deps).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should not be found, but got: {:?}",
+            error,
+        );
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    authorized_payers: Some(vec!["counterparty".to_string()]),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let response = query_authorized_payers(&deps).unwrap();
+        assert!(response.is_restricted);
+        assert_eq!(
+            vec![Addr::unchecked("counterparty")],
+            response.authorized_payers,
+        );
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response = query_authorized_payers(&deps).unwrap();
+        assert!(
+            !response.is_restricted,
+            "a payable registered without an allowlist should be unrestricted",
+        );
+        assert!(response.authorized_payers.is_empty());
+    }
+
+    fn query_authorized_payers(
+        deps: &MockOwnedDeps,
+    ) -> Result<PayableAuthorizedPayersResponse, ContractError> {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryPayableAuthorizedPayers {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .map(|binary| from_binary(&binary).unwrap())
+    }
+}
@@ -1,24 +1,42 @@
 use crate::core::error::ContractError;
-use crate::core::state::{config_read_v2, PayableScopeAttribute};
+use crate::core::msg::PayableQueryResponse;
+use crate::core::state::{
+    config_read_v2, load_denom_display_metadata, load_payable_custom_fields,
+    load_payable_priority_fee, load_payment_batch, PayableScopeAttribute,
+};
 use cosmwasm_std::{to_binary, Addr, Binary, Deps};
 use provwasm_std::{ProvenanceQuerier, ProvenanceQuery};
 
 /// Finds the PayableScopeAttribute tagged to a scope's address (scope_id - as it's referred to in
-/// many places in the documentation, which is a bech32 address prefixed with "scope").
+/// many places in the documentation, which is a bech32 address prefixed with "scope").  Any custom
+/// fields and priority fee stored for the payable at registration and any display metadata for its
+/// denom are included in the resulting PayableQueryResponse.
 pub fn query_payable_binary_by_scope_id(
     deps: &Deps<ProvenanceQuery>,
     scope_id: impl Into<String>,
 ) -> Result<Binary, ContractError> {
     let attribute_result = query_payable_attribute_by_scope_id(deps, scope_id);
     if let Ok(attr) = attribute_result {
-        Ok(to_binary(&attr)?)
+        let custom_fields = load_payable_custom_fields(deps.storage, &attr.payable_uuid)?;
+        let payable_denom_metadata =
+            load_denom_display_metadata(deps.storage, &attr.payable_denom)?.map(Into::into);
+        let priority_fee = load_payable_priority_fee(deps.storage, &attr.payable_uuid)?
+            .map(|priority_fee| priority_fee.amount);
+        Ok(to_binary(&PayableQueryResponse {
+            custom_fields,
+            payable_denom_metadata,
+            priority_fee,
+            ..PayableQueryResponse::from(attr)
+        })?)
     } else {
         Err(attribute_result.expect_err("result should be error"))
     }
 }
 
 /// Finds the PayableScopeAttribute tagged to a scope's address (scope_id) and deserializes the
-/// resulting value from the resulting Binary from the cosmwasm resources.
+/// resulting value from the resulting Binary from the cosmwasm resources.  Any payments that are
+/// being held in a payment batch are applied to the result, so callers always see the true
+/// remaining owed amount.
 pub fn query_payable_attribute_by_scope_id(
     deps: &Deps<ProvenanceQuery>,
     scope_id: impl Into<String>,
@@ -40,22 +58,29 @@ pub fn query_payable_attribute_by_scope_id(
         }
         .to_result();
     }
-    Ok(attributes.first().unwrap().to_owned())
+    let mut attribute = attributes.first().unwrap().to_owned();
+    if let Some(batch) = load_payment_batch(deps.storage, &attribute.payable_uuid)? {
+        attribute.payable_remaining_owed = batch.remaining_owed;
+    }
+    Ok(attribute)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::contract::query;
-    use crate::core::msg::QueryMsg;
-    use crate::core::state::PayableScopeAttribute;
+    use crate::core::msg::{PayableQueryResponse, QueryMsg, ScopeAttributesResponse};
+    use crate::execute::denom_display_metadata::{
+        set_denom_display_metadata, SetDenomDisplayMetadataV1,
+    };
     use crate::query::query_payable_by_scope_id::query_payable_attribute_by_scope_id;
     use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
     use crate::testutil::test_utilities::{
-        setup_test_suite, InstArgs, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM,
-        DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+        setup_test_suite, InstArgs, DEFAULT_INFO_NAME, DEFAULT_ORACLE_ADDRESS,
+        DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID,
+        DEFAULT_SCOPE_ID,
     };
-    use cosmwasm_std::from_binary;
-    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Binary, Uint128};
     use provwasm_mocks::mock_dependencies;
 
     #[test]
@@ -71,7 +96,7 @@ mod tests {
             },
         )
         .unwrap();
-        let scope_attribute = from_binary::<PayableScopeAttribute>(&payable_binary).unwrap();
+        let scope_attribute = from_binary::<PayableQueryResponse>(&payable_binary).unwrap();
         assert_eq!(
             DEFAULT_PAYABLE_TYPE,
             scope_attribute.payable_type.as_str(),
@@ -114,4 +139,63 @@ mod tests {
             .expect("the default payable should deserialize correctly");
         provenance_util.assert_attribute_matches_latest(&scope_attr);
     }
+
+    #[test]
+    fn test_query_only_fields_are_never_written_to_the_scope() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        set_denom_display_metadata(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            SetDenomDisplayMetadataV1 {
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                symbol: "HASH".to_string(),
+                exponent: 9,
+            },
+        )
+        .unwrap();
+        // Send the onboarding cost (100) + the priority fee (50)
+        let mut register = TestRegisterPayable::default_with_amount(150);
+        register.register_payable.custom_fields =
+            Some(Binary::from(b"{\"invoice_number\":\"INV-1\"}".to_vec()));
+        register.register_payable.priority_fee = Some(Uint128::new(50));
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        let payable = from_binary::<PayableQueryResponse>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayableByScopeId {
+                    scope_id: DEFAULT_SCOPE_ID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(
+            payable.custom_fields.is_some()
+                && payable.payable_denom_metadata.is_some()
+                && payable.priority_fee.is_some(),
+            "the payable query should include every query-only field",
+        );
+        let stored_attributes = from_binary::<ScopeAttributesResponse>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryScopeAttributes {
+                    scope_id: DEFAULT_SCOPE_ID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let raw_json = &stored_attributes.attributes[0].raw_json;
+        for field in ["custom_fields", "payable_denom_metadata", "priority_fee"] {
+            assert!(
+                !raw_json.contains(field),
+                "{} should never be written to the scope attribute, but found: {}",
+                field,
+                raw_json,
+            );
+        }
+    }
 }
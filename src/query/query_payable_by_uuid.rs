@@ -8,7 +8,7 @@ use provwasm_std::ProvenanceQuery;
 
 /// Finds the scope_id by looking up local storage for the payable uuid link, and funnels the result
 /// into the query_payable_by_scope_id function, which pulls all the data from the actual scope's
-/// attribute list.
+/// attribute list into a PayableQueryResponse.
 pub fn query_payable_binary_by_uuid(
     deps: &Deps<ProvenanceQuery>,
     payable_uuid: impl Into<String>,
@@ -41,16 +41,15 @@ fn get_scope_id_for_payable_uuid(
 #[cfg(test)]
 mod tests {
     use crate::contract::query;
-    use crate::core::msg::QueryMsg;
-    use crate::core::state::PayableScopeAttribute;
+    use crate::core::msg::{PayableQueryResponse, QueryMsg, ScopeAttributesResponse};
     use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
     use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
     use crate::testutil::test_utilities::{
         setup_test_suite, InstArgs, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM,
         DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_TYPE, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
     };
-    use cosmwasm_std::from_binary;
     use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Binary};
     use provwasm_mocks::mock_dependencies;
 
     #[test]
@@ -66,7 +65,7 @@ mod tests {
             },
         )
         .unwrap();
-        let scope_attribute = from_binary::<PayableScopeAttribute>(&payable_binary).unwrap();
+        let scope_attribute = from_binary::<PayableQueryResponse>(&payable_binary).unwrap();
         assert_eq!(
             DEFAULT_PAYABLE_TYPE,
             scope_attribute.payable_type.as_str(),
@@ -109,4 +108,45 @@ mod tests {
             .expect("the default payable should deserialize correctly");
         provenance_util.assert_attribute_matches_latest(&scope_attr);
     }
+
+    #[test]
+    fn test_query_payable_by_uuid_includes_custom_fields() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let custom_fields = Binary::from(b"{\"invoice_number\":\"INV-1\"}".to_vec());
+        let mut register = TestRegisterPayable::default();
+        register.register_payable.custom_fields = Some(custom_fields.clone());
+        test_register_payable(&mut deps, &provenance_util, register).unwrap();
+        let payable_binary = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryPayableByUuid {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap();
+        let scope_attribute = from_binary::<PayableQueryResponse>(&payable_binary).unwrap();
+        assert_eq!(
+            Some(custom_fields),
+            scope_attribute.custom_fields,
+            "the custom fields provided at registration should be included in the query response",
+        );
+        let stored_attributes = from_binary::<ScopeAttributesResponse>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryScopeAttributes {
+                    scope_id: DEFAULT_SCOPE_ID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(
+            !stored_attributes.attributes[0]
+                .raw_json
+                .contains("custom_fields"),
+            "the custom fields should never be written to the scope attribute",
+        );
+    }
 }
@@ -0,0 +1,193 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{PayableDiffResponse, PayableFieldChangeV1};
+use crate::core::state::{
+    load_latest_payable_snapshot_sequence, load_payable_snapshot, payable_meta_storage_read_v2,
+    PayableSnapshotV1,
+};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Compares two snapshots of a payable, taken when its scope attribute was written, and lists each
+/// key field whose value differs between them.  Snapshots are numbered from zero at registration,
+/// so the response includes the latest sequence number to show how far a payable's history goes.
+/// Payables written before snapshots were recorded only have the snapshots taken since.
+pub fn query_payable_diff(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: impl Into<String>,
+    from_seq: u64,
+    to_seq: u64,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let from = match load_payable_snapshot(deps.storage, &payable_uuid, from_seq)? {
+        Some(snapshot) => snapshot,
+        None => return ContractError::invalid_fields(vec!["from_seq"]).to_result(),
+    };
+    let to = match load_payable_snapshot(deps.storage, &payable_uuid, to_seq)? {
+        Some(snapshot) => snapshot,
+        None => return ContractError::invalid_fields(vec!["to_seq"]).to_result(),
+    };
+    let changes = snapshot_fields(&from)
+        .into_iter()
+        .zip(snapshot_fields(&to))
+        .filter(|((_, from_value), (_, to_value))| from_value != to_value)
+        .map(
+            |((field, from_value), (_, to_value))| PayableFieldChangeV1 {
+                field: field.to_string(),
+                from: from_value,
+                to: to_value,
+            },
+        )
+        .collect();
+    Ok(to_binary(&PayableDiffResponse {
+        latest_seq: load_latest_payable_snapshot_sequence(deps.storage, &payable_uuid)?
+            .unwrap_or(to_seq),
+        payable_uuid,
+        from_seq,
+        from_taken_at: from.taken_at,
+        to_seq,
+        to_taken_at: to.taken_at,
+        changes,
+    })?)
+}
+
+/// Renders each compared field of a snapshot by name, with absent optional values as None.
+fn snapshot_fields(snapshot: &PayableSnapshotV1) -> Vec<(&'static str, Option<String>)> {
+    vec![
+        ("scope_id", Some(snapshot.scope_id.clone())),
+        ("oracle_address", Some(snapshot.oracle_address.to_string())),
+        ("payable_denom", Some(snapshot.payable_denom.clone())),
+        (
+            "payable_total_owed",
+            Some(snapshot.payable_total_owed.to_string()),
+        ),
+        (
+            "payable_remaining_owed",
+            Some(snapshot.payable_remaining_owed.to_string()),
+        ),
+        (
+            "oracle_approved",
+            Some(snapshot.oracle_approved.to_string()),
+        ),
+        (
+            "expiration_time",
+            snapshot
+                .expiration_time
+                .map(|expiration_time| expiration_time.seconds().to_string()),
+        ),
+        ("is_written_off", Some(snapshot.is_written_off.to_string())),
+        (
+            "payee",
+            snapshot.payee.as_ref().map(|payee| payee.to_string()),
+        ),
+        ("is_declined", Some(snapshot.is_declined.to_string())),
+        ("is_disputed", Some(snapshot.is_disputed.to_string())),
+        ("is_frozen", Some(snapshot.is_frozen.to_string())),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PayableDiffResponse, PayableFieldChangeV1, QueryMsg};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Binary};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payable_diff() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(400),
+        )
+        .unwrap();
+        let diff: PayableDiffResponse = from_binary(&query_diff(&deps, 0, 1).unwrap()).unwrap();
+        assert_eq!(
+            vec![PayableFieldChangeV1 {
+                field: "oracle_approved".to_string(),
+                from: Some("false".to_string()),
+                to: Some("true".to_string()),
+            }],
+            diff.changes,
+            "the approval should be the only change between registration and approval",
+        );
+        assert_eq!(
+            2, diff.latest_seq,
+            "registration, approval and payment should each record a snapshot",
+        );
+        assert_eq!(mock_env().block.time, diff.to_taken_at);
+        let diff: PayableDiffResponse = from_binary(&query_diff(&deps, 0, 2).unwrap()).unwrap();
+        assert_eq!(
+            vec!["payable_remaining_owed", "oracle_approved"],
+            diff.changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect::<Vec<&str>>(),
+            "every field changed since registration should be listed in field order",
+        );
+        assert_eq!(
+            Some("600".to_string()),
+            diff.changes[0].to,
+            "the remaining owed amount should reflect the payment",
+        );
+        let diff: PayableDiffResponse = from_binary(&query_diff(&deps, 2, 2).unwrap()).unwrap();
+        assert!(
+            diff.changes.is_empty(),
+            "a snapshot should not differ from itself",
+        );
+    }
+
+    #[test]
+    fn test_query_payable_diff_rejections() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = query_diff(&deps, 0, 0).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should be reported as not found, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        match query_diff(&deps, 0, 1).unwrap_err() {
+            ContractError::InvalidFields { fields } => assert_eq!(
+                vec!["to_seq".to_string()],
+                fields,
+                "a snapshot that has not been taken should be reported as invalid",
+            ),
+            error => panic!("unexpected error encountered: {:?}", error),
+        }
+    }
+
+    fn query_diff(
+        deps: &MockOwnedDeps,
+        from_seq: u64,
+        to_seq: u64,
+    ) -> Result<Binary, ContractError> {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryPayableDiff {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                from_seq,
+                to_seq,
+            },
+        )
+    }
+}
@@ -0,0 +1,156 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{InstallmentStatusV1, PayableInstallmentsResponse};
+use crate::core::state::payable_meta_storage_read_v2;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
+use provwasm_std::ProvenanceQuery;
+
+/// Reports how much has been paid toward each installment in a payable's payment schedule, and
+/// which installments are overdue: not yet paid in full after their due time has passed relative
+/// to the block time.  Payables registered without a payment schedule produce no installments.
+pub fn query_payable_installments(
+    deps: &Deps<ProvenanceQuery>,
+    env: Env,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let scope_attribute = query_payable_attribute_by_uuid(deps, &payable_uuid)?;
+    let installments = scope_attribute
+        .payment_schedule
+        .iter()
+        .zip(scope_attribute.installment_amounts_paid())
+        .map(|(installment, amount_paid)| InstallmentStatusV1 {
+            due_time: installment.due_time,
+            amount: installment.amount,
+            amount_paid,
+            is_overdue: amount_paid < installment.amount && env.block.time > installment.due_time,
+        })
+        .collect::<Vec<InstallmentStatusV1>>();
+    let overdue_amount = installments
+        .iter()
+        .filter(|installment| installment.is_overdue)
+        .map(|installment| installment.amount - installment.amount_paid)
+        .sum::<Uint128>();
+    Ok(to_binary(&PayableInstallmentsResponse {
+        payable_uuid,
+        installments,
+        overdue_amount,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PayableInstallmentsResponse, QueryMsg};
+    use crate::core::state::PaymentInstallmentV1;
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Env, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payable_installments_reports_overdue_installments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let now = mock_env().block.time;
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    payment_schedule: Some(vec![
+                        PaymentInstallmentV1 {
+                            due_time: now.minus_seconds(100),
+                            amount: Uint128::new(300),
+                        },
+                        PaymentInstallmentV1 {
+                            due_time: now.plus_seconds(100),
+                            amount: Uint128::new(700),
+                        },
+                    ]),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(200),
+        )
+        .unwrap();
+        let response = query_installments(&deps, mock_env()).unwrap();
+        assert_eq!(
+            vec![(200, true), (0, false)],
+            response
+                .installments
+                .iter()
+                .map(|installment| (installment.amount_paid.u128(), installment.is_overdue))
+                .collect::<Vec<(u128, bool)>>(),
+            "only the partially paid installment that is past due should be overdue",
+        );
+        assert_eq!(
+            100,
+            response.overdue_amount.u128(),
+            "the unpaid portion of the overdue installment should be reported",
+        );
+        let mut later_env = mock_env();
+        later_env.block.time = now.plus_seconds(200);
+        assert_eq!(
+            800,
+            query_installments(&deps, later_env)
+                .unwrap()
+                .overdue_amount
+                .u128(),
+            "every unpaid installment should be overdue once its due time has passed",
+        );
+    }
+
+    #[test]
+    fn test_query_payable_installments_without_schedule() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = query_installments(&deps, mock_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should be reported as not found, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let response = query_installments(&deps, mock_env()).unwrap();
+        assert!(
+            response.installments.is_empty(),
+            "a payable without a payment schedule should have no installments",
+        );
+        assert!(response.overdue_amount.is_zero());
+    }
+
+    fn query_installments(
+        deps: &MockOwnedDeps,
+        env: Env,
+    ) -> Result<PayableInstallmentsResponse, ContractError> {
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::QueryPayableInstallments {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .map(|binary| from_binary(&binary).unwrap())
+    }
+}
@@ -0,0 +1,180 @@
+use crate::core::error::ContractError;
+use crate::core::msg::PayableInterestResponse;
+use crate::core::state::payable_meta_storage_read_v2;
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Uint128};
+use provwasm_std::ProvenanceQuery;
+
+/// Reports the interest charged against a payable as of the block time.  Interest accrues lazily,
+/// when payments are made, so any interest accrued since the last payment is calculated here and
+/// included in the accrued and outstanding amounts without being applied.
+pub fn query_payable_interest(
+    deps: &Deps<ProvenanceQuery>,
+    env: Env,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let scope_attribute = query_payable_attribute_by_uuid(deps, &payable_uuid)?;
+    let interest_due = scope_attribute.interest_due(env.block.time)?;
+    let (amount_accrued, interest_paid) = scope_attribute
+        .interest_accrual
+        .as_ref()
+        .map_or((Uint128::zero(), Uint128::zero()), |accrual| {
+            (accrual.amount_accrued, accrual.amount_paid)
+        });
+    let interest_accrued = amount_accrued + interest_due;
+    Ok(to_binary(&PayableInterestResponse {
+        payable_uuid,
+        principal_remaining: scope_attribute.principal_remaining(),
+        interest: scope_attribute.interest,
+        interest_accrued,
+        interest_paid,
+        interest_outstanding: interest_accrued - interest_paid,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PayableInterestResponse, QueryMsg};
+    use crate::core::state::InterestTermsV1;
+    use crate::execute::register_payable::RegisterPayableV2;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::interest::{InterestCompounding, SECONDS_PER_YEAR};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Decimal, Env};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payable_interest_includes_unapplied_interest() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = query_interest(&deps, mock_env()).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should not be found, but got: {:?}",
+            error,
+        );
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    interest: Some(InterestTermsV1 {
+                        start_time: mock_env().block.time.minus_seconds(SECONDS_PER_YEAR),
+                        annual_rate: Decimal::percent(10),
+                        compounding: InterestCompounding::Simple,
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        assert_eq!(
+            (1000, 100, 0, 100),
+            interest_amounts(&query_interest(&deps, mock_env()).unwrap()),
+            "a year of interest should be reported before any payment applies it",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(150),
+        )
+        .unwrap();
+        assert_eq!(
+            (950, 100, 100, 0),
+            interest_amounts(&query_interest(&deps, mock_env()).unwrap()),
+            "the payment should have settled the interest before reducing the principal",
+        );
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(SECONDS_PER_YEAR / 2);
+        assert_eq!(
+            (950, 147, 100, 47),
+            interest_amounts(&query_interest(&deps, later_env).unwrap()),
+            "interest should continue to accrue on the remaining principal alone",
+        );
+    }
+
+    #[test]
+    fn test_query_payable_interest_uses_compounding_mode() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                register_payable: RegisterPayableV2 {
+                    interest: Some(InterestTermsV1 {
+                        start_time: mock_env().block.time.minus_seconds(SECONDS_PER_YEAR),
+                        annual_rate: Decimal::percent(10),
+                        compounding: InterestCompounding::Daily,
+                    }),
+                    ..TestRegisterPayable::default_register_payable()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        let response = query_interest(&deps, mock_env()).unwrap();
+        assert_eq!(
+            InterestCompounding::Daily,
+            response.interest.unwrap().compounding,
+            "the payable's compounding mode should be reported with its terms",
+        );
+        // 1000 * ((1 + 0.1 / 365)^365 - 1) = 105.15
+        assert_eq!(
+            (1000, 105, 0, 105),
+            interest_amounts(&query_interest(&deps, mock_env()).unwrap()),
+            "a year of daily compounding should accrue more than simple interest",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(105),
+        )
+        .unwrap();
+        assert_eq!(
+            (1000, 105, 105, 0),
+            interest_amounts(&query_interest(&deps, mock_env()).unwrap()),
+            "the payment should settle the compounded interest",
+        );
+    }
+
+    fn interest_amounts(response: &PayableInterestResponse) -> (u128, u128, u128, u128) {
+        (
+            response.principal_remaining.u128(),
+            response.interest_accrued.u128(),
+            response.interest_paid.u128(),
+            response.interest_outstanding.u128(),
+        )
+    }
+
+    fn query_interest(
+        deps: &MockOwnedDeps,
+        env: Env,
+    ) -> Result<PayableInterestResponse, ContractError> {
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::QueryPayableInterest {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )?;
+        Ok(from_binary(&bin)?)
+    }
+}
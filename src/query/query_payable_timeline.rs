@@ -0,0 +1,180 @@
+use crate::core::error::ContractError;
+use crate::core::state::{load_payable_timeline, payable_meta_storage_read_v2, PayableTimelineV1};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Gathers every recorded lifecycle timestamp for a payable into a single response.  Payables that
+/// are registered but have no recorded events (registered before timelines were tracked) produce an
+/// empty timeline.  Cancelled and expired payables are no longer registered, but keep their
+/// timeline, so it is still returned.  Unknown payables produce an error.
+pub fn query_payable_timeline(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if let Some(timeline) = load_payable_timeline(deps.storage, &payable_uuid)? {
+        return Ok(to_binary(&timeline)?);
+    }
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    Ok(to_binary(&PayableTimelineV1::new(payable_uuid))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::QueryMsg;
+    use crate::core::state::PayableTimelineV1;
+    use crate::execute::cancel_payable::{cancel_payable_with_util, CancelPayableV1};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_DENOM,
+        DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, Env};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payable_timeline_through_completion() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                env: env_at_seconds(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        test_oracle_approval(
+            &mut deps,
+            &provenance_util,
+            TestOracleApproval {
+                env: env_at_seconds(200),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        for (seconds, amount) in [(300, DEFAULT_PAYABLE_TOTAL - 1), (400, 1)] {
+            test_make_payment(
+                &mut deps,
+                &provenance_util,
+                TestMakePayment {
+                    env: env_at_seconds(seconds),
+                    info: mock_info("payer", &[coin(amount, DEFAULT_PAYABLE_DENOM)]),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+        let timeline = from_binary::<PayableTimelineV1>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayableTimeline {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            PayableTimelineV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                registered_at: Some(env_at_seconds(100).block.time),
+                approved_at: Some(env_at_seconds(200).block.time),
+                payment_times: vec![
+                    env_at_seconds(300).block.time,
+                    env_at_seconds(400).block.time
+                ],
+                completed_at: Some(env_at_seconds(400).block.time),
+                payment_memos: vec![],
+                declined_at: None,
+                cancelled_at: None,
+                expired_at: None,
+            },
+            timeline,
+            "the timeline should include every lifecycle event at its block time",
+        );
+    }
+
+    #[test]
+    fn test_query_payable_timeline_after_cancellation() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(
+            &mut deps,
+            &provenance_util,
+            TestRegisterPayable {
+                env: env_at_seconds(100),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        cancel_payable_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            env_at_seconds(200),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            CancelPayableV1 {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap();
+        let timeline = from_binary::<PayableTimelineV1>(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayableTimeline {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            Some(env_at_seconds(100).block.time),
+            timeline.registered_at,
+            "the registration time should survive the cancellation",
+        );
+        assert_eq!(
+            Some(env_at_seconds(200).block.time),
+            timeline.cancelled_at,
+            "the cancellation time should be returned after the payable is removed",
+        );
+    }
+
+    #[test]
+    fn test_query_payable_timeline_unknown_payable() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryPayableTimeline {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "a timeline query for an unregistered payable should fail, but got: {:?}",
+            error,
+        );
+    }
+
+    fn env_at_seconds(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+}
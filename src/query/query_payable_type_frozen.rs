@@ -0,0 +1,17 @@
+use crate::core::error::ContractError;
+use crate::core::msg::PayableTypeFrozenResponse;
+use crate::core::state::is_payable_type_frozen;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Reports whether or not payables of the given type are frozen by the contract admin, which
+/// prevents them from being approved or paid.
+pub fn query_payable_type_frozen(
+    deps: Deps<ProvenanceQuery>,
+    payable_type: String,
+) -> Result<Binary, ContractError> {
+    Ok(to_binary(&PayableTypeFrozenResponse {
+        frozen: is_payable_type_frozen(deps.storage, &payable_type)?,
+        payable_type,
+    })?)
+}
@@ -0,0 +1,25 @@
+use crate::core::error::ContractError;
+use crate::core::msg::PaymentAllowancesResponse;
+use crate::core::state::{load_payment_allowances, payable_meta_storage_read_v2};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Lists the allowances that payers have granted the contract to pull scheduled payments against a
+/// payable.  Unknown payables produce an error.
+pub fn query_payment_allowances(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let allowances = load_payment_allowances(deps.storage, &payable_uuid)?;
+    Ok(to_binary(&PaymentAllowancesResponse {
+        payable_uuid,
+        allowances,
+    })?)
+}
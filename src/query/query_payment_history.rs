@@ -0,0 +1,156 @@
+use crate::core::error::ContractError;
+use crate::core::msg::PaymentHistoryResponse;
+use crate::core::state::{load_payment_history, payable_meta_storage_read_v2};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Lists every payment recorded against a payable, in the order that they were made.  Payables
+/// paid before payment history was recorded only include the payments made since, while unknown
+/// payables produce an error.
+pub fn query_payment_history(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let payments = load_payment_history(deps.storage, &payable_uuid)?;
+    Ok(to_binary(&PaymentHistoryResponse {
+        payable_uuid,
+        payments,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{PaymentHistoryResponse, QueryMsg};
+    use crate::core::state::PaymentRecordV1;
+    use crate::execute::make_batch_payment::{
+        make_batch_payment_with_util, BatchPaymentV1, MakeBatchPaymentV1,
+    };
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, Addr, Env, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payment_history() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        assert_eq!(
+            Vec::<PaymentRecordV1>::new(),
+            query_history(&deps).payments,
+            "a payable without payments should have an empty history",
+        );
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: env_at_seconds(300),
+                info: mock_info("payer", &[coin(100, DEFAULT_PAYABLE_DENOM)]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        make_batch_payment_with_util(
+            deps.as_mut(),
+            &provenance_util,
+            env_at_seconds(400),
+            mock_info("batch-payer", &[coin(50, DEFAULT_PAYABLE_DENOM)]),
+            MakeBatchPaymentV1 {
+                payments: vec![BatchPaymentV1 {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                    amount: Uint128::new(50),
+                }],
+            },
+        )
+        .unwrap();
+        let history = query_history(&deps);
+        assert_eq!(
+            DEFAULT_PAYABLE_UUID, history.payable_uuid,
+            "the history should be for the requested payable",
+        );
+        assert_eq!(
+            vec![
+                PaymentRecordV1 {
+                    payer: Addr::unchecked("payer"),
+                    amount: Uint128::new(100),
+                    denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                    paid_at: env_at_seconds(300).block.time,
+                    on_behalf_of: None,
+                    external_reference: None,
+                    payee_splits: None,
+                    late_fees_paid: Uint128::zero(),
+                    interest_paid: Uint128::zero(),
+                    netting: None,
+                },
+                PaymentRecordV1 {
+                    payer: Addr::unchecked("batch-payer"),
+                    amount: Uint128::new(50),
+                    denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                    paid_at: env_at_seconds(400).block.time,
+                    on_behalf_of: None,
+                    external_reference: None,
+                    payee_splits: None,
+                    late_fees_paid: Uint128::zero(),
+                    interest_paid: Uint128::zero(),
+                    netting: None,
+                },
+            ],
+            history.payments,
+            "single and batch payments should be recorded in the order they were made",
+        );
+    }
+
+    #[test]
+    fn test_query_payment_history_unknown_payable() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let error = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryPaymentHistory {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unknown payable should produce a not found error, but got: {:?}",
+            error,
+        );
+    }
+
+    fn query_history(deps: &MockOwnedDeps) -> PaymentHistoryResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPaymentHistory {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn env_at_seconds(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+}
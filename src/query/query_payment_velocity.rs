@@ -0,0 +1,133 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{PaymentVelocityResponse, PaymentVelocityWindowV1};
+use crate::core::state::{
+    load_payment_velocity, load_payment_volume_cap, load_payment_volume_window,
+    PAYMENT_VELOCITY_BUCKET_SECONDS,
+};
+use cosmwasm_std::{to_binary, Binary, Deps, Env, StdResult};
+use provwasm_std::ProvenanceQuery;
+
+/// The windows, in buckets, that payment velocity is reported over: the last hour and the last day.
+const PAYMENT_VELOCITY_WINDOW_BUCKETS: [u64; 2] = [1, 24];
+
+/// Reports the number and total amount of payments made in a denom across all payables over recent
+/// windows, alongside the denom's payment volume cap and the volume observed in its current cap
+/// window, if one has been set.  Payments are counted in coarse buckets, so each window includes
+/// the payments made since the start of its oldest bucket.
+pub fn query_payment_velocity(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    denom: String,
+) -> Result<Binary, ContractError> {
+    let windows = PAYMENT_VELOCITY_WINDOW_BUCKETS
+        .iter()
+        .map(|bucket_count| {
+            let velocity = load_payment_velocity(deps.storage, &env.block, &denom, *bucket_count)?;
+            Ok(PaymentVelocityWindowV1 {
+                window_seconds: bucket_count * PAYMENT_VELOCITY_BUCKET_SECONDS,
+                payment_count: velocity.payment_count,
+                payment_volume: velocity.payment_volume,
+            })
+        })
+        .collect::<StdResult<Vec<PaymentVelocityWindowV1>>>()?;
+    Ok(to_binary(&PaymentVelocityResponse {
+        volume_cap: load_payment_volume_cap(deps.storage, &denom)?,
+        volume_cap_window: load_payment_volume_window(deps.storage, &denom)?,
+        denom,
+        windows,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{PaymentVelocityResponse, QueryMsg};
+    use crate::core::state::PAYMENT_VELOCITY_BUCKET_SECONDS;
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_DENOM,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Env, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_payment_velocity_over_recent_windows() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        for amount in [100, 200] {
+            test_make_payment(
+                &mut deps,
+                &provenance_util,
+                TestMakePayment::default_with_amount(amount),
+            )
+            .unwrap();
+        }
+        let mut later_env = mock_env();
+        later_env.block.time = later_env
+            .block
+            .time
+            .plus_seconds(2 * PAYMENT_VELOCITY_BUCKET_SECONDS);
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment {
+                env: later_env.clone(),
+                ..TestMakePayment::default_with_amount(300)
+            },
+        )
+        .unwrap();
+        let velocity = query_velocity(&deps, later_env);
+        assert_eq!(DEFAULT_PAYABLE_DENOM, velocity.denom);
+        assert_eq!(
+            vec![
+                (PAYMENT_VELOCITY_BUCKET_SECONDS, 1, Uint128::new(300)),
+                (24 * PAYMENT_VELOCITY_BUCKET_SECONDS, 3, Uint128::new(600)),
+            ],
+            velocity
+                .windows
+                .iter()
+                .map(|window| (
+                    window.window_seconds,
+                    window.payment_count,
+                    window.payment_volume
+                ))
+                .collect::<Vec<_>>(),
+            "the hourly window should only include the latest payment",
+        );
+        assert!(
+            velocity.volume_cap.is_none() && velocity.volume_cap_window.is_none(),
+            "no volume cap state should be reported for a denom without a cap",
+        );
+        let mut next_day_env = mock_env();
+        next_day_env.block.time = next_day_env
+            .block
+            .time
+            .plus_seconds(26 * PAYMENT_VELOCITY_BUCKET_SECONDS);
+        assert!(
+            query_velocity(&deps, next_day_env)
+                .windows
+                .iter()
+                .all(|window| window.payment_count == 0 && window.payment_volume.is_zero()),
+            "payments should age out of every window",
+        );
+    }
+
+    fn query_velocity(deps: &MockOwnedDeps, env: Env) -> PaymentVelocityResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::QueryPaymentVelocity {
+                    denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,213 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{ExportedPaymentV1, PaymentsResponse};
+use crate::core::state::scan_payment_history;
+use cosmwasm_std::{to_binary, Binary, Deps, Timestamp};
+use provwasm_std::ProvenanceQuery;
+
+/// The number of payments returned by a page of filtered payments when the caller does not provide
+/// a limit.
+const DEFAULT_QUERY_PAYMENTS_LIMIT: u32 = 50;
+/// The largest number of payments that a single page of filtered payments reads, so that a filter
+/// matching few payments cannot exhaust the query's gas.
+const MAX_QUERY_PAYMENTS_SCAN: usize = 1000;
+
+/// Lists the payments recorded against a payable, or against every payable, that match the payer
+/// and time filters provided, beginning after the provided cursor.  Pages are bounded by the number
+/// of payments scanned as well as the number returned, so auditors should keep requesting pages
+/// until no cursor is returned.
+pub fn query_payments(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: Option<String>,
+    payer: Option<String>,
+    after_time: Option<Timestamp>,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let scan = scan_payment_history(
+        deps.storage,
+        payable_uuid.as_deref(),
+        start_after
+            .as_ref()
+            .map(|(payable_uuid, sequence)| (payable_uuid.as_str(), *sequence)),
+        MAX_QUERY_PAYMENTS_SCAN,
+        limit.unwrap_or(DEFAULT_QUERY_PAYMENTS_LIMIT) as usize,
+        |payment| {
+            payer
+                .as_ref()
+                .is_none_or(|payer| payment.payer.as_str() == payer)
+                && after_time.is_none_or(|after_time| payment.paid_at > after_time)
+        },
+    )?;
+    Ok(to_binary(&PaymentsResponse {
+        payments: scan
+            .payments
+            .into_iter()
+            .map(|((payable_uuid, sequence), payment)| ExportedPaymentV1 {
+                payable_uuid,
+                sequence,
+                payment,
+            })
+            .collect(),
+        next_start_after: scan.next_start_after,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{PaymentsResponse, QueryMsg};
+    use crate::core::state::{append_payment_record, PaymentRecordV1};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Timestamp, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    const LATER_PAYABLE_UUID: &str = "4cb54a6e-83ab-11ec-a486-eb4f069082c5";
+
+    #[test]
+    fn test_query_payments_filters_by_payable_payer_and_time() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, "payer-a", 10, 100);
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, "payer-b", 20, 200);
+        record_payment(&mut deps, LATER_PAYABLE_UUID, "payer-a", 30, 300);
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, "payer-a", 40, 400);
+        let amounts = |response: PaymentsResponse| {
+            response
+                .payments
+                .iter()
+                .map(|exported| exported.payment.amount.u128())
+                .collect::<Vec<u128>>()
+        };
+        assert_eq!(
+            vec![10, 20, 40, 30],
+            amounts(query_payments(&deps, None, None, None, None, None)),
+            "without filters every payment should be returned in key order",
+        );
+        assert_eq!(
+            vec![10, 20, 40],
+            amounts(query_payments(
+                &deps,
+                Some(DEFAULT_PAYABLE_UUID),
+                None,
+                None,
+                None,
+                None
+            )),
+            "only the payable's payments should be returned",
+        );
+        assert_eq!(
+            vec![10, 40, 30],
+            amounts(query_payments(
+                &deps,
+                None,
+                Some("payer-a"),
+                None,
+                None,
+                None
+            )),
+            "only the payer's payments should be returned",
+        );
+        assert_eq!(
+            vec![40],
+            amounts(query_payments(
+                &deps,
+                Some(DEFAULT_PAYABLE_UUID),
+                Some("payer-a"),
+                Some(Timestamp::from_seconds(300)),
+                None,
+                None,
+            )),
+            "only payments made after the time should be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_payments_pages_with_cursor() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, "payer-a", 10, 100);
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, "payer-b", 20, 200);
+        record_payment(&mut deps, DEFAULT_PAYABLE_UUID, "payer-a", 30, 300);
+        let page = query_payments(&deps, None, Some("payer-a"), None, None, Some(1));
+        assert_eq!(1, page.payments.len());
+        assert_eq!(
+            Some((DEFAULT_PAYABLE_UUID.to_string(), 0)),
+            page.next_start_after,
+            "a full page should return the cursor of the last payment scanned",
+        );
+        let page = query_payments(
+            &deps,
+            None,
+            Some("payer-a"),
+            None,
+            page.next_start_after,
+            Some(1),
+        );
+        assert_eq!(
+            vec![30],
+            page.payments
+                .iter()
+                .map(|exported| exported.payment.amount.u128())
+                .collect::<Vec<u128>>(),
+            "the next page should skip the payments of other payers",
+        );
+        assert_eq!(
+            None, page.next_start_after,
+            "a scan that reached the end of the history should not return a cursor",
+        );
+    }
+
+    fn record_payment(
+        deps: &mut MockOwnedDeps,
+        payable_uuid: &str,
+        payer: &str,
+        amount: u128,
+        paid_at_seconds: u64,
+    ) {
+        append_payment_record(
+            deps.as_mut().storage,
+            payable_uuid,
+            &PaymentRecordV1 {
+                payer: Addr::unchecked(payer),
+                amount: Uint128::new(amount),
+                denom: DEFAULT_PAYABLE_DENOM.to_string(),
+                paid_at: Timestamp::from_seconds(paid_at_seconds),
+                on_behalf_of: None,
+                external_reference: None,
+                payee_splits: None,
+                late_fees_paid: Uint128::zero(),
+                interest_paid: Uint128::zero(),
+                netting: None,
+            },
+        )
+        .unwrap();
+    }
+
+    fn query_payments(
+        deps: &MockOwnedDeps,
+        payable_uuid: Option<&str>,
+        payer: Option<&str>,
+        after_time: Option<Timestamp>,
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    ) -> PaymentsResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayments {
+                    payable_uuid: payable_uuid.map(|uuid| uuid.to_string()),
+                    payer: payer.map(|payer| payer.to_string()),
+                    after_time,
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
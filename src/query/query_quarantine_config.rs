@@ -0,0 +1,13 @@
+use crate::core::error::ContractError;
+use crate::core::msg::QuarantineConfigResponse;
+use crate::core::state::load_quarantine_config;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Produces the heuristics that payments are currently screened with, so that payers and monitoring
+/// tools can anticipate which payments will be held for admin review.
+pub fn query_quarantine_config(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
+    Ok(to_binary(&QuarantineConfigResponse {
+        quarantine_config: load_quarantine_config(deps.storage)?,
+    })?)
+}
@@ -0,0 +1,108 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{QuarantinedPaymentEntryV1, QuarantinedPaymentsResponse};
+use crate::core::state::{load_quarantined_payments, payable_meta_storage_read_v2};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Lists the payments made against a payable that are still held in quarantine, awaiting release
+/// or refund by the admin.  Unknown payables produce an error.
+pub fn query_quarantined_payments(
+    deps: &Deps<ProvenanceQuery>,
+    payable_uuid: impl Into<String>,
+) -> Result<Binary, ContractError> {
+    let payable_uuid = payable_uuid.into();
+    if payable_meta_storage_read_v2(deps.storage)
+        .may_load(payable_uuid.as_bytes())?
+        .is_none()
+    {
+        return ContractError::PayableNotFound { payable_uuid }.to_result();
+    }
+    let quarantined_payments = load_quarantined_payments(deps.storage, &payable_uuid)?
+        .into_iter()
+        .map(|(payment_id, payment)| QuarantinedPaymentEntryV1 {
+            payment_id,
+            payment,
+        })
+        .collect();
+    Ok(to_binary(&QuarantinedPaymentsResponse {
+        payable_uuid,
+        quarantined_payments,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::error::ContractError;
+    use crate::core::msg::{QuarantinedPaymentsResponse, QueryMsg};
+    use crate::execute::quarantine::{set_quarantine_config, SetQuarantineConfigV1};
+    use crate::testutil::make_payment_helpers::{test_make_payment, TestMakePayment};
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_DENOM,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Addr, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_query_quarantined_payments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        let error = query_quarantined_payments(&deps).unwrap_err();
+        assert!(
+            matches!(error, ContractError::PayableNotFound { .. }),
+            "an unregistered payable should not be found, but got: {:?}",
+            error,
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        set_quarantine_config(
+            deps.as_mut(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            SetQuarantineConfigV1 {
+                flagged_payers: vec!["flagged-payer".to_string()],
+                large_payment_thresholds: vec![],
+            },
+        )
+        .unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_with_amount(100),
+        )
+        .unwrap();
+        test_make_payment(
+            &mut deps,
+            &provenance_util,
+            TestMakePayment::default_full_sender("flagged-payer", 200, DEFAULT_PAYABLE_DENOM),
+        )
+        .unwrap();
+        let response = query_quarantined_payments(&deps).unwrap();
+        assert_eq!(
+            1,
+            response.quarantined_payments.len(),
+            "only the payment from the flagged payer should be quarantined",
+        );
+        let entry = response.quarantined_payments.first().unwrap();
+        assert_eq!(1, entry.payment_id);
+        assert_eq!(Addr::unchecked("flagged-payer"), entry.payment.payer);
+        assert_eq!(Uint128::new(200), entry.payment.amount);
+        assert_eq!(mock_env().block.time, entry.payment.quarantined_at);
+    }
+
+    fn query_quarantined_payments(
+        deps: &MockOwnedDeps,
+    ) -> Result<QuarantinedPaymentsResponse, ContractError> {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryQuarantinedPayments {
+                payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            },
+        )
+        .map(|binary| from_binary(&binary).unwrap())
+    }
+}
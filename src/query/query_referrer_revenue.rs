@@ -0,0 +1,23 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ReferrerRevenueResponse;
+use crate::core::state::{load_referral_config, load_referrer_revenue};
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Produces the revenue share that a referrer has been paid in each onboarding denom, so that
+/// partners can be reported on without indexing registration events.  Referrers that have been
+/// removed from the allowlist retain the revenue they were paid while listed.
+pub fn query_referrer_revenue(
+    deps: Deps<ProvenanceQuery>,
+    referrer: String,
+) -> Result<Binary, ContractError> {
+    let fee_share_bps = load_referral_config(deps.storage)?
+        .filter(|config| config.find_referrer(&referrer).is_some())
+        .map(|config| config.fee_share_bps);
+    Ok(to_binary(&ReferrerRevenueResponse {
+        revenue: load_referrer_revenue(deps.storage, &referrer)?,
+        is_referrer: fee_share_bps.is_some(),
+        fee_share_bps,
+        referrer,
+    })?)
+}
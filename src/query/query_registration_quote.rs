@@ -0,0 +1,130 @@
+use crate::core::error::ContractError;
+use crate::core::msg::RegistrationQuoteResponse;
+use crate::core::state::{config_read_v2, is_payable_type_supported, load_payable_type_config};
+use crate::util::fees::calculate_registration_fees;
+use cosmwasm_std::{to_binary, Binary, Deps, Env};
+use provwasm_std::ProvenanceQuery;
+
+/// Quotes the funds that a registration of the given payable type must send right now, so that
+/// clients can build the registration without reading the contract's configuration themselves.
+/// The values are calculated by the same function used during registration, so the quote includes
+/// any payable type config and fee holiday that would apply to the charge.
+pub fn query_registration_quote(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    payable_type: String,
+) -> Result<Binary, ContractError> {
+    Ok(to_binary(&quote_registration(&deps, &env, payable_type)?)?)
+}
+
+/// Builds the registration quote for the given payable type.  Every fee quote is derived from this
+/// function so that the quoting queries can never disagree with each other.
+pub(crate) fn quote_registration(
+    deps: &Deps<ProvenanceQuery>,
+    env: &Env,
+    payable_type: String,
+) -> Result<RegistrationQuoteResponse, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    let fees = calculate_registration_fees(deps.storage, &state, &payable_type, env.block.time)?;
+    Ok(RegistrationQuoteResponse {
+        is_supported: is_payable_type_supported(deps.storage, &payable_type),
+        onboarding_cost: fees.onboarding_cost.amount,
+        onboarding_denom: fees.onboarding_cost.denom,
+        fee_amount: fees.fee_amount.amount,
+        oracle_amount: fees.oracle_amount.amount,
+        fee_bps: fees.fee_bps,
+        fee_holiday_id: fees.fee_holiday_id,
+        payable_type_config: load_payable_type_config(deps.storage, &payable_type)?,
+        payable_type,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::{execute, query};
+    use crate::core::msg::{ExecuteMsg, QueryMsg, RegistrationQuoteResponse};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM,
+        DEFAULT_PAYABLE_TYPE,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Decimal, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    const LOAN_PAYABLE_TYPE: &str = "loan";
+
+    #[test]
+    fn test_query_registration_quote() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let quote = query_quote(&deps, DEFAULT_PAYABLE_TYPE);
+        assert!(
+            quote.is_supported,
+            "the default payable type should be supported"
+        );
+        assert_eq!(
+            (100, DEFAULT_ONBOARDING_DENOM, 75, 25, 7500),
+            (
+                quote.onboarding_cost.u128(),
+                quote.onboarding_denom.as_str(),
+                quote.fee_amount.u128(),
+                quote.oracle_amount.u128(),
+                quote.fee_bps.u128(),
+            ),
+            "a payable type without a config should be quoted the contract's fees",
+        );
+        assert!(quote.payable_type_config.is_none());
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_INFO_NAME, &[]),
+            ExecuteMsg::UpsertPayableTypeConfig {
+                payable_type: LOAN_PAYABLE_TYPE.to_string(),
+                onboarding_cost: Uint128::new(500),
+                onboarding_denom: "usdf".to_string(),
+                fee_percent: Decimal::percent(20),
+                default_oracle: None,
+                registrant_requirement: None,
+            },
+        )
+        .expect("the admin should be able to configure a payable type");
+        let quote = query_quote(&deps, LOAN_PAYABLE_TYPE);
+        assert!(
+            !quote.is_supported,
+            "a configured payable type is not supported until it is added",
+        );
+        assert_eq!(
+            (500, "usdf", 100, 400, 2000),
+            (
+                quote.onboarding_cost.u128(),
+                quote.onboarding_denom.as_str(),
+                quote.fee_amount.u128(),
+                quote.oracle_amount.u128(),
+                quote.fee_bps.u128(),
+            ),
+            "a configured payable type should be quoted its own fees",
+        );
+        assert_eq!(
+            Some(LOAN_PAYABLE_TYPE),
+            quote
+                .payable_type_config
+                .as_ref()
+                .map(|config| config.payable_type.as_str()),
+            "the payable type's overrides should be included",
+        );
+    }
+
+    fn query_quote(deps: &MockOwnedDeps, payable_type: &str) -> RegistrationQuoteResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryRegistrationQuote {
+                    payable_type: payable_type.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
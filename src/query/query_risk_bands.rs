@@ -0,0 +1,13 @@
+use crate::core::error::ContractError;
+use crate::core::msg::RiskBandsResponse;
+use crate::core::state::load_risk_bands;
+use cosmwasm_std::{to_binary, Binary, Deps};
+use provwasm_std::ProvenanceQuery;
+
+/// Produces the bands that payments are currently keyed off by risk score, so that oracles and
+/// payers can anticipate the holdbacks and co-signing thresholds that a risk score will produce.
+pub fn query_risk_bands(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
+    Ok(to_binary(&RiskBandsResponse {
+        risk_bands: load_risk_bands(deps.storage)?,
+    })?)
+}
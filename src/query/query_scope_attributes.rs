@@ -0,0 +1,127 @@
+use crate::core::error::ContractError;
+use crate::core::msg::{RawScopeAttributeV1, ScopeAttributesResponse};
+use crate::core::state::{config_read_v2, PayableScopeAttribute};
+use cosmwasm_std::{from_binary, to_binary, Addr, Binary, Deps};
+use provwasm_std::{ProvenanceQuerier, ProvenanceQuery};
+
+/// Lists every attribute found on a scope under the contract's name, without requiring that they
+/// form a valid payable.  Where the payable queries fail with InvalidScopeAttribute when the
+/// attributes have drifted (ex: after attributes are manually deleted or duplicated), this exposes
+/// the values that are actually stored so that the drift can be investigated.
+pub fn query_scope_attributes(
+    deps: &Deps<ProvenanceQuery>,
+    scope_id: String,
+) -> Result<Binary, ContractError> {
+    let state = config_read_v2(deps.storage).load()?;
+    let attributes = ProvenanceQuerier::new(&deps.querier)
+        .get_attributes(Addr::unchecked(&scope_id), Some(&state.contract_name))?
+        .attributes
+        .into_iter()
+        .map(|attribute| {
+            let (payable_uuid, parse_error) =
+                match from_binary::<PayableScopeAttribute>(&attribute.value) {
+                    Ok(payable) => (Some(payable.payable_uuid), None),
+                    Err(e) => (None, Some(e.to_string())),
+                };
+            RawScopeAttributeV1 {
+                value_type: attribute.value_type,
+                raw_json: String::from_utf8_lossy(attribute.value.as_slice()).to_string(),
+                payable_uuid,
+                parse_error,
+            }
+        })
+        .collect::<Vec<RawScopeAttributeV1>>();
+    Ok(to_binary(&ScopeAttributesResponse {
+        scope_id,
+        attribute_name: state.contract_name,
+        attribute_count: attributes.len() as u32,
+        attributes,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{QueryMsg, ScopeAttributesResponse};
+    use crate::query::query_payable_by_scope_id::query_payable_attribute_by_scope_id;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_CONTRACT_NAME, DEFAULT_PAYABLE_UUID,
+        DEFAULT_SCOPE_ID,
+    };
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+    use serde_json_wasm::to_string;
+
+    #[test]
+    fn test_query_scope_attributes_for_registered_payable() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let response = query_scope_attributes(&deps);
+        assert_eq!(DEFAULT_SCOPE_ID, response.scope_id);
+        assert_eq!(DEFAULT_CONTRACT_NAME, response.attribute_name);
+        assert_eq!(1, response.attribute_count);
+        let attribute = response.attributes.first().unwrap();
+        assert_eq!(
+            Some(DEFAULT_PAYABLE_UUID.to_string()),
+            attribute.payable_uuid,
+            "the attribute should be identified as the registered payable",
+        );
+        assert!(attribute.parse_error.is_none());
+    }
+
+    #[test]
+    fn test_query_scope_attributes_reports_drifted_attributes() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        assert_eq!(
+            0,
+            query_scope_attributes(&deps).attribute_count,
+            "a scope without any attributes should report none rather than failing",
+        );
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let payable_json = to_string(
+            &query_payable_attribute_by_scope_id(&deps.as_ref(), DEFAULT_SCOPE_ID).unwrap(),
+        )
+        .unwrap();
+        deps.querier.with_attributes(
+            DEFAULT_SCOPE_ID,
+            &[
+                (DEFAULT_CONTRACT_NAME, payable_json.as_str(), "json"),
+                (DEFAULT_CONTRACT_NAME, "{\"unexpected\":true}", "json"),
+            ],
+        );
+        query_payable_attribute_by_scope_id(&deps.as_ref(), DEFAULT_SCOPE_ID)
+            .expect_err("the payable query should fail when the scope has multiple attributes");
+        let response = query_scope_attributes(&deps);
+        assert_eq!(2, response.attribute_count);
+        assert_eq!(payable_json, response.attributes[0].raw_json);
+        assert_eq!(
+            "{\"unexpected\":true}", response.attributes[1].raw_json,
+            "the raw value of the foreign attribute should be returned",
+        );
+        assert!(
+            response.attributes[1].payable_uuid.is_none()
+                && response.attributes[1].parse_error.is_some(),
+            "the foreign attribute should be reported as an invalid payable attribute",
+        );
+    }
+
+    fn query_scope_attributes(deps: &MockOwnedDeps) -> ScopeAttributesResponse {
+        from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryScopeAttributes {
+                    scope_id: DEFAULT_SCOPE_ID.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,207 @@
+use crate::core::error::ContractError;
+use crate::core::money::Money;
+use crate::core::msg::{PaymentSimulationResponse, SimulatedPaymentErrorV1, SimulatedPaymentV1};
+use crate::execute::make_payment::{process_payment, MakePaymentV1};
+use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl};
+use crate::util::storage_overlay::StorageOverlay;
+use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo};
+use provwasm_std::ProvenanceQuery;
+
+/// Parent function path for the contract to simulate a payment.  Ensures that the
+/// ProvenanceUtilImpl is the implementation used for this functionality outside of tests.
+pub fn query_simulate_payment(
+    deps: Deps<ProvenanceQuery>,
+    env: Env,
+    payable_uuid: String,
+    payment: Money,
+    payer: String,
+    refund_overpayment: bool,
+) -> Result<Binary, ContractError> {
+    query_simulate_payment_with_util(
+        deps,
+        &ProvenanceUtilImpl,
+        env,
+        payable_uuid,
+        payment,
+        payer,
+        refund_overpayment,
+    )
+}
+
+/// Runs a payment of the given funds from the payer through the same steps as make_payment, against
+/// storage that discards every write, so that wallets can show the outcome of a payment, or the
+/// error that it would be rejected with, before it is sent.  A rejected payment is reported in the
+/// response rather than failing the query.
+pub fn query_simulate_payment_with_util<T: ProvenanceUtil>(
+    deps: Deps<ProvenanceQuery>,
+    provenance_util: &T,
+    env: Env,
+    payable_uuid: String,
+    payment: Money,
+    payer: String,
+    refund_overpayment: bool,
+) -> Result<Binary, ContractError> {
+    let info = MessageInfo {
+        sender: deps.api.addr_validate(&payer)?,
+        funds: vec![payment.to_coin()],
+    };
+    let mut storage = StorageOverlay::new(deps.storage);
+    let simulated_deps = DepsMut {
+        storage: &mut storage,
+        api: deps.api,
+        querier: deps.querier,
+    };
+    let made_payment = process_payment(
+        simulated_deps,
+        provenance_util,
+        &env,
+        &info,
+        MakePaymentV1 {
+            payable_uuid: payable_uuid.clone(),
+            refund_overpayment,
+            memo: None,
+            on_behalf_of: None,
+        },
+    );
+    let (outcome, error) = match made_payment {
+        Ok(made_payment) => (
+            Some(SimulatedPaymentV1 {
+                remaining_owed: made_payment.applied_payment.remaining_owed,
+                refund: made_payment.refund.amount,
+                payee: made_payment.applied_payment.payee,
+            }),
+            None,
+        ),
+        Err(error) => (
+            None,
+            Some(SimulatedPaymentErrorV1 {
+                kind: error.kind(),
+                message: error.to_string(),
+            }),
+        ),
+    };
+    Ok(to_binary(&PaymentSimulationResponse {
+        payable_uuid,
+        outcome,
+        error,
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::money::Money;
+    use crate::core::msg::PaymentSimulationResponse;
+    use crate::core::state::load_payment_history;
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::query::query_simulate_payment::query_simulate_payment_with_util;
+    use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+    use crate::testutil::oracle_approval_helpers::{test_oracle_approval, TestOracleApproval};
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_DENOM,
+        DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_simulate_payment_does_not_change_state() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let simulation = simulate(&deps, &provenance_util, 40, false);
+        assert!(simulation.error.is_none());
+        let outcome = simulation
+            .outcome
+            .expect("a valid payment should produce an outcome");
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL - 40,
+            outcome.remaining_owed.u128(),
+            "the remaining owed amount should reflect the payment",
+        );
+        assert!(outcome.refund.is_zero());
+        assert_eq!(
+            DEFAULT_INFO_NAME,
+            outcome.payee.as_str(),
+            "the payee should be the value owner of the scope",
+        );
+        assert_eq!(
+            DEFAULT_PAYABLE_TOTAL,
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .payable_remaining_owed
+                .u128(),
+            "the simulation should not change the payable",
+        );
+        assert!(
+            load_payment_history(&deps.storage, DEFAULT_PAYABLE_UUID)
+                .unwrap()
+                .is_empty(),
+            "the simulation should not record a payment",
+        );
+    }
+
+    #[test]
+    fn test_simulate_payment_reports_overpayments() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        test_oracle_approval(&mut deps, &provenance_util, TestOracleApproval::default()).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let simulation = simulate(&deps, &provenance_util, DEFAULT_PAYABLE_TOTAL + 5, false);
+        assert!(simulation.outcome.is_none());
+        let error = simulation
+            .error
+            .expect("an overpayment should produce an error");
+        assert_eq!("PaymentTooLarge", error.kind);
+        assert!(
+            error.message.contains(&DEFAULT_PAYABLE_TOTAL.to_string()),
+            "the error message should describe the amount owed, but was: {}",
+            error.message,
+        );
+        let outcome = simulate(&deps, &provenance_util, DEFAULT_PAYABLE_TOTAL + 5, true)
+            .outcome
+            .expect("an overpayment with a refund requested should produce an outcome");
+        assert_eq!(
+            (Uint128::zero(), Uint128::new(5)),
+            (outcome.remaining_owed, outcome.refund),
+            "the excess funds should be refunded",
+        );
+    }
+
+    #[test]
+    fn test_simulate_payment_reports_unapproved_payables() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        provenance_util.bind_captured_attribute(&mut deps);
+        let error = simulate(&deps, &provenance_util, 40, false)
+            .error
+            .expect("a payment on an unapproved payable should produce an error");
+        assert_eq!("NotReadyForPayment", error.kind);
+    }
+
+    fn simulate(
+        deps: &MockOwnedDeps,
+        provenance_util: &MockProvenanceUtil,
+        amount: u128,
+        refund_overpayment: bool,
+    ) -> PaymentSimulationResponse {
+        from_binary(
+            &query_simulate_payment_with_util(
+                deps.as_ref(),
+                provenance_util,
+                mock_env(),
+                DEFAULT_PAYABLE_UUID.to_string(),
+                Money::new(amount, DEFAULT_PAYABLE_DENOM),
+                "payer-guy".to_string(),
+                refund_overpayment,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+}
@@ -1,19 +1,20 @@
 use crate::core::error::ContractError;
+use crate::core::msg::StateQueryResponse;
 use crate::core::state::config_read_v2;
 use cosmwasm_std::{to_binary, Binary, Deps};
 use provwasm_std::ProvenanceQuery;
 
 /// A very simple query that deserializes the StateV2 value in local storage that drives contract
-/// functionality.
+/// functionality, and returns it as a StateQueryResponse.
 pub fn query_state(deps: Deps<ProvenanceQuery>) -> Result<Binary, ContractError> {
     let state = config_read_v2(deps.storage).load()?;
-    Ok(to_binary(&state)?)
+    Ok(to_binary(&StateQueryResponse::from(state))?)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::contract::query;
-    use crate::core::msg::{QueryMsg, QueryResponse};
+    use crate::core::msg::{QueryMsg, StateQueryResponse};
     use crate::testutil::test_utilities::{
         test_instantiate, InstArgs, DEFAULT_CONTRACT_NAME, DEFAULT_FEE_COLLECTION_ADDRESS,
         DEFAULT_FEE_PERCENT, DEFAULT_ONBOARDING_DENOM,
@@ -31,7 +32,7 @@ mod tests {
 
         // Call the smart contract query function to get stored state.
         let bin = query(deps.as_ref(), mock_env(), QueryMsg::QueryState {}).unwrap();
-        let resp: QueryResponse = from_binary(&bin).unwrap();
+        let resp: StateQueryResponse = from_binary(&bin).unwrap();
 
         // Ensure the expected init fields were properly stored.
         assert_eq!(DEFAULT_CONTRACT_NAME, resp.contract_name);
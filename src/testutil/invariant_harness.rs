@@ -0,0 +1,356 @@
+use crate::core::error::ContractError;
+#[cfg(feature = "escrow")]
+use crate::core::state::load_escrowed_payments;
+use crate::core::state::{
+    load_held_oracle_fee, load_oracle_gas_rebate, load_payable_priority_fee, load_payment_history,
+    load_payment_reversal, load_proposed_payment, load_quarantined_payments,
+    payable_meta_storage_read_v2,
+};
+use crate::execute::cancel_payable::{cancel_payable_with_util, CancelPayableV1};
+use crate::execute::make_payment::{make_payment_with_util, MakePaymentV1};
+use crate::execute::oracle_approval::oracle_approval_with_util;
+use crate::execute::register_payable::register_payable_with_util;
+use crate::migrate::migrate_contract::{migrate_contract, MigrateContractV2};
+use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+use crate::testutil::make_payment_helpers::TestMakePayment;
+use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+use crate::testutil::oracle_approval_helpers::TestOracleApproval;
+use crate::testutil::register_payable_helpers::TestRegisterPayable;
+use crate::testutil::test_utilities::{
+    setup_test_suite, InstArgs, MockOwnedDeps, DEFAULT_INFO_NAME, DEFAULT_ONBOARDING_DENOM,
+    DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
+};
+use cosmwasm_std::testing::{mock_env, mock_info, MockStorage};
+use cosmwasm_std::{coin, BankMsg, Coin, CosmosMsg, Decimal, Order, Response, Storage, Uint128};
+use provwasm_mocks::mock_dependencies;
+use provwasm_std::ProvenanceMsg;
+use std::collections::BTreeMap;
+
+/// A single execution that the invariant harness can apply to the default payable.  The mock
+/// querier can only hold a single scope and attribute at a time, so every action targets the same
+/// payable, which may be registered, cancelled and registered again over the course of a sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HarnessAction {
+    Register {
+        funds: u128,
+    },
+    Approve,
+    Pay {
+        amount: u128,
+        refund_overpayment: bool,
+    },
+    Cancel,
+    MigrateConfig {
+        onboarding_cost: Option<u128>,
+        fee_percent: Option<u64>,
+        minimum_payment_amount: Option<u128>,
+    },
+}
+
+/// Applies sequences of executions against mocked dependencies, tracking the funds that each one
+/// moves so that the contract's global invariants can be asserted after every step.
+pub struct InvariantHarness {
+    pub deps: MockOwnedDeps,
+    provenance_util: MockProvenanceUtil,
+    // The funds sent to the contract less the funds sent out by its responses, by denom
+    contract_balances: BTreeMap<String, i128>,
+}
+impl InvariantHarness {
+    pub fn new() -> Self {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        InvariantHarness {
+            deps,
+            provenance_util,
+            contract_balances: BTreeMap::new(),
+        }
+    }
+
+    /// Applies the action, mirroring the chain: a failed execution leaves no writes behind, and the
+    /// funds of a successful one are credited to the contract before its messages send them out.
+    pub fn apply(
+        &mut self,
+        action: &HarnessAction,
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let snapshot = self
+            .deps
+            .storage
+            .range(None, None, Order::Ascending)
+            .collect::<Vec<(Vec<u8>, Vec<u8>)>>();
+        let sent_funds = match action {
+            HarnessAction::Register { funds } => funds_of(*funds, DEFAULT_ONBOARDING_DENOM),
+            HarnessAction::Pay { amount, .. } => funds_of(*amount, DEFAULT_PAYABLE_DENOM),
+            _ => vec![],
+        };
+        let captured_attribute_count = self.provenance_util.captured_attribute_count();
+        let result = self.execute(action, &sent_funds);
+        match &result {
+            Ok(response) => {
+                for funds in &sent_funds {
+                    *self
+                        .contract_balances
+                        .entry(funds.denom.clone())
+                        .or_default() += funds.amount.u128() as i128;
+                }
+                for msg in &response.messages {
+                    if let CosmosMsg::Bank(BankMsg::Send { amount, .. }) = &msg.msg {
+                        for funds in amount {
+                            *self
+                                .contract_balances
+                                .entry(funds.denom.clone())
+                                .or_default() -= funds.amount.u128() as i128;
+                        }
+                    }
+                }
+                // The mock querier only reflects attribute writes once they are bound, and a
+                // cancelled payable's attribute is deleted from its scope
+                if action == &HarnessAction::Cancel {
+                    self.deps.querier.with_attributes(DEFAULT_SCOPE_ID, &[]);
+                } else if self.provenance_util.captured_attribute_count() > captured_attribute_count
+                {
+                    self.provenance_util.bind_captured_attribute(&mut self.deps);
+                }
+            }
+            Err(_) => {
+                self.deps.storage = MockStorage::new();
+                for (key, value) in snapshot {
+                    self.deps.storage.set(&key, &value);
+                }
+            }
+        }
+        result
+    }
+
+    /// Asserts every invariant that must hold between executions:
+    /// - The contract never sends out more of a denom than it has received.
+    /// - The contract's balance is exactly the funds it holds on behalf of others: the retained
+    ///   oracle fee, priority fee and gas rebate, and any escrowed, quarantined or proposed payments.
+    /// - A registered payable's scope attribute agrees with its local meta, and an unregistered
+    ///   payable leaves neither behind.
+    /// - The amount paid on a payable is exactly its recorded payments, less those reversed, and
+    ///   never exceeds its total owed.
+    pub fn assert_invariants(&self) {
+        let storage = &self.deps.storage;
+        for (denom, balance) in &self.contract_balances {
+            assert!(
+                *balance >= 0,
+                "the contract sent out [{}] more [{}] than it received",
+                -balance,
+                denom,
+            );
+        }
+        let mut held_funds = BTreeMap::<String, i128>::new();
+        let mut hold = |denom: &str, amount: Uint128| {
+            *held_funds.entry(denom.to_string()).or_default() += amount.u128() as i128;
+        };
+        if let Some(held_fee) = load_held_oracle_fee(storage, DEFAULT_PAYABLE_UUID).unwrap() {
+            hold(&held_fee.denom, held_fee.amount);
+        }
+        if let Some(priority_fee) =
+            load_payable_priority_fee(storage, DEFAULT_PAYABLE_UUID).unwrap()
+        {
+            hold(&priority_fee.denom, priority_fee.amount);
+        }
+        if let Some(rebate) = load_oracle_gas_rebate(storage, DEFAULT_PAYABLE_UUID).unwrap() {
+            hold(&rebate.denom, rebate.amount);
+        }
+        #[cfg(feature = "escrow")]
+        for (_, escrowed) in load_escrowed_payments(storage, DEFAULT_PAYABLE_UUID).unwrap() {
+            hold(&escrowed.denom, escrowed.amount);
+        }
+        for (_, quarantined) in load_quarantined_payments(storage, DEFAULT_PAYABLE_UUID).unwrap() {
+            hold(&quarantined.denom, quarantined.amount);
+        }
+        if let Some(proposed) = load_proposed_payment(storage, DEFAULT_PAYABLE_UUID).unwrap() {
+            hold(&proposed.denom, proposed.amount);
+        }
+        held_funds.retain(|_, amount| *amount != 0);
+        let mut balances = self.contract_balances.clone();
+        balances.retain(|_, balance| *balance != 0);
+        assert_eq!(
+            held_funds, balances,
+            "the contract's balance should be exactly the funds that it holds on behalf of others",
+        );
+        let meta = payable_meta_storage_read_v2(storage)
+            .may_load(DEFAULT_PAYABLE_UUID.as_bytes())
+            .unwrap();
+        let attribute = query_payable_attribute_by_uuid(&self.deps.as_ref(), DEFAULT_PAYABLE_UUID);
+        let (meta, attribute) = match (meta, attribute) {
+            (Some(meta), Ok(attribute)) => (meta, attribute),
+            (None, Err(_)) => {
+                assert!(
+                    held_funds.is_empty(),
+                    "an unregistered payable should not hold any funds",
+                );
+                return;
+            }
+            (meta, attribute) => panic!(
+                "the payable's local meta and scope attribute disagree: {:?} / {:?}",
+                meta, attribute,
+            ),
+        };
+        assert_eq!(attribute.payable_uuid, meta.payable_uuid);
+        assert_eq!(attribute.scope_id, meta.scope_id);
+        assert!(
+            attribute.payable_remaining_owed <= attribute.payable_total_owed,
+            "the payable's remaining owed amount should never exceed its total",
+        );
+        let mut amount_paid = Uint128::zero();
+        // Payment ids are sequential from zero, so they are the indices of the payment history
+        let payment_history = load_payment_history(storage, DEFAULT_PAYABLE_UUID).unwrap();
+        for (payment_id, payment) in payment_history.into_iter().enumerate() {
+            if load_payment_reversal(storage, DEFAULT_PAYABLE_UUID, payment_id as u64)
+                .unwrap()
+                .is_none()
+            {
+                amount_paid += payment.amount;
+            }
+        }
+        assert_eq!(
+            attribute.payable_total_owed - attribute.payable_remaining_owed,
+            amount_paid,
+            "the amount paid on the payable should match its unreversed payment history",
+        );
+    }
+
+    fn execute(
+        &mut self,
+        action: &HarnessAction,
+        sent_funds: &[Coin],
+    ) -> Result<Response<ProvenanceMsg>, ContractError> {
+        let deps = self.deps.as_mut();
+        let provenance_util = &self.provenance_util;
+        match action {
+            HarnessAction::Register { .. } => register_payable_with_util(
+                deps,
+                provenance_util,
+                mock_env(),
+                mock_info(DEFAULT_INFO_NAME, sent_funds),
+                TestRegisterPayable::default_register_payable(),
+            ),
+            HarnessAction::Approve => oracle_approval_with_util(
+                deps,
+                provenance_util,
+                mock_env(),
+                mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
+                TestOracleApproval::default_oracle_approval(),
+            ),
+            HarnessAction::Pay {
+                refund_overpayment, ..
+            } => make_payment_with_util(
+                deps,
+                provenance_util,
+                mock_env(),
+                mock_info(DEFAULT_INFO_NAME, sent_funds),
+                MakePaymentV1 {
+                    refund_overpayment: *refund_overpayment,
+                    ..TestMakePayment::default_make_payment()
+                },
+            ),
+            HarnessAction::Cancel => cancel_payable_with_util(
+                deps,
+                provenance_util,
+                mock_env(),
+                mock_info(DEFAULT_INFO_NAME, &[]),
+                CancelPayableV1 {
+                    payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+                },
+            ),
+            HarnessAction::MigrateConfig {
+                onboarding_cost,
+                fee_percent,
+                minimum_payment_amount,
+            } => migrate_contract(
+                deps,
+                mock_env(),
+                MigrateContractV2 {
+                    onboarding_cost: onboarding_cost.map(Uint128::new),
+                    fee_percent: fee_percent.map(Decimal::percent),
+                    minimum_payment_amount: minimum_payment_amount.map(Uint128::new),
+                    ..MigrateContractV2::empty()
+                },
+            ),
+        }
+    }
+}
+impl Default for InvariantHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn funds_of(amount: u128, denom: &str) -> Vec<Coin> {
+    if amount == 0 {
+        vec![]
+    } else {
+        vec![coin(amount, denom)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testutil::invariant_harness::{HarnessAction, InvariantHarness};
+    use proptest::prelude::*;
+
+    fn action_strategy() -> impl Strategy<Value = HarnessAction> {
+        prop_oneof![
+            (0..300u128).prop_map(|funds| HarnessAction::Register { funds }),
+            Just(HarnessAction::Approve),
+            (0..1200u128, any::<bool>()).prop_map(|(amount, refund_overpayment)| {
+                HarnessAction::Pay {
+                    amount,
+                    refund_overpayment,
+                }
+            }),
+            Just(HarnessAction::Cancel),
+            (
+                proptest::option::of(0..300u128),
+                proptest::option::of(0..=100u64),
+                proptest::option::of(1..200u128),
+            )
+                .prop_map(|(onboarding_cost, fee_percent, minimum_payment_amount)| {
+                    HarnessAction::MigrateConfig {
+                        onboarding_cost,
+                        fee_percent,
+                        minimum_payment_amount,
+                    }
+                }),
+        ]
+    }
+
+    #[test]
+    fn test_harness_applies_full_lifecycle() {
+        let mut harness = InvariantHarness::new();
+        for action in [
+            HarnessAction::Register { funds: 150 },
+            HarnessAction::Cancel,
+            HarnessAction::Register { funds: 100 },
+            HarnessAction::Approve,
+            HarnessAction::Pay {
+                amount: 1200,
+                refund_overpayment: true,
+            },
+        ] {
+            harness
+                .apply(&action)
+                .unwrap_or_else(|e| panic!("{:?} should succeed, but got: {:?}", action, e));
+            harness.assert_invariants();
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_invariants_hold_over_execute_sequences(
+            actions in prop::collection::vec(action_strategy(), 1..20),
+        ) {
+            let mut harness = InvariantHarness::new();
+            for action in &actions {
+                // Rejected executions are expected, as the sequences are not filtered for validity
+                let _ = harness.apply(action);
+                harness.assert_invariants();
+            }
+        }
+    }
+}
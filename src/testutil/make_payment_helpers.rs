@@ -5,11 +5,12 @@ use crate::testutil::test_utilities::{
     MockOwnedDeps, DEFAULT_CONTRACT_NAME, DEFAULT_INFO_NAME, DEFAULT_PAYABLE_DENOM,
     DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_UUID,
 };
-use cosmwasm_std::testing::mock_info;
-use cosmwasm_std::{coin, MessageInfo, Response};
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{coin, Env, MessageInfo, Response};
 use provwasm_std::ProvenanceMsg;
 
 pub struct TestMakePayment {
+    pub env: Env,
     pub info: MessageInfo,
     pub contract_name: String,
     pub make_payment: MakePaymentV1,
@@ -18,6 +19,9 @@ impl TestMakePayment {
     pub fn default_make_payment() -> MakePaymentV1 {
         MakePaymentV1 {
             payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            refund_overpayment: false,
+            memo: None,
+            on_behalf_of: None,
         }
     }
     pub fn default_full_sender(sender: &str, amount: u128, denom: &str) -> Self {
@@ -42,6 +46,7 @@ impl TestMakePayment {
 impl Default for TestMakePayment {
     fn default() -> Self {
         TestMakePayment {
+            env: mock_env(),
             info: mock_info(
                 DEFAULT_INFO_NAME,
                 &[coin(DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_DENOM)],
@@ -57,8 +62,13 @@ pub fn test_make_payment(
     provenance_util: &MockProvenanceUtil,
     msg: TestMakePayment,
 ) -> Result<Response<ProvenanceMsg>, ContractError> {
-    let response =
-        make_payment_with_util(deps.as_mut(), provenance_util, msg.info, msg.make_payment);
+    let response = make_payment_with_util(
+        deps.as_mut(),
+        provenance_util,
+        msg.env,
+        msg.info,
+        msg.make_payment,
+    );
     provenance_util.bind_captured_attribute_named(deps, msg.contract_name);
     response
 }
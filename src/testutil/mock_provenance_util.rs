@@ -5,7 +5,7 @@ use crate::testutil::test_utilities::{
 };
 use crate::util::provenance_util::{ProvenanceUtil, ProvenanceUtilImpl, WriteAttributeMessages};
 use cosmwasm_std::{CosmosMsg, Deps, QuerierWrapper, StdResult};
-use provwasm_std::{ProvenanceMsg, ProvenanceQuery, Scope};
+use provwasm_std::{Marker, ProvenanceMsg, ProvenanceQuery, Scope};
 use std::cell::RefCell;
 
 pub struct MockProvenanceUtil {
@@ -31,6 +31,14 @@ impl ProvenanceUtil for MockProvenanceUtil {
         ProvenanceUtilImpl.get_scope_by_id(querier, scope_id)
     }
 
+    fn get_marker_by_denom(
+        &self,
+        querier: &QuerierWrapper<ProvenanceQuery>,
+        denom: impl Into<String>,
+    ) -> StdResult<Marker> {
+        ProvenanceUtilImpl.get_marker_by_denom(querier, denom)
+    }
+
     fn get_add_initial_attribute_to_scope_msg(
         &self,
         deps: &Deps<ProvenanceQuery>,
@@ -43,14 +51,21 @@ impl ProvenanceUtil for MockProvenanceUtil {
 
     fn upsert_attribute_to_scope(
         &self,
+        deps: &Deps<ProvenanceQuery>,
         attribute: &PayableScopeAttribute,
         contract_name: impl Into<String>,
     ) -> Result<WriteAttributeMessages, ContractError> {
+        let messages =
+            ProvenanceUtilImpl.upsert_attribute_to_scope(deps, attribute, contract_name)?;
         self.add_attribute(attribute.clone());
-        ProvenanceUtilImpl.upsert_attribute_to_scope(attribute, contract_name)
+        Ok(messages)
     }
 }
 impl MockProvenanceUtil {
+    pub fn captured_attribute_count(&self) -> usize {
+        self.captured_attributes.borrow().len()
+    }
+
     pub fn bind_captured_attribute(&self, deps: &mut MockOwnedDeps) {
         if let Some(attr) = self.captured_attributes.borrow().last() {
             mock_default_scope_attribute(deps, attr);
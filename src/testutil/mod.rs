@@ -1,4 +1,6 @@
 #[cfg(feature = "enable-test-utils")]
+pub mod invariant_harness;
+#[cfg(feature = "enable-test-utils")]
 pub mod make_payment_helpers;
 #[cfg(feature = "enable-test-utils")]
 pub mod mock_provenance_util;
@@ -4,11 +4,12 @@ use crate::testutil::mock_provenance_util::MockProvenanceUtil;
 use crate::testutil::test_utilities::{
     MockOwnedDeps, DEFAULT_CONTRACT_NAME, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_UUID,
 };
-use cosmwasm_std::testing::mock_info;
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{Env, MessageInfo, Response};
 use provwasm_std::ProvenanceMsg;
 
 pub struct TestOracleApproval {
+    pub env: Env,
     pub info: MessageInfo,
     pub contract_name: String,
     pub oracle_approval: OracleApprovalV1,
@@ -17,12 +18,16 @@ impl TestOracleApproval {
     pub fn default_oracle_approval() -> OracleApprovalV1 {
         OracleApprovalV1 {
             payable_uuid: DEFAULT_PAYABLE_UUID.to_string(),
+            external_reference_id: None,
+            risk_score: None,
+            approval_valid_for: None,
         }
     }
 }
 impl Default for TestOracleApproval {
     fn default() -> Self {
         TestOracleApproval {
+            env: mock_env(),
             // Bind the default oracle address as the sender - it should match the oracle address
             // that was bound to the scope attribute
             info: mock_info(DEFAULT_ORACLE_ADDRESS, &[]),
@@ -40,6 +45,7 @@ pub fn test_oracle_approval(
     let response = oracle_approval_with_util(
         deps.as_mut(),
         provenance_util,
+        msg.env,
         msg.info,
         msg.oracle_approval,
     );
@@ -6,11 +6,12 @@ use crate::testutil::test_utilities::{
     DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_DENOM, DEFAULT_PAYABLE_TOTAL, DEFAULT_PAYABLE_TYPE,
     DEFAULT_PAYABLE_UUID, DEFAULT_SCOPE_ID,
 };
-use cosmwasm_std::testing::mock_info;
-use cosmwasm_std::{coin, MessageInfo, Response, Uint128};
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{coin, Env, MessageInfo, Response, Uint128};
 use provwasm_std::ProvenanceMsg;
 
 pub struct TestRegisterPayable {
+    pub env: Env,
     pub info: MessageInfo,
     pub contract_name: String,
     pub register_payable: RegisterPayableV2,
@@ -24,11 +25,26 @@ impl TestRegisterPayable {
             oracle_address: DEFAULT_ORACLE_ADDRESS.to_string(),
             payable_denom: DEFAULT_PAYABLE_DENOM.to_string(),
             payable_total: Uint128::new(DEFAULT_PAYABLE_TOTAL),
+            custom_fields: None,
+            priority_fee: None,
+            oracle_gas_rebate: None,
+            minimum_payment_amount: None,
+            expiration_time: None,
+            accepted_denoms: None,
+            payment_schedule: None,
+            late_fee: None,
+            escrow_timeout_seconds: None,
+            authorized_payers: None,
+            interest: None,
+            payee_splits: None,
+            registration_nonce: None,
+            referrer: None,
         }
     }
 
     pub fn default_full_sender(sender: &str, amount: u128, denom: &str) -> Self {
         TestRegisterPayable {
+            env: mock_env(),
             info: mock_info(sender, &[coin(amount, denom)]),
             ..Default::default()
         }
@@ -53,6 +69,7 @@ impl TestRegisterPayable {
 impl Default for TestRegisterPayable {
     fn default() -> Self {
         TestRegisterPayable {
+            env: mock_env(),
             info: mock_info(
                 DEFAULT_INFO_NAME,
                 &[coin(100, DEFAULT_ONBOARDING_DENOM.to_string())],
@@ -71,6 +88,7 @@ pub fn test_register_payable(
     let response = register_payable_with_util(
         deps.as_mut(),
         provenance_util,
+        msg.env,
         msg.info,
         msg.register_payable,
     );
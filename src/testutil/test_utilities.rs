@@ -1,10 +1,20 @@
 use crate::contract::instantiate;
 use crate::core::error::ContractError;
 use crate::core::msg::{ExecuteMsg, InitMsg};
-use crate::core::state::PayableScopeAttribute;
+use crate::core::state::{
+    add_supported_payable_type, append_payment_record, payable_meta_storage_v2,
+    record_payable_snapshot, update_payable_timeline, FundsToleranceV1, PayableMetaV2,
+    PayableScopeAttribute, PaymentBatchWindowV1, PaymentRecordV1,
+};
+use crate::execute::register_payable::RegisterPayableV2;
 use crate::testutil::mock_provenance_util::MockProvenanceUtil;
+use crate::testutil::register_payable_helpers::TestRegisterPayable;
+use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+use crate::validation::{bech32_polymod_step, BECH32_CHARSET, SCOPE_BECH32_PREFIX};
 use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage};
-use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, OwnedDeps, Response, Uint128};
+use cosmwasm_std::{
+    Addr, Decimal, DepsMut, Env, MessageInfo, OwnedDeps, Response, Timestamp, Uint128,
+};
 use provwasm_mocks::ProvenanceMockQuerier;
 use provwasm_std::{Party, PartyType, ProvenanceMsg, ProvenanceQuery, Scope};
 use serde_json_wasm::to_string;
@@ -33,6 +43,17 @@ pub struct InstArgs {
     pub fee_collection_address: String,
     pub fee_percent: Decimal,
     pub is_local: bool,
+    pub payment_batch_window: Option<PaymentBatchWindowV1>,
+    pub migration_timelock_seconds: Option<u64>,
+    pub oracle_funds_tolerance: Option<FundsToleranceV1>,
+    pub minimum_payment_amount: Option<Uint128>,
+    pub settled_retention_seconds: Option<u64>,
+    pub approval_valid_duration: Option<u64>,
+    pub oracle_approval_sla_seconds: Option<u64>,
+    pub large_payment_threshold: Option<Uint128>,
+    pub payment_reversal_window_seconds: Option<u64>,
+    pub provenance_msg_version: Option<ProvenanceMsgVersion>,
+    pub authorized_registrars: Option<Vec<String>>,
 }
 impl Default for InstArgs {
     fn default() -> Self {
@@ -45,6 +66,17 @@ impl Default for InstArgs {
             fee_collection_address: DEFAULT_FEE_COLLECTION_ADDRESS.into(),
             fee_percent: Decimal::percent(DEFAULT_FEE_PERCENT),
             is_local: false,
+            payment_batch_window: None,
+            migration_timelock_seconds: None,
+            oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            settled_retention_seconds: None,
+            approval_valid_duration: None,
+            oracle_approval_sla_seconds: None,
+            large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            provenance_msg_version: None,
+            authorized_registrars: None,
         }
     }
 }
@@ -64,12 +96,25 @@ pub fn test_instantiate(
             fee_collection_address: args.fee_collection_address,
             fee_percent: args.fee_percent,
             is_local: Some(args.is_local),
+            payment_batch_window: args.payment_batch_window,
+            migration_timelock_seconds: args.migration_timelock_seconds,
+            oracle_funds_tolerance: args.oracle_funds_tolerance,
+            minimum_payment_amount: args.minimum_payment_amount,
+            settled_retention_seconds: args.settled_retention_seconds,
+            approval_valid_duration: args.approval_valid_duration,
+            oracle_approval_sla_seconds: args.oracle_approval_sla_seconds,
+            large_payment_threshold: args.large_payment_threshold,
+            payment_reversal_window_seconds: args.payment_reversal_window_seconds,
+            provenance_msg_version: args.provenance_msg_version,
+            authorized_registrars: args.authorized_registrars,
         },
     )
 }
 
 pub fn setup_test_suite(deps: &mut MockOwnedDeps, args: InstArgs) -> MockProvenanceUtil {
     test_instantiate(deps.as_mut(), args).expect("instantiation should succeed");
+    add_supported_payable_type(deps.as_mut().storage, DEFAULT_PAYABLE_TYPE)
+        .expect("the default payable type should be supported");
     mock_default_scope(deps);
     MockProvenanceUtil::new()
 }
@@ -82,6 +127,20 @@ pub fn default_register_payable() -> ExecuteMsg {
         oracle_address: DEFAULT_ORACLE_ADDRESS.into(),
         payable_denom: DEFAULT_PAYABLE_DENOM.into(),
         payable_total: Uint128::new(DEFAULT_PAYABLE_TOTAL),
+        custom_fields: None,
+        priority_fee: None,
+        oracle_gas_rebate: None,
+        minimum_payment_amount: None,
+        expiration_time: None,
+        accepted_denoms: None,
+        payment_schedule: None,
+        late_fee: None,
+        escrow_timeout_seconds: None,
+        authorized_payers: None,
+        interest: None,
+        payee_splits: None,
+        registration_nonce: None,
+        referrer: None,
     }
 }
 
@@ -140,3 +199,426 @@ pub fn single_attribute_for_key<'a, T>(response: &'a Response<T>, key: &'a str)
         .value
         .as_str()
 }
+
+/// Describes a generated dataset of payables.  The same arguments always produce the same dataset.
+pub struct DatasetArgs {
+    pub seed: u64,
+    pub payable_count: usize,
+    pub payable_types: Vec<String>,
+    pub oracle_addresses: Vec<String>,
+    pub payer_addresses: Vec<String>,
+    pub max_payments_per_payable: u64,
+}
+impl Default for DatasetArgs {
+    fn default() -> Self {
+        DatasetArgs {
+            seed: 42,
+            payable_count: 100,
+            payable_types: vec![DEFAULT_PAYABLE_TYPE.to_string(), "loan".to_string()],
+            oracle_addresses: vec![DEFAULT_ORACLE_ADDRESS.to_string(), "oracle-two".to_string()],
+            payer_addresses: vec![DEFAULT_INFO_NAME.to_string(), "payer-two".to_string()],
+            max_payments_per_payable: 5,
+        }
+    }
+}
+
+/// The point in its lifecycle that a generated payable was left at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeneratedPayableStatus {
+    AwaitingApproval,
+    AwaitingPayment,
+    PartiallyPaid,
+    PaidOff,
+    WrittenOff,
+    Declined,
+}
+impl GeneratedPayableStatus {
+    const ALL: [GeneratedPayableStatus; 6] = [
+        GeneratedPayableStatus::AwaitingApproval,
+        GeneratedPayableStatus::AwaitingPayment,
+        GeneratedPayableStatus::PartiallyPaid,
+        GeneratedPayableStatus::PaidOff,
+        GeneratedPayableStatus::WrittenOff,
+        GeneratedPayableStatus::Declined,
+    ];
+}
+
+/// A payable written to storage by generate_payable_dataset, with the attribute that its scope
+/// would hold and every payment recorded against it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedPayable {
+    pub attribute: PayableScopeAttribute,
+    pub status: GeneratedPayableStatus,
+    pub payments: Vec<PaymentRecordV1>,
+}
+
+/// Writes a deterministic dataset of payables directly into the contract's storage, bypassing
+/// execution.  Each payable is given a type, oracle, status and payment pattern derived from the
+/// seed, along with its meta, timeline, snapshot and payment history.  The mock querier can only
+/// hold a single scope and attribute at a time, so the attributes are returned rather than mocked.
+/// Use bind_generated_payable to make one of them visible to queries that read the scope.
+pub fn generate_payable_dataset(
+    deps: &mut MockOwnedDeps,
+    args: DatasetArgs,
+) -> Vec<GeneratedPayable> {
+    let mut rng = DatasetRng::new(args.seed);
+    let block = mock_env().block;
+    for payable_type in &args.payable_types {
+        add_supported_payable_type(deps.as_mut().storage, payable_type)
+            .expect("generated payable types should be supported");
+    }
+    (0..args.payable_count)
+        .map(|index| {
+            let registered_at = block.time.minus_seconds(rng.below(365 * 86400) + 86400);
+            let mut attribute = RegisterPayableV2 {
+                payable_type: rng.pick(&args.payable_types).clone(),
+                payable_uuid: rng.uuid(index as u64),
+                scope_id: rng.scope_id(index as u64),
+                oracle_address: rng.pick(&args.oracle_addresses).clone(),
+                payable_total: Uint128::new((rng.below(1000) as u128 + 1) * 100),
+                ..TestRegisterPayable::default_register_payable()
+            }
+            .to_scope_attribute();
+            let status = *rng.pick(&GeneratedPayableStatus::ALL);
+            let payment_count = match status {
+                GeneratedPayableStatus::PartiallyPaid | GeneratedPayableStatus::PaidOff => {
+                    rng.below(args.max_payments_per_payable.max(1)) + 1
+                }
+                _ => 0,
+            };
+            // Partially paid payables always leave something owed, so they never pay the full total
+            let amount_paid = match status {
+                GeneratedPayableStatus::PartiallyPaid => {
+                    rng.below(attribute.payable_total_owed.u128() as u64 - payment_count)
+                        + payment_count
+                }
+                GeneratedPayableStatus::PaidOff => attribute.payable_total_owed.u128() as u64,
+                _ => 0,
+            };
+            let payments = (0..payment_count)
+                .map(|payment_index| {
+                    let amount = if payment_index + 1 == payment_count {
+                        amount_paid - amount_paid / payment_count * payment_index
+                    } else {
+                        amount_paid / payment_count
+                    };
+                    PaymentRecordV1 {
+                        payer: Addr::unchecked(rng.pick(&args.payer_addresses)),
+                        amount: Uint128::new(amount as u128),
+                        denom: attribute.payable_denom.clone(),
+                        paid_at: registered_at.plus_seconds((payment_index + 2) * 3600),
+                        on_behalf_of: None,
+                        external_reference: None,
+                        payee_splits: None,
+                        late_fees_paid: Uint128::zero(),
+                        interest_paid: Uint128::zero(),
+                        netting: None,
+                    }
+                })
+                .collect::<Vec<PaymentRecordV1>>();
+            attribute.oracle_approved = status != GeneratedPayableStatus::AwaitingApproval;
+            attribute.payable_remaining_owed -= Uint128::new(amount_paid as u128);
+            match status {
+                GeneratedPayableStatus::WrittenOff => {
+                    attribute.is_written_off = true;
+                    attribute.write_off_reason = Some("generated write off".to_string());
+                    attribute.payable_remaining_owed = Uint128::zero();
+                }
+                GeneratedPayableStatus::Declined => attribute.is_declined = true,
+                _ => {}
+            }
+            write_generated_payable(deps, &attribute, &payments, registered_at);
+            GeneratedPayable {
+                attribute,
+                status,
+                payments,
+            }
+        })
+        .collect()
+}
+
+/// Mocks the scope and attribute of a generated payable, making it visible to queries and
+/// executions that read from the scope.  Replaces any previously mocked scope and attribute.
+pub fn bind_generated_payable(deps: &mut MockOwnedDeps, payable: &GeneratedPayable) {
+    mock_scope(deps, &payable.attribute.scope_id, DEFAULT_INFO_NAME);
+    mock_default_scope_attribute(deps, &payable.attribute);
+}
+
+fn write_generated_payable(
+    deps: &mut MockOwnedDeps,
+    attribute: &PayableScopeAttribute,
+    payments: &[PaymentRecordV1],
+    registered_at: Timestamp,
+) {
+    let storage = deps.as_mut().storage;
+    payable_meta_storage_v2(storage)
+        .save(
+            attribute.payable_uuid.as_bytes(),
+            &PayableMetaV2 {
+                payable_uuid: attribute.payable_uuid.clone(),
+                scope_id: attribute.scope_id.clone(),
+            },
+        )
+        .expect("generated payable meta should save");
+    for payment in payments {
+        append_payment_record(storage, &attribute.payable_uuid, payment)
+            .expect("generated payments should save");
+    }
+    let approved_at = registered_at.plus_seconds(3600);
+    update_payable_timeline(storage, &attribute.payable_uuid, |timeline| {
+        timeline.registered_at = Some(registered_at);
+        if attribute.oracle_approved {
+            timeline.approved_at = Some(approved_at);
+        }
+        timeline.payment_times = payments.iter().map(|payment| payment.paid_at).collect();
+        if attribute.payable_remaining_owed.is_zero() && !attribute.is_written_off {
+            timeline.completed_at = payments.last().map(|payment| payment.paid_at);
+        }
+    })
+    .expect("generated timelines should save");
+    record_payable_snapshot(storage, attribute, &mock_env().block)
+        .expect("generated snapshots should save");
+}
+
+/// A small splitmix64 generator.  Test datasets must be reproducible from their seed alone, so no
+/// external source of randomness is used.
+struct DatasetRng(u64);
+impl DatasetRng {
+    fn new(seed: u64) -> Self {
+        DatasetRng(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = self.0;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn pick<'a, T>(&mut self, values: &'a [T]) -> &'a T {
+        &values[self.below(values.len() as u64) as usize]
+    }
+
+    /// A version 4 style uuid, made unique by embedding the index in its final group.
+    fn uuid(&mut self, index: u64) -> String {
+        let random = self.next();
+        format!(
+            "{:08x}-{:04x}-4{:03x}-8{:03x}-{:012x}",
+            random >> 32,
+            (random >> 16) & 0xffff,
+            random & 0xfff,
+            (random >> 52) & 0xfff,
+            index,
+        )
+    }
+
+    /// A valid bech32 scope address, made unique by embedding the index in its data.
+    fn scope_id(&mut self, index: u64) -> String {
+        let random = self.next();
+        let data = (0..13)
+            .map(|group| ((index >> (60 - group * 5)) & 31) as u8)
+            .chain((0..13).map(|group| ((random >> (60 - group * 5)) & 31) as u8))
+            .collect::<Vec<u8>>();
+        bech32_encode(SCOPE_BECH32_PREFIX, &data)
+    }
+}
+
+fn bech32_encode(prefix: &str, data: &[u8]) -> String {
+    let mut checksum: u32 = 1;
+    for byte in prefix.bytes() {
+        checksum = bech32_polymod_step(checksum, byte >> 5);
+    }
+    checksum = bech32_polymod_step(checksum, 0);
+    for byte in prefix.bytes() {
+        checksum = bech32_polymod_step(checksum, byte & 31);
+    }
+    for value in data.iter().chain([0u8; 6].iter()) {
+        checksum = bech32_polymod_step(checksum, *value);
+    }
+    checksum ^= 1;
+    let checksum_values = (0..6).map(|index| ((checksum >> (5 * (5 - index))) & 31) as u8);
+    let encoded = data
+        .iter()
+        .copied()
+        .chain(checksum_values)
+        .map(|value| BECH32_CHARSET[value as usize] as char)
+        .collect::<String>();
+    format!("{}1{}", prefix, encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::core::msg::{
+        ExportPaymentsResponse, MetricsSnapshotResponse, PayableQueryResponse, QueryMsg,
+    };
+    use crate::testutil::test_utilities::{
+        bind_generated_payable, generate_payable_dataset, setup_test_suite, DatasetArgs,
+        GeneratedPayableStatus, InstArgs,
+    };
+    use crate::validation::{is_valid_bech32, is_valid_uuid, SCOPE_BECH32_PREFIX};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_generate_payable_dataset_is_deterministic() {
+        let mut first_deps = mock_dependencies(&[]);
+        setup_test_suite(&mut first_deps, InstArgs::default());
+        let first = generate_payable_dataset(&mut first_deps, DatasetArgs::default());
+        let mut second_deps = mock_dependencies(&[]);
+        setup_test_suite(&mut second_deps, InstArgs::default());
+        let second = generate_payable_dataset(&mut second_deps, DatasetArgs::default());
+        assert_eq!(
+            first, second,
+            "the same seed should generate the same dataset"
+        );
+        let mut other_deps = mock_dependencies(&[]);
+        setup_test_suite(&mut other_deps, InstArgs::default());
+        let other = generate_payable_dataset(
+            &mut other_deps,
+            DatasetArgs {
+                seed: 7,
+                ..DatasetArgs::default()
+            },
+        );
+        assert_ne!(
+            first, other,
+            "a different seed should generate a different dataset"
+        );
+    }
+
+    #[test]
+    fn test_generate_payable_dataset_produces_valid_payables() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let dataset = generate_payable_dataset(
+            &mut deps,
+            DatasetArgs {
+                payable_count: 2000,
+                ..DatasetArgs::default()
+            },
+        );
+        assert_eq!(2000, dataset.len());
+        assert_eq!(
+            2000,
+            dataset
+                .iter()
+                .map(|payable| &payable.attribute.payable_uuid)
+                .collect::<BTreeSet<&String>>()
+                .len(),
+            "every generated payable should have a unique uuid",
+        );
+        for payable in &dataset {
+            let attribute = &payable.attribute;
+            assert!(is_valid_uuid(&attribute.payable_uuid));
+            assert!(is_valid_bech32(
+                &attribute.scope_id,
+                Some(SCOPE_BECH32_PREFIX)
+            ));
+            let amount_paid = payable
+                .payments
+                .iter()
+                .map(|payment| payment.amount)
+                .sum::<Uint128>();
+            if !attribute.is_written_off {
+                assert_eq!(
+                    attribute.payable_total_owed - amount_paid,
+                    attribute.payable_remaining_owed,
+                    "the payments should account for the amount no longer owed",
+                );
+            }
+        }
+        for status in [
+            GeneratedPayableStatus::AwaitingApproval,
+            GeneratedPayableStatus::PartiallyPaid,
+            GeneratedPayableStatus::PaidOff,
+            GeneratedPayableStatus::Declined,
+        ] {
+            assert!(
+                dataset.iter().any(|payable| payable.status == status),
+                "a large dataset should include payables that are {:?}",
+                status,
+            );
+        }
+        let paid_off = dataset
+            .iter()
+            .filter(|payable| payable.status == GeneratedPayableStatus::PaidOff)
+            .count();
+        let metrics: MetricsSnapshotResponse = from_binary(
+            &query(deps.as_ref(), mock_env(), QueryMsg::QueryMetricsSnapshot {}).unwrap(),
+        )
+        .unwrap();
+        let metric = |name: &str| {
+            metrics
+                .metrics
+                .iter()
+                .find(|metric| metric.name == name)
+                .unwrap()
+                .value
+                .u128() as usize
+        };
+        assert_eq!(2000, metric("payables_registered"));
+        assert_eq!(paid_off, metric("payables_paid_off"));
+    }
+
+    #[test]
+    fn test_generated_payables_can_be_paged_and_queried() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let dataset = generate_payable_dataset(&mut deps, DatasetArgs::default());
+        let mut exported = 0;
+        let mut start_after = None;
+        loop {
+            let page: ExportPaymentsResponse = from_binary(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ExportPayments {
+                        start_after,
+                        limit: Some(50),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            exported += page.payments.len();
+            start_after = page.next_start_after;
+            if start_after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(
+            dataset
+                .iter()
+                .map(|payable| payable.payments.len())
+                .sum::<usize>(),
+            exported,
+            "paging through the exported payments should return every generated payment",
+        );
+        let payable = dataset.last().unwrap();
+        bind_generated_payable(&mut deps, payable);
+        let queried: PayableQueryResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::QueryPayableByUuid {
+                    payable_uuid: payable.attribute.payable_uuid.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(payable.attribute.scope_id, queried.scope_id);
+        assert_eq!(
+            payable.attribute.payable_remaining_owed,
+            queried.payable_remaining_owed,
+        );
+    }
+}
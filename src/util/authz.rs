@@ -0,0 +1,122 @@
+// Hand-rolled protobuf encoding for the few Cosmos SDK messages that the contract sends as
+// stargate messages.  Only the fields that the contract populates are encoded, which keeps a
+// protobuf dependency out of the wasm binary.
+use crate::core::money::Money;
+use cosmwasm_std::{Addr, Binary, CosmosMsg};
+use provwasm_std::ProvenanceMsg;
+
+/// The type url of the authz module's message for executing messages under a granter's grant.
+pub const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+/// The type url of the bank module's send message.
+pub const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+/// Creates a message that, when sent by the grantee, moves funds out of the granter's account using
+/// the send authorization that the granter gave the grantee through the authz module.  The message
+/// fails, reverting the entire transaction, if the grant is missing or does not cover the amount.
+pub fn authz_send_msg(
+    grantee: &Addr,
+    granter: &Addr,
+    to_address: &Addr,
+    amount: &Money,
+) -> CosmosMsg<ProvenanceMsg> {
+    let mut msg_send = vec![];
+    encode_string(&mut msg_send, 1, granter.as_str());
+    encode_string(&mut msg_send, 2, to_address.as_str());
+    let mut coin = vec![];
+    encode_string(&mut coin, 1, &amount.denom);
+    encode_string(&mut coin, 2, &amount.amount.to_string());
+    encode_bytes(&mut msg_send, 3, &coin);
+    let mut any = vec![];
+    encode_string(&mut any, 1, MSG_SEND_TYPE_URL);
+    encode_bytes(&mut any, 2, &msg_send);
+    let mut msg_exec = vec![];
+    encode_string(&mut msg_exec, 1, grantee.as_str());
+    encode_bytes(&mut msg_exec, 2, &any);
+    CosmosMsg::Stargate {
+        type_url: MSG_EXEC_TYPE_URL.to_string(),
+        value: Binary::from(msg_exec),
+    }
+}
+
+fn encode_string(buf: &mut Vec<u8>, field_number: u64, value: &str) {
+    encode_bytes(buf, field_number, value.as_bytes());
+}
+
+/// Appends a length-delimited field, the wire type of every field that the contract encodes.
+fn encode_bytes(buf: &mut Vec<u8>, field_number: u64, value: &[u8]) {
+    encode_varint(buf, field_number << 3 | 2);
+    encode_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+fn encode_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::money::Money;
+    use crate::util::authz::{
+        authz_send_msg, encode_bytes, encode_varint, MSG_EXEC_TYPE_URL, MSG_SEND_TYPE_URL,
+    };
+    use cosmwasm_std::{Addr, CosmosMsg};
+
+    #[test]
+    fn test_encode_varint() {
+        let encode = |value: u64| {
+            let mut buf = vec![];
+            encode_varint(&mut buf, value);
+            buf
+        };
+        assert_eq!(vec![0x00], encode(0));
+        assert_eq!(vec![0x7f], encode(127));
+        assert_eq!(vec![0x80, 0x01], encode(128));
+        assert_eq!(vec![0xac, 0x02], encode(300));
+    }
+
+    #[test]
+    fn test_encode_bytes_uses_multi_byte_lengths() {
+        let mut buf = vec![];
+        encode_bytes(&mut buf, 2, &[7; 200]);
+        assert_eq!(vec![0x12, 0xc8, 0x01], buf[..3].to_vec());
+        assert_eq!(203, buf.len());
+    }
+
+    #[test]
+    fn test_authz_send_msg_layout() {
+        let msg = authz_send_msg(
+            &Addr::unchecked("ct"),
+            &Addr::unchecked("py"),
+            &Addr::unchecked("ct"),
+            &Money::new(5u128, "nhash"),
+        );
+        let (type_url, value) = match msg {
+            CosmosMsg::Stargate { type_url, value } => (type_url, value),
+            other => panic!("expected a stargate message, but got: {:?}", other),
+        };
+        assert_eq!(MSG_EXEC_TYPE_URL, type_url);
+        let coin = [&[0x0a, 5][..], b"nhash", &[0x12, 1], b"5"].concat();
+        let msg_send = [
+            &[0x0a, 2][..],
+            b"py",
+            &[0x12, 2],
+            b"ct",
+            &[0x1a, coin.len() as u8],
+            &coin,
+        ]
+        .concat();
+        let any = [
+            &[0x0a, MSG_SEND_TYPE_URL.len() as u8][..],
+            MSG_SEND_TYPE_URL.as_bytes(),
+            &[0x12, msg_send.len() as u8],
+            &msg_send,
+        ]
+        .concat();
+        let msg_exec = [&[0x0a, 2][..], b"ct", &[0x12, any.len() as u8], &any].concat();
+        assert_eq!(msg_exec, value.to_vec());
+    }
+}
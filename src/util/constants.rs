@@ -13,10 +13,44 @@ pub const SCOPE_ID_KEY: &str = "payable_related_scope_id";
 pub const TOTAL_OWED_KEY: &str = "payable_total_owed";
 /// Value = Payable denom input value (String)
 pub const REGISTERED_DENOM_KEY: &str = "payable_denom";
+/// Value = Additional denoms accepted for payment, only emitted when provided (comma-separated String)
+pub const ACCEPTED_DENOMS_KEY: &str = "payable_accepted_denoms";
 /// Value = Amount of funds kept in the contract address to redistribute to the oracle later (u128 + denom: ex "420/nhash")
 pub const ORACLE_FUNDS_KEPT: &str = "payable_oracle_funds_kept";
 /// Value = Amount of overage funds refunded to the sender (u128 + denom: ex "100/nhash")
 pub const REFUND_AMOUNT_KEY: &str = "payable_refund_amount";
+/// Value = Priority fee held in the contract address to pay the oracle on approval (u128 + denom: ex "50/nhash")
+pub const PRIORITY_FEE_KEY: &str = "payable_priority_fee";
+/// Value = Oracle gas rebate held in the contract address to pay the oracle on approval (u128 + denom: ex "10/nhash")
+pub const ORACLE_GAS_REBATE_KEY: &str = "payable_oracle_gas_rebate";
+/// Value = Portion of the onboarding cost sent to the fee collector, in basis points (u128)
+pub const EFFECTIVE_FEE_BPS_KEY: &str = "payable_effective_fee_bps";
+/// Value = Onboarding cost charged for the registration (u128 + denom: ex "100/nhash")
+pub const ONBOARDING_COST_CHARGED_KEY: &str = "payable_onboarding_cost_charged";
+/// Value = Time after which the payable expires, only emitted when provided (u64 seconds since epoch)
+pub const EXPIRATION_TIME_KEY: &str = "payable_expiration_time";
+/// Value = Id of the fee holiday that reduced the onboarding cost, only emitted when one applied (String)
+pub const FEE_HOLIDAY_KEY: &str = "payable_fee_holiday";
+/// Value = Number of installments in the payment schedule, only emitted when provided (u64)
+pub const INSTALLMENT_COUNT_KEY: &str = "payable_installment_count";
+/// Value = Daily late fee rate charged once the payable is overdue, only emitted when provided (Decimal)
+pub const LATE_FEE_RATE_KEY: &str = "payable_late_fee_rate";
+/// Value = Annual simple interest rate charged on the remaining principal, only emitted when provided (Decimal)
+pub const INTEREST_RATE_KEY: &str = "payable_interest_rate";
+/// Value = Seconds after which an unreleased escrowed payment may be reclaimed by its payer, only emitted when provided (u64)
+pub const ESCROW_TIMEOUT_KEY: &str = "payable_escrow_timeout";
+/// Value = Comma-separated addresses that may make payments against the payable, only emitted when provided (String)
+pub const AUTHORIZED_PAYERS_KEY: &str = "payable_authorized_payers";
+/// Value = Comma-separated address:weight pairs of the payees that divide the payable's payments, only emitted when provided (String)
+pub const PAYEE_SPLITS_KEY: &str = "payable_payee_splits";
+/// Value = Payable UUID, only emitted when a declined or expired registration was replaced (String)
+pub const PAYABLE_RE_REGISTERED_KEY: &str = "payable_re_registered";
+/// Value = Payable UUID, only emitted when a retry of an earlier registration was acknowledged without registering again (String)
+pub const REGISTRATION_RETRIED_KEY: &str = "payable_registration_retried";
+/// Value = Allowlisted referrer of a registration, only emitted when provided (String)
+pub const REFERRER_KEY: &str = "payable_referrer";
+/// Value = Portion of the collected fee sent to the referrer emitted before it (u128 + denom: ex "10/nhash")
+pub const REFERRAL_SHARE_KEY: &str = "payable_referral_share";
 
 ///////////////////////////////////////
 // Oracle approved output attributes //
@@ -24,6 +58,115 @@ pub const REFUND_AMOUNT_KEY: &str = "payable_refund_amount";
 
 /// Value = Payable UUID (String)
 pub const ORACLE_APPROVED_KEY: &str = "payable_oracle_approved";
+/// Value = External reference id provided by the oracle, only emitted when provided (String)
+pub const ORACLE_EXTERNAL_REFERENCE_KEY: &str = "payable_oracle_external_reference_id";
+/// Value = Risk score assigned to the payable by the oracle, only emitted when provided (u32)
+pub const ORACLE_RISK_SCORE_KEY: &str = "payable_oracle_risk_score";
+/// Value = Bech32 address of the oracle delegate that submitted the approval, only emitted for delegated approvals (String)
+pub const ORACLE_DELEGATE_KEY: &str = "payable_oracle_delegate";
+/// Value = Time at which the approval lapses, only emitted when the contract has an approval valid duration (u64 seconds since epoch)
+pub const APPROVAL_LAPSES_AT_KEY: &str = "payable_oracle_approval_lapses_at";
+/// Value = Time at which the approval goes stale if no payment has been made, only emitted when the oracle provided a validity window (u64 seconds since epoch)
+pub const APPROVAL_VALID_UNTIL_KEY: &str = "payable_oracle_approval_valid_until";
+/// Value = Payable UUID (String)
+pub const ORACLE_APPROVAL_RENEWED_KEY: &str = "payable_oracle_approval_renewed";
+
+///////////////////////////////////////
+// Oracle delegate output attributes //
+///////////////////////////////////////
+
+/// Value = Bech32 address of the delegate granted approval authority (String)
+pub const ORACLE_DELEGATE_ADDED_KEY: &str = "payable_oracle_delegate_added";
+/// Value = Bech32 address of the delegate whose approval authority was revoked (String)
+pub const ORACLE_DELEGATE_REMOVED_KEY: &str = "payable_oracle_delegate_removed";
+
+/////////////////////////////////////////////
+// Payable total updated output attributes //
+/////////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_TOTAL_UPDATED_KEY: &str = "payable_total_updated";
+/// Value = Payable total owed before the update (u128)
+pub const PREVIOUS_TOTAL_OWED_KEY: &str = "payable_previous_total_owed";
+
+//////////////////////////////////////
+// Oracle changed output attributes //
+//////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const ORACLE_CHANGED_KEY: &str = "payable_oracle_changed";
+/// Value = Bech32 address of the oracle assigned to the payable before the change (String)
+pub const PREVIOUS_ORACLE_ADDRESS_KEY: &str = "payable_previous_oracle_address";
+
+///////////////////////////////////////////
+// Payable restructure output attributes //
+///////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const RESTRUCTURE_CONFIRMED_KEY: &str = "payable_restructure_confirmed";
+/// Value = Bech32 address of the payee or oracle that confirmed the restructure terms (String)
+pub const RESTRUCTURE_CONFIRMED_BY_KEY: &str = "payable_restructure_confirmed_by";
+/// Value = Payable UUID, only emitted once both parties have confirmed and the terms are applied (String)
+pub const PAYABLE_RESTRUCTURED_KEY: &str = "payable_restructured";
+
+///////////////////////////////////////////
+// Payable transferred output attributes //
+///////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_TRANSFERRED_KEY: &str = "payable_transferred";
+/// Value = Bech32 address that received payments before the transfer (String)
+pub const PREVIOUS_PAYEE_KEY: &str = "payable_previous_payee";
+/// Value = Bech32 address that receives payments after the transfer (String)
+pub const NEW_PAYEE_KEY: &str = "payable_new_payee";
+
+///////////////////////////////////////////
+// Payable written off output attributes //
+///////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_WRITTEN_OFF_KEY: &str = "payable_written_off";
+/// Value = The remaining owed amount forgiven by the write off (Uint128)
+pub const WRITTEN_OFF_AMOUNT_KEY: &str = "payable_written_off_amount";
+/// Value = The reason provided by the payee for the write off (String)
+pub const WRITE_OFF_REASON_KEY: &str = "payable_write_off_reason";
+
+///////////////////////////////////////
+// Payable expired output attributes //
+///////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_EXPIRED_KEY: &str = "payable_expired";
+/// Value = Oracle gas rebate returned to the registrant of an unapproved payable (u128 + denom: ex "10/nhash")
+pub const ORACLE_GAS_REBATE_REFUNDED_KEY: &str = "payable_oracle_gas_rebate_refunded";
+
+//////////////////////////////////////
+// Payable pruned output attributes //
+//////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_PRUNED_KEY: &str = "payable_pruned";
+/// Value = Time at which the pruned payable was paid off (u64 seconds since epoch)
+pub const SETTLED_AT_KEY: &str = "payable_settled_at";
+
+/////////////////////////////////////////
+// Payable cancelled output attributes //
+/////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_CANCELLED_KEY: &str = "payable_cancelled";
+/// Value = Retained oracle portion of the onboarding cost returned to the registrant (u128 + denom: ex "25/nhash")
+pub const ONBOARDING_FEE_REFUNDED_KEY: &str = "payable_onboarding_fee_refunded";
+/// Value = Priority fee returned to the registrant (u128 + denom: ex "50/nhash")
+pub const PRIORITY_FEE_REFUNDED_KEY: &str = "payable_priority_fee_refunded";
+/// Value = Payable UUID, emitted instead of the cancelled key when an approved payable's cancellation must wait out its claim period (String)
+pub const PAYABLE_CANCELLATION_PENDING_KEY: &str = "payable_cancellation_pending";
+/// Value = The block time at and after which a pending cancellation can be finalized (Timestamp)
+pub const CANCELLATION_FINALIZABLE_AT_KEY: &str = "payable_cancellation_finalizable_at";
+/// Value = Payable UUID (String)
+pub const CANCELLATION_REFUND_CLAIMED_KEY: &str = "payable_cancellation_refund_claimed";
+/// Value = Number of unclaimed escrowed payments returned to their payers when a pending cancellation was finalized (usize)
+pub const CANCELLATION_REFUND_COUNT_KEY: &str = "payable_cancellation_refund_count";
 
 ////////////////////////////////////
 // Payment made output attributes //
@@ -39,6 +182,314 @@ pub const TOTAL_REMAINING_KEY: &str = "payable_total_remaining";
 pub const PAYER_KEY: &str = "payable_payer";
 /// Value = Bech32 address of th entity that received the payment (String)
 pub const PAYEE_KEY: &str = "payable_payee";
+/// Value = Amount of overpayment refunded to the payer, only emitted when refunds were requested (u128 + denom: ex "5/nhash")
+pub const PAYMENT_REFUND_KEY: &str = "payable_payment_refund";
+/// Value = The memo provided by the payer, only emitted when a memo was provided (String)
+pub const PAYMENT_MEMO_KEY: &str = "payable_payment_memo";
+/// Value = Bech32 address of the client that a third party made the payment for, only emitted when provided (String)
+pub const PAYMENT_ON_BEHALF_OF_KEY: &str = "payable_payment_on_behalf_of";
+/// Value = Payable UUID, only emitted by the payment that paid off the payable (String)
+pub const PAYABLE_SETTLED_KEY: &str = "payable_settled";
+/// Value = Payable UUID, only emitted when the scope attribute write was deferred by the payment batch window (String)
+pub const ATTRIBUTE_WRITE_DEFERRED_KEY: &str = "payable_attribute_write_deferred";
+/// Value = Zero-based index of an installment in the payment schedule that the payment satisfied, emitted once per installment (u64)
+pub const INSTALLMENT_PAID_KEY: &str = "payable_installment_paid";
+/// Value = Comma-separated address:amount pairs of the portion of the payment sent to each payee, only emitted for payables with payee splits (String)
+pub const PAYEE_SPLIT_AMOUNTS_KEY: &str = "payable_payee_split_amounts";
+/// Value = Portion of the payment applied to the principal, only emitted for payables with late fee or interest terms (u128)
+pub const PAYMENT_PRINCIPAL_KEY: &str = "payable_payment_principal";
+/// Value = Portion of the payment applied to accrued late fees, only emitted for payables with late fee terms (u128)
+pub const PAYMENT_LATE_FEE_KEY: &str = "payable_payment_late_fee";
+/// Value = Late fees accrued by the payment and added to the amount owed, only emitted when nonzero (u128)
+pub const LATE_FEE_ACCRUED_KEY: &str = "payable_late_fee_accrued";
+/// Value = Portion of the payment applied to accrued interest, only emitted for payables with interest terms (u128)
+pub const PAYMENT_INTEREST_KEY: &str = "payable_payment_interest";
+/// Value = Interest accrued by the payment and added to the amount owed, only emitted when nonzero (u128)
+pub const INTEREST_ACCRUED_KEY: &str = "payable_interest_accrued";
+/// Value = Id of the payment held by the contract, only emitted for payables registered in escrow mode (u64)
+pub const PAYMENT_ESCROWED_KEY: &str = "payable_payment_escrowed";
+/// Value = Id of the payment held by the contract, only emitted when the payment tripped a quarantine heuristic (u64)
+pub const PAYMENT_QUARANTINED_KEY: &str = "payable_payment_quarantined";
+/// Value = Description of the quarantine heuristic that the payment tripped, only emitted alongside the quarantined payment id (String)
+pub const QUARANTINE_REASON_KEY: &str = "payable_quarantine_reason";
+/// Value = Portion of the payment quarantined as a holdback of the payable's risk band, only emitted when a holdback applies (u128)
+pub const PAYMENT_HOLDBACK_KEY: &str = "payable_payment_holdback";
+
+/////////////////////////////////////
+// Batch payment output attributes //
+/////////////////////////////////////
+
+/// Event type emitted once per batch payment, alongside the attributes of each individual payment
+pub const BATCH_PAYMENT_EVENT: &str = "payable_batch_payment";
+/// Value = Number of payables paid in the batch (usize)
+pub const BATCH_PAYMENT_COUNT_KEY: &str = "payable_batch_payment_count";
+/// Value = Total funds paid across the batch (coin list: ex "1000nhash,50usd")
+pub const BATCH_PAYMENT_TOTAL_KEY: &str = "payable_batch_payment_total";
+
+//////////////////////////////////////////////
+// Denom display metadata output attributes //
+//////////////////////////////////////////////
+
+/// Value = Denom that had its display metadata set (String)
+pub const DENOM_METADATA_SET_KEY: &str = "payable_denom_metadata_set";
+/// Value = Denom that had its display metadata removed (String)
+pub const DENOM_METADATA_REMOVED_KEY: &str = "payable_denom_metadata_removed";
+/// Value = Display symbol for the denom (String)
+pub const DENOM_SYMBOL_KEY: &str = "payable_denom_symbol";
+/// Value = Display exponent for the denom (u32)
+pub const DENOM_EXPONENT_KEY: &str = "payable_denom_exponent";
+
+///////////////////////////////////////////
+// Supply check config output attributes //
+///////////////////////////////////////////
+
+/// Value = Payable type that had its marker supply check configured (String)
+pub const SUPPLY_CHECK_PAYABLE_TYPE_KEY: &str = "payable_supply_check_payable_type";
+/// Value = Whether or not the marker supply check is now enabled for the payable type (bool)
+pub const SUPPLY_CHECK_ENABLED_KEY: &str = "payable_supply_check_enabled";
+
+/////////////////////////////////////////
+// Attribute cleanup output attributes //
+/////////////////////////////////////////
+
+/// Value = Scope ID that had its orphaned contract attributes removed (String)
+pub const ORPHANED_ATTRIBUTE_REMOVED_KEY: &str = "payable_orphaned_attribute_removed";
+
+////////////////////////////////////////////
+// Payable subscription output attributes //
+////////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_SUBSCRIBED_KEY: &str = "payable_subscribed";
+/// Value = Bech32 address of the subscriber that registered interest in the payable (String)
+pub const SUBSCRIBER_KEY: &str = "payable_subscriber";
+
+///////////////////////////////////
+// Fee holiday output attributes //
+///////////////////////////////////
+
+/// Value = Id of the scheduled fee holiday (String)
+pub const FEE_HOLIDAY_SCHEDULED_KEY: &str = "payable_fee_holiday_scheduled";
+/// Value = Id of the cancelled fee holiday (String)
+pub const FEE_HOLIDAY_CANCELLED_KEY: &str = "payable_fee_holiday_cancelled";
+/// Value = Onboarding cost charged during the fee holiday (u128 + denom: ex "0/nhash")
+pub const FEE_HOLIDAY_ONBOARDING_COST_KEY: &str = "payable_fee_holiday_onboarding_cost";
+
+//////////////////////////////////////
+// Contract pause output attributes //
+//////////////////////////////////////
+
+/// Value = Whether or not the contract is now paused (bool)
+pub const CONTRACT_PAUSED_KEY: &str = "payable_contract_paused";
+
+//////////////////////////////////////////
+// Payable meta patch output attributes //
+//////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const META_PATCH_CONFIRMED_KEY: &str = "payable_meta_patch_confirmed";
+/// Value = Bech32 address of the admin or oracle that confirmed the meta patch (String)
+pub const META_PATCH_CONFIRMED_BY_KEY: &str = "payable_meta_patch_confirmed_by";
+/// Value = The reason provided for the meta patch (String)
+pub const META_PATCH_REASON_KEY: &str = "payable_meta_patch_reason";
+/// Value = Payable UUID, only emitted once both parties have confirmed and the patch is applied (String)
+pub const PAYABLE_META_PATCHED_KEY: &str = "payable_meta_patched";
+/// Value = Scope ID held in the payable's meta before the patch was applied (String)
+pub const META_PATCH_PREVIOUS_SCOPE_ID_KEY: &str = "payable_meta_patch_previous_scope_id";
+/// Value = Scope ID held in the payable's meta after the patch was applied (String)
+pub const META_PATCH_SCOPE_ID_KEY: &str = "payable_meta_patch_scope_id";
+
+///////////////////////////////////////////
+// Payable type config output attributes //
+///////////////////////////////////////////
+
+/// Value = Payable type that had its config created or replaced (String)
+pub const PAYABLE_TYPE_CONFIG_UPSERTED_KEY: &str = "payable_type_config_upserted";
+/// Value = Onboarding cost charged for registrations of the payable type (u128 + denom: ex "100/nhash")
+pub const PAYABLE_TYPE_CONFIG_ONBOARDING_COST_KEY: &str = "payable_type_config_onboarding_cost";
+/// Value = Percentage of the onboarding cost taken as fee for the payable type (Decimal)
+pub const PAYABLE_TYPE_CONFIG_FEE_PERCENT_KEY: &str = "payable_type_config_fee_percent";
+/// Value = Bech32 address of the default oracle for the payable type, only emitted when provided (String)
+pub const PAYABLE_TYPE_CONFIG_DEFAULT_ORACLE_KEY: &str = "payable_type_config_default_oracle";
+/// Value = The check that registrants of the payable type must pass, only emitted when provided (String)
+pub const PAYABLE_TYPE_CONFIG_REGISTRANT_REQUIREMENT_KEY: &str =
+    "payable_type_config_registrant_requirement";
+
+//////////////////////////////////////////////
+// Supported payable type output attributes //
+//////////////////////////////////////////////
+
+/// Value = Payable type that was added to the registration allowlist (String)
+pub const PAYABLE_TYPE_SUPPORTED_KEY: &str = "payable_type_supported";
+/// Value = Payable type that was removed from the registration allowlist (String)
+pub const PAYABLE_TYPE_UNSUPPORTED_KEY: &str = "payable_type_unsupported";
+
+///////////////////////////////////////
+// Payable decline output attributes //
+///////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYABLE_DECLINED_KEY: &str = "payable_declined";
+
+//////////////////////////////////////////
+// Payment volume cap output attributes //
+//////////////////////////////////////////
+
+/// Value = Denom that had its payment volume cap set (String)
+pub const PAYMENT_VOLUME_CAP_SET_KEY: &str = "payable_payment_volume_cap_set";
+/// Value = Payment volume allowed in the denom per window before the breaker trips (u128)
+pub const PAYMENT_VOLUME_MAX_KEY: &str = "payable_payment_volume_max";
+/// Value = Length of each payment volume window in seconds (u64)
+pub const PAYMENT_VOLUME_WINDOW_KEY: &str = "payable_payment_volume_window";
+/// Value = Denom whose payment volume window was reset by the admin (String)
+pub const PAYMENT_VOLUME_RESET_KEY: &str = "payable_payment_volume_reset";
+/// Value = Denom whose payment volume cap was exceeded by a payment, only emitted by the payment that trips the breaker (String)
+pub const PAYMENT_VOLUME_CAP_TRIPPED_KEY: &str = "payable_payment_volume_cap_tripped";
+
+////////////////////////////////////////
+// Escrowed payment output attributes //
+////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYMENT_RELEASED_KEY: &str = "payable_payment_released";
+/// Value = Payable UUID (String)
+pub const ESCROW_RECLAIMED_KEY: &str = "payable_escrow_reclaimed";
+/// Value = Payable UUID (String)
+pub const PAYMENT_REVERSED_KEY: &str = "payable_payment_reversed";
+/// Value = The admin's reason for reversing an escrowed payment (String)
+pub const PAYMENT_REVERSAL_REASON_KEY: &str = "payable_payment_reversal_reason";
+/// Value = Id of the escrowed or quarantined payment that was released, reclaimed or refunded (u64)
+pub const PAYMENT_ID_KEY: &str = "payable_payment_id";
+/// Event type emitted once per escrowed payment settled by netting
+pub const NETTED_PAYMENT_EVENT: &str = "payable_payment_netted";
+/// Value = Portion of a netted payment that offset its payee's payments to its payer, and was returned to the payer (u128)
+pub const NETTING_OFFSET_AMOUNT_KEY: &str = "payable_netting_offset_amount";
+/// Value = Portion of a netted payment that was sent to its payee (u128)
+pub const NETTING_NET_AMOUNT_KEY: &str = "payable_netting_net_amount";
+/// Value = Number of escrowed payments settled by netting (usize)
+pub const SETTLEMENT_NETTED_KEY: &str = "payable_settlement_netted";
+/// Value = Number of transfers that the netted payments were settled with (usize)
+pub const NETTING_TRANSFER_COUNT_KEY: &str = "payable_netting_transfer_count";
+
+//////////////////////////////////
+// Quarantine output attributes //
+//////////////////////////////////
+
+/// Value = Whether or not payments are screened by quarantine heuristics after the update (bool)
+pub const QUARANTINE_CONFIG_SET_KEY: &str = "payable_quarantine_config_set";
+/// Value = Payable UUID (String)
+pub const QUARANTINED_PAYMENT_RELEASED_KEY: &str = "payable_quarantined_payment_released";
+/// Value = Payable UUID (String)
+pub const QUARANTINED_PAYMENT_REFUNDED_KEY: &str = "payable_quarantined_payment_refunded";
+
+////////////////////////////////
+// Referral output attributes //
+////////////////////////////////
+
+/// Value = Whether or not registrations can name a referrer after the update (bool)
+pub const REFERRAL_CONFIG_SET_KEY: &str = "payable_referral_config_set";
+
+/////////////////////////////////
+// Risk band output attributes //
+/////////////////////////////////
+
+/// Value = The number of risk bands that payments are keyed off after the update (usize)
+pub const RISK_BANDS_SET_KEY: &str = "payable_risk_bands_set";
+
+/////////////////////////////////////////
+// Erroneous payment output attributes //
+/////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYMENT_FLAGGED_ERRONEOUS_KEY: &str = "payable_payment_flagged_erroneous";
+
+//////////////////////////////////////
+// Payable freeze output attributes //
+//////////////////////////////////////
+
+/// Value = Whether or not the payable is now frozen (bool)
+pub const PAYABLE_FROZEN_KEY: &str = "payable_frozen";
+
+///////////////////////////////////////////
+// Payable type freeze output attributes //
+///////////////////////////////////////////
+
+/// Value = Whether or not payables of the payable type are now frozen (bool)
+pub const PAYABLE_TYPE_FROZEN_KEY: &str = "payable_type_frozen";
+
+//////////////////////////////////////////////
+// Stale approval revert output attributes //
+//////////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const APPROVAL_REVERTED_KEY: &str = "payable_oracle_approval_reverted";
+
+//////////////////////////////////////////
+// Payment co-signing output attributes //
+//////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYMENT_PROPOSED_KEY: &str = "payable_payment_proposed";
+/// Value = Payable UUID (String)
+pub const PROPOSED_PAYMENT_APPROVED_KEY: &str = "payable_proposed_payment_approved";
+/// Value = Payable UUID (String)
+pub const PROPOSED_PAYMENT_CANCELLED_KEY: &str = "payable_proposed_payment_cancelled";
+
+/////////////////////////////////////////
+// Payment allowance output attributes //
+/////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const PAYMENT_ALLOWANCE_GRANTED_KEY: &str = "payable_payment_allowance_granted";
+/// Value = Payable UUID (String)
+pub const PAYMENT_ALLOWANCE_REVOKED_KEY: &str = "payable_payment_allowance_revoked";
+/// Value = Payable UUID (String)
+pub const PAYMENT_ALLOWANCE_PULLED_KEY: &str = "payable_payment_allowance_pulled";
+/// Value = Why the allowance was removed: expired, exhausted, settled, or payable_not_found (String)
+pub const PAYMENT_ALLOWANCE_CLOSED_KEY: &str = "payable_payment_allowance_closed";
+/// Value = The amount that can still be pulled under the allowance (Uint128)
+pub const PAYMENT_ALLOWANCE_REMAINING_KEY: &str = "payable_payment_allowance_remaining";
+/// Value = Unix timestamp, in seconds, at which the next pull falls due (u64)
+pub const PAYMENT_ALLOWANCE_NEXT_PULL_KEY: &str = "payable_payment_allowance_next_pull";
+
+///////////////////////////////////////////
+// External settlement output attributes //
+///////////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const EXTERNAL_SETTLEMENT_RECORDED_KEY: &str = "payable_external_settlement_recorded";
+/// Value = The external ledger reference of the settlement, ex: a wire confirmation id (String)
+pub const EXTERNAL_SETTLEMENT_REFERENCE_KEY: &str = "payable_external_settlement_reference";
+
+///////////////////////////////////////
+// Payable dispute output attributes //
+///////////////////////////////////////
+
+/// Value = Payable UUID (String)
+pub const DISPUTE_RAISED_KEY: &str = "payable_dispute_raised";
+/// Value = The reason provided by the party that raised the dispute (String)
+pub const DISPUTE_REASON_KEY: &str = "payable_dispute_reason";
+/// Value = Bech32 address of the party that raised the dispute (String)
+pub const DISPUTE_RAISED_BY_KEY: &str = "payable_dispute_raised_by";
+/// Value = Payable UUID (String)
+pub const DISPUTE_RESOLVED_KEY: &str = "payable_dispute_resolved";
+/// Value = The resolution provided by the oracle or admin that resolved the dispute (String)
+pub const DISPUTE_RESOLUTION_KEY: &str = "payable_dispute_resolution";
+
+////////////////////////////////////////////////
+// Bulk oracle reassignment output attributes //
+////////////////////////////////////////////////
+
+/// Value = Bech32 address of the oracle whose payables were reassigned (String)
+pub const ORACLE_BULK_REASSIGNED_KEY: &str = "payable_oracle_bulk_reassigned";
+/// Value = Number of payables scanned by the call (u64)
+pub const BULK_REASSIGN_SCANNED_KEY: &str = "payable_bulk_reassign_scanned";
+/// Value = Number of scanned payables that were reassigned to the new oracle (u64)
+pub const BULK_REASSIGN_REASSIGNED_KEY: &str = "payable_bulk_reassign_reassigned";
+/// Value = Whether or not every payable has now been scanned (bool)
+pub const BULK_REASSIGN_COMPLETE_KEY: &str = "payable_bulk_reassign_complete";
+/// Value = Payable UUID to provide as start_after in the next call, only emitted when more payables remain (String)
+pub const BULK_REASSIGN_NEXT_START_AFTER_KEY: &str = "payable_bulk_reassign_next_start_after";
 
 /////////////////////////////////
 // Migration output attributes //
@@ -50,6 +501,8 @@ pub const MIGRATION_STATE_CHANGE_PREFIX: &str = "payable_migration_state_field_"
 pub const MIGRATION_CONTRACT_NAME: &str = "payable_migration_contract_name";
 /// Value = New contract version that has been migrated to (String)
 pub const MIGRATION_CONTRACT_VERSION: &str = "payable_migration_contract_version";
+/// Value = Unix timestamp, in seconds, at which staged migration params can be applied (u64)
+pub const MIGRATION_STAGED_KEY: &str = "payable_migration_staged";
 
 //////////////////////////////
 // Shared output attributes //
@@ -61,3 +514,7 @@ pub const PAYABLE_UUID_KEY: &str = "payable_uuid";
 pub const PAYABLE_TYPE_KEY: &str = "payable_type";
 /// Value = The address of the oracle associated with the payable (String)
 pub const ORACLE_ADDRESS_KEY: &str = "payable_oracle_address";
+/// Value = The number of addresses subscribed to the payable, only emitted on lifecycle events for payables with subscribers (u64)
+pub const SUBSCRIBER_COUNT_KEY: &str = "payable_subscriber_count";
+/// Value = Comma-separated Bech32 addresses of the first subscribers to the payable, ordered by address (String)
+pub const SUBSCRIBERS_KEY: &str = "payable_subscribers";
@@ -0,0 +1,214 @@
+use crate::core::money::Money;
+use crate::core::state::{load_all_fee_holidays, load_payable_type_config, StateV2};
+use crate::validation;
+use crate::validation::execute::MAX_REFERRAL_FEE_SHARE_BPS;
+use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp, Uint128, Uint256};
+use std::ops::Mul;
+
+/// The breakdown of the onboarding cost charged when a payable is registered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectiveFees {
+    // The total amount charged to the registrant, in the onboarding denom
+    pub onboarding_cost: Money,
+    // The portion of the onboarding cost sent to the fee collection address
+    pub fee_amount: Money,
+    // The portion of the onboarding cost held by the contract and paid to the oracle on approval
+    pub oracle_amount: Money,
+    // The fee percent applied to the onboarding cost, expressed in basis points
+    pub fee_bps: Uint128,
+    // The fee holiday that reduced the onboarding cost, if one applied
+    pub fee_holiday_id: Option<String>,
+}
+
+/// Derives the fees that a registration would be charged given the current contract configuration.
+/// Registration, oracle approval, and fee quotes all route through this function to ensure that
+/// quoted values never drift from the values that are actually charged.
+pub fn calculate_effective_fees(state: &StateV2) -> EffectiveFees {
+    split_onboarding_cost(
+        state.onboarding_cost,
+        &state.onboarding_denom,
+        state.fee_percent,
+        None,
+    )
+}
+
+/// Derives the fees that a registration of the given payable type would be charged at the given
+/// time.  A payable type with a config is charged its configured cost, denom, and fee percent in
+/// place of the contract's.  If any scheduled fee holidays cover the registration, the cheapest of
+/// them replaces the onboarding cost.  A holiday never raises the cost, and holidays are priced in
+/// the contract's onboarding denom, so they never apply to types configured with another denom.
+pub fn calculate_registration_fees(
+    storage: &dyn Storage,
+    state: &StateV2,
+    payable_type: &str,
+    now: Timestamp,
+) -> StdResult<EffectiveFees> {
+    let (onboarding_cost, onboarding_denom, fee_percent) =
+        match load_payable_type_config(storage, payable_type)? {
+            Some(config) => (
+                config.onboarding_cost,
+                config.onboarding_denom,
+                config.fee_percent,
+            ),
+            None => (
+                state.onboarding_cost,
+                state.onboarding_denom.clone(),
+                state.fee_percent,
+            ),
+        };
+    let cheapest_holiday = if onboarding_denom == state.onboarding_denom {
+        load_all_fee_holidays(storage)?
+            .into_iter()
+            .filter(|holiday| holiday.applies_to(payable_type, now))
+            .filter(|holiday| holiday.onboarding_cost < onboarding_cost)
+            .min_by_key(|holiday| holiday.onboarding_cost)
+    } else {
+        None
+    };
+    Ok(match cheapest_holiday {
+        Some(holiday) => split_onboarding_cost(
+            holiday.onboarding_cost,
+            &onboarding_denom,
+            fee_percent,
+            Some(holiday.holiday_id),
+        ),
+        None => split_onboarding_cost(onboarding_cost, &onboarding_denom, fee_percent, None),
+    })
+}
+
+fn split_onboarding_cost(
+    onboarding_cost: Uint128,
+    onboarding_denom: &str,
+    fee_percent: Decimal,
+    fee_holiday_id: Option<String>,
+) -> EffectiveFees {
+    // The collected fee is the fee percent * the onboarding cost.  The remaining amount will stay in
+    // the contract's account, waiting for the oracle to withdraw it
+    let fee_amount = onboarding_cost.mul(fee_percent);
+    EffectiveFees {
+        onboarding_cost: Money::new(onboarding_cost, onboarding_denom),
+        fee_amount: Money::new(fee_amount, onboarding_denom),
+        oracle_amount: Money::new(onboarding_cost - fee_amount, onboarding_denom),
+        fee_bps: Uint128::new(10_000).mul(fee_percent),
+        fee_holiday_id,
+    }
+}
+
+/// Determines if the fee percent splits the onboarding cost into whole base units, using the same
+/// rule that the message validation applies to configured fees.
+pub fn is_fee_split_exact(onboarding_cost: Uint128, fee_percent: Decimal) -> bool {
+    validation::is_fee_split_exact(onboarding_cost.u128(), fee_percent.atomics().u128())
+}
+
+/// Determines if the referral share of a collected fee is a whole number of base units.  Like the
+/// fee itself, the share is rounded down when routed, so a share that is not exact would silently
+/// shift sub-unit dust from the referrer to the fee collector on every referred registration.
+pub fn is_referral_share_exact(fee_amount: Uint128, fee_share_bps: u64) -> bool {
+    (fee_amount.full_mul(fee_share_bps) % Uint256::from(MAX_REFERRAL_FEE_SHARE_BPS)).is_zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::state::StateV2;
+    use crate::util::fees::{calculate_effective_fees, is_fee_split_exact};
+    use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+    use cosmwasm_std::{Addr, Decimal, Uint128};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_calculate_effective_fees_splits_onboarding_cost() {
+        let fees = calculate_effective_fees(&get_state(100, Decimal::percent(75)));
+        assert_eq!(
+            100,
+            fees.onboarding_cost.amount.u128(),
+            "the full cost should be reported"
+        );
+        assert_eq!(
+            "nhash", fees.onboarding_cost.denom,
+            "the onboarding denom should be reported"
+        );
+        assert_eq!(
+            75,
+            fees.fee_amount.amount.u128(),
+            "the fee should be 75% of the cost"
+        );
+        assert_eq!(
+            25,
+            fees.oracle_amount.amount.u128(),
+            "the oracle should receive the remainder"
+        );
+        assert_eq!(
+            7500,
+            fees.fee_bps.u128(),
+            "the fee percent should be reported in basis points"
+        );
+    }
+
+    #[test]
+    fn test_calculate_effective_fees_rounds_fee_down() {
+        // 33% of 10 is 3.3, which floors to 3 - the oracle receives the remainder
+        let fees = calculate_effective_fees(&get_state(10, Decimal::percent(33)));
+        assert_eq!(
+            3,
+            fees.fee_amount.amount.u128(),
+            "the fee should be rounded down"
+        );
+        assert_eq!(
+            7,
+            fees.oracle_amount.amount.u128(),
+            "the oracle should receive the rounding remainder"
+        );
+    }
+
+    #[test]
+    fn test_is_fee_split_exact() {
+        assert!(
+            is_fee_split_exact(Uint128::new(100), Decimal::percent(75)),
+            "75% of 100 is a whole amount",
+        );
+        assert!(
+            is_fee_split_exact(Uint128::new(10_000), Decimal::from_str("0.3333").unwrap()),
+            "33.33% of 10000 is a whole amount",
+        );
+        assert!(
+            is_fee_split_exact(Uint128::zero(), Decimal::percent(33)),
+            "a free onboarding cost has nothing to split",
+        );
+        assert!(
+            is_fee_split_exact(Uint128::new(7), Decimal::one()),
+            "the full cost is always a whole amount",
+        );
+        assert!(
+            !is_fee_split_exact(Uint128::new(100), Decimal::from_str("0.3333").unwrap()),
+            "33.33% of 100 leaves dust",
+        );
+        assert!(
+            !is_fee_split_exact(Uint128::new(10), Decimal::percent(33)),
+            "33% of 10 leaves dust",
+        );
+    }
+
+    fn get_state(onboarding_cost: u128, fee_percent: Decimal) -> StateV2 {
+        StateV2 {
+            contract_name: "payables.asset".to_string(),
+            onboarding_cost: Uint128::new(onboarding_cost),
+            onboarding_denom: "nhash".to_string(),
+            fee_collection_address: Addr::unchecked("feebucket"),
+            fee_percent,
+            is_local: false,
+            payment_batch_window: None,
+            admin: None,
+            migration_timelock_seconds: None,
+            oracle_funds_tolerance: None,
+            minimum_payment_amount: None,
+            is_paused: false,
+            settled_retention_seconds: None,
+            approval_valid_duration: None,
+            oracle_approval_sla_seconds: None,
+            large_payment_threshold: None,
+            payment_reversal_window_seconds: None,
+            provenance_msg_version: ProvenanceMsgVersion::V1,
+            authorized_registrars: vec![],
+        }
+    }
+}
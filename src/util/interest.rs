@@ -0,0 +1,238 @@
+use crate::core::error::ContractError;
+use cosmwasm_std::{Decimal, StdError, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+pub const DAYS_PER_YEAR: u64 = 365;
+pub const SECONDS_PER_YEAR: u64 = SECONDS_PER_DAY * DAYS_PER_YEAR;
+// The continuous approximation stops expanding its series once terms vanish at Decimal precision,
+// or after this many terms, whichever comes first
+const CONTINUOUS_SERIES_MAX_TERMS: u128 = 40;
+
+/// Determines how often accrued interest is added to the balance that future interest accrues on.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InterestCompounding {
+    // Interest only ever accrues on the principal
+    #[default]
+    Simple,
+    // Interest is added to the balance at the end of each full day, with simple interest accruing
+    // within a partial day
+    Daily,
+    // Interest is added to the balance continuously, approximated via a truncated series of e^x
+    Continuous,
+}
+
+/// Calculates the interest accrued on the given principal at the annualized rate over the elapsed
+/// time, using the provided compounding mode.  Fractional amounts are always rounded down so that
+/// interest is never over-charged.
+pub fn calculate_accrued_interest(
+    principal: Uint128,
+    annual_rate: Decimal,
+    elapsed_seconds: u64,
+    compounding: &InterestCompounding,
+) -> Result<Uint128, ContractError> {
+    let growth = match compounding {
+        InterestCompounding::Simple => simple_growth(annual_rate, elapsed_seconds)?,
+        InterestCompounding::Daily => daily_growth(annual_rate, elapsed_seconds)?,
+        InterestCompounding::Continuous => continuous_growth(annual_rate, elapsed_seconds)?,
+    };
+    principal
+        .checked_multiply_ratio(growth.atomics(), Decimal::one().atomics())
+        .map_err(|e| ContractError::std_err(format!("interest calculation failed: {}", e)))
+}
+
+/// The fraction of the principal accrued as simple interest: rate * years elapsed.
+fn simple_growth(annual_rate: Decimal, elapsed_seconds: u64) -> Result<Decimal, ContractError> {
+    Ok(annual_rate
+        .checked_mul(years_elapsed(elapsed_seconds))
+        .map_err(StdError::from)?)
+}
+
+/// The fraction of the principal accrued with daily compounding: (1 + rate / 365)^days - 1, with
+/// any partial day accruing simple interest on the compounded balance.
+fn daily_growth(annual_rate: Decimal, elapsed_seconds: u64) -> Result<Decimal, ContractError> {
+    let daily_rate = Decimal::from_ratio(
+        annual_rate.atomics(),
+        Decimal::one().atomics().u128() * DAYS_PER_YEAR as u128,
+    );
+    let full_days = u32::try_from(elapsed_seconds / SECONDS_PER_DAY).map_err(|_| {
+        ContractError::std_err("interest calculation failed: too many days elapsed")
+    })?;
+    let partial_day = Decimal::from_ratio(elapsed_seconds % SECONDS_PER_DAY, SECONDS_PER_DAY);
+    let compounded = (Decimal::one() + daily_rate)
+        .checked_pow(full_days)
+        .map_err(StdError::from)?;
+    let partial_day_growth = Decimal::one()
+        + daily_rate
+            .checked_mul(partial_day)
+            .map_err(StdError::from)?;
+    Ok(compounded
+        .checked_mul(partial_day_growth)
+        .map_err(StdError::from)?
+        - Decimal::one())
+}
+
+/// The fraction of the principal accrued with continuous compounding: e^(rate * years) - 1,
+/// expanded as the series x + x^2/2! + x^3/3! + ...
+fn continuous_growth(annual_rate: Decimal, elapsed_seconds: u64) -> Result<Decimal, ContractError> {
+    let exponent = annual_rate
+        .checked_mul(years_elapsed(elapsed_seconds))
+        .map_err(StdError::from)?;
+    let mut term = exponent;
+    let mut growth = exponent;
+    for k in 2..=CONTINUOUS_SERIES_MAX_TERMS {
+        term = term
+            .checked_mul(exponent)
+            .and_then(|term| term.checked_mul(Decimal::from_ratio(1u128, k)))
+            .map_err(StdError::from)?;
+        if term.is_zero() {
+            break;
+        }
+        growth = growth.checked_add(term).map_err(StdError::from)?;
+    }
+    Ok(growth)
+}
+
+fn years_elapsed(elapsed_seconds: u64) -> Decimal {
+    Decimal::from_ratio(elapsed_seconds, SECONDS_PER_YEAR)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::interest::{
+        calculate_accrued_interest, InterestCompounding, SECONDS_PER_DAY, SECONDS_PER_YEAR,
+    };
+    use cosmwasm_std::{Decimal, Uint128};
+
+    const PRINCIPAL: u128 = 1_000_000;
+
+    #[test]
+    fn test_simple_interest() {
+        assert_eq!(
+            100_000,
+            accrue(
+                Decimal::percent(10),
+                SECONDS_PER_YEAR,
+                InterestCompounding::Simple
+            ),
+            "a full year of simple interest at 10% should accrue 10% of the principal",
+        );
+        assert_eq!(
+            50_000,
+            accrue(
+                Decimal::percent(10),
+                SECONDS_PER_YEAR / 2,
+                InterestCompounding::Simple
+            ),
+            "half a year of simple interest at 10% should accrue 5% of the principal",
+        );
+    }
+
+    #[test]
+    fn test_daily_interest() {
+        // 1,000,000 * ((1 + 0.1 / 365)^365 - 1) = 105,155.78
+        assert_eq!(
+            105_155,
+            accrue(Decimal::percent(10), SECONDS_PER_YEAR, InterestCompounding::Daily),
+            "a full year of daily compounding at 10% should accrue slightly more than simple interest",
+        );
+        // A single day should match simple interest: 1,000,000 * 0.1 / 365 = 273.97
+        assert_eq!(
+            273,
+            accrue(
+                Decimal::percent(10),
+                SECONDS_PER_DAY,
+                InterestCompounding::Daily
+            ),
+            "a single day of daily compounding should match simple interest",
+        );
+        // Half a day accrues simple interest within the day
+        assert_eq!(
+            136,
+            accrue(
+                Decimal::percent(10),
+                SECONDS_PER_DAY / 2,
+                InterestCompounding::Daily
+            ),
+            "a partial day should accrue simple interest",
+        );
+    }
+
+    #[test]
+    fn test_continuous_interest() {
+        // 1,000,000 * (e^0.1 - 1) = 105,170.92
+        assert_eq!(
+            105_170,
+            accrue(
+                Decimal::percent(10),
+                SECONDS_PER_YEAR,
+                InterestCompounding::Continuous
+            ),
+            "a full year of continuous compounding at 10% should accrue e^0.1 - 1 of the principal",
+        );
+        // 1,000,000 * (e^2 - 1) = 6,389,056.10
+        assert_eq!(
+            6_389_056,
+            accrue(
+                Decimal::percent(100),
+                SECONDS_PER_YEAR * 2,
+                InterestCompounding::Continuous
+            ),
+            "the series should converge for larger exponents",
+        );
+    }
+
+    #[test]
+    fn test_no_interest_without_elapsed_time_or_rate() {
+        for compounding in [
+            InterestCompounding::Simple,
+            InterestCompounding::Daily,
+            InterestCompounding::Continuous,
+        ] {
+            assert_eq!(
+                0,
+                accrue(Decimal::percent(10), 0, compounding.clone()),
+                "no interest should accrue when no time has elapsed with {:?} compounding",
+                compounding,
+            );
+            assert_eq!(
+                0,
+                accrue(Decimal::zero(), SECONDS_PER_YEAR, compounding.clone()),
+                "no interest should accrue at a zero rate with {:?} compounding",
+                compounding,
+            );
+        }
+    }
+
+    #[test]
+    fn test_interest_overflow_is_an_error() {
+        let result = calculate_accrued_interest(
+            Uint128::new(PRINCIPAL),
+            Decimal::percent(10_000),
+            SECONDS_PER_YEAR * 100,
+            &InterestCompounding::Daily,
+        );
+        assert!(
+            result.is_err(),
+            "an interest calculation that overflows should produce an error instead of panicking",
+        );
+    }
+
+    fn accrue(
+        annual_rate: Decimal,
+        elapsed_seconds: u64,
+        compounding: InterestCompounding,
+    ) -> u128 {
+        calculate_accrued_interest(
+            Uint128::new(PRINCIPAL),
+            annual_rate,
+            elapsed_seconds,
+            &compounding,
+        )
+        .unwrap()
+        .u128()
+    }
+}
@@ -1,4 +1,11 @@
+#[cfg(feature = "allowance")]
+pub mod authz;
 pub mod constants;
 pub mod conversions;
+pub mod fees;
+pub mod interest;
+pub mod provenance_msg_factory;
 pub mod provenance_util;
+pub mod response_builder;
+pub mod storage_overlay;
 pub mod traits;
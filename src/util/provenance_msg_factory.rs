@@ -0,0 +1,188 @@
+use crate::core::error::ContractError;
+use crate::core::state::PayableScopeAttribute;
+use cosmwasm_std::{to_binary, Addr, Binary, CosmosMsg, StdResult};
+use provwasm_std::{
+    add_json_attribute, bind_name, delete_attributes, update_attribute, AttributeValueType,
+    NameBinding, ProvenanceMsg,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Provenance message formats that a contract instance produces.  Newer chain versions support
+/// messages that older ones reject, so each environment selects its version through instantiation
+/// or migration rather than requiring a separate build.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvenanceMsgVersion {
+    // Scope attributes are rewritten by deleting every attribute under the contract name and then
+    // adding the new attribute
+    V1,
+    // Scope attributes are rewritten in place with a single update_attribute message
+    V2,
+}
+impl Default for ProvenanceMsgVersion {
+    /// Contracts instantiated before the version switch existed produce V1 messages.
+    fn default() -> Self {
+        ProvenanceMsgVersion::V1
+    }
+}
+impl fmt::Display for ProvenanceMsgVersion {
+    /// Formats the version as its serialized name, ex: "v1", for use in event attributes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvenanceMsgVersion::V1 => write!(f, "v1"),
+            ProvenanceMsgVersion::V2 => write!(f, "v2"),
+        }
+    }
+}
+
+/// Constructs every name and attribute message that the contract sends to the Provenance modules,
+/// in the format of the configured ProvenanceMsgVersion.  Execution flows should produce these
+/// messages through a factory rather than invoking provwasm helper functions directly.
+pub struct ProvenanceMsgFactory {
+    version: ProvenanceMsgVersion,
+}
+impl ProvenanceMsgFactory {
+    pub fn new(version: ProvenanceMsgVersion) -> ProvenanceMsgFactory {
+        ProvenanceMsgFactory { version }
+    }
+
+    /// Binds a restricted name to the contract address, under which scope attributes are written.
+    pub fn bind_contract_name(
+        &self,
+        contract_name: impl Into<String>,
+        contract_address: Addr,
+    ) -> StdResult<CosmosMsg<ProvenanceMsg>> {
+        bind_name(contract_name, contract_address, NameBinding::Restricted)
+    }
+
+    /// Adds the payable's attribute to its scope, which must not already hold one.
+    pub fn add_scope_attribute(
+        &self,
+        attribute: &PayableScopeAttribute,
+        contract_name: impl Into<String>,
+    ) -> Result<CosmosMsg<ProvenanceMsg>, ContractError> {
+        add_json_attribute(
+            // Until there's a way to parse a scope address as an Addr, we must use Addr::unchecked.
+            // It's not the best policy, but contract execution will fail if it's an incorrect
+            // address, so it'll just fail later down the line with a less sane error message than
+            // if it was being properly checked.
+            Addr::unchecked(&attribute.scope_id),
+            contract_name,
+            attribute,
+        )
+        .map_err(ContractError::Std)
+    }
+
+    /// Removes every attribute under the contract name from the scope.
+    pub fn delete_scope_attributes(
+        &self,
+        scope_id: impl Into<String>,
+        contract_name: impl Into<String>,
+    ) -> Result<CosmosMsg<ProvenanceMsg>, ContractError> {
+        delete_attributes(Addr::unchecked(scope_id.into()), contract_name)
+            .map_err(ContractError::Std)
+    }
+
+    /// Replaces the payable's attribute on its scope.  The existing values are the raw values of
+    /// every attribute currently under the contract name on the scope.  V2 updates a single
+    /// existing value in place, while V1, or a scope without exactly one existing value, deletes
+    /// all existing values before adding the new attribute.
+    pub fn replace_scope_attribute(
+        &self,
+        attribute: &PayableScopeAttribute,
+        contract_name: impl Into<String>,
+        existing_values: &[Binary],
+    ) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+        let contract_name = contract_name.into();
+        match (self.version, existing_values) {
+            (ProvenanceMsgVersion::V2, [existing_value]) => Ok(vec![update_attribute(
+                Addr::unchecked(&attribute.scope_id),
+                &contract_name,
+                existing_value.to_owned(),
+                AttributeValueType::Json,
+                to_binary(attribute)?,
+                AttributeValueType::Json,
+            )
+            .map_err(ContractError::Std)?]),
+            _ => Ok(vec![
+                self.delete_scope_attributes(&attribute.scope_id, &contract_name)?,
+                self.add_scope_attribute(attribute, &contract_name)?,
+            ]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testutil::register_payable_helpers::TestRegisterPayable;
+    use crate::testutil::test_utilities::{DEFAULT_CONTRACT_NAME, DEFAULT_SCOPE_ID};
+    use crate::util::provenance_msg_factory::{ProvenanceMsgFactory, ProvenanceMsgVersion};
+    use cosmwasm_std::{to_binary, CosmosMsg};
+    use provwasm_std::{
+        AttributeMsgParams, AttributeValueType, ProvenanceMsg, ProvenanceMsgParams,
+    };
+
+    #[test]
+    fn test_replace_scope_attribute_by_version() {
+        let attribute = TestRegisterPayable::default_register_payable().to_scope_attribute();
+        let existing_values = vec![to_binary(&attribute).unwrap()];
+        let messages = ProvenanceMsgFactory::new(ProvenanceMsgVersion::V1)
+            .replace_scope_attribute(&attribute, DEFAULT_CONTRACT_NAME, &existing_values)
+            .unwrap();
+        assert_eq!(
+            vec!["delete_attribute", "add_attribute"],
+            messages.iter().map(attribute_msg_name).collect::<Vec<_>>(),
+            "v1 should delete the existing attribute before adding the new one",
+        );
+        let factory = ProvenanceMsgFactory::new(ProvenanceMsgVersion::V2);
+        let messages = factory
+            .replace_scope_attribute(&attribute, DEFAULT_CONTRACT_NAME, &existing_values)
+            .unwrap();
+        match &messages[..] {
+            [CosmosMsg::Custom(msg)] => match &msg.params {
+                ProvenanceMsgParams::Attribute(AttributeMsgParams::UpdateAttribute {
+                    address,
+                    name,
+                    original_value,
+                    original_value_type,
+                    ..
+                }) => {
+                    assert_eq!(DEFAULT_SCOPE_ID, address.as_str());
+                    assert_eq!(DEFAULT_CONTRACT_NAME, name);
+                    assert_eq!(&existing_values[0], original_value);
+                    assert_eq!(&AttributeValueType::Json, original_value_type);
+                }
+                params => panic!("unexpected params emitted: {:?}", params),
+            },
+            msgs => panic!(
+                "v2 should emit a single update message, but got: {:?}",
+                msgs
+            ),
+        }
+        let messages = factory
+            .replace_scope_attribute(&attribute, DEFAULT_CONTRACT_NAME, &[])
+            .unwrap();
+        assert_eq!(
+            vec!["delete_attribute", "add_attribute"],
+            messages.iter().map(attribute_msg_name).collect::<Vec<_>>(),
+            "v2 should fall back to a delete and add when there is no single value to update",
+        );
+    }
+
+    fn attribute_msg_name(msg: &CosmosMsg<ProvenanceMsg>) -> &'static str {
+        match msg {
+            CosmosMsg::Custom(msg) => match &msg.params {
+                ProvenanceMsgParams::Attribute(AttributeMsgParams::AddAttribute { .. }) => {
+                    "add_attribute"
+                }
+                ProvenanceMsgParams::Attribute(AttributeMsgParams::DeleteAttribute { .. }) => {
+                    "delete_attribute"
+                }
+                params => panic!("unexpected params emitted: {:?}", params),
+            },
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        }
+    }
+}
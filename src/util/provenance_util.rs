@@ -1,10 +1,11 @@
 use crate::core::error::ContractError;
-use crate::core::state::PayableScopeAttribute;
+use crate::core::state::{config_read_v2, remove_payment_batch, PayableScopeAttribute};
 use crate::query::query_payable_by_scope_id::query_payable_attribute_by_scope_id;
-use cosmwasm_std::{Addr, CosmosMsg, Deps, QuerierWrapper, StdResult};
-use provwasm_std::{
-    add_json_attribute, delete_attributes, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery, Scope,
+use crate::util::provenance_msg_factory::ProvenanceMsgFactory;
+use cosmwasm_std::{
+    from_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, QuerierWrapper, StdResult,
 };
+use provwasm_std::{Marker, ProvenanceMsg, ProvenanceQuerier, ProvenanceQuery, Scope};
 
 /// Defines a ProvenanceUtil instance.  This value should be used to query provenance modules or to
 /// generate messages utilizing provwasm helper functions.
@@ -17,6 +18,14 @@ pub trait ProvenanceUtil {
         scope_id: impl Into<String>,
     ) -> StdResult<Scope>;
 
+    /// Returns a provwasm Marker struct for the given denom.  Fails if the denom is not backed by a
+    /// marker.
+    fn get_marker_by_denom(
+        &self,
+        querier: &QuerierWrapper<ProvenanceQuery>,
+        denom: impl Into<String>,
+    ) -> StdResult<Marker>;
+
     /// Derives a CosmosMsg<ProvenanceMsg> Custom wrapper that will add a PayableScopeAttribute to
     /// a target scope.  The target scope should be defined by the scope_id value within the
     /// PayableScopeAttribute parameter.
@@ -27,13 +36,14 @@ pub trait ProvenanceUtil {
         contract_name: impl Into<String>,
     ) -> Result<CosmosMsg<ProvenanceMsg>, ContractError>;
 
-    /// Provwasm currently does not expose an "update attribute" functionality, so this function is
-    /// a placeholder that should delete all attributes listed until the provided contract_name, and
-    /// add a new attribute correlating to the json values of the provided PayableScopeAttribute.
-    /// The target scope should be defined by the scope_id value within the PayableScopeAttribute
-    /// parameter.
+    /// Replaces the attributes listed under the provided contract_name with a new attribute
+    /// correlating to the json values of the provided PayableScopeAttribute, in the message format
+    /// of the contract's configured ProvenanceMsgVersion.  The target scope should be defined by
+    /// the scope_id value within the PayableScopeAttribute parameter.  Fails if any existing
+    /// attribute under the contract_name is not this payable's attribute, rather than replacing it.
     fn upsert_attribute_to_scope(
         &self,
+        deps: &Deps<ProvenanceQuery>,
         attribute: &PayableScopeAttribute,
         contract_name: impl Into<String>,
     ) -> Result<WriteAttributeMessages, ContractError>;
@@ -54,6 +64,16 @@ impl ProvenanceUtil for ProvenanceUtilImpl {
         ProvenanceQuerier::new(querier).get_scope(scope_id)
     }
 
+    /// Simply generates a ProvenanceQuerier from the QuerierWrapper and invokes
+    /// get_marker_by_denom for the given denom.
+    fn get_marker_by_denom(
+        &self,
+        querier: &QuerierWrapper<ProvenanceQuery>,
+        denom: impl Into<String>,
+    ) -> StdResult<Marker> {
+        ProvenanceQuerier::new(querier).get_marker_by_denom(denom)
+    }
+
     /// Checks to determine if the scope has already been registered with an attribute for this
     /// contract.  If so, returns a ContractError.  If not, generates an add attribute message.
     fn get_add_initial_attribute_to_scope_msg(
@@ -68,58 +88,227 @@ impl ProvenanceUtil for ProvenanceUtilImpl {
             }
             .to_result();
         }
-        super::provenance_util::get_add_attribute_to_scope_msg(attribute, contract_name)
+        let state = config_read_v2(deps.storage).load()?;
+        ProvenanceMsgFactory::new(state.provenance_msg_version)
+            .add_scope_attribute(attribute, contract_name)
     }
 
     /// Forgoes validation on whether or not the scope exists, because the current attribute (if any)
-    /// on the scope will be deleted.  Generates a deletion and addition message, as the trait
-    /// documentation implies.
+    /// on the scope will be replaced.  Verifies that every attribute that would be replaced belongs
+    /// to the payable, then generates the replacement messages, as the trait documentation implies.
     fn upsert_attribute_to_scope(
         &self,
+        deps: &Deps<ProvenanceQuery>,
         attribute: &PayableScopeAttribute,
         contract_name: impl Into<String>,
     ) -> Result<WriteAttributeMessages, ContractError> {
         let contract_name = contract_name.into();
-        let delete_attributes_msg =
-            delete_attributes(Addr::unchecked(&attribute.scope_id), &contract_name)
-                .map_err(ContractError::Std)?;
-        let add_attribute_msg =
-            super::provenance_util::get_add_attribute_to_scope_msg(attribute, &contract_name)?;
+        let existing_values = verify_existing_attributes_match(deps, attribute, &contract_name)?;
+        let state = config_read_v2(deps.storage).load()?;
         Ok(WriteAttributeMessages {
-            delete_attributes_msg,
-            add_attribute_msg,
+            messages: ProvenanceMsgFactory::new(state.provenance_msg_version)
+                .replace_scope_attribute(attribute, &contract_name, &existing_values)?,
         })
     }
 }
 
-/// Helper function to generate an "add attribute" message, as the functionality is re-used across
-/// multiple functions.
-fn get_add_attribute_to_scope_msg(
+/// Deleting attributes removes every attribute under the contract name on the scope, so each one
+/// must be this payable's attribute.  A foreign or corrupted attribute indicates that something
+/// other than this contract wrote to the name, and it should be investigated rather than destroyed.
+/// Returns the raw value of each existing attribute, which updating an attribute in place requires.
+fn verify_existing_attributes_match(
+    deps: &Deps<ProvenanceQuery>,
     attribute: &PayableScopeAttribute,
-    contract_name: impl Into<String>,
-) -> Result<CosmosMsg<ProvenanceMsg>, ContractError> {
-    add_json_attribute(
-        // Until there's a way to parse a scope address as an Addr, we must use Addr::unchecked.
-        // It's not the best policy, but contract execution will fail if it's an incorrect address,
-        // so it'll just fail later down the line with a less sane error message than if it was
-        // being properly checked.
-        Addr::unchecked(&attribute.scope_id),
-        contract_name,
-        attribute,
-    )
-    .map_err(ContractError::Std)
+    contract_name: &str,
+) -> Result<Vec<Binary>, ContractError> {
+    let existing_attributes = ProvenanceQuerier::new(&deps.querier)
+        .get_attributes(Addr::unchecked(&attribute.scope_id), Some(contract_name))?
+        .attributes;
+    let mut existing_values = vec![];
+    for existing_attribute in existing_attributes {
+        let mismatch_reason = match from_binary::<PayableScopeAttribute>(&existing_attribute.value)
+        {
+            Ok(existing) if existing.payable_uuid == attribute.payable_uuid => {
+                existing_values.push(existing_attribute.value);
+                continue;
+            }
+            Ok(existing) => format!(
+                "found an attribute for a different payable [{}]",
+                existing.payable_uuid
+            ),
+            Err(_) => "found an attribute that is not a valid payable attribute".to_string(),
+        };
+        return ContractError::ScopeAttributeMismatch {
+            scope_id: attribute.scope_id.clone(),
+            payable_uuid: attribute.payable_uuid.clone(),
+            mismatch_reason,
+        }
+        .to_result();
+    }
+    Ok(existing_values)
+}
+
+/// Rewrites a payable's attribute on its scope, clearing any payment batch held for it.  The
+/// attribute is loaded with any deferred payments applied, so the batched remaining owed amount is
+/// written along with the change and the batch is no longer needed.
+pub fn write_through_payable<T: ProvenanceUtil>(
+    deps: DepsMut<ProvenanceQuery>,
+    provenance_util: &T,
+    scope_attribute: &PayableScopeAttribute,
+    contract_name: &str,
+) -> Result<Vec<CosmosMsg<ProvenanceMsg>>, ContractError> {
+    remove_payment_batch(deps.storage, &scope_attribute.payable_uuid);
+    Ok(provenance_util
+        .upsert_attribute_to_scope(&deps.as_ref(), scope_attribute, contract_name)?
+        .to_vec())
 }
 
-/// Helper struct - contains both a delete and add attribute message for the response of
+/// Helper struct - contains the ordered messages that rewrite a scope attribute for the response of
 /// upsert_attribute_to_scope in the ProvenanceUtil trait.
 pub struct WriteAttributeMessages {
-    delete_attributes_msg: CosmosMsg<ProvenanceMsg>,
-    add_attribute_msg: CosmosMsg<ProvenanceMsg>,
+    messages: Vec<CosmosMsg<ProvenanceMsg>>,
 }
 impl WriteAttributeMessages {
-    /// Helper function to convert both messages to a properly ordered Vec for easy insertion into
+    /// Helper function to convert the messages to a properly ordered Vec for easy insertion into
     /// cosomwasm Response structs.
     pub fn to_vec(self) -> Vec<CosmosMsg<ProvenanceMsg>> {
-        vec![self.delete_attributes_msg, self.add_attribute_msg]
+        self.messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::error::ContractError;
+    use crate::core::state::{
+        load_payment_batch, save_payment_batch, PayableScopeAttribute, PaymentBatchV1,
+    };
+    use crate::testutil::register_payable_helpers::TestRegisterPayable;
+    use crate::testutil::test_utilities::{
+        mock_default_scope_attribute, setup_test_suite, InstArgs, DEFAULT_CONTRACT_NAME,
+        DEFAULT_SCOPE_ID,
+    };
+    use crate::util::provenance_msg_factory::ProvenanceMsgVersion;
+    use crate::util::provenance_util::{write_through_payable, ProvenanceUtil, ProvenanceUtilImpl};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_upsert_attribute_to_scope_replaces_own_attribute() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let attribute = default_attribute();
+        mock_default_scope_attribute(&mut deps, &attribute);
+        let messages = ProvenanceUtilImpl
+            .upsert_attribute_to_scope(&deps.as_ref(), &attribute, DEFAULT_CONTRACT_NAME)
+            .expect("the payable's own attribute should be replaced");
+        assert_eq!(
+            2,
+            messages.to_vec().len(),
+            "a delete and an add message should be produced",
+        );
+    }
+
+    #[test]
+    fn test_write_through_payable_clears_payment_batch() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let attribute = default_attribute();
+        mock_default_scope_attribute(&mut deps, &attribute);
+        save_payment_batch(
+            deps.as_mut().storage,
+            &PaymentBatchV1 {
+                payable_uuid: attribute.payable_uuid.clone(),
+                remaining_owed: Uint128::new(100),
+                synced_remaining_owed: Uint128::new(150),
+                batch_start_height: 1,
+            },
+        )
+        .unwrap();
+        let messages = write_through_payable(
+            deps.as_mut(),
+            &ProvenanceUtilImpl,
+            &attribute,
+            DEFAULT_CONTRACT_NAME,
+        )
+        .expect("the payable's attribute should be written through");
+        assert_eq!(
+            2,
+            messages.len(),
+            "a delete and an add message should be produced",
+        );
+        assert!(
+            load_payment_batch(deps.as_ref().storage, &attribute.payable_uuid)
+                .unwrap()
+                .is_none(),
+            "the payment batch should be cleared once the attribute is written through",
+        );
+    }
+
+    #[test]
+    fn test_upsert_attribute_to_scope_uses_configured_msg_version() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                provenance_msg_version: Some(ProvenanceMsgVersion::V2),
+                ..Default::default()
+            },
+        );
+        let attribute = default_attribute();
+        mock_default_scope_attribute(&mut deps, &attribute);
+        let messages = ProvenanceUtilImpl
+            .upsert_attribute_to_scope(&deps.as_ref(), &attribute, DEFAULT_CONTRACT_NAME)
+            .expect("the payable's own attribute should be replaced");
+        assert_eq!(
+            1,
+            messages.to_vec().len(),
+            "a single update message should be produced for v2",
+        );
+    }
+
+    #[test]
+    fn test_upsert_attribute_to_scope_rejects_foreign_attribute() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let attribute = default_attribute();
+        mock_default_scope_attribute(
+            &mut deps,
+            &PayableScopeAttribute {
+                payable_uuid: "4cb54a6e-83ab-11ec-a486-eb4f069082c5".to_string(),
+                ..attribute.clone()
+            },
+        );
+        let error = ProvenanceUtilImpl
+            .upsert_attribute_to_scope(&deps.as_ref(), &attribute, DEFAULT_CONTRACT_NAME)
+            .err()
+            .expect("an attribute for another payable should cause a failure");
+        assert!(
+            matches!(error, ContractError::ScopeAttributeMismatch { .. }),
+            "an attribute for another payable should not be deleted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_upsert_attribute_to_scope_rejects_corrupted_attribute() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        deps.querier.with_attributes(
+            DEFAULT_SCOPE_ID,
+            &[(DEFAULT_CONTRACT_NAME, "{\"unrelated\":true}", "json")],
+        );
+        let error = ProvenanceUtilImpl
+            .upsert_attribute_to_scope(&deps.as_ref(), &default_attribute(), DEFAULT_CONTRACT_NAME)
+            .err()
+            .expect("a corrupted attribute should cause a failure");
+        assert!(
+            matches!(error, ContractError::ScopeAttributeMismatch { .. }),
+            "an attribute that is not a payable attribute should not be deleted, but got: {:?}",
+            error,
+        );
+    }
+
+    fn default_attribute() -> PayableScopeAttribute {
+        TestRegisterPayable::default_register_payable().to_scope_attribute()
     }
 }
@@ -0,0 +1,147 @@
+use crate::core::state::{record_payable_snapshot, PayableScopeAttribute};
+use crate::execute::subscribe::get_subscriber_attributes;
+use crate::util::constants::{ORACLE_ADDRESS_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY};
+use cosmwasm_std::{Attribute, BlockInfo, CosmosMsg, Event, Response, StdResult, Storage};
+use provwasm_std::ProvenanceMsg;
+
+/// The attributes that identify a payable in the response of every execute that acts on it, so that
+/// indexers can always attribute an event to its payable, payable type, and oracle.
+pub fn payable_attributes(scope_attribute: &PayableScopeAttribute) -> Vec<Attribute> {
+    vec![
+        Attribute::new(PAYABLE_TYPE_KEY, &scope_attribute.payable_type),
+        Attribute::new(PAYABLE_UUID_KEY, &scope_attribute.payable_uuid),
+        Attribute::new(ORACLE_ADDRESS_KEY, scope_attribute.oracle_address.as_str()),
+    ]
+}
+
+/// Assembles the response of the instantiate, migrate, and execute handlers.  Handlers that act on a payable start from
+/// for_payable, which emits the standard payable attributes exactly once, and add the messages,
+/// events, and attributes specific to their action on top of it.
+#[derive(Default)]
+pub struct ResponseBuilder {
+    response: Response<ProvenanceMsg>,
+}
+impl ResponseBuilder {
+    pub fn new() -> ResponseBuilder {
+        ResponseBuilder::default()
+    }
+
+    /// Starts a response for an action taken on the given payable.
+    pub fn for_payable(scope_attribute: &PayableScopeAttribute) -> ResponseBuilder {
+        ResponseBuilder::new().attributes(payable_attributes(scope_attribute))
+    }
+
+    pub fn attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> ResponseBuilder {
+        self.response = self.response.add_attribute(key, value);
+        self
+    }
+
+    pub fn attributes<A: Into<Attribute>>(
+        mut self,
+        attributes: impl IntoIterator<Item = A>,
+    ) -> ResponseBuilder {
+        self.response = self.response.add_attributes(attributes);
+        self
+    }
+
+    /// Adds the attribute only when a value is present.
+    pub fn optional_attribute(
+        self,
+        key: impl Into<String>,
+        value: Option<impl Into<String>>,
+    ) -> ResponseBuilder {
+        match value {
+            Some(value) => self.attribute(key, value),
+            None => self,
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<CosmosMsg<ProvenanceMsg>>) -> ResponseBuilder {
+        self.response = self.response.add_message(message);
+        self
+    }
+
+    pub fn messages<M: Into<CosmosMsg<ProvenanceMsg>>>(
+        mut self,
+        messages: impl IntoIterator<Item = M>,
+    ) -> ResponseBuilder {
+        self.response = self.response.add_messages(messages);
+        self
+    }
+
+    pub fn event(mut self, event: Event) -> ResponseBuilder {
+        self.response = self.response.add_event(event);
+        self
+    }
+
+    /// Notifies the payable's subscribers of the action through the subscriber attributes.
+    pub fn notify_subscribers(
+        self,
+        storage: &dyn Storage,
+        payable_uuid: &str,
+    ) -> StdResult<ResponseBuilder> {
+        Ok(self.attributes(get_subscriber_attributes(storage, payable_uuid)?))
+    }
+
+    /// Records the payable's state after the action to its snapshot audit log, from which payable
+    /// diffs are served.
+    pub fn audit(
+        self,
+        storage: &mut dyn Storage,
+        scope_attribute: &PayableScopeAttribute,
+        block: &BlockInfo,
+    ) -> StdResult<ResponseBuilder> {
+        record_payable_snapshot(storage, scope_attribute, block)?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> Response<ProvenanceMsg> {
+        self.response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_payable_by_uuid::query_payable_attribute_by_uuid;
+    use crate::testutil::register_payable_helpers::{test_register_payable, TestRegisterPayable};
+    use crate::testutil::test_utilities::{
+        setup_test_suite, InstArgs, DEFAULT_ORACLE_ADDRESS, DEFAULT_PAYABLE_TYPE,
+        DEFAULT_PAYABLE_UUID,
+    };
+    use crate::util::constants::{ORACLE_ADDRESS_KEY, PAYABLE_TYPE_KEY, PAYABLE_UUID_KEY};
+    use crate::util::response_builder::ResponseBuilder;
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn test_for_payable_emits_standard_attributes_once() {
+        let mut deps = mock_dependencies(&[]);
+        let provenance_util = setup_test_suite(&mut deps, InstArgs::default());
+        test_register_payable(&mut deps, &provenance_util, TestRegisterPayable::default()).unwrap();
+        let scope_attribute =
+            query_payable_attribute_by_uuid(&deps.as_ref(), DEFAULT_PAYABLE_UUID).unwrap();
+        let response = ResponseBuilder::for_payable(&scope_attribute)
+            .attribute("action", "test")
+            .optional_attribute("absent", None::<String>)
+            .build();
+        let values_for = |key: &str| {
+            response
+                .attributes
+                .iter()
+                .filter(|attr| attr.key == key)
+                .map(|attr| attr.value.as_str())
+                .collect::<Vec<&str>>()
+        };
+        assert_eq!(vec![DEFAULT_PAYABLE_TYPE], values_for(PAYABLE_TYPE_KEY));
+        assert_eq!(vec![DEFAULT_PAYABLE_UUID], values_for(PAYABLE_UUID_KEY));
+        assert_eq!(vec![DEFAULT_ORACLE_ADDRESS], values_for(ORACLE_ADDRESS_KEY));
+        assert_eq!(vec!["test"], values_for("action"));
+        assert!(
+            values_for("absent").is_empty(),
+            "missing optional values should not be emitted",
+        );
+    }
+}
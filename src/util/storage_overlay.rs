@@ -0,0 +1,107 @@
+use cosmwasm_std::{Order, Record, Storage};
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+/// A writable view over read-only storage that holds every write in memory, so that execute logic
+/// can be run from a query without changing contract state.  Reads see the pending writes layered
+/// over the underlying storage, and the writes are discarded when the overlay is dropped.
+pub struct StorageOverlay<'a> {
+    base: &'a dyn Storage,
+    // Pending writes by key.  A None value marks a key that has been removed
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+impl<'a> StorageOverlay<'a> {
+    pub fn new(base: &'a dyn Storage) -> StorageOverlay<'a> {
+        StorageOverlay {
+            base,
+            writes: BTreeMap::new(),
+        }
+    }
+}
+impl Storage for StorageOverlay<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    /// Merges the underlying range with the pending writes that fall within it.  The underlying
+    /// range is read in full before iteration, which is acceptable for the bounded prefix ranges
+    /// that execute logic reads.
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        let mut merged = self
+            .base
+            .range(start, end, Order::Ascending)
+            .collect::<BTreeMap<Vec<u8>, Vec<u8>>>();
+        let bounds = (
+            start.map_or(Bound::Unbounded, |start| Bound::Included(start.to_vec())),
+            end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+        );
+        for (key, value) in self.writes.range(bounds) {
+            match value {
+                Some(value) => merged.insert(key.clone(), value.clone()),
+                None => merged.remove(key),
+            };
+        }
+        match order {
+            Order::Ascending => Box::new(merged.into_iter()),
+            Order::Descending => Box::new(merged.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::storage_overlay::StorageOverlay;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{Order, Storage};
+
+    #[test]
+    fn test_overlay_writes_do_not_reach_base_storage() {
+        let mut base = MockStorage::new();
+        base.set(b"a", b"1");
+        base.set(b"b", b"2");
+        base.set(b"d", b"4");
+        let mut overlay = StorageOverlay::new(&base);
+        overlay.set(b"a", b"10");
+        overlay.set(b"c", b"3");
+        overlay.remove(b"b");
+        assert_eq!(Some(b"10".to_vec()), overlay.get(b"a"));
+        assert_eq!(None, overlay.get(b"b"));
+        assert_eq!(Some(b"3".to_vec()), overlay.get(b"c"));
+        assert_eq!(
+            vec![b"a".to_vec(), b"c".to_vec(), b"d".to_vec()],
+            overlay
+                .range(None, None, Order::Ascending)
+                .map(|(key, _)| key)
+                .collect::<Vec<Vec<u8>>>(),
+            "pending writes and removals should be merged into ranges",
+        );
+        assert_eq!(
+            vec![b"c".to_vec(), b"a".to_vec()],
+            overlay
+                .range(Some(b"a"), Some(b"d"), Order::Descending)
+                .map(|(key, _)| key)
+                .collect::<Vec<Vec<u8>>>(),
+            "ranges should respect their bounds and order",
+        );
+        drop(overlay);
+        assert_eq!(Some(b"1".to_vec()), base.get(b"a"));
+        assert_eq!(Some(b"2".to_vec()), base.get(b"b"));
+        assert_eq!(None, base.get(b"c"));
+    }
+}
@@ -1,7 +1,18 @@
 use crate::core::error::ContractError;
+use crate::validation::FieldValidation;
 
 /// Defines a self-validating contract message. The response should create a ContractError if any
 /// provided fields are improperly-formatted.
 pub trait ValidatedMsg {
     fn validate(&self) -> Result<(), ContractError>;
 }
+impl<T: FieldValidation> ValidatedMsg for T {
+    fn validate(&self) -> Result<(), ContractError> {
+        let invalid_fields = self.invalid_fields();
+        if !invalid_fields.is_empty() {
+            ContractError::invalid_fields(invalid_fields).to_result()
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! Validation rules for the fields of the contract's execute messages.
+
+use crate::validation::{
+    is_fee_split_exact, is_valid_bech32, is_valid_denom, is_valid_uuid, DECIMAL_ONE,
+    SCOPE_BECH32_PREFIX,
+};
+use std::collections::BTreeSet;
+
+/// The longest external reference id that an oracle can attach to its approval.
+pub const MAX_EXTERNAL_REFERENCE_ID_LENGTH: usize = 128;
+/// The highest risk score that an oracle can assign a payable.
+pub const MAX_RISK_SCORE: u32 = 1000;
+/// The longest memo that a payer can attach to a payment.
+pub const MAX_PAYMENT_MEMO_LENGTH: usize = 256;
+/// The largest number of payables that can be paid in a single batch payment.
+pub const MAX_BATCH_PAYMENTS: usize = 25;
+/// The largest number of decimal places that denom display metadata can declare.
+pub const MAX_DENOM_DISPLAY_EXPONENT: u32 = 18;
+/// The longest external ledger reference that can be attached to an external settlement.
+pub const MAX_EXTERNAL_SETTLEMENT_REFERENCE_LENGTH: usize = 128;
+/// The largest number of payables that a single bulk oracle reassignment can scan.
+pub const MAX_REASSIGN_ORACLE_LIMIT: u32 = 100;
+/// The largest number of flagged payers that the quarantine heuristics can hold, as every payment
+/// is screened against them.
+pub const MAX_QUARANTINE_FLAGGED_PAYERS: usize = 100;
+/// The largest number of risk bands that the contract can hold, as every payment is keyed off them.
+pub const MAX_RISK_BANDS: usize = 20;
+/// The largest number of partners that can be allowlisted as referrers.
+pub const MAX_REFERRERS: usize = 100;
+/// The largest share of a collected fee that can be routed to a referrer, in basis points.
+pub const MAX_REFERRAL_FEE_SHARE_BPS: u64 = 10_000;
+/// The shortest interval between scheduled pulls of a payment allowance, in seconds.
+pub const MIN_ALLOWANCE_PULL_INTERVAL_SECONDS: u64 = 3600;
+/// The largest number of payables whose escrowed payments can be netted in a single settlement.
+pub const MAX_NETTING_PAYABLES: usize = 25;
+
+/// Collects the names of all fields that are invalid for a message that identifies a payable
+/// alongside a single required text field, ex: the reason for writing the payable off.
+pub fn invalid_payable_text_fields(
+    payable_uuid: &str,
+    text: &str,
+    text_field: &'static str,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if text.is_empty() {
+        invalid_fields.push(text_field);
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for changing a payable's total.
+pub fn invalid_payable_total_fields(payable_uuid: &str, new_total: u128) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if new_total == 0 {
+        invalid_fields.push("new_total");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for approving a payable, or renewing its
+/// approval.
+pub fn invalid_oracle_approval_fields(
+    payable_uuid: &str,
+    external_reference_id: Option<&str>,
+    risk_score: Option<u32>,
+    approval_valid_for: Option<u64>,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if let Some(external_reference_id) = external_reference_id {
+        if external_reference_id.is_empty()
+            || external_reference_id.len() > MAX_EXTERNAL_REFERENCE_ID_LENGTH
+        {
+            invalid_fields.push("external_reference_id");
+        }
+    }
+    if risk_score.is_some_and(|risk_score| risk_score > MAX_RISK_SCORE) {
+        invalid_fields.push("risk_score");
+    }
+    if approval_valid_for == Some(0) {
+        invalid_fields.push("approval_valid_for");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for making a payment, or proposing one.
+pub fn invalid_payment_fields(
+    payable_uuid: &str,
+    memo: Option<&str>,
+    on_behalf_of: Option<&str>,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if let Some(memo) = memo {
+        if memo.is_empty() || memo.len() > MAX_PAYMENT_MEMO_LENGTH {
+            invalid_fields.push("memo");
+        }
+    }
+    if on_behalf_of.is_some_and(|client| client.is_empty()) {
+        invalid_fields.push("on_behalf_of");
+    }
+    invalid_fields
+}
+
+/// The fields of a single payment within a batch payment.
+pub struct BatchPaymentFields<'a> {
+    pub payable_uuid: &'a str,
+    pub amount: u128,
+}
+
+/// Collects the names of all fields that are invalid for paying multiple payables in a single
+/// batch.
+pub fn invalid_batch_payment_fields(payments: &[BatchPaymentFields]) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if payments.is_empty() || payments.len() > MAX_BATCH_PAYMENTS {
+        invalid_fields.push("payments");
+    }
+    if payments
+        .iter()
+        .any(|payment| !is_valid_uuid(payment.payable_uuid))
+    {
+        invalid_fields.push("payable_uuid");
+    }
+    if payments.iter().any(|payment| payment.amount == 0) {
+        invalid_fields.push("amount");
+    }
+    // Each payment reads the payable's attribute as it was before this execution, so paying the
+    // same payable twice in one batch would apply payments to stale values
+    let unique_uuids = payments
+        .iter()
+        .map(|payment| payment.payable_uuid)
+        .collect::<BTreeSet<&str>>();
+    if unique_uuids.len() != payments.len() {
+        invalid_fields.push("payments");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for defining how a denom is displayed.
+pub fn invalid_denom_display_metadata_fields(
+    denom: &str,
+    symbol: &str,
+    exponent: u32,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_denom(denom) {
+        invalid_fields.push("denom");
+    }
+    if symbol.is_empty() {
+        invalid_fields.push("symbol");
+    }
+    if exponent > MAX_DENOM_DISPLAY_EXPONENT {
+        invalid_fields.push("exponent");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for removing orphaned attributes from scopes.
+pub fn invalid_cleanup_orphaned_attributes_fields(scope_ids: &[String]) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if scope_ids.is_empty()
+        || scope_ids
+            .iter()
+            .any(|scope_id| !is_valid_bech32(scope_id, Some(SCOPE_BECH32_PREFIX)))
+    {
+        invalid_fields.push("scope_ids");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for scheduling a fee holiday.
+pub fn invalid_fee_holiday_fields(
+    holiday_id: &str,
+    start_time: u64,
+    end_time: u64,
+    payable_types: &[String],
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if holiday_id.is_empty() {
+        invalid_fields.push("holiday_id");
+    }
+    if end_time <= start_time {
+        invalid_fields.push("end_time");
+    }
+    if payable_types
+        .iter()
+        .any(|payable_type| payable_type.is_empty())
+    {
+        invalid_fields.push("payable_types");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for patching a payable's meta.  The patch
+/// must change the scope id, as it is the only field that can be patched.
+pub fn invalid_patch_payable_meta_fields(
+    payable_uuid: &str,
+    patch_scope_id: Option<&str>,
+    reason: &str,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if !patch_scope_id.is_some_and(|scope_id| is_valid_bech32(scope_id, Some(SCOPE_BECH32_PREFIX)))
+    {
+        invalid_fields.push("patch");
+    }
+    if reason.is_empty() {
+        invalid_fields.push("reason");
+    }
+    invalid_fields
+}
+
+/// The fields of a payable type config.
+pub struct PayableTypeConfigFields<'a> {
+    pub payable_type: &'a str,
+    pub onboarding_cost: u128,
+    pub onboarding_denom: &'a str,
+    pub fee_percent: u128,
+    pub default_oracle: Option<&'a str>,
+    // Whether the registrant requirement names the unspecified party type, which no scope owner
+    // can hold
+    pub requires_unspecified_owner_role: bool,
+}
+
+/// Collects the names of all fields that are invalid for configuring a payable type.
+pub fn invalid_payable_type_config_fields(config: &PayableTypeConfigFields) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if config.payable_type.is_empty() {
+        invalid_fields.push("payable_type");
+    }
+    if !is_valid_denom(config.onboarding_denom) {
+        invalid_fields.push("onboarding_denom");
+    }
+    if config.fee_percent > DECIMAL_ONE
+        || !is_fee_split_exact(config.onboarding_cost, config.fee_percent)
+    {
+        invalid_fields.push("fee_percent");
+    }
+    if config
+        .default_oracle
+        .is_some_and(|oracle| oracle.is_empty())
+    {
+        invalid_fields.push("default_oracle");
+    }
+    if config.requires_unspecified_owner_role {
+        invalid_fields.push("registrant_requirement");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for capping the payment volume of a denom.
+pub fn invalid_payment_volume_cap_fields(
+    denom: &str,
+    max_volume: u128,
+    window_seconds: u64,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_denom(denom) {
+        invalid_fields.push("denom");
+    }
+    if max_volume == 0 {
+        invalid_fields.push("max_volume");
+    }
+    if window_seconds == 0 {
+        invalid_fields.push("window_seconds");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for recording a settlement made outside of the
+/// chain.
+pub fn invalid_external_settlement_fields(
+    payable_uuid: &str,
+    amount: u128,
+    reference: &str,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if amount == 0 {
+        invalid_fields.push("amount");
+    }
+    if reference.is_empty() || reference.len() > MAX_EXTERNAL_SETTLEMENT_REFERENCE_LENGTH {
+        invalid_fields.push("reference");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for resolving a dispute.
+pub fn invalid_resolve_dispute_fields(
+    payable_uuid: &str,
+    resolution: &str,
+    adjusted_total: Option<u128>,
+) -> Vec<&'static str> {
+    let mut invalid_fields = invalid_payable_text_fields(payable_uuid, resolution, "resolution");
+    if adjusted_total == Some(0) {
+        invalid_fields.push("adjusted_total");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for reassigning the payables of one oracle to
+/// another.
+pub fn invalid_reassign_oracle_bulk_fields(
+    old_oracle_address: &str,
+    new_oracle_address: &str,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if old_oracle_address.is_empty() {
+        invalid_fields.push("old_oracle_address");
+    }
+    if new_oracle_address.is_empty() || new_oracle_address == old_oracle_address {
+        invalid_fields.push("new_oracle_address");
+    }
+    if start_after.is_some_and(|payable_uuid| !is_valid_uuid(payable_uuid)) {
+        invalid_fields.push("start_after");
+    }
+    if limit.is_some_and(|limit| limit == 0 || limit > MAX_REASSIGN_ORACLE_LIMIT) {
+        invalid_fields.push("limit");
+    }
+    invalid_fields
+}
+
+/// The fields of a large payment threshold for a single denom.
+pub struct ThresholdFields<'a> {
+    pub denom: &'a str,
+    pub amount: u128,
+}
+
+/// Collects the names of all fields that are invalid for configuring payment quarantine.
+pub fn invalid_quarantine_config_fields(
+    flagged_payers: &[String],
+    large_payment_thresholds: &[ThresholdFields],
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if flagged_payers.len() > MAX_QUARANTINE_FLAGGED_PAYERS
+        || flagged_payers.iter().any(|payer| payer.is_empty())
+        || flagged_payers.iter().collect::<BTreeSet<&String>>().len() != flagged_payers.len()
+    {
+        invalid_fields.push("flagged_payers");
+    }
+    // Each denom can only have a single threshold
+    let mut unique_denoms = BTreeSet::new();
+    if large_payment_thresholds.iter().any(|threshold| {
+        !is_valid_denom(threshold.denom)
+            || threshold.amount == 0
+            || !unique_denoms.insert(threshold.denom)
+    }) {
+        invalid_fields.push("large_payment_thresholds");
+    }
+    invalid_fields
+}
+
+/// The fields of a single risk band.
+pub struct RiskBandFields {
+    pub min_score: u32,
+    pub holdback_percent: Option<u128>,
+    pub large_payment_threshold: Option<u128>,
+}
+
+/// Collects the names of all fields that are invalid for replacing the contract's risk bands.
+pub fn invalid_risk_bands_fields(risk_bands: &[RiskBandFields]) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    // Each band must apply at least one rule, and no two bands can share a minimum
+    let mut unique_min_scores = BTreeSet::new();
+    if risk_bands.len() > MAX_RISK_BANDS
+        || risk_bands.iter().any(|band| {
+            band.min_score > MAX_RISK_SCORE
+                || !unique_min_scores.insert(band.min_score)
+                || (band.holdback_percent.is_none() && band.large_payment_threshold.is_none())
+                || band
+                    .holdback_percent
+                    .is_some_and(|percent| percent == 0 || percent >= DECIMAL_ONE)
+                || band.large_payment_threshold == Some(0)
+        })
+    {
+        invalid_fields.push("risk_bands");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for replacing the contract's referral config.
+pub fn invalid_referral_config_fields(
+    fee_share_bps: u64,
+    referrers: &[String],
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    // A share is only required while referrals are enabled
+    if fee_share_bps > MAX_REFERRAL_FEE_SHARE_BPS || (fee_share_bps == 0 && !referrers.is_empty()) {
+        invalid_fields.push("fee_share_bps");
+    }
+    if referrers.len() > MAX_REFERRERS
+        || referrers.iter().any(|referrer| referrer.is_empty())
+        || referrers.iter().collect::<BTreeSet<&String>>().len() != referrers.len()
+    {
+        invalid_fields.push("referrers");
+    }
+    invalid_fields
+}
+
+/// The fields of a payment allowance grant.
+pub struct PaymentAllowanceFields<'a> {
+    pub payable_uuid: &'a str,
+    pub denom: &'a str,
+    pub max_total: u128,
+    pub pull_amount: u128,
+    pub pull_interval_seconds: u64,
+    pub first_pull_at: Option<u64>,
+    pub expires_at: Option<u64>,
+}
+
+/// Collects the names of all fields that are invalid for granting a payment allowance.
+pub fn invalid_payment_allowance_fields(allowance: &PaymentAllowanceFields) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(allowance.payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if !is_valid_denom(allowance.denom) {
+        invalid_fields.push("denom");
+    }
+    if allowance.max_total == 0 {
+        invalid_fields.push("max_total");
+    }
+    if allowance.pull_amount == 0 || allowance.pull_amount > allowance.max_total {
+        invalid_fields.push("pull_amount");
+    }
+    if allowance.pull_interval_seconds < MIN_ALLOWANCE_PULL_INTERVAL_SECONDS {
+        invalid_fields.push("pull_interval_seconds");
+    }
+    if let (Some(first_pull_at), Some(expires_at)) = (allowance.first_pull_at, allowance.expires_at)
+    {
+        if expires_at <= first_pull_at {
+            invalid_fields.push("expires_at");
+        }
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for netting the escrowed payments of the
+/// given payables.
+pub fn invalid_settle_netting_fields(payable_uuids: &[String]) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    // Netting a payable twice would count its escrowed payments twice
+    if payable_uuids.is_empty()
+        || payable_uuids.len() > MAX_NETTING_PAYABLES
+        || payable_uuids.iter().collect::<BTreeSet<&String>>().len() != payable_uuids.len()
+    {
+        invalid_fields.push("payable_uuids");
+    }
+    if payable_uuids
+        .iter()
+        .any(|payable_uuid| !is_valid_uuid(payable_uuid))
+    {
+        invalid_fields.push("payable_uuid");
+    }
+    invalid_fields
+}
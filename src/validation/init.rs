@@ -0,0 +1,222 @@
+//! Validation rules for the contract configuration set by instantiation and migration.
+
+use crate::validation::{is_fee_split_exact, is_valid_denom, DECIMAL_ONE};
+
+/// The fields of a payment batch window.
+pub struct PaymentBatchWindowFields {
+    pub change_threshold: u128,
+    pub block_interval: u64,
+}
+impl PaymentBatchWindowFields {
+    /// A zero threshold or interval would write on every payment, and a threshold above 100% could
+    /// never be crossed, so neither is considered a valid window.
+    pub fn is_valid(&self) -> bool {
+        self.change_threshold != 0
+            && self.change_threshold <= DECIMAL_ONE
+            && self.block_interval > 0
+    }
+}
+
+/// The fields of an oracle funds tolerance.
+pub struct FundsToleranceFields<'a> {
+    pub denom: &'a str,
+    pub max_amount: u128,
+}
+impl FundsToleranceFields<'_> {
+    pub fn is_valid(&self) -> bool {
+        is_valid_denom(self.denom) && self.max_amount != 0
+    }
+}
+
+/// The fields of an InitMsg.
+pub struct InitFields<'a> {
+    pub contract_name: &'a str,
+    pub onboarding_cost: &'a str,
+    pub onboarding_denom: &'a str,
+    pub fee_collection_address: &'a str,
+    pub fee_percent: u128,
+    pub payment_batch_window: Option<PaymentBatchWindowFields>,
+    pub migration_timelock_seconds: Option<u64>,
+    pub oracle_funds_tolerance: Option<FundsToleranceFields<'a>>,
+    pub minimum_payment_amount: Option<u128>,
+    pub approval_valid_duration: Option<u64>,
+    pub oracle_approval_sla_seconds: Option<u64>,
+    pub large_payment_threshold: Option<u128>,
+    pub payment_reversal_window_seconds: Option<u64>,
+    pub authorized_registrars: Option<&'a [String]>,
+}
+
+/// Collects the names of all fields that are invalid for instantiating the contract.
+pub fn invalid_init_fields(init: &InitFields) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if init.contract_name.is_empty() {
+        invalid_fields.push("contract_name");
+    }
+    if init.onboarding_cost.parse::<u128>().is_err() {
+        invalid_fields.push("onboarding_cost");
+    }
+    if !is_valid_denom(init.onboarding_denom) {
+        invalid_fields.push("onboarding_denom");
+    }
+    if init.fee_collection_address.is_empty() {
+        invalid_fields.push("fee_collection_address");
+    }
+    if init.fee_percent > DECIMAL_ONE {
+        invalid_fields.push("fee_percent");
+    } else if let Ok(onboarding_cost) = init.onboarding_cost.parse::<u128>() {
+        if !is_fee_split_exact(onboarding_cost, init.fee_percent) {
+            invalid_fields.push("fee_percent");
+        }
+    }
+    if let Some(window) = &init.payment_batch_window {
+        if !window.is_valid() {
+            invalid_fields.push("payment_batch_window");
+        }
+    }
+    if init.migration_timelock_seconds == Some(0) {
+        invalid_fields.push("migration_timelock_seconds");
+    }
+    if let Some(tolerance) = &init.oracle_funds_tolerance {
+        if !tolerance.is_valid() {
+            invalid_fields.push("oracle_funds_tolerance");
+        }
+    }
+    if init.minimum_payment_amount == Some(0) {
+        invalid_fields.push("minimum_payment_amount");
+    }
+    if init.approval_valid_duration == Some(0) {
+        invalid_fields.push("approval_valid_duration");
+    }
+    if init.oracle_approval_sla_seconds == Some(0) {
+        invalid_fields.push("oracle_approval_sla_seconds");
+    }
+    if init.large_payment_threshold == Some(0) {
+        invalid_fields.push("large_payment_threshold");
+    }
+    if init.payment_reversal_window_seconds == Some(0) {
+        invalid_fields.push("payment_reversal_window_seconds");
+    }
+    if let Some(registrars) = init.authorized_registrars {
+        if registrars.iter().any(|registrar| registrar.is_empty()) {
+            invalid_fields.push("authorized_registrars");
+        }
+    }
+    invalid_fields
+}
+
+/// The fields of a MigrateMsg.  Each disable flag is only considered set when it is true.
+pub struct MigrateFields<'a> {
+    pub onboarding_cost: Option<&'a str>,
+    pub onboarding_denom: Option<&'a str>,
+    pub fee_collection_address: Option<&'a str>,
+    pub fee_percent: Option<u128>,
+    pub payment_batch_window: Option<PaymentBatchWindowFields>,
+    pub disable_payment_batching: bool,
+    pub admin_address: Option<&'a str>,
+    pub migration_timelock_seconds: Option<u64>,
+    pub disable_migration_timelock: bool,
+    pub oracle_funds_tolerance: Option<FundsToleranceFields<'a>>,
+    pub disable_oracle_funds_tolerance: bool,
+    pub minimum_payment_amount: Option<u128>,
+    pub disable_minimum_payment: bool,
+    pub settled_retention_seconds: Option<u64>,
+    pub disable_settled_pruning: bool,
+    pub approval_valid_duration: Option<u64>,
+    pub disable_approval_expiry: bool,
+    pub oracle_approval_sla_seconds: Option<u64>,
+    pub disable_oracle_approval_sla: bool,
+    pub large_payment_threshold: Option<u128>,
+    pub disable_large_payment_threshold: bool,
+    pub payment_reversal_window_seconds: Option<u64>,
+    pub disable_payment_reversal: bool,
+    pub authorized_registrars: Option<&'a [String]>,
+}
+
+/// Collects the names of all fields that are invalid for migrating the contract, or for staging the
+/// params of a later migration.
+pub fn invalid_migrate_fields(migrate: &MigrateFields) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if let Some(cost) = migrate.onboarding_cost {
+        if cost.parse::<u128>().is_err() {
+            invalid_fields.push("onboarding_cost");
+        }
+    }
+    if let Some(denom) = migrate.onboarding_denom {
+        if !is_valid_denom(denom) {
+            invalid_fields.push("onboarding_denom");
+        }
+    }
+    if let Some(fee_addr) = migrate.fee_collection_address {
+        if fee_addr.is_empty() {
+            invalid_fields.push("fee_collection_address");
+        }
+    }
+    if let Some(admin_addr) = migrate.admin_address {
+        if admin_addr.is_empty() {
+            invalid_fields.push("admin_address");
+        }
+    }
+    if let Some(fee_percent) = migrate.fee_percent {
+        if fee_percent > DECIMAL_ONE {
+            invalid_fields.push("fee_percent");
+        }
+    }
+    if let Some(window) = &migrate.payment_batch_window {
+        // Enabling and disabling the batch window in the same migration is contradictory
+        if !window.is_valid() || migrate.disable_payment_batching {
+            invalid_fields.push("payment_batch_window");
+        }
+    }
+    if let Some(timelock_seconds) = migrate.migration_timelock_seconds {
+        // Enabling and disabling the timelock in the same migration is contradictory
+        if timelock_seconds == 0 || migrate.disable_migration_timelock {
+            invalid_fields.push("migration_timelock_seconds");
+        }
+    }
+    if let Some(tolerance) = &migrate.oracle_funds_tolerance {
+        // Enabling and disabling the tolerance in the same migration is contradictory
+        if !tolerance.is_valid() || migrate.disable_oracle_funds_tolerance {
+            invalid_fields.push("oracle_funds_tolerance");
+        }
+    }
+    if let Some(minimum_payment_amount) = migrate.minimum_payment_amount {
+        // Enabling and disabling the minimum in the same migration is contradictory
+        if minimum_payment_amount == 0 || migrate.disable_minimum_payment {
+            invalid_fields.push("minimum_payment_amount");
+        }
+    }
+    // Enabling and disabling pruning in the same migration is contradictory
+    if migrate.settled_retention_seconds.is_some() && migrate.disable_settled_pruning {
+        invalid_fields.push("settled_retention_seconds");
+    }
+    if let Some(approval_valid_duration) = migrate.approval_valid_duration {
+        // Enabling and disabling approval expiry in the same migration is contradictory
+        if approval_valid_duration == 0 || migrate.disable_approval_expiry {
+            invalid_fields.push("approval_valid_duration");
+        }
+    }
+    if let Some(oracle_approval_sla_seconds) = migrate.oracle_approval_sla_seconds {
+        // Setting and removing the approval SLA in the same migration is contradictory
+        if oracle_approval_sla_seconds == 0 || migrate.disable_oracle_approval_sla {
+            invalid_fields.push("oracle_approval_sla_seconds");
+        }
+    }
+    if let Some(large_payment_threshold) = migrate.large_payment_threshold {
+        // Setting and removing the threshold in the same migration is contradictory
+        if large_payment_threshold == 0 || migrate.disable_large_payment_threshold {
+            invalid_fields.push("large_payment_threshold");
+        }
+    }
+    if let Some(window_seconds) = migrate.payment_reversal_window_seconds {
+        // Setting and removing the reversal window in the same migration is contradictory
+        if window_seconds == 0 || migrate.disable_payment_reversal {
+            invalid_fields.push("payment_reversal_window_seconds");
+        }
+    }
+    if let Some(registrars) = migrate.authorized_registrars {
+        if registrars.iter().any(|registrar| registrar.is_empty()) {
+            invalid_fields.push("authorized_registrars");
+        }
+    }
+    invalid_fields
+}
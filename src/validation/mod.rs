@@ -0,0 +1,291 @@
+//! Pure validation rules for the contract's messages.  Everything in this module operates on
+//! borrowed data and only uses functionality available in `core` and `alloc`, so it can be compiled
+//! into a wasm-bindgen wrapper (or any no-std consumer) to run the exact same checks client-side
+//! before a message is ever broadcast.  The module is always compiled, while the rest of the crate
+//! requires the contract feature, so it can never come to depend on cosmwasm or provwasm.
+//!
+//! The rules take each message's fields as plain values: amounts as u128, times as nanoseconds
+//! since the epoch, and decimals as their atomics with 18 decimal places.
+
+pub mod execute;
+pub mod init;
+pub mod query;
+pub mod register;
+
+/// The human-readable prefix used by all Provenance metadata scope addresses.
+pub const SCOPE_BECH32_PREFIX: &str = "scope";
+
+/// Defines a contract message whose fields can be checked without access to the chain.  Every
+/// improperly-formatted field is named in the result, which is empty when the message is valid.
+/// The contract's ValidatedMsg is derived from this, so the on-chain and client-side checks
+/// cannot drift apart.
+pub trait FieldValidation {
+    fn invalid_fields(&self) -> Vec<&'static str>;
+}
+
+/// The atomics of a decimal value of one, with 18 decimal places.
+pub const DECIMAL_ONE: u128 = 1_000_000_000_000_000_000;
+
+/// Names the payable uuid as invalid when it is not a uuid.  Shared by every message that only
+/// identifies a payable.
+pub fn invalid_payable_uuid_fields(payable_uuid: &str) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    invalid_fields
+}
+
+/// Names the denom as invalid when it is not a coin denomination.  Shared by every message that
+/// only identifies a denom.
+pub fn invalid_denom_fields(denom: &str) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_denom(denom) {
+        invalid_fields.push("denom");
+    }
+    invalid_fields
+}
+
+/// Names the field as invalid when its value is empty.  Shared by every message whose only rule is
+/// a required value, ex: the payable type that a payable type message acts on.
+pub fn invalid_empty_fields(value: &str, field: &'static str) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if value.is_empty() {
+        invalid_fields.push(field);
+    }
+    invalid_fields
+}
+
+/// Determines if the fee percent splits the onboarding cost into whole base units.  The fee portion
+/// is rounded down when charged, so a split that is not exact would silently shift sub-unit dust to
+/// the oracle on every registration.  Configuration is rejected up front instead.
+pub fn is_fee_split_exact(onboarding_cost: u128, fee_percent: u128) -> bool {
+    // Only the fractional part of the product matters, so reducing both factors first keeps the
+    // multiplication within a u128
+    ((onboarding_cost % DECIMAL_ONE) * (fee_percent % DECIMAL_ONE)).is_multiple_of(DECIMAL_ONE)
+}
+
+pub(crate) const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_GENERATORS: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+const BECH32_CHECKSUM_LENGTH: usize = 6;
+const BECH32_MAX_LENGTH: usize = 90;
+const DENOM_MIN_LENGTH: usize = 3;
+const DENOM_MAX_LENGTH: usize = 128;
+const UUID_LENGTH: usize = 36;
+const UUID_HYPHEN_INDICES: [usize; 4] = [8, 13, 18, 23];
+
+/// Determines if the value is a hyphenated UUID string, ex: 200425c6-83ab-11ec-a486-eb4f069082c5.
+/// Both upper and lowercase hex characters are accepted.
+pub fn is_valid_uuid(value: &str) -> bool {
+    value.len() == UUID_LENGTH
+        && value.bytes().enumerate().all(|(index, byte)| {
+            if UUID_HYPHEN_INDICES.contains(&index) {
+                byte == b'-'
+            } else {
+                byte.is_ascii_hexdigit()
+            }
+        })
+}
+
+/// Determines if the value is a coin denomination accepted by the Cosmos SDK, which requires a
+/// leading letter followed by 2 to 127 letters, digits, or any of the characters / : . _ -
+pub fn is_valid_denom(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    (DENOM_MIN_LENGTH..=DENOM_MAX_LENGTH).contains(&bytes.len())
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1..]
+            .iter()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"/:._-".contains(byte))
+}
+
+/// Determines if the value is a well-formed bech32 string with a valid checksum.  When an
+/// expected human-readable prefix is provided (ex: "scope" or "tp"), the value must also use that
+/// prefix.
+pub fn is_valid_bech32(value: &str, expected_prefix: Option<&str>) -> bool {
+    if value.len() > BECH32_MAX_LENGTH || !value.is_ascii() {
+        return false;
+    }
+    // Mixed case strings are explicitly disallowed by the bech32 specification
+    if value.bytes().any(|byte| byte.is_ascii_uppercase())
+        && value.bytes().any(|byte| byte.is_ascii_lowercase())
+    {
+        return false;
+    }
+    let separator_index = match value.rfind('1') {
+        Some(index) => index,
+        None => return false,
+    };
+    let (prefix, data) = (&value[..separator_index], &value[separator_index + 1..]);
+    if prefix.is_empty() || data.len() < BECH32_CHECKSUM_LENGTH {
+        return false;
+    }
+    if let Some(expected_prefix) = expected_prefix {
+        if !prefix.eq_ignore_ascii_case(expected_prefix) {
+            return false;
+        }
+    }
+    if prefix.bytes().any(|byte| !(33..=126).contains(&byte)) {
+        return false;
+    }
+    let mut checksum: u32 = 1;
+    for byte in prefix.bytes() {
+        checksum = bech32_polymod_step(checksum, byte.to_ascii_lowercase() >> 5);
+    }
+    checksum = bech32_polymod_step(checksum, 0);
+    for byte in prefix.bytes() {
+        checksum = bech32_polymod_step(checksum, byte.to_ascii_lowercase() & 31);
+    }
+    for byte in data.bytes() {
+        match BECH32_CHARSET
+            .iter()
+            .position(|charset_byte| *charset_byte == byte.to_ascii_lowercase())
+        {
+            Some(value) => checksum = bech32_polymod_step(checksum, value as u8),
+            None => return false,
+        }
+    }
+    checksum == 1
+}
+
+pub(crate) fn bech32_polymod_step(checksum: u32, value: u8) -> u32 {
+    let top = checksum >> 25;
+    let mut checksum = ((checksum & 0x01ff_ffff) << 5) ^ value as u32;
+    for (index, generator) in BECH32_GENERATORS.iter().enumerate() {
+        if (top >> index) & 1 == 1 {
+            checksum ^= generator;
+        }
+    }
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::{
+        is_fee_split_exact, is_valid_bech32, is_valid_denom, is_valid_uuid, DECIMAL_ONE,
+    };
+
+    #[test]
+    fn test_valid_uuids() {
+        assert!(is_valid_uuid("200425c6-83ab-11ec-a486-eb4f069082c5"));
+        assert!(is_valid_uuid("200425C6-83AB-11EC-A486-EB4F069082C5"));
+    }
+
+    #[test]
+    fn test_invalid_uuids() {
+        assert!(!is_valid_uuid(""), "an empty string is not a uuid");
+        assert!(
+            !is_valid_uuid("200425c683ab11eca486eb4f069082c5"),
+            "an unhyphenated uuid should be rejected",
+        );
+        assert!(
+            !is_valid_uuid("200425c6-83ab-11ec-a486-eb4f069082cz"),
+            "non-hex characters should be rejected",
+        );
+        assert!(
+            !is_valid_uuid("200425c6-83ab-11ec-a486-eb4f069082c5a"),
+            "values of the wrong length should be rejected",
+        );
+        assert!(
+            !is_valid_uuid("200425c6-83ab-11eca-486-eb4f069082c5"),
+            "misplaced hyphens should be rejected",
+        );
+    }
+
+    #[test]
+    fn test_valid_denoms() {
+        for denom in [
+            "nhash",
+            "usdf",
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+            "pool:a-b_c.d",
+        ] {
+            assert!(
+                is_valid_denom(denom),
+                "expected denom {} to be valid",
+                denom
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_denoms() {
+        for denom in ["", "ab", "1hash", "n hash", "nhash!"] {
+            assert!(
+                !is_valid_denom(denom),
+                "expected denom {} to be invalid",
+                denom
+            );
+        }
+        assert!(
+            !is_valid_denom(&"a".repeat(129)),
+            "denoms longer than 128 characters should be rejected",
+        );
+    }
+
+    #[test]
+    fn test_is_fee_split_exact() {
+        assert!(
+            is_fee_split_exact(100, 75 * DECIMAL_ONE / 100),
+            "75% of 100 is a whole number of base units",
+        );
+        assert!(
+            !is_fee_split_exact(10, 33 * DECIMAL_ONE / 100),
+            "33% of 10 leaves a fractional base unit",
+        );
+        assert!(
+            is_fee_split_exact(u128::MAX - u128::MAX % 4, DECIMAL_ONE / 4),
+            "the largest costs should be checked without overflowing",
+        );
+        assert!(
+            !is_fee_split_exact(u128::MAX, DECIMAL_ONE / 4),
+            "the largest costs should still have their remainders detected",
+        );
+    }
+
+    #[test]
+    fn test_valid_bech32() {
+        assert!(is_valid_bech32(
+            "scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8",
+            Some("scope")
+        ));
+        assert!(is_valid_bech32(
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            None
+        ));
+        assert!(
+            is_valid_bech32("A12UEL5L", None),
+            "uppercase bech32 strings should be accepted",
+        );
+    }
+
+    #[test]
+    fn test_invalid_bech32() {
+        assert!(
+            !is_valid_bech32("scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl9", None),
+            "a bad checksum should be rejected",
+        );
+        assert!(
+            !is_valid_bech32("scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8", Some("tp")),
+            "an unexpected prefix should be rejected",
+        );
+        assert!(
+            !is_valid_bech32("Scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8", None),
+            "mixed case should be rejected",
+        );
+        assert!(
+            !is_valid_bech32("scopeqpyq6g6j0tuprmyglw0hn2czfzsq6fcyl8", None),
+            "a missing separator should be rejected",
+        );
+        assert!(
+            !is_valid_bech32("scope1qpyq6g6j0tuprmyglw0hn2czfzsq6fcylb", None),
+            "characters outside the bech32 charset should be rejected",
+        );
+        assert!(!is_valid_bech32("", None), "an empty string is not bech32");
+    }
+}
@@ -0,0 +1,121 @@
+//! Validation rules for the fields of the contract's query messages.
+
+use crate::validation::{is_valid_bech32, is_valid_denom, is_valid_uuid, SCOPE_BECH32_PREFIX};
+
+/// The largest number of payments that can be returned by a single payment export page.
+pub const MAX_EXPORT_PAYMENTS_LIMIT: u32 = 500;
+/// The largest number of overdue oracle approvals that can be returned by a single query.
+pub const MAX_OVERDUE_APPROVALS_LIMIT: u32 = 100;
+/// The largest number of payables that can be returned by a single page of registered payables.
+pub const MAX_ALL_PAYABLES_LIMIT: u32 = 100;
+/// The largest number of payments that can be returned by a single page of filtered payments.
+pub const MAX_QUERY_PAYMENTS_LIMIT: u32 = 100;
+
+/// Names the scope id as invalid when it is not the bech32 address of a scope.
+pub fn invalid_scope_id_fields(scope_id: &str) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_bech32(scope_id, Some(SCOPE_BECH32_PREFIX)) {
+        invalid_fields.push("scope_id");
+    }
+    invalid_fields
+}
+
+/// Names the limit as invalid when it is zero or above the largest page that the query can return.
+pub fn invalid_limit_fields(limit: Option<u32>, max_limit: u32) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if limit.is_some_and(|limit| limit == 0 || limit > max_limit) {
+        invalid_fields.push("limit");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for a page of payables or payments, which
+/// resumes after the payable uuid of its cursor.
+pub fn invalid_page_fields(
+    start_after_uuid: Option<&str>,
+    limit: Option<u32>,
+    max_limit: u32,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if start_after_uuid.is_some_and(|payable_uuid| !is_valid_uuid(payable_uuid)) {
+        invalid_fields.push("start_after");
+    }
+    invalid_fields.append(&mut invalid_limit_fields(limit, max_limit));
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for comparing two snapshots of a payable.
+pub fn invalid_payable_diff_fields(
+    payable_uuid: &str,
+    from_seq: u64,
+    to_seq: u64,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if to_seq < from_seq {
+        invalid_fields.push("to_seq");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for simulating a payment.
+pub fn invalid_simulate_payment_fields(
+    payable_uuid: &str,
+    amount: u128,
+    denom: &str,
+    payer: &str,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if amount == 0 {
+        invalid_fields.push("amount");
+    }
+    if !is_valid_denom(denom) {
+        invalid_fields.push("denom");
+    }
+    if payer.is_empty() {
+        invalid_fields.push("payer");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for a page of filtered payments.
+pub fn invalid_query_payments_fields(
+    payable_uuid: Option<&str>,
+    payer: Option<&str>,
+    start_after_uuid: Option<&str>,
+    limit: Option<u32>,
+) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if payable_uuid.is_some_and(|payable_uuid| !is_valid_uuid(payable_uuid)) {
+        invalid_fields.push("payable_uuid");
+    }
+    if payer.is_some_and(|payer| payer.is_empty()) {
+        invalid_fields.push("payer");
+    }
+    // A cursor into another payable's payments would resume the scan outside of the requested
+    // payable
+    if let Some(cursor_uuid) = start_after_uuid {
+        if !is_valid_uuid(cursor_uuid)
+            || payable_uuid.is_some_and(|payable_uuid| payable_uuid != cursor_uuid)
+        {
+            invalid_fields.push("start_after");
+        }
+    }
+    invalid_fields.append(&mut invalid_limit_fields(limit, MAX_QUERY_PAYMENTS_LIMIT));
+    invalid_fields
+}
+
+/// Names the identifier as invalid when it is neither a payable uuid nor the bech32 address of a
+/// scope.
+pub fn invalid_payable_identifier_fields(identifier: &str) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if !is_valid_uuid(identifier) && !is_valid_bech32(identifier, Some(SCOPE_BECH32_PREFIX)) {
+        invalid_fields.push("identifier");
+    }
+    invalid_fields
+}
@@ -0,0 +1,231 @@
+//! Validation rules for payable registrations, shared between single, batch, and re-registrations
+//! to ensure each payable is held to the same standard.
+
+use crate::validation::{
+    is_valid_bech32, is_valid_denom, is_valid_uuid, DECIMAL_ONE, SCOPE_BECH32_PREFIX,
+};
+use std::collections::BTreeSet;
+
+/// The largest custom field payload, in bytes, that can be attached to a payable at registration.
+pub const MAX_CUSTOM_FIELDS_BYTES: usize = 4096;
+/// The largest number of payees that can divide the payments of a single payable.
+pub const MAX_PAYEE_SPLITS: usize = 20;
+/// The longest nonce that a registrant can attach to a registration to identify its retries.
+pub const MAX_REGISTRATION_NONCE_LENGTH: usize = 128;
+/// The largest number of payables that can be registered in a single batch registration.
+pub const MAX_BATCH_REGISTRATIONS: usize = 50;
+
+/// The fields of a single installment of a payment schedule.
+pub struct InstallmentFields {
+    pub due_time: u64,
+    pub amount: u128,
+}
+
+/// The fields of a payable's late fee terms.
+pub struct LateFeeFields {
+    pub due_time: u64,
+    pub daily_rate: u128,
+}
+
+/// The fields of a payable's interest terms.
+pub struct InterestFields {
+    pub start_time: u64,
+    pub annual_rate: u128,
+    // Whether the contract's interest math can accrue the interest across the payable's full term.
+    // This requires the contract's decimal math, so it is determined by the caller
+    pub is_accrual_calculable: bool,
+}
+
+/// The fields of a single payee's split of a payable's payments.
+pub struct PayeeSplitFields<'a> {
+    pub address: &'a str,
+    pub weight: u64,
+}
+
+/// The fields of a payable registration.
+pub struct RegisterPayableFields<'a> {
+    pub payable_type: &'a str,
+    pub payable_uuid: &'a str,
+    pub scope_id: &'a str,
+    pub payable_denom: &'a str,
+    pub payable_total: u128,
+    pub custom_fields: Option<&'a [u8]>,
+    pub priority_fee: Option<u128>,
+    pub oracle_gas_rebate: Option<u128>,
+    pub minimum_payment_amount: Option<u128>,
+    pub expiration_time: Option<u64>,
+    pub accepted_denoms: Option<&'a [String]>,
+    pub payment_schedule: Option<Vec<InstallmentFields>>,
+    pub late_fee: Option<LateFeeFields>,
+    pub escrow_timeout_seconds: Option<u64>,
+    pub authorized_payers: Option<&'a [String]>,
+    pub interest: Option<InterestFields>,
+    pub payee_splits: Option<Vec<PayeeSplitFields<'a>>>,
+    pub registration_nonce: Option<&'a str>,
+    pub referrer: Option<&'a str>,
+}
+
+/// Collects the names of all fields that are invalid for registering the given payable.
+pub fn invalid_register_payable_fields(register: &RegisterPayableFields) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if register.payable_type.is_empty() {
+        invalid_fields.push("payable_type");
+    }
+    if !is_valid_uuid(register.payable_uuid) {
+        invalid_fields.push("payable_uuid");
+    }
+    if !is_valid_bech32(register.scope_id, Some(SCOPE_BECH32_PREFIX)) {
+        invalid_fields.push("scope_id");
+    }
+    if !is_valid_denom(register.payable_denom) {
+        invalid_fields.push("payable_denom");
+    }
+    if register.payable_total == 0 {
+        invalid_fields.push("payable_total");
+    }
+    if let Some(custom_fields) = register.custom_fields {
+        if custom_fields.is_empty() || custom_fields.len() > MAX_CUSTOM_FIELDS_BYTES {
+            invalid_fields.push("custom_fields");
+        }
+    }
+    if register.priority_fee == Some(0) {
+        invalid_fields.push("priority_fee");
+    }
+    if register.oracle_gas_rebate == Some(0) {
+        invalid_fields.push("oracle_gas_rebate");
+    }
+    if register.minimum_payment_amount == Some(0) {
+        invalid_fields.push("minimum_payment_amount");
+    }
+    if let Some(accepted_denoms) = register.accepted_denoms {
+        // Each additional denom must be distinct from the payable denom and from one another
+        let mut unique_denoms = BTreeSet::from([register.payable_denom]);
+        if accepted_denoms.is_empty()
+            || accepted_denoms
+                .iter()
+                .any(|denom| !is_valid_denom(denom) || !unique_denoms.insert(denom))
+        {
+            invalid_fields.push("accepted_denoms");
+        }
+    }
+    if let Some(payment_schedule) = &register.payment_schedule {
+        // The installments must be listed in due order and add up to the payable's total
+        let scheduled_total = payment_schedule
+            .iter()
+            .try_fold(0u128, |total, installment| {
+                total.checked_add(installment.amount)
+            });
+        if payment_schedule.is_empty()
+            || payment_schedule
+                .iter()
+                .any(|installment| installment.amount == 0)
+            || payment_schedule
+                .windows(2)
+                .any(|pair| pair[0].due_time >= pair[1].due_time)
+            || scheduled_total != Some(register.payable_total)
+        {
+            invalid_fields.push("payment_schedule");
+        }
+    }
+    if let Some(late_fee) = &register.late_fee {
+        // Late fees must be payable, so they have to begin accruing before the payable expires
+        if late_fee.daily_rate == 0
+            || late_fee.daily_rate > DECIMAL_ONE
+            || register
+                .expiration_time
+                .is_some_and(|expiration_time| late_fee.due_time >= expiration_time)
+        {
+            invalid_fields.push("late_fee");
+        }
+    }
+    if let Some(interest) = &register.interest {
+        // As with late fees, interest that only begins to accrue once the payable expires could
+        // never be paid.  Compounded interest must also remain calculable across the payable's
+        // full term, or payments would start failing before it expires
+        if interest.annual_rate == 0
+            || interest.annual_rate > DECIMAL_ONE
+            || register
+                .expiration_time
+                .is_some_and(|expiration_time| interest.start_time >= expiration_time)
+            || !interest.is_accrual_calculable
+        {
+            invalid_fields.push("interest");
+        }
+    }
+    // Escrowed payments could never be released or reclaimed without the escrow subsystem
+    if register.escrow_timeout_seconds == Some(0)
+        || (register.escrow_timeout_seconds.is_some() && !cfg!(feature = "escrow"))
+    {
+        invalid_fields.push("escrow_timeout_seconds");
+    }
+    if let Some(authorized_payers) = register.authorized_payers {
+        // An empty allowlist would make the payable impossible to pay
+        if authorized_payers.is_empty()
+            || authorized_payers.iter().any(|payer| payer.is_empty())
+            || authorized_payers
+                .iter()
+                .collect::<BTreeSet<&String>>()
+                .len()
+                != authorized_payers.len()
+        {
+            invalid_fields.push("authorized_payers");
+        }
+    }
+    if let Some(payee_splits) = &register.payee_splits {
+        if payee_splits.is_empty()
+            || payee_splits.len() > MAX_PAYEE_SPLITS
+            || payee_splits
+                .iter()
+                .any(|split| split.address.is_empty() || split.weight == 0)
+            || payee_splits
+                .iter()
+                .map(|split| split.address)
+                .collect::<BTreeSet<&str>>()
+                .len()
+                != payee_splits.len()
+        {
+            invalid_fields.push("payee_splits");
+        }
+    }
+    if let Some(registration_nonce) = register.registration_nonce {
+        if registration_nonce.is_empty() || registration_nonce.len() > MAX_REGISTRATION_NONCE_LENGTH
+        {
+            invalid_fields.push("registration_nonce");
+        }
+    }
+    if register
+        .referrer
+        .is_some_and(|referrer| referrer.is_empty())
+    {
+        invalid_fields.push("referrer");
+    }
+    invalid_fields
+}
+
+/// Collects the names of all fields that are invalid for registering the given payables in a
+/// single batch.  Each field is only named once, no matter how many payables it is invalid for.
+pub fn invalid_register_payables_fields(payables: &[RegisterPayableFields]) -> Vec<&'static str> {
+    let mut invalid_fields: Vec<&'static str> = vec![];
+    if payables.is_empty() || payables.len() > MAX_BATCH_REGISTRATIONS {
+        invalid_fields.push("payables");
+    }
+    for payable in payables.iter() {
+        for field in invalid_register_payable_fields(payable) {
+            if !invalid_fields.contains(&field) {
+                invalid_fields.push(field);
+            }
+        }
+    }
+    let unique_uuids = payables
+        .iter()
+        .map(|payable| payable.payable_uuid)
+        .collect::<BTreeSet<&str>>();
+    let unique_scope_ids = payables
+        .iter()
+        .map(|payable| payable.scope_id)
+        .collect::<BTreeSet<&str>>();
+    if unique_uuids.len() != payables.len() || unique_scope_ids.len() != payables.len() {
+        invalid_fields.push("payables");
+    }
+    invalid_fields
+}